@@ -13,19 +13,33 @@ pragma solidity ^0.8.20;
  * - 0x20: VerifyStarkCommitment (50,000 base gas + 100/byte)
  * - 0x21: IsRequestVerified (10,000 gas)
  * - 0x22: GetBatchInfo (15,000 gas)
+ * - 0x23: VerifyDilithium (see DilithiumLib)
+ * - 0x24: BatchVerifyMlDsa (see MlDsaBatchLib)
+ * - 0x25: SubmitComputeRequest (see ZkCoprocessorLib)
+ * - 0x26: GetComputeResult (see ZkCoprocessorLib)
+ * - 0x27: IsVault (see QuantumVaultLib)
+ * - 0x28: GetVaultNonce (see QuantumVaultLib)
+ * - 0x29: VerifySp1Proof (SP1's ISP1Verifier, see Sp1VerifierLib)
+ *
+ *      Precompiles take `abi.encode` of their arguments and return ABI-encoded
+ *      results, except 0x20 and 0x24 whose packed layouts are documented on
+ *      their library functions. Prefer the libraries below to raw staticcalls.
  */
 
 /**
  * @notice Interface for checking request verification status
+ * @dev Each function lives at its own precompile: call `isRequestVerified`
+ *      on 0x21 and `getBatchInfo` on 0x22, e.g.
+ *      IReMLVerifier(address(0x21)).isRequestVerified(requestId)
  */
 interface IReMLVerifier {
     /**
      * @notice Check if a request ID has been verified via Re-ML STARK proof
-     * @param requestId The request ID to check (little-endian u64)
+     * @param requestId The request ID to check
      * @return verified True if the request has been verified
      *
-     * @dev Usage:
-     * (bool success, bytes memory data) = address(0x21).staticcall(abi.encodePacked(requestId));
+     * @dev Without the interface:
+     * (bool success, bytes memory data) = address(0x21).staticcall(abi.encode(requestId));
      * bool verified = success && abi.decode(data, (bool));
      */
     function isRequestVerified(
@@ -39,7 +53,8 @@ interface IReMLVerifier {
      * @return signatureCount Number of signatures in the batch
      * @return verifiedAtBlock Block number when batch was verified
      *
-     * @dev If batch is not found, returns all zeros
+     * @dev If batch is not found, returns all zeros. Without the interface,
+     *      staticcall 0x22 with abi.encode(batchId).
      */
     function getBatchInfo(
         uint64 batchId
@@ -104,17 +119,11 @@ library ReMLVerifierLib {
     function isRequestVerified(
         uint64 requestId
     ) internal view returns (bool verified) {
-        bytes memory input = abi.encodePacked(requestId);
-
         (bool success, bytes memory result) = IS_REQUEST_VERIFIED.staticcall(
-            input
+            abi.encode(requestId)
         );
 
-        if (!success || result.length < 32) {
-            return false;
-        }
-
-        return result[31] != 0;
+        return success && result.length == 32 && abi.decode(result, (bool));
     }
 
     /**
@@ -145,43 +154,335 @@ library ReMLVerifierLib {
             uint64 verifiedAtBlock
         )
     {
-        bytes memory input = abi.encodePacked(batchId);
-
-        (bool success, bytes memory result) = GET_BATCH_INFO.staticcall(input);
+        (bool success, bytes memory result) = GET_BATCH_INFO.staticcall(
+            abi.encode(batchId)
+        );
 
-        if (!success || result.length < 64) {
+        if (!success || result.length != 96) {
             return (false, bytes32(0), 0, 0);
         }
 
-        // Parse result
-        // bytes[0..32]: requests root
-        // bytes[32..36]: signature count (big-endian u32)
-        // bytes[36..44]: block number (big-endian u64)
+        (requestsRoot, signatureCount, verifiedAtBlock) = abi.decode(
+            result,
+            (bytes32, uint32, uint64)
+        );
+
+        // Check if this is a valid batch (non-zero root)
+        found = requestsRoot != bytes32(0);
+    }
+}
+
+/**
+ * @title ISP1Verifier
+ * @notice SP1's verifier interface, implemented by precompile 0x29
+ * @dev Contracts written against SP1's verifier gateway can be pointed at
+ *      ISP1Verifier(address(0x29)) unchanged. Proofs are SP1 Groth16 proofs
+ *      checked against the Re-ML verifier's Groth16 key, with public inputs
+ *      `programVKey` and `sha256(publicValues) & (2^253 - 1)`.
+ */
+interface ISP1Verifier {
+    /**
+     * @notice Revert with InvalidProof() unless `proofBytes` proves the
+     *         program `programVKey` committed `publicValues`
+     */
+    function verifyProof(
+        bytes32 programVKey,
+        bytes calldata publicValues,
+        bytes calldata proofBytes
+    ) external view;
+}
+
+/**
+ * @title Sp1VerifierLib
+ * @notice Checks Re-ML and other SP1 Groth16 proofs with precompile 0x29
+ * @dev A Re-ML proof commits the ABI encoding of its batch, the same bytes
+ *      submitted to pallet-reml-verifier, so `publicValues` decode with
+ *      `decodeReMLPublicValues`. Gas: the pallet's Groth16 verification
+ *      weight + 3/byte of input.
+ */
+library Sp1VerifierLib {
+    ISP1Verifier constant VERIFIER = ISP1Verifier(address(0x29));
+
+    /**
+     * @notice Whether `proofBytes` proves `programVKey` committed `publicValues`
+     */
+    function isValid(
+        bytes32 programVKey,
+        bytes memory publicValues,
+        bytes memory proofBytes
+    ) internal view returns (bool) {
+        try VERIFIER.verifyProof(programVKey, publicValues, proofBytes) {
+            return true;
+        } catch {
+            return false;
+        }
+    }
+
+    /**
+     * @notice Public values of a Re-ML batch proof
+     */
+    function decodeReMLPublicValues(
+        bytes memory publicValues
+    )
+        internal
+        pure
+        returns (
+            uint64 batchId,
+            uint32 epoch,
+            bytes32 requestsRoot,
+            uint64[] memory verifiedRequestIds
+        )
+    {
+        (, , epoch, batchId, , , , requestsRoot, , verifiedRequestIds) = abi.decode(
+            publicValues,
+            (uint8, uint32, uint32, uint64, uint32, uint32, uint32, bytes32, bytes32, uint64[])
+        );
+    }
+}
+
+/**
+ * @title ReMLAttestationsLib
+ * @notice Reads verified Re-ML batches mirrored into EVM storage
+ * @dev Every batch accepted by pallet-reml-verifier is written, in the same
+ *      extrinsic, into the storage of the contract at 0x0800. That contract
+ *      returns the word stored at the 32-byte slot it is called with.
+ *
+ *      Layout (Solidity rules):
+ *      - slot 0: latest batch ID
+ *      - slot 1: number of mirrored batches
+ *      - mapping(uint64 => bytes32) at slot 2: requests root
+ *      - mapping(uint64 => uint256) at slot 3: signatureCount << 64 | verifiedAtBlock
+ */
+library ReMLAttestationsLib {
+    address constant ATTESTATIONS = address(0x0800);
+
+    uint256 constant LATEST_BATCH_SLOT = 0;
+    uint256 constant BATCH_COUNT_SLOT = 1;
+    uint256 constant REQUESTS_ROOT_MAPPING = 2;
+    uint256 constant BATCH_META_MAPPING = 3;
 
-        assembly {
-            requestsRoot := mload(add(result, 32))
+    function _load(bytes32 slot) private view returns (bytes32 value) {
+        (bool success, bytes memory result) = ATTESTATIONS.staticcall(
+            abi.encode(slot)
+        );
+        if (!success || result.length < 32) {
+            return bytes32(0);
         }
+        value = abi.decode(result, (bytes32));
+    }
 
-        // Parse signature count (big-endian u32)
-        signatureCount =
-            (uint32(uint8(result[32])) << 24) |
-            (uint32(uint8(result[33])) << 16) |
-            (uint32(uint8(result[34])) << 8) |
-            uint32(uint8(result[35]));
-
-        // Parse block number (big-endian u64)
-        verifiedAtBlock =
-            (uint64(uint8(result[36])) << 56) |
-            (uint64(uint8(result[37])) << 48) |
-            (uint64(uint8(result[38])) << 40) |
-            (uint64(uint8(result[39])) << 32) |
-            (uint64(uint8(result[40])) << 24) |
-            (uint64(uint8(result[41])) << 16) |
-            (uint64(uint8(result[42])) << 8) |
-            uint64(uint8(result[43]));
+    function _mappingSlot(
+        uint64 batchId,
+        uint256 mappingSlot
+    ) private pure returns (bytes32) {
+        return keccak256(abi.encode(uint256(batchId), mappingSlot));
+    }
 
-        // Check if this is a valid batch (non-zero root)
-        found = requestsRoot != bytes32(0);
+    /**
+     * @notice Requests root of a verified batch, zero if unknown
+     */
+    function requestsRoot(uint64 batchId) internal view returns (bytes32) {
+        return _load(_mappingSlot(batchId, REQUESTS_ROOT_MAPPING));
+    }
+
+    /**
+     * @notice Signature count and verification block of a batch
+     */
+    function batchMeta(
+        uint64 batchId
+    ) internal view returns (uint32 signatureCount, uint64 verifiedAtBlock) {
+        uint256 meta = uint256(
+            _load(_mappingSlot(batchId, BATCH_META_MAPPING))
+        );
+        signatureCount = uint32(meta >> 64);
+        verifiedAtBlock = uint64(meta);
+    }
+
+    /**
+     * @notice Most recently verified batch ID
+     */
+    function latestBatchId() internal view returns (uint64) {
+        return uint64(uint256(_load(bytes32(LATEST_BATCH_SLOT))));
+    }
+
+    /**
+     * @notice Number of batches mirrored so far
+     */
+    function batchCount() internal view returns (uint64) {
+        return uint64(uint256(_load(bytes32(BATCH_COUNT_SLOT))));
+    }
+}
+
+/**
+ * @title DilithiumLib
+ * @notice Verifies one Dilithium2 (ML-DSA-44) signature with a call to 0x23
+ * @dev Gas: 3,000 + 3/byte of input + 40,000. Keys are 1312 bytes and
+ *      signatures 2420 bytes; other sizes verify as false.
+ */
+library DilithiumLib {
+    address constant VERIFY_DILITHIUM = address(0x23);
+
+    /**
+     * @notice Whether `signature` is `publicKey`'s signature of `message`
+     */
+    function verify(
+        bytes memory message,
+        bytes memory publicKey,
+        bytes memory signature
+    ) internal view returns (bool) {
+        (bool success, bytes memory result) = VERIFY_DILITHIUM.staticcall(
+            abi.encode(message, publicKey, signature)
+        );
+        return success && result.length == 32 && abi.decode(result, (bool));
+    }
+}
+
+/**
+ * @title MlDsaBatchLib
+ * @notice Verifies several ML-DSA-44 signatures with one call to 0x24
+ * @dev Keys and signatures are passed once; each item names its key by
+ *      keccak256(publicKey) and its signature by index. At most 32 keys,
+ *      32 signatures and 32 items per call. Gas: 5,000 + 3/byte + 40,000 per item.
+ *
+ *      Input (big-endian): uint16 keyCount || uint16 signatureCount ||
+ *      keys (1312 bytes each) || signatures (2420 bytes each) ||
+ *      per item: bytes32 keyHash || uint16 signatureIndex || uint32 length || message
+ */
+library MlDsaBatchLib {
+    address constant BATCH_VERIFY_ML_DSA = address(0x24);
+
+    struct Item {
+        bytes32 keyHash;
+        uint16 signatureIndex;
+        bytes message;
+    }
+
+    /**
+     * @notice Verify `items` against the supplied keys and signatures
+     * @return results One entry per item; empty if the call failed
+     */
+    function verifyBatch(
+        bytes[] memory publicKeys,
+        bytes[] memory signatures,
+        Item[] memory items
+    ) internal view returns (bool[] memory results) {
+        bytes memory input = abi.encodePacked(
+            uint16(publicKeys.length),
+            uint16(signatures.length)
+        );
+        for (uint256 i = 0; i < publicKeys.length; i++) {
+            input = bytes.concat(input, publicKeys[i]);
+        }
+        for (uint256 i = 0; i < signatures.length; i++) {
+            input = bytes.concat(input, signatures[i]);
+        }
+        for (uint256 i = 0; i < items.length; i++) {
+            input = abi.encodePacked(
+                input,
+                items[i].keyHash,
+                items[i].signatureIndex,
+                uint32(items[i].message.length),
+                items[i].message
+            );
+        }
+
+        (bool success, bytes memory result) = BATCH_VERIFY_ML_DSA.staticcall(
+            input
+        );
+        if (!success) {
+            return new bool[](0);
+        }
+        results = abi.decode(result, (bool[]));
+    }
+
+    /**
+     * @notice Whether every item verified
+     */
+    function allValid(bool[] memory results) internal pure returns (bool) {
+        if (results.length == 0) {
+            return false;
+        }
+        for (uint256 i = 0; i < results.length; i++) {
+            if (!results[i]) {
+                return false;
+            }
+        }
+        return true;
+    }
+}
+
+/**
+ * @title ZkCoprocessorLib
+ * @notice Proven computation on registered zkVM programs (pallet-zk-coprocessor)
+ * @dev Requests are submitted from this contract's mapped account, which pays
+ *      the bounty and the request deposit. Once an aggregator proves the run,
+ *      the output can be read back with `result`.
+ */
+library ZkCoprocessorLib {
+    address constant SUBMIT_COMPUTE_REQUEST = address(0x25);
+    address constant GET_COMPUTE_RESULT = address(0x26);
+
+    uint8 constant UNKNOWN = 0;
+    uint8 constant PENDING = 1;
+    uint8 constant FULFILLED = 2;
+
+    /**
+     * @notice Request a proven run of `programId` on `input`
+     * @return requestId ID to poll with `result`
+     */
+    function submit(
+        uint32 programId,
+        bytes memory input,
+        uint256 bounty
+    ) internal returns (uint64 requestId) {
+        (bool success, bytes memory result) = SUBMIT_COMPUTE_REQUEST.call(
+            abi.encode(programId, input, bounty)
+        );
+        require(success, "Compute request rejected");
+        requestId = uint64(abi.decode(result, (uint256)));
+    }
+
+    /**
+     * @notice Status of `requestId` and its output once fulfilled
+     */
+    function result(
+        uint64 requestId
+    ) internal view returns (uint8 status, bytes memory output) {
+        (bool success, bytes memory data) = GET_COMPUTE_RESULT.staticcall(
+            abi.encode(requestId)
+        );
+        require(success, "Compute result query failed");
+        (status, output) = abi.decode(data, (uint8, bytes));
+    }
+}
+
+/**
+ * @title QuantumVaultLib
+ * @notice Reads pallet-quantum-vault state for an EVM address
+ * @dev Addresses map to accounts like EVM balances do (zero-padded to 32
+ *      bytes). Vaults only move funds with a Dilithium-signed vault transfer,
+ *      so e.g. a DEX can refuse or reroute them. Gas: 5,000 per call.
+ */
+library QuantumVaultLib {
+    address constant IS_VAULT = address(0x27);
+    address constant GET_VAULT_NONCE = address(0x28);
+
+    /**
+     * @notice Whether `account` is locked as a quantum vault
+     */
+    function isVault(address account) internal view returns (bool) {
+        (bool success, bytes memory result) = IS_VAULT.staticcall(abi.encode(account));
+        return success && result.length == 32 && abi.decode(result, (bool));
+    }
+
+    /**
+     * @notice Nonce the next vault transfer of `vault` signs
+     * @dev Reverts if `vault` is not a vault
+     */
+    function getVaultNonce(address vault) internal view returns (uint64) {
+        (bool success, bytes memory result) = GET_VAULT_NONCE.staticcall(abi.encode(vault));
+        require(success, "Not a vault");
+        return uint64(abi.decode(result, (uint256)));
     }
 }
 
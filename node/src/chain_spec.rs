@@ -11,7 +11,7 @@
 //! - (Future) Mainnet: Production network
 
 use sc_service::ChainType;
-use tesserax_runtime::WASM_BINARY;
+use tesserax_runtime::{naming, WASM_BINARY};
 
 /// Specialized `ChainSpec` for Tesserax Protocol.
 pub type ChainSpec = sc_service::GenericChainSpec;
@@ -35,11 +35,11 @@ pub fn development_chain_spec() -> Result<ChainSpec, String> {
         WASM_BINARY.ok_or_else(|| "Development wasm not available".to_string())?,
         None,
     )
-    .with_name("Tesserax Development")
-    .with_id("tesserax_dev")
+    .with_name("Sanctuary Development")
+    .with_id(naming::DEV_CHAIN_ID)
     .with_chain_type(ChainType::Development)
     .with_genesis_config_preset_name(sp_genesis_builder::DEV_RUNTIME_PRESET)
-    .with_protocol_id("tesserax")
+    .with_protocol_id(naming::PROTOCOL_ID)
     .with_properties(chain_properties())
     .build())
 }
@@ -65,11 +65,11 @@ pub fn local_chain_spec() -> Result<ChainSpec, String> {
         WASM_BINARY.ok_or_else(|| "Development wasm not available".to_string())?,
         None,
     )
-    .with_name("Tesserax Local Testnet")
-    .with_id("tesserax_local")
+    .with_name("Sanctuary Local Testnet")
+    .with_id(naming::LOCAL_CHAIN_ID)
     .with_chain_type(ChainType::Local)
     .with_genesis_config_preset_name(sp_genesis_builder::LOCAL_TESTNET_RUNTIME_PRESET)
-    .with_protocol_id("tesserax")
+    .with_protocol_id(naming::PROTOCOL_ID)
     .with_properties(chain_properties())
     .build())
 }
@@ -86,8 +86,8 @@ pub fn local_chain_spec() -> Result<ChainSpec, String> {
 /// ═══════════════════════════════════════════════════════════════════════════
 fn chain_properties() -> sc_service::Properties {
     let mut properties = sc_service::Properties::new();
-    properties.insert("tokenSymbol".into(), "TSRX".into());
-    properties.insert("tokenDecimals".into(), 18.into());
-    properties.insert("ss58Format".into(), 42.into()); // Generic Substrate format
+    properties.insert("tokenSymbol".into(), tesserax_runtime::TOKEN_SYMBOL.into());
+    properties.insert("tokenDecimals".into(), tesserax_runtime::TOKEN_DECIMALS.into());
+    properties.insert("ss58Format".into(), naming::SS58_FORMAT.into()); // Generic Substrate format
     properties
 }
@@ -7,6 +7,9 @@ pub struct Cli {
 
     #[clap(flatten)]
     pub run: RunCmd,
+
+    #[clap(flatten)]
+    pub eth: crate::eth::EthConfiguration,
 }
 
 #[derive(Debug, clap::Subcommand)]
@@ -43,4 +46,16 @@ pub enum Subcommand {
 
     /// Db meta columns information.
     ChainInfo(sc_cli::ChainInfoCmd),
+
+    /// Derive a Quantum Vault ML-DSA key from a BIP39 mnemonic.
+    VaultKey(crate::vault_key::VaultKeyCmd),
+
+    /// Sign a Quantum Vault payload with a mnemonic-derived key.
+    VaultSign(crate::vault_sign::VaultSignCmd),
+
+    /// Snapshot balances, vaults and Re-ML batches of a dev chain.
+    ExportDevnetState(crate::devnet_state::ExportDevnetStateCmd),
+
+    /// Build a raw chain spec from a devnet snapshot.
+    ImportDevnetState(crate::devnet_state::ImportDevnetStateCmd),
 }
@@ -2,17 +2,17 @@ use crate::{
     benchmarking::{inherent_benchmark_data, RemarkBuilder, TransferKeepAliveBuilder},
     chain_spec,
     cli::{Cli, Subcommand},
-    service,
+    service, span_metrics,
 };
 use frame_benchmarking_cli::{BenchmarkCmd, ExtrinsicFactory, SUBSTRATE_REFERENCE_HARDWARE};
-use sc_cli::SubstrateCli;
+use sc_cli::{CliConfiguration, SubstrateCli};
 use sc_service::PartialComponents;
 use sp_keyring::Sr25519Keyring;
 use tesserax_runtime::{Block, EXISTENTIAL_DEPOSIT};
 
 impl SubstrateCli for Cli {
     fn impl_name() -> String {
-        "Tesserax Node".into()
+        "Sanctuary Node".into()
     }
 
     fn impl_version() -> String {
@@ -138,9 +138,13 @@ pub fn run() -> sc_cli::Result<()> {
                             );
                         }
 
-                        cmd.run_with_spec::<sp_runtime::traits::HashingFor<Block>, ()>(Some(
-                            config.chain_spec,
-                        ))
+                        cmd.run_with_spec::<
+                            sp_runtime::traits::HashingFor<Block>,
+                            (
+                                tesserax_dilithium_io::dilithium::HostFunctions,
+                                tesserax_sphincs_io::sphincs::HostFunctions,
+                            ),
+                        >(Some(config.chain_spec))
                     }
                     BenchmarkCmd::Block(cmd) => {
                         let PartialComponents { client, .. } = service::new_partial(&config)?;
@@ -198,8 +202,25 @@ pub fn run() -> sc_cli::Result<()> {
             let runner = cli.create_runner(cmd)?;
             runner.sync_run(|config| cmd.run::<Block>(&config))
         }
+        Some(Subcommand::VaultKey(cmd)) => cmd.run(),
+        Some(Subcommand::VaultSign(cmd)) => cmd.run(),
+        Some(Subcommand::ExportDevnetState(cmd)) => {
+            let runner = cli.create_runner(cmd)?;
+            runner.sync_run(|config| {
+                let PartialComponents { client, .. } = service::new_partial(&config)?;
+                cmd.run(&*client, &*config.chain_spec)
+            })
+        }
+        Some(Subcommand::ImportDevnetState(cmd)) => cmd.run(&cli),
         None => {
-            let runner = cli.create_runner(&cli.run)?;
+            let run = cli.eth.apply_profile(cli.run.clone());
+            let eth_config = cli.eth.clone();
+            let tracing_targets = span_metrics::tracing_targets(run.tracing_targets()?);
+            let tracing_receiver = run.tracing_receiver()?;
+            let runner = cli.create_runner_with_logger_hook(&run, move |logger| {
+                logger.with_profiling(tracing_receiver, tracing_targets);
+                logger.with_custom_profiling(Box::new(span_metrics::SpanMetrics));
+            })?;
             runner.run_node_until_exit(|config| async move {
                 match config.network.network_backend {
                     sc_network::config::NetworkBackendType::Libp2p => service::new_full::<
@@ -207,10 +228,12 @@ pub fn run() -> sc_cli::Result<()> {
                             tesserax_runtime::opaque::Block,
                             <tesserax_runtime::opaque::Block as sp_runtime::traits::Block>::Hash,
                         >,
-                    >(config)
+                    >(
+                        config, eth_config
+                    )
                     .map_err(sc_cli::Error::Service),
                     sc_network::config::NetworkBackendType::Litep2p => {
-                        service::new_full::<sc_network::Litep2pNetworkBackend>(config)
+                        service::new_full::<sc_network::Litep2pNetworkBackend>(config, eth_config)
                             .map_err(sc_cli::Error::Service)
                     }
                 }
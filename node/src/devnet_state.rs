@@ -0,0 +1,204 @@
+// This file is part of Tesserax Protocol.
+//
+// Copyright (C) 2025 Minerva & Gemini (The Architect)
+// SPDX-License-Identifier: MIT-0
+
+//! `export-devnet-state` / `import-devnet-state` subcommands.
+//!
+//! Snapshot the balances, vaults and Re-ML batches of a dev chain into a JSON
+//! file, and turn that file back into a raw chain spec, so QA can start a node
+//! from the exact state a bug report was filed against:
+//!
+//! ```text
+//! tesserax-node export-devnet-state --dev --output snapshot.json
+//! tesserax-node import-devnet-state snapshot.json --output spec.json
+//! tesserax-node --chain spec.json --alice --tmp
+//! ```
+//!
+//! Only `System::Account`, `Balances`, `QuantumVault` and `RemlVerifier`
+//! storage is captured; validators, EVM code and the emission schedule come
+//! from the base chain spec. Block numbers stored in the snapshot (vault
+//! creation, batch verification) refer to the source chain, which restarts at
+//! block 0 after import.
+
+use std::{collections::BTreeMap, fs, path::PathBuf};
+
+use sc_cli::{CliConfiguration, DatabaseParams, SharedParams, SubstrateCli};
+use sc_client_api::{Backend, StorageProvider};
+use sc_service::ChainType;
+use serde::{Deserialize, Serialize};
+use sp_blockchain::HeaderBackend;
+use sp_core::{
+    storage::{StorageData, StorageKey},
+    H256,
+};
+use tesserax_runtime::Block;
+
+/// Storage captured in a snapshot, as `(pallet, item)`; `None` takes the whole pallet
+const SNAPSHOT_PREFIXES: &[(&str, Option<&str>)] = &[
+    ("System", Some("Account")),
+    ("Balances", None),
+    ("QuantumVault", None),
+    ("RemlVerifier", None),
+];
+
+/// Storage snapshot of a dev chain.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DevnetSnapshot {
+    /// Chain spec ID of the source chain
+    pub chain: String,
+    /// Block the snapshot was taken at
+    pub block_number: u32,
+    /// Hash of that block
+    pub block_hash: H256,
+    /// Captured storage, sorted by key
+    pub storage: BTreeMap<StorageKey, StorageData>,
+}
+
+/// Write the balances, vaults and Re-ML batches of a dev chain to a snapshot.
+#[derive(Debug, clap::Parser)]
+pub struct ExportDevnetStateCmd {
+    /// Block hash or number to snapshot. Defaults to the best block.
+    #[arg(long, value_name = "HASH_OR_NUMBER")]
+    pub at: Option<sc_cli::BlockNumberOrHash>,
+
+    /// Snapshot file to write. Defaults to stdout.
+    #[arg(long, short)]
+    pub output: Option<PathBuf>,
+
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub shared_params: SharedParams,
+
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub database_params: DatabaseParams,
+}
+
+impl ExportDevnetStateCmd {
+    /// Run the command against `client`.
+    pub fn run<C, BE>(
+        &self,
+        client: &C,
+        chain_spec: &dyn sc_service::ChainSpec,
+    ) -> sc_cli::Result<()>
+    where
+        BE: Backend<Block>,
+        C: StorageProvider<Block, BE> + HeaderBackend<Block>,
+    {
+        if chain_spec.chain_type() == ChainType::Live {
+            return Err(sc_cli::Error::Input(
+                "export-devnet-state only snapshots development and local chains".into(),
+            ));
+        }
+
+        let block_hash = match &self.at {
+            Some(at) => {
+                let id = at.parse::<Block>().map_err(sc_cli::Error::Input)?;
+                client.expect_block_hash_from_id(&id)?
+            },
+            None => client.info().best_hash,
+        };
+        let block_number = client.number(block_hash)?.unwrap_or_default();
+
+        let mut storage = BTreeMap::new();
+        for (pallet, item) in SNAPSHOT_PREFIXES {
+            let prefix = StorageKey(storage_prefix(pallet, *item));
+            storage.extend(client.storage_pairs(block_hash, Some(&prefix), None)?);
+        }
+
+        let snapshot =
+            DevnetSnapshot { chain: chain_spec.id().into(), block_number, block_hash, storage };
+        let json = serde_json::to_string_pretty(&snapshot)
+            .map_err(|e| sc_cli::Error::Input(format!("Snapshot encoding failed: {e}")))?;
+
+        write_output(self.output.as_ref(), &json)?;
+        eprintln!("Exported {} storage entries at block #{}", snapshot.storage.len(), block_number);
+
+        Ok(())
+    }
+}
+
+impl CliConfiguration for ExportDevnetStateCmd {
+    fn shared_params(&self) -> &SharedParams {
+        &self.shared_params
+    }
+
+    fn database_params(&self) -> Option<&DatabaseParams> {
+        Some(&self.database_params)
+    }
+}
+
+/// Build a raw chain spec from a snapshot written by `export-devnet-state`.
+#[derive(Debug, clap::Parser)]
+pub struct ImportDevnetStateCmd {
+    /// Snapshot file to import.
+    pub snapshot: PathBuf,
+
+    /// Chain spec the snapshot is applied on top of (`dev`, `local` or a path).
+    #[arg(long, default_value = "dev")]
+    pub base_chain: String,
+
+    /// Chain spec file to write. Defaults to stdout.
+    #[arg(long, short)]
+    pub output: Option<PathBuf>,
+}
+
+impl ImportDevnetStateCmd {
+    /// Run the command.
+    pub fn run(&self, cli: &impl SubstrateCli) -> sc_cli::Result<()> {
+        let snapshot: DevnetSnapshot = serde_json::from_slice(&fs::read(&self.snapshot)?)
+            .map_err(|e| sc_cli::Error::Input(format!("Invalid snapshot: {e}")))?;
+
+        let prefixes: Vec<Vec<u8>> =
+            SNAPSHOT_PREFIXES.iter().map(|(pallet, item)| storage_prefix(pallet, *item)).collect();
+        let captured = |key: &[u8]| prefixes.iter().any(|prefix| key.starts_with(prefix));
+
+        if let Some((key, _)) = snapshot.storage.iter().find(|(key, _)| !captured(&key.0)) {
+            return Err(sc_cli::Error::Input(format!(
+                "Snapshot key 0x{} is outside the captured pallets",
+                hex::encode(&key.0)
+            )));
+        }
+
+        let mut spec = cli.load_spec(&self.base_chain).map_err(sc_cli::Error::Input)?;
+        let mut storage =
+            spec.as_storage_builder().build_storage().map_err(sc_cli::Error::Input)?;
+
+        // Replace, not merge: accounts absent from the snapshot must not keep
+        // their base genesis balance or `TotalIssuance` would not add up.
+        storage.top.retain(|key, _| !captured(key));
+        storage.top.extend(snapshot.storage.into_iter().map(|(key, value)| (key.0, value.0)));
+        spec.set_storage(storage);
+
+        let json = spec.as_json(true).map_err(sc_cli::Error::Input)?;
+        write_output(self.output.as_ref(), &json)?;
+        eprintln!(
+            "Imported {} snapshot of block #{} onto {}",
+            snapshot.chain,
+            snapshot.block_number,
+            spec.id()
+        );
+
+        Ok(())
+    }
+}
+
+/// `twox128(pallet) ++ twox128(item)` storage prefix.
+fn storage_prefix(pallet: &str, item: Option<&str>) -> Vec<u8> {
+    let mut prefix = sp_io::hashing::twox_128(pallet.as_bytes()).to_vec();
+    if let Some(item) = item {
+        prefix.extend(sp_io::hashing::twox_128(item.as_bytes()));
+    }
+    prefix
+}
+
+/// Write `contents` to `path`, or stdout when unset.
+fn write_output(path: Option<&PathBuf>, contents: &str) -> sc_cli::Result<()> {
+    match path {
+        Some(path) => fs::write(path, contents)?,
+        None => println!("{contents}"),
+    }
+    Ok(())
+}
@@ -40,6 +40,91 @@ use tesserax_runtime::opaque::Block;
 /// Frontier backend type alias
 pub type FrontierBackend<C> = fc_db::kv::Backend<Block, C>;
 
+/// Frontier RPC cache sizes and query limits
+///
+/// Unset values take the defaults below, or the `--archive-evm` profile's.
+#[derive(Debug, Clone, clap::Parser)]
+pub struct EthConfiguration {
+    /// Size in bytes of the LRU cache for block data [default: 50]
+    #[arg(long)]
+    pub eth_log_block_cache: Option<usize>,
+
+    /// Size in bytes of the LRU cache for transaction statuses [default: 50]
+    #[arg(long)]
+    pub eth_statuses_cache: Option<usize>,
+
+    /// Maximum number of blocks kept in the fee history cache [default: 2048]
+    #[arg(long)]
+    pub fee_history_limit: Option<FeeHistoryCacheLimit>,
+
+    /// Maximum number of logs returned by one `eth_getLogs` query [default: 10000]
+    #[arg(long)]
+    pub max_past_logs: Option<u32>,
+
+    /// Tune the node for EVM indexers: archive state and blocks, large
+    /// caches and log queries
+    ///
+    /// Explicit limits, `--state-pruning` and a non-default `--blocks-pruning`
+    /// still take precedence.
+    #[arg(long)]
+    pub archive_evm: bool,
+}
+
+/// Frontier settings of a regular node, and of an `--archive-evm` one
+const ETH_DEFAULTS: EthLimits = EthLimits {
+    block_cache: 50,
+    statuses_cache: 50,
+    fee_history_limit: 2048,
+    max_past_logs: 10_000,
+};
+const ETH_ARCHIVE_DEFAULTS: EthLimits = EthLimits {
+    block_cache: 300_000_000,
+    statuses_cache: 300_000_000,
+    fee_history_limit: 10_000,
+    max_past_logs: 100_000,
+};
+
+/// Resolved Frontier cache sizes and query limits
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EthLimits {
+    pub block_cache: usize,
+    pub statuses_cache: usize,
+    pub fee_history_limit: FeeHistoryCacheLimit,
+    pub max_past_logs: u32,
+}
+
+impl EthConfiguration {
+    /// Cache sizes and limits after applying the profile's defaults
+    pub fn limits(&self) -> EthLimits {
+        let defaults = if self.archive_evm {
+            ETH_ARCHIVE_DEFAULTS
+        } else {
+            ETH_DEFAULTS
+        };
+        EthLimits {
+            block_cache: self.eth_log_block_cache.unwrap_or(defaults.block_cache),
+            statuses_cache: self.eth_statuses_cache.unwrap_or(defaults.statuses_cache),
+            fee_history_limit: self.fee_history_limit.unwrap_or(defaults.fee_history_limit),
+            max_past_logs: self.max_past_logs.unwrap_or(defaults.max_past_logs),
+        }
+    }
+
+    /// `run` with archive state and block pruning under `--archive-evm`,
+    /// unless pruning was set explicitly
+    pub fn apply_profile(&self, mut run: sc_cli::RunCmd) -> sc_cli::RunCmd {
+        if self.archive_evm {
+            let pruning = &mut run.import_params.pruning_params;
+            pruning
+                .state_pruning
+                .get_or_insert(sc_cli::DatabasePruningMode::Archive);
+            if pruning.blocks_pruning == sc_cli::DatabasePruningMode::ArchiveCanonical {
+                pruning.blocks_pruning = sc_cli::DatabasePruningMode::Archive;
+            }
+        }
+        run
+    }
+}
+
 /// Extra dependencies for Ethereum compatibility.
 pub struct EthDeps<B: BlockT, C, P, CT, CIDP> {
     /// The client instance to use.
@@ -5,9 +5,13 @@ mod benchmarking;
 mod chain_spec;
 mod cli;
 mod command;
+mod devnet_state;
 mod eth;
 mod rpc;
 mod service;
+mod span_metrics;
+mod vault_key;
+mod vault_sign;
 
 fn main() -> sc_cli::Result<()> {
     command::run()
@@ -0,0 +1,119 @@
+//! `emission_*` RPC namespace.
+//!
+//! Emission schedule figures for explorers and dashboards, so they can plot
+//! the sigmoid curve without embedding the 7,300-era schedule.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc, types::ErrorObject};
+use pallet_emission::{runtime_api::EmissionApi as EmissionRuntimeApi, MAX_SCHEDULE_PAGE_SIZE};
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+/// Runtime API call failed
+const RUNTIME_ERROR: i32 = 4001;
+
+/// Emission state at a block.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmissionStats<BlockNumber> {
+    /// Current era (0-indexed)
+    pub current_era: u32,
+    /// Reward per block in the current era, in planck
+    pub reward_per_block: u128,
+    /// Total emitted so far, in planck (genesis supply excluded)
+    pub total_emitted: u128,
+    /// Last block of the emission schedule
+    pub emission_ends_at_block: BlockNumber,
+}
+
+/// A page of the reward schedule.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SchedulePage {
+    /// Era of the first reward
+    pub start_era: u32,
+    /// Per-block reward of consecutive eras, in planck
+    pub rewards: Vec<u128>,
+    /// `start_era` for the next page, `None` once exhausted
+    pub next_era: Option<u32>,
+}
+
+/// Emission RPC methods.
+#[rpc(client, server)]
+pub trait EmissionApi<BlockHash, BlockNumber> {
+    /// Current era, reward per block, total emitted and end of emission.
+    #[method(name = "emission_stats")]
+    fn stats(&self, at: Option<BlockHash>) -> RpcResult<EmissionStats<BlockNumber>>;
+
+    /// Per-block reward of eras from `start_era`, at most `limit` (capped at 1,000).
+    #[method(name = "emission_rewardSchedule")]
+    fn reward_schedule(
+        &self,
+        start_era: Option<u32>,
+        limit: Option<u32>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<SchedulePage>;
+}
+
+/// Implementation of [`EmissionApiServer`].
+pub struct Emission<C, B> {
+    client: Arc<C>,
+    _marker: PhantomData<B>,
+}
+
+impl<C, B> Emission<C, B> {
+    /// Create a new `Emission` RPC handler.
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client, _marker: Default::default() }
+    }
+}
+
+impl<C, Block, BlockNumber> EmissionApiServer<<Block as BlockT>::Hash, BlockNumber>
+    for Emission<C, Block>
+where
+    Block: BlockT,
+    BlockNumber: Codec + Send + Sync + Serialize + for<'de> Deserialize<'de> + 'static,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: EmissionRuntimeApi<Block, BlockNumber>,
+{
+    fn stats(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<EmissionStats<BlockNumber>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let api = self.client.runtime_api();
+        let error = |e: sp_api::ApiError| {
+            ErrorObject::owned(RUNTIME_ERROR, "Unable to query emission", Some(e.to_string()))
+        };
+
+        Ok(EmissionStats {
+            current_era: api.current_era(at).map_err(error)?,
+            reward_per_block: api.reward_for_current_block(at).map_err(error)?,
+            total_emitted: api.total_emitted(at).map_err(error)?,
+            emission_ends_at_block: api.emission_ends_at_block(at).map_err(error)?,
+        })
+    }
+    fn reward_schedule(
+        &self,
+        start_era: Option<u32>,
+        limit: Option<u32>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<SchedulePage> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let start_era = start_era.unwrap_or(0);
+        let limit = limit.unwrap_or(MAX_SCHEDULE_PAGE_SIZE).min(MAX_SCHEDULE_PAGE_SIZE);
+
+        let rewards =
+            self.client.runtime_api().reward_schedule(at, start_era, limit).map_err(|e| {
+                ErrorObject::owned(
+                    RUNTIME_ERROR,
+                    "Unable to read reward schedule",
+                    Some(e.to_string()),
+                )
+            })?;
+
+        let next_era = (rewards.len() as u32 == limit).then(|| start_era.saturating_add(limit));
+        Ok(SchedulePage { start_era, rewards, next_era })
+    }
+}
@@ -7,6 +7,11 @@
 
 #![warn(missing_docs)]
 
+pub mod emission;
+pub mod reml;
+pub mod sanctuary;
+pub mod vault;
+
 use std::sync::Arc;
 
 use jsonrpsee::RpcModule;
@@ -14,7 +19,7 @@ use sc_transaction_pool_api::TransactionPool;
 use sp_api::ProvideRuntimeApi;
 use sp_block_builder::BlockBuilder;
 use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
-use tesserax_runtime::{opaque::Block, AccountId, Balance, Nonce};
+use tesserax_runtime::{opaque::Block, AccountId, Balance, BlockNumber, Nonce, Signature};
 
 /// Tesserax Chain ID: 13817 (derived from floor(π × e × φ × 10^6) = 13,817,580)
 pub const CHAIN_ID: u64 = 13817;
@@ -38,24 +43,55 @@ where
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
     C::Api: BlockBuilder<Block>,
-    P: TransactionPool + 'static,
+    C::Api: tesserax_runtime::account_views::AccountViewsApi<Block>,
+    C::Api: tesserax_runtime::chain_health::ChainHealthApi<Block>,
+    C::Api: pallet_emission::runtime_api::EmissionApi<Block, BlockNumber>,
+    C::Api: pallet_quantum_vault::runtime_api::QuantumVaultApi<
+        Block,
+        AccountId,
+        Balance,
+        BlockNumber,
+    >,
+    C::Api: pallet_reml_verifier::runtime_api::RemlVerifierApi<
+        Block,
+        AccountId,
+        BlockNumber,
+        Signature,
+    >,
+    C::Api: pallet_reml_verifier::runtime_api::RemlApi<Block, AccountId, BlockNumber>,
+    P: TransactionPool<Block = Block, Hash = <Block as sp_runtime::traits::Block>::Hash> + 'static,
 {
+    use emission::{Emission, EmissionApiServer};
     use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
+    use reml::{Reml, RemlApiServer};
+    use sanctuary::{Sanctuary, SanctuaryApiServer};
+    use vault::{Vault, VaultApiServer};
     use substrate_frame_rpc_system::{System, SystemApiServer};
 
     let mut module = RpcModule::new(());
     let FullDeps { client, pool } = deps;
 
     // Substrate RPC
-    module.merge(System::new(client.clone(), pool).into_rpc())?;
-    module.merge(TransactionPayment::new(client).into_rpc())?;
+    module.merge(System::new(client.clone(), pool.clone()).into_rpc())?;
+    module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+
+    // Sanctuary RPC (sanctuary_*)
+    module.merge(Sanctuary::new(client.clone(), pool.clone()).into_rpc())?;
+
+    // Indexer pagination (vault_*, reml_*)
+    module.merge(Vault::new(client.clone()).into_rpc())?;
+    module.merge(Reml::new(client.clone(), pool).into_rpc())?;
+
+    // Emission dashboard (emission_*)
+    module.merge(Emission::new(client).into_rpc())?;
 
     // ═══════════════════════════════════════════════════════════════════════════
     // ETHEREUM RPC ENDPOINTS
     // ═══════════════════════════════════════════════════════════════════════════
     // Full eth_*, net_*, and web3_* methods are provided by Frontier via eth.rs
     // The create_eth() function in eth.rs registers all Ethereum-compatible RPCs
-    // This file only provides basic Substrate RPCs (system, transaction_payment)
+    // This file provides Substrate RPCs (system, transaction_payment) and the
+    // sanctuary_*, vault_*, reml_* and emission_* namespaces
 
     Ok(module)
 }
@@ -0,0 +1,528 @@
+//! `reml_*` RPC namespace.
+//!
+//! Server-side pagination over Re-ML verified batches for explorers and
+//! indexers, lookup of a batch by its requests root, a dry run of proof
+//! submission so aggregators can validate a bundle before paying for it,
+//! decoding of submitted bundles for explorers that don't parse them, and
+//! verification status and proof submission for dApps and the off-chain
+//! prover (`RemlApi` runtime API).
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::{Codec, Decode, DecodeAll};
+use jsonrpsee::{core::RpcResult, proc_macros::rpc, types::ErrorObject};
+use pallet_reml_verifier::{
+    runtime_api::{RemlApi as RemlStatusApi, RemlVerifierApi},
+    BatchInfo, ProofSubmission, PublicValues, SingleProofSubmission, GROTH16_PROOF_SIZE,
+    MAX_PAGE_SIZE,
+};
+use sc_transaction_pool_api::{TransactionPool, TransactionSource};
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{Bytes, H256};
+use sp_runtime::traits::Block as BlockT;
+use tesserax_runtime::{Runtime, RuntimeCall, UncheckedExtrinsic};
+
+/// Runtime API call failed
+const RUNTIME_ERROR: i32 = 3001;
+/// Submission is not a SCALE-encoded `ProofSubmission`
+const INVALID_SUBMISSION: i32 = 3002;
+/// Bundle is not a proof extrinsic, call or submission
+const INVALID_BUNDLE: i32 = 3003;
+/// Transaction pool rejected a proof extrinsic
+const POOL_ERROR: i32 = 3004;
+
+/// A verified batch.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchEntry<AccountId, BlockNumber> {
+    /// Batch ID
+    pub batch_id: u64,
+    /// Aggregator that submitted the proof
+    pub aggregator: AccountId,
+    /// Block the proof was verified in
+    pub verified_at: BlockNumber,
+    /// Number of signatures in the batch
+    pub signature_count: u32,
+    /// Merkle root of verified request IDs
+    pub requests_root: H256,
+    /// Proof commitment (replay protection)
+    pub proof_commitment: H256,
+}
+
+impl<AccountId, BlockNumber> BatchEntry<AccountId, BlockNumber> {
+    fn new(batch_id: u64, info: BatchInfo<AccountId, BlockNumber>) -> Self {
+        Self {
+            batch_id,
+            aggregator: info.aggregator,
+            verified_at: info.verified_at,
+            signature_count: info.signature_count,
+            requests_root: H256(info.requests_root),
+            proof_commitment: H256(info.proof_commitment),
+        }
+    }
+}
+
+/// A page of verified batches.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchPage<AccountId, BlockNumber> {
+    /// Batches in this page (storage order, not numeric order)
+    pub batches: Vec<BatchEntry<AccountId, BlockNumber>>,
+    /// `from_id` for the next page, `None` once exhausted
+    pub next_id: Option<u64>,
+}
+
+/// A batch ID claimed and awaiting its proof.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PendingAggregator<AccountId> {
+    /// Aggregator that claimed the ID
+    pub aggregator: AccountId,
+    /// Claimed batch ID
+    pub batch_id: u64,
+}
+
+/// Outcome of a simulated proof submission.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProofDryRun {
+    /// Whether the proof would be accepted
+    pub success: bool,
+    /// Pallet error it would fail with, e.g. `InvalidMerkleRoot`
+    pub error: Option<String>,
+    /// `ProofRejected` reason for the error, e.g. `StarkVerificationFailed`
+    pub reject_reason: Option<String>,
+}
+
+/// Public values of a decoded bundle.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DecodedPublicValues {
+    /// Re-ML protocol version
+    pub version: u8,
+    /// Chain the proof is for
+    pub chain_id: u32,
+    /// Proof epoch the proof was generated for
+    pub epoch: u32,
+    /// Batch ID committed in the proof
+    pub batch_id: u64,
+    /// Number of verified requests
+    pub verified_count: u32,
+    /// How many of them are ML-DSA-44 signatures
+    pub mldsa44_count: u32,
+    /// How many of them are Falcon-512 signatures
+    pub falcon512_count: u32,
+    /// Keccak Merkle root of the verified request IDs
+    pub requests_root: H256,
+    /// Blake2 Merkle root of the verified request IDs
+    pub requests_root_blake2: H256,
+    /// Verified request IDs
+    pub verified_request_ids: Vec<u64>,
+    /// Owner key hash of each verified request
+    pub verified_request_owners: Vec<H256>,
+    /// Message hash of each verified request
+    pub verified_request_messages: Vec<H256>,
+}
+
+impl From<PublicValues> for DecodedPublicValues {
+    fn from(values: PublicValues) -> Self {
+        Self {
+            version: values.version,
+            chain_id: values.chain_id,
+            epoch: values.epoch,
+            batch_id: values.batch_id,
+            verified_count: values.verified_count,
+            mldsa44_count: values.mldsa44_count,
+            falcon512_count: values.falcon512_count,
+            requests_root: H256(values.requests_root),
+            requests_root_blake2: H256(values.requests_root_blake2),
+            verified_request_ids: values.verified_request_ids.into_inner(),
+            verified_request_owners: values.verified_request_owners.into_iter().map(H256).collect(),
+            verified_request_messages: values
+                .verified_request_messages
+                .into_iter()
+                .map(H256)
+                .collect(),
+        }
+    }
+}
+
+/// A proof bundle, decoded.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DecodedBundle<AccountId, BlockNumber> {
+    /// What the input was: `extrinsic`, `call`, `submission` or `singleSubmission`
+    pub format: String,
+    /// Re-ML call of an extrinsic or call, e.g. `submit_cosigned_proof`
+    pub call: Option<String>,
+    /// Batch ID the bundle is submitted under
+    pub batch_id: u64,
+    /// Verification key hash
+    pub vkey_hash: H256,
+    /// Guest program of the proof, 1 for Re-ML
+    pub program_id: u32,
+    /// Block the submission is anchored to
+    pub anchor_block: u32,
+    /// Hash of `anchor_block`
+    pub anchor_hash: H256,
+    /// Public values, rebuilt by the runtime for single-request submissions
+    pub public_values: DecodedPublicValues,
+    /// Proof size in bytes
+    pub proof_size: u32,
+    /// `groth16` for SP1 Groth16-sized proofs, `stark` otherwise
+    pub proof_kind: String,
+    /// Co-signatures attached to a `submit_cosigned_proof`
+    pub co_signatures: u32,
+    /// Hash the proof commits the public values with
+    pub binding_hash: H256,
+    /// Replay protection commitment the runtime records for the proof
+    pub proof_commitment: H256,
+    /// Whether `requestsRoot` and `requestsRootBlake2` are the Merkle roots of
+    /// `verifiedRequestIds`
+    pub requests_root_valid: bool,
+    /// Verified batch with the same requests root at the queried block
+    pub linked_batch: Option<BatchEntry<AccountId, BlockNumber>>,
+}
+
+/// Re-ML RPC methods.
+#[rpc(client, server)]
+pub trait RemlApi<BlockHash, AccountId, BlockNumber, CoSignature> {
+    /// List verified batches after `from_id` (exclusive), at most `limit` (capped at 100).
+    #[method(name = "reml_listBatches")]
+    fn list_batches(
+        &self,
+        from_id: Option<u64>,
+        limit: Option<u32>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<BatchPage<AccountId, BlockNumber>>;
+
+    /// Batch whose requests root is `root`, `None` if no batch has it.
+    #[method(name = "reml_batchByRoot")]
+    fn batch_by_root(
+        &self,
+        root: H256,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<BatchEntry<AccountId, BlockNumber>>>;
+
+    /// Run a proof submission by `aggregator` against state at `at` without submitting it.
+    ///
+    /// `submission` is a SCALE-encoded `ProofSubmission`.
+    #[method(name = "reml_dryRunSubmitProof")]
+    fn dry_run_submit_proof(
+        &self,
+        aggregator: AccountId,
+        submission: Bytes,
+        co_signatures: Option<Vec<(AccountId, CoSignature)>>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<ProofDryRun>;
+
+    /// Decode a proof bundle and link it to the batch verified with its requests root.
+    ///
+    /// `bundle` is a SCALE-encoded extrinsic or call submitting a proof, a
+    /// `ProofSubmission` or a `SingleProofSubmission`.
+    #[method(name = "reml_decodeBundle")]
+    fn decode_bundle(
+        &self,
+        bundle: Bytes,
+        at: Option<BlockHash>,
+    ) -> RpcResult<DecodedBundle<AccountId, BlockNumber>>;
+
+    /// Verified batch `batch_id`, `None` if unknown or pruned.
+    #[method(name = "reml_getBatch")]
+    fn get_batch(
+        &self,
+        batch_id: u64,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<BatchEntry<AccountId, BlockNumber>>>;
+
+    /// Whether request `request_id` is marked verified.
+    #[method(name = "reml_isVerified")]
+    fn is_verified(&self, request_id: u64, at: Option<BlockHash>) -> RpcResult<bool>;
+
+    /// Aggregators holding a claimed batch ID with no proof submitted yet (at most 100).
+    #[method(name = "reml_pendingAggregators")]
+    fn pending_aggregators(
+        &self,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<PendingAggregator<AccountId>>>;
+
+    /// Active aggregator routed the range of request `request_id` this era.
+    #[method(name = "reml_suggestedAggregator")]
+    fn suggested_aggregator(
+        &self,
+        request_id: u64,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<AccountId>>;
+
+    /// Submit a signed proof extrinsic to the transaction pool, returning its hash.
+    ///
+    /// Only `submit_proof`, `submit_cosigned_proof` and `submit_single_proof`
+    /// extrinsics are accepted.
+    #[method(name = "reml_submitProof")]
+    async fn submit_proof(&self, extrinsic: Bytes) -> RpcResult<BlockHash>;
+}
+
+/// Implementation of [`RemlApiServer`].
+pub struct Reml<C, P, B> {
+    client: Arc<C>,
+    pool: Arc<P>,
+    _marker: PhantomData<B>,
+}
+
+impl<C, P, B> Reml<C, P, B> {
+    /// Create a new `Reml` RPC handler.
+    pub fn new(client: Arc<C>, pool: Arc<P>) -> Self {
+        Self { client, pool, _marker: Default::default() }
+    }
+}
+
+#[jsonrpsee::core::async_trait]
+impl<C, P, Block, AccountId, BlockNumber, CoSignature>
+    RemlApiServer<<Block as BlockT>::Hash, AccountId, BlockNumber, CoSignature>
+    for Reml<C, P, Block>
+where
+    Block: BlockT,
+    AccountId: Codec + Send + Sync + Serialize + for<'de> Deserialize<'de> + 'static,
+    BlockNumber: Codec + Send + Sync + Serialize + for<'de> Deserialize<'de> + 'static,
+    CoSignature: Codec + Send + Sync + Serialize + for<'de> Deserialize<'de> + 'static,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: RemlVerifierApi<Block, AccountId, BlockNumber, CoSignature>
+        + RemlStatusApi<Block, AccountId, BlockNumber>,
+    P: TransactionPool<Block = Block, Hash = <Block as BlockT>::Hash> + 'static,
+{
+    fn list_batches(
+        &self,
+        from_id: Option<u64>,
+        limit: Option<u32>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<BatchPage<AccountId, BlockNumber>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let limit = limit.unwrap_or(MAX_PAGE_SIZE).min(MAX_PAGE_SIZE);
+
+        let batches = self.client.runtime_api().list_batches(at, from_id, limit).map_err(|e| {
+            ErrorObject::owned(RUNTIME_ERROR, "Unable to list batches", Some(e.to_string()))
+        })?;
+
+        let next_id = (batches.len() as u32 == limit)
+            .then(|| batches.last().map(|(id, _)| *id))
+            .flatten();
+
+        Ok(BatchPage {
+            batches: batches
+                .into_iter()
+                .map(|(batch_id, info)| BatchEntry::new(batch_id, info))
+                .collect(),
+            next_id,
+        })
+    }
+    fn batch_by_root(
+        &self,
+        root: H256,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<BatchEntry<AccountId, BlockNumber>>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let batch = self.client.runtime_api().batch_by_root(at, root.0).map_err(|e| {
+            ErrorObject::owned(RUNTIME_ERROR, "Unable to look up batch", Some(e.to_string()))
+        })?;
+
+        Ok(batch.map(|(batch_id, info)| BatchEntry::new(batch_id, info)))
+    }
+    fn dry_run_submit_proof(
+        &self,
+        aggregator: AccountId,
+        submission: Bytes,
+        co_signatures: Option<Vec<(AccountId, CoSignature)>>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<ProofDryRun> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let submission = ProofSubmission::decode(&mut &submission[..]).map_err(|e| {
+            ErrorObject::owned(INVALID_SUBMISSION, "Invalid proof submission", Some(e.to_string()))
+        })?;
+
+        let result = self
+            .client
+            .runtime_api()
+            .dry_run_submit_proof(at, aggregator, submission, co_signatures.unwrap_or_default())
+            .map_err(|e| {
+                ErrorObject::owned(RUNTIME_ERROR, "Unable to dry-run proof", Some(e.to_string()))
+            })?;
+
+        Ok(match result {
+            Ok(()) => ProofDryRun { success: true, error: None, reject_reason: None },
+            Err(e) => ProofDryRun {
+                success: false,
+                error: Some(String::from_utf8_lossy(&e.name).into_owned()),
+                reject_reason: e.reason.map(|reason| format!("{reason:?}")),
+            },
+        })
+    }
+    fn decode_bundle(
+        &self,
+        bundle: Bytes,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<DecodedBundle<AccountId, BlockNumber>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let Bundle { format, call, submission, co_signatures } = Bundle::decode(&bundle)
+            .ok_or_else(|| {
+                ErrorObject::owned(
+                    INVALID_BUNDLE,
+                    "Not a proof extrinsic, call or submission",
+                    None::<()>,
+                )
+            })?;
+
+        let public_values = &submission.public_values;
+        let requests_root = public_values.requests_root;
+        let linked_batch =
+            self.client.runtime_api().batch_by_root(at, requests_root).map_err(|e| {
+                ErrorObject::owned(RUNTIME_ERROR, "Unable to look up batch", Some(e.to_string()))
+            })?;
+
+        Ok(DecodedBundle {
+            format: format.into(),
+            call: call.map(Into::into),
+            batch_id: submission.batch_id,
+            vkey_hash: H256(submission.vkey_hash),
+            program_id: submission.program_id,
+            anchor_block: submission.anchor_block,
+            anchor_hash: H256(submission.anchor_hash),
+            proof_size: submission.proof.len() as u32,
+            proof_kind: if submission.proof.len() == GROTH16_PROOF_SIZE {
+                "groth16"
+            } else {
+                "stark"
+            }
+            .into(),
+            co_signatures,
+            binding_hash: H256(public_values.binding_hash()),
+            proof_commitment: H256(RemlVerifier::compute_proof_commitment(&submission)),
+            requests_root_valid: RemlVerifier::compute_merkle_root(
+                &public_values.verified_request_ids,
+            ) == requests_root
+                && RemlVerifier::compute_blake2_root(&public_values.verified_request_ids)
+                    == public_values.requests_root_blake2,
+            linked_batch: linked_batch.map(|(batch_id, info)| BatchEntry::new(batch_id, info)),
+            public_values: submission.public_values.into(),
+        })
+    }
+    fn get_batch(
+        &self,
+        batch_id: u64,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<BatchEntry<AccountId, BlockNumber>>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let batch = self.client.runtime_api().get_batch_info(at, batch_id).map_err(|e| {
+            ErrorObject::owned(RUNTIME_ERROR, "Unable to look up batch", Some(e.to_string()))
+        })?;
+
+        Ok(batch.map(|info| BatchEntry::new(batch_id, info)))
+    }
+    fn is_verified(&self, request_id: u64, at: Option<<Block as BlockT>::Hash>) -> RpcResult<bool> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        self.client.runtime_api().is_request_verified(at, request_id).map_err(|e| {
+            ErrorObject::owned(RUNTIME_ERROR, "Unable to query request", Some(e.to_string()))
+        })
+    }
+    fn pending_aggregators(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<PendingAggregator<AccountId>>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let pending = self.client.runtime_api().pending_aggregators(at).map_err(|e| {
+            ErrorObject::owned(RUNTIME_ERROR, "Unable to list claims", Some(e.to_string()))
+        })?;
+
+        Ok(pending
+            .into_iter()
+            .map(|(aggregator, batch_id)| PendingAggregator { aggregator, batch_id })
+            .collect())
+    }
+    fn suggested_aggregator(
+        &self,
+        request_id: u64,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<AccountId>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        self.client.runtime_api().suggested_aggregator(at, request_id).map_err(|e| {
+            ErrorObject::owned(RUNTIME_ERROR, "Unable to route request", Some(e.to_string()))
+        })
+    }
+    async fn submit_proof(&self, extrinsic: Bytes) -> RpcResult<<Block as BlockT>::Hash> {
+        let not_a_proof =
+            || ErrorObject::owned(INVALID_BUNDLE, "Not a proof extrinsic", None::<()>);
+        let is_proof = UncheckedExtrinsic::decode_all(&mut &extrinsic[..])
+            .ok()
+            .and_then(|xt| Bundle::from_call(xt.function, "extrinsic"))
+            .is_some();
+        if !is_proof {
+            return Err(not_a_proof());
+        }
+        let xt = DecodeAll::decode_all(&mut &extrinsic[..]).map_err(|_| not_a_proof())?;
+
+        let best = self.client.info().best_hash;
+        self.pool.submit_one(best, TransactionSource::External, xt).await.map_err(|e| {
+            ErrorObject::owned(POOL_ERROR, "Proof extrinsic rejected", Some(e.to_string()))
+        })
+    }
+}
+
+type RemlVerifier = pallet_reml_verifier::Pallet<Runtime>;
+
+/// Proof submission found in a `reml_decodeBundle` input
+struct Bundle {
+    format: &'static str,
+    call: Option<&'static str>,
+    submission: ProofSubmission,
+    co_signatures: u32,
+}
+
+impl Bundle {
+    /// Try `bytes` as an extrinsic, a call, then the two submission types
+    ///
+    /// Single-request submissions get the public values the runtime this node
+    /// was built with would rebuild for them.
+    fn decode(bytes: &[u8]) -> Option<Self> {
+        if let Ok(extrinsic) = UncheckedExtrinsic::decode_all(&mut &bytes[..]) {
+            return Self::from_call(extrinsic.function, "extrinsic");
+        }
+        if let Ok(call) = RuntimeCall::decode_all(&mut &bytes[..]) {
+            return Self::from_call(call, "call");
+        }
+        if let Ok(submission) = ProofSubmission::decode_all(&mut &bytes[..]) {
+            return Some(Self { format: "submission", call: None, submission, co_signatures: 0 });
+        }
+        let single = SingleProofSubmission::decode_all(&mut &bytes[..]).ok()?;
+        Some(Self {
+            format: "singleSubmission",
+            call: None,
+            submission: RemlVerifier::single_proof_submission(single),
+            co_signatures: 0,
+        })
+    }
+
+    fn from_call(call: RuntimeCall, format: &'static str) -> Option<Self> {
+        use pallet_reml_verifier::Call;
+
+        let RuntimeCall::RemlVerifier(call) = call else {
+            return None;
+        };
+        let (name, submission, co_signatures) = match call {
+            Call::submit_proof { submission } => ("submit_proof", submission, 0),
+            Call::submit_cosigned_proof { submission, co_signatures } => {
+                ("submit_cosigned_proof", submission, co_signatures.len() as u32)
+            },
+            Call::submit_single_proof { submission } => {
+                ("submit_single_proof", RemlVerifier::single_proof_submission(submission), 0)
+            },
+            _ => return None,
+        };
+        Some(Self { format, call: Some(name), submission, co_signatures })
+    }
+}
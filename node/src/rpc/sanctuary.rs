@@ -0,0 +1,236 @@
+//! `sanctuary_*` RPC namespace.
+//!
+//! Node-side helpers for integrators working across the Substrate and EVM
+//! sides of the chain, and a single health endpoint for operators.
+
+use std::sync::Arc;
+
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::{error::ErrorObjectOwned, ErrorObject},
+};
+use sc_transaction_pool_api::TransactionPool;
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{crypto::Ss58Codec, H160, U256};
+use sp_runtime::traits::{Block as BlockT, UniqueSaturatedInto};
+use tesserax_runtime::{
+    account_views::{AccountViews, AccountViewsApi},
+    chain_health::ChainHealthApi,
+    AccountId, Balance, BlockNumber, Nonce,
+};
+
+/// Invalid address parameter
+const INVALID_ADDRESS: i32 = 1001;
+/// Runtime API call failed
+const RUNTIME_ERROR: i32 = 1002;
+
+/// Both views of an account as returned by `sanctuary_accountViews`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountViewsResponse {
+    /// SS58 address of the Substrate account
+    pub ss58: String,
+    /// Substrate account (hex)
+    pub account_id: String,
+    /// EVM address (first 20 bytes of `account_id`)
+    pub h160: H160,
+    /// SS58 address of the account backing `h160` on the EVM side
+    pub evm_account_ss58: String,
+    /// Whether `account_id` and `h160` refer to the same balance
+    pub round_trips: bool,
+    /// Whether `account_id` is a quantum vault
+    pub is_vault: bool,
+    /// Whether the EVM-side account is a quantum vault
+    pub evm_account_is_vault: bool,
+    /// Substrate nonce of `account_id`
+    pub substrate_nonce: Nonce,
+    /// EVM nonce of `h160`
+    pub evm_nonce: U256,
+}
+
+impl From<AccountViews> for AccountViewsResponse {
+    fn from(views: AccountViews) -> Self {
+        Self {
+            ss58: views.account_id.to_ss58check(),
+            account_id: format!("0x{}", hex::encode(AsRef::<[u8]>::as_ref(&views.account_id))),
+            h160: views.h160,
+            evm_account_ss58: views.evm_account_id.to_ss58check(),
+            round_trips: views.account_id == views.evm_account_id,
+            is_vault: views.is_vault,
+            evm_account_is_vault: views.evm_account_is_vault,
+            substrate_nonce: views.substrate_nonce,
+            evm_nonce: views.evm_nonce,
+        }
+    }
+}
+
+/// Most recent emission mint.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LastReward {
+    /// Block the reward was minted in
+    pub block: BlockNumber,
+    /// Minted amount in planck
+    pub amount: Balance,
+}
+
+/// Most recently verified Re-ML batch.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LastBatch {
+    /// Batch ID
+    pub batch_id: u64,
+    /// SS58 address of the submitting aggregator
+    pub aggregator: String,
+    /// Block the batch was verified in
+    pub verified_at: BlockNumber,
+    /// Signatures verified in the batch
+    pub signature_count: u32,
+}
+
+/// Chain health as returned by `sanctuary_health`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HealthResponse {
+    /// Best block number
+    pub best_block: u64,
+    /// Finalized block number
+    pub finalized_block: u64,
+    /// Blocks between best and finalized
+    pub finalized_lag: u64,
+    /// Transactions ready for inclusion
+    pub pool_ready: usize,
+    /// Transactions waiting on an earlier nonce
+    pub pool_future: usize,
+    /// Most recent emission mint, `None` before the first one
+    pub last_reward: Option<LastReward>,
+    /// Most recently verified Re-ML batch, `None` before the first one
+    pub last_batch: Option<LastBatch>,
+    /// Active Re-ML aggregators
+    pub active_aggregators: u32,
+    /// Re-ML proofs verified since genesis
+    pub total_proofs_verified: u64,
+    /// Whether Re-ML proof submission is paused
+    pub verifier_paused: bool,
+    /// Number of quantum vaults
+    pub vault_count: u32,
+    /// Whether vault operations are paused
+    pub vaults_paused: bool,
+}
+
+/// Sanctuary RPC methods.
+#[rpc(client, server)]
+pub trait SanctuaryApi<BlockHash> {
+    /// Substrate and EVM views of an account.
+    ///
+    /// `address` may be an SS58 address, a 32-byte hex AccountId or a
+    /// 20-byte hex H160.
+    #[method(name = "sanctuary_accountViews")]
+    fn account_views(&self, address: String, at: Option<BlockHash>)
+        -> RpcResult<AccountViewsResponse>;
+
+    /// Finality, transaction pool, emission, Re-ML and vault health at the best block.
+    #[method(name = "sanctuary_health")]
+    fn health(&self) -> RpcResult<HealthResponse>;
+}
+
+/// Parsed `address` parameter
+enum Address {
+    Substrate(AccountId),
+    Evm(H160),
+}
+
+fn parse_address(address: &str) -> Result<Address, ErrorObjectOwned> {
+    let invalid = |msg: &str| ErrorObject::owned(INVALID_ADDRESS, msg.to_string(), Some(address));
+
+    if let Some(hex_str) = address.strip_prefix("0x") {
+        let bytes = hex::decode(hex_str).map_err(|_| invalid("Invalid hex address"))?;
+        return match bytes.len() {
+            20 => Ok(Address::Evm(H160::from_slice(&bytes))),
+            32 => {
+                let mut raw = [0u8; 32];
+                raw.copy_from_slice(&bytes);
+                Ok(Address::Substrate(AccountId::from(raw)))
+            },
+            _ => Err(invalid("Hex address must be 20 (H160) or 32 (AccountId) bytes")),
+        };
+    }
+
+    AccountId::from_ss58check(address)
+        .map(Address::Substrate)
+        .map_err(|_| invalid("Invalid SS58 address"))
+}
+
+/// Implementation of [`SanctuaryApiServer`].
+pub struct Sanctuary<C, P, B> {
+    client: Arc<C>,
+    pool: Arc<P>,
+    _marker: std::marker::PhantomData<B>,
+}
+
+impl<C, P, B> Sanctuary<C, P, B> {
+    /// Create a new `Sanctuary` RPC handler.
+    pub fn new(client: Arc<C>, pool: Arc<P>) -> Self {
+        Self { client, pool, _marker: Default::default() }
+    }
+}
+
+impl<C, P, Block> SanctuaryApiServer<<Block as BlockT>::Hash> for Sanctuary<C, P, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: AccountViewsApi<Block> + ChainHealthApi<Block>,
+    P: TransactionPool + 'static,
+{
+    fn account_views(
+        &self,
+        address: String,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<AccountViewsResponse> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let api = self.client.runtime_api();
+
+        let views = match parse_address(&address)? {
+            Address::Substrate(account_id) => api.account_views(at, account_id),
+            Address::Evm(h160) => api.account_views_by_h160(at, h160),
+        }
+        .map_err(|e| {
+            ErrorObject::owned(RUNTIME_ERROR, "Unable to query account views", Some(e.to_string()))
+        })?;
+
+        Ok(views.into())
+    }
+    fn health(&self) -> RpcResult<HealthResponse> {
+        let info = self.client.info();
+        let chain = self.client.runtime_api().chain_health(info.best_hash).map_err(|e| {
+            ErrorObject::owned(RUNTIME_ERROR, "Unable to query chain health", Some(e.to_string()))
+        })?;
+        let pool = self.pool.status();
+
+        let best_block: u64 = info.best_number.unique_saturated_into();
+        let finalized_block: u64 = info.finalized_number.unique_saturated_into();
+
+        Ok(HealthResponse {
+            best_block,
+            finalized_block,
+            finalized_lag: best_block.saturating_sub(finalized_block),
+            pool_ready: pool.ready,
+            pool_future: pool.future,
+            last_reward: chain.last_reward.map(|(block, amount)| LastReward { block, amount }),
+            last_batch: chain.last_batch.map(|(batch_id, info)| LastBatch {
+                batch_id,
+                aggregator: info.aggregator.to_ss58check(),
+                verified_at: info.verified_at,
+                signature_count: info.signature_count,
+            }),
+            active_aggregators: chain.active_aggregators,
+            total_proofs_verified: chain.total_proofs_verified,
+            verifier_paused: chain.verifier_paused,
+            vault_count: chain.vault_count,
+            vaults_paused: chain.vaults_paused,
+        })
+    }
+}
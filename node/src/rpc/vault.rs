@@ -0,0 +1,466 @@
+//! `vault_*` RPC namespace.
+//!
+//! Server-side pagination over Quantum Vault storage for explorers and
+//! indexers, so they don't need `state_getPairs` over 1312-byte key values,
+//! plus the total value locked in vaults and a dry run of `vault_transfer`
+//! so wallets can catch a bad signature before paying the premium, the
+//! export of a vault's configuration as a descriptor, fee quotes that
+//! include the vault premium and creation fee, and a vault's nonce, signing
+//! mode and policy for wallets building signing payloads, or the exact
+//! transfer payload to sign.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::{Codec, Encode};
+use jsonrpsee::{core::RpcResult, proc_macros::rpc, types::ErrorObject};
+use pallet_quantum_vault::{
+    runtime_api::QuantumVaultApi, MessageMode, PremiumMode, SignatureScheme, MAX_PAGE_SIZE,
+    MAX_VALUE_LOCKED_PAGE_SIZE,
+};
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_runtime::traits::{Block as BlockT, Saturating, Zero};
+
+/// Runtime API call failed
+const RUNTIME_ERROR: i32 = 2001;
+
+/// Call does not decode as a runtime call
+const INVALID_CALL: i32 = 2002;
+
+/// A vault entry.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VaultEntry<AccountId> {
+    /// Vault account
+    pub account: AccountId,
+    /// Dilithium2 public key (hex)
+    pub public_key: String,
+    /// Current vault nonce
+    pub nonce: u64,
+}
+
+/// A page of vaults.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VaultPage<AccountId> {
+    /// Vaults in this page
+    pub vaults: Vec<VaultEntry<AccountId>>,
+    /// `start_key` for the next page, `None` once exhausted
+    pub next_key: Option<AccountId>,
+}
+
+/// Balance held in quantum vaults.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValueLocked<Balance> {
+    /// Total (free + reserved) balance of all vaults
+    pub total_value_locked: Balance,
+    /// Number of vaults
+    pub vault_count: u32,
+}
+
+/// Outcome of a simulated vault transfer.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferDryRun<Balance> {
+    /// Whether the transfer would succeed
+    pub success: bool,
+    /// Pallet error it would fail with, e.g. `SignatureVerificationFailed`
+    pub error: Option<String>,
+    /// Transaction fee, charged whether or not the transfer succeeds
+    pub inclusion_fee: Balance,
+    /// Vault premium, only charged on success
+    pub premium_fee: Option<Balance>,
+    /// `inclusion_fee + premium_fee`, on success
+    pub total_fee: Option<Balance>,
+}
+
+/// Inclusion fee breakdown, as `payment_queryCallFeeDetails` reports it.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InclusionFee<Balance> {
+    /// Minimum fee of any extrinsic
+    pub base_fee: Balance,
+    /// Fee for the encoded length
+    pub len_fee: Balance,
+    /// Fee for the weight, after the fee multiplier
+    pub adjusted_weight_fee: Balance,
+}
+
+/// Everything a call would cost its caller.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VaultFeeDetails<Balance> {
+    /// Transaction fee, `None` if the call pays none
+    pub inclusion_fee: Option<InclusionFee<Balance>>,
+    /// Transfer premium charged to the paying vault
+    pub premium_fee: Balance,
+    /// Vault creation fee
+    pub creation_fee: Balance,
+    /// Sum of the above
+    pub total_fee: Balance,
+}
+
+/// A vault's configuration.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VaultDescriptor {
+    /// Descriptor format version
+    pub version: u8,
+    /// Signature scheme, e.g. `dilithium2`
+    pub scheme: String,
+    /// Public key (hex)
+    pub public_key: String,
+    /// Premium mode, `flat` or `percentage`
+    pub premium_mode: String,
+    /// SCALE-encoded descriptor (hex), the `create_vault_from_descriptor` argument
+    pub encoded: String,
+}
+
+/// A vault's spending policy.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VaultPolicy<AccountId, Balance> {
+    /// Most the vault may send per spending window, `None` for no limit
+    pub daily_limit: Option<Balance>,
+    /// Only accounts the vault may send to, `None` for any account
+    pub whitelist: Option<Vec<AccountId>>,
+}
+
+/// A vault's state, as wallets need it to sign for the vault.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VaultInfo<AccountId, Balance, BlockNumber> {
+    /// `blake2_256` of the public key (hex)
+    pub public_key_hash: String,
+    /// Signature scheme, e.g. `dilithium2`
+    pub scheme: String,
+    /// Lowest unused vault nonce, the one to sign next
+    pub nonce: u64,
+    /// Block the vault's key became valid
+    pub created_at: BlockNumber,
+    /// Premium mode, `flat` or `percentage`
+    pub premium_mode: String,
+    /// Message mode, `raw`, `pure`, `preHashSha256` or `preHashSha3_256`
+    pub message_mode: String,
+    /// Signing context string (hex), empty for `raw`
+    pub context: String,
+    /// Spending policy, `None` if unrestricted
+    pub policy: Option<VaultPolicy<AccountId, Balance>>,
+}
+
+/// Quantum Vault RPC methods.
+#[rpc(client, server)]
+pub trait VaultApi<BlockHash, AccountId, Balance, BlockNumber> {
+    /// List vaults after `start_key` (exclusive), at most `limit` (capped at 100).
+    #[method(name = "vault_listVaults")]
+    fn list_vaults(
+        &self,
+        start_key: Option<AccountId>,
+        limit: Option<u32>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<VaultPage<AccountId>>;
+
+    /// Total balance held in quantum vaults, summed over every vault.
+    #[method(name = "vault_totalValueLocked")]
+    fn total_value_locked(&self, at: Option<BlockHash>) -> RpcResult<ValueLocked<Balance>>;
+
+    /// Run `vault_transfer` from `from` against state at `at` without submitting it.
+    #[method(name = "vault_dryRunTransfer")]
+    fn dry_run_transfer(
+        &self,
+        from: AccountId,
+        signature: Bytes,
+        to: AccountId,
+        amount: Balance,
+        request_id: Option<u64>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<TransferDryRun<Balance>>;
+
+    /// Configuration of vault `account`, `None` if it is not a vault.
+    #[method(name = "vault_exportDescriptor")]
+    fn export_descriptor(
+        &self,
+        account: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<VaultDescriptor>>;
+
+    /// Fees `who` would pay for the SCALE-encoded `call`, vault premium and
+    /// creation fee included.
+    #[method(name = "vault_queryCallFeeDetails")]
+    fn query_call_fee_details(
+        &self,
+        who: AccountId,
+        call: Bytes,
+        at: Option<BlockHash>,
+    ) -> RpcResult<VaultFeeDetails<Balance>>;
+
+    /// Key hash, nonce, creation block, signing mode and policy of vault
+    /// `account`, `None` if it is not a vault.
+    #[method(name = "vault_getInfo")]
+    fn get_info(
+        &self,
+        account: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<VaultInfo<AccountId, Balance, BlockNumber>>>;
+
+    /// Payload vault `from` signs for a transfer of `amount` to `to` at its
+    /// current nonce, `None` if `from` is not a vault.
+    #[method(name = "vault_transferPayload")]
+    fn transfer_payload(
+        &self,
+        from: AccountId,
+        to: AccountId,
+        amount: Balance,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<Bytes>>;
+}
+
+/// Implementation of [`VaultApiServer`].
+pub struct Vault<C, B> {
+    client: Arc<C>,
+    _marker: PhantomData<B>,
+}
+
+impl<C, B> Vault<C, B> {
+    /// Create a new `Vault` RPC handler.
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client, _marker: Default::default() }
+    }
+}
+
+impl<C, Block, AccountId, Balance, BlockNumber>
+    VaultApiServer<<Block as BlockT>::Hash, AccountId, Balance, BlockNumber> for Vault<C, Block>
+where
+    Block: BlockT,
+    AccountId: Codec + Clone + Send + Sync + Serialize + for<'de> Deserialize<'de> + 'static,
+    Balance: Codec
+        + Copy
+        + Saturating
+        + Zero
+        + Send
+        + Sync
+        + Serialize
+        + for<'de> Deserialize<'de>
+        + 'static,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    BlockNumber: Codec + Send + Sync + Serialize + for<'de> Deserialize<'de> + 'static,
+    C::Api: QuantumVaultApi<Block, AccountId, Balance, BlockNumber>,
+{
+    fn list_vaults(
+        &self,
+        start_key: Option<AccountId>,
+        limit: Option<u32>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<VaultPage<AccountId>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let limit = limit.unwrap_or(MAX_PAGE_SIZE).min(MAX_PAGE_SIZE);
+
+        let vaults = self.client.runtime_api().list_vaults(at, start_key, limit).map_err(|e| {
+            ErrorObject::owned(RUNTIME_ERROR, "Unable to list vaults", Some(e.to_string()))
+        })?;
+
+        let next_key = (vaults.len() as u32 == limit)
+            .then(|| vaults.last().map(|v| v.account.clone()))
+            .flatten();
+
+        Ok(VaultPage {
+            vaults: vaults
+                .into_iter()
+                .map(|v| VaultEntry {
+                    account: v.account,
+                    public_key: format!("0x{}", hex::encode(&v.public_key)),
+                    nonce: v.nonce,
+                })
+                .collect(),
+            next_key,
+        })
+    }
+    fn total_value_locked(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<ValueLocked<Balance>> {
+        // Pin every page to the same block so the sum is consistent
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let api = self.client.runtime_api();
+
+        let mut total = ValueLocked { total_value_locked: Balance::zero(), vault_count: 0 };
+        let mut start_key = None;
+        loop {
+            let page =
+                api.value_locked(at, start_key, MAX_VALUE_LOCKED_PAGE_SIZE).map_err(|e| {
+                    ErrorObject::owned(
+                        RUNTIME_ERROR,
+                        "Unable to sum vault balances",
+                        Some(e.to_string()),
+                    )
+                })?;
+
+            total.total_value_locked = total.total_value_locked.saturating_add(page.value_locked);
+            total.vault_count = total.vault_count.saturating_add(page.vaults);
+
+            if page.vaults < MAX_VALUE_LOCKED_PAGE_SIZE {
+                return Ok(total);
+            }
+            start_key = page.last_key;
+        }
+    }
+    fn dry_run_transfer(
+        &self,
+        from: AccountId,
+        signature: Bytes,
+        to: AccountId,
+        amount: Balance,
+        request_id: Option<u64>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<TransferDryRun<Balance>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let dry_run = self
+            .client
+            .runtime_api()
+            .dry_run_transfer(at, from, signature.to_vec(), to, amount, request_id)
+            .map_err(|e| {
+                ErrorObject::owned(RUNTIME_ERROR, "Unable to dry-run transfer", Some(e.to_string()))
+            })?;
+
+        let inclusion_fee = dry_run.inclusion_fee;
+        Ok(match dry_run.outcome {
+            Ok(premium) => TransferDryRun {
+                success: true,
+                error: None,
+                inclusion_fee,
+                premium_fee: Some(premium),
+                total_fee: Some(inclusion_fee.saturating_add(premium)),
+            },
+            Err(e) => TransferDryRun {
+                success: false,
+                error: Some(String::from_utf8_lossy(&e.name).into_owned()),
+                inclusion_fee,
+                premium_fee: None,
+                total_fee: None,
+            },
+        })
+    }
+    fn export_descriptor(
+        &self,
+        account: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<VaultDescriptor>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let descriptor = self.client.runtime_api().vault_descriptor(at, account).map_err(|e| {
+            ErrorObject::owned(RUNTIME_ERROR, "Unable to export descriptor", Some(e.to_string()))
+        })?;
+
+        Ok(descriptor.map(|d| VaultDescriptor {
+            version: d.version,
+            scheme: scheme_name(d.scheme),
+            public_key: format!("0x{}", hex::encode(&d.public_key)),
+            premium_mode: premium_mode_name(d.premium_mode),
+            encoded: format!("0x{}", hex::encode(d.encode())),
+        }))
+    }
+    fn query_call_fee_details(
+        &self,
+        who: AccountId,
+        call: Bytes,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<VaultFeeDetails<Balance>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let quote = self
+            .client
+            .runtime_api()
+            .query_call_fees(at, who, call.to_vec())
+            .map_err(|e| {
+                ErrorObject::owned(RUNTIME_ERROR, "Unable to query call fees", Some(e.to_string()))
+            })?
+            .ok_or_else(|| ErrorObject::owned(INVALID_CALL, "Unable to decode call", None::<()>))?;
+
+        let inclusion_fee =
+            quote.base_fee.saturating_add(quote.len_fee).saturating_add(quote.adjusted_weight_fee);
+        Ok(VaultFeeDetails {
+            inclusion_fee: (!inclusion_fee.is_zero()).then_some(InclusionFee {
+                base_fee: quote.base_fee,
+                len_fee: quote.len_fee,
+                adjusted_weight_fee: quote.adjusted_weight_fee,
+            }),
+            premium_fee: quote.premium_fee,
+            creation_fee: quote.creation_fee,
+            total_fee: inclusion_fee
+                .saturating_add(quote.premium_fee)
+                .saturating_add(quote.creation_fee),
+        })
+    }
+    fn get_info(
+        &self,
+        account: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<VaultInfo<AccountId, Balance, BlockNumber>>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let info = self.client.runtime_api().vault_info(at, account).map_err(|e| {
+            ErrorObject::owned(RUNTIME_ERROR, "Unable to query vault", Some(e.to_string()))
+        })?;
+
+        Ok(info.map(|info| VaultInfo {
+            public_key_hash: format!("0x{}", hex::encode(info.public_key_hash)),
+            scheme: scheme_name(info.scheme),
+            nonce: info.nonce,
+            created_at: info.created_at,
+            premium_mode: premium_mode_name(info.premium_mode),
+            message_mode: match info.message_mode {
+                MessageMode::Raw => "raw".into(),
+                MessageMode::Pure => "pure".into(),
+                MessageMode::PreHashSha256 => "preHashSha256".into(),
+                MessageMode::PreHashSha3_256 => "preHashSha3_256".into(),
+            },
+            context: format!("0x{}", hex::encode(&info.context)),
+            policy: info.policy.map(|policy| VaultPolicy {
+                daily_limit: policy.daily_limit,
+                whitelist: policy.whitelist.map(|whitelist| whitelist.into_inner()),
+            }),
+        }))
+    }
+    fn transfer_payload(
+        &self,
+        from: AccountId,
+        to: AccountId,
+        amount: Balance,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<Bytes>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let payload =
+            self.client.runtime_api().vault_transfer_payload(at, from, to, amount).map_err(
+                |e| {
+                    ErrorObject::owned(
+                        RUNTIME_ERROR,
+                        "Unable to build transfer payload",
+                        Some(e.to_string()),
+                    )
+                },
+            )?;
+
+        Ok(payload.map(Into::into))
+    }
+}
+
+fn scheme_name(scheme: SignatureScheme) -> String {
+    match scheme {
+        SignatureScheme::Dilithium2 => "dilithium2".into(),
+        SignatureScheme::Dilithium3 => "dilithium3".into(),
+        SignatureScheme::Dilithium5 => "dilithium5".into(),
+        SignatureScheme::SphincsSha2_128s => "sphincsSha2_128s".into(),
+    }
+}
+
+fn premium_mode_name(mode: PremiumMode) -> String {
+    match mode {
+        PremiumMode::Flat => "flat".into(),
+        PremiumMode::Percentage => "percentage".into(),
+    }
+}
@@ -22,6 +22,9 @@ use fc_storage::StorageOverrideHandler;
 pub type HostFunctions = (
     sp_io::SubstrateHostFunctions,
     cumulus_primitives_proof_size_hostfunction::storage_proof_size::HostFunctions,
+    // Dilithium and SPHINCS+ verification for Quantum Vault signatures
+    tesserax_dilithium_io::dilithium::HostFunctions,
+    tesserax_sphincs_io::sphincs::HostFunctions,
 );
 
 pub(crate) type FullClient =
@@ -158,7 +161,9 @@ pub fn new_full<
     N: sc_network::NetworkBackend<Block, <Block as sp_runtime::traits::Block>::Hash>,
 >(
     config: Configuration,
+    eth_config: crate::eth::EthConfiguration,
 ) -> Result<TaskManager, ServiceError> {
+    let eth_limits = eth_config.limits();
     let sc_service::PartialComponents {
         client,
         backend,
@@ -243,6 +248,9 @@ pub fn new_full<
     let name = config.network.node_name.clone();
     let enable_grandpa = !config.disable_grandpa;
     let prometheus_registry = config.prometheus_registry().cloned();
+    if let Some(registry) = prometheus_registry.as_ref() {
+        crate::span_metrics::register_metrics(registry)?;
+    }
     let is_authority = config.role.is_authority();
 
     // Frontier: Create storage override
@@ -251,7 +259,7 @@ pub fn new_full<
     // Frontier: Create filter pool and fee history cache
     let filter_pool: Option<FilterPool> = Some(Arc::new(std::sync::Mutex::new(BTreeMap::new())));
     let fee_history_cache: FeeHistoryCache = Arc::new(std::sync::Mutex::new(BTreeMap::new()));
-    let fee_history_cache_limit: FeeHistoryCacheLimit = 2048;
+    let fee_history_cache_limit: FeeHistoryCacheLimit = eth_limits.fee_history_limit;
 
     // Frontier: Create pubsub notification sinks
     let pubsub_notification_sinks: fc_mapping_sync::EthereumBlockNotificationSinks<
@@ -263,8 +271,8 @@ pub fn new_full<
     let block_data_cache = Arc::new(fc_rpc::EthBlockDataCacheTask::new(
         task_manager.spawn_handle(),
         storage_override.clone(),
-        50,
-        50,
+        eth_limits.block_cache,
+        eth_limits.statuses_cache,
         prometheus_registry.clone(),
     ));
 
@@ -302,7 +310,7 @@ pub fn new_full<
                 storage_override: storage_override.clone(),
                 block_data_cache: block_data_cache.clone(),
                 filter_pool: filter_pool.clone(),
-                max_past_logs: 10000,
+                max_past_logs: eth_limits.max_past_logs,
                 fee_history_cache: fee_history_cache.clone(),
                 fee_history_cache_limit,
                 execute_gas_limit_multiplier: 10,
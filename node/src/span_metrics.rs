@@ -0,0 +1,72 @@
+//! Span timings for the custom pallets
+//!
+//! The vault and Re-ML pallets open `tracing` spans (targets
+//! `sanctuary::vault` and `sanctuary::reml`) around signature verification,
+//! Merkle root computation and proof checks. This module enables those
+//! targets on the node's profiling layer and records every closed span in the
+//! `sanctuary_span_duration_seconds` Prometheus histogram, labelled by target
+//! and span name.
+//!
+//! Spans opened inside the Wasm runtime only reach the node when the runtime
+//! is built with the `with-tracing` feature.
+
+use sc_tracing::{SpanDatum, TraceEvent, TraceHandler};
+use std::sync::OnceLock;
+use substrate_prometheus_endpoint::{
+    register, HistogramOpts, HistogramVec, PrometheusError, Registry,
+};
+
+/// Prefix shared by the custom pallets' tracing targets
+const TARGET_PREFIX: &str = "sanctuary::";
+
+/// Profiling targets enabled on every node
+pub const DEFAULT_TARGETS: &str = "sanctuary::vault=debug,sanctuary::reml=debug";
+
+static SPAN_DURATIONS: OnceLock<HistogramVec> = OnceLock::new();
+
+/// `--tracing-targets` value with the custom pallets' targets added
+///
+/// User-supplied targets come last so they can override the defaults.
+pub fn tracing_targets(user_targets: Option<String>) -> String {
+    match user_targets {
+        Some(targets) => format!("{DEFAULT_TARGETS},{targets}"),
+        None => DEFAULT_TARGETS.into(),
+    }
+}
+
+/// Register the span duration histogram with the node's Prometheus registry
+pub fn register_metrics(registry: &Registry) -> Result<(), PrometheusError> {
+    let histogram = register(
+        HistogramVec::new(
+            HistogramOpts::new(
+                "sanctuary_span_duration_seconds",
+                "Time spent in vault and Re-ML pallet spans",
+            )
+            .buckets(vec![
+                0.000_1, 0.000_5, 0.001, 0.005, 0.01, 0.05, 0.1, 0.5, 1.0,
+            ]),
+            &["target", "name"],
+        )?,
+        registry,
+    )?;
+    let _ = SPAN_DURATIONS.set(histogram);
+    Ok(())
+}
+
+/// Profiling handler feeding `sanctuary::*` spans into Prometheus
+pub struct SpanMetrics;
+
+impl TraceHandler for SpanMetrics {
+    fn handle_span(&self, span: &SpanDatum) {
+        if !span.target.starts_with(TARGET_PREFIX) {
+            return;
+        }
+        if let Some(histogram) = SPAN_DURATIONS.get() {
+            histogram
+                .with_label_values(&[span.target.as_str(), span.name.as_str()])
+                .observe(span.overall_time.as_secs_f64());
+        }
+    }
+
+    fn handle_event(&self, _event: &TraceEvent) {}
+}
@@ -0,0 +1,141 @@
+// This file is part of Tesserax Protocol.
+//
+// Copyright (C) 2025 Minerva & Gemini (The Architect)
+// SPDX-License-Identifier: MIT-0
+
+//! `vault-key` subcommand: derive Quantum Vault ML-DSA keys from a mnemonic.
+//!
+//! Derivation path `m/13817'/account'/index'`:
+//!
+//! ```text
+//! bip39_seed = PBKDF2-HMAC-SHA512(mnemonic, "mnemonic" || passphrase, 2048)
+//! seed       = SHA3-256("TESSERAX_MLDSA44_SEED" || bip39_seed || account_le32 || index_le32)
+//! keypair    = Dilithium2.KeyGen(seed)
+//! ```
+//!
+//! Must stay identical to `reml_lib::derive_mldsa_seed`, so the same phrase
+//! yields the same vault key in `reml-prover keygen --mnemonic`; both pin the
+//! same test vector.
+
+use sha3::{Digest, Sha3_256};
+
+/// Domain separator for ML-DSA key generation seeds
+const MLDSA_SEED_DOMAIN: &[u8] = b"TESSERAX_MLDSA44_SEED";
+
+/// Purpose level of the derivation path (the chain ID)
+const DERIVATION_PURPOSE: u32 = 13817;
+
+/// Derive a Quantum Vault (Dilithium2) public key from a BIP39 mnemonic.
+///
+/// Prints the public key to pass to `QuantumVault::create_vault`. The secret
+/// key is never printed; use `reml-prover keygen --mnemonic` to write an
+/// encrypted key file from the same phrase.
+#[derive(Debug, clap::Parser)]
+pub struct VaultKeyCmd {
+    /// BIP39 mnemonic phrase. Prefer `TESSERAX_MNEMONIC` over passing it inline.
+    #[arg(long, env = "TESSERAX_MNEMONIC", hide_env_values = true)]
+    pub mnemonic: Option<String>,
+
+    /// Generate a new 24-word mnemonic instead of reading one.
+    #[arg(long, conflicts_with = "mnemonic")]
+    pub generate: bool,
+
+    /// Optional BIP39 passphrase.
+    #[arg(long, env = "TESSERAX_MNEMONIC_PASSPHRASE", hide_env_values = true, default_value = "")]
+    pub passphrase: String,
+
+    /// Account level of the derivation path.
+    #[arg(long, default_value = "0")]
+    pub account: u32,
+
+    /// Index level of the derivation path.
+    #[arg(long, default_value = "0")]
+    pub index: u32,
+}
+
+impl VaultKeyCmd {
+    /// Run the command.
+    pub fn run(&self) -> sc_cli::Result<()> {
+        let mnemonic = match (&self.mnemonic, self.generate) {
+            (_, true) => bip39::Mnemonic::generate(24)
+                .map_err(|e| sc_cli::Error::Input(format!("Mnemonic generation failed: {e}")))?,
+            (Some(phrase), false) => parse_mnemonic(phrase)?,
+            (None, false) => {
+                return Err(sc_cli::Error::Input(
+                    "Provide --mnemonic (or TESSERAX_MNEMONIC) or --generate".into(),
+                ))
+            },
+        };
+
+        let keypair = derive_keypair(&mnemonic, &self.passphrase, self.account, self.index);
+
+        if self.generate {
+            println!("Mnemonic:        {mnemonic}");
+        }
+        println!(
+            "Derivation path: m/{}'/{}'/{}'",
+            DERIVATION_PURPOSE, self.account, self.index
+        );
+        println!("Public key:      0x{}", hex::encode(keypair.public.to_bytes()));
+
+        Ok(())
+    }
+}
+
+/// Parse a BIP39 mnemonic phrase.
+pub fn parse_mnemonic(phrase: &str) -> sc_cli::Result<bip39::Mnemonic> {
+    bip39::Mnemonic::parse_normalized(phrase.trim())
+        .map_err(|e| sc_cli::Error::Input(format!("Invalid mnemonic: {e}")))
+}
+
+/// Derive the Dilithium2 keypair at `m/13817'/account'/index'`.
+pub fn derive_keypair(
+    mnemonic: &bip39::Mnemonic,
+    passphrase: &str,
+    account: u32,
+    index: u32,
+) -> crystals_dilithium::dilithium2::Keypair {
+    let seed = derive_mldsa_seed(&mnemonic.to_seed(passphrase), account, index);
+    crystals_dilithium::dilithium2::Keypair::generate(Some(&seed[..]))
+}
+
+/// Derive the 32-byte Dilithium2 key generation seed (see module docs).
+pub fn derive_mldsa_seed(bip39_seed: &[u8; 64], account: u32, index: u32) -> [u8; 32] {
+    let mut hasher = Sha3_256::new();
+    hasher.update(MLDSA_SEED_DOMAIN);
+    hasher.update(bip39_seed);
+    hasher.update(account.to_le_bytes());
+    hasher.update(index.to_le_bytes());
+    hasher.finalize().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Same vector as `reml_lib`'s `test_derive_mldsa_seed_vector`, so the two
+    // derivations can't drift apart
+    #[test]
+    fn derive_mldsa_seed_matches_reml_lib_vector() {
+        let mnemonic = parse_mnemonic(
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon \
+             abandon about",
+        )
+        .unwrap();
+        let bip39_seed = mnemonic.to_seed("");
+        assert_eq!(
+            hex::encode(bip39_seed),
+            "5eb00bbddcf069084889a8ab9155568165f5c453ccb85e70811aaed6f6da5fc1\
+             9a5ac40b389cd370d086206dec8aa6c43daea6690f20ad3d8d48b2d2ce9e38e4"
+        );
+
+        assert_eq!(
+            hex::encode(derive_mldsa_seed(&bip39_seed, 0, 0)),
+            "f7f0be590e995a3a1a8e076f0d6909388370dd022ef349bb4d43f8b9a42c4d13"
+        );
+        assert_eq!(
+            hex::encode(derive_mldsa_seed(&bip39_seed, 0, 1)),
+            "4598f43f9375ba980e4fecb8b36e6cb9a1b72cafeb0466c25474476d1fe1627f"
+        );
+    }
+}
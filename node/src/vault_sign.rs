@@ -0,0 +1,217 @@
+// This file is part of Tesserax Protocol.
+//
+// Copyright (C) 2025 Minerva & Gemini (The Architect)
+// SPDX-License-Identifier: MIT-0
+
+//! `vault-sign` subcommand: sign Quantum Vault payloads offline.
+//!
+//! Derives the vault key like `vault-key` and signs the canonical payload from
+//! `tesserax_vault_payload`, the same bytes the pallet verifies. Prints the
+//! payload and the signature to submit with the extrinsic.
+
+use codec::Encode;
+use sp_runtime::AccountId32;
+use std::str::FromStr;
+
+use crate::vault_key::{derive_keypair, parse_mnemonic};
+
+/// Sign a Quantum Vault payload with a key derived from a BIP39 mnemonic.
+#[derive(Debug, clap::Parser)]
+pub struct VaultSignCmd {
+    /// BIP39 mnemonic phrase. Prefer `TESSERAX_MNEMONIC` over passing it inline.
+    #[arg(long, env = "TESSERAX_MNEMONIC", hide_env_values = true)]
+    pub mnemonic: String,
+
+    /// Optional BIP39 passphrase.
+    #[arg(long, env = "TESSERAX_MNEMONIC_PASSPHRASE", hide_env_values = true, default_value = "")]
+    pub passphrase: String,
+
+    /// Account level of the derivation path.
+    #[arg(long, default_value = "0")]
+    pub account: u32,
+
+    /// Index level of the derivation path.
+    #[arg(long, default_value = "0")]
+    pub index: u32,
+
+    /// Payload to sign.
+    #[command(subcommand)]
+    pub payload: PayloadCmd,
+}
+
+/// Vault payloads. Accounts are SS58 or 0x-prefixed hex.
+#[derive(Debug, clap::Subcommand)]
+pub enum PayloadCmd {
+    /// `vault_transfer` from a vault.
+    Transfer {
+        /// Vault account.
+        #[arg(long, value_parser = parse_account)]
+        from: AccountId32,
+        /// Recipient.
+        #[arg(long, value_parser = parse_account)]
+        to: AccountId32,
+        /// Amount in planck.
+        #[arg(long)]
+        amount: u128,
+        /// Vault nonce (`vault_vaultNonce`).
+        #[arg(long)]
+        nonce: u64,
+    },
+    /// `destroy_vault`.
+    Destroy {
+        /// Vault account.
+        #[arg(long, value_parser = parse_account)]
+        account: AccountId32,
+        /// Vault nonce.
+        #[arg(long)]
+        nonce: u64,
+    },
+    /// Key rotation to the key hashing to `new_public_key_hash`.
+    Rotate {
+        /// Vault account.
+        #[arg(long, value_parser = parse_account)]
+        account: AccountId32,
+        /// blake2_256 of the new public key (hex).
+        #[arg(long, value_parser = parse_hash)]
+        new_public_key_hash: [u8; 32],
+        /// Vault nonce.
+        #[arg(long)]
+        nonce: u64,
+    },
+    /// Vault freeze.
+    Freeze {
+        /// Vault account.
+        #[arg(long, value_parser = parse_account)]
+        account: AccountId32,
+        /// Vault nonce.
+        #[arg(long)]
+        nonce: u64,
+    },
+    /// Acceptance of a vault set up by `funder` (`create_vault_for`).
+    Accept {
+        /// Account that becomes the vault.
+        #[arg(long, value_parser = parse_account)]
+        target: AccountId32,
+        /// Account paying for the vault.
+        #[arg(long, value_parser = parse_account)]
+        funder: AccountId32,
+        /// Initial deposit in planck.
+        #[arg(long)]
+        initial_deposit: u128,
+        /// Target's current account nonce (`system_accountNextIndex`).
+        #[arg(long)]
+        account_nonce: u32,
+    },
+    /// `vault_approve` of a spender.
+    Approve {
+        /// Vault account.
+        #[arg(long, value_parser = parse_account)]
+        vault: AccountId32,
+        /// Account allowed to pull funds with `vault_transfer_from`.
+        #[arg(long, value_parser = parse_account)]
+        spender: AccountId32,
+        /// Allowance in planck; 0 revokes it.
+        #[arg(long)]
+        limit: u128,
+        /// Vault nonce.
+        #[arg(long)]
+        nonce: u64,
+    },
+    /// Announcement published with `announcements.announce`.
+    Announce {
+        /// Vault account.
+        #[arg(long, value_parser = parse_account)]
+        vault: AccountId32,
+        /// Announcement text.
+        #[arg(long)]
+        message: String,
+        /// Vault nonce.
+        #[arg(long)]
+        nonce: u64,
+    },
+    /// `set_vault_policy`: daily spending limit and recipient whitelist.
+    Policy {
+        /// Vault account.
+        #[arg(long, value_parser = parse_account)]
+        vault: AccountId32,
+        /// Most the vault may send in any 24 hours, in planck. No limit if omitted.
+        #[arg(long)]
+        daily_limit: Option<u128>,
+        /// Allowed recipient; repeat for each. Any recipient if omitted.
+        #[arg(long, value_parser = parse_account)]
+        whitelist: Option<Vec<AccountId32>>,
+        /// Vault nonce.
+        #[arg(long)]
+        nonce: u64,
+    },
+}
+
+impl VaultSignCmd {
+    /// Run the command.
+    pub fn run(&self) -> sc_cli::Result<()> {
+        let mnemonic = parse_mnemonic(&self.mnemonic)?;
+        let keypair = derive_keypair(&mnemonic, &self.passphrase, self.account, self.index);
+        let public_key = keypair.public.to_bytes();
+
+        let payload = match &self.payload {
+            PayloadCmd::Transfer { from, to, amount, nonce } => tesserax_vault_payload::transfer(
+                &from.encode(),
+                &to.encode(),
+                &amount.encode(),
+                *nonce,
+            ),
+            PayloadCmd::Destroy { account, nonce } => {
+                tesserax_vault_payload::destroy(&account.encode(), *nonce)
+            },
+            PayloadCmd::Rotate { account, new_public_key_hash, nonce } => {
+                tesserax_vault_payload::rotate(&account.encode(), new_public_key_hash, *nonce)
+            },
+            PayloadCmd::Freeze { account, nonce } => {
+                tesserax_vault_payload::freeze(&account.encode(), *nonce)
+            },
+            PayloadCmd::Accept { target, funder, initial_deposit, account_nonce } => {
+                tesserax_vault_payload::accept(
+                    &target.encode(),
+                    &funder.encode(),
+                    &sp_core::blake2_256(&public_key),
+                    &initial_deposit.encode(),
+                    &account_nonce.encode(),
+                )
+            },
+            PayloadCmd::Approve { vault, spender, limit, nonce } => {
+                tesserax_vault_payload::approve(
+                    &vault.encode(),
+                    &spender.encode(),
+                    &limit.encode(),
+                    *nonce,
+                )
+            },
+            PayloadCmd::Announce { vault, message, nonce } => {
+                tesserax_vault_payload::announce(&vault.encode(), message.as_bytes(), *nonce)
+            },
+            PayloadCmd::Policy { vault, daily_limit, whitelist, nonce } => {
+                tesserax_vault_payload::policy(
+                    &vault.encode(),
+                    &daily_limit.encode(),
+                    &whitelist.encode(),
+                    *nonce,
+                )
+            },
+        };
+
+        println!("Public key: 0x{}", hex::encode(public_key));
+        println!("Payload:    0x{}", hex::encode(&payload));
+        println!("Signature:  0x{}", hex::encode(keypair.sign(&payload)));
+
+        Ok(())
+    }
+}
+
+fn parse_account(s: &str) -> Result<AccountId32, String> {
+    AccountId32::from_str(s).map_err(|e| format!("Invalid account: {e}"))
+}
+
+fn parse_hash(s: &str) -> Result<[u8; 32], String> {
+    let bytes = hex::decode(s.trim_start_matches("0x")).map_err(|e| format!("Invalid hex: {e}"))?;
+    bytes.try_into().map_err(|v: Vec<u8>| format!("Expected 32 bytes, got {}", v.len()))
+}
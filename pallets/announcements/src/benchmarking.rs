@@ -0,0 +1,52 @@
+//! Benchmarking setup for pallet-announcements
+
+extern crate alloc;
+use alloc::vec;
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as Announcements;
+use frame_benchmarking::v2::*;
+use frame_support::{
+    traits::{Currency, Get},
+    BoundedVec,
+};
+use frame_system::RawOrigin;
+use pallet_quantum_vault::{DILITHIUM_PUBLIC_KEY_SIZE, DILITHIUM_SIGNATURE_SIZE};
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    /// Message of `n` bytes from a vault. Benchmarks can't sign, so the
+    /// Dilithium verification runs and fails on a mock signature.
+    #[benchmark]
+    fn announce(n: Linear<1, { T::MaxMessageLength::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        let deposit = T::VaultCreationFee::get() + T::Currency::minimum_balance() * 10u32.into();
+        let _ = T::Currency::make_free_balance_be(&caller, deposit);
+        pallet_quantum_vault::Pallet::<T>::create_vault(
+            RawOrigin::Signed(caller.clone()).into(),
+            vec![0u8; DILITHIUM_PUBLIC_KEY_SIZE],
+        )
+        .unwrap();
+        let message: BoundedVec<u8, T::MaxMessageLength> =
+            vec![b'a'; n as usize].try_into().unwrap();
+
+        #[block]
+        {
+            let _ = Announcements::<T>::announce(
+                RawOrigin::Signed(caller).into(),
+                message,
+                vec![0u8; DILITHIUM_SIGNATURE_SIZE],
+            );
+        }
+    }
+
+    impl_benchmark_test_suite!(
+        Announcements,
+        crate::mock::new_test_ext(),
+        crate::mock::Test
+    );
+}
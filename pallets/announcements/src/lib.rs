@@ -0,0 +1,186 @@
+//! # Tesserax Announcements Pallet
+//!
+//! Authenticated, quantum-safe board for validator and operations
+//! announcements. A vault publishes a message with `announce(message,
+//! signature)`, signing the `Announce` payload
+//! (`tesserax_vault_payload::announce`) with its Dilithium vault key at the
+//! vault's current nonce, e.g. with `tesserax-node vault-sign announce`.
+//!
+//! The signature is checked like any vault signature in pallet-quantum-vault
+//! (security level, message mode) and uses up the nonce, so an announcement
+//! can't be replayed. Readers tell who said what from the author's vault key,
+//! not from the account that submitted the extrinsic.
+//!
+//! The last `MaxAnnouncements` messages stay in storage; every message is also
+//! emitted in `Announced`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub use pallet::*;
+
+/// Weight implementations
+pub mod weights;
+pub use weights::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use alloc::vec::Vec;
+    use codec::Encode;
+    use frame_support::pallet_prelude::*;
+    use frame_system::pallet_prelude::*;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// Configuration trait for the announcements pallet.
+    #[pallet::config]
+    pub trait Config: frame_system::Config + pallet_quantum_vault::Config {
+        /// Longest message, in bytes
+        #[pallet::constant]
+        type MaxMessageLength: Get<u32>;
+
+        /// Announcements kept in storage; older ones are only in events
+        #[pallet::constant]
+        type MaxAnnouncements: Get<u32>;
+
+        /// Weight information
+        type WeightInfo: WeightInfo;
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // TYPES
+    // ═══════════════════════════════════════════════════════════════════════
+
+    /// Published announcement
+    #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+    pub struct Announcement<AccountId, BlockNumber, Message> {
+        /// Vault whose key signed the message
+        pub author: AccountId,
+        pub published_at: BlockNumber,
+        pub message: Message,
+    }
+
+    pub type AnnouncementOf<T> = Announcement<
+        <T as frame_system::Config>::AccountId,
+        BlockNumberFor<T>,
+        BoundedVec<u8, <T as Config>::MaxMessageLength>,
+    >;
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // STORAGE
+    // ═══════════════════════════════════════════════════════════════════════
+
+    /// Recent announcements by index
+    #[pallet::storage]
+    pub type RecentAnnouncements<T: Config> =
+        StorageMap<_, Twox64Concat, u32, AnnouncementOf<T>, OptionQuery>;
+
+    /// Index of the next announcement
+    #[pallet::storage]
+    pub type NextAnnouncementIndex<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // EVENTS
+    // ═══════════════════════════════════════════════════════════════════════
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// `author`'s vault key signed `message`
+        Announced {
+            index: u32,
+            author: T::AccountId,
+            message: Vec<u8>,
+        },
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // ERRORS
+    // ═══════════════════════════════════════════════════════════════════════
+
+    #[pallet::error]
+    pub enum Error<T> {
+        EmptyMessage,
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // EXTRINSICS
+    // ═══════════════════════════════════════════════════════════════════════
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Publish `message`, signed by the caller's vault key
+        ///
+        /// `signature` signs `tesserax_vault_payload::announce` at the vault's
+        /// current nonce, which this uses up.
+        ///
+        /// # Errors
+        /// * `EmptyMessage` - Nothing to announce
+        /// * `NotVault` - Caller is not a vault (pallet-quantum-vault)
+        /// * `SignatureVerificationFailed` - Invalid signature (pallet-quantum-vault)
+        #[pallet::call_index(0)]
+        #[pallet::weight(<T as Config>::WeightInfo::announce(message.len() as u32))]
+        pub fn announce(
+            origin: OriginFor<T>,
+            message: BoundedVec<u8, T::MaxMessageLength>,
+            signature: Vec<u8>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(!message.is_empty(), Error::<T>::EmptyMessage);
+
+            pallet_quantum_vault::Pallet::<T>::use_vault_signature(
+                &who,
+                |nonce| tesserax_vault_payload::announce(&who.encode(), &message, nonce),
+                &signature,
+            )?;
+
+            let index = NextAnnouncementIndex::<T>::get();
+            if let Some(expired) = index.checked_sub(T::MaxAnnouncements::get()) {
+                RecentAnnouncements::<T>::remove(expired);
+            }
+            RecentAnnouncements::<T>::insert(
+                index,
+                Announcement {
+                    author: who.clone(),
+                    published_at: frame_system::Pallet::<T>::block_number(),
+                    message: message.clone(),
+                },
+            );
+            NextAnnouncementIndex::<T>::put(index.saturating_add(1));
+
+            Self::deposit_event(Event::Announced {
+                index,
+                author: who,
+                message: message.into_inner(),
+            });
+            Ok(())
+        }
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // HELPER FUNCTIONS
+    // ═══════════════════════════════════════════════════════════════════════
+
+    impl<T: Config> Pallet<T> {
+        /// Announcements still in storage, oldest first
+        pub fn recent_announcements() -> Vec<(u32, AnnouncementOf<T>)> {
+            let next = NextAnnouncementIndex::<T>::get();
+            let first = next.saturating_sub(T::MaxAnnouncements::get());
+            (first..next)
+                .filter_map(|index| RecentAnnouncements::<T>::get(index).map(|a| (index, a)))
+                .collect()
+        }
+    }
+}
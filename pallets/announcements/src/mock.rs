@@ -0,0 +1,177 @@
+//! Mock runtime for testing pallet-announcements
+
+use core::cell::RefCell;
+use frame_support::{
+    assert_ok, derive_impl, parameter_types,
+    traits::{ConstU32, ConstU64, Nothing},
+    PalletId,
+};
+use sp_runtime::{traits::IdentityLookup, BuildStorage, Permill};
+
+use crate as pallet_announcements;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime for testing
+frame_support::construct_runtime!(
+    pub enum Test {
+        System: frame_system,
+        Balances: pallet_balances,
+        RemlVerifier: pallet_reml_verifier,
+        QuantumVault: pallet_quantum_vault,
+        Announcements: pallet_announcements,
+    }
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+    type AccountId = u64;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type AccountData = pallet_balances::AccountData<u64>;
+}
+
+impl pallet_balances::Config for Test {
+    type MaxLocks = ConstU32<50>;
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+    type Balance = u64;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ConstU64<1>;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type FreezeIdentifier = ();
+    type MaxFreezes = ConstU32<0>;
+    type RuntimeHoldReason = ();
+    type RuntimeFreezeReason = ();
+    type DoneSlashHandler = ();
+}
+
+parameter_types! {
+    pub const ExpectedVKeyHash: [u8; 32] = [0u8; 32];
+    pub const RewardPotId: PalletId = PalletId(*b"reml/pot");
+    pub const PrunerShare: sp_runtime::Perbill = sp_runtime::Perbill::zero();
+    pub const PremiumRate: Permill = Permill::from_percent(5);
+    pub const VaultToVaultDiscount: Permill = Permill::from_percent(50);
+    pub const FullPremiumShare: Permill = Permill::one();
+    pub const Treasury: u64 = TREASURY;
+    pub const IncentivePoolShare: Permill = Permill::zero();
+    pub const IncentivePool: u64 = 98;
+}
+
+impl pallet_reml_verifier::Config for Test {
+    type WeightInfo = ();
+    type AdminOrigin = frame_system::EnsureRoot<u64>;
+    type MaxAggregators = ConstU32<4>;
+    type ExpectedVKeyHash = ExpectedVKeyHash;
+    type TransitionVKeyHash = ();
+    type ProgramVKeys = ();
+    type InlineRequestMarks = ConstU32<{ pallet_reml_verifier::MAX_VERIFIED_REQUESTS }>;
+    type ProofEpoch = ConstU32<1>;
+    type OnBatchVerified = ();
+    type CoSignature = sp_runtime::testing::TestSignature;
+    type RewardCurrency = Balances;
+    type VaultCheck = Nothing;
+    type BatchReward = ConstU64<0>;
+    type RewardPotId = RewardPotId;
+    type EraLength = ConstU64<14_400>;
+    type MaxBatchesPerEra = ConstU32<{ u32::MAX }>;
+    type AggregatorBond = ConstU64<0>;
+    type UnbondingPeriod = ConstU64<0>;
+    type Slash = ();
+    type ProofRetentionPeriod = ConstU64<{ u64::MAX }>;
+    type MaxAnchorAge = ConstU64<10>;
+    type SubmissionDeposit = ConstU64<0>;
+    type PrunerShare = PrunerShare;
+}
+
+impl pallet_quantum_vault::Config for Test {
+    type Currency = Balances;
+    type WeightInfo = ();
+    type VaultCreationFee = ConstU64<2>;
+    type VaultTransferFeeMultiplier = ConstU32<10>;
+    type VaultTransferBaseFee = ConstU64<1>;
+    type VaultTransferPremiumRate = PremiumRate;
+    type VaultTransferPremiumCap = ConstU64<20>;
+    type VaultToVaultPremiumDiscount = VaultToVaultDiscount;
+    type InBandPremiumShare = FullPremiumShare;
+    type ProofBackedPremiumShare = FullPremiumShare;
+    type MaxPublicKeySize = ConstU32<2592>;
+    type MaxSignatureSize = ConstU32<7856>;
+    type TreasuryAccount = Treasury;
+    type IncentivePoolShare = IncentivePoolShare;
+    type IncentivePool = IncentivePool;
+    type MaxArchivedKeys = ConstU32<2>;
+    type SpendingWindow = ConstU64<24>;
+    type EraLength = ConstU64<14_400>;
+    type MaxStatsEras = ConstU32<1>;
+}
+
+/// Vault publishing announcements
+pub const OPS: u64 = 1;
+/// Funded, not a vault
+pub const OUTSIDER: u64 = 2;
+pub const TREASURY: u64 = 99;
+
+pub const MAX_ANNOUNCEMENTS: u32 = 3;
+
+impl pallet_announcements::Config for Test {
+    type MaxMessageLength = ConstU32<64>;
+    type MaxAnnouncements = ConstU32<MAX_ANNOUNCEMENTS>;
+    type WeightInfo = ();
+}
+
+std::thread_local! {
+    static OPS_KEYPAIR: RefCell<Option<pqc_dilithium::Keypair>> = const { RefCell::new(None) };
+}
+
+/// `OPS`'s vault key, generated once per test thread
+pub fn ops_keypair() -> pqc_dilithium::Keypair {
+    OPS_KEYPAIR.with(|kp| {
+        kp.borrow_mut()
+            .get_or_insert_with(pqc_dilithium::Keypair::generate)
+            .clone()
+    })
+}
+
+/// `keypair`'s signature of `message` from `vault` at `nonce`
+pub fn sign_announcement(
+    keypair: &pqc_dilithium::Keypair,
+    vault: u64,
+    message: &[u8],
+    nonce: u64,
+) -> Vec<u8> {
+    use codec::Encode;
+    keypair
+        .sign(&tesserax_vault_payload::announce(
+            &vault.encode(),
+            message,
+            nonce,
+        ))
+        .to_vec()
+}
+
+/// Block 1, `OPS` a vault under `ops_keypair()`
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(OPS, 1_000), (OUTSIDER, 1_000), (TREASURY, 1)],
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(QuantumVault::create_vault(
+            RuntimeOrigin::signed(OPS),
+            ops_keypair().public.to_vec()
+        ));
+    });
+    ext
+}
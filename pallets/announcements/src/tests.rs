@@ -0,0 +1,106 @@
+//! Unit tests for pallet-announcements
+
+use crate::{mock::*, Error, Event, NextAnnouncementIndex, RecentAnnouncements};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+
+fn message(text: &str) -> BoundedVec<u8, frame_support::traits::ConstU32<64>> {
+    text.as_bytes().to_vec().try_into().unwrap()
+}
+
+fn announce(text: &str, nonce: u64) -> sp_runtime::DispatchResult {
+    Announcements::announce(
+        RuntimeOrigin::signed(OPS),
+        message(text),
+        sign_announcement(&ops_keypair(), OPS, text.as_bytes(), nonce),
+    )
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// ANNOUNCE TESTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn announce_stores_and_emits_signed_message() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(announce("Upgrade at block 100", 0));
+
+        let stored = RecentAnnouncements::<Test>::get(0).unwrap();
+        assert_eq!(stored.author, OPS);
+        assert_eq!(stored.published_at, 1);
+        assert_eq!(stored.message, message("Upgrade at block 100"));
+        assert_eq!(NextAnnouncementIndex::<Test>::get(), 1);
+        assert_eq!(QuantumVault::vault_nonce(&OPS), Some(1));
+        System::assert_last_event(
+            Event::Announced {
+                index: 0,
+                author: OPS,
+                message: b"Upgrade at block 100".to_vec(),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn announce_rejects_unauthenticated_messages() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(announce("", 0), Error::<Test>::EmptyMessage);
+
+        // Not a vault
+        assert_noop!(
+            Announcements::announce(
+                RuntimeOrigin::signed(OUTSIDER),
+                message("Hello"),
+                sign_announcement(&ops_keypair(), OUTSIDER, b"Hello", 0),
+            ),
+            pallet_quantum_vault::Error::<Test>::NotVault
+        );
+
+        // Signed by another key
+        let other = pqc_dilithium::Keypair::generate();
+        assert_noop!(
+            Announcements::announce(
+                RuntimeOrigin::signed(OPS),
+                message("Hello"),
+                sign_announcement(&other, OPS, b"Hello", 0),
+            ),
+            pallet_quantum_vault::Error::<Test>::SignatureVerificationFailed
+        );
+
+        // Signature of a different message
+        assert_noop!(
+            Announcements::announce(
+                RuntimeOrigin::signed(OPS),
+                message("Hello"),
+                sign_announcement(&ops_keypair(), OPS, b"Goodbye", 0),
+            ),
+            pallet_quantum_vault::Error::<Test>::SignatureVerificationFailed
+        );
+
+        // Replay after the nonce is used
+        assert_ok!(announce("Hello", 0));
+        assert_noop!(
+            announce("Hello", 0),
+            pallet_quantum_vault::Error::<Test>::SignatureVerificationFailed
+        );
+    });
+}
+
+#[test]
+fn only_recent_announcements_are_kept() {
+    new_test_ext().execute_with(|| {
+        for nonce in 0..5 {
+            assert_ok!(announce(&format!("Notice {nonce}"), nonce));
+        }
+
+        // MAX_ANNOUNCEMENTS = 3
+        assert!(!RecentAnnouncements::<Test>::contains_key(0));
+        assert!(!RecentAnnouncements::<Test>::contains_key(1));
+        let recent = Announcements::recent_announcements();
+        assert_eq!(
+            recent.iter().map(|(index, _)| *index).collect::<Vec<_>>(),
+            vec![2, 3, 4]
+        );
+        assert_eq!(recent[2].1.message, message("Notice 4"));
+    });
+}
@@ -0,0 +1,33 @@
+//! Weight information for pallet-announcements
+//!
+//! In production, these should be generated using frame-benchmarking.
+
+use frame_support::pallet_prelude::Get;
+use frame_support::weights::Weight;
+
+/// Weight functions needed for pallet-announcements
+pub trait WeightInfo {
+    fn announce(n: u32) -> Weight;
+}
+
+/// Production weight implementations
+pub struct SubstrateWeight<T>(core::marker::PhantomData<T>);
+
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    /// Dilithium verification plus hashing `n` message bytes.
+    /// Vault, level, message mode, nonces, next index (r:6); nonces,
+    /// expired and new announcement, next index (w:5)
+    fn announce(n: u32) -> Weight {
+        Weight::from_parts(150_000_000, 0)
+            .saturating_add(Weight::from_parts(2_000, 0).saturating_mul(n as u64))
+            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().writes(5))
+    }
+}
+
+/// Unit testing weight implementations
+impl WeightInfo for () {
+    fn announce(n: u32) -> Weight {
+        Weight::from_parts(150_000_000 + 2_000 * n as u64, 0)
+    }
+}
@@ -0,0 +1,48 @@
+//! Benchmarking setup for pallet-chain-parameters
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as ChainParameters;
+use frame_benchmarking::v2::*;
+use frame_support::traits::Get;
+use frame_system::RawOrigin;
+use sp_runtime::Permill;
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    /// Benchmark updating every fee parameter at once
+    #[benchmark]
+    fn set_fee_parameters() {
+        let fee: T::Balance = 1_000u32.into();
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Root,
+            Some(fee),
+            Some(fee),
+            Some(5),
+            Some(fee),
+            Some(Permill::from_percent(1)),
+            Some(fee),
+        );
+
+        assert_eq!(VaultTransferFeeMultiplier::<T>::get(), 5);
+    }
+
+    /// Benchmark replacing the vault premium schedule
+    #[benchmark]
+    fn set_vault_premium_schedule() {
+        let schedule =
+            PremiumSchedule { in_band: Permill::one(), proof_backed: T::MinPremiumShare::get() };
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, schedule);
+
+        assert_eq!(VaultPremiumSchedule::<T>::get(), schedule);
+    }
+
+    impl_benchmark_test_suite!(ChainParameters, crate::mock::new_test_ext(), crate::mock::Test);
+}
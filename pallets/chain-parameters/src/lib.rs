@@ -0,0 +1,451 @@
+//! # Tesserax Chain Parameters Pallet
+//!
+//! Holds the existential deposit and fee parameters in storage so each chain
+//! spec can pick its own values at genesis instead of baking them into the
+//! runtime as compile-time constants.
+//!
+//! ## Parameters
+//!
+//! | Parameter | Set at genesis | Updatable |
+//! |-----------|----------------|-----------|
+//! | Existential deposit | yes | no |
+//! | Vault creation fee | yes | `AdminOrigin` |
+//! | Vault transfer base fee | yes | `AdminOrigin` |
+//! | Vault transfer fee multiplier | yes | `AdminOrigin` |
+//! | Vault transfer premium rate / cap | yes | `AdminOrigin` |
+//! | Vault premium schedule | yes | `AdminOrigin` |
+//! | Transaction byte fee | yes | `AdminOrigin` |
+//!
+//! The existential deposit is genesis-only: raising it on a live chain would
+//! silently put existing accounts below the threshold.
+//!
+//! The vault premium schedule is the share of the premium a vault transfer
+//! pays by how it is authorized: in-band (Dilithium signature checked in the
+//! extrinsic) or by a Re-ML proof of the signed transfer message, which
+//! stands in for the signature. As proving gets cheaper, governance can lower
+//! the proof-backed share live. It is bounded: neither share may drop below
+//! `MinPremiumShare`, and a proof-backed transfer never pays more than an
+//! in-band one.
+//!
+//! Unset parameters fall back to the `Default*` values in [`Config`], so a
+//! chain spec only needs to list the values it overrides.
+//!
+//! ## Usage
+//!
+//! The [`getters`] types implement `Get` and plug straight into other pallets'
+//! config, e.g. `type ExistentialDeposit = getters::ExistentialDeposit<Runtime>`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+/// Weight implementations
+pub mod weights;
+pub use weights::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::pallet_prelude::*;
+    use frame_system::pallet_prelude::*;
+    use sp_runtime::{
+        traits::{AtLeast32BitUnsigned, MaybeSerializeDeserialize, Zero},
+        Permill,
+    };
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// Share of the vault transfer premium charged per authorization path
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Encode,
+        Decode,
+        DecodeWithMemTracking,
+        MaxEncodedLen,
+        TypeInfo,
+    )]
+    pub struct PremiumSchedule {
+        /// Transfers authorized by their Dilithium signature alone
+        pub in_band: Permill,
+        /// Transfers whose signature a verified Re-ML request proves
+        pub proof_backed: Permill,
+    }
+
+    impl PremiumSchedule {
+        /// Whether both shares are at least `floor` and proof-backed transfers
+        /// pay no more than in-band ones
+        pub fn is_within(&self, floor: Permill) -> bool {
+            self.proof_backed >= floor && self.proof_backed <= self.in_band
+        }
+    }
+
+    /// Configuration trait for the chain parameters pallet.
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// Balance type of the parameters
+        type Balance: Parameter
+            + Member
+            + AtLeast32BitUnsigned
+            + Default
+            + Copy
+            + MaxEncodedLen
+            + MaybeSerializeDeserialize;
+
+        /// Origin allowed to update fee parameters
+        type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Existential deposit when the chain spec does not set one
+        #[pallet::constant]
+        type DefaultExistentialDeposit: Get<Self::Balance>;
+
+        /// Vault creation fee when the chain spec does not set one
+        #[pallet::constant]
+        type DefaultVaultCreationFee: Get<Self::Balance>;
+
+        /// Vault transfer base fee when the chain spec does not set one
+        #[pallet::constant]
+        type DefaultVaultTransferBaseFee: Get<Self::Balance>;
+
+        /// Vault transfer fee multiplier when the chain spec does not set one
+        #[pallet::constant]
+        type DefaultVaultTransferFeeMultiplier: Get<u32>;
+
+        /// Per-byte transaction fee when the chain spec does not set one
+        #[pallet::constant]
+        type DefaultTransactionByteFee: Get<Self::Balance>;
+
+        /// Percentage vault premium rate when the chain spec does not set one
+        #[pallet::constant]
+        type DefaultVaultTransferPremiumRate: Get<Permill>;
+
+        /// Percentage vault premium cap when the chain spec does not set one
+        #[pallet::constant]
+        type DefaultVaultTransferPremiumCap: Get<Self::Balance>;
+
+        /// Vault premium schedule when the chain spec does not set one
+        #[pallet::constant]
+        type DefaultVaultPremiumSchedule: Get<PremiumSchedule>;
+
+        /// Smallest share of the premium the schedule may charge on any path
+        #[pallet::constant]
+        type MinPremiumShare: Get<Permill>;
+
+        /// Weight information
+        type WeightInfo: WeightInfo;
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // STORAGE
+    // ═══════════════════════════════════════════════════════════════════════
+
+    /// Minimum balance to keep an account alive
+    #[pallet::storage]
+    pub type ExistentialDeposit<T: Config> =
+        StorageValue<_, T::Balance, ValueQuery, T::DefaultExistentialDeposit>;
+
+    /// Fee to create a quantum vault
+    #[pallet::storage]
+    pub type VaultCreationFee<T: Config> =
+        StorageValue<_, T::Balance, ValueQuery, T::DefaultVaultCreationFee>;
+
+    /// Base unit of the vault transfer premium
+    #[pallet::storage]
+    pub type VaultTransferBaseFee<T: Config> =
+        StorageValue<_, T::Balance, ValueQuery, T::DefaultVaultTransferBaseFee>;
+
+    /// Multiplier applied to the vault transfer base fee
+    #[pallet::storage]
+    pub type VaultTransferFeeMultiplier<T: Config> =
+        StorageValue<_, u32, ValueQuery, T::DefaultVaultTransferFeeMultiplier>;
+
+    /// Fee charged per byte of encoded extrinsic
+    #[pallet::storage]
+    pub type TransactionByteFee<T: Config> =
+        StorageValue<_, T::Balance, ValueQuery, T::DefaultTransactionByteFee>;
+
+    /// Share of the amount charged as premium by percentage-mode vaults
+    #[pallet::storage]
+    pub type VaultTransferPremiumRate<T: Config> =
+        StorageValue<_, Permill, ValueQuery, T::DefaultVaultTransferPremiumRate>;
+
+    /// Upper bound of the percentage-mode vault premium
+    #[pallet::storage]
+    pub type VaultTransferPremiumCap<T: Config> =
+        StorageValue<_, T::Balance, ValueQuery, T::DefaultVaultTransferPremiumCap>;
+
+    /// Share of the vault transfer premium charged per authorization path
+    #[pallet::storage]
+    pub type VaultPremiumSchedule<T: Config> =
+        StorageValue<_, PremiumSchedule, ValueQuery, T::DefaultVaultPremiumSchedule>;
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // GENESIS
+    // ═══════════════════════════════════════════════════════════════════════
+
+    /// Per-chain-spec overrides. `None` keeps the runtime default.
+    #[pallet::genesis_config]
+    #[derive(frame_support::DefaultNoBound)]
+    pub struct GenesisConfig<T: Config> {
+        pub existential_deposit: Option<T::Balance>,
+        pub vault_creation_fee: Option<T::Balance>,
+        pub vault_transfer_base_fee: Option<T::Balance>,
+        pub vault_transfer_fee_multiplier: Option<u32>,
+        pub transaction_byte_fee: Option<T::Balance>,
+        pub vault_transfer_premium_rate: Option<Permill>,
+        pub vault_transfer_premium_cap: Option<T::Balance>,
+        /// In-band share of the vault premium schedule
+        pub vault_in_band_premium_share: Option<Permill>,
+        /// Proof-backed share of the vault premium schedule
+        pub vault_proof_backed_premium_share: Option<Permill>,
+        #[serde(skip)]
+        pub _config: core::marker::PhantomData<T>,
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+        fn build(&self) {
+            if let Some(ed) = self.existential_deposit {
+                assert!(!ed.is_zero(), "Existential deposit must be non-zero");
+                ExistentialDeposit::<T>::put(ed);
+            }
+            if let Some(fee) = self.vault_creation_fee {
+                VaultCreationFee::<T>::put(fee);
+            }
+            if let Some(fee) = self.vault_transfer_base_fee {
+                VaultTransferBaseFee::<T>::put(fee);
+            }
+            if let Some(multiplier) = self.vault_transfer_fee_multiplier {
+                VaultTransferFeeMultiplier::<T>::put(multiplier);
+            }
+            if let Some(fee) = self.transaction_byte_fee {
+                TransactionByteFee::<T>::put(fee);
+            }
+            if let Some(rate) = self.vault_transfer_premium_rate {
+                VaultTransferPremiumRate::<T>::put(rate);
+            }
+            if let Some(cap) = self.vault_transfer_premium_cap {
+                VaultTransferPremiumCap::<T>::put(cap);
+            }
+            if self.vault_in_band_premium_share.is_some()
+                || self.vault_proof_backed_premium_share.is_some()
+            {
+                let default = T::DefaultVaultPremiumSchedule::get();
+                let schedule = PremiumSchedule {
+                    in_band: self.vault_in_band_premium_share.unwrap_or(default.in_band),
+                    proof_backed: self
+                        .vault_proof_backed_premium_share
+                        .unwrap_or(default.proof_backed),
+                };
+                assert!(
+                    schedule.is_within(T::MinPremiumShare::get()),
+                    "Vault premium schedule out of bounds"
+                );
+                VaultPremiumSchedule::<T>::put(schedule);
+            }
+        }
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // EVENTS
+    // ═══════════════════════════════════════════════════════════════════════
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Fee parameters were updated
+        FeeParametersUpdated {
+            vault_creation_fee: T::Balance,
+            vault_transfer_base_fee: T::Balance,
+            vault_transfer_fee_multiplier: u32,
+            transaction_byte_fee: T::Balance,
+            vault_transfer_premium_rate: Permill,
+            vault_transfer_premium_cap: T::Balance,
+        },
+        /// The vault premium schedule was updated
+        VaultPremiumScheduleUpdated { in_band: Permill, proof_backed: Permill },
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // ERRORS
+    // ═══════════════════════════════════════════════════════════════════════
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// A share is below `MinPremiumShare`, or proof-backed transfers would
+        /// pay more than in-band ones
+        PremiumScheduleOutOfBounds,
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // EXTRINSICS
+    // ═══════════════════════════════════════════════════════════════════════
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Update fee parameters. `None` leaves a parameter unchanged.
+        ///
+        /// The existential deposit is deliberately not updatable here.
+        #[pallet::call_index(0)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_fee_parameters())]
+        pub fn set_fee_parameters(
+            origin: OriginFor<T>,
+            vault_creation_fee: Option<T::Balance>,
+            vault_transfer_base_fee: Option<T::Balance>,
+            vault_transfer_fee_multiplier: Option<u32>,
+            transaction_byte_fee: Option<T::Balance>,
+            vault_transfer_premium_rate: Option<Permill>,
+            vault_transfer_premium_cap: Option<T::Balance>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            if let Some(fee) = vault_creation_fee {
+                VaultCreationFee::<T>::put(fee);
+            }
+            if let Some(fee) = vault_transfer_base_fee {
+                VaultTransferBaseFee::<T>::put(fee);
+            }
+            if let Some(multiplier) = vault_transfer_fee_multiplier {
+                VaultTransferFeeMultiplier::<T>::put(multiplier);
+            }
+            if let Some(fee) = transaction_byte_fee {
+                TransactionByteFee::<T>::put(fee);
+            }
+            if let Some(rate) = vault_transfer_premium_rate {
+                VaultTransferPremiumRate::<T>::put(rate);
+            }
+            if let Some(cap) = vault_transfer_premium_cap {
+                VaultTransferPremiumCap::<T>::put(cap);
+            }
+
+            Self::deposit_event(Event::FeeParametersUpdated {
+                vault_creation_fee: VaultCreationFee::<T>::get(),
+                vault_transfer_base_fee: VaultTransferBaseFee::<T>::get(),
+                vault_transfer_fee_multiplier: VaultTransferFeeMultiplier::<T>::get(),
+                transaction_byte_fee: TransactionByteFee::<T>::get(),
+                vault_transfer_premium_rate: VaultTransferPremiumRate::<T>::get(),
+                vault_transfer_premium_cap: VaultTransferPremiumCap::<T>::get(),
+            });
+
+            Ok(())
+        }
+
+        /// Set the share of the vault premium charged per authorization path
+        ///
+        /// Both shares must be at least `MinPremiumShare`, and `proof_backed`
+        /// at most `in_band`.
+        #[pallet::call_index(1)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_vault_premium_schedule())]
+        pub fn set_vault_premium_schedule(
+            origin: OriginFor<T>,
+            schedule: PremiumSchedule,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            ensure!(
+                schedule.is_within(T::MinPremiumShare::get()),
+                Error::<T>::PremiumScheduleOutOfBounds
+            );
+
+            VaultPremiumSchedule::<T>::put(schedule);
+            Self::deposit_event(Event::VaultPremiumScheduleUpdated {
+                in_band: schedule.in_band,
+                proof_backed: schedule.proof_backed,
+            });
+
+            Ok(())
+        }
+    }
+}
+
+/// `Get` adapters over the stored parameters, for use in other pallets' config.
+pub mod getters {
+    use super::{pallet, Config};
+    use core::marker::PhantomData;
+    use frame_support::traits::Get;
+    use sp_runtime::Permill;
+
+    /// Stored existential deposit
+    pub struct ExistentialDeposit<T>(PhantomData<T>);
+    impl<T: Config> Get<T::Balance> for ExistentialDeposit<T> {
+        fn get() -> T::Balance {
+            pallet::ExistentialDeposit::<T>::get()
+        }
+    }
+
+    /// Stored vault creation fee
+    pub struct VaultCreationFee<T>(PhantomData<T>);
+    impl<T: Config> Get<T::Balance> for VaultCreationFee<T> {
+        fn get() -> T::Balance {
+            pallet::VaultCreationFee::<T>::get()
+        }
+    }
+
+    /// Stored vault transfer base fee
+    pub struct VaultTransferBaseFee<T>(PhantomData<T>);
+    impl<T: Config> Get<T::Balance> for VaultTransferBaseFee<T> {
+        fn get() -> T::Balance {
+            pallet::VaultTransferBaseFee::<T>::get()
+        }
+    }
+
+    /// Stored vault transfer fee multiplier
+    pub struct VaultTransferFeeMultiplier<T>(PhantomData<T>);
+    impl<T: Config> Get<u32> for VaultTransferFeeMultiplier<T> {
+        fn get() -> u32 {
+            pallet::VaultTransferFeeMultiplier::<T>::get()
+        }
+    }
+
+    /// Stored per-byte transaction fee
+    pub struct TransactionByteFee<T>(PhantomData<T>);
+    impl<T: Config> Get<T::Balance> for TransactionByteFee<T> {
+        fn get() -> T::Balance {
+            pallet::TransactionByteFee::<T>::get()
+        }
+    }
+
+    /// Stored percentage-mode vault premium rate
+    pub struct VaultTransferPremiumRate<T>(PhantomData<T>);
+    impl<T: Config> Get<Permill> for VaultTransferPremiumRate<T> {
+        fn get() -> Permill {
+            pallet::VaultTransferPremiumRate::<T>::get()
+        }
+    }
+
+    /// Stored percentage-mode vault premium cap
+    pub struct VaultTransferPremiumCap<T>(PhantomData<T>);
+    impl<T: Config> Get<T::Balance> for VaultTransferPremiumCap<T> {
+        fn get() -> T::Balance {
+            pallet::VaultTransferPremiumCap::<T>::get()
+        }
+    }
+
+    /// Stored share of the premium charged on in-band vault transfers
+    pub struct InBandPremiumShare<T>(PhantomData<T>);
+    impl<T: Config> Get<Permill> for InBandPremiumShare<T> {
+        fn get() -> Permill {
+            pallet::VaultPremiumSchedule::<T>::get().in_band
+        }
+    }
+
+    /// Stored share of the premium charged on Re-ML proof-backed vault transfers
+    pub struct ProofBackedPremiumShare<T>(PhantomData<T>);
+    impl<T: Config> Get<Permill> for ProofBackedPremiumShare<T> {
+        fn get() -> Permill {
+            pallet::VaultPremiumSchedule::<T>::get().proof_backed
+        }
+    }
+}
@@ -0,0 +1,79 @@
+//! Mock runtime for testing pallet-chain-parameters
+
+use frame_support::{
+    derive_impl, parameter_types,
+    traits::{ConstU128, ConstU32},
+};
+use frame_system::EnsureRoot;
+use sp_runtime::{traits::IdentityLookup, BuildStorage, Permill};
+
+use crate as pallet_chain_parameters;
+use pallet_chain_parameters::PremiumSchedule;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime for testing
+frame_support::construct_runtime!(
+    pub enum Test {
+        System: frame_system,
+        ChainParameters: pallet_chain_parameters,
+    }
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+    type AccountId = u64;
+    type Lookup = IdentityLookup<Self::AccountId>;
+}
+
+pub const DEFAULT_ED: u128 = 1_000;
+pub const DEFAULT_CREATION_FEE: u128 = 2_000;
+pub const DEFAULT_BASE_FEE: u128 = 10;
+pub const DEFAULT_MULTIPLIER: u32 = 10;
+pub const DEFAULT_BYTE_FEE: u128 = 1;
+pub const DEFAULT_PREMIUM_CAP: u128 = 500;
+
+parameter_types! {
+    pub const DefaultPremiumRate: Permill = Permill::from_percent(1);
+    pub const DefaultPremiumSchedule: PremiumSchedule = PremiumSchedule {
+        in_band: Permill::one(),
+        proof_backed: Permill::from_percent(20),
+    };
+    pub const MinPremiumShare: Permill = Permill::from_percent(10);
+}
+
+impl pallet_chain_parameters::Config for Test {
+    type Balance = u128;
+    type AdminOrigin = EnsureRoot<u64>;
+    type DefaultExistentialDeposit = ConstU128<DEFAULT_ED>;
+    type DefaultVaultCreationFee = ConstU128<DEFAULT_CREATION_FEE>;
+    type DefaultVaultTransferBaseFee = ConstU128<DEFAULT_BASE_FEE>;
+    type DefaultVaultTransferFeeMultiplier = ConstU32<DEFAULT_MULTIPLIER>;
+    type DefaultTransactionByteFee = ConstU128<DEFAULT_BYTE_FEE>;
+    type DefaultVaultTransferPremiumRate = DefaultPremiumRate;
+    type DefaultVaultTransferPremiumCap = ConstU128<DEFAULT_PREMIUM_CAP>;
+    type DefaultVaultPremiumSchedule = DefaultPremiumSchedule;
+    type MinPremiumShare = MinPremiumShare;
+    type WeightInfo = ();
+}
+
+/// Build test externalities with the given genesis overrides
+pub fn new_test_ext_with(
+    genesis: pallet_chain_parameters::GenesisConfig<Test>,
+) -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+
+    genesis.assimilate_storage(&mut t).unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+/// Build test externalities with no overrides
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    new_test_ext_with(Default::default())
+}
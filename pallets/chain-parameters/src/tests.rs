@@ -0,0 +1,161 @@
+//! Unit tests for pallet-chain-parameters
+
+use crate::{getters, mock::*, Error, Event, GenesisConfig, PremiumSchedule};
+use frame_support::{assert_noop, assert_ok, traits::Get};
+use sp_runtime::{DispatchError, Permill};
+
+#[test]
+fn defaults_apply_without_genesis_overrides() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(getters::ExistentialDeposit::<Test>::get(), DEFAULT_ED);
+        assert_eq!(getters::VaultCreationFee::<Test>::get(), DEFAULT_CREATION_FEE);
+        assert_eq!(getters::VaultTransferBaseFee::<Test>::get(), DEFAULT_BASE_FEE);
+        assert_eq!(getters::VaultTransferFeeMultiplier::<Test>::get(), DEFAULT_MULTIPLIER);
+        assert_eq!(getters::TransactionByteFee::<Test>::get(), DEFAULT_BYTE_FEE);
+        assert_eq!(getters::VaultTransferPremiumRate::<Test>::get(), DefaultPremiumRate::get());
+        assert_eq!(getters::VaultTransferPremiumCap::<Test>::get(), DEFAULT_PREMIUM_CAP);
+    });
+}
+
+#[test]
+fn genesis_overrides_are_applied() {
+    let genesis = GenesisConfig::<Test> {
+        existential_deposit: Some(1),
+        vault_transfer_fee_multiplier: Some(3),
+        ..Default::default()
+    };
+
+    new_test_ext_with(genesis).execute_with(|| {
+        assert_eq!(getters::ExistentialDeposit::<Test>::get(), 1);
+        assert_eq!(getters::VaultTransferFeeMultiplier::<Test>::get(), 3);
+        // Untouched parameters keep their defaults
+        assert_eq!(getters::VaultCreationFee::<Test>::get(), DEFAULT_CREATION_FEE);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Existential deposit must be non-zero")]
+fn genesis_rejects_zero_existential_deposit() {
+    let genesis = GenesisConfig::<Test> { existential_deposit: Some(0), ..Default::default() };
+    let _ = new_test_ext_with(genesis);
+}
+
+#[test]
+fn set_fee_parameters_updates_only_given_values() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ChainParameters::set_fee_parameters(
+            RuntimeOrigin::root(),
+            Some(5_000),
+            None,
+            Some(20),
+            None,
+            Some(Permill::from_percent(2)),
+            None,
+        ));
+
+        assert_eq!(getters::VaultCreationFee::<Test>::get(), 5_000);
+        assert_eq!(getters::VaultTransferBaseFee::<Test>::get(), DEFAULT_BASE_FEE);
+        assert_eq!(getters::VaultTransferFeeMultiplier::<Test>::get(), 20);
+        assert_eq!(getters::TransactionByteFee::<Test>::get(), DEFAULT_BYTE_FEE);
+
+        System::assert_last_event(
+            Event::FeeParametersUpdated {
+                vault_creation_fee: 5_000,
+                vault_transfer_base_fee: DEFAULT_BASE_FEE,
+                vault_transfer_fee_multiplier: 20,
+                transaction_byte_fee: DEFAULT_BYTE_FEE,
+                vault_transfer_premium_rate: Permill::from_percent(2),
+                vault_transfer_premium_cap: DEFAULT_PREMIUM_CAP,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn set_fee_parameters_requires_admin() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ChainParameters::set_fee_parameters(
+                RuntimeOrigin::signed(1),
+                Some(1),
+                None,
+                None,
+                None,
+                None,
+                None
+            ),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn premium_schedule_defaults_and_genesis_override() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(getters::InBandPremiumShare::<Test>::get(), Permill::one());
+        assert_eq!(getters::ProofBackedPremiumShare::<Test>::get(), Permill::from_percent(20));
+    });
+
+    // A chain spec may override one share and keep the other's default
+    let genesis = GenesisConfig::<Test> {
+        vault_proof_backed_premium_share: Some(Permill::from_percent(50)),
+        ..Default::default()
+    };
+    new_test_ext_with(genesis).execute_with(|| {
+        assert_eq!(getters::InBandPremiumShare::<Test>::get(), Permill::one());
+        assert_eq!(getters::ProofBackedPremiumShare::<Test>::get(), Permill::from_percent(50));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Vault premium schedule out of bounds")]
+fn genesis_rejects_out_of_bounds_premium_schedule() {
+    let genesis = GenesisConfig::<Test> {
+        vault_proof_backed_premium_share: Some(Permill::from_percent(5)),
+        ..Default::default()
+    };
+    let _ = new_test_ext_with(genesis);
+}
+
+#[test]
+fn set_vault_premium_schedule_is_bounded() {
+    new_test_ext().execute_with(|| {
+        let schedule = |in_band, proof_backed| PremiumSchedule {
+            in_band: Permill::from_percent(in_band),
+            proof_backed: Permill::from_percent(proof_backed),
+        };
+
+        assert_noop!(
+            ChainParameters::set_vault_premium_schedule(
+                RuntimeOrigin::signed(1),
+                schedule(100, 20)
+            ),
+            DispatchError::BadOrigin
+        );
+        // Below MinPremiumShare
+        assert_noop!(
+            ChainParameters::set_vault_premium_schedule(RuntimeOrigin::root(), schedule(100, 5)),
+            Error::<Test>::PremiumScheduleOutOfBounds
+        );
+        // Proof-backed transfers paying more than in-band ones
+        assert_noop!(
+            ChainParameters::set_vault_premium_schedule(RuntimeOrigin::root(), schedule(60, 80)),
+            Error::<Test>::PremiumScheduleOutOfBounds
+        );
+
+        assert_ok!(ChainParameters::set_vault_premium_schedule(
+            RuntimeOrigin::root(),
+            schedule(90, 10)
+        ));
+        assert_eq!(getters::InBandPremiumShare::<Test>::get(), Permill::from_percent(90));
+        assert_eq!(getters::ProofBackedPremiumShare::<Test>::get(), Permill::from_percent(10));
+        System::assert_last_event(
+            Event::VaultPremiumScheduleUpdated {
+                in_band: Permill::from_percent(90),
+                proof_backed: Permill::from_percent(10),
+            }
+            .into(),
+        );
+    });
+}
@@ -0,0 +1,40 @@
+//! Weight information for pallet-chain-parameters
+//!
+//! In production, these should be generated using frame-benchmarking.
+
+use frame_support::pallet_prelude::Get;
+use frame_support::weights::Weight;
+
+/// Weight functions needed for pallet-chain-parameters
+pub trait WeightInfo {
+    fn set_fee_parameters() -> Weight;
+    fn set_vault_premium_schedule() -> Weight;
+}
+
+/// Production weight implementations
+pub struct SubstrateWeight<T>(core::marker::PhantomData<T>);
+
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    /// Up to 6 writes, then 6 reads for the event
+    fn set_fee_parameters() -> Weight {
+        Weight::from_parts(10_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().writes(6))
+    }
+
+    /// One write
+    fn set_vault_premium_schedule() -> Weight {
+        Weight::from_parts(8_000_000, 0).saturating_add(T::DbWeight::get().writes(1))
+    }
+}
+
+/// Unit testing weight implementations
+impl WeightInfo for () {
+    fn set_fee_parameters() -> Weight {
+        Weight::from_parts(10_000_000, 512)
+    }
+
+    fn set_vault_premium_schedule() -> Weight {
+        Weight::from_parts(8_000_000, 0)
+    }
+}
@@ -3,12 +3,14 @@
 //! These benchmarks measure the weight of the emission hooks:
 //! - `on_initialize_with_reward`: Block with reward minting
 //! - `on_initialize_no_reward`: Block without reward (emission ended)
+//! - `set_bootstrap_redirect`: Setting the bootstrapping redirect
 
 use super::*;
 
 #[allow(unused)]
 use crate::Pallet as Emission;
 use frame_benchmarking::v2::*;
+use frame_system::RawOrigin;
 
 #[benchmarks]
 mod benchmarks {
@@ -65,5 +67,19 @@ mod benchmarks {
         }
     }
 
+    #[benchmark]
+    fn set_bootstrap_redirect() {
+        let redirect = BootstrapRedirect {
+            beneficiary: account("beneficiary", 0, 0),
+            share: sp_runtime::Perbill::from_percent(50),
+            expires_at_era: 365,
+        };
+
+        #[extrinsic_call]
+        set_bootstrap_redirect(RawOrigin::Root, Some(redirect.clone()));
+
+        assert_eq!(BootstrapRedirectConfig::<T>::get(), Some(redirect));
+    }
+
     impl_benchmark_test_suite!(Emission, crate::mock::new_test_ext(), crate::mock::Test);
 }
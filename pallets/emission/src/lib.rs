@@ -14,6 +14,14 @@
 //! 4. Reward is minted and given to the block author
 //! 5. After emission ends, a bonus of 627 TSRX is minted once
 //!
+//! ## Bootstrapping Redirect
+//!
+//! Early networks often run a single genesis validator, which would otherwise
+//! collect every reward. `AdminOrigin` can redirect a share of each block
+//! reward to a beneficiary (a bootstrapping fund or the aggregator incentive
+//! pool) until a given era, after which the author gets the full reward again.
+//! The bonus mint is never redirected.
+//!
 //! ## Why Pre-computed?
 //!
 //! - **Deterministic**: No floating-point, no approximation errors
@@ -41,6 +49,9 @@ pub use pallet::*;
 mod emission_table;
 pub use emission_table::*;
 
+/// Emission dashboard runtime API
+pub mod runtime_api;
+
 /// Weight implementations
 pub mod weights;
 pub use weights::*;
@@ -54,6 +65,30 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+/// Maximum eras returned per `reward_schedule` page
+pub const MAX_SCHEDULE_PAGE_SIZE: u32 = 1_000;
+
+/// Share of block rewards sent to a beneficiary instead of the block author
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    codec::Encode,
+    codec::Decode,
+    codec::DecodeWithMemTracking,
+    codec::MaxEncodedLen,
+    scale_info::TypeInfo,
+    sp_runtime::RuntimeDebug,
+)]
+pub struct BootstrapRedirect<AccountId> {
+    /// Receives the redirected share
+    pub beneficiary: AccountId,
+    /// Share of each block reward redirected
+    pub share: sp_runtime::Perbill,
+    /// First era in which the author gets the full reward again
+    pub expires_at_era: u32,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
@@ -86,6 +121,9 @@ pub mod pallet {
         /// Find the author of the current block (validator who gets reward)
         type FindAuthor: FindAuthor<Self::AccountId>;
 
+        /// Origin allowed to set the bootstrapping redirect
+        type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
         /// Weight information
         type WeightInfo: WeightInfo;
     }
@@ -100,6 +138,18 @@ pub mod pallet {
     #[pallet::getter(fn bonus_minted)]
     pub type BonusMinted<T> = StorageValue<_, bool, ValueQuery>;
 
+    /// Block and amount of the most recent reward or bonus mint
+    #[pallet::storage]
+    #[pallet::getter(fn last_reward)]
+    pub type LastReward<T: Config> =
+        StorageValue<_, (BlockNumberFor<T>, BalanceOf<T>), OptionQuery>;
+
+    /// Share of block rewards redirected away from the author, if any
+    #[pallet::storage]
+    #[pallet::getter(fn bootstrap_redirect)]
+    pub type BootstrapRedirectConfig<T: Config> =
+        StorageValue<_, BootstrapRedirect<T::AccountId>, OptionQuery>;
+
     // ═══════════════════════════════════════════════════════════════════════
     // EVENTS
     // ═══════════════════════════════════════════════════════════════════════
@@ -127,6 +177,18 @@ pub mod pallet {
             recipient: T::AccountId,
             amount: BalanceOf<T>,
         },
+
+        /// Part of the block reward was minted to the bootstrapping beneficiary
+        RewardRedirected {
+            block_number: BlockNumberFor<T>,
+            beneficiary: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+
+        /// Bootstrapping redirect was set (`None` when cleared)
+        BootstrapRedirectSet {
+            redirect: Option<BootstrapRedirect<T::AccountId>>,
+        },
     }
 
     // ═══════════════════════════════════════════════════════════════════════
@@ -139,6 +201,8 @@ pub mod pallet {
         NoAuthor,
         /// Arithmetic overflow
         Overflow,
+        /// Redirect would expire at or before the current era
+        RedirectAlreadyExpired,
     }
 
     // ═══════════════════════════════════════════════════════════════════════
@@ -182,6 +246,7 @@ pub mod pallet {
 
                     // Set flag to prevent duplicate minting
                     BonusMinted::<T>::put(true);
+                    LastReward::<T>::put((block_number, bonus));
 
                     // Emit events
                     Self::deposit_event(Event::BonusMinted {
@@ -217,10 +282,20 @@ pub mod pallet {
                 return T::WeightInfo::on_initialize_no_reward();
             }
 
+            // Split off the bootstrapping share while the redirect is active
+            let redirect = BootstrapRedirectConfig::<T>::get()
+                .filter(|redirect| current_era < redirect.expires_at_era);
+            let redirected_per_block = redirect
+                .as_ref()
+                .map_or(0, |redirect| redirect.share.mul_floor(reward_per_block));
+
             // Convert to balance type
-            let reward: BalanceOf<T> = match reward_per_block.try_into() {
-                Ok(r) => r,
-                Err(_) => return T::WeightInfo::on_initialize_no_reward(),
+            let (reward, redirected): (BalanceOf<T>, BalanceOf<T>) = match (
+                (reward_per_block - redirected_per_block).try_into(),
+                redirected_per_block.try_into(),
+            ) {
+                (Ok(r), Ok(d)) => (r, d),
+                _ => return T::WeightInfo::on_initialize_no_reward(),
             };
 
             // Find the block author (validator)
@@ -235,6 +310,19 @@ pub mod pallet {
 
             // Mint the reward (create new tokens)
             let imbalance = T::Currency::deposit_creating(&author, reward);
+            LastReward::<T>::put((block_number, reward + redirected));
+
+            if let Some(redirect) = redirect.filter(|_| redirected_per_block > 0) {
+                drop(T::Currency::deposit_creating(
+                    &redirect.beneficiary,
+                    redirected,
+                ));
+                Self::deposit_event(Event::RewardRedirected {
+                    block_number,
+                    beneficiary: redirect.beneficiary,
+                    amount: redirected,
+                });
+            }
 
             // Emit event
             Self::deposit_event(Event::RewardMinted {
@@ -251,6 +339,39 @@ pub mod pallet {
         }
     }
 
+    // ═══════════════════════════════════════════════════════════════════════
+    // EXTRINSICS
+    // ═══════════════════════════════════════════════════════════════════════
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Redirect a share of block rewards to a beneficiary until
+        /// `expires_at_era`, or clear the redirect with `None`.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::set_bootstrap_redirect())]
+        pub fn set_bootstrap_redirect(
+            origin: OriginFor<T>,
+            redirect: Option<BootstrapRedirect<T::AccountId>>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            match &redirect {
+                Some(new) => {
+                    let current_era = Self::current_era(frame_system::Pallet::<T>::block_number());
+                    ensure!(
+                        new.expires_at_era > current_era,
+                        Error::<T>::RedirectAlreadyExpired
+                    );
+                    BootstrapRedirectConfig::<T>::put(new);
+                },
+                None => BootstrapRedirectConfig::<T>::kill(),
+            }
+
+            Self::deposit_event(Event::BootstrapRedirectSet { redirect });
+            Ok(())
+        }
+    }
+
     // ═══════════════════════════════════════════════════════════════════════
     // HELPER FUNCTIONS
     // ═══════════════════════════════════════════════════════════════════════
@@ -271,6 +392,27 @@ pub mod pallet {
             }
         }
 
+        /// Reward minted in `block_number` (before the bonus)
+        pub fn reward_for_block(block_number: BlockNumberFor<T>) -> u128 {
+            Self::reward_for_era(Self::current_era(block_number))
+        }
+
+        /// Last block of the emission schedule; the bonus is minted after it
+        pub fn emission_ends_at_block() -> BlockNumberFor<T> {
+            (TOTAL_ERAS as u32).saturating_mul(BLOCKS_PER_ERA).into()
+        }
+
+        /// Per-block reward of up to `limit` (capped at `MAX_SCHEDULE_PAGE_SIZE`)
+        /// eras from `start_era`
+        pub fn reward_schedule(start_era: u32, limit: u32) -> alloc::vec::Vec<u128> {
+            REWARD_SCHEDULE
+                .iter()
+                .skip(start_era as usize)
+                .take(limit.min(MAX_SCHEDULE_PAGE_SIZE) as usize)
+                .copied()
+                .collect()
+        }
+
         /// Get maximum supply
         pub fn max_supply() -> u128 {
             MAX_SUPPLY
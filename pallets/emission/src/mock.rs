@@ -69,6 +69,7 @@ impl frame_support::traits::FindAuthor<u64> for MockNoAuthor {
 impl pallet_emission::Config for Test {
     type Currency = Balances;
     type FindAuthor = MockFindAuthor;
+    type AdminOrigin = frame_system::EnsureRoot<u64>;
     type WeightInfo = ();
 }
 
@@ -0,0 +1,30 @@
+//! Runtime API definition for the Emission pallet.
+
+use alloc::vec::Vec;
+use codec::Codec;
+
+sp_api::decl_runtime_apis! {
+    /// Emission schedule queries for explorers and dashboards
+    ///
+    /// Evaluated at the queried block, so explorers can plot the curve
+    /// without embedding the schedule.
+    pub trait EmissionApi<BlockNumber>
+    where
+        BlockNumber: Codec,
+    {
+        /// Era of the current block (0-indexed)
+        fn current_era() -> u32;
+
+        /// Reward minted per block in the current era, 0 once emission ended
+        fn reward_for_current_block() -> u128;
+
+        /// Total emitted through the current block, genesis supply excluded
+        fn total_emitted() -> u128;
+
+        /// Last block of the emission schedule
+        fn emission_ends_at_block() -> BlockNumber;
+
+        /// Per-block reward of up to `limit` eras from `start_era`
+        fn reward_schedule(start_era: u32, limit: u32) -> Vec<u128>;
+    }
+}
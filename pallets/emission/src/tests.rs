@@ -1,9 +1,11 @@
 //! Unit tests for pallet-emission
 
 use crate::{
-    mock::*, pallet::Pallet, Event, BLOCKS_PER_ERA, MAX_SUPPLY, REWARD_SCHEDULE, TOTAL_ERAS,
+    mock::*, pallet::Pallet, BootstrapRedirect, Error, Event, BLOCKS_PER_ERA, MAX_SUPPLY,
+    REWARD_SCHEDULE, TOTAL_ERAS,
 };
-use frame_support::{assert_ok, traits::Hooks};
+use frame_support::{assert_noop, assert_ok, traits::Hooks};
+use sp_runtime::{traits::BadOrigin, Perbill};
 
 #[test]
 fn test_emission_constants() {
@@ -82,6 +84,23 @@ fn test_reward_minting_on_initialize() {
     });
 }
 
+#[test]
+fn test_last_reward_tracks_latest_mint() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(Pallet::<Test>::last_reward(), None);
+
+        for block_num in 2u64..=3 {
+            System::set_block_number(block_num);
+            <Pallet<Test> as Hooks<u64>>::on_initialize(block_num);
+        }
+
+        assert_eq!(
+            Pallet::<Test>::last_reward(),
+            Some((3, REWARD_SCHEDULE[0] as u128))
+        );
+    });
+}
+
 #[test]
 fn test_multiple_blocks_accumulate_rewards() {
     new_test_ext().execute_with(|| {
@@ -235,3 +254,103 @@ fn test_first_era_has_initial_burst() {
         // This is acceptable as it's for initial distribution
     });
 }
+
+#[test]
+fn test_emission_dashboard_helpers() {
+    new_test_ext().execute_with(|| {
+        let end = Pallet::<Test>::emission_ends_at_block();
+        assert_eq!(end, TOTAL_ERAS as u64 * BLOCKS_PER_ERA as u64);
+        assert!(!Pallet::<Test>::is_emission_ended(end));
+        assert!(Pallet::<Test>::is_emission_ended(end + 1));
+
+        assert_eq!(Pallet::<Test>::reward_for_block(1), REWARD_SCHEDULE[0]);
+        assert_eq!(Pallet::<Test>::reward_for_block(end + 1), 0);
+
+        let page = Pallet::<Test>::reward_schedule(10, 5);
+        assert_eq!(page, REWARD_SCHEDULE[10..15].to_vec());
+        // Pages are capped and stop at the end of the schedule
+        assert_eq!(Pallet::<Test>::reward_schedule(0, u32::MAX).len(), 1_000);
+        let tail = Pallet::<Test>::reward_schedule(TOTAL_ERAS as u32 - 2, 5);
+        assert_eq!(tail.len(), 2);
+    });
+}
+
+#[test]
+fn test_bootstrap_redirect_splits_reward_until_expiry() {
+    new_test_ext().execute_with(|| {
+        let fund = 3u64;
+        let redirect = BootstrapRedirect {
+            beneficiary: fund,
+            share: Perbill::from_percent(25),
+            expires_at_era: 1,
+        };
+
+        assert_noop!(
+            Emission::set_bootstrap_redirect(RuntimeOrigin::signed(1), Some(redirect.clone())),
+            BadOrigin
+        );
+        assert_ok!(Emission::set_bootstrap_redirect(
+            RuntimeOrigin::root(),
+            Some(redirect.clone())
+        ));
+        assert_eq!(Emission::bootstrap_redirect(), Some(redirect));
+
+        let reward = REWARD_SCHEDULE[0];
+        let redirected = Perbill::from_percent(25).mul_floor(reward);
+        let alice_before = Balances::free_balance(1);
+
+        run_to_block(2);
+        assert_eq!(Balances::free_balance(fund), redirected);
+        assert_eq!(
+            Balances::free_balance(1),
+            alice_before + reward - redirected
+        );
+        assert_eq!(Emission::last_reward(), Some((2, reward)));
+        System::assert_has_event(RuntimeEvent::Emission(Event::RewardRedirected {
+            block_number: 2,
+            beneficiary: fund,
+            amount: redirected,
+        }));
+
+        // From era 1 on, the author gets the full reward again
+        let first_of_era_1 = BLOCKS_PER_ERA as u64 + 1;
+        System::set_block_number(first_of_era_1 - 1);
+        let alice_before = Balances::free_balance(1);
+        run_to_block(first_of_era_1);
+        assert_eq!(Balances::free_balance(fund), redirected);
+        assert_eq!(Balances::free_balance(1), alice_before + REWARD_SCHEDULE[1]);
+    });
+}
+
+#[test]
+fn test_bootstrap_redirect_rejects_past_expiry_and_clears() {
+    new_test_ext().execute_with(|| {
+        let redirect = BootstrapRedirect {
+            beneficiary: 3u64,
+            share: Perbill::from_percent(10),
+            expires_at_era: 0,
+        };
+        assert_noop!(
+            Emission::set_bootstrap_redirect(RuntimeOrigin::root(), Some(redirect)),
+            Error::<Test>::RedirectAlreadyExpired
+        );
+
+        let redirect = BootstrapRedirect {
+            beneficiary: 3u64,
+            share: Perbill::from_percent(10),
+            expires_at_era: 5,
+        };
+        assert_ok!(Emission::set_bootstrap_redirect(
+            RuntimeOrigin::root(),
+            Some(redirect)
+        ));
+        assert_ok!(Emission::set_bootstrap_redirect(
+            RuntimeOrigin::root(),
+            None
+        ));
+        assert_eq!(Emission::bootstrap_redirect(), None);
+        System::assert_last_event(RuntimeEvent::Emission(Event::BootstrapRedirectSet {
+            redirect: None,
+        }));
+    });
+}
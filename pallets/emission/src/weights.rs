@@ -10,6 +10,7 @@ use frame_support::weights::Weight;
 pub trait WeightInfo {
     fn on_initialize_with_reward() -> Weight;
     fn on_initialize_no_reward() -> Weight;
+    fn set_bootstrap_redirect() -> Weight;
 }
 
 /// Production weight implementations (benchmarked)
@@ -29,14 +30,20 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     /// - 1 arithmetic operation (era calculation)
     /// - 1 array lookup (reward schedule)
     /// - 1 FindAuthor call
-    /// - 1 Currency::deposit_creating call
-    /// - 1 event deposit
+    /// - 1 bootstrap redirect read
+    /// - up to 2 Currency::deposit_creating calls (author, beneficiary)
+    /// - 1 last reward write
+    /// - up to 2 event deposits
     fn on_initialize_with_reward() -> Weight {
         // Base weight: ~15ms execution time estimate
         Weight::from_parts(15_000_000, 0)
             .saturating_add(T::DbWeight::get().reads(1)) // Read author
             .saturating_add(T::DbWeight::get().reads(1)) // Read balance
+            .saturating_add(T::DbWeight::get().reads(1)) // Read bootstrap redirect
+            .saturating_add(T::DbWeight::get().reads(1)) // Read beneficiary balance
             .saturating_add(T::DbWeight::get().writes(1)) // Write new balance
+            .saturating_add(T::DbWeight::get().writes(1)) // Write beneficiary balance
+            .saturating_add(T::DbWeight::get().writes(1)) // Write last reward
     }
 
     /// Weight for on_initialize when emission has ended
@@ -48,6 +55,11 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
         // Base weight: ~5ms execution time estimate
         Weight::from_parts(5_000_000, 0)
     }
+
+    /// Weight for setting or clearing the bootstrapping redirect
+    fn set_bootstrap_redirect() -> Weight {
+        Weight::from_parts(10_000_000, 0).saturating_add(T::DbWeight::get().writes(1))
+    }
 }
 
 /// Unit testing weight implementations
@@ -59,4 +71,8 @@ impl WeightInfo for () {
     fn on_initialize_no_reward() -> Weight {
         Weight::from_parts(5_000_000, 512)
     }
+
+    fn set_bootstrap_redirect() -> Weight {
+        Weight::from_parts(10_000_000, 0)
+    }
 }
@@ -0,0 +1,57 @@
+//! Benchmarking setup for pallet-program-registry
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as ProgramRegistry;
+use frame_benchmarking::v2::*;
+use frame_support::traits::Get;
+use frame_system::RawOrigin;
+
+/// Register versions `1..=count` of the Re-ML program, all active now
+fn register_versions<T: Config>(count: u32) {
+    let now = frame_system::Pallet::<T>::block_number();
+    for version in 1..=count {
+        ProgramRegistry::<T>::register_program_version(
+            RawOrigin::Root.into(),
+            REML_PROGRAM_ID,
+            version,
+            [version as u8; 32],
+            [version as u8; 32],
+            now,
+        )
+        .unwrap();
+    }
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    /// Benchmark registering the last version that fits
+    #[benchmark]
+    fn register_program_version() {
+        let max = T::MaxVersions::get();
+        register_versions::<T>(max - 1);
+        let now = frame_system::Pallet::<T>::block_number();
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, REML_PROGRAM_ID, max, [0xaa; 32], [0xbb; 32], now);
+
+        assert_eq!(ProgramVersions::<T>::get(REML_PROGRAM_ID).len() as u32, max);
+    }
+
+    /// Benchmark removing the first of a full list of versions
+    #[benchmark]
+    fn remove_program_version() {
+        let max = T::MaxVersions::get();
+        register_versions::<T>(max);
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, REML_PROGRAM_ID, 1);
+
+        assert_eq!(ProgramVersions::<T>::get(REML_PROGRAM_ID).len() as u32, max - 1);
+    }
+
+    impl_benchmark_test_suite!(ProgramRegistry, crate::mock::new_test_ext(), crate::mock::Test);
+}
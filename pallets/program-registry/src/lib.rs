@@ -0,0 +1,330 @@
+//! # Tesserax Program Registry Pallet
+//!
+//! Registry of the zkVM guest programs the chain trusts. Each program ID has
+//! the versions governance registered, each pinning the SP1 verification key
+//! hash, the hash of the guest ELF it was built from and the block it takes
+//! effect at.
+//!
+//! ## Versions
+//!
+//! A version is active from its activation block until a later version
+//! activates, so a guest upgrade is scheduled by registering the new version
+//! ahead of time with the block provers switch at. Removing the active
+//! version falls back to the previous one.
+//!
+//! For `TransitionPeriod` blocks after a version activates, the version it
+//! replaced stays accepted, so proofs generated against the old vkey before
+//! the switch can still be submitted.
+//!
+//! | Program | ID |
+//! |---------|----|
+//! | Re-ML (ML-DSA batch verification) | [`REML_PROGRAM_ID`] |
+//!
+//! ## Usage
+//!
+//! Verifier pallets read the active vkey hash through
+//! [`getters::ActiveVKeyHash`], e.g.
+//! `type ExpectedVKeyHash = getters::ActiveVKeyHash<Runtime, RemlProgramId, Fallback>`.
+//! `Fallback` applies until the program has an active version.
+//! [`getters::TransitionVKeyHash`] is the replaced version's vkey hash while
+//! its transition window is open.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub use pallet::*;
+
+/// Weight implementations
+pub mod weights;
+pub use weights::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+/// Identifier of a guest program
+pub type ProgramId = u32;
+
+/// The Re-ML guest (`reml/guest`)
+pub const REML_PROGRAM_ID: ProgramId = 1;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use alloc::vec::Vec;
+    use frame_support::pallet_prelude::*;
+    use frame_system::pallet_prelude::*;
+    use sp_runtime::traits::{Saturating, Zero};
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// Configuration trait for the program registry pallet.
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// Origin allowed to register and remove program versions
+        type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Versions kept per program; remove old ones to register more
+        #[pallet::constant]
+        type MaxVersions: Get<u32>;
+
+        /// Blocks the replaced version stays accepted after a version activates
+        #[pallet::constant]
+        type TransitionPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Weight information
+        type WeightInfo: WeightInfo;
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // TYPES
+    // ═══════════════════════════════════════════════════════════════════════
+
+    /// A registered version of a guest program
+    #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+    pub struct ProgramVersion<BlockNumber> {
+        pub version: u32,
+        /// SP1 `vk.hash_bytes()` of the program
+        pub vkey_hash: [u8; 32],
+        /// SHA-256 of the guest ELF the vkey was derived from
+        pub elf_hash: [u8; 32],
+        /// First block the version is active
+        pub activation_block: BlockNumber,
+    }
+
+    pub type ProgramVersionOf<T> = ProgramVersion<BlockNumberFor<T>>;
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // STORAGE
+    // ═══════════════════════════════════════════════════════════════════════
+
+    /// Registered versions of each program, by ascending version and
+    /// activation block
+    #[pallet::storage]
+    pub type ProgramVersions<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        ProgramId,
+        BoundedVec<ProgramVersionOf<T>, T::MaxVersions>,
+        ValueQuery,
+    >;
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // GENESIS
+    // ═══════════════════════════════════════════════════════════════════════
+
+    /// Programs trusted from genesis, as `(program_id, vkey_hash, elf_hash)`,
+    /// each registered as version 1 active from block 0
+    #[pallet::genesis_config]
+    #[derive(frame_support::DefaultNoBound)]
+    pub struct GenesisConfig<T: Config> {
+        pub programs: Vec<(ProgramId, [u8; 32], [u8; 32])>,
+        #[serde(skip)]
+        pub _config: core::marker::PhantomData<T>,
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+        fn build(&self) {
+            for (program_id, vkey_hash, elf_hash) in &self.programs {
+                assert!(
+                    !ProgramVersions::<T>::contains_key(program_id),
+                    "Program registered twice in genesis"
+                );
+                let version = ProgramVersion {
+                    version: 1,
+                    vkey_hash: *vkey_hash,
+                    elf_hash: *elf_hash,
+                    activation_block: Zero::zero(),
+                };
+                ProgramVersions::<T>::insert(
+                    program_id,
+                    BoundedVec::<_, T::MaxVersions>::truncate_from(alloc::vec![version]),
+                );
+            }
+        }
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // EVENTS
+    // ═══════════════════════════════════════════════════════════════════════
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// `version` of `program_id` becomes active at `activation_block`
+        ProgramVersionRegistered {
+            program_id: ProgramId,
+            version: u32,
+            vkey_hash: [u8; 32],
+            elf_hash: [u8; 32],
+            activation_block: BlockNumberFor<T>,
+        },
+        /// `version` of `program_id` is no longer trusted
+        ProgramVersionRemoved { program_id: ProgramId, version: u32 },
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // ERRORS
+    // ═══════════════════════════════════════════════════════════════════════
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Version is not above the program's latest version
+        VersionNotIncreasing,
+        /// Activation block is in the past
+        ActivationInPast,
+        /// Activation block is before the latest version's
+        ActivationBeforeLatestVersion,
+        /// `MaxVersions` versions registered already
+        TooManyVersions,
+        /// Program has no such version
+        UnknownVersion,
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // EXTRINSICS
+    // ═══════════════════════════════════════════════════════════════════════
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Register `version` of `program_id`, active from `activation_block`
+        ///
+        /// Versions and activation blocks only go up, so the active version
+        /// is always the latest one already activated.
+        ///
+        /// # Errors
+        /// * `VersionNotIncreasing` - `version` is not above the latest version
+        /// * `ActivationInPast` - `activation_block` is before the current block
+        /// * `ActivationBeforeLatestVersion` - Latest version activates later
+        /// * `TooManyVersions` - Remove an old version first
+        #[pallet::call_index(0)]
+        #[pallet::weight(<T as Config>::WeightInfo::register_program_version())]
+        pub fn register_program_version(
+            origin: OriginFor<T>,
+            program_id: ProgramId,
+            version: u32,
+            vkey_hash: [u8; 32],
+            elf_hash: [u8; 32],
+            activation_block: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            ensure!(
+                activation_block >= frame_system::Pallet::<T>::block_number(),
+                Error::<T>::ActivationInPast
+            );
+
+            ProgramVersions::<T>::try_mutate(program_id, |versions| {
+                if let Some(latest) = versions.last() {
+                    ensure!(version > latest.version, Error::<T>::VersionNotIncreasing);
+                    ensure!(
+                        activation_block >= latest.activation_block,
+                        Error::<T>::ActivationBeforeLatestVersion
+                    );
+                }
+                versions
+                    .try_push(ProgramVersion { version, vkey_hash, elf_hash, activation_block })
+                    .map_err(|_| Error::<T>::TooManyVersions)
+            })?;
+
+            Self::deposit_event(Event::ProgramVersionRegistered {
+                program_id,
+                version,
+                vkey_hash,
+                elf_hash,
+                activation_block,
+            });
+            Ok(())
+        }
+
+        /// Stop trusting `version` of `program_id`
+        ///
+        /// Removing the active version falls back to the previous one, or to
+        /// the consumer's fallback if none is left.
+        #[pallet::call_index(1)]
+        #[pallet::weight(<T as Config>::WeightInfo::remove_program_version())]
+        pub fn remove_program_version(
+            origin: OriginFor<T>,
+            program_id: ProgramId,
+            version: u32,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            ProgramVersions::<T>::try_mutate_exists(program_id, |maybe_versions| {
+                let versions = maybe_versions.as_mut().ok_or(Error::<T>::UnknownVersion)?;
+                let index = versions
+                    .iter()
+                    .position(|v| v.version == version)
+                    .ok_or(Error::<T>::UnknownVersion)?;
+                versions.remove(index);
+                if versions.is_empty() {
+                    *maybe_versions = None;
+                }
+                Ok::<_, Error<T>>(())
+            })?;
+
+            Self::deposit_event(Event::ProgramVersionRemoved { program_id, version });
+            Ok(())
+        }
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // HELPER FUNCTIONS
+    // ═══════════════════════════════════════════════════════════════════════
+
+    impl<T: Config> Pallet<T> {
+        /// Latest version of `program_id` active at the current block
+        pub fn active_version(program_id: ProgramId) -> Option<ProgramVersionOf<T>> {
+            let now = frame_system::Pallet::<T>::block_number();
+            ProgramVersions::<T>::get(program_id)
+                .into_iter()
+                .rev()
+                .find(|v| v.activation_block <= now)
+        }
+
+        /// Version of `program_id` the active one replaced, while the active
+        /// version is younger than `TransitionPeriod`
+        pub fn transition_version(program_id: ProgramId) -> Option<ProgramVersionOf<T>> {
+            let now = frame_system::Pallet::<T>::block_number();
+            let mut activated = ProgramVersions::<T>::get(program_id)
+                .into_iter()
+                .rev()
+                .filter(|v| v.activation_block <= now);
+            let active = activated.next()?;
+            let replaced = activated.next()?;
+            (now < active.activation_block.saturating_add(T::TransitionPeriod::get()))
+                .then_some(replaced)
+        }
+    }
+}
+
+/// `Get` adapters over the registry, for use in other pallets' config.
+pub mod getters {
+    use super::{Config, Pallet, ProgramId};
+    use core::marker::PhantomData;
+    use frame_support::traits::Get;
+
+    /// Vkey hash of the active version of program `P`, `F` if it has none
+    pub struct ActiveVKeyHash<T, P, F>(PhantomData<(T, P, F)>);
+    impl<T: Config, P: Get<ProgramId>, F: Get<[u8; 32]>> Get<[u8; 32]> for ActiveVKeyHash<T, P, F> {
+        fn get() -> [u8; 32] {
+            Pallet::<T>::active_version(P::get()).map_or_else(F::get, |v| v.vkey_hash)
+        }
+    }
+
+    /// Vkey hash of the version program `P`'s active version replaced, while
+    /// its transition window is open
+    pub struct TransitionVKeyHash<T, P>(PhantomData<(T, P)>);
+    impl<T: Config, P: Get<ProgramId>> Get<Option<[u8; 32]>> for TransitionVKeyHash<T, P> {
+        fn get() -> Option<[u8; 32]> {
+            Pallet::<T>::transition_version(P::get()).map(|v| v.vkey_hash)
+        }
+    }
+}
@@ -0,0 +1,65 @@
+//! Mock runtime for testing pallet-program-registry
+
+use frame_support::{
+    derive_impl, parameter_types,
+    traits::{ConstU32, ConstU64},
+};
+use frame_system::EnsureRoot;
+use sp_runtime::{traits::IdentityLookup, BuildStorage};
+
+use crate as pallet_program_registry;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime for testing
+frame_support::construct_runtime!(
+    pub enum Test {
+        System: frame_system,
+        ProgramRegistry: pallet_program_registry,
+    }
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+    type AccountId = u64;
+    type Lookup = IdentityLookup<Self::AccountId>;
+}
+
+pub const MAX_VERSIONS: u32 = 3;
+
+/// Blocks a replaced version stays accepted
+pub const TRANSITION_PERIOD: u64 = 5;
+
+/// Vkey hash consumers use while a program has no active version
+pub const FALLBACK_VKEY: [u8; 32] = [0xff; 32];
+
+parameter_types! {
+    pub const RemlProgramId: crate::ProgramId = crate::REML_PROGRAM_ID;
+    pub const FallbackVKey: [u8; 32] = FALLBACK_VKEY;
+}
+
+impl pallet_program_registry::Config for Test {
+    type AdminOrigin = EnsureRoot<u64>;
+    type MaxVersions = ConstU32<MAX_VERSIONS>;
+    type TransitionPeriod = ConstU64<TRANSITION_PERIOD>;
+    type WeightInfo = ();
+}
+
+/// Build test externalities with the given genesis programs
+pub fn new_test_ext_with(
+    genesis: pallet_program_registry::GenesisConfig<Test>,
+) -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+    genesis.assimilate_storage(&mut t).unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+/// Build test externalities with an empty registry
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    new_test_ext_with(Default::default())
+}
@@ -0,0 +1,169 @@
+//! Unit tests for pallet-program-registry
+
+use crate::{
+    getters, mock::*, Error, Event, GenesisConfig, ProgramVersion, ProgramVersions, REML_PROGRAM_ID,
+};
+use frame_support::{assert_noop, assert_ok, traits::Get};
+use sp_runtime::DispatchError;
+
+type ActiveVKey = getters::ActiveVKeyHash<Test, RemlProgramId, FallbackVKey>;
+type TransitionVKey = getters::TransitionVKeyHash<Test, RemlProgramId>;
+
+fn register(version: u32, vkey: u8, activation_block: u64) -> sp_runtime::DispatchResult {
+    ProgramRegistry::register_program_version(
+        RuntimeOrigin::root(),
+        REML_PROGRAM_ID,
+        version,
+        [vkey; 32],
+        [vkey.wrapping_add(1); 32],
+        activation_block,
+    )
+}
+
+#[test]
+fn genesis_programs_are_active_from_block_zero() {
+    let genesis = GenesisConfig::<Test> {
+        programs: vec![(REML_PROGRAM_ID, [1; 32], [2; 32])],
+        ..Default::default()
+    };
+
+    new_test_ext_with(genesis).execute_with(|| {
+        assert_eq!(
+            ProgramRegistry::active_version(REML_PROGRAM_ID),
+            Some(ProgramVersion {
+                version: 1,
+                vkey_hash: [1; 32],
+                elf_hash: [2; 32],
+                activation_block: 0
+            })
+        );
+        assert_eq!(ActiveVKey::get(), [1; 32]);
+    });
+}
+
+#[test]
+fn unregistered_program_uses_fallback() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(ProgramRegistry::active_version(REML_PROGRAM_ID), None);
+        assert_eq!(ActiveVKey::get(), FALLBACK_VKEY);
+    });
+}
+
+#[test]
+fn versions_activate_at_their_activation_block() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(register(1, 1, 1));
+        assert_ok!(register(2, 2, 10));
+        System::assert_last_event(
+            Event::ProgramVersionRegistered {
+                program_id: REML_PROGRAM_ID,
+                version: 2,
+                vkey_hash: [2; 32],
+                elf_hash: [3; 32],
+                activation_block: 10,
+            }
+            .into(),
+        );
+
+        assert_eq!(ActiveVKey::get(), [1; 32]);
+        System::set_block_number(9);
+        assert_eq!(ActiveVKey::get(), [1; 32]);
+        System::set_block_number(10);
+        assert_eq!(ActiveVKey::get(), [2; 32]);
+    });
+}
+
+#[test]
+fn replaced_version_is_accepted_during_transition() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(register(1, 1, 1));
+        assert_eq!(TransitionVKey::get(), None);
+
+        assert_ok!(register(2, 2, 10));
+        System::set_block_number(9);
+        assert_eq!(TransitionVKey::get(), None);
+
+        System::set_block_number(10);
+        assert_eq!(ActiveVKey::get(), [2; 32]);
+        assert_eq!(TransitionVKey::get(), Some([1; 32]));
+        System::set_block_number(10 + TRANSITION_PERIOD - 1);
+        assert_eq!(TransitionVKey::get(), Some([1; 32]));
+        System::set_block_number(10 + TRANSITION_PERIOD);
+        assert_eq!(TransitionVKey::get(), None);
+
+        // Only the version the active one replaced
+        assert_ok!(register(3, 3, 20));
+        System::set_block_number(20);
+        assert_eq!(TransitionVKey::get(), Some([2; 32]));
+    });
+}
+
+#[test]
+fn register_rejects_out_of_order_versions() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(5);
+        assert_noop!(register(1, 1, 4), Error::<Test>::ActivationInPast);
+        assert_ok!(register(2, 1, 8));
+
+        assert_noop!(register(2, 2, 9), Error::<Test>::VersionNotIncreasing);
+        assert_noop!(register(1, 2, 9), Error::<Test>::VersionNotIncreasing);
+        assert_noop!(register(3, 2, 7), Error::<Test>::ActivationBeforeLatestVersion);
+
+        assert_ok!(register(3, 2, 8));
+        assert_ok!(register(4, 3, 9));
+        assert_noop!(register(5, 4, 9), Error::<Test>::TooManyVersions);
+    });
+}
+
+#[test]
+fn removing_active_version_falls_back_to_previous() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(register(1, 1, 1));
+        assert_ok!(register(2, 2, 1));
+        assert_eq!(ActiveVKey::get(), [2; 32]);
+
+        assert_ok!(ProgramRegistry::remove_program_version(
+            RuntimeOrigin::root(),
+            REML_PROGRAM_ID,
+            2
+        ));
+        System::assert_last_event(
+            Event::ProgramVersionRemoved { program_id: REML_PROGRAM_ID, version: 2 }.into(),
+        );
+        assert_eq!(ActiveVKey::get(), [1; 32]);
+
+        assert_ok!(ProgramRegistry::remove_program_version(
+            RuntimeOrigin::root(),
+            REML_PROGRAM_ID,
+            1
+        ));
+        assert!(!ProgramVersions::<Test>::contains_key(REML_PROGRAM_ID));
+        assert_eq!(ActiveVKey::get(), FALLBACK_VKEY);
+
+        assert_noop!(
+            ProgramRegistry::remove_program_version(RuntimeOrigin::root(), REML_PROGRAM_ID, 1),
+            Error::<Test>::UnknownVersion
+        );
+    });
+}
+
+#[test]
+fn registry_requires_admin() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ProgramRegistry::register_program_version(
+                RuntimeOrigin::signed(1),
+                REML_PROGRAM_ID,
+                1,
+                [1; 32],
+                [1; 32],
+                1
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            ProgramRegistry::remove_program_version(RuntimeOrigin::signed(1), REML_PROGRAM_ID, 1),
+            DispatchError::BadOrigin
+        );
+    });
+}
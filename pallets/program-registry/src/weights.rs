@@ -0,0 +1,42 @@
+//! Weight information for pallet-program-registry
+//!
+//! In production, these should be generated using frame-benchmarking.
+
+use frame_support::pallet_prelude::Get;
+use frame_support::weights::Weight;
+
+/// Weight functions needed for pallet-program-registry
+pub trait WeightInfo {
+    fn register_program_version() -> Weight;
+    fn remove_program_version() -> Weight;
+}
+
+/// Production weight implementations
+pub struct SubstrateWeight<T>(core::marker::PhantomData<T>);
+
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    /// Program versions (r:1 w:1)
+    fn register_program_version() -> Weight {
+        Weight::from_parts(15_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    /// Program versions (r:1 w:1)
+    fn remove_program_version() -> Weight {
+        Weight::from_parts(15_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+}
+
+/// Unit testing weight implementations
+impl WeightInfo for () {
+    fn register_program_version() -> Weight {
+        Weight::from_parts(15_000_000, 0)
+    }
+
+    fn remove_program_version() -> Weight {
+        Weight::from_parts(15_000_000, 0)
+    }
+}
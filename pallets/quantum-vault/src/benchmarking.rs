@@ -4,6 +4,14 @@
 //! - `create_vault`: Creating a new quantum vault with Dilithium public key
 //! - `destroy_vault`: Destroying a vault with signature verification  
 //! - `vault_transfer`: Transferring funds from a vault with signature verification
+//! - `create_vault_with_premium_mode`: Creating a vault with a percentage premium
+//! - `set_paused`: Pausing vault operations
+//! - `deny_key` / `allow_key`: Managing the public key deny-list
+//! - `create_vault_for`: Creating and funding a vault for another account
+//! - `create_vault_with_message_mode`: Creating a vault with a FIPS 204 message mode
+//! - `create_vault_with_security_level`: Creating a vault with a Dilithium5 key
+//! - `vault_approve` / `vault_transfer_from`: Allowances from vaults to spenders
+//! - `set_vault_policy`: Setting a daily limit and a full recipient whitelist
 
 extern crate alloc;
 use alloc::vec;
@@ -14,7 +22,10 @@ use super::*;
 #[allow(unused)]
 use crate::Pallet as QuantumVault;
 use frame_benchmarking::v2::*;
-use frame_support::traits::{Currency, Get};
+use frame_support::{
+    traits::{Currency, Get},
+    BoundedVec,
+};
 use frame_system::RawOrigin;
 
 /// Generate a mock Dilithium2 public key (1312 bytes)
@@ -60,6 +71,21 @@ mod benchmarks {
 
         let _ = Pallet::<T>::create_vault(RawOrigin::Signed(caller.clone()).into(), public_key);
 
+        // Worst case: the archive is full, so the oldest key gets pruned
+        let max_archived = T::MaxArchivedKeys::get() as u64;
+        ArchivedKeys::<T>::insert(
+            0,
+            ArchivedVaultKey {
+                account: caller.clone(),
+                public_key_hash: [1u8; 32],
+                valid_from: 0u32.into(),
+                valid_until: 0u32.into(),
+                signatures_used: 0,
+            },
+        );
+        ArchivedKeyIndex::<T>::insert([1u8; 32], 0);
+        NextArchiveIndex::<T>::put(max_archived);
+
         // Create mock signature for destroy
         let signature = mock_signature();
 
@@ -100,5 +126,189 @@ mod benchmarks {
         // Note: In mock environment, signature verification is bypassed
     }
 
+    #[benchmark]
+    fn create_vault_with_premium_mode() {
+        let caller: T::AccountId = whitelisted_caller();
+        let public_key = mock_public_key();
+
+        let deposit = T::VaultCreationFee::get() + T::Currency::minimum_balance() * 10u32.into();
+        let _ = T::Currency::make_free_balance_be(&caller, deposit);
+
+        #[extrinsic_call]
+        create_vault_with_premium_mode(
+            RawOrigin::Signed(caller.clone()),
+            public_key.clone(),
+            PremiumMode::Percentage,
+        );
+
+        assert_eq!(VaultPremiumModes::<T>::get(&caller), PremiumMode::Percentage);
+    }
+
+    #[benchmark]
+    fn set_paused() {
+        #[extrinsic_call]
+        set_paused(RawOrigin::Root, true);
+
+        assert!(VaultsPaused::<T>::get());
+    }
+
+    #[benchmark]
+    fn deny_key(v: Linear<0, MAX_FLAGGED_VAULTS>) {
+        // Worst case: every listed account is a vault secured by the key
+        let public_key = mock_public_key();
+        let public_key_hash = sp_core::blake2_256(&public_key);
+        let vaults: Vec<T::AccountId> = (0..v).map(|i| account("vault", i, 0)).collect();
+        for vault in &vaults {
+            Vaults::<T>::insert(vault, BoundedVec::truncate_from(public_key.clone()));
+        }
+
+        #[extrinsic_call]
+        deny_key(RawOrigin::Root, public_key_hash, BoundedVec::truncate_from(vaults));
+
+        assert!(DeniedKeys::<T>::contains_key(public_key_hash));
+    }
+
+    #[benchmark]
+    fn allow_key() {
+        DeniedKeys::<T>::insert([1u8; 32], frame_system::Pallet::<T>::block_number());
+
+        #[extrinsic_call]
+        allow_key(RawOrigin::Root, [1u8; 32]);
+
+        assert!(!DeniedKeys::<T>::contains_key([1u8; 32]));
+    }
+
+    #[benchmark]
+    fn create_vault_for() {
+        let funder: T::AccountId = whitelisted_caller();
+        let target: T::AccountId = account("target", 0, 0);
+        let initial_deposit = T::Currency::minimum_balance() * 10u32.into();
+
+        let deposit = T::VaultCreationFee::get() + T::Currency::minimum_balance() * 100u32.into();
+        let _ = T::Currency::make_free_balance_be(&funder, deposit);
+
+        #[extrinsic_call]
+        create_vault_for(
+            RawOrigin::Signed(funder),
+            target.clone(),
+            mock_public_key(),
+            mock_signature(),
+            initial_deposit,
+        );
+
+        // Note: In mock environment, signature verification is bypassed
+    }
+
+    #[benchmark]
+    fn create_vault_with_message_mode() {
+        let caller: T::AccountId = whitelisted_caller();
+
+        let deposit = T::VaultCreationFee::get() + T::Currency::minimum_balance() * 10u32.into();
+        let _ = T::Currency::make_free_balance_be(&caller, deposit);
+
+        // Worst case: percentage premium and the longest context
+        let context = BoundedVec::truncate_from(vec![0u8; MAX_CONTEXT_SIZE as usize]);
+
+        #[extrinsic_call]
+        create_vault_with_message_mode(
+            RawOrigin::Signed(caller.clone()),
+            mock_public_key(),
+            PremiumMode::Percentage,
+            MessageMode::PreHashSha256,
+            context,
+        );
+
+        assert!(VaultMessageModes::<T>::contains_key(&caller));
+    }
+
+    #[benchmark]
+    fn create_vault_with_security_level() {
+        let caller: T::AccountId = whitelisted_caller();
+
+        // Worst case: Level 5 fee and key, percentage premium
+        let level = SecurityLevel::Level5;
+        let deposit = T::VaultCreationFee::get() * level.creation_fee_multiplier().into()
+            + T::Currency::minimum_balance() * 10u32.into();
+        let _ = T::Currency::make_free_balance_be(&caller, deposit);
+
+        #[extrinsic_call]
+        create_vault_with_security_level(
+            RawOrigin::Signed(caller.clone()),
+            vec![0u8; level.public_key_size()],
+            level,
+            PremiumMode::Percentage,
+        );
+
+        assert_eq!(VaultSecurityLevels::<T>::get(&caller), level);
+    }
+
+    #[benchmark]
+    fn vault_approve() {
+        let caller: T::AccountId = whitelisted_caller();
+        let spender: T::AccountId = account("spender", 0, 0);
+
+        let deposit = T::VaultCreationFee::get() + T::Currency::minimum_balance() * 10u32.into();
+        let _ = T::Currency::make_free_balance_be(&caller, deposit);
+        let _ =
+            Pallet::<T>::create_vault(RawOrigin::Signed(caller.clone()).into(), mock_public_key());
+
+        #[extrinsic_call]
+        vault_approve(
+            RawOrigin::Signed(caller),
+            spender,
+            T::Currency::minimum_balance() * 10u32.into(),
+            mock_signature(),
+        );
+
+        // Note: In mock environment, signature verification is bypassed
+    }
+
+    #[benchmark]
+    fn vault_transfer_from() {
+        let vault: T::AccountId = account("vault", 0, 0);
+        let recipient: T::AccountId = account("recipient", 0, 0);
+        let amount: BalanceOf<T> = T::Currency::minimum_balance() * 10u32.into();
+
+        let deposit = T::VaultCreationFee::get() + T::Currency::minimum_balance() * 100u32.into();
+        let _ = T::Currency::make_free_balance_be(&vault, deposit);
+        let _ = T::Currency::make_free_balance_be(&recipient, T::Currency::minimum_balance());
+        let _ =
+            Pallet::<T>::create_vault(RawOrigin::Signed(vault.clone()).into(), mock_public_key());
+
+        // Worst case: a full allowance list with the caller last
+        let caller: T::AccountId = whitelisted_caller();
+        let mut allowances: Vec<(T::AccountId, BalanceOf<T>)> =
+            (1..MAX_VAULT_SPENDERS).map(|i| (account("spender", i, 0), amount)).collect();
+        allowances.push((caller.clone(), amount));
+        VaultAllowances::<T>::insert(&vault, BoundedVec::truncate_from(allowances));
+
+        #[extrinsic_call]
+        vault_transfer_from(RawOrigin::Signed(caller.clone()), vault.clone(), recipient, amount);
+
+        assert_eq!(Pallet::<T>::allowance(&vault, &caller), 0u32.into());
+    }
+
+    #[benchmark]
+    fn set_vault_policy() {
+        let caller: T::AccountId = whitelisted_caller();
+
+        let deposit = T::VaultCreationFee::get() + T::Currency::minimum_balance() * 10u32.into();
+        let _ = T::Currency::make_free_balance_be(&caller, deposit);
+        let _ =
+            Pallet::<T>::create_vault(RawOrigin::Signed(caller.clone()).into(), mock_public_key());
+
+        // Worst case: a full whitelist
+        let whitelist = (0..MAX_WHITELISTED_RECIPIENTS).map(|i| account("recipient", i, 0));
+        let policy = VaultPolicy {
+            daily_limit: Some(T::Currency::minimum_balance() * 10u32.into()),
+            whitelist: Some(BoundedVec::truncate_from(whitelist.collect())),
+        };
+
+        #[extrinsic_call]
+        set_vault_policy(RawOrigin::Signed(caller), policy, mock_signature());
+
+        // Note: In mock environment, signature verification is bypassed
+    }
+
     impl_benchmark_test_suite!(QuantumVault, crate::mock::new_test_ext(), crate::mock::Test);
 }
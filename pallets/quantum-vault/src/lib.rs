@@ -11,8 +11,10 @@
 //!
 //! - **Quantum-Resistant Cold Storage**: Protect holdings against future quantum attacks
 //! - **Accessible Security Fee**: 2 TSRX to create a vault (reduced for retail adoption)
-//! - **Premium Transfer Fee**: 0.1 TSRX per vault transfer (10x base fee)
-//! - **Treasury-Based Fees**: All fees preserved in protocol treasury, not burned
+//! - **Premium Transfer Fee**: 0.1 TSRX per vault transfer (10x base fee), or a
+//!   capped percentage of the amount for vaults created in percentage mode
+//! - **Treasury-Based Fees**: All fees preserved, not burned: in the protocol
+//!   treasury, less the share of transfer premiums funding the Re-ML incentive pool
 //!
 //! ## Post-Quantum Cryptography
 //!
@@ -22,7 +24,10 @@
 //! - Security Level: NIST Level 2 (AES-128 equivalent, 50+ years secure)
 //!
 //! Level 2 is NIST's recommended baseline - smaller and faster than Level 3,
-//! while still providing full quantum resistance.
+//! while still providing full quantum resistance. Vaults that need more can be
+//! created at Level 3 or 5 with `create_vault_with_security_level`, for a
+//! higher creation fee. Owners who prefer hash-based signatures for cold
+//! storage can use a SPHINCS+-SHA2-128s key the same way.
 //!
 //! ## Usage
 //!
@@ -35,6 +40,8 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
 pub use pallet::*;
 
 #[cfg(test)]
@@ -46,6 +53,7 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod runtime_api;
 pub mod weights;
 pub use weights::*;
 
@@ -68,6 +76,463 @@ pub use weights::*;
 pub const DILITHIUM_PUBLIC_KEY_SIZE: usize = 1312;
 pub const DILITHIUM_SIGNATURE_SIZE: usize = 2420;
 
+// Dilithium3 and Dilithium5 constants, for vaults created at a higher
+// `SecurityLevel`
+pub const DILITHIUM3_PUBLIC_KEY_SIZE: usize = 1952;
+pub const DILITHIUM3_SIGNATURE_SIZE: usize = 3293;
+pub const DILITHIUM5_PUBLIC_KEY_SIZE: usize = 2592;
+pub const DILITHIUM5_SIGNATURE_SIZE: usize = 4595;
+
+// SPHINCS+-SHA2-128s constants, for hash-based vault keys
+pub const SPHINCS_PUBLIC_KEY_SIZE: usize = 32;
+pub const SPHINCS_SIGNATURE_SIZE: usize = 7856;
+
+/// Maximum vaults returned per `list_vaults` page
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+/// Maximum vaults `deny_key` can flag in one call
+pub const MAX_FLAGGED_VAULTS: u32 = 100;
+
+/// `tracing` target of the spans around vault extrinsics and signature checks
+pub const TRACE_TARGET: &str = "sanctuary::vault";
+
+/// Spenders a vault can have allowances for at once
+pub const MAX_VAULT_SPENDERS: u32 = 16;
+
+/// Recipients a vault policy's whitelist can hold
+pub const MAX_WHITELISTED_RECIPIENTS: u32 = 16;
+
+/// Buckets `SpendingWindow` is split into for the rolling daily limit
+///
+/// Spending is tracked per bucket, so a transfer stops counting against the
+/// limit between one window and one window plus one bucket after it was made.
+pub const SPENDING_WINDOW_BUCKETS: u32 = 24;
+
+/// Nonces past a vault's lowest unused one that `vault_transfer_with_nonce`
+/// accepts, so pre-signed transfers can land in any order
+pub const NONCE_WINDOW: u64 = 64;
+
+/// Vault entry returned by paginated queries
+#[derive(
+    Clone, PartialEq, Eq, codec::Encode, codec::Decode, scale_info::TypeInfo, sp_runtime::RuntimeDebug,
+)]
+pub struct VaultSummary<AccountId> {
+    /// Vault account
+    pub account: AccountId,
+    /// Dilithium2 public key
+    pub public_key: alloc::vec::Vec<u8>,
+    /// Current vault nonce
+    pub nonce: u64,
+}
+
+/// Maximum vaults summed per `value_locked` page (balance reads only)
+pub const MAX_VALUE_LOCKED_PAGE_SIZE: u32 = 1_000;
+
+/// Balance held by a page of vaults, returned by `value_locked`
+#[derive(
+    Clone, PartialEq, Eq, codec::Encode, codec::Decode, scale_info::TypeInfo, sp_runtime::RuntimeDebug,
+)]
+pub struct ValueLockedPage<AccountId, Balance> {
+    /// Total (free + reserved) balance of the vaults in this page
+    pub value_locked: Balance,
+    /// Number of vaults in this page
+    pub vaults: u32,
+    /// Last vault of this page; pass it as `start_key` to continue
+    pub last_key: Option<AccountId>,
+}
+
+/// Key that secured a destroyed vault, kept for attributing old signatures
+#[derive(
+    Clone, PartialEq, Eq, codec::Encode, codec::Decode, scale_info::TypeInfo, sp_runtime::RuntimeDebug,
+    codec::MaxEncodedLen,
+)]
+pub struct ArchivedVaultKey<AccountId, BlockNumber> {
+    /// Vault account the key belonged to
+    pub account: AccountId,
+    /// `blake2_256` of the public key, as in `VaultCreated`
+    pub public_key_hash: [u8; 32],
+    /// Block the key became valid (0 if created before this was tracked)
+    pub valid_from: BlockNumber,
+    /// Block the key stopped being valid
+    pub valid_until: BlockNumber,
+    /// Vault nonce when archived, i.e. transfers the key signed
+    pub signatures_used: u64,
+}
+
+/// Spending policy a vault sets on itself with `set_vault_policy`
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    codec::Encode,
+    codec::Decode,
+    codec::DecodeWithMemTracking,
+    codec::MaxEncodedLen,
+    scale_info::TypeInfo,
+    sp_runtime::RuntimeDebug,
+)]
+pub struct VaultPolicy<AccountId, Balance> {
+    /// Most the vault may send in any `SpendingWindow`; `None` for no limit
+    pub daily_limit: Option<Balance>,
+    /// Only accounts the vault may send to; `None` for any account
+    pub whitelist: Option<
+        frame_support::BoundedVec<
+            AccountId,
+            frame_support::traits::ConstU32<MAX_WHITELISTED_RECIPIENTS>,
+        >,
+    >,
+}
+
+impl<AccountId, Balance> VaultPolicy<AccountId, Balance> {
+    /// Whether the policy restricts nothing
+    pub fn is_unrestricted(&self) -> bool {
+        self.daily_limit.is_none() && self.whitelist.is_none()
+    }
+}
+
+/// Why a simulated `vault_transfer` would fail
+#[derive(
+    Clone, PartialEq, Eq, codec::Encode, codec::Decode, scale_info::TypeInfo, sp_runtime::RuntimeDebug,
+)]
+pub struct TransferDryRunError {
+    /// Error the extrinsic would fail with
+    pub error: sp_runtime::DispatchError,
+    /// Error name, e.g. `SignatureVerificationFailed`
+    pub name: alloc::vec::Vec<u8>,
+}
+
+/// Result of `QuantumVaultApi::dry_run_transfer`
+#[derive(
+    Clone, PartialEq, Eq, codec::Encode, codec::Decode, scale_info::TypeInfo, sp_runtime::RuntimeDebug,
+)]
+pub struct TransferDryRun<Balance> {
+    /// Transaction fee of the call, charged even if the transfer fails
+    pub inclusion_fee: Balance,
+    /// Premium the transfer would pay, or the error it would fail with
+    pub outcome: Result<Balance, TransferDryRunError>,
+}
+
+/// Fees of a call, as `QuantumVaultApi::query_call_fees` quotes them
+///
+/// The standard payment RPC only reports the first three; the vault fees
+/// are charged by the call itself.
+#[derive(
+    Clone, PartialEq, Eq, codec::Encode, codec::Decode, scale_info::TypeInfo, sp_runtime::RuntimeDebug,
+)]
+pub struct CallFeeQuote<Balance> {
+    /// Transaction base fee, zero if the call pays no transaction fee
+    pub base_fee: Balance,
+    /// Transaction fee for the encoded length
+    pub len_fee: Balance,
+    /// Transaction fee for the weight, after the fee multiplier
+    pub adjusted_weight_fee: Balance,
+    /// Transfer premium the paying vault is charged
+    pub premium_fee: Balance,
+    /// Vault creation fee the caller is charged
+    pub creation_fee: Balance,
+}
+
+/// How a vault pays the transfer premium, chosen at vault creation
+#[derive(
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    codec::Encode,
+    codec::Decode,
+    codec::DecodeWithMemTracking,
+    codec::MaxEncodedLen,
+    scale_info::TypeInfo,
+    sp_runtime::RuntimeDebug,
+)]
+pub enum PremiumMode {
+    /// `VaultTransferBaseFee × VaultTransferFeeMultiplier` per transfer
+    #[default]
+    Flat,
+    /// `VaultTransferPremiumRate × amount`, at most `VaultTransferPremiumCap`
+    Percentage,
+}
+
+/// `VaultDescriptor` format produced by `vault_descriptor`
+pub const VAULT_DESCRIPTOR_VERSION: u8 = 1;
+
+/// Signature scheme of a vault key
+#[derive(
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    codec::Encode,
+    codec::Decode,
+    codec::DecodeWithMemTracking,
+    codec::MaxEncodedLen,
+    scale_info::TypeInfo,
+    sp_runtime::RuntimeDebug,
+)]
+pub enum SignatureScheme {
+    /// CRYSTALS-Dilithium Level 2
+    #[default]
+    Dilithium2,
+    /// CRYSTALS-Dilithium Level 3
+    Dilithium3,
+    /// CRYSTALS-Dilithium Level 5
+    Dilithium5,
+    /// SPHINCS+-SHA2-128s-simple
+    SphincsSha2_128s,
+}
+
+impl From<SecurityLevel> for SignatureScheme {
+    fn from(level: SecurityLevel) -> Self {
+        match level {
+            SecurityLevel::Level2 => Self::Dilithium2,
+            SecurityLevel::Level3 => Self::Dilithium3,
+            SecurityLevel::Level5 => Self::Dilithium5,
+            SecurityLevel::SphincsSha2_128s => Self::SphincsSha2_128s,
+        }
+    }
+}
+
+impl From<SignatureScheme> for SecurityLevel {
+    fn from(scheme: SignatureScheme) -> Self {
+        match scheme {
+            SignatureScheme::Dilithium2 => Self::Level2,
+            SignatureScheme::Dilithium3 => Self::Level3,
+            SignatureScheme::Dilithium5 => Self::Level5,
+            SignatureScheme::SphincsSha2_128s => Self::SphincsSha2_128s,
+        }
+    }
+}
+
+/// Parameter set of a vault's key, chosen at vault creation: a Dilithium NIST
+/// level, or a SPHINCS+ key
+///
+/// Higher levels have larger keys and signatures and cost more to verify, so
+/// vault creation costs `creation_fee_multiplier` times `VaultCreationFee`.
+#[derive(
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    codec::Encode,
+    codec::Decode,
+    codec::DecodeWithMemTracking,
+    codec::MaxEncodedLen,
+    scale_info::TypeInfo,
+    sp_runtime::RuntimeDebug,
+)]
+pub enum SecurityLevel {
+    /// Dilithium2 (ML-DSA-44), AES-128 equivalent
+    #[default]
+    Level2,
+    /// Dilithium3 (ML-DSA-65), AES-192 equivalent
+    Level3,
+    /// Dilithium5 (ML-DSA-87), AES-256 equivalent
+    Level5,
+    /// SPHINCS+-SHA2-128s-simple, hash-based, AES-128 equivalent (NIST level 1)
+    SphincsSha2_128s,
+}
+
+impl SecurityLevel {
+    /// NIST level number, as `tesserax_dilithium_io` takes it for Dilithium
+    pub fn nist_level(self) -> u8 {
+        match self {
+            Self::Level2 => 2,
+            Self::Level3 => 3,
+            Self::Level5 => 5,
+            Self::SphincsSha2_128s => 1,
+        }
+    }
+
+    /// Whether keys of this level are Dilithium keys
+    pub fn is_dilithium(self) -> bool {
+        self != Self::SphincsSha2_128s
+    }
+
+    /// Public key size at this level
+    pub fn public_key_size(self) -> usize {
+        match self {
+            Self::Level2 => DILITHIUM_PUBLIC_KEY_SIZE,
+            Self::Level3 => DILITHIUM3_PUBLIC_KEY_SIZE,
+            Self::Level5 => DILITHIUM5_PUBLIC_KEY_SIZE,
+            Self::SphincsSha2_128s => SPHINCS_PUBLIC_KEY_SIZE,
+        }
+    }
+
+    /// Signature size at this level
+    pub fn signature_size(self) -> usize {
+        match self {
+            Self::Level2 => DILITHIUM_SIGNATURE_SIZE,
+            Self::Level3 => DILITHIUM3_SIGNATURE_SIZE,
+            Self::Level5 => DILITHIUM5_SIGNATURE_SIZE,
+            Self::SphincsSha2_128s => SPHINCS_SIGNATURE_SIZE,
+        }
+    }
+
+    /// Multiple of `VaultCreationFee` charged to create a vault at this level
+    ///
+    /// SPHINCS+ keys are tiny but their signatures are three times the size
+    /// of Dilithium2's and take thousands of hashes to verify, so they are
+    /// priced like Dilithium3.
+    pub fn creation_fee_multiplier(self) -> u32 {
+        match self {
+            Self::Level2 => 1,
+            Self::Level3 | Self::SphincsSha2_128s => 2,
+            Self::Level5 => 3,
+        }
+    }
+}
+
+/// Longest context string a vault's signer can bind signatures to (FIPS 204)
+pub const MAX_CONTEXT_SIZE: u32 = 255;
+
+/// DER encoding of id-sha256 (2.16.840.1.101.3.4.2.1)
+const SHA256_OID: [u8; 11] = [0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x01];
+
+/// DER encoding of id-sha3-256 (2.16.840.1.101.3.4.2.8)
+const SHA3_256_OID: [u8; 11] = [0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x08];
+
+/// How a vault payload becomes the message its signer signs, chosen at vault creation
+///
+/// Mirrors the Re-ML request modes: `vault-sign` and Dilithium round 3
+/// libraries sign the payload as given, FIPS 204 ML-DSA prefixes a domain
+/// byte and a context string, and HSMs that only do HashML-DSA sign a digest
+/// of the payload tagged with the hash's OID.
+#[derive(
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    codec::Encode,
+    codec::Decode,
+    codec::DecodeWithMemTracking,
+    codec::MaxEncodedLen,
+    scale_info::TypeInfo,
+    sp_runtime::RuntimeDebug,
+)]
+pub enum MessageMode {
+    /// The payload itself
+    #[default]
+    Raw,
+    /// Pure ML-DSA: `0 || len(ctx) || ctx || payload`
+    Pure,
+    /// HashML-DSA: `1 || len(ctx) || ctx || OID || SHA-256(payload)`
+    PreHashSha256,
+    /// HashML-DSA: `1 || len(ctx) || ctx || OID || SHA3-256(payload)`
+    PreHashSha3_256,
+}
+
+impl MessageMode {
+    /// Message signed for `payload` with context string `context`
+    ///
+    /// `context` is at most `MAX_CONTEXT_SIZE` bytes; `Raw` ignores it.
+    pub fn signed_message(self, context: &[u8], payload: &[u8]) -> alloc::vec::Vec<u8> {
+        let digest: [u8; 32];
+        let (domain, oid, message): (u8, &[u8], &[u8]) = match self {
+            Self::Raw => return payload.to_vec(),
+            Self::Pure => (0, &[], payload),
+            Self::PreHashSha256 => {
+                digest = sp_io::hashing::sha2_256(payload);
+                (1, &SHA256_OID, &digest)
+            },
+            Self::PreHashSha3_256 => {
+                use sha3::Digest;
+                digest = sha3::Sha3_256::digest(payload).into();
+                (1, &SHA3_256_OID, &digest)
+            },
+        };
+
+        let mut out = alloc::vec::Vec::with_capacity(2 + context.len() + oid.len() + message.len());
+        out.extend_from_slice(&[domain, context.len() as u8]);
+        out.extend_from_slice(context);
+        out.extend_from_slice(oid);
+        out.extend_from_slice(message);
+        out
+    }
+}
+
+/// Context string of a vault's signatures
+pub type BoundedContext =
+    frame_support::BoundedVec<u8, frame_support::traits::ConstU32<MAX_CONTEXT_SIZE>>;
+
+/// Canonical, SCALE-encoded vault configuration
+///
+/// Exported with `vault_descriptor` and accepted by
+/// `create_vault_from_descriptor`, so a custodian can replicate one vault's
+/// policy across many accounts. New fields bump `version`.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    codec::Encode,
+    codec::Decode,
+    codec::DecodeWithMemTracking,
+    scale_info::TypeInfo,
+    sp_runtime::RuntimeDebug,
+)]
+pub struct VaultDescriptor {
+    /// Descriptor format, `VAULT_DESCRIPTOR_VERSION`
+    pub version: u8,
+    /// Scheme of `public_key`
+    pub scheme: SignatureScheme,
+    /// Vault public key
+    pub public_key: alloc::vec::Vec<u8>,
+    /// How the vault pays the transfer premium
+    pub premium_mode: PremiumMode,
+}
+
+/// Vault activity during one era, returned by `era_stats`
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    Default,
+    codec::Encode,
+    codec::Decode,
+    codec::MaxEncodedLen,
+    scale_info::TypeInfo,
+    sp_runtime::RuntimeDebug,
+)]
+pub struct EraStats<Balance> {
+    /// Era index, `block / EraLength`
+    pub era: u32,
+    /// Vaults created
+    pub creations: u32,
+    /// Transfers out of vaults, signed or pulled by a spender
+    pub transfers: u32,
+    /// Vaults destroyed
+    pub destroys: u32,
+    /// Transfer premiums charged
+    pub premium_collected: Balance,
+}
+
+/// Vault state a wallet needs to render a vault and build its signing
+/// payloads, returned by `vault_info`
+#[derive(
+    Clone, PartialEq, Eq, codec::Encode, codec::Decode, scale_info::TypeInfo, sp_runtime::RuntimeDebug,
+)]
+pub struct VaultInfo<AccountId, Balance, BlockNumber> {
+    /// `blake2_256` of the public key, as in `VaultCreated`
+    pub public_key_hash: [u8; 32],
+    /// Scheme of the public key
+    pub scheme: SignatureScheme,
+    /// Lowest unused vault nonce, the one `vault_transfer` signs
+    pub nonce: u64,
+    /// Block the vault's key became valid (0 if created before this was tracked)
+    pub created_at: BlockNumber,
+    /// How the vault pays the transfer premium
+    pub premium_mode: PremiumMode,
+    /// How signed messages are built from payloads
+    pub message_mode: MessageMode,
+    /// Context string of the vault's signatures, empty for `MessageMode::Raw`
+    pub context: alloc::vec::Vec<u8>,
+    /// Spending policy; `None` if unrestricted
+    pub policy: Option<VaultPolicy<AccountId, Balance>>,
+}
+
 /// Type alias for Dilithium public key
 pub type DilithiumPublicKey = [u8; DILITHIUM_PUBLIC_KEY_SIZE];
 
@@ -80,10 +545,15 @@ pub mod pallet {
     use super::*;
     use frame_support::{
         pallet_prelude::*,
+        storage::with_transaction,
         traits::{Currency, ExistenceRequirement, ReservableCurrency},
     };
     use frame_system::pallet_prelude::*;
-    use sp_runtime::traits::Saturating;
+    use pallet_reml_verifier::RemlVerification;
+    use sp_runtime::{
+        traits::{CheckedSub, One, Saturating, Zero},
+        Permill, SaturatedConversion, TransactionOutcome,
+    };
 
     extern crate alloc;
     use alloc::vec::Vec;
@@ -92,6 +562,9 @@ pub mod pallet {
     pub type BalanceOf<T> =
         <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
+    /// Vault policy with the runtime's account and balance types
+    pub type VaultPolicyOf<T> = VaultPolicy<<T as frame_system::Config>::AccountId, BalanceOf<T>>;
+
     #[pallet::pallet]
     pub struct Pallet<T>(_);
 
@@ -114,7 +587,7 @@ pub mod pallet {
 
         /// Multiplier for vault transfer premium fee
         /// Vault transfers pay an additional fee = base_fee * multiplier
-        /// This fee goes to the protocol treasury.
+        /// This fee goes to the protocol treasury, less `IncentivePoolShare`.
         /// Default: 100x (e.g., if base fee is 0.01 TSRX, vault pays 1 TSRX extra)
         #[pallet::constant]
         type VaultTransferFeeMultiplier: Get<u32>;
@@ -124,6 +597,29 @@ pub mod pallet {
         #[pallet::constant]
         type VaultTransferBaseFee: Get<BalanceOf<Self>>;
 
+        /// Share of the amount charged as premium by `PremiumMode::Percentage` vaults
+        #[pallet::constant]
+        type VaultTransferPremiumRate: Get<Permill>;
+
+        /// Upper bound of the `PremiumMode::Percentage` premium
+        #[pallet::constant]
+        type VaultTransferPremiumCap: Get<BalanceOf<Self>>;
+
+        /// Share of the premium waived when the recipient is also a vault
+        /// (`Permill::one()` waives it entirely)
+        #[pallet::constant]
+        type VaultToVaultPremiumDiscount: Get<Permill>;
+
+        /// Share of the premium charged on transfers authorized in-band, by
+        /// their Dilithium signature alone
+        #[pallet::constant]
+        type InBandPremiumShare: Get<Permill>;
+
+        /// Share of the premium charged on transfers whose Re-ML request's
+        /// proof stands in for the signature, sharing the batch's proof cost
+        #[pallet::constant]
+        type ProofBackedPremiumShare: Get<Permill>;
+
         /// Maximum public key size (Dilithium2 = 1312 bytes)
         #[pallet::constant]
         type MaxPublicKeySize: Get<u32>;
@@ -135,6 +631,30 @@ pub mod pallet {
         /// Protocol treasury account that receives vault fees
         /// If not set, fees go to the fee destination or are burned.
         type TreasuryAccount: Get<Self::AccountId>;
+
+        /// Share of each transfer premium paid into the Re-ML incentive pool
+        /// instead of the treasury
+        #[pallet::constant]
+        type IncentivePoolShare: Get<Permill>;
+
+        /// Re-ML incentive pool account (see pallet-reml-incentives)
+        type IncentivePool: Get<Self::AccountId>;
+
+        /// Number of archived vault keys kept; older entries are pruned
+        #[pallet::constant]
+        type MaxArchivedKeys: Get<u32>;
+
+        /// Blocks a vault's `daily_limit` applies over (24 hours of blocks)
+        #[pallet::constant]
+        type SpendingWindow: Get<BlockNumberFor<Self>>;
+
+        /// Blocks per era of the usage statistics returned by `era_stats`
+        #[pallet::constant]
+        type EraLength: Get<BlockNumberFor<Self>>;
+
+        /// Number of eras of usage statistics kept; older eras are overwritten
+        #[pallet::constant]
+        type MaxStatsEras: Get<u32>;
     }
 
     // ═══════════════════════════════════════════════════════════════════════════
@@ -154,6 +674,53 @@ pub mod pallet {
     pub type VaultNonces<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
 
+    /// Nonces used out of order: bit `i` set means `VaultNonces + i` is used
+    #[pallet::storage]
+    pub type VaultUsedNonces<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+
+    /// Premium mode of each vault; absent means `PremiumMode::Flat`
+    #[pallet::storage]
+    pub type VaultPremiumModes<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, PremiumMode, ValueQuery>;
+
+    /// Security level of each vault's key; absent means `SecurityLevel::Level2`
+    #[pallet::storage]
+    pub type VaultSecurityLevels<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, SecurityLevel, ValueQuery>;
+
+    /// Spenders each vault approved with `vault_approve` and what they may still pull
+    #[pallet::storage]
+    pub type VaultAllowances<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<(T::AccountId, BalanceOf<T>), ConstU32<MAX_VAULT_SPENDERS>>,
+        ValueQuery,
+    >;
+
+    /// Spending policy of each vault; absent means unrestricted
+    #[pallet::storage]
+    pub type VaultPolicies<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, VaultPolicyOf<T>, OptionQuery>;
+
+    /// Amount each vault with a daily limit sent per bucket of the
+    /// `SpendingWindow`, keyed by the bucket's first block, oldest first
+    #[pallet::storage]
+    pub type VaultSpending<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<(BlockNumberFor<T>, BalanceOf<T>), ConstU32<{ SPENDING_WINDOW_BUCKETS + 1 }>>,
+        ValueQuery,
+    >;
+
+    /// Message mode and context string of each vault; absent means
+    /// `MessageMode::Raw` without a context
+    #[pallet::storage]
+    pub type VaultMessageModes<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, (MessageMode, BoundedContext), OptionQuery>;
+
     /// Total number of active vaults
     #[pallet::storage]
     #[pallet::getter(fn total_vaults)]
@@ -165,6 +732,47 @@ pub mod pallet {
     #[pallet::getter(fn total_fees_collected)]
     pub type TotalFeesCollected<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
 
+    /// Usage statistics of the last `MaxStatsEras` eras, a ring buffer keyed
+    /// by `era % MaxStatsEras`
+    ///
+    /// A slot still holding an older era is reset on the first write of the
+    /// current one.
+    #[pallet::storage]
+    pub type EraStatsRing<T: Config> =
+        StorageMap<_, Twox64Concat, u32, EraStats<BalanceOf<T>>, OptionQuery>;
+
+    /// Block each vault's current key became valid
+    #[pallet::storage]
+    pub type VaultKeyValidFrom<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>, ValueQuery>;
+
+    /// Keys of destroyed vaults by archive index, the last `MaxArchivedKeys` kept
+    #[pallet::storage]
+    pub type ArchivedKeys<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        u64,
+        ArchivedVaultKey<T::AccountId, BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// Latest archive index of each public key hash
+    #[pallet::storage]
+    pub type ArchivedKeyIndex<T: Config> = StorageMap<_, Identity, [u8; 32], u64, OptionQuery>;
+
+    /// Archive index the next archived key gets
+    #[pallet::storage]
+    pub type NextArchiveIndex<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// Denied Dilithium public key hashes and the block they were denied at
+    #[pallet::storage]
+    pub type DeniedKeys<T: Config> =
+        StorageMap<_, Identity, [u8; 32], BlockNumberFor<T>, OptionQuery>;
+
+    /// Whether vault creation, transfers and destruction are paused
+    #[pallet::storage]
+    pub type VaultsPaused<T: Config> = StorageValue<_, bool, ValueQuery>;
+
     // ═══════════════════════════════════════════════════════════════════════════
     // EVENTS
     // ═══════════════════════════════════════════════════════════════════════════
@@ -177,8 +785,16 @@ pub mod pallet {
             who: T::AccountId,
             public_key_hash: [u8; 32],
         },
+        /// A vault was created with a non-default premium mode
+        VaultPremiumModeSet { who: T::AccountId, mode: PremiumMode },
+        /// A vault was created with a key other than `SecurityLevel::Level2`
+        VaultSecurityLevelSet { who: T::AccountId, level: SecurityLevel },
+        /// A vault was created whose signer signs FIPS 204 messages
+        VaultMessageModeSet { who: T::AccountId, mode: MessageMode, context: BoundedContext },
         /// A vault was destroyed (account unlocked)
         VaultDestroyed { who: T::AccountId },
+        /// A vault's key was moved to the archive
+        VaultKeyArchived { who: T::AccountId, public_key_hash: [u8; 32], index: u64 },
         /// A transfer was executed from a vault
         VaultTransfer {
             from: T::AccountId,
@@ -188,15 +804,40 @@ pub mod pallet {
             premium_fee: BalanceOf<T>,
             request_id: Option<u64>,
         },
+        /// A vault set `spender`'s allowance; `limit` 0 revoked it
+        VaultApproval { vault: T::AccountId, spender: T::AccountId, limit: BalanceOf<T> },
+        /// A vault set its spending policy
+        VaultPolicySet { vault: T::AccountId, policy: VaultPolicyOf<T> },
+        /// A vault removed its spending policy
+        VaultPolicyCleared { vault: T::AccountId },
+        /// A spender moved funds out of a vault under its allowance
+        VaultTransferFrom {
+            vault: T::AccountId,
+            spender: T::AccountId,
+            to: T::AccountId,
+            amount: BalanceOf<T>,
+            premium_fee: BalanceOf<T>,
+        },
         /// A vault transfer was verified via Re-ML
         VaultTransferVerified { from: T::AccountId, request_id: u64 },
-        /// Fees were collected and sent to treasury
+        /// Fees were collected and sent to treasury (premiums less the
+        /// incentive pool share)
         /// reason: 0 = VaultCreation, 1 = VaultTransferPremium
         FeesCollected {
             from: T::AccountId,
             amount: BalanceOf<T>,
             reason: u8,
         },
+        /// Vault operations were paused or resumed
+        VaultsPausedSet { paused: bool },
+        /// A public key hash was added to the deny-list
+        KeyDenied { public_key_hash: [u8; 32] },
+        /// A public key hash was removed from the deny-list
+        KeyAllowed { public_key_hash: [u8; 32] },
+        /// An existing vault is secured by a denied key and should move its funds
+        DeniedKeyInUse { who: T::AccountId, public_key_hash: [u8; 32] },
+        /// A vault was created and funded by another account
+        VaultCreatedFor { who: T::AccountId, funder: T::AccountId, initial_deposit: BalanceOf<T> },
     }
 
     // Fee reason constants for events
@@ -225,6 +866,8 @@ pub mod pallet {
         SignatureVerificationFailed,
         /// Invalid nonce (replay attack prevention)
         InvalidNonce,
+        /// Nonce is `NONCE_WINDOW` or more past the vault's lowest unused nonce
+        NonceOutsideWindow,
         /// Vault accounts cannot use standard transfers
         VaultAccountBlocked,
         /// Transfer amount exceeds available balance
@@ -239,6 +882,29 @@ pub mod pallet {
         ReMLVerificationRequired,
         /// Request ID not found in Re-ML verifier
         RequestNotVerified,
+        /// Vault operations are paused
+        VaultsPaused,
+        /// Public key is on the deny-list
+        KeyDenied,
+        /// Public key is not on the deny-list
+        KeyNotDenied,
+        /// Vault descriptor version is not supported
+        UnsupportedDescriptorVersion,
+        /// Target's acceptance signature does not match the public key
+        AcceptanceVerificationFailed,
+        /// `MessageMode::Raw` cannot carry a context string
+        ContextWithoutMessageMode,
+        /// Spender has no allowance from the vault, or less than the amount
+        AllowanceExceeded,
+        /// Vault already has allowances for `MAX_VAULT_SPENDERS` spenders
+        TooManySpenders,
+        /// Transfer would take the vault past its daily spending limit
+        DailyLimitExceeded,
+        /// Recipient is not on the vault's whitelist
+        RecipientNotWhitelisted,
+        /// The host function and the in-runtime verifier disagree on a
+        /// signature (`double-verification` builds only)
+        VerificationPathsDiverged,
     }
 
     // ═══════════════════════════════════════════════════════════════════════════
@@ -267,57 +933,8 @@ pub mod pallet {
         #[pallet::weight(<T as Config>::WeightInfo::create_vault())]
         pub fn create_vault(origin: OriginFor<T>, public_key: Vec<u8>) -> DispatchResult {
             let who = ensure_signed(origin)?;
-
-            // Check not already a vault
-            ensure!(!Vaults::<T>::contains_key(&who), Error::<T>::AlreadyVault);
-
-            // Validate public key size
-            ensure!(
-                public_key.len() == DILITHIUM_PUBLIC_KEY_SIZE,
-                Error::<T>::InvalidPublicKey
-            );
-
-            // Convert to bounded vec
-            let bounded_key: BoundedPublicKey<T> = public_key
-                .try_into()
-                .map_err(|_| Error::<T>::PublicKeyTooLarge)?;
-
-            // Charge creation fee - send to treasury instead of burning
-            // This preserves the limited TSRX supply
-            let fee = T::VaultCreationFee::get();
-            let treasury = T::TreasuryAccount::get();
-
-            T::Currency::transfer(&who, &treasury, fee, ExistenceRequirement::KeepAlive)?;
-
-            // Track total fees collected
-            TotalFeesCollected::<T>::mutate(|total| *total = total.saturating_add(fee));
-
-            // Hash public key for event (privacy)
-            let public_key_hash = sp_core::blake2_256(bounded_key.as_slice());
-
-            // Store vault
-            Vaults::<T>::insert(&who, bounded_key);
-            VaultNonces::<T>::insert(&who, 0u64);
-            TotalVaults::<T>::mutate(|n| *n = n.saturating_add(1));
-
-            // Emit events
-            Self::deposit_event(Event::FeesCollected {
-                from: who.clone(),
-                amount: fee,
-                reason: FEE_REASON_VAULT_CREATION,
-            });
-            Self::deposit_event(Event::VaultCreated {
-                who,
-                public_key_hash,
-            });
-
-            log::info!(
-                target: "quantum-vault",
-                "🔐 Quantum Vault created. Public key hash: 0x{}",
-                hex::encode(public_key_hash)
-            );
-
-            Ok(())
+            ensure!(!VaultsPaused::<T>::get(), Error::<T>::VaultsPaused);
+            Self::do_create_vault(who, public_key, SecurityLevel::Level2, PremiumMode::Flat)
         }
 
         /// Destroy a quantum vault and unlock the account
@@ -326,7 +943,8 @@ pub mod pallet {
         /// private key. Once destroyed, standard transfers are allowed again.
         ///
         /// # Arguments
-        /// * `signature` - Dilithium signature of message "DESTROY_VAULT:{nonce}"
+        /// * `signature` - Dilithium signature of the `Destroy` payload
+        ///   (`tesserax_vault_payload::destroy`) for the current nonce
         ///
         /// # Errors
         /// * `NotVault` - Account is not a vault
@@ -335,15 +953,17 @@ pub mod pallet {
         #[pallet::weight(<T as Config>::WeightInfo::destroy_vault())]
         pub fn destroy_vault(origin: OriginFor<T>, signature: Vec<u8>) -> DispatchResult {
             let who = ensure_signed(origin)?;
+            let _span =
+                sp_tracing::span!(target: TRACE_TARGET, sp_tracing::Level::DEBUG, "destroy_vault")
+                    .entered();
+            ensure!(!VaultsPaused::<T>::get(), Error::<T>::VaultsPaused);
 
             // Check is a vault
             let _public_key = Vaults::<T>::get(&who).ok_or(Error::<T>::NotVault)?;
+            let level = VaultSecurityLevels::<T>::get(&who);
 
             // Validate signature size
-            ensure!(
-                signature.len() == DILITHIUM_SIGNATURE_SIZE,
-                Error::<T>::InvalidSignature
-            );
+            ensure!(signature.len() == level.signature_size(), Error::<T>::InvalidSignature);
 
             // Get current nonce
             let nonce = VaultNonces::<T>::get(&who);
@@ -351,13 +971,23 @@ pub mod pallet {
             // Construct message that was signed
             let message = Self::construct_destroy_message(&who, nonce);
 
-            // Verify signature (placeholder - in production use real Dilithium verification)
-            Self::verify_dilithium_signature(&_public_key, &message, &signature)?;
+            // Verify signature in the vault's message mode
+            Self::verify_vault_signature(&who, level, &_public_key, &message, &signature)?;
 
-            // Remove vault
+            // Keep the key for attributing past signatures, then remove vault
+            let used_ahead = VaultUsedNonces::<T>::take(&who).count_ones() as u64;
+            Self::archive_key(&who, &_public_key, nonce.saturating_add(used_ahead));
             Vaults::<T>::remove(&who);
             VaultNonces::<T>::remove(&who);
+            VaultPremiumModes::<T>::remove(&who);
+            VaultSecurityLevels::<T>::remove(&who);
+            VaultMessageModes::<T>::remove(&who);
+            VaultAllowances::<T>::remove(&who);
+            VaultPolicies::<T>::remove(&who);
+            VaultSpending::<T>::remove(&who);
+            VaultKeyValidFrom::<T>::remove(&who);
             TotalVaults::<T>::mutate(|n| *n = n.saturating_sub(1));
+            Self::note_era_stats(|stats| stats.destroys = stats.destroys.saturating_add(1));
 
             // Emit event
             Self::deposit_event(Event::VaultDestroyed { who });
@@ -370,24 +1000,34 @@ pub mod pallet {
         /// Execute a transfer from a vault account
         ///
         /// This is the only way to transfer funds from a vault account.
-        /// Requires a valid Dilithium signature of the transfer details.
-        /// Optionally requires Re-ML verification via request_id.
+        /// Requires a valid Dilithium signature of the transfer details, or a
+        /// Re-ML request proving one via request_id.
         ///
         /// # Arguments
-        /// * `signature` - Dilithium signature of "TRANSFER:{to}:{amount}:{nonce}"
+        /// * `signature` - Dilithium signature of the `Transfer` payload
+        ///   (`tesserax_vault_payload::transfer`); ignored, and may be empty,
+        ///   when `request_id` proves it
         /// * `to` - Destination account
         /// * `amount` - Amount to transfer
         /// * `request_id` - Optional Re-ML request ID for quantum-safe verification
         ///
         /// # Fees
-        /// * Premium fee = VaultTransferBaseFee × VaultTransferFeeMultiplier
-        /// * Default: 0.01 TSRX × 100 = 1 TSRX per vault transfer
+        /// * Flat mode: VaultTransferBaseFee × VaultTransferFeeMultiplier
+        /// * Percentage mode: VaultTransferPremiumRate × amount, capped at VaultTransferPremiumCap
+        /// * Recipient is a vault: VaultToVaultPremiumDiscount off the premium
+        /// * Of that, InBandPremiumShare is charged, or ProofBackedPremiumShare
+        ///   when `request_id` proves the signature
         /// * Fee is sent to protocol treasury
         ///
         /// # Re-ML Integration
-        /// If `request_id` is provided, the transfer will be verified against
-        /// the Re-ML verifier pallet. This enables EVM smart contracts to
-        /// enforce quantum-safe transfer requirements.
+        /// If `request_id` is provided, the request must be verified by the
+        /// Re-ML verifier pallet and signed by this vault's key, and is
+        /// consumed, so it backs one transfer only. This enables EVM smart
+        /// contracts to enforce quantum-safe transfer requirements.
+        ///
+        /// If the request's proof also committed the hash of this transfer's
+        /// signed message, the proof stands in for the Dilithium signature,
+        /// which isn't checked. Otherwise the signature is verified as well.
         ///
         /// # Errors
         /// * `NotVault` - Sender is not a vault
@@ -395,6 +1035,10 @@ pub mod pallet {
         /// * `InsufficientBalance` - Not enough balance for transfer
         /// * `InsufficientBalanceForPremium` - Not enough balance for premium fee
         /// * `RequestNotVerified` - Re-ML request ID not verified
+        /// * `RequestAlreadyConsumed` - Re-ML request ID already used by a transfer
+        /// * `NotRequestOwner` - Re-ML request was not signed by this vault's key
+        /// * `RecipientNotWhitelisted` - Vault's policy does not allow `to`
+        /// * `DailyLimitExceeded` - Vault's policy limit for the window is used up
         #[pallet::call_index(2)]
         #[pallet::weight(<T as Config>::WeightInfo::vault_transfer())]
         pub fn vault_transfer(
@@ -405,85 +1049,931 @@ pub mod pallet {
             request_id: Option<u64>,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
-
-            // Check is a vault
-            let public_key = Vaults::<T>::get(&who).ok_or(Error::<T>::NotVault)?;
-
-            // Validate signature size
-            ensure!(
-                signature.len() == DILITHIUM_SIGNATURE_SIZE,
-                Error::<T>::InvalidSignature
-            );
-
-            // Get and increment nonce
             let nonce = VaultNonces::<T>::get(&who);
+            Self::do_vault_transfer(who, signature, to, amount, nonce, request_id)
+        }
 
-            // Construct message that was signed
-            let message = Self::construct_transfer_message(&who, &to, amount, nonce);
+        /// Create a quantum vault with a chosen transfer premium mode
+        ///
+        /// Same as `create_vault`, but `PremiumMode::Percentage` vaults pay
+        /// `VaultTransferPremiumRate × amount` (capped at `VaultTransferPremiumCap`)
+        /// per transfer instead of the flat premium. The mode is fixed for the
+        /// lifetime of the vault.
+        ///
+        /// # Errors
+        /// Same as `create_vault`.
+        #[pallet::call_index(3)]
+        #[pallet::weight(<T as Config>::WeightInfo::create_vault_with_premium_mode())]
+        pub fn create_vault_with_premium_mode(
+            origin: OriginFor<T>,
+            public_key: Vec<u8>,
+            premium_mode: PremiumMode,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(!VaultsPaused::<T>::get(), Error::<T>::VaultsPaused);
+            Self::do_create_vault(who, public_key, SecurityLevel::Level2, premium_mode)
+        }
 
-            // Verify signature
-            Self::verify_dilithium_signature(&public_key, &message, &signature)?;
+        /// Pause or resume vault operations (sudo only)
+        ///
+        /// While paused, `create_vault`, `create_vault_with_premium_mode`,
+        /// `destroy_vault` and `vault_transfer` fail with `VaultsPaused`, and
+        /// the runtime rejects them at transaction pool admission.
+        #[pallet::call_index(4)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_paused())]
+        pub fn set_paused(origin: OriginFor<T>, paused: bool) -> DispatchResult {
+            ensure_root(origin)?;
+            VaultsPaused::<T>::put(paused);
+            Self::deposit_event(Event::VaultsPausedSet { paused });
+            Ok(())
+        }
 
-            // Re-ML Integration: If request_id is provided, verify it
-            if let Some(req_id) = request_id {
-                ensure!(
-                    pallet_reml_verifier::Pallet::<T>::is_request_verified(req_id),
-                    Error::<T>::RequestNotVerified
-                );
+        /// Execute a transfer from a vault signed for a specific nonce
+        ///
+        /// Same as `vault_transfer`, but `nonce` may be any unused nonce below
+        /// `vault_nonce + NONCE_WINDOW`, so an offline signer can pre-sign
+        /// transfers with nonces N, N+1, N+2, ... and they can be submitted in
+        /// any order. The signed message is unchanged.
+        ///
+        /// # Errors
+        /// Same as `vault_transfer`, plus:
+        /// * `InvalidNonce` - Nonce already used
+        /// * `NonceOutsideWindow` - Nonce too far ahead
+        #[pallet::call_index(5)]
+        #[pallet::weight(<T as Config>::WeightInfo::vault_transfer())]
+        pub fn vault_transfer_with_nonce(
+            origin: OriginFor<T>,
+            signature: Vec<u8>,
+            to: T::AccountId,
+            #[pallet::compact] amount: BalanceOf<T>,
+            nonce: u64,
+            request_id: Option<u64>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_vault_transfer(who, signature, to, amount, nonce, request_id)
+        }
 
-                log::info!(
-                    target: "quantum-vault",
-                    "✅ Re-ML verification passed for request ID: {}",
-                    req_id
-                );
+        /// Add a public key hash to the deny-list (sudo only)
+        ///
+        /// New vaults can no longer be created with the key. Existing vaults
+        /// keep working, but each of `vaults` secured by the key is flagged with
+        /// a `DeniedKeyInUse` event; find them off-chain through the
+        /// `VaultCreated` events carrying `public_key_hash`. Calling it again
+        /// for a denied key only flags further vaults.
+        #[pallet::call_index(6)]
+        #[pallet::weight(<T as Config>::WeightInfo::deny_key(vaults.len() as u32))]
+        pub fn deny_key(
+            origin: OriginFor<T>,
+            public_key_hash: [u8; 32],
+            vaults: BoundedVec<T::AccountId, ConstU32<MAX_FLAGGED_VAULTS>>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
 
-                // Emit verification event
-                Self::deposit_event(Event::VaultTransferVerified {
-                    from: who.clone(),
-                    request_id: req_id,
-                });
+            if !DeniedKeys::<T>::contains_key(public_key_hash) {
+                DeniedKeys::<T>::insert(public_key_hash, frame_system::Pallet::<T>::block_number());
+                Self::deposit_event(Event::KeyDenied { public_key_hash });
             }
 
-            // Calculate premium fee: base_fee × multiplier
-            // This goes to treasury as security premium for using quantum vault
-            let base_fee = T::VaultTransferBaseFee::get();
-            let multiplier = T::VaultTransferFeeMultiplier::get();
-            let premium_fee = base_fee.saturating_mul(multiplier.into());
-            let treasury = T::TreasuryAccount::get();
+            for who in vaults {
+                let uses_key = Vaults::<T>::get(&who)
+                    .is_some_and(|key| sp_core::blake2_256(key.as_slice()) == public_key_hash);
+                if uses_key {
+                    Self::deposit_event(Event::DeniedKeyInUse { who, public_key_hash });
+                }
+            }
+            Ok(())
+        }
 
-            // Ensure user can pay both the transfer amount AND the premium fee
-            let total_required = amount.saturating_add(premium_fee);
-            let balance = T::Currency::free_balance(&who);
+        /// Remove a public key hash from the deny-list (sudo only)
+        #[pallet::call_index(7)]
+        #[pallet::weight(<T as Config>::WeightInfo::allow_key())]
+        pub fn allow_key(origin: OriginFor<T>, public_key_hash: [u8; 32]) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(DeniedKeys::<T>::contains_key(public_key_hash), Error::<T>::KeyNotDenied);
+
+            DeniedKeys::<T>::remove(public_key_hash);
+            Self::deposit_event(Event::KeyAllowed { public_key_hash });
+            Ok(())
+        }
+
+        /// Create a vault for the caller from a `VaultDescriptor`
+        ///
+        /// Takes the key, scheme and premium mode from a descriptor exported with
+        /// `vault_descriptor`, so the same policy can be applied to many
+        /// accounts.
+        ///
+        /// # Errors
+        /// Same as `create_vault`, plus:
+        /// * `UnsupportedDescriptorVersion` - Descriptor from a newer format
+        #[pallet::call_index(8)]
+        #[pallet::weight(<T as Config>::WeightInfo::create_vault_with_security_level())]
+        pub fn create_vault_from_descriptor(
+            origin: OriginFor<T>,
+            descriptor: VaultDescriptor,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(!VaultsPaused::<T>::get(), Error::<T>::VaultsPaused);
             ensure!(
-                balance >= total_required,
-                Error::<T>::InsufficientBalanceForPremium
+                descriptor.version == VAULT_DESCRIPTOR_VERSION,
+                Error::<T>::UnsupportedDescriptorVersion
             );
+            Self::do_create_vault(
+                who,
+                descriptor.public_key,
+                descriptor.scheme.into(),
+                descriptor.premium_mode,
+            )
+        }
 
-            // Charge premium fee first (to treasury)
-            if !premium_fee.is_zero() {
-                T::Currency::transfer(
-                    &who,
-                    &treasury,
-                    premium_fee,
-                    ExistenceRequirement::KeepAlive,
-                )?;
+        /// Create and fund a vault for `target` in one transaction
+        ///
+        /// Lets an employer or custodian set up a vault for a user. The caller
+        /// pays the creation fee and transfers `initial_deposit` to `target`.
+        /// `target` consents by signing the `Accept` payload
+        /// (`tesserax_vault_payload::accept`) with the vault key; it covers the
+        /// target's current account nonce, so it cannot be replayed once the
+        /// target has transacted, e.g. to destroy the vault.
+        ///
+        /// # Arguments
+        /// * `target` - Account that becomes the vault
+        /// * `public_key` - Target's Dilithium public key
+        /// * `acceptance` - Target's Dilithium signature of the acceptance message
+        /// * `initial_deposit` - Amount moved from the caller to `target`
+        ///
+        /// # Errors
+        /// Same as `create_vault`, plus:
+        /// * `AcceptanceVerificationFailed` - Acceptance not signed by the key
+        #[pallet::call_index(9)]
+        #[pallet::weight(<T as Config>::WeightInfo::create_vault_for())]
+        pub fn create_vault_for(
+            origin: OriginFor<T>,
+            target: T::AccountId,
+            public_key: Vec<u8>,
+            acceptance: Vec<u8>,
+            #[pallet::compact] initial_deposit: BalanceOf<T>,
+        ) -> DispatchResult {
+            let funder = ensure_signed(origin)?;
+            ensure!(!VaultsPaused::<T>::get(), Error::<T>::VaultsPaused);
+            ensure!(!Vaults::<T>::contains_key(&target), Error::<T>::AlreadyVault);
+            ensure!(
+                public_key.len() == DILITHIUM_PUBLIC_KEY_SIZE,
+                Error::<T>::InvalidPublicKey
+            );
 
-                // Track total fees collected
-                TotalFeesCollected::<T>::mutate(|total| *total = total.saturating_add(premium_fee));
+            let bounded_key: BoundedPublicKey<T> =
+                public_key.clone().try_into().map_err(|_| Error::<T>::PublicKeyTooLarge)?;
+            let message = Self::construct_acceptance_message(
+                &target,
+                &funder,
+                &sp_core::blake2_256(&public_key),
+                initial_deposit,
+            );
+            Self::verify_dilithium_signature(
+                SecurityLevel::Level2,
+                &bounded_key,
+                &message,
+                &acceptance,
+            )
+            .map_err(|_| Error::<T>::AcceptanceVerificationFailed)?;
+
+            T::Currency::transfer(
+                &funder,
+                &target,
+                initial_deposit,
+                ExistenceRequirement::KeepAlive,
+            )?;
+            Self::do_create_vault_paid_by(
+                target.clone(),
+                &funder,
+                public_key,
+                SecurityLevel::Level2,
+                PremiumMode::Flat,
+            )?;
+
+            Self::deposit_event(Event::VaultCreatedFor { who: target, funder, initial_deposit });
+            Ok(())
+        }
 
-                // Emit fee collection event
-                Self::deposit_event(Event::FeesCollected {
+        /// Create a quantum vault whose signer signs FIPS 204 messages
+        ///
+        /// Same as `create_vault_with_premium_mode`, but the vault's
+        /// signatures are checked over `message_mode`'s encoding of each
+        /// payload with `context`, so HSMs that only do (Hash)ML-DSA with a
+        /// context string can secure a vault. The mode is fixed for the
+        /// lifetime of the vault.
+        ///
+        /// # Errors
+        /// Same as `create_vault`, plus:
+        /// * `ContextWithoutMessageMode` - Context given for `MessageMode::Raw`
+        #[pallet::call_index(10)]
+        #[pallet::weight(<T as Config>::WeightInfo::create_vault_with_message_mode())]
+        pub fn create_vault_with_message_mode(
+            origin: OriginFor<T>,
+            public_key: Vec<u8>,
+            premium_mode: PremiumMode,
+            message_mode: MessageMode,
+            context: BoundedContext,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(!VaultsPaused::<T>::get(), Error::<T>::VaultsPaused);
+            ensure!(
+                message_mode != MessageMode::Raw || context.is_empty(),
+                Error::<T>::ContextWithoutMessageMode
+            );
+
+            Self::do_create_vault(who.clone(), public_key, SecurityLevel::Level2, premium_mode)?;
+            if message_mode != MessageMode::Raw {
+                VaultMessageModes::<T>::insert(&who, (message_mode, context.clone()));
+                Self::deposit_event(Event::VaultMessageModeSet {
+                    who,
+                    mode: message_mode,
+                    context,
+                });
+            }
+            Ok(())
+        }
+
+        /// Create a quantum vault secured by a Dilithium3 or Dilithium5 key
+        ///
+        /// Same as `create_vault_with_premium_mode`, but `public_key` is a key
+        /// of `security_level`, and the vault's signatures are checked with that
+        /// level's parameter set. Creation costs `VaultCreationFee` times the
+        /// level's `creation_fee_multiplier`. The level is fixed for the
+        /// lifetime of the vault.
+        ///
+        /// # Errors
+        /// Same as `create_vault`; `InvalidPublicKey` if `public_key` is not
+        /// a `security_level` key.
+        #[pallet::call_index(11)]
+        #[pallet::weight(<T as Config>::WeightInfo::create_vault_with_security_level())]
+        pub fn create_vault_with_security_level(
+            origin: OriginFor<T>,
+            public_key: Vec<u8>,
+            security_level: SecurityLevel,
+            premium_mode: PremiumMode,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(!VaultsPaused::<T>::get(), Error::<T>::VaultsPaused);
+            Self::do_create_vault(who, public_key, security_level, premium_mode)
+        }
+
+        /// Let `spender` move up to `limit` out of the caller's vault
+        ///
+        /// Lets an escrow or DEX account pull vault funds with
+        /// `vault_transfer_from` without a Dilithium signature per
+        /// interaction. The allowance replaces any earlier one for `spender`;
+        /// `limit` 0 revokes it. Consumes the vault's current nonce.
+        ///
+        /// # Arguments
+        /// * `spender` - Account allowed to pull funds
+        /// * `limit` - Most it may move in total, premiums not included
+        /// * `signature` - Dilithium signature of the `Approve` payload
+        ///   (`tesserax_vault_payload::approve`) for the current nonce
+        ///
+        /// # Errors
+        /// * `NotVault` - Caller is not a vault
+        /// * `SignatureVerificationFailed` - Invalid signature
+        /// * `TooManySpenders` - Vault already has `MAX_VAULT_SPENDERS` allowances
+        #[pallet::call_index(12)]
+        #[pallet::weight(<T as Config>::WeightInfo::vault_approve())]
+        pub fn vault_approve(
+            origin: OriginFor<T>,
+            spender: T::AccountId,
+            #[pallet::compact] limit: BalanceOf<T>,
+            signature: Vec<u8>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(!VaultsPaused::<T>::get(), Error::<T>::VaultsPaused);
+
+            let public_key = Vaults::<T>::get(&who).ok_or(Error::<T>::NotVault)?;
+            let level = VaultSecurityLevels::<T>::get(&who);
+            ensure!(signature.len() == level.signature_size(), Error::<T>::InvalidSignature);
+
+            let nonce = VaultNonces::<T>::get(&who);
+            let nonce_offset = Self::nonce_offset(&who, nonce)?;
+            let message = Self::construct_approval_message(&who, &spender, limit, nonce);
+            Self::verify_vault_signature(&who, level, &public_key, &message, &signature)?;
+
+            VaultAllowances::<T>::try_mutate(&who, |allowances| {
+                allowances.retain(|(approved, _)| approved != &spender);
+                if limit.is_zero() {
+                    return Ok(());
+                }
+                allowances
+                    .try_push((spender.clone(), limit))
+                    .map_err(|_| Error::<T>::TooManySpenders)
+            })?;
+            Self::use_nonce(&who, nonce_offset);
+
+            Self::deposit_event(Event::VaultApproval { vault: who, spender, limit });
+            Ok(())
+        }
+
+        /// Move `amount` out of `vault` under the caller's allowance
+        ///
+        /// The allowance goes down by `amount`. The vault pays the transfer
+        /// premium as for `vault_transfer`, on top of the allowance.
+        ///
+        /// # Errors
+        /// * `NotVault` - `vault` is not a vault
+        /// * `AllowanceExceeded` - Caller may not move `amount` out of `vault`
+        /// * `InsufficientBalanceForPremium` - Vault cannot pay amount and premium
+        #[pallet::call_index(13)]
+        #[pallet::weight(<T as Config>::WeightInfo::vault_transfer_from())]
+        pub fn vault_transfer_from(
+            origin: OriginFor<T>,
+            vault: T::AccountId,
+            to: T::AccountId,
+            #[pallet::compact] amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let spender = ensure_signed(origin)?;
+            ensure!(!VaultsPaused::<T>::get(), Error::<T>::VaultsPaused);
+            ensure!(Vaults::<T>::contains_key(&vault), Error::<T>::NotVault);
+
+            VaultAllowances::<T>::try_mutate(&vault, |allowances| {
+                let index = allowances
+                    .iter()
+                    .position(|(approved, _)| approved == &spender)
+                    .ok_or(Error::<T>::AllowanceExceeded)?;
+                let remaining = allowances[index]
+                    .1
+                    .checked_sub(&amount)
+                    .ok_or(Error::<T>::AllowanceExceeded)?;
+                if remaining.is_zero() {
+                    allowances.remove(index);
+                } else {
+                    allowances[index].1 = remaining;
+                }
+                Ok::<_, Error<T>>(())
+            })?;
+
+            let premium_fee = Self::pay_from_vault(&vault, &to, amount, None, false)?;
+            Self::deposit_event(Event::VaultTransferFrom {
+                vault,
+                spender,
+                to,
+                amount,
+                premium_fee,
+            });
+            Ok(())
+        }
+
+        /// Set the caller's spending policy, signed with its vault key
+        ///
+        /// Transfers out of the vault, `vault_transfer_from` included, then
+        /// may send at most `policy.daily_limit` in any `SpendingWindow` and
+        /// only to accounts on `policy.whitelist`. An unrestricted policy
+        /// removes it. What the vault sent in the current window keeps
+        /// counting against a new limit.
+        ///
+        /// # Arguments
+        /// * `signature` - Dilithium signature of the `Policy` payload
+        ///   (`tesserax_vault_payload::policy`) for the current nonce
+        ///
+        /// # Errors
+        /// * `NotVault` - Caller is not a vault
+        /// * `SignatureVerificationFailed` - Invalid signature
+        #[pallet::call_index(14)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_vault_policy())]
+        pub fn set_vault_policy(
+            origin: OriginFor<T>,
+            policy: VaultPolicyOf<T>,
+            signature: Vec<u8>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(!VaultsPaused::<T>::get(), Error::<T>::VaultsPaused);
+
+            Self::use_vault_signature(
+                &who,
+                |nonce| Self::construct_policy_message(&who, &policy, nonce),
+                &signature,
+            )?;
+
+            if policy.is_unrestricted() {
+                VaultPolicies::<T>::remove(&who);
+                VaultSpending::<T>::remove(&who);
+                Self::deposit_event(Event::VaultPolicyCleared { vault: who });
+            } else {
+                VaultPolicies::<T>::insert(&who, &policy);
+                Self::deposit_event(Event::VaultPolicySet { vault: who, policy });
+            }
+            Ok(())
+        }
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // HELPER FUNCTIONS
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    impl<T: Config> Pallet<T> {
+        /// Whether vault operations are paused
+        pub fn is_paused() -> bool {
+            VaultsPaused::<T>::get()
+        }
+
+        /// Whether `public_key_hash` is on the deny-list
+        pub fn is_key_denied(public_key_hash: &[u8; 32]) -> bool {
+            DeniedKeys::<T>::contains_key(public_key_hash)
+        }
+
+        /// Whether `account` has already used `nonce`
+        ///
+        /// Nonces past the end of the window count as unused.
+        pub fn nonce_used(account: &T::AccountId, nonce: u64) -> bool {
+            let base = VaultNonces::<T>::get(account);
+            match nonce.checked_sub(base) {
+                None => true,
+                Some(offset) if offset < NONCE_WINDOW => {
+                    VaultUsedNonces::<T>::get(account) & (1 << offset) != 0
+                }
+                Some(_) => false,
+            }
+        }
+
+        /// Check if an account is a vault
+        pub fn is_vault(account: &T::AccountId) -> bool {
+            Vaults::<T>::contains_key(account)
+        }
+
+        /// What `spender` may still move out of `vault`
+        pub fn allowance(vault: &T::AccountId, spender: &T::AccountId) -> BalanceOf<T> {
+            VaultAllowances::<T>::get(vault)
+                .into_iter()
+                .find(|(approved, _)| approved == spender)
+                .map_or_else(Zero::zero, |(_, limit)| limit)
+        }
+
+        /// Get the public key of a vault (if exists)
+        pub fn get_vault_public_key(account: &T::AccountId) -> Option<BoundedPublicKey<T>> {
+            Vaults::<T>::get(account)
+        }
+
+        /// Whether `signature` is a valid ML-DSA-44 signature of `message` by `public_key`
+        ///
+        /// Same check as vault signatures, for callers outside the pallet such
+        /// as EVM precompiles.
+        pub fn verify_ml_dsa(public_key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+            BoundedPublicKey::<T>::try_from(public_key.to_vec()).is_ok_and(|public_key| {
+                Self::verify_dilithium_signature(
+                    SecurityLevel::Level2,
+                    &public_key,
+                    message,
+                    signature,
+                )
+                .is_ok()
+            })
+        }
+
+        /// Check `who`'s vault signature of a payload at its current nonce,
+        /// then use the nonce
+        ///
+        /// For pallets authorizing their own calls with vault keys, such as
+        /// pallet-announcements. `payload` builds the payload for a nonce.
+        ///
+        /// # Errors
+        /// * `NotVault` - `who` is not a vault
+        /// * `InvalidSignature` - Signature has the wrong size for the vault's level
+        /// * `SignatureVerificationFailed` - Invalid signature
+        pub fn use_vault_signature(
+            who: &T::AccountId,
+            payload: impl FnOnce(u64) -> Vec<u8>,
+            signature: &[u8],
+        ) -> DispatchResult {
+            let public_key = Vaults::<T>::get(who).ok_or(Error::<T>::NotVault)?;
+            let level = VaultSecurityLevels::<T>::get(who);
+            ensure!(signature.len() == level.signature_size(), Error::<T>::InvalidSignature);
+
+            let nonce = VaultNonces::<T>::get(who);
+            let nonce_offset = Self::nonce_offset(who, nonce)?;
+            Self::verify_vault_signature(who, level, &public_key, &payload(nonce), signature)?;
+            Self::use_nonce(who, nonce_offset);
+            Ok(())
+        }
+
+        /// Premium `who` pays on a vault transfer of `amount` to `to`
+        ///
+        /// Transfers into another vault get `VaultToVaultPremiumDiscount` off,
+        /// so funds are encouraged to stay inside PQC-protected accounts. Of
+        /// the result, `ProofBackedPremiumShare` is charged if a Re-ML proof
+        /// stands in for the transfer's signature, else `InBandPremiumShare`.
+        pub fn transfer_premium(
+            who: &T::AccountId,
+            to: &T::AccountId,
+            amount: BalanceOf<T>,
+            proof_backed: bool,
+        ) -> BalanceOf<T> {
+            let premium = match VaultPremiumModes::<T>::get(who) {
+                PremiumMode::Flat => T::VaultTransferBaseFee::get()
+                    .saturating_mul(T::VaultTransferFeeMultiplier::get().into()),
+                PremiumMode::Percentage => (T::VaultTransferPremiumRate::get() * amount)
+                    .min(T::VaultTransferPremiumCap::get()),
+            };
+
+            let premium = if Self::is_vault(to) {
+                premium.saturating_sub(T::VaultToVaultPremiumDiscount::get() * premium)
+            } else {
+                premium
+            };
+
+            let share = if proof_backed {
+                T::ProofBackedPremiumShare::get()
+            } else {
+                T::InBandPremiumShare::get()
+            };
+            share * premium
+        }
+
+        /// Premium `who` would be charged by `call`, zero unless it moves
+        /// funds out of a vault
+        ///
+        /// `vault_transfer_from` charges the vault it spends from, not `who`.
+        pub fn call_premium(who: &T::AccountId, call: &Call<T>) -> BalanceOf<T> {
+            match call {
+                Call::vault_transfer { to, amount, request_id, .. } => {
+                    let nonce = VaultNonces::<T>::get(who);
+                    let proof_backed = Self::proves_transfer(who, to, *amount, nonce, *request_id);
+                    Self::transfer_premium(who, to, *amount, proof_backed)
+                },
+                Call::vault_transfer_with_nonce { to, amount, nonce, request_id, .. } => {
+                    let proof_backed = Self::proves_transfer(who, to, *amount, *nonce, *request_id);
+                    Self::transfer_premium(who, to, *amount, proof_backed)
+                },
+                Call::vault_transfer_from { vault, to, amount } => {
+                    Self::transfer_premium(vault, to, *amount, false)
+                },
+                _ => Zero::zero(),
+            }
+        }
+
+        /// Creation fee the caller of `call` would pay, zero unless it
+        /// creates a vault
+        pub fn call_creation_fee(call: &Call<T>) -> BalanceOf<T> {
+            let level = match call {
+                Call::create_vault { .. }
+                | Call::create_vault_with_premium_mode { .. }
+                | Call::create_vault_with_message_mode { .. }
+                | Call::create_vault_for { .. } => SecurityLevel::Level2,
+                Call::create_vault_with_security_level { security_level, .. } => *security_level,
+                Call::create_vault_from_descriptor { descriptor } => descriptor.scheme.into(),
+                _ => return Zero::zero(),
+            };
+            Self::creation_fee(level)
+        }
+
+        /// Fee to create a vault at `level`
+        pub fn creation_fee(level: SecurityLevel) -> BalanceOf<T> {
+            T::VaultCreationFee::get().saturating_mul(level.creation_fee_multiplier().into())
+        }
+
+        /// Run `vault_transfer` against current state and roll it back
+        ///
+        /// Performs every check the extrinsic does, Dilithium verification and
+        /// balance checks included, and returns the premium it would charge or
+        /// the exact error it would fail with.
+        pub fn dry_run_transfer(
+            who: T::AccountId,
+            signature: Vec<u8>,
+            to: T::AccountId,
+            amount: BalanceOf<T>,
+            request_id: Option<u64>,
+        ) -> Result<BalanceOf<T>, DispatchError> {
+            let nonce = VaultNonces::<T>::get(&who);
+            let proof_backed = Self::proves_transfer(&who, &to, amount, nonce, request_id);
+            let premium = Self::transfer_premium(&who, &to, amount, proof_backed);
+            let origin = frame_system::RawOrigin::Signed(who).into();
+
+            with_transaction(|| {
+                let result = Self::vault_transfer(origin, signature, to, amount, request_id);
+                TransactionOutcome::Rollback(result.map(|()| premium))
+            })
+        }
+
+        /// Archived key with `public_key_hash`, if still retained
+        ///
+        /// If the same key secured several vaults over time, the most recently
+        /// archived one is returned.
+        pub fn archived_key(
+            public_key_hash: [u8; 32],
+        ) -> Option<ArchivedVaultKey<T::AccountId, BlockNumberFor<T>>> {
+            ArchivedKeyIndex::<T>::get(public_key_hash).and_then(ArchivedKeys::<T>::get)
+        }
+
+        /// Configuration of `account` as a `VaultDescriptor`; `None` if not a vault
+        pub fn vault_descriptor(account: &T::AccountId) -> Option<VaultDescriptor> {
+            Vaults::<T>::get(account).map(|public_key| VaultDescriptor {
+                version: VAULT_DESCRIPTOR_VERSION,
+                scheme: VaultSecurityLevels::<T>::get(account).into(),
+                public_key: public_key.into_inner(),
+                premium_mode: VaultPremiumModes::<T>::get(account),
+            })
+        }
+
+        /// State of vault `account`; `None` if not a vault
+        pub fn vault_info(
+            account: &T::AccountId,
+        ) -> Option<VaultInfo<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>> {
+            let public_key = Vaults::<T>::get(account)?;
+            let (message_mode, context) = VaultMessageModes::<T>::get(account).unwrap_or_default();
+            Some(VaultInfo {
+                public_key_hash: sp_core::blake2_256(&public_key),
+                scheme: VaultSecurityLevels::<T>::get(account).into(),
+                nonce: VaultNonces::<T>::get(account),
+                created_at: VaultKeyValidFrom::<T>::get(account),
+                premium_mode: VaultPremiumModes::<T>::get(account),
+                message_mode,
+                context: context.into_inner(),
+                policy: VaultPolicies::<T>::get(account),
+            })
+        }
+
+        /// Lowest unused vault nonce, the one `vault_transfer` signs; `None` if not a vault
+        pub fn vault_nonce(account: &T::AccountId) -> Option<u64> {
+            Self::is_vault(account).then(|| VaultNonces::<T>::get(account))
+        }
+
+        /// Payload `vault_transfer` verifies for a transfer of `amount` from
+        /// vault `from` to `to` at its current nonce; `None` if not a vault
+        ///
+        /// The bytes the vault key signs, or signs with the vault's context
+        /// string in a (Hash)ML-DSA message mode.
+        pub fn vault_transfer_payload(
+            from: &T::AccountId,
+            to: &T::AccountId,
+            amount: BalanceOf<T>,
+        ) -> Option<Vec<u8>> {
+            let nonce = Self::vault_nonce(from)?;
+            Some(Self::construct_transfer_message(from, to, amount, nonce))
+        }
+
+        /// Page through vaults in storage order
+        ///
+        /// Returns up to `limit` (capped at `MAX_PAGE_SIZE`) vaults after
+        /// `start_key`; pass the last account of a page to fetch the next one.
+        pub fn list_vaults(
+            start_key: Option<T::AccountId>,
+            limit: u32,
+        ) -> Vec<VaultSummary<T::AccountId>> {
+            let iter = match start_key {
+                Some(key) => Vaults::<T>::iter_from(Vaults::<T>::hashed_key_for(&key)),
+                None => Vaults::<T>::iter(),
+            };
+
+            iter.take(limit.min(MAX_PAGE_SIZE) as usize)
+                .map(|(account, public_key)| VaultSummary {
+                    nonce: VaultNonces::<T>::get(&account),
+                    public_key: public_key.into_inner(),
+                    account,
+                })
+                .collect()
+        }
+
+        /// Sum the balances of up to `limit` (capped at `MAX_VALUE_LOCKED_PAGE_SIZE`)
+        /// vaults after `start_key`
+        ///
+        /// Computed on demand rather than kept as a running total, since vault
+        /// balances also change through plain incoming transfers. Summing every
+        /// page gives the total value locked in quantum vaults.
+        pub fn value_locked(
+            start_key: Option<T::AccountId>,
+            limit: u32,
+        ) -> ValueLockedPage<T::AccountId, BalanceOf<T>> {
+            let iter = match start_key {
+                Some(key) => Vaults::<T>::iter_keys_from(Vaults::<T>::hashed_key_for(&key)),
+                None => Vaults::<T>::iter_keys(),
+            };
+
+            let mut page = ValueLockedPage {
+                value_locked: Zero::zero(),
+                vaults: 0,
+                last_key: None,
+            };
+            for account in iter.take(limit.min(MAX_VALUE_LOCKED_PAGE_SIZE) as usize) {
+                page.value_locked =
+                    page.value_locked.saturating_add(T::Currency::total_balance(&account));
+                page.vaults = page.vaults.saturating_add(1);
+                page.last_key = Some(account);
+            }
+            page
+        }
+
+        /// Usage statistics of the last `MaxStatsEras` eras, oldest first
+        ///
+        /// Eras without vault activity are omitted. The current era is still
+        /// counting.
+        pub fn era_stats() -> Vec<EraStats<BalanceOf<T>>> {
+            let current = Self::stats_era_of(frame_system::Pallet::<T>::block_number());
+            let kept = T::MaxStatsEras::get().max(1);
+            let oldest = current.saturating_sub(kept - 1);
+            (oldest..=current)
+                .filter_map(|era| EraStatsRing::<T>::get(era % kept).filter(|s| s.era == era))
+                .collect()
+        }
+
+        /// Shared implementation of `create_vault` and `create_vault_with_premium_mode`
+        fn do_create_vault(
+            who: T::AccountId,
+            public_key: Vec<u8>,
+            security_level: SecurityLevel,
+            premium_mode: PremiumMode,
+        ) -> DispatchResult {
+            let payer = who.clone();
+            Self::do_create_vault_paid_by(who, &payer, public_key, security_level, premium_mode)
+        }
+
+        /// Create a vault for `who` with the creation fee charged to `payer`
+        fn do_create_vault_paid_by(
+            who: T::AccountId,
+            payer: &T::AccountId,
+            public_key: Vec<u8>,
+            security_level: SecurityLevel,
+            premium_mode: PremiumMode,
+        ) -> DispatchResult {
+            // Check not already a vault
+            ensure!(!Vaults::<T>::contains_key(&who), Error::<T>::AlreadyVault);
+
+            // Validate public key size
+            ensure!(
+                public_key.len() == security_level.public_key_size(),
+                Error::<T>::InvalidPublicKey
+            );
+
+            // Convert to bounded vec
+            let bounded_key: BoundedPublicKey<T> = public_key
+                .try_into()
+                .map_err(|_| Error::<T>::PublicKeyTooLarge)?;
+
+            // Hash public key for event (privacy) and the deny-list check
+            let public_key_hash = sp_core::blake2_256(bounded_key.as_slice());
+            ensure!(!DeniedKeys::<T>::contains_key(public_key_hash), Error::<T>::KeyDenied);
+
+            // Charge creation fee - send to treasury instead of burning
+            // This preserves the limited TSRX supply
+            let fee = Self::creation_fee(security_level);
+            let treasury = T::TreasuryAccount::get();
+
+            T::Currency::transfer(payer, &treasury, fee, ExistenceRequirement::KeepAlive)?;
+
+            // Track total fees collected
+            TotalFeesCollected::<T>::mutate(|total| *total = total.saturating_add(fee));
+
+            // Store vault
+            Vaults::<T>::insert(&who, bounded_key);
+            VaultNonces::<T>::insert(&who, 0u64);
+            VaultKeyValidFrom::<T>::insert(&who, frame_system::Pallet::<T>::block_number());
+            if premium_mode != PremiumMode::Flat {
+                VaultPremiumModes::<T>::insert(&who, premium_mode);
+            }
+            if security_level != SecurityLevel::Level2 {
+                VaultSecurityLevels::<T>::insert(&who, security_level);
+            }
+            TotalVaults::<T>::mutate(|n| *n = n.saturating_add(1));
+            Self::note_era_stats(|stats| stats.creations = stats.creations.saturating_add(1));
+
+            // Emit events
+            Self::deposit_event(Event::FeesCollected {
+                from: payer.clone(),
+                amount: fee,
+                reason: FEE_REASON_VAULT_CREATION,
+            });
+            Self::deposit_event(Event::VaultCreated {
+                who: who.clone(),
+                public_key_hash,
+            });
+            if security_level != SecurityLevel::Level2 {
+                Self::deposit_event(Event::VaultSecurityLevelSet {
+                    who: who.clone(),
+                    level: security_level,
+                });
+            }
+            if premium_mode != PremiumMode::Flat {
+                Self::deposit_event(Event::VaultPremiumModeSet { who, mode: premium_mode });
+            }
+
+            log::info!(
+                target: "quantum-vault",
+                "🔐 Quantum Vault created. Public key hash: 0x{}",
+                hex::encode(public_key_hash)
+            );
+
+            Ok(())
+        }
+
+        /// Move `who`'s current key to the archive, pruning the oldest entry
+        ///
+        /// Called whenever a key stops being valid (vault destruction).
+        fn archive_key(who: &T::AccountId, public_key: &BoundedPublicKey<T>, nonce: u64) {
+            let public_key_hash = sp_core::blake2_256(public_key.as_slice());
+            let index = NextArchiveIndex::<T>::mutate(|next| {
+                let index = *next;
+                *next = next.saturating_add(1);
+                index
+            });
+
+            ArchivedKeys::<T>::insert(
+                index,
+                ArchivedVaultKey {
+                    account: who.clone(),
+                    public_key_hash,
+                    valid_from: VaultKeyValidFrom::<T>::get(who),
+                    valid_until: frame_system::Pallet::<T>::block_number(),
+                    signatures_used: nonce,
+                },
+            );
+            ArchivedKeyIndex::<T>::insert(public_key_hash, index);
+
+            // Bounded retention: drop the entry that fell out of the window
+            if let Some(expired) = index.checked_sub(T::MaxArchivedKeys::get() as u64) {
+                if let Some(old) = ArchivedKeys::<T>::take(expired) {
+                    if ArchivedKeyIndex::<T>::get(old.public_key_hash) == Some(expired) {
+                        ArchivedKeyIndex::<T>::remove(old.public_key_hash);
+                    }
+                }
+            }
+
+            Self::deposit_event(Event::VaultKeyArchived {
+                who: who.clone(),
+                public_key_hash,
+                index,
+            });
+        }
+
+        /// Shared body of `vault_transfer` and `vault_transfer_with_nonce`
+        fn do_vault_transfer(
+            who: T::AccountId,
+            signature: Vec<u8>,
+            to: T::AccountId,
+            amount: BalanceOf<T>,
+            nonce: u64,
+            request_id: Option<u64>,
+        ) -> DispatchResult {
+            let _span = sp_tracing::span!(
+                target: TRACE_TARGET,
+                sp_tracing::Level::DEBUG,
+                "vault_transfer",
+                nonce
+            )
+            .entered();
+            ensure!(!VaultsPaused::<T>::get(), Error::<T>::VaultsPaused);
+
+            // Check is a vault
+            let public_key = Vaults::<T>::get(&who).ok_or(Error::<T>::NotVault)?;
+            let level = VaultSecurityLevels::<T>::get(&who);
+
+            // Construct message that was signed
+            let message = Self::construct_transfer_message(&who, &to, amount, nonce);
+            let signed_message = Self::vault_signed_message(&who, &message);
+
+            // A cited request whose proof committed exactly this message
+            // stands in for the signature; consuming it below checks the
+            // vault's key signed it
+            let proof_backed = Self::proves_message(&signed_message, request_id);
+
+            // Validate signature size
+            ensure!(
+                proof_backed || signature.len() == level.signature_size(),
+                Error::<T>::InvalidSignature
+            );
+
+            // Reject replays before the expensive signature check
+            let nonce_offset = Self::nonce_offset(&who, nonce)?;
+
+            // Verify signature
+            if !proof_backed {
+                Self::verify_dilithium_signature(level, &public_key, &signed_message, &signature)?;
+            }
+
+            // Re-ML Integration: If request_id is provided, verify it
+            if let Some(req_id) = request_id {
+                ensure!(
+                    pallet_reml_verifier::Pallet::<T>::is_request_verified(req_id),
+                    Error::<T>::RequestNotVerified
+                );
+                // Each verified request authorizes a single transfer, of the
+                // vault whose key signed it
+                pallet_reml_verifier::Pallet::<T>::consume_verified_request(
+                    req_id,
+                    &sp_core::blake2_256(public_key.as_slice()),
+                    &who,
+                )?;
+
+                log::info!(
+                    target: "quantum-vault",
+                    "✅ Re-ML verification passed for request ID: {}",
+                    req_id
+                );
+
+                // Emit verification event
+                Self::deposit_event(Event::VaultTransferVerified {
                     from: who.clone(),
-                    amount: premium_fee,
-                    reason: FEE_REASON_VAULT_TRANSFER_PREMIUM,
+                    request_id: req_id,
                 });
             }
 
-            // Execute the actual transfer
-            T::Currency::transfer(&who, &to, amount, ExistenceRequirement::KeepAlive)?;
+            // Charge the premium to treasury and execute the actual transfer
+            let premium_fee = Self::pay_from_vault(&who, &to, amount, request_id, proof_backed)?;
 
-            // Increment nonce
-            VaultNonces::<T>::insert(&who, nonce.saturating_add(1));
+            // Mark nonce as used
+            Self::use_nonce(&who, nonce_offset);
 
             // Emit event
             Self::deposit_event(Event::VaultTransfer {
@@ -504,21 +1994,183 @@ pub mod pallet {
 
             Ok(())
         }
-    }
 
-    // ═══════════════════════════════════════════════════════════════════════════
-    // HELPER FUNCTIONS
-    // ═══════════════════════════════════════════════════════════════════════════
+        /// Charge `who`'s transfer premium and move `amount` to `to`
+        ///
+        /// Shared by the signed transfers and `vault_transfer_from`; returns
+        /// the premium charged, at the proof-backed share if `proof_backed`.
+        fn pay_from_vault(
+            who: &T::AccountId,
+            to: &T::AccountId,
+            amount: BalanceOf<T>,
+            request_id: Option<u64>,
+            proof_backed: bool,
+        ) -> Result<BalanceOf<T>, DispatchError> {
+            // Calculate premium fee per the vault's premium mode, discounted
+            // when the recipient is a vault too
+            // This goes to treasury as security premium for using quantum vault
+            let premium_fee = Self::transfer_premium(who, to, amount, proof_backed);
+            let treasury = T::TreasuryAccount::get();
 
-    impl<T: Config> Pallet<T> {
-        /// Check if an account is a vault
-        pub fn is_vault(account: &T::AccountId) -> bool {
-            Vaults::<T>::contains_key(account)
+            // Ensure user can pay both the transfer amount AND the premium fee
+            let total_required = amount.saturating_add(premium_fee);
+            let balance = T::Currency::free_balance(who);
+            ensure!(balance >= total_required, Error::<T>::InsufficientBalanceForPremium);
+
+            Self::spend_under_policy(who, to, amount)?;
+
+            // Charge premium fee first (split between treasury and incentive pool)
+            if !premium_fee.is_zero() {
+                let to_pool = Self::incentive_pool_share(premium_fee);
+                if !to_pool.is_zero() {
+                    T::Currency::transfer(
+                        who,
+                        &T::IncentivePool::get(),
+                        to_pool,
+                        ExistenceRequirement::KeepAlive,
+                    )?;
+                }
+                let to_treasury = premium_fee.saturating_sub(to_pool);
+                if !to_treasury.is_zero() {
+                    T::Currency::transfer(
+                        who,
+                        &treasury,
+                        to_treasury,
+                        ExistenceRequirement::KeepAlive,
+                    )?;
+                }
+
+                // Track total fees collected
+                TotalFeesCollected::<T>::mutate(|total| *total = total.saturating_add(premium_fee));
+
+                // Emit fee collection event
+                Self::deposit_event(Event::FeesCollected {
+                    from: who.clone(),
+                    amount: premium_fee,
+                    reason: FEE_REASON_VAULT_TRANSFER_PREMIUM,
+                });
+
+                // Attribute the premium to the batch that verified the request
+                if let Some(req_id) = request_id {
+                    pallet_reml_verifier::Pallet::<T>::attribute_premium(
+                        req_id,
+                        premium_fee.saturated_into::<u128>().saturated_into(),
+                    );
+                }
+            }
+
+            // Execute the actual transfer
+            T::Currency::transfer(who, to, amount, ExistenceRequirement::KeepAlive)?;
+
+            Self::note_era_stats(|stats| {
+                stats.transfers = stats.transfers.saturating_add(1);
+                stats.premium_collected = stats.premium_collected.saturating_add(premium_fee);
+            });
+
+            Ok(premium_fee)
         }
 
-        /// Get the public key of a vault (if exists)
-        pub fn get_vault_public_key(account: &T::AccountId) -> Option<BoundedPublicKey<T>> {
-            Vaults::<T>::get(account)
+        /// Era of the usage statistics `block` counts towards
+        fn stats_era_of(block: BlockNumberFor<T>) -> u32 {
+            (block / T::EraLength::get().max(One::one())).saturated_into()
+        }
+
+        /// Apply `update` to the current era's usage statistics
+        fn note_era_stats(update: impl FnOnce(&mut EraStats<BalanceOf<T>>)) {
+            let era = Self::stats_era_of(frame_system::Pallet::<T>::block_number());
+            let slot = era % T::MaxStatsEras::get().max(1);
+            EraStatsRing::<T>::mutate(slot, |entry| {
+                if entry.as_ref().map_or(true, |stats| stats.era != era) {
+                    *entry = Some(EraStats { era, ..Default::default() });
+                }
+                if let Some(stats) = entry {
+                    update(stats);
+                }
+            });
+        }
+
+        /// Part of `premium` paid into the incentive pool
+        ///
+        /// Zero while that part could not open the pool account, leaving the
+        /// whole premium to the treasury.
+        fn incentive_pool_share(premium: BalanceOf<T>) -> BalanceOf<T> {
+            let share = T::IncentivePoolShare::get() * premium;
+            let pool_balance = T::Currency::total_balance(&T::IncentivePool::get());
+            if pool_balance.saturating_add(share) < T::Currency::minimum_balance() {
+                return Zero::zero();
+            }
+            share
+        }
+
+        /// Check `who`'s policy allows sending `amount` to `to` and count it
+        /// against the daily limit
+        ///
+        /// The premium does not count against the limit.
+        fn spend_under_policy(
+            who: &T::AccountId,
+            to: &T::AccountId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let Some(policy) = VaultPolicies::<T>::get(who) else {
+                return Ok(());
+            };
+            if let Some(whitelist) = &policy.whitelist {
+                ensure!(whitelist.contains(to), Error::<T>::RecipientNotWhitelisted);
+            }
+            let Some(daily_limit) = policy.daily_limit else {
+                return Ok(());
+            };
+
+            // Rounding the bucket length up keeps at most
+            // `SPENDING_WINDOW_BUCKETS + 1` buckets in the window
+            let now = frame_system::Pallet::<T>::block_number();
+            let window = T::SpendingWindow::get();
+            let buckets = BlockNumberFor::<T>::from(SPENDING_WINDOW_BUCKETS);
+            let bucket_length =
+                ((window.saturating_add(buckets) - One::one()) / buckets).max(One::one());
+            let bucket = now - now % bucket_length;
+
+            let mut spending = VaultSpending::<T>::get(who);
+            spending.retain(|(start, _)| {
+                now < start.saturating_add(bucket_length).saturating_add(window)
+            });
+            let spent = spending
+                .iter()
+                .fold(amount, |spent, (_, bucket_spent)| spent.saturating_add(*bucket_spent));
+            ensure!(spent <= daily_limit, Error::<T>::DailyLimitExceeded);
+
+            match spending.last_mut() {
+                Some((start, bucket_spent)) if *start == bucket => {
+                    *bucket_spent = bucket_spent.saturating_add(amount)
+                },
+                _ => spending
+                    .try_push((bucket, amount))
+                    .map_err(|_| Error::<T>::DailyLimitExceeded)?,
+            }
+            VaultSpending::<T>::insert(who, spending);
+            Ok(())
+        }
+
+        /// Position of `nonce` in `who`'s nonce window, if still usable
+        fn nonce_offset(who: &T::AccountId, nonce: u64) -> Result<u64, Error<T>> {
+            let offset =
+                nonce.checked_sub(VaultNonces::<T>::get(who)).ok_or(Error::<T>::InvalidNonce)?;
+            ensure!(offset < NONCE_WINDOW, Error::<T>::NonceOutsideWindow);
+            ensure!(VaultUsedNonces::<T>::get(who) & (1 << offset) == 0, Error::<T>::InvalidNonce);
+            Ok(offset)
+        }
+
+        /// Mark the nonce at `offset` used, sliding the window past the used
+        /// nonces at its start
+        fn use_nonce(who: &T::AccountId, offset: u64) {
+            let used = VaultUsedNonces::<T>::get(who) | (1 << offset);
+            let advance = used.trailing_ones();
+
+            VaultNonces::<T>::mutate(who, |base| *base = base.saturating_add(advance as u64));
+            match used.checked_shr(advance).unwrap_or(0) {
+                0 => VaultUsedNonces::<T>::remove(who),
+                used => VaultUsedNonces::<T>::insert(who, used),
+            }
         }
 
         /// Construct the message for a transfer signature
@@ -529,176 +2181,231 @@ pub mod pallet {
             nonce: u64,
         ) -> Vec<u8> {
             use codec::Encode;
-            let mut message = b"TESSERAX_VAULT_TRANSFER:".to_vec();
-            message.extend(from.encode());
-            message.extend(to.encode());
-            message.extend(amount.encode());
-            message.extend(nonce.encode());
-            message
+            tesserax_vault_payload::transfer(&from.encode(), &to.encode(), &amount.encode(), nonce)
+        }
+
+        /// Construct the message for a `set_vault_policy` signature
+        fn construct_policy_message(
+            vault: &T::AccountId,
+            policy: &VaultPolicyOf<T>,
+            nonce: u64,
+        ) -> Vec<u8> {
+            use codec::Encode;
+            tesserax_vault_payload::policy(
+                &vault.encode(),
+                &policy.daily_limit.encode(),
+                &policy.whitelist.encode(),
+                nonce,
+            )
+        }
+
+        /// Construct the message for a `vault_approve` signature
+        fn construct_approval_message(
+            vault: &T::AccountId,
+            spender: &T::AccountId,
+            limit: BalanceOf<T>,
+            nonce: u64,
+        ) -> Vec<u8> {
+            use codec::Encode;
+            tesserax_vault_payload::approve(
+                &vault.encode(),
+                &spender.encode(),
+                &limit.encode(),
+                nonce,
+            )
         }
 
         /// Construct the message for vault destruction
         fn construct_destroy_message(account: &T::AccountId, nonce: u64) -> Vec<u8> {
             use codec::Encode;
-            let mut message = b"TESSERAX_VAULT_DESTROY:".to_vec();
-            message.extend(account.encode());
-            message.extend(nonce.encode());
-            message
+            tesserax_vault_payload::destroy(&account.encode(), nonce)
+        }
+
+        /// Construct the message `target` signs to accept a vault set up by `funder`
+        fn construct_acceptance_message(
+            target: &T::AccountId,
+            funder: &T::AccountId,
+            public_key_hash: &[u8; 32],
+            initial_deposit: BalanceOf<T>,
+        ) -> Vec<u8> {
+            use codec::Encode;
+            tesserax_vault_payload::accept(
+                &target.encode(),
+                &funder.encode(),
+                public_key_hash,
+                &initial_deposit.encode(),
+                &frame_system::Pallet::<T>::account_nonce(target).encode(),
+            )
+        }
+
+        /// Verify `who`'s signature of `payload` in the vault's message mode
+        fn verify_vault_signature(
+            who: &T::AccountId,
+            level: SecurityLevel,
+            public_key: &BoundedPublicKey<T>,
+            payload: &[u8],
+            signature: &[u8],
+        ) -> Result<(), Error<T>> {
+            let message = Self::vault_signed_message(who, payload);
+            Self::verify_dilithium_signature(level, public_key, &message, signature)
+        }
+
+        /// Whether `request_id`'s proof committed the message `who`'s key
+        /// signs for the transfer of `amount` to `to` at `nonce`
+        fn proves_transfer(
+            who: &T::AccountId,
+            to: &T::AccountId,
+            amount: BalanceOf<T>,
+            nonce: u64,
+            request_id: Option<u64>,
+        ) -> bool {
+            request_id.is_some() && {
+                let message = Self::construct_transfer_message(who, to, amount, nonce);
+                Self::proves_message(&Self::vault_signed_message(who, &message), request_id)
+            }
         }
 
-        /// Verify a Dilithium signature
+        /// Whether `request_id`'s proof committed exactly `signed_message`
+        fn proves_message(signed_message: &[u8], request_id: Option<u64>) -> bool {
+            request_id.is_some_and(|req_id| {
+                pallet_reml_verifier::Pallet::<T>::request_message(req_id)
+                    == Some(sp_core::blake2_256(signed_message))
+            })
+        }
+
+        /// Message `who`'s key signs for `payload`, per the vault's message mode
+        fn vault_signed_message(who: &T::AccountId, payload: &[u8]) -> Vec<u8> {
+            match VaultMessageModes::<T>::get(who) {
+                Some((mode, context)) => mode.signed_message(&context, payload),
+                None => payload.to_vec(),
+            }
+        }
+
+        /// Verify a Dilithium signature at `level`, or a SPHINCS+ signature
         ///
-        /// This function performs REAL CRYSTALS-Dilithium Level 2 signature
-        /// verification. In native (std) mode, it uses the pqc_dilithium crate.
-        /// In WASM (no_std) mode, it uses a host function for verification.
+        /// This function performs REAL CRYSTALS-Dilithium signature
+        /// verification through the `tesserax_dilithium_io` host functions, so
+        /// native and WASM builds check signatures the same way. SPHINCS+ keys
+        /// go through the `tesserax_sphincs_io` host function instead.
         ///
         /// # Security
-        /// - Uses NIST FIPS 204 standard Dilithium2 (ML-DSA-44)
-        /// - Provides AES-128 equivalent security (quantum-resistant)
+        /// - Uses NIST FIPS 204 standard Dilithium2/3/5 (ML-DSA-44/65/87)
+        /// - Provides AES-128 equivalent security or better (quantum-resistant)
         /// - Resistant to all known classical and quantum attacks
         fn verify_dilithium_signature(
+            level: SecurityLevel,
             public_key: &BoundedPublicKey<T>,
             message: &[u8],
             signature: &[u8],
         ) -> Result<(), Error<T>> {
+            let _span = sp_tracing::span!(
+                target: TRACE_TARGET,
+                sp_tracing::Level::DEBUG,
+                "verify_dilithium_signature"
+            )
+            .entered();
+
             // Validate sizes first
-            if public_key.len() != DILITHIUM_PUBLIC_KEY_SIZE {
+            if public_key.len() != level.public_key_size() {
                 log::warn!(
                     target: "quantum-vault",
                     "❌ Invalid public key size: {} (expected {})",
                     public_key.len(),
-                    DILITHIUM_PUBLIC_KEY_SIZE
+                    level.public_key_size()
                 );
                 return Err(Error::<T>::InvalidPublicKey);
             }
-            if signature.len() != DILITHIUM_SIGNATURE_SIZE {
+            if signature.len() != level.signature_size() {
                 log::warn!(
                     target: "quantum-vault",
                     "❌ Invalid signature size: {} (expected {})",
                     signature.len(),
-                    DILITHIUM_SIGNATURE_SIZE
+                    level.signature_size()
                 );
                 return Err(Error::<T>::InvalidSignature);
             }
 
-            // Convert public key bytes to fixed-size array
-            let pk_bytes: [u8; DILITHIUM_PUBLIC_KEY_SIZE] = public_key
-                .as_slice()
-                .try_into()
-                .map_err(|_| Error::<T>::InvalidPublicKey)?;
-
-            // Convert signature bytes to fixed-size array
-            let sig_bytes: [u8; DILITHIUM_SIGNATURE_SIZE] = signature
-                .try_into()
-                .map_err(|_| Error::<T>::InvalidSignature)?;
-
-            // ═══════════════════════════════════════════════════════════════════
-            // NATIVE (std) MODE: Use pqc_dilithium crate directly
-            // ═══════════════════════════════════════════════════════════════════
-            #[cfg(feature = "std")]
-            {
-                match pqc_dilithium::verify(&sig_bytes, message, &pk_bytes) {
-                    Ok(()) => {
-                        log::info!(
-                            target: "quantum-vault",
-                            "✅ Dilithium signature verified successfully (native)"
-                        );
-                        Ok(())
-                    }
-                    Err(_) => {
-                        log::warn!(
-                            target: "quantum-vault",
-                            "❌ Dilithium signature verification FAILED (native)"
-                        );
-                        Err(Error::<T>::SignatureVerificationFailed)
-                    }
-                }
+            // Native builds run this directly; the Wasm runtime calls into the
+            // node through the `dilithium` and `sphincs` host functions
+            let valid = match level {
+                SecurityLevel::SphincsSha2_128s => tesserax_sphincs_io::sphincs::verify(
+                    public_key.as_slice(),
+                    message,
+                    signature,
+                ),
+                SecurityLevel::Level2 => tesserax_dilithium_io::dilithium::verify(
+                    public_key.as_slice(),
+                    message,
+                    signature,
+                ),
+                level => tesserax_dilithium_io::dilithium::verify_at_level(
+                    level.nist_level(),
+                    public_key.as_slice(),
+                    message,
+                    signature,
+                ),
+            };
+            #[cfg(feature = "double-verification")]
+            if level.is_dilithium() {
+                Self::cross_check_verification(level, public_key, message, signature, valid)?;
             }
-
-            // ═══════════════════════════════════════════════════════════════════
-            // WASM (no_std) MODE: Use host function for verification
-            // We use sp_io::hashing to hash the components and verify via
-            // a commitment scheme until proper host function is available
-            // ═══════════════════════════════════════════════════════════════════
-            #[cfg(not(feature = "std"))]
-            {
-                use sp_io::hashing::blake2_256;
-
-                // Compute a binding commitment from all inputs
-                // This ties the public key, message, and signature together
-                let mut commitment_input = Vec::new();
-                commitment_input.extend_from_slice(&pk_bytes);
-                commitment_input.extend_from_slice(message);
-                commitment_input.extend_from_slice(&sig_bytes);
-
-                let commitment_hash = blake2_256(&commitment_input);
-
-                // For WASM runtime, we use a cryptographic commitment check:
-                // The signature must contain a valid binding to the message.
-                //
-                // The actual Dilithium verification happens in the node's native
-                // executor before transaction inclusion. This on-chain check
-                // serves as a binding commitment verification.
-                //
-                // Security: The Dilithium signature structure inherently binds
-                // the signature to both the message and public key. An invalid
-                // signature cannot produce a valid commitment.
-                //
-                // For production, this should be enhanced with:
-                // 1. A custom host function: sp_io::crypto::dilithium2_verify
-                // 2. Or off-chain worker verification with on-chain attestation
-
-                // Verify the signature contains valid structure markers
-                // Dilithium signatures have specific byte patterns
-                let c_tilde = &sig_bytes[0..32]; // challenge seed
-                let z_start = &sig_bytes[32..64]; // start of z vector
-
-                // Basic structural integrity check
-                // A randomly generated fake signature is extremely unlikely to pass
-                let structural_check = {
-                    let mut valid = true;
-                    // Check that signature is not all zeros
-                    valid = valid && sig_bytes.iter().any(|&b| b != 0);
-                    // Check that public key is not all zeros
-                    valid = valid && pk_bytes.iter().any(|&b| b != 0);
-                    // Check non-trivial challenge seed
-                    valid = valid && c_tilde.iter().any(|&b| b != 0);
-                    valid
-                };
-
-                if !structural_check {
-                    log::warn!(
-                        target: "quantum-vault",
-                        "❌ Dilithium signature structural check FAILED (wasm)"
-                    );
-                    return Err(Error::<T>::SignatureVerificationFailed);
-                }
-
-                // Verify the commitment binding
-                // In a properly signed message, the commitment should be verifiable
-                let expected_binding =
-                    blake2_256(&[c_tilde, &blake2_256(message), &pk_bytes[0..32]].concat());
-
-                // The signature's z component should correlate with the binding
-                // This is a simplified commitment verification
-                if z_start[0..8] == [0u8; 8] && z_start[8..16] == [0u8; 8] {
-                    log::warn!(
-                        target: "quantum-vault",
-                        "❌ Dilithium signature binding check suspicious (wasm)"
-                    );
-                    // Still allow for now but log warning
-                    // In production, implement proper host function verification
-                }
-
+            if valid {
                 log::info!(
                     target: "quantum-vault",
-                    "✅ Dilithium signature commitment verified (wasm) - commitment: 0x{}",
-                    hex::encode(&commitment_hash[0..8])
+                    "✅ {:?} signature verified successfully",
+                    SignatureScheme::from(level)
                 );
-
                 Ok(())
+            } else {
+                log::warn!(
+                    target: "quantum-vault",
+                    "❌ {:?} signature verification FAILED",
+                    SignatureScheme::from(level)
+                );
+                Err(Error::<T>::SignatureVerificationFailed)
             }
         }
+
+        /// Check the host function's `verdict` against the in-runtime verifier
+        ///
+        /// `double-verification` builds only: the Wasm runtime verifies the
+        /// signature again with `tesserax_dilithium_io::portable`, without the
+        /// node, and fails the call with `VerificationPathsDiverged` and an
+        /// error log when the two disagree, so a soundness regression in either
+        /// path shows up on a testnet instead of accepting or rejecting
+        /// signatures silently. SPHINCS+ signatures have no in-runtime
+        /// verifier and are not cross-checked.
+        #[cfg(feature = "double-verification")]
+        fn cross_check_verification(
+            level: SecurityLevel,
+            public_key: &BoundedPublicKey<T>,
+            message: &[u8],
+            signature: &[u8],
+            verdict: bool,
+        ) -> Result<(), Error<T>> {
+            let portable = tesserax_dilithium_io::portable::verify(
+                level.nist_level(),
+                public_key.as_slice(),
+                message,
+                signature,
+            );
+            if portable == verdict {
+                return Ok(());
+            }
+
+            log::error!(
+                target: "quantum-vault",
+                "🚨 Dilithium{} verification paths diverged: host function {}, runtime {} \
+                 (public key 0x{}, message 0x{}, signature 0x{})",
+                level.nist_level(),
+                verdict,
+                portable,
+                hex::encode(public_key.as_slice()),
+                hex::encode(message),
+                hex::encode(signature)
+            );
+            Err(Error::<T>::VerificationPathsDiverged)
+        }
     }
 
     // ═══════════════════════════════════════════════════════════════════════════
@@ -6,9 +6,10 @@
 use core::cell::RefCell;
 use frame_support::{
     derive_impl, parameter_types,
-    traits::{ConstU32, ConstU64},
+    traits::{ConstU32, ConstU64, Nothing},
+    PalletId,
 };
-use sp_runtime::{traits::IdentityLookup, BuildStorage};
+use sp_runtime::{traits::IdentityLookup, BuildStorage, Permill};
 
 use crate as pallet_quantum_vault;
 
@@ -57,22 +58,66 @@ parameter_types! {
     /// Base fee for vault transfers (1 unit)
     /// Premium = 1 * 10 = 10 units per vault transfer
     pub const VaultTransferBaseFee: u64 = 1;
-    /// Dilithium2 public key size
-    pub const MaxPublicKeySize: u32 = 1312;
-    /// Dilithium2 signature size
-    pub const MaxSignatureSize: u32 = 2420;
+    /// 5% premium for percentage-mode vaults
+    pub const VaultTransferPremiumRate: Permill = Permill::from_percent(5);
+    /// Percentage-mode premium never exceeds 20 units
+    pub const VaultTransferPremiumCap: u64 = 20;
+    /// Vault-to-vault transfers pay half the premium
+    pub const VaultToVaultPremiumDiscount: Permill = Permill::from_percent(50);
+    /// In-band transfers pay the whole premium
+    pub const InBandPremiumShare: Permill = Permill::one();
+    /// Proof-backed transfers pay the whole premium unless a test sets a share
+    pub static ProofBackedPremiumShare: Permill = Permill::one();
+    /// Dilithium5 public key size
+    pub const MaxPublicKeySize: u32 = 2592;
+    /// SPHINCS+-SHA2-128s signature size
+    pub const MaxSignatureSize: u32 = 7856;
     /// Treasury account for test (account 99)
     pub const TreasuryAccountId: u64 = 99;
+    /// Re-ML incentive pool account for test (account 98)
+    pub const IncentivePoolAccount: u64 = 98;
+    /// Premiums go to the treasury alone unless a test sets a pool share
+    pub static IncentivePoolShare: Permill = Permill::zero();
+    /// Keep the two most recently archived vault keys
+    pub const MaxArchivedKeys: u32 = 2;
+    /// Daily limits apply over 24 blocks, one block per bucket
+    pub const SpendingWindow: u64 = 24;
+    /// Usage statistics eras of 10 blocks, the last three kept
+    pub const StatsEraLength: u64 = 10;
+    pub const MaxStatsEras: u32 = 3;
     /// Max aggregators for Re-ML (test value)
     pub const MaxAggregators: u32 = 10;
     /// Expected VKey hash (zeros for testing)
     pub const ExpectedVKeyHash: [u8; 32] = [0u8; 32];
+    /// Re-ML aggregator reward pot
+    pub const RewardPotId: PalletId = PalletId(*b"reml/pot");
+    pub const PrunerShare: sp_runtime::Perbill = sp_runtime::Perbill::zero();
 }
 
 impl pallet_reml_verifier::Config for Test {
     type WeightInfo = ();
+    type AdminOrigin = frame_system::EnsureRoot<u64>;
     type MaxAggregators = MaxAggregators;
     type ExpectedVKeyHash = ExpectedVKeyHash;
+    type TransitionVKeyHash = ();
+    type ProgramVKeys = ();
+    type InlineRequestMarks = ConstU32<{ pallet_reml_verifier::MAX_VERIFIED_REQUESTS }>;
+    type ProofEpoch = ConstU32<1>;
+    type OnBatchVerified = ();
+    type CoSignature = sp_runtime::testing::TestSignature;
+    type RewardCurrency = Balances;
+    type VaultCheck = Nothing;
+    type BatchReward = ConstU64<0>;
+    type RewardPotId = RewardPotId;
+    type EraLength = ConstU64<14_400>;
+    type MaxBatchesPerEra = ConstU32<{ u32::MAX }>;
+    type AggregatorBond = ConstU64<0>;
+    type UnbondingPeriod = ConstU64<0>;
+    type Slash = ();
+    type ProofRetentionPeriod = ConstU64<{ u64::MAX }>;
+    type MaxAnchorAge = ConstU64<10>;
+    type SubmissionDeposit = ConstU64<0>;
+    type PrunerShare = PrunerShare;
 }
 
 impl pallet_quantum_vault::Config for Test {
@@ -81,9 +126,20 @@ impl pallet_quantum_vault::Config for Test {
     type VaultCreationFee = VaultCreationFee;
     type VaultTransferFeeMultiplier = VaultTransferFeeMultiplier;
     type VaultTransferBaseFee = VaultTransferBaseFee;
+    type VaultTransferPremiumRate = VaultTransferPremiumRate;
+    type VaultTransferPremiumCap = VaultTransferPremiumCap;
+    type VaultToVaultPremiumDiscount = VaultToVaultPremiumDiscount;
+    type InBandPremiumShare = InBandPremiumShare;
+    type ProofBackedPremiumShare = ProofBackedPremiumShare;
     type MaxPublicKeySize = MaxPublicKeySize;
     type MaxSignatureSize = MaxSignatureSize;
     type TreasuryAccount = TreasuryAccountId;
+    type IncentivePoolShare = IncentivePoolShare;
+    type IncentivePool = IncentivePoolAccount;
+    type MaxArchivedKeys = MaxArchivedKeys;
+    type SpendingWindow = SpendingWindow;
+    type EraLength = StatsEraLength;
+    type MaxStatsEras = MaxStatsEras;
 }
 
 /// Build test externalities
@@ -216,11 +272,8 @@ pub fn create_transfer_signature(from: u64, to: u64, amount: u64, nonce: u64) ->
     let keypair = get_keypair_for_account(from);
 
     // Construct the message exactly as the pallet does
-    let mut message = b"TESSERAX_VAULT_TRANSFER:".to_vec();
-    message.extend(from.encode());
-    message.extend(to.encode());
-    message.extend(amount.encode());
-    message.extend(nonce.encode());
+    let message =
+        tesserax_vault_payload::transfer(&from.encode(), &to.encode(), &amount.encode(), nonce);
 
     // Sign with real Dilithium2
     let signature = keypair.sign(&message);
@@ -236,15 +289,59 @@ pub fn create_destroy_signature(account: u64, nonce: u64) -> Vec<u8> {
     let keypair = get_keypair_for_account(account);
 
     // Construct the message exactly as the pallet does
-    let mut message = b"TESSERAX_VAULT_DESTROY:".to_vec();
-    message.extend(account.encode());
-    message.extend(nonce.encode());
+    let message = tesserax_vault_payload::destroy(&account.encode(), nonce);
 
     // Sign with real Dilithium2
     let signature = keypair.sign(&message);
     signature.to_vec()
 }
 
+/// Helper to create REAL acceptance signature for `create_vault_for`
+/// Signed by `target`'s keypair at its current account nonce
+pub fn create_acceptance_signature(target: u64, funder: u64, initial_deposit: u64) -> Vec<u8> {
+    use codec::Encode;
+
+    let keypair = get_keypair_for_account(target);
+    let message = tesserax_vault_payload::accept(
+        &target.encode(),
+        &funder.encode(),
+        &sp_core::blake2_256(&keypair.public),
+        &initial_deposit.encode(),
+        &System::account_nonce(target).encode(),
+    );
+    keypair.sign(&message).to_vec()
+}
+
+/// Helper to create REAL signature for `vault_approve`
+/// Uses actual Dilithium signing with the test keypair
+pub fn create_approve_signature(vault: u64, spender: u64, limit: u64, nonce: u64) -> Vec<u8> {
+    use codec::Encode;
+
+    let keypair = get_keypair_for_account(vault);
+    let message =
+        tesserax_vault_payload::approve(&vault.encode(), &spender.encode(), &limit.encode(), nonce);
+    keypair.sign(&message).to_vec()
+}
+
+/// Helper to create REAL signature for `set_vault_policy`
+/// Uses actual Dilithium signing with the test keypair
+pub fn create_policy_signature(
+    vault: u64,
+    policy: &crate::VaultPolicyOf<Test>,
+    nonce: u64,
+) -> Vec<u8> {
+    use codec::Encode;
+
+    let keypair = get_keypair_for_account(vault);
+    let message = tesserax_vault_payload::policy(
+        &vault.encode(),
+        &policy.daily_limit.encode(),
+        &policy.whitelist.encode(),
+        nonce,
+    );
+    keypair.sign(&message).to_vec()
+}
+
 /// Create a signature with WRONG keypair (for negative tests)
 /// This should fail verification because it uses a different keypair
 pub fn create_invalid_signature(from: u64, to: u64, amount: u64, nonce: u64) -> Vec<u8> {
@@ -254,11 +351,8 @@ pub fn create_invalid_signature(from: u64, to: u64, amount: u64, nonce: u64) ->
     // This will fail verification because the signature doesn't match the stored public key
     let wrong_keypair = get_wrong_keypair();
 
-    let mut message = b"TESSERAX_VAULT_TRANSFER:".to_vec();
-    message.extend(from.encode());
-    message.extend(to.encode());
-    message.extend(amount.encode());
-    message.extend(nonce.encode());
+    let message =
+        tesserax_vault_payload::transfer(&from.encode(), &to.encode(), &amount.encode(), nonce);
 
     let signature = wrong_keypair.sign(&message);
     signature.to_vec()
@@ -0,0 +1,67 @@
+//! Runtime API definition for the Quantum Vault pallet.
+
+use crate::{
+    CallFeeQuote, EraStats, TransferDryRun, ValueLockedPage, VaultDescriptor, VaultInfo,
+    VaultSummary,
+};
+use alloc::vec::Vec;
+use codec::Codec;
+
+sp_api::decl_runtime_apis! {
+    /// Quantum Vault queries for RPC, explorers and wallets
+    ///
+    /// Kept to small, fixed-size reads so light clients (smoldot) can answer
+    /// them from a handful of storage proofs.
+    pub trait QuantumVaultApi<AccountId, Balance, BlockNumber>
+    where
+        AccountId: Codec,
+        Balance: Codec,
+        BlockNumber: Codec,
+    {
+        /// Whether `account` is a vault
+        fn is_vault(account: AccountId) -> bool;
+
+        /// Lowest unused vault nonce, the one `vault_transfer` signs; `None` if not a vault
+        fn vault_nonce(account: AccountId) -> Option<u64>;
+
+        /// Exact payload the vault key of `from` signs for a `vault_transfer`
+        /// of `amount` to `to`, at the current nonce; `None` if not a vault
+        fn vault_transfer_payload(from: AccountId, to: AccountId, amount: Balance) -> Option<Vec<u8>>;
+
+        /// Configuration of `account`, to replicate with `create_vault_from_descriptor`;
+        /// `None` if not a vault
+        fn vault_descriptor(account: AccountId) -> Option<VaultDescriptor>;
+
+        /// Key hash, nonce, creation block, signing mode and policy of
+        /// `account`; `None` if not a vault
+        fn vault_info(account: AccountId) -> Option<VaultInfo<AccountId, Balance, BlockNumber>>;
+
+        /// Up to `limit` vaults after `start_key` (exclusive), in storage order
+        fn list_vaults(start_key: Option<AccountId>, limit: u32) -> Vec<VaultSummary<AccountId>>;
+
+        /// Balance held by up to `limit` vaults after `start_key` (exclusive)
+        fn value_locked(start_key: Option<AccountId>, limit: u32) -> ValueLockedPage<AccountId, Balance>;
+
+        /// Simulate `vault_transfer` from `from` against current state
+        ///
+        /// Nothing is submitted or persisted. Returns the fees the call would
+        /// be charged, or the exact error it would fail with.
+        fn dry_run_transfer(
+            from: AccountId,
+            signature: Vec<u8>,
+            to: AccountId,
+            amount: Balance,
+            request_id: Option<u64>,
+        ) -> TransferDryRun<Balance>;
+
+        /// Fees `who` would pay for the SCALE-encoded runtime `call`: the
+        /// transaction fee breakdown plus any vault premium or creation fee
+        ///
+        /// `None` if `call` does not decode.
+        fn query_call_fees(who: AccountId, call: Vec<u8>) -> Option<CallFeeQuote<Balance>>;
+
+        /// Vault creations, transfers, destroys and premiums of the last
+        /// `MaxStatsEras` eras, oldest first; eras without activity are omitted
+        fn era_stats() -> Vec<EraStats<Balance>>;
+    }
+}
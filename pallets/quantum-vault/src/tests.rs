@@ -3,8 +3,16 @@
 //! These tests use REAL Dilithium2 signatures via the pqc_dilithium crate.
 //! This ensures that cryptographic verification is properly tested.
 
-use crate::{mock::*, Error, Event, TotalFeesCollected, TotalVaults, VaultNonces, Vaults};
+use crate::{
+    mock::*, ArchivedKeys, ArchivedVaultKey, EraStats, Error, Event, MessageMode, NextArchiveIndex,
+    PremiumMode, SecurityLevel, SignatureScheme, TotalFeesCollected, TotalVaults, VaultAllowances,
+    VaultDescriptor, VaultInfo, VaultKeyValidFrom, VaultMessageModes, VaultNonces, VaultPolicies,
+    VaultPolicy, VaultPolicyOf, VaultPremiumModes, VaultSecurityLevels, VaultSpending,
+    VaultUsedNonces, Vaults, NONCE_WINDOW, VAULT_DESCRIPTOR_VERSION,
+};
+use codec::{Decode, Encode};
 use frame_support::{assert_noop, assert_ok};
+use sp_runtime::Permill;
 
 /// Creation fee per whitepaper v3.0: 2 units (reduced from 10)
 const CREATION_FEE: u64 = 2;
@@ -341,6 +349,861 @@ fn vault_transfer_fails_with_tampered_recipient() {
     });
 }
 
+#[test]
+fn verified_request_authorizes_one_transfer() {
+    new_test_ext().execute_with(|| {
+        let alice = 1;
+        let bob = 2;
+        assert_ok!(QuantumVault::create_vault(
+            RuntimeOrigin::signed(alice),
+            mock_public_key()
+        ));
+        pallet_reml_verifier::VerifiedRequests::<Test>::insert(7, (1, 1));
+        let alice_key_hash = sp_core::blake2_256(&mock_public_key());
+        pallet_reml_verifier::RequestOwners::<Test>::insert(7, alice_key_hash);
+
+        let transfer = |nonce| {
+            QuantumVault::vault_transfer(
+                RuntimeOrigin::signed(alice),
+                create_transfer_signature(alice, bob, 10, nonce),
+                bob,
+                10,
+                Some(7),
+            )
+        };
+        assert_ok!(transfer(0));
+        assert_eq!(
+            pallet_reml_verifier::ConsumedRequests::<Test>::get(7),
+            Some((alice, 1))
+        );
+
+        assert_noop!(
+            transfer(1),
+            pallet_reml_verifier::Error::<Test>::RequestAlreadyConsumed
+        );
+    });
+}
+
+#[test]
+fn verified_request_is_only_consumed_by_its_signer() {
+    new_test_ext().execute_with(|| {
+        let alice = 1;
+        let bob = 2;
+        assert_ok!(QuantumVault::create_vault(
+            RuntimeOrigin::signed(alice),
+            mock_public_key()
+        ));
+        assert_ok!(QuantumVault::create_vault(
+            RuntimeOrigin::signed(bob),
+            get_public_key_for_account(bob)
+        ));
+        // Request 7 was signed with Alice's key, request 8 by an unknown one
+        pallet_reml_verifier::VerifiedRequests::<Test>::insert(7, (1, 1));
+        let alice_key_hash = sp_core::blake2_256(&mock_public_key());
+        pallet_reml_verifier::RequestOwners::<Test>::insert(7, alice_key_hash);
+        pallet_reml_verifier::VerifiedRequests::<Test>::insert(8, (1, 1));
+
+        let transfer = |from, to, request_id| {
+            QuantumVault::vault_transfer(
+                RuntimeOrigin::signed(from),
+                create_transfer_signature(from, to, 10, 0),
+                to,
+                10,
+                Some(request_id),
+            )
+        };
+        assert_noop!(
+            transfer(bob, alice, 7),
+            pallet_reml_verifier::Error::<Test>::NotRequestOwner
+        );
+        assert_noop!(
+            transfer(alice, bob, 8),
+            pallet_reml_verifier::Error::<Test>::NotRequestOwner
+        );
+
+        assert_ok!(transfer(alice, bob, 7));
+        assert_eq!(
+            pallet_reml_verifier::ConsumedRequests::<Test>::get(7),
+            Some((alice, 1))
+        );
+    });
+}
+
+#[test]
+fn verified_request_does_not_waive_vault_signature() {
+    new_test_ext().execute_with(|| {
+        let alice = 1;
+        let bob = 2;
+        assert_ok!(QuantumVault::create_vault(
+            RuntimeOrigin::signed(alice),
+            mock_public_key()
+        ));
+        // Its proof committed no message, so the signature is checked
+        pallet_reml_verifier::VerifiedRequests::<Test>::insert(7, (1, 1));
+
+        // Signed for a different amount
+        assert_noop!(
+            QuantumVault::vault_transfer(
+                RuntimeOrigin::signed(alice),
+                create_transfer_signature(alice, bob, 11, 0),
+                bob,
+                10,
+                Some(7),
+            ),
+            Error::<Test>::SignatureVerificationFailed
+        );
+        assert!(!pallet_reml_verifier::ConsumedRequests::<Test>::contains_key(7));
+    });
+}
+
+#[test]
+fn proof_of_the_signed_payload_stands_in_for_vault_signature() {
+    new_test_ext().execute_with(|| {
+        let alice = 1;
+        let bob = 2;
+        assert_ok!(QuantumVault::create_vault(
+            RuntimeOrigin::signed(alice),
+            mock_public_key()
+        ));
+        // Request 7 proves Alice's key signed the transfer of 10 to Bob at nonce 0
+        let payload = QuantumVault::vault_transfer_payload(&alice, &bob, 10).unwrap();
+        pallet_reml_verifier::VerifiedRequests::<Test>::insert(7, (1, 1));
+        let alice_key_hash = sp_core::blake2_256(&mock_public_key());
+        pallet_reml_verifier::RequestOwners::<Test>::insert(7, alice_key_hash);
+        pallet_reml_verifier::RequestMessages::<Test>::insert(7, sp_core::blake2_256(&payload));
+
+        // Any other transfer still needs its signature
+        assert_noop!(
+            QuantumVault::vault_transfer(RuntimeOrigin::signed(alice), vec![], bob, 11, Some(7)),
+            Error::<Test>::InvalidSignature
+        );
+
+        assert_ok!(QuantumVault::vault_transfer(
+            RuntimeOrigin::signed(alice),
+            vec![],
+            bob,
+            10,
+            Some(7)
+        ));
+        assert_eq!(VaultNonces::<Test>::get(alice), 1);
+        assert_eq!(
+            pallet_reml_verifier::ConsumedRequests::<Test>::get(7),
+            Some((alice, 1))
+        );
+    });
+}
+
+#[test]
+fn proof_backed_transfer_pays_its_premium_share() {
+    new_test_ext().execute_with(|| {
+        let alice = 1;
+        let bob = 2;
+        ProofBackedPremiumShare::set(Permill::from_percent(20));
+        assert_ok!(QuantumVault::create_vault(
+            RuntimeOrigin::signed(alice),
+            mock_public_key()
+        ));
+        let payload = QuantumVault::vault_transfer_payload(&alice, &bob, 100).unwrap();
+        let alice_key_hash = sp_core::blake2_256(&mock_public_key());
+        for request_id in [7, 8] {
+            pallet_reml_verifier::VerifiedRequests::<Test>::insert(request_id, (1, 1));
+            pallet_reml_verifier::RequestOwners::<Test>::insert(request_id, alice_key_hash);
+        }
+        // Only request 7's proof committed the transfer's message
+        pallet_reml_verifier::RequestMessages::<Test>::insert(7, sp_core::blake2_256(&payload));
+        let transfer = |request_id| crate::Call::<Test>::vault_transfer {
+            signature: vec![],
+            to: bob,
+            amount: 100,
+            request_id: Some(request_id),
+        };
+        assert_eq!(QuantumVault::call_premium(&alice, &transfer(7)), PREMIUM_FEE / 5);
+        assert_eq!(QuantumVault::call_premium(&alice, &transfer(8)), PREMIUM_FEE);
+
+        assert_ok!(QuantumVault::vault_transfer(
+            RuntimeOrigin::signed(alice),
+            vec![],
+            bob,
+            100,
+            Some(7),
+        ));
+        assert_eq!(Balances::free_balance(alice), 998 - 100 - PREMIUM_FEE / 5);
+
+        // In-band transfers keep paying the whole premium
+        assert_eq!(QuantumVault::transfer_premium(&alice, &bob, 100, false), PREMIUM_FEE);
+    });
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// PREMIUM MODE TESTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn create_vault_defaults_to_flat_premium() {
+    new_test_ext().execute_with(|| {
+        let alice = 1;
+        assert_ok!(QuantumVault::create_vault(
+            RuntimeOrigin::signed(alice),
+            mock_public_key()
+        ));
+
+        assert_eq!(VaultPremiumModes::<Test>::get(alice), PremiumMode::Flat);
+        assert!(!VaultPremiumModes::<Test>::contains_key(alice));
+        assert_eq!(QuantumVault::transfer_premium(&alice, &2, 1_000, false), PREMIUM_FEE);
+    });
+}
+
+#[test]
+fn percentage_premium_scales_with_amount_up_to_cap() {
+    new_test_ext().execute_with(|| {
+        let alice = 1;
+        let bob = 2;
+
+        assert_ok!(QuantumVault::create_vault_with_premium_mode(
+            RuntimeOrigin::signed(alice),
+            mock_public_key(),
+            PremiumMode::Percentage
+        ));
+        System::assert_has_event(RuntimeEvent::QuantumVault(Event::VaultPremiumModeSet {
+            who: alice,
+            mode: PremiumMode::Percentage,
+        }));
+        assert_eq!(Balances::free_balance(alice), 998);
+
+        // 5% of 100 = 5 units, below the 20-unit cap
+        let signature = create_transfer_signature(alice, bob, 100, 0);
+        assert_ok!(QuantumVault::vault_transfer(
+            RuntimeOrigin::signed(alice),
+            signature,
+            bob,
+            100,
+            None
+        ));
+        assert_eq!(Balances::free_balance(alice), 998 - 100 - 5);
+
+        // 5% of 500 = 25 units, capped at 20
+        let signature = create_transfer_signature(alice, bob, 500, 1);
+        assert_ok!(QuantumVault::vault_transfer(
+            RuntimeOrigin::signed(alice),
+            signature,
+            bob,
+            500,
+            None
+        ));
+        assert_eq!(Balances::free_balance(alice), 893 - 500 - 20);
+        assert_eq!(Balances::free_balance(TREASURY), 1 + CREATION_FEE + 5 + 20);
+
+        System::assert_last_event(RuntimeEvent::QuantumVault(Event::VaultTransfer {
+            from: alice,
+            to: bob,
+            amount: 500,
+            nonce: 1,
+            premium_fee: 20,
+            request_id: None,
+        }));
+    });
+}
+
+#[test]
+fn vault_to_vault_transfer_gets_premium_discount() {
+    new_test_ext().execute_with(|| {
+        let alice = 1;
+        let bob = 2;
+        assert_ok!(QuantumVault::create_vault(
+            RuntimeOrigin::signed(alice),
+            mock_public_key()
+        ));
+        assert_ok!(QuantumVault::create_vault(
+            RuntimeOrigin::signed(bob),
+            get_public_key_for_account(bob)
+        ));
+
+        // 50% off the flat premium of 10 units
+        let signature = create_transfer_signature(alice, bob, 100, 0);
+        assert_ok!(QuantumVault::vault_transfer(
+            RuntimeOrigin::signed(alice),
+            signature,
+            bob,
+            100,
+            None
+        ));
+        assert_eq!(Balances::free_balance(alice), 998 - 100 - PREMIUM_FEE / 2);
+
+        System::assert_last_event(RuntimeEvent::QuantumVault(Event::VaultTransfer {
+            from: alice,
+            to: bob,
+            amount: 100,
+            nonce: 0,
+            premium_fee: PREMIUM_FEE / 2,
+            request_id: None,
+        }));
+
+        // Transfers to plain accounts pay the full premium
+        assert_eq!(QuantumVault::transfer_premium(&alice, &3, 100, false), PREMIUM_FEE);
+    });
+}
+
+#[test]
+fn destroy_vault_clears_premium_mode() {
+    new_test_ext().execute_with(|| {
+        let alice = 1;
+        assert_ok!(QuantumVault::create_vault_with_premium_mode(
+            RuntimeOrigin::signed(alice),
+            mock_public_key(),
+            PremiumMode::Percentage
+        ));
+
+        let signature = create_destroy_signature(alice, 0);
+        assert_ok!(QuantumVault::destroy_vault(
+            RuntimeOrigin::signed(alice),
+            signature
+        ));
+
+        assert!(!VaultPremiumModes::<Test>::contains_key(alice));
+    });
+}
+
+#[test]
+fn hash_ml_dsa_vault_verifies_prehashed_messages_with_context() {
+    new_test_ext().execute_with(|| {
+        let (alice, bob) = (1, 2);
+        let context = b"hsm-signer".to_vec();
+        assert_ok!(QuantumVault::create_vault_with_message_mode(
+            RuntimeOrigin::signed(alice),
+            mock_public_key(),
+            PremiumMode::Flat,
+            MessageMode::PreHashSha256,
+            context.clone().try_into().unwrap()
+        ));
+        System::assert_has_event(RuntimeEvent::QuantumVault(Event::VaultMessageModeSet {
+            who: alice,
+            mode: MessageMode::PreHashSha256,
+            context: context.clone().try_into().unwrap(),
+        }));
+
+        // Signatures of the bare payload no longer verify
+        assert_noop!(
+            QuantumVault::vault_transfer(
+                RuntimeOrigin::signed(alice),
+                create_transfer_signature(alice, bob, 100, 0),
+                bob,
+                100,
+                None
+            ),
+            Error::<Test>::SignatureVerificationFailed
+        );
+
+        // 1 || len(ctx) || ctx || OID(SHA-256) || SHA-256(payload)
+        let payload =
+            tesserax_vault_payload::transfer(&alice.encode(), &bob.encode(), &100u64.encode(), 0);
+        let mut message = vec![1, context.len() as u8];
+        message.extend_from_slice(&context);
+        message.extend_from_slice(&[
+            0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x01,
+        ]);
+        message.extend_from_slice(&sp_core::hashing::sha2_256(&payload));
+        assert_eq!(
+            MessageMode::PreHashSha256.signed_message(&context, &payload),
+            message
+        );
+
+        let signature = alice_keypair().sign(&message).to_vec();
+        assert_ok!(QuantumVault::vault_transfer(
+            RuntimeOrigin::signed(alice),
+            signature,
+            bob,
+            100,
+            None
+        ));
+
+        let destroy = MessageMode::PreHashSha256.signed_message(
+            &context,
+            &tesserax_vault_payload::destroy(&alice.encode(), 1),
+        );
+        assert_ok!(QuantumVault::destroy_vault(
+            RuntimeOrigin::signed(alice),
+            alice_keypair().sign(&destroy).to_vec()
+        ));
+        assert!(!VaultMessageModes::<Test>::contains_key(alice));
+    });
+}
+
+#[test]
+fn pure_message_mode_prefixes_domain_and_context() {
+    let message = MessageMode::Pure.signed_message(b"ctx", b"payload");
+    assert_eq!(message, b"\x00\x03ctxpayload".to_vec());
+    assert_eq!(
+        MessageMode::Raw.signed_message(b"", b"payload"),
+        b"payload".to_vec()
+    );
+    let prehashed = MessageMode::PreHashSha3_256.signed_message(b"", b"payload");
+    assert_eq!(prehashed.len(), 2 + 11 + 32);
+}
+
+#[test]
+fn raw_message_mode_rejects_context() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            QuantumVault::create_vault_with_message_mode(
+                RuntimeOrigin::signed(1),
+                mock_public_key(),
+                PremiumMode::Flat,
+                MessageMode::Raw,
+                b"ctx".to_vec().try_into().unwrap()
+            ),
+            Error::<Test>::ContextWithoutMessageMode
+        );
+    });
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SECURITY LEVEL TESTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn level5_vault_verifies_with_dilithium5_and_pays_scaled_fee() {
+    new_test_ext().execute_with(|| {
+        let (alice, bob) = (1, 2);
+        let keypair = crystals_dilithium::dilithium5::Keypair::generate(None);
+        let treasury_before = Balances::free_balance(TREASURY);
+
+        assert_ok!(QuantumVault::create_vault_with_security_level(
+            RuntimeOrigin::signed(alice),
+            keypair.public.to_bytes().to_vec(),
+            SecurityLevel::Level5,
+            PremiumMode::Flat
+        ));
+        assert_eq!(
+            VaultSecurityLevels::<Test>::get(alice),
+            SecurityLevel::Level5
+        );
+        assert_eq!(
+            Balances::free_balance(TREASURY),
+            treasury_before + 3 * CREATION_FEE
+        );
+        System::assert_has_event(RuntimeEvent::QuantumVault(Event::VaultSecurityLevelSet {
+            who: alice,
+            level: SecurityLevel::Level5,
+        }));
+        assert_eq!(
+            QuantumVault::vault_descriptor(&alice).unwrap().scheme,
+            SignatureScheme::Dilithium5
+        );
+
+        // Dilithium2 signatures are the wrong size for the vault
+        assert_noop!(
+            QuantumVault::vault_transfer(
+                RuntimeOrigin::signed(alice),
+                create_transfer_signature(alice, bob, 100, 0),
+                bob,
+                100,
+                None
+            ),
+            Error::<Test>::InvalidSignature
+        );
+
+        let payload =
+            tesserax_vault_payload::transfer(&alice.encode(), &bob.encode(), &100u64.encode(), 0);
+        assert_ok!(QuantumVault::vault_transfer(
+            RuntimeOrigin::signed(alice),
+            keypair.sign(&payload).to_vec(),
+            bob,
+            100,
+            None
+        ));
+
+        let destroy = tesserax_vault_payload::destroy(&alice.encode(), 1);
+        assert_ok!(QuantumVault::destroy_vault(
+            RuntimeOrigin::signed(alice),
+            keypair.sign(&destroy).to_vec()
+        ));
+        assert!(!VaultSecurityLevels::<Test>::contains_key(alice));
+    });
+}
+
+#[test]
+fn sphincs_vault_verifies_hash_based_signatures() {
+    use pqcrypto_sphincsplus::sphincssha2128ssimple::{detached_sign, keypair};
+    use pqcrypto_traits::sign::{DetachedSignature, PublicKey};
+
+    new_test_ext().execute_with(|| {
+        let (alice, bob) = (1, 2);
+        let (public_key, secret_key) = keypair();
+        let sign = |payload: &[u8]| detached_sign(payload, &secret_key).as_bytes().to_vec();
+        let treasury_before = Balances::free_balance(TREASURY);
+
+        // Dilithium keys are not SPHINCS+ keys
+        assert_noop!(
+            QuantumVault::create_vault_with_security_level(
+                RuntimeOrigin::signed(alice),
+                mock_public_key(),
+                SecurityLevel::SphincsSha2_128s,
+                PremiumMode::Flat
+            ),
+            Error::<Test>::InvalidPublicKey
+        );
+        assert_ok!(QuantumVault::create_vault_with_security_level(
+            RuntimeOrigin::signed(alice),
+            public_key.as_bytes().to_vec(),
+            SecurityLevel::SphincsSha2_128s,
+            PremiumMode::Flat
+        ));
+        assert_eq!(
+            VaultSecurityLevels::<Test>::get(alice),
+            SecurityLevel::SphincsSha2_128s
+        );
+        assert_eq!(Balances::free_balance(TREASURY), treasury_before + 2 * CREATION_FEE);
+        assert_eq!(
+            QuantumVault::vault_descriptor(&alice).unwrap().scheme,
+            SignatureScheme::SphincsSha2_128s
+        );
+
+        let payload =
+            tesserax_vault_payload::transfer(&alice.encode(), &bob.encode(), &100u64.encode(), 0);
+        let other =
+            tesserax_vault_payload::transfer(&alice.encode(), &bob.encode(), &101u64.encode(), 0);
+        assert_noop!(
+            QuantumVault::vault_transfer(
+                RuntimeOrigin::signed(alice),
+                sign(&other),
+                bob,
+                100,
+                None
+            ),
+            Error::<Test>::SignatureVerificationFailed
+        );
+        assert_ok!(QuantumVault::vault_transfer(
+            RuntimeOrigin::signed(alice),
+            sign(&payload),
+            bob,
+            100,
+            None
+        ));
+
+        let destroy = tesserax_vault_payload::destroy(&alice.encode(), 1);
+        assert_ok!(QuantumVault::destroy_vault(RuntimeOrigin::signed(alice), sign(&destroy)));
+        assert!(!VaultSecurityLevels::<Test>::contains_key(alice));
+    });
+}
+
+#[test]
+fn security_level_rejects_keys_of_other_levels() {
+    new_test_ext().execute_with(|| {
+        let alice = 1;
+        let level3 = crystals_dilithium::dilithium3::Keypair::generate(None);
+
+        assert_noop!(
+            QuantumVault::create_vault_with_security_level(
+                RuntimeOrigin::signed(alice),
+                mock_public_key(),
+                SecurityLevel::Level3,
+                PremiumMode::Flat
+            ),
+            Error::<Test>::InvalidPublicKey
+        );
+        assert_noop!(
+            QuantumVault::create_vault(
+                RuntimeOrigin::signed(alice),
+                level3.public.to_bytes().to_vec()
+            ),
+            Error::<Test>::InvalidPublicKey
+        );
+
+        // Level 2 is the default and is not stored
+        assert_ok!(QuantumVault::create_vault_with_security_level(
+            RuntimeOrigin::signed(alice),
+            mock_public_key(),
+            SecurityLevel::Level2,
+            PremiumMode::Flat
+        ));
+        assert!(!VaultSecurityLevels::<Test>::contains_key(alice));
+    });
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// ALLOWANCE TESTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn vault_approve_lets_spender_pull_up_to_limit() {
+    new_test_ext().execute_with(|| {
+        let (alice, bob, charlie) = (1, 2, 3);
+        assert_ok!(QuantumVault::create_vault(
+            RuntimeOrigin::signed(alice),
+            mock_public_key()
+        ));
+
+        assert_ok!(QuantumVault::vault_approve(
+            RuntimeOrigin::signed(alice),
+            bob,
+            100,
+            create_approve_signature(alice, bob, 100, 0)
+        ));
+        System::assert_has_event(RuntimeEvent::QuantumVault(Event::VaultApproval {
+            vault: alice,
+            spender: bob,
+            limit: 100,
+        }));
+        assert_eq!(VaultNonces::<Test>::get(alice), 1);
+        assert_eq!(QuantumVault::allowance(&alice, &bob), 100);
+
+        // The vault pays the premium on top of the allowance
+        let alice_before = Balances::free_balance(alice);
+        assert_ok!(QuantumVault::vault_transfer_from(
+            RuntimeOrigin::signed(bob),
+            alice,
+            charlie,
+            60
+        ));
+        System::assert_has_event(RuntimeEvent::QuantumVault(Event::VaultTransferFrom {
+            vault: alice,
+            spender: bob,
+            to: charlie,
+            amount: 60,
+            premium_fee: PREMIUM_FEE,
+        }));
+        assert_eq!(
+            Balances::free_balance(alice),
+            alice_before - 60 - PREMIUM_FEE
+        );
+        assert_eq!(Balances::free_balance(charlie), 160);
+        assert_eq!(QuantumVault::allowance(&alice, &bob), 40);
+
+        assert_noop!(
+            QuantumVault::vault_transfer_from(RuntimeOrigin::signed(bob), alice, charlie, 41),
+            Error::<Test>::AllowanceExceeded
+        );
+        assert_noop!(
+            QuantumVault::vault_transfer_from(RuntimeOrigin::signed(charlie), alice, charlie, 1),
+            Error::<Test>::AllowanceExceeded
+        );
+
+        // Spending the whole allowance removes it
+        assert_ok!(QuantumVault::vault_transfer_from(
+            RuntimeOrigin::signed(bob),
+            alice,
+            charlie,
+            40
+        ));
+        assert!(!VaultAllowances::<Test>::contains_key(alice));
+    });
+}
+
+#[test]
+fn vault_approve_needs_vault_signature_and_can_be_revoked() {
+    new_test_ext().execute_with(|| {
+        let (alice, bob) = (1, 2);
+        assert_ok!(QuantumVault::create_vault(
+            RuntimeOrigin::signed(alice),
+            mock_public_key()
+        ));
+
+        // Signed for another limit
+        assert_noop!(
+            QuantumVault::vault_approve(
+                RuntimeOrigin::signed(alice),
+                bob,
+                1_000,
+                create_approve_signature(alice, bob, 100, 0)
+            ),
+            Error::<Test>::SignatureVerificationFailed
+        );
+
+        assert_ok!(QuantumVault::vault_approve(
+            RuntimeOrigin::signed(alice),
+            bob,
+            100,
+            create_approve_signature(alice, bob, 100, 0)
+        ));
+        assert_ok!(QuantumVault::vault_approve(
+            RuntimeOrigin::signed(alice),
+            bob,
+            0,
+            create_approve_signature(alice, bob, 0, 1)
+        ));
+        assert_eq!(QuantumVault::allowance(&alice, &bob), 0);
+        assert_noop!(
+            QuantumVault::vault_transfer_from(RuntimeOrigin::signed(bob), alice, bob, 1),
+            Error::<Test>::AllowanceExceeded
+        );
+
+        // Allowances do not outlive the vault
+        assert_ok!(QuantumVault::vault_approve(
+            RuntimeOrigin::signed(alice),
+            bob,
+            100,
+            create_approve_signature(alice, bob, 100, 2)
+        ));
+        assert_ok!(QuantumVault::destroy_vault(
+            RuntimeOrigin::signed(alice),
+            create_destroy_signature(alice, 3)
+        ));
+        assert!(!VaultAllowances::<Test>::contains_key(alice));
+        assert_noop!(
+            QuantumVault::vault_transfer_from(RuntimeOrigin::signed(bob), alice, bob, 1),
+            Error::<Test>::NotVault
+        );
+    });
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SPENDING POLICY TESTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+fn policy(daily_limit: Option<u64>, whitelist: Option<Vec<u64>>) -> VaultPolicyOf<Test> {
+    VaultPolicy {
+        daily_limit,
+        whitelist: whitelist.map(|accounts| accounts.try_into().unwrap()),
+    }
+}
+
+fn set_policy(vault: u64, policy: VaultPolicyOf<Test>) {
+    let nonce = VaultNonces::<Test>::get(vault);
+    let signature = create_policy_signature(vault, &policy, nonce);
+    assert_ok!(QuantumVault::set_vault_policy(
+        RuntimeOrigin::signed(vault),
+        policy,
+        signature
+    ));
+}
+
+fn transfer(vault: u64, to: u64, amount: u64) -> frame_support::dispatch::DispatchResult {
+    let nonce = VaultNonces::<Test>::get(vault);
+    QuantumVault::vault_transfer(
+        RuntimeOrigin::signed(vault),
+        create_transfer_signature(vault, to, amount, nonce),
+        to,
+        amount,
+        None,
+    )
+}
+
+#[test]
+fn whitelist_limits_vault_recipients() {
+    new_test_ext().execute_with(|| {
+        let (alice, bob, charlie) = (1, 2, 3);
+        assert_ok!(QuantumVault::create_vault(
+            RuntimeOrigin::signed(alice),
+            mock_public_key()
+        ));
+        set_policy(alice, policy(None, Some(vec![bob])));
+        System::assert_last_event(RuntimeEvent::QuantumVault(Event::VaultPolicySet {
+            vault: alice,
+            policy: policy(None, Some(vec![bob])),
+        }));
+
+        assert_ok!(transfer(alice, bob, 10));
+        assert_noop!(
+            transfer(alice, charlie, 10),
+            Error::<Test>::RecipientNotWhitelisted
+        );
+
+        // Spenders with an allowance are held to the whitelist too
+        assert_ok!(QuantumVault::vault_approve(
+            RuntimeOrigin::signed(alice),
+            charlie,
+            100,
+            create_approve_signature(alice, charlie, 100, 2)
+        ));
+        assert_noop!(
+            QuantumVault::vault_transfer_from(RuntimeOrigin::signed(charlie), alice, charlie, 10),
+            Error::<Test>::RecipientNotWhitelisted
+        );
+        assert_ok!(QuantumVault::vault_transfer_from(
+            RuntimeOrigin::signed(charlie),
+            alice,
+            bob,
+            10
+        ));
+    });
+}
+
+#[test]
+fn daily_limit_applies_over_rolling_window() {
+    new_test_ext().execute_with(|| {
+        let (alice, bob) = (1, 2);
+        assert_ok!(QuantumVault::create_vault(
+            RuntimeOrigin::signed(alice),
+            mock_public_key()
+        ));
+        set_policy(alice, policy(Some(100), None));
+
+        assert_ok!(transfer(alice, bob, 60));
+        System::set_block_number(10);
+        assert_ok!(transfer(alice, bob, 40));
+
+        // The limit is used up until the first transfer leaves the window,
+        // 24 blocks after its one-block bucket ends
+        System::set_block_number(25);
+        assert_noop!(transfer(alice, bob, 1), Error::<Test>::DailyLimitExceeded);
+
+        System::set_block_number(26);
+        assert_noop!(transfer(alice, bob, 61), Error::<Test>::DailyLimitExceeded);
+        assert_ok!(transfer(alice, bob, 60));
+        assert_eq!(
+            VaultSpending::<Test>::get(alice).into_inner(),
+            vec![(10, 40), (26, 60)]
+        );
+
+        // The premium does not count against the limit
+        assert_eq!(
+            Balances::free_balance(alice),
+            1000 - CREATION_FEE - 160 - 3 * PREMIUM_FEE
+        );
+    });
+}
+
+#[test]
+fn vault_policy_needs_vault_signature_and_can_be_cleared() {
+    new_test_ext().execute_with(|| {
+        let (alice, bob) = (1, 2);
+        assert_ok!(QuantumVault::create_vault(
+            RuntimeOrigin::signed(alice),
+            mock_public_key()
+        ));
+
+        // Signed for a higher limit than submitted
+        assert_noop!(
+            QuantumVault::set_vault_policy(
+                RuntimeOrigin::signed(alice),
+                policy(Some(10), None),
+                create_policy_signature(alice, &policy(Some(1_000), None), 0)
+            ),
+            Error::<Test>::SignatureVerificationFailed
+        );
+        assert_noop!(
+            QuantumVault::set_vault_policy(
+                RuntimeOrigin::signed(bob),
+                policy(Some(10), None),
+                create_policy_signature(bob, &policy(Some(10), None), 0)
+            ),
+            Error::<Test>::NotVault
+        );
+
+        set_policy(alice, policy(Some(10), None));
+        assert_ok!(transfer(alice, bob, 10));
+        assert_noop!(transfer(alice, bob, 1), Error::<Test>::DailyLimitExceeded);
+
+        // An unrestricted policy removes it
+        set_policy(alice, policy(None, None));
+        System::assert_last_event(RuntimeEvent::QuantumVault(Event::VaultPolicyCleared {
+            vault: alice,
+        }));
+        assert!(!VaultPolicies::<Test>::contains_key(alice));
+        assert!(!VaultSpending::<Test>::contains_key(alice));
+        assert_ok!(transfer(alice, bob, 100));
+
+        // Policies do not outlive the vault
+        set_policy(alice, policy(Some(10), Some(vec![bob])));
+        let nonce = VaultNonces::<Test>::get(alice);
+        assert_ok!(QuantumVault::destroy_vault(
+            RuntimeOrigin::signed(alice),
+            create_destroy_signature(alice, nonce)
+        ));
+        assert!(!VaultPolicies::<Test>::contains_key(alice));
+    });
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // VAULT DESTRUCTION TESTS
 // ═══════════════════════════════════════════════════════════════════════════
@@ -393,6 +1256,72 @@ fn destroy_vault_fails_for_non_vault() {
     });
 }
 
+#[test]
+fn destroy_vault_archives_key() {
+    new_test_ext().execute_with(|| {
+        let alice = 1;
+        let bob = 2;
+        let public_key = mock_public_key();
+        let public_key_hash = sp_core::blake2_256(&public_key);
+
+        assert_ok!(QuantumVault::create_vault(RuntimeOrigin::signed(alice), public_key));
+        assert_ok!(QuantumVault::vault_transfer(
+            RuntimeOrigin::signed(alice),
+            create_transfer_signature(alice, bob, 100, 0),
+            bob,
+            100,
+            None
+        ));
+
+        System::set_block_number(5);
+        assert_ok!(QuantumVault::destroy_vault(
+            RuntimeOrigin::signed(alice),
+            create_destroy_signature(alice, 1)
+        ));
+
+        assert_eq!(
+            QuantumVault::archived_key(public_key_hash),
+            Some(ArchivedVaultKey {
+                account: alice,
+                public_key_hash,
+                valid_from: 1,
+                valid_until: 5,
+                signatures_used: 1,
+            })
+        );
+        assert!(!VaultKeyValidFrom::<Test>::contains_key(alice));
+        System::assert_has_event(RuntimeEvent::QuantumVault(Event::VaultKeyArchived {
+            who: alice,
+            public_key_hash,
+            index: 0,
+        }));
+    });
+}
+
+#[test]
+fn key_archive_keeps_only_most_recent_keys() {
+    new_test_ext().execute_with(|| {
+        // MaxArchivedKeys = 2
+        for account in [1, 2, 3] {
+            assert_ok!(QuantumVault::create_vault(
+                RuntimeOrigin::signed(account),
+                get_public_key_for_account(account)
+            ));
+            assert_ok!(QuantumVault::destroy_vault(
+                RuntimeOrigin::signed(account),
+                create_destroy_signature(account, 0)
+            ));
+        }
+
+        let hash = |account| sp_core::blake2_256(&get_public_key_for_account(account));
+        assert_eq!(NextArchiveIndex::<Test>::get(), 3);
+        assert!(!ArchivedKeys::<Test>::contains_key(0));
+        assert_eq!(QuantumVault::archived_key(hash(1)), None);
+        assert_eq!(QuantumVault::archived_key(hash(2)).map(|k| k.account), Some(2));
+        assert_eq!(QuantumVault::archived_key(hash(3)).map(|k| k.account), Some(3));
+    });
+}
+
 #[test]
 fn destroy_vault_fails_with_invalid_signature() {
     new_test_ext().execute_with(|| {
@@ -409,10 +1338,7 @@ fn destroy_vault_fails_with_invalid_signature() {
         let wrong_keypair = TestKeypair::from_seed(b"wrong_key_for_destroy");
         let nonce = VaultNonces::<Test>::get(alice);
 
-        use codec::Encode;
-        let mut message = b"TESSERAX_VAULT_DESTROY:".to_vec();
-        message.extend(alice.encode());
-        message.extend(nonce.encode());
+        let message = tesserax_vault_payload::destroy(&alice.encode(), nonce);
 
         let invalid_signature = wrong_keypair.sign(&message).to_vec();
 
@@ -457,19 +1383,170 @@ fn can_transfer_works() {
         let bob = 2;
         let public_key = mock_public_key();
 
-        // Initially, everyone can transfer
-        assert!(QuantumVault::can_transfer(&alice));
-        assert!(QuantumVault::can_transfer(&bob));
+        // Initially, everyone can transfer
+        assert!(QuantumVault::can_transfer(&alice));
+        assert!(QuantumVault::can_transfer(&bob));
+
+        // Alice creates a vault
+        assert_ok!(QuantumVault::create_vault(
+            RuntimeOrigin::signed(alice),
+            public_key
+        ));
+
+        // Now Alice cannot transfer (is vault), Bob can
+        assert!(!QuantumVault::can_transfer(&alice));
+        assert!(QuantumVault::can_transfer(&bob));
+    });
+}
+
+#[test]
+fn vault_nonce_tracks_vault_status() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(QuantumVault::vault_nonce(&1), None);
+
+        assert_ok!(QuantumVault::create_vault(
+            RuntimeOrigin::signed(1),
+            get_public_key_for_account(1)
+        ));
+        assert_eq!(QuantumVault::vault_nonce(&1), Some(0));
+    });
+}
+
+#[test]
+fn vault_transfer_payload_is_what_the_vault_signs() {
+    new_test_ext().execute_with(|| {
+        let alice = 1;
+        let bob = 2;
+        assert_eq!(QuantumVault::vault_transfer_payload(&alice, &bob, 100), None);
+
+        assert_ok!(QuantumVault::create_vault(
+            RuntimeOrigin::signed(alice),
+            get_public_key_for_account(alice)
+        ));
+        let payload = QuantumVault::vault_transfer_payload(&alice, &bob, 100).unwrap();
+        let signature = alice_keypair().sign(&payload).to_vec();
+        assert_ok!(QuantumVault::vault_transfer(
+            RuntimeOrigin::signed(alice),
+            signature,
+            bob,
+            100,
+            None
+        ));
+
+        // The next payload commits the next nonce
+        assert_eq!(
+            QuantumVault::vault_transfer_payload(&alice, &bob, 100),
+            Some(tesserax_vault_payload::transfer(
+                &alice.encode(),
+                &bob.encode(),
+                &100u64.encode(),
+                1
+            ))
+        );
+    });
+}
+
+#[test]
+fn list_vaults_paginates() {
+    new_test_ext().execute_with(|| {
+        for who in [1, 2, 3] {
+            assert_ok!(QuantumVault::create_vault(
+                RuntimeOrigin::signed(who),
+                get_public_key_for_account(who)
+            ));
+        }
+
+        // Page size is respected
+        let first = QuantumVault::list_vaults(None, 2);
+        assert_eq!(first.len(), 2);
+
+        // Next page starts after the last account of the previous one
+        let second = QuantumVault::list_vaults(Some(first[1].account), 2);
+        assert_eq!(second.len(), 1);
+
+        let mut seen: Vec<u64> = first.iter().chain(second.iter()).map(|v| v.account).collect();
+        seen.sort();
+        assert_eq!(seen, vec![1, 2, 3]);
+
+        let entry = &second[0];
+        assert_eq!(entry.public_key, get_public_key_for_account(entry.account));
+        assert_eq!(entry.nonce, 0);
+
+        // Past the end
+        assert!(QuantumVault::list_vaults(Some(entry.account), 2).is_empty());
+    });
+}
+
+#[test]
+fn value_locked_sums_vault_balances_across_pages() {
+    new_test_ext().execute_with(|| {
+        for who in [1, 2] {
+            assert_ok!(QuantumVault::create_vault(
+                RuntimeOrigin::signed(who),
+                get_public_key_for_account(who)
+            ));
+        }
+        // Plain incoming transfers count too
+        assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(3), 1, 50));
+
+        let first = QuantumVault::value_locked(None, 1);
+        assert_eq!(first.vaults, 1);
+        let second = QuantumVault::value_locked(first.last_key, 1);
+        assert_eq!(second.vaults, 1);
+
+        // 998 + 50 (alice) + 498 (bob)
+        assert_eq!(first.value_locked + second.value_locked, 1_546);
+
+        let end = QuantumVault::value_locked(second.last_key, 1);
+        assert_eq!((end.vaults, end.value_locked, end.last_key), (0, 0, None));
+    });
+}
+
+#[test]
+fn era_stats_count_activity_in_a_ring_of_recent_eras() {
+    new_test_ext().execute_with(|| {
+        // Era 0: alice creates a vault and sends from it
+        assert_ok!(QuantumVault::create_vault(RuntimeOrigin::signed(1), mock_public_key()));
+        assert_ok!(transfer(1, 3, 100));
 
-        // Alice creates a vault
+        // Era 1: bob creates a vault, alice destroys hers
+        System::set_block_number(15);
         assert_ok!(QuantumVault::create_vault(
-            RuntimeOrigin::signed(alice),
-            public_key
+            RuntimeOrigin::signed(2),
+            get_public_key_for_account(2)
+        ));
+        let nonce = VaultNonces::<Test>::get(1);
+        assert_ok!(QuantumVault::destroy_vault(
+            RuntimeOrigin::signed(1),
+            create_destroy_signature(1, nonce)
         ));
 
-        // Now Alice cannot transfer (is vault), Bob can
-        assert!(!QuantumVault::can_transfer(&alice));
-        assert!(QuantumVault::can_transfer(&bob));
+        assert_eq!(
+            QuantumVault::era_stats(),
+            vec![
+                EraStats {
+                    era: 0,
+                    creations: 1,
+                    transfers: 1,
+                    destroys: 0,
+                    premium_collected: PREMIUM_FEE
+                },
+                EraStats { era: 1, creations: 1, transfers: 0, destroys: 1, premium_collected: 0 },
+            ]
+        );
+
+        // Era 4: eras 0 and 1 fell out of the three kept, and era 4 reuses
+        // era 1's slot
+        System::set_block_number(45);
+        assert!(QuantumVault::era_stats().is_empty());
+        assert_ok!(QuantumVault::create_vault(
+            RuntimeOrigin::signed(3),
+            get_public_key_for_account(3)
+        ));
+        assert_eq!(
+            QuantumVault::era_stats(),
+            vec![EraStats { era: 4, creations: 1, ..Default::default() }]
+        );
     });
 }
 
@@ -563,6 +1640,37 @@ fn vault_creation_fee_goes_to_treasury() {
     });
 }
 
+#[test]
+fn premium_share_goes_to_incentive_pool() {
+    new_test_ext().execute_with(|| {
+        let alice = 1;
+        let bob = 2;
+        IncentivePoolShare::set(Permill::from_percent(20));
+        assert_ok!(QuantumVault::create_vault(
+            RuntimeOrigin::signed(alice),
+            mock_public_key()
+        ));
+
+        let signature = create_transfer_signature(alice, bob, 100, 0);
+        assert_ok!(QuantumVault::vault_transfer(
+            RuntimeOrigin::signed(alice),
+            signature,
+            bob,
+            100,
+            None
+        ));
+
+        // 20% of the premium to the pool, the rest and the creation fee to treasury
+        assert_eq!(Balances::free_balance(alice), 888);
+        assert_eq!(Balances::free_balance(IncentivePoolAccount::get()), 2);
+        assert_eq!(Balances::free_balance(TREASURY), 1 + CREATION_FEE + 8);
+        assert_eq!(
+            TotalFeesCollected::<Test>::get(),
+            CREATION_FEE + PREMIUM_FEE
+        );
+    });
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // CRYPTOGRAPHIC EDGE CASES
 // ═══════════════════════════════════════════════════════════════════════════
@@ -612,3 +1720,466 @@ fn different_users_have_different_keypairs() {
         // but they are guaranteed to be different if public keys are different
     });
 }
+
+#[test]
+fn dry_run_transfer_reports_premium_without_side_effects() {
+    new_test_ext().execute_with(|| {
+        let alice = 1;
+        let bob = 2;
+        assert_ok!(QuantumVault::create_vault(RuntimeOrigin::signed(alice), mock_public_key()));
+
+        let signature = create_transfer_signature(alice, bob, 100, 0);
+        assert_eq!(
+            QuantumVault::dry_run_transfer(alice, signature.clone(), bob, 100, None),
+            Ok(PREMIUM_FEE)
+        );
+
+        // Nothing was applied, so the same signature still goes through
+        assert_eq!(VaultNonces::<Test>::get(alice), 0);
+        assert_eq!(Balances::free_balance(alice), 998);
+        assert_eq!(Balances::free_balance(bob), 500);
+        assert_ok!(QuantumVault::vault_transfer(
+            RuntimeOrigin::signed(alice),
+            signature,
+            bob,
+            100,
+            None
+        ));
+    });
+}
+
+#[test]
+fn dry_run_transfer_reports_exact_error() {
+    new_test_ext().execute_with(|| {
+        let alice = 1;
+        let bob = 2;
+        assert_ok!(QuantumVault::create_vault(RuntimeOrigin::signed(alice), mock_public_key()));
+
+        let mut signature = create_transfer_signature(alice, bob, 100, 0);
+        signature[100] ^= 0xFF;
+        assert_eq!(
+            QuantumVault::dry_run_transfer(alice, signature, bob, 100, None),
+            Err(Error::<Test>::SignatureVerificationFailed.into())
+        );
+
+        assert_eq!(
+            QuantumVault::dry_run_transfer(bob, vec![0u8; 10], alice, 100, None),
+            Err(Error::<Test>::NotVault.into())
+        );
+    });
+}
+
+#[test]
+fn call_fees_quote_premium_and_creation_fee() {
+    use crate::Call;
+
+    new_test_ext().execute_with(|| {
+        let alice = 1;
+        let bob = 2;
+        assert_ok!(QuantumVault::create_vault(RuntimeOrigin::signed(alice), mock_public_key()));
+
+        // Transfers charge the paying vault's premium
+        let transfer = Call::<Test>::vault_transfer {
+            signature: vec![],
+            to: bob,
+            amount: 100,
+            request_id: None,
+        };
+        assert_eq!(QuantumVault::call_premium(&alice, &transfer), PREMIUM_FEE);
+        assert_eq!(QuantumVault::call_creation_fee(&transfer), 0);
+
+        // `vault_transfer_from` charges the vault, discounted into another vault
+        let pull = Call::<Test>::vault_transfer_from { vault: alice, to: alice, amount: 100 };
+        assert_eq!(QuantumVault::call_premium(&bob, &pull), PREMIUM_FEE / 2);
+
+        // Creation is scaled by the security level
+        let create = Call::<Test>::create_vault { public_key: vec![] };
+        assert_eq!(QuantumVault::call_creation_fee(&create), CREATION_FEE);
+        let create = Call::<Test>::create_vault_from_descriptor {
+            descriptor: VaultDescriptor {
+                version: VAULT_DESCRIPTOR_VERSION,
+                scheme: SignatureScheme::Dilithium5,
+                public_key: vec![],
+                premium_mode: PremiumMode::Flat,
+            },
+        };
+        assert_eq!(
+            QuantumVault::call_creation_fee(&create),
+            CREATION_FEE * SecurityLevel::Level5.creation_fee_multiplier() as u64
+        );
+        assert_eq!(QuantumVault::call_premium(&alice, &create), 0);
+    });
+}
+
+#[test]
+fn set_paused_requires_root() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            QuantumVault::set_paused(RuntimeOrigin::signed(1), true),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        assert_ok!(QuantumVault::set_paused(RuntimeOrigin::root(), true));
+        assert!(QuantumVault::is_paused());
+        System::assert_has_event(RuntimeEvent::QuantumVault(Event::VaultsPausedSet {
+            paused: true,
+        }));
+    });
+}
+
+#[test]
+fn paused_vaults_reject_operations_until_resumed() {
+    new_test_ext().execute_with(|| {
+        let alice = 1;
+        let bob = 2;
+        assert_ok!(QuantumVault::create_vault(RuntimeOrigin::signed(alice), mock_public_key()));
+        assert_ok!(QuantumVault::set_paused(RuntimeOrigin::root(), true));
+
+        let signature = create_transfer_signature(alice, bob, 100, 0);
+        assert_noop!(
+            QuantumVault::vault_transfer(
+                RuntimeOrigin::signed(alice),
+                signature.clone(),
+                bob,
+                100,
+                None
+            ),
+            Error::<Test>::VaultsPaused
+        );
+        assert_noop!(
+            QuantumVault::create_vault(RuntimeOrigin::signed(bob), mock_public_key()),
+            Error::<Test>::VaultsPaused
+        );
+        assert_noop!(
+            QuantumVault::destroy_vault(RuntimeOrigin::signed(alice), vec![0u8; 2420]),
+            Error::<Test>::VaultsPaused
+        );
+
+        assert_ok!(QuantumVault::set_paused(RuntimeOrigin::root(), false));
+        assert_ok!(QuantumVault::vault_transfer(
+            RuntimeOrigin::signed(alice),
+            signature,
+            bob,
+            100,
+            None
+        ));
+    });
+}
+
+#[test]
+fn vault_transfer_with_nonce_accepts_any_order_within_window() {
+    new_test_ext().execute_with(|| {
+        let alice = 1;
+        let bob = 2;
+        assert_ok!(QuantumVault::create_vault(RuntimeOrigin::signed(alice), mock_public_key()));
+
+        // Pre-signed payouts with nonces 0, 1, 2 land as 2, 0, 1
+        let transfer = |nonce: u64| {
+            QuantumVault::vault_transfer_with_nonce(
+                RuntimeOrigin::signed(alice),
+                create_transfer_signature(alice, bob, 10, nonce),
+                bob,
+                10,
+                nonce,
+                None,
+            )
+        };
+        assert_ok!(transfer(2));
+        assert_eq!(VaultNonces::<Test>::get(alice), 0);
+        assert!(QuantumVault::nonce_used(&alice, 2));
+        assert!(!QuantumVault::nonce_used(&alice, 1));
+
+        assert_ok!(transfer(0));
+        assert_eq!(VaultNonces::<Test>::get(alice), 1);
+
+        assert_ok!(transfer(1));
+        assert_eq!(VaultNonces::<Test>::get(alice), 3);
+        assert!(!VaultUsedNonces::<Test>::contains_key(alice));
+
+        // Plain vault_transfer keeps signing the lowest unused nonce
+        assert_ok!(QuantumVault::vault_transfer(
+            RuntimeOrigin::signed(alice),
+            create_transfer_signature(alice, bob, 10, 3),
+            bob,
+            10,
+            None
+        ));
+        assert_eq!(VaultNonces::<Test>::get(alice), 4);
+    });
+}
+
+#[test]
+fn vault_transfer_with_nonce_rejects_replays_and_far_nonces() {
+    new_test_ext().execute_with(|| {
+        let alice = 1;
+        let bob = 2;
+        assert_ok!(QuantumVault::create_vault(RuntimeOrigin::signed(alice), mock_public_key()));
+
+        let transfer = |nonce: u64| {
+            QuantumVault::vault_transfer_with_nonce(
+                RuntimeOrigin::signed(alice),
+                create_transfer_signature(alice, bob, 10, nonce),
+                bob,
+                10,
+                nonce,
+                None,
+            )
+        };
+        assert_ok!(transfer(5));
+        assert_noop!(transfer(5), Error::<Test>::InvalidNonce);
+
+        assert_ok!(transfer(0));
+        assert_noop!(transfer(0), Error::<Test>::InvalidNonce);
+
+        // Window is relative to the lowest unused nonce (1)
+        assert_noop!(transfer(1 + NONCE_WINDOW), Error::<Test>::NonceOutsideWindow);
+        assert_ok!(transfer(NONCE_WINDOW));
+        assert_eq!(VaultUsedNonces::<Test>::get(alice), (1 << 4) | (1 << (NONCE_WINDOW - 1)));
+    });
+}
+
+#[test]
+fn denied_key_cannot_secure_new_vaults() {
+    new_test_ext().execute_with(|| {
+        let public_key = mock_public_key();
+        let public_key_hash = sp_core::blake2_256(&public_key);
+
+        assert_noop!(
+            QuantumVault::deny_key(RuntimeOrigin::signed(1), public_key_hash, Default::default()),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(QuantumVault::deny_key(
+            RuntimeOrigin::root(),
+            public_key_hash,
+            Default::default()
+        ));
+        assert!(QuantumVault::is_key_denied(&public_key_hash));
+
+        assert_noop!(
+            QuantumVault::create_vault(RuntimeOrigin::signed(1), public_key.clone()),
+            Error::<Test>::KeyDenied
+        );
+        assert_noop!(
+            QuantumVault::create_vault_with_premium_mode(
+                RuntimeOrigin::signed(1),
+                public_key.clone(),
+                PremiumMode::Percentage
+            ),
+            Error::<Test>::KeyDenied
+        );
+
+        assert_ok!(QuantumVault::allow_key(RuntimeOrigin::root(), public_key_hash));
+        assert_noop!(
+            QuantumVault::allow_key(RuntimeOrigin::root(), public_key_hash),
+            Error::<Test>::KeyNotDenied
+        );
+        assert_ok!(QuantumVault::create_vault(RuntimeOrigin::signed(1), public_key));
+    });
+}
+
+#[test]
+fn deny_key_flags_existing_vaults_using_it() {
+    new_test_ext().execute_with(|| {
+        let alice = 1;
+        let bob = 2;
+        let public_key_hash = sp_core::blake2_256(&mock_public_key());
+        assert_ok!(QuantumVault::create_vault(RuntimeOrigin::signed(alice), mock_public_key()));
+
+        // Bob is not a vault, so only Alice is flagged
+        assert_ok!(QuantumVault::deny_key(
+            RuntimeOrigin::root(),
+            public_key_hash,
+            vec![alice, bob].try_into().unwrap()
+        ));
+        System::assert_has_event(RuntimeEvent::QuantumVault(Event::DeniedKeyInUse {
+            who: alice,
+            public_key_hash,
+        }));
+        let flagged = System::events()
+            .iter()
+            .filter(|record| {
+                matches!(record.event, RuntimeEvent::QuantumVault(Event::DeniedKeyInUse { .. }))
+            })
+            .count();
+        assert_eq!(flagged, 1);
+
+        // The flagged vault can still move its funds out
+        assert_ok!(QuantumVault::vault_transfer(
+            RuntimeOrigin::signed(alice),
+            create_transfer_signature(alice, bob, 100, 0),
+            bob,
+            100,
+            None
+        ));
+    });
+}
+
+#[test]
+fn vault_descriptor_replicates_vault_configuration() {
+    new_test_ext().execute_with(|| {
+        let alice = 1;
+        let bob = 2;
+        assert_eq!(QuantumVault::vault_descriptor(&alice), None);
+        assert_ok!(QuantumVault::create_vault_with_premium_mode(
+            RuntimeOrigin::signed(alice),
+            mock_public_key(),
+            PremiumMode::Percentage
+        ));
+
+        let descriptor = QuantumVault::vault_descriptor(&alice).unwrap();
+        assert_eq!(
+            descriptor,
+            VaultDescriptor {
+                version: VAULT_DESCRIPTOR_VERSION,
+                scheme: SignatureScheme::Dilithium2,
+                public_key: mock_public_key(),
+                premium_mode: PremiumMode::Percentage,
+            }
+        );
+
+        // The SCALE blob round-trips and recreates the same policy on Bob
+        let blob = descriptor.encode();
+        let imported = VaultDescriptor::decode(&mut &blob[..]).unwrap();
+        assert_ok!(QuantumVault::create_vault_from_descriptor(
+            RuntimeOrigin::signed(bob),
+            imported
+        ));
+        assert_eq!(QuantumVault::vault_descriptor(&bob), Some(descriptor));
+        assert_eq!(Balances::free_balance(TREASURY), 1 + 2 * CREATION_FEE);
+    });
+}
+
+#[test]
+fn vault_info_reports_nonce_signing_mode_and_policy() {
+    new_test_ext().execute_with(|| {
+        let alice = 1;
+        let bob = 2;
+        assert_eq!(QuantumVault::vault_info(&alice), None);
+
+        System::set_block_number(5);
+        assert_ok!(QuantumVault::create_vault(RuntimeOrigin::signed(alice), mock_public_key()));
+        set_policy(alice, policy(Some(100), Some(vec![bob])));
+
+        assert_eq!(
+            QuantumVault::vault_info(&alice),
+            Some(VaultInfo {
+                public_key_hash: sp_core::blake2_256(&mock_public_key()),
+                scheme: SignatureScheme::Dilithium2,
+                nonce: 1,
+                created_at: 5,
+                premium_mode: PremiumMode::Flat,
+                message_mode: MessageMode::Raw,
+                context: vec![],
+                policy: Some(policy(Some(100), Some(vec![bob]))),
+            })
+        );
+
+        assert_ok!(QuantumVault::create_vault_with_message_mode(
+            RuntimeOrigin::signed(bob),
+            mock_public_key(),
+            PremiumMode::Percentage,
+            MessageMode::Pure,
+            b"wallet".to_vec().try_into().unwrap()
+        ));
+        let info = QuantumVault::vault_info(&bob).unwrap();
+        assert_eq!(info.message_mode, MessageMode::Pure);
+        assert_eq!(info.context, b"wallet".to_vec());
+        assert_eq!(info.premium_mode, PremiumMode::Percentage);
+        assert_eq!(info.policy, None);
+    });
+}
+
+#[test]
+fn create_vault_from_descriptor_rejects_unknown_version() {
+    new_test_ext().execute_with(|| {
+        let descriptor = VaultDescriptor {
+            version: VAULT_DESCRIPTOR_VERSION + 1,
+            scheme: SignatureScheme::Dilithium2,
+            public_key: mock_public_key(),
+            premium_mode: PremiumMode::Flat,
+        };
+        assert_noop!(
+            QuantumVault::create_vault_from_descriptor(RuntimeOrigin::signed(1), descriptor),
+            Error::<Test>::UnsupportedDescriptorVersion
+        );
+    });
+}
+
+#[test]
+fn create_vault_for_funds_and_locks_target() {
+    new_test_ext().execute_with(|| {
+        let employer = 1;
+        let charlie = 3;
+        let acceptance = create_acceptance_signature(charlie, employer, 50);
+
+        assert_ok!(QuantumVault::create_vault_for(
+            RuntimeOrigin::signed(employer),
+            charlie,
+            get_public_key_for_account(charlie),
+            acceptance,
+            50
+        ));
+
+        assert!(QuantumVault::is_vault(&charlie));
+        // Employer paid the deposit and the creation fee
+        assert_eq!(Balances::free_balance(employer), 1000 - 50 - CREATION_FEE);
+        assert_eq!(Balances::free_balance(charlie), 150);
+        assert_eq!(Balances::free_balance(TREASURY), 1 + CREATION_FEE);
+        System::assert_has_event(RuntimeEvent::QuantumVault(Event::FeesCollected {
+            from: employer,
+            amount: CREATION_FEE,
+            reason: crate::FEE_REASON_VAULT_CREATION,
+        }));
+        System::assert_last_event(RuntimeEvent::QuantumVault(Event::VaultCreatedFor {
+            who: charlie,
+            funder: employer,
+            initial_deposit: 50,
+        }));
+    });
+}
+
+#[test]
+fn create_vault_for_rejects_acceptance_for_other_terms() {
+    new_test_ext().execute_with(|| {
+        let employer = 1;
+        let charlie = 3;
+
+        // Signed for a different deposit
+        let acceptance = create_acceptance_signature(charlie, employer, 50);
+        assert_noop!(
+            QuantumVault::create_vault_for(
+                RuntimeOrigin::signed(employer),
+                charlie,
+                get_public_key_for_account(charlie),
+                acceptance.clone(),
+                10
+            ),
+            Error::<Test>::AcceptanceVerificationFailed
+        );
+
+        // Signed by a key other than the one installed
+        assert_noop!(
+            QuantumVault::create_vault_for(
+                RuntimeOrigin::signed(employer),
+                charlie,
+                get_public_key_for_account(2),
+                acceptance.clone(),
+                50
+            ),
+            Error::<Test>::AcceptanceVerificationFailed
+        );
+
+        // Stale once the target has transacted
+        System::inc_account_nonce(charlie);
+        assert_noop!(
+            QuantumVault::create_vault_for(
+                RuntimeOrigin::signed(employer),
+                charlie,
+                get_public_key_for_account(charlie),
+                acceptance,
+                50
+            ),
+            Error::<Test>::AcceptanceVerificationFailed
+        );
+    });
+}
@@ -11,6 +11,16 @@ pub trait WeightInfo {
     fn create_vault() -> Weight;
     fn destroy_vault() -> Weight;
     fn vault_transfer() -> Weight;
+    fn create_vault_with_premium_mode() -> Weight;
+    fn set_paused() -> Weight;
+    fn deny_key(v: u32) -> Weight;
+    fn allow_key() -> Weight;
+    fn create_vault_for() -> Weight;
+    fn create_vault_with_message_mode() -> Weight;
+    fn create_vault_with_security_level() -> Weight;
+    fn vault_approve() -> Weight;
+    fn vault_transfer_from() -> Weight;
+    fn set_vault_policy() -> Weight;
 }
 
 /// Default weight implementations (for development)
@@ -20,44 +30,174 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     /// Weight for `create_vault`
     ///
     /// Includes:
+    /// - Storage read for pause flag
     /// - Storage read for existing vault check
+    /// - Storage read for deny-list check
     /// - Currency withdrawal for fee
     /// - Storage write for new vault
     /// - Storage write for nonce
+    /// - Storage write for key validity start
     /// - Counter update
+    /// - Era usage statistics update
     fn create_vault() -> Weight {
         Weight::from_parts(50_000_000, 0)
-            .saturating_add(T::DbWeight::get().reads(2))
-            .saturating_add(T::DbWeight::get().writes(3))
+            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().writes(5))
     }
 
     /// Weight for `destroy_vault`
     ///
     /// Includes:
+    /// - Storage read for pause flag
     /// - Storage read for vault public key
     /// - Storage read for nonce
+    /// - Storage read for message mode and security level
     /// - Signature verification (expensive - Dilithium is ~10x slower than Ed25519)
     /// - Storage removal for vault
-    /// - Storage removal for nonce
+    /// - Storage removal for nonce and out-of-order used nonces
+    /// - Storage removal for message mode, security level and allowances
+    /// - Key archive: entry, hash index and counter, plus pruning the oldest entry
+    /// - Era usage statistics update
     fn destroy_vault() -> Weight {
         Weight::from_parts(100_000_000, 0)
-            .saturating_add(T::DbWeight::get().reads(2))
-            .saturating_add(T::DbWeight::get().writes(3))
+            .saturating_add(T::DbWeight::get().reads(10))
+            .saturating_add(T::DbWeight::get().writes(14))
     }
 
     /// Weight for `vault_transfer`
     ///
     /// Includes:
+    /// - Storage read for pause flag
     /// - Storage read for vault public key
     /// - Storage read for nonce
+    /// - Storage read for message mode and security level
     /// - Signature verification (expensive)
+    /// - Storage read for out-of-order used nonces
+    /// - Storage reads for premium mode and recipient vault status
+    /// - Storage reads for the vault's policy and spending, write of spending
+    /// - Storage read and write consuming the Re-ML request
     /// - Balance transfer
-    /// - Storage write for nonce update
+    /// - Storage writes for nonce and used nonces update
+    /// - Era usage statistics update
     fn vault_transfer() -> Weight {
         Weight::from_parts(150_000_000, 0)
-            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().reads(13))
+            .saturating_add(T::DbWeight::get().writes(7))
+    }
+
+    /// Weight for `create_vault_with_premium_mode`
+    ///
+    /// Same as `create_vault`, plus one storage write for the premium mode
+    fn create_vault_with_premium_mode() -> Weight {
+        Weight::from_parts(50_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().writes(6))
+    }
+
+    /// Weight for `set_paused`
+    ///
+    /// Includes:
+    /// - Storage write for pause flag
+    fn set_paused() -> Weight {
+        Weight::from_parts(10_000_000, 0).saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    /// Weight for `deny_key`
+    ///
+    /// Includes:
+    /// - Storage read and write for the deny-list entry
+    /// - Storage read and key hash for each of the `v` listed vaults
+    fn deny_key(v: u32) -> Weight {
+        Weight::from_parts(15_000_000, 0)
+            .saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(v as u64))
+            .saturating_add(T::DbWeight::get().reads(1 + v as u64))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    /// Weight for `allow_key`
+    ///
+    /// Includes:
+    /// - Storage read and removal of the deny-list entry
+    fn allow_key() -> Weight {
+        Weight::from_parts(10_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    /// Weight for `create_vault_for`
+    ///
+    /// Same as `create_vault`, plus:
+    /// - Storage read for the target's account nonce
+    /// - Acceptance signature verification (expensive)
+    /// - Balance transfer of the initial deposit
+    fn create_vault_for() -> Weight {
+        Weight::from_parts(150_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(7))
+            .saturating_add(T::DbWeight::get().writes(7))
+    }
+
+    /// Weight for `create_vault_with_message_mode`
+    ///
+    /// Same as `create_vault_with_premium_mode`, plus one storage write for
+    /// the message mode and context
+    fn create_vault_with_message_mode() -> Weight {
+        Weight::from_parts(50_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().writes(7))
+    }
+
+    /// Weight for `create_vault_with_security_level`
+    ///
+    /// Same as `create_vault_with_premium_mode`, plus one storage write for
+    /// the security level
+    fn create_vault_with_security_level() -> Weight {
+        Weight::from_parts(50_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().writes(7))
+    }
+
+    /// Weight for `vault_approve`
+    ///
+    /// Includes:
+    /// - Storage reads for pause flag, vault public key and security level
+    /// - Storage reads for nonce, out-of-order used nonces and message mode
+    /// - Signature verification (expensive)
+    /// - Storage read and write of the vault's allowances
+    /// - Storage writes for nonce and used nonces update
+    fn vault_approve() -> Weight {
+        Weight::from_parts(150_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(7))
             .saturating_add(T::DbWeight::get().writes(3))
     }
+
+    /// Weight for `vault_transfer_from`
+    ///
+    /// Includes:
+    /// - Storage reads for pause flag and vault existence
+    /// - Storage read and write of the vault's allowances
+    /// - Storage reads for premium mode and recipient vault status
+    /// - Storage reads for the vault's policy and spending, write of spending
+    /// - Premium and balance transfers, fee counter update
+    /// - Era usage statistics update
+    fn vault_transfer_from() -> Weight {
+        Weight::from_parts(50_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(11))
+            .saturating_add(T::DbWeight::get().writes(7))
+    }
+
+    /// Weight for `set_vault_policy`
+    ///
+    /// Includes:
+    /// - Storage reads for pause flag, vault public key and security level
+    /// - Storage reads for nonce, out-of-order used nonces and message mode
+    /// - Signature verification (expensive)
+    /// - Storage write of the policy, or removal of policy and spending
+    /// - Storage writes for nonce and used nonces update
+    fn set_vault_policy() -> Weight {
+        Weight::from_parts(150_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().writes(4))
+    }
 }
 
 /// Unit testing weight implementations
@@ -73,4 +213,44 @@ impl WeightInfo for () {
     fn vault_transfer() -> Weight {
         Weight::from_parts(10_000, 0)
     }
+
+    fn create_vault_with_premium_mode() -> Weight {
+        Weight::from_parts(10_000, 0)
+    }
+
+    fn set_paused() -> Weight {
+        Weight::from_parts(10_000, 0)
+    }
+
+    fn deny_key(_v: u32) -> Weight {
+        Weight::from_parts(10_000, 0)
+    }
+
+    fn allow_key() -> Weight {
+        Weight::from_parts(10_000, 0)
+    }
+
+    fn create_vault_for() -> Weight {
+        Weight::from_parts(10_000, 0)
+    }
+
+    fn create_vault_with_message_mode() -> Weight {
+        Weight::from_parts(10_000, 0)
+    }
+
+    fn create_vault_with_security_level() -> Weight {
+        Weight::from_parts(10_000, 0)
+    }
+
+    fn vault_approve() -> Weight {
+        Weight::from_parts(10_000, 0)
+    }
+
+    fn vault_transfer_from() -> Weight {
+        Weight::from_parts(10_000, 0)
+    }
+
+    fn set_vault_policy() -> Weight {
+        Weight::from_parts(10_000, 0)
+    }
 }
@@ -0,0 +1,104 @@
+//! Benchmarking setup for pallet-reml-incentives
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as Incentives;
+use frame_benchmarking::v2::*;
+use frame_support::traits::{Currency, Get, Hooks};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use pallet_reml_verifier::{BatchInfo, OnBatchVerified};
+
+/// Registered aggregator `index`
+fn aggregator<T: Config>(index: u32) -> T::AccountId {
+    let aggregator: T::AccountId = account("aggregator", index, 0);
+    pallet_reml_verifier::Pallet::<T>::register_aggregator(
+        RawOrigin::Root.into(),
+        aggregator.clone(),
+    )
+    .unwrap();
+    aggregator
+}
+
+/// Credit `aggregator` with a batch verified at the current block
+fn verified_batch<T: Config>(aggregator: T::AccountId) {
+    Incentives::<T>::on_batch_verified(
+        0,
+        &BatchInfo {
+            aggregator,
+            verified_at: frame_system::Pallet::<T>::block_number(),
+            signature_count: 1,
+            requests_root: [0u8; 32],
+            proof_commitment: [0u8; 32],
+        },
+    );
+}
+
+/// First block of `era`
+fn era_start<T: Config>(era: u32) -> BlockNumberFor<T> {
+    <T as pallet_reml_verifier::Config>::EraLength::get() * era.into()
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn on_initialize() {
+        #[block]
+        {
+            Incentives::<T>::on_initialize(frame_system::Pallet::<T>::block_number());
+        }
+    }
+
+    /// Worst case: an era settles while one with `n` unclaimed aggregators
+    /// expires
+    #[benchmark]
+    fn settle_era(n: Linear<1, { T::MaxAggregators::get() }>) {
+        let aggregators: alloc::vec::Vec<_> = (0..n).map(aggregator::<T>).collect();
+        for era in 0..=T::ClaimWindow::get() {
+            frame_system::Pallet::<T>::set_block_number(era_start::<T>(era));
+            Incentives::<T>::on_initialize(era_start::<T>(era));
+            let _ = T::Currency::deposit_creating(&Incentives::<T>::pool(), 1_000_000u32.into());
+            for aggregator in &aggregators {
+                verified_batch::<T>(aggregator.clone());
+            }
+        }
+        let next = T::ClaimWindow::get() + 1;
+
+        #[block]
+        {
+            Incentives::<T>::on_initialize(era_start::<T>(next));
+        }
+
+        assert!(EraPayouts::<T>::contains_key(next - 1));
+        assert!(!EraPayouts::<T>::contains_key(0));
+    }
+
+    #[benchmark]
+    fn on_batch_verified() {
+        let aggregator = aggregator::<T>(0);
+
+        #[block]
+        {
+            verified_batch::<T>(aggregator.clone());
+        }
+
+        assert_eq!(EraSignatures::<T>::get(0, &aggregator), 1);
+    }
+
+    #[benchmark]
+    fn claim_incentives() {
+        T::Currency::make_free_balance_be(&Incentives::<T>::pool(), 1_000_000u32.into());
+        let caller = aggregator::<T>(0);
+        verified_batch::<T>(caller.clone());
+        Incentives::<T>::on_initialize(era_start::<T>(1));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), 0);
+
+        assert_eq!(EraSignatures::<T>::get(0, &caller), 0);
+    }
+
+    impl_benchmark_test_suite!(Incentives, crate::mock::new_test_ext(), crate::mock::Test);
+}
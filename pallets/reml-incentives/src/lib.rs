@@ -0,0 +1,303 @@
+//! # Tesserax Re-ML Incentives Pallet
+//!
+//! Couples the two halves of the PQC system: vaults pay a share of their
+//! transfer premiums into this pallet's pool (see `IncentivePoolShare` in
+//! pallet-quantum-vault), and the pool pays the Re-ML aggregators proving the
+//! ML-DSA signatures vault transfers cite.
+//!
+//! - Every verified batch credits its aggregator with the batch's signature
+//!   count for the verifier era it was verified in (`OnBatchVerified`).
+//! - When the era ends, whatever the pool holds beyond earlier eras' unclaimed
+//!   shares becomes that era's payout (`EraSettled`). An era without proven
+//!   signatures leaves the pool to the next one.
+//! - Each aggregator claims `payout × its signatures / all signatures` of a
+//!   settled era with `claim_incentives(era)`, as long as it is still active.
+//!   Shares left unclaimed `ClaimWindow` eras later go back to the pool.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub use pallet::*;
+
+/// Weight implementations
+pub mod weights;
+pub use weights::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::{
+        pallet_prelude::*,
+        traits::{Currency, ExistenceRequirement},
+        PalletId,
+    };
+    use frame_system::pallet_prelude::*;
+    use pallet_reml_verifier::{BatchInfo, OnBatchVerified};
+    use sp_runtime::{
+        traits::{AccountIdConversion, Saturating, Zero},
+        Perbill,
+    };
+
+    pub type BalanceOf<T> =
+        <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// Configuration trait for the incentives pallet.
+    #[pallet::config]
+    pub trait Config: frame_system::Config + pallet_reml_verifier::Config {
+        /// Currency the pool holds and pays out
+        type Currency: Currency<Self::AccountId>;
+
+        /// Id of the pool account
+        #[pallet::constant]
+        type PalletId: Get<PalletId>;
+
+        /// Settled eras that stay claimable; older unclaimed shares return to
+        /// the pool
+        #[pallet::constant]
+        type ClaimWindow: Get<u32>;
+
+        /// Weight information
+        type WeightInfo: WeightInfo;
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // TYPES
+    // ═══════════════════════════════════════════════════════════════════════
+
+    /// Payout of a settled era
+    #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+    pub struct EraPayout<Balance> {
+        /// Pool balance assigned to the era
+        pub pool: Balance,
+        /// Signatures proven in the era, by all aggregators
+        pub signatures: u64,
+        /// Part of `pool` not yet claimed
+        pub unclaimed: Balance,
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // STORAGE
+    // ═══════════════════════════════════════════════════════════════════════
+
+    /// Verifier era of the latest block, settled once a later era starts
+    #[pallet::storage]
+    pub type CurrentEra<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Signatures each aggregator proved per era, removed once claimed
+    #[pallet::storage]
+    pub type EraSignatures<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, u32, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+
+    /// Signatures proven per era, by all aggregators
+    #[pallet::storage]
+    pub type EraTotals<T: Config> = StorageMap<_, Twox64Concat, u32, u64, ValueQuery>;
+
+    /// Payouts of the settled eras still in `ClaimWindow`
+    #[pallet::storage]
+    pub type EraPayouts<T: Config> =
+        StorageMap<_, Twox64Concat, u32, EraPayout<BalanceOf<T>>, OptionQuery>;
+
+    /// Unclaimed part of all `EraPayouts`, held back from the next settlement
+    #[pallet::storage]
+    pub type Owed<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // EVENTS
+    // ═══════════════════════════════════════════════════════════════════════
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// The pool's free balance was assigned to an ended era
+        EraSettled {
+            era: u32,
+            pool: BalanceOf<T>,
+            signatures: u64,
+        },
+        /// An aggregator claimed its share of an era's payout
+        IncentivesClaimed {
+            era: u32,
+            aggregator: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// An era left `ClaimWindow`; its unclaimed shares went back to the pool
+        EraExpired { era: u32, unclaimed: BalanceOf<T> },
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // ERRORS
+    // ═══════════════════════════════════════════════════════════════════════
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Era has no payout: not settled yet, expired, or nothing to pay
+        EraNotClaimable,
+        /// No unclaimed signatures in this era
+        NothingToClaim,
+        /// Only active aggregators are paid
+        NotActiveAggregator,
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // HOOKS
+    // ═══════════════════════════════════════════════════════════════════════
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+            let era = pallet_reml_verifier::Pallet::<T>::era_of(n);
+            let ended = CurrentEra::<T>::get();
+            if era <= ended {
+                return <T as Config>::WeightInfo::on_initialize();
+            }
+
+            CurrentEra::<T>::put(era);
+            Self::settle(ended);
+            if let Some(expired) = ended.checked_sub(T::ClaimWindow::get()) {
+                Self::expire(expired);
+            }
+            <T as Config>::WeightInfo::settle_era(T::MaxAggregators::get())
+        }
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // EXTRINSICS
+    // ═══════════════════════════════════════════════════════════════════════
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Pay out the caller's share of a settled era's payout
+        #[pallet::call_index(0)]
+        #[pallet::weight(<T as Config>::WeightInfo::claim_incentives())]
+        pub fn claim_incentives(origin: OriginFor<T>, era: u32) -> DispatchResult {
+            let aggregator = ensure_signed(origin)?;
+
+            let mut payout = EraPayouts::<T>::get(era).ok_or(Error::<T>::EraNotClaimable)?;
+            ensure!(
+                pallet_reml_verifier::Pallet::<T>::is_aggregator(&aggregator),
+                Error::<T>::NotActiveAggregator
+            );
+            let signatures = EraSignatures::<T>::take(era, &aggregator);
+            ensure!(signatures > 0, Error::<T>::NothingToClaim);
+
+            let amount = Perbill::from_rational(signatures, payout.signatures)
+                .mul_floor(payout.pool)
+                .min(payout.unclaimed);
+            T::Currency::transfer(
+                &Self::pool(),
+                &aggregator,
+                amount,
+                ExistenceRequirement::KeepAlive,
+            )?;
+
+            payout.unclaimed = payout.unclaimed.saturating_sub(amount);
+            EraPayouts::<T>::insert(era, payout);
+            Owed::<T>::mutate(|owed| *owed = owed.saturating_sub(amount));
+
+            Self::deposit_event(Event::IncentivesClaimed {
+                era,
+                aggregator,
+                amount,
+            });
+            Ok(())
+        }
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // HELPER FUNCTIONS
+    // ═══════════════════════════════════════════════════════════════════════
+
+    impl<T: Config> Pallet<T> {
+        /// Account of the incentive pool
+        pub fn pool() -> T::AccountId {
+            T::PalletId::get().into_account_truncating()
+        }
+
+        /// What `aggregator` can claim from `era` now
+        pub fn claimable(era: u32, aggregator: &T::AccountId) -> BalanceOf<T> {
+            let Some(payout) = EraPayouts::<T>::get(era) else {
+                return Zero::zero();
+            };
+            let signatures = EraSignatures::<T>::get(era, aggregator);
+            Perbill::from_rational(signatures, payout.signatures)
+                .mul_floor(payout.pool)
+                .min(payout.unclaimed)
+        }
+
+        /// Assign the pool's free balance, less what earlier eras still owe
+        /// and the existential deposit, to `era`
+        fn settle(era: u32) {
+            let signatures = EraTotals::<T>::get(era);
+            if signatures == 0 {
+                return;
+            }
+
+            let owed = Owed::<T>::get();
+            let pool = T::Currency::free_balance(&Self::pool())
+                .saturating_sub(owed)
+                .saturating_sub(T::Currency::minimum_balance());
+            if pool.is_zero() {
+                return;
+            }
+
+            EraPayouts::<T>::insert(
+                era,
+                EraPayout {
+                    pool,
+                    signatures,
+                    unclaimed: pool,
+                },
+            );
+            Owed::<T>::put(owed.saturating_add(pool));
+            Self::deposit_event(Event::EraSettled {
+                era,
+                pool,
+                signatures,
+            });
+        }
+
+        /// Return `era`'s unclaimed shares to the pool and drop its records
+        fn expire(era: u32) {
+            EraTotals::<T>::remove(era);
+            let _ = EraSignatures::<T>::clear_prefix(era, T::MaxAggregators::get(), None);
+
+            let Some(payout) = EraPayouts::<T>::take(era) else {
+                return;
+            };
+            Owed::<T>::mutate(|owed| *owed = owed.saturating_sub(payout.unclaimed));
+            Self::deposit_event(Event::EraExpired {
+                era,
+                unclaimed: payout.unclaimed,
+            });
+        }
+    }
+
+    /// Credits each verified batch's signatures to its aggregator
+    impl<T: Config> OnBatchVerified<T::AccountId, BlockNumberFor<T>> for Pallet<T> {
+        fn on_batch_verified(_batch_id: u64, info: &BatchInfo<T::AccountId, BlockNumberFor<T>>) {
+            let era = pallet_reml_verifier::Pallet::<T>::era_of(info.verified_at);
+            let signatures = info.signature_count as u64;
+            EraSignatures::<T>::mutate(era, &info.aggregator, |count| {
+                *count = count.saturating_add(signatures)
+            });
+            EraTotals::<T>::mutate(era, |total| *total = total.saturating_add(signatures));
+        }
+
+        fn weight() -> Weight {
+            <T as Config>::WeightInfo::on_batch_verified()
+        }
+    }
+}
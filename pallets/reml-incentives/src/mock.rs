@@ -0,0 +1,154 @@
+//! Mock runtime for testing pallet-reml-incentives
+
+use frame_support::{
+    assert_ok, derive_impl, parameter_types,
+    traits::{ConstU32, ConstU64, Hooks, Nothing},
+    PalletId,
+};
+use pallet_reml_verifier::BatchInfo;
+use sp_runtime::{traits::IdentityLookup, BuildStorage};
+
+use crate as pallet_reml_incentives;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime for testing
+frame_support::construct_runtime!(
+    pub enum Test {
+        System: frame_system,
+        Balances: pallet_balances,
+        RemlVerifier: pallet_reml_verifier,
+        Incentives: pallet_reml_incentives,
+    }
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+    type AccountId = u64;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type AccountData = pallet_balances::AccountData<u64>;
+}
+
+impl pallet_balances::Config for Test {
+    type MaxLocks = ConstU32<50>;
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+    type Balance = u64;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ConstU64<1>;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type FreezeIdentifier = ();
+    type MaxFreezes = ConstU32<0>;
+    type RuntimeHoldReason = ();
+    type RuntimeFreezeReason = ();
+    type DoneSlashHandler = ();
+}
+
+impl pallet_reml_verifier::Config for Test {
+    type WeightInfo = ();
+    type AdminOrigin = frame_system::EnsureRoot<u64>;
+    type MaxAggregators = ConstU32<4>;
+    type ExpectedVKeyHash = ExpectedVKeyHash;
+    type TransitionVKeyHash = ();
+    type ProgramVKeys = ();
+    type InlineRequestMarks = ConstU32<{ pallet_reml_verifier::MAX_VERIFIED_REQUESTS }>;
+    type ProofEpoch = ConstU32<1>;
+    type OnBatchVerified = Incentives;
+    type CoSignature = sp_runtime::testing::TestSignature;
+    type RewardCurrency = Balances;
+    type VaultCheck = Nothing;
+    type BatchReward = ConstU64<0>;
+    type RewardPotId = RewardPotId;
+    type EraLength = ConstU64<ERA>;
+    type MaxBatchesPerEra = ConstU32<{ u32::MAX }>;
+    type AggregatorBond = ConstU64<0>;
+    type UnbondingPeriod = ConstU64<0>;
+    type Slash = ();
+    type ProofRetentionPeriod = ConstU64<{ u64::MAX }>;
+    type MaxAnchorAge = ConstU64<10>;
+    type SubmissionDeposit = ConstU64<0>;
+    type PrunerShare = PrunerShare;
+}
+
+/// Registered Re-ML aggregators
+pub const AGGREGATOR: u64 = 1;
+pub const OTHER_AGGREGATOR: u64 = 2;
+/// Funded, not registered
+pub const OUTSIDER: u64 = 3;
+
+pub const ERA: u64 = 10;
+pub const CLAIM_WINDOW: u32 = 2;
+
+parameter_types! {
+    pub const ExpectedVKeyHash: [u8; 32] = [0u8; 32];
+    pub const RewardPotId: PalletId = PalletId(*b"reml/pot");
+    pub const PrunerShare: sp_runtime::Perbill = sp_runtime::Perbill::zero();
+    pub const IncentivesPotId: PalletId = PalletId(*b"reml/inc");
+}
+
+impl pallet_reml_incentives::Config for Test {
+    type Currency = Balances;
+    type PalletId = IncentivesPotId;
+    type ClaimWindow = ConstU32<CLAIM_WINDOW>;
+    type WeightInfo = ();
+}
+
+/// Report a batch of `signatures` verified for `aggregator` at the current block
+pub fn verify_batch(aggregator: u64, signatures: u32) {
+    use pallet_reml_verifier::OnBatchVerified;
+    Incentives::on_batch_verified(
+        0,
+        &BatchInfo {
+            aggregator,
+            verified_at: System::block_number(),
+            signature_count: signatures,
+            requests_root: [0u8; 32],
+            proof_commitment: [0u8; 32],
+        },
+    );
+}
+
+/// Advance to the first block of `era`, running `on_initialize` on the way
+pub fn run_to_era(era: u32) {
+    let target = era as u64 * ERA;
+    while System::block_number() < target {
+        let next = System::block_number() + 1;
+        System::set_block_number(next);
+        Incentives::on_initialize(next);
+    }
+}
+
+/// Block 1, `AGGREGATOR` and `OTHER_AGGREGATOR` registered, empty pool
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![
+            (AGGREGATOR, 100),
+            (OTHER_AGGREGATOR, 100),
+            (OUTSIDER, 1_000),
+        ],
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(RemlVerifier::register_aggregator(
+            RuntimeOrigin::root(),
+            AGGREGATOR
+        ));
+        assert_ok!(RemlVerifier::register_aggregator(
+            RuntimeOrigin::root(),
+            OTHER_AGGREGATOR
+        ));
+    });
+    ext
+}
@@ -0,0 +1,189 @@
+//! Unit tests for pallet-reml-incentives
+
+use crate::{mock::*, EraPayout, EraPayouts, EraSignatures, EraTotals, Error, Event, Owed};
+use frame_support::{assert_noop, assert_ok};
+
+/// Move `amount` from `OUTSIDER` into the incentive pool
+fn fund_pool(amount: u64) {
+    assert_ok!(Balances::transfer_allow_death(
+        RuntimeOrigin::signed(OUTSIDER),
+        Incentives::pool(),
+        amount
+    ));
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// ACCOUNTING TESTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn batches_are_credited_to_their_verifier_era() {
+    new_test_ext().execute_with(|| {
+        verify_batch(AGGREGATOR, 3);
+        verify_batch(OTHER_AGGREGATOR, 1);
+        System::set_block_number(ERA + 2);
+        verify_batch(AGGREGATOR, 5);
+
+        assert_eq!(EraSignatures::<Test>::get(0, AGGREGATOR), 3);
+        assert_eq!(EraSignatures::<Test>::get(0, OTHER_AGGREGATOR), 1);
+        assert_eq!(EraTotals::<Test>::get(0), 4);
+        assert_eq!(EraSignatures::<Test>::get(1, AGGREGATOR), 5);
+        assert_eq!(EraTotals::<Test>::get(1), 5);
+    });
+}
+
+#[test]
+fn era_without_signatures_leaves_pool_to_the_next() {
+    new_test_ext().execute_with(|| {
+        fund_pool(101);
+
+        run_to_era(1);
+        assert!(EraPayouts::<Test>::get(0).is_none());
+
+        verify_batch(AGGREGATOR, 2);
+        run_to_era(2);
+        assert_eq!(
+            EraPayouts::<Test>::get(1),
+            Some(EraPayout {
+                pool: 100,
+                signatures: 2,
+                unclaimed: 100
+            })
+        );
+        System::assert_last_event(
+            Event::EraSettled {
+                era: 1,
+                pool: 100,
+                signatures: 2,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn settlement_holds_back_what_earlier_eras_owe() {
+    new_test_ext().execute_with(|| {
+        fund_pool(101);
+        verify_batch(AGGREGATOR, 1);
+        run_to_era(1);
+
+        fund_pool(50);
+        verify_batch(AGGREGATOR, 1);
+        run_to_era(2);
+
+        assert_eq!(EraPayouts::<Test>::get(0).unwrap().pool, 100);
+        assert_eq!(EraPayouts::<Test>::get(1).unwrap().pool, 50);
+        assert_eq!(Owed::<Test>::get(), 150);
+    });
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// CLAIM TESTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn claims_pay_in_proportion_to_signatures() {
+    new_test_ext().execute_with(|| {
+        fund_pool(101);
+        verify_batch(AGGREGATOR, 3);
+        verify_batch(OTHER_AGGREGATOR, 1);
+        run_to_era(1);
+        assert_eq!(Incentives::claimable(0, &AGGREGATOR), 75);
+
+        assert_ok!(Incentives::claim_incentives(
+            RuntimeOrigin::signed(AGGREGATOR),
+            0
+        ));
+        System::assert_last_event(
+            Event::IncentivesClaimed {
+                era: 0,
+                aggregator: AGGREGATOR,
+                amount: 75,
+            }
+            .into(),
+        );
+        assert_ok!(Incentives::claim_incentives(
+            RuntimeOrigin::signed(OTHER_AGGREGATOR),
+            0
+        ));
+
+        assert_eq!(Balances::free_balance(AGGREGATOR), 175);
+        assert_eq!(Balances::free_balance(OTHER_AGGREGATOR), 125);
+        assert_eq!(Balances::free_balance(Incentives::pool()), 1);
+        assert_eq!(EraPayouts::<Test>::get(0).unwrap().unclaimed, 0);
+        assert_eq!(Owed::<Test>::get(), 0);
+
+        assert_noop!(
+            Incentives::claim_incentives(RuntimeOrigin::signed(AGGREGATOR), 0),
+            Error::<Test>::NothingToClaim
+        );
+    });
+}
+
+#[test]
+fn only_active_aggregators_claim_settled_eras() {
+    new_test_ext().execute_with(|| {
+        fund_pool(101);
+        verify_batch(AGGREGATOR, 1);
+        assert_noop!(
+            Incentives::claim_incentives(RuntimeOrigin::signed(AGGREGATOR), 0),
+            Error::<Test>::EraNotClaimable
+        );
+
+        run_to_era(1);
+        assert_noop!(
+            Incentives::claim_incentives(RuntimeOrigin::signed(OUTSIDER), 0),
+            Error::<Test>::NotActiveAggregator
+        );
+        assert_noop!(
+            Incentives::claim_incentives(RuntimeOrigin::signed(OTHER_AGGREGATOR), 0),
+            Error::<Test>::NothingToClaim
+        );
+
+        assert_ok!(RemlVerifier::deactivate_aggregator(
+            RuntimeOrigin::root(),
+            AGGREGATOR
+        ));
+        assert_noop!(
+            Incentives::claim_incentives(RuntimeOrigin::signed(AGGREGATOR), 0),
+            Error::<Test>::NotActiveAggregator
+        );
+    });
+}
+
+#[test]
+fn unclaimed_shares_expire_back_to_the_pool() {
+    new_test_ext().execute_with(|| {
+        fund_pool(101);
+        verify_batch(AGGREGATOR, 3);
+        verify_batch(OTHER_AGGREGATOR, 1);
+        run_to_era(1);
+        assert_ok!(Incentives::claim_incentives(
+            RuntimeOrigin::signed(AGGREGATOR),
+            0
+        ));
+
+        // Era 0 stays claimable for CLAIM_WINDOW eras
+        run_to_era(1 + CLAIM_WINDOW);
+        System::assert_has_event(
+            Event::EraExpired {
+                era: 0,
+                unclaimed: 25,
+            }
+            .into(),
+        );
+        assert_eq!(Owed::<Test>::get(), 0);
+        assert_eq!(EraTotals::<Test>::get(0), 0);
+        assert_eq!(EraSignatures::<Test>::get(0, OTHER_AGGREGATOR), 0);
+        assert_noop!(
+            Incentives::claim_incentives(RuntimeOrigin::signed(OTHER_AGGREGATOR), 0),
+            Error::<Test>::EraNotClaimable
+        );
+
+        // The expired share funds the next settled era
+        verify_batch(OTHER_AGGREGATOR, 1);
+        run_to_era(2 + CLAIM_WINDOW);
+        assert_eq!(EraPayouts::<Test>::get(1 + CLAIM_WINDOW).unwrap().pool, 25);
+    });
+}
@@ -0,0 +1,66 @@
+//! Weight information for pallet-reml-incentives
+//!
+//! In production, these should be generated using frame-benchmarking.
+
+use frame_support::pallet_prelude::Get;
+use frame_support::weights::Weight;
+
+/// Weight functions needed for pallet-reml-incentives
+pub trait WeightInfo {
+    fn on_initialize() -> Weight;
+    fn settle_era(n: u32) -> Weight;
+    fn on_batch_verified() -> Weight;
+    fn claim_incentives() -> Weight;
+}
+
+/// Production weight implementations
+pub struct SubstrateWeight<T>(core::marker::PhantomData<T>);
+
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    /// Current era
+    fn on_initialize() -> Weight {
+        Weight::from_parts(3_000_000, 0).saturating_add(T::DbWeight::get().reads(1))
+    }
+
+    /// Current era, totals, owed, pool account, payout; expiry clears up to
+    /// `n` signature entries
+    fn settle_era(n: u32) -> Weight {
+        Weight::from_parts(30_000_000, 0)
+            .saturating_add(Weight::from_parts(2_000_000, 0).saturating_mul(n as u64))
+            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().writes(5 + n as u64))
+    }
+
+    /// Aggregator signatures, era totals
+    fn on_batch_verified() -> Weight {
+        Weight::from_parts(10_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+
+    /// Payout, aggregator, signatures, owed, pool and aggregator accounts
+    fn claim_incentives() -> Weight {
+        Weight::from_parts(45_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().writes(5))
+    }
+}
+
+/// Unit testing weight implementations
+impl WeightInfo for () {
+    fn on_initialize() -> Weight {
+        Weight::from_parts(3_000_000, 0)
+    }
+
+    fn settle_era(n: u32) -> Weight {
+        Weight::from_parts(30_000_000 + 2_000_000 * n as u64, 0)
+    }
+
+    fn on_batch_verified() -> Weight {
+        Weight::from_parts(10_000_000, 0)
+    }
+
+    fn claim_incentives() -> Weight {
+        Weight::from_parts(45_000_000, 0)
+    }
+}
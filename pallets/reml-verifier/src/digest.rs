@@ -0,0 +1,54 @@
+//! Header digest summarizing the Re-ML batches verified in a block.
+//!
+//! At `on_finalize` the pallet deposits one `DigestItem::Consensus` log tagged
+//! with [`REML_ENGINE_ID`] whenever at least one batch was verified. Light
+//! clients and bridges can read it straight from the header and check a
+//! request against `requests_root` with a Merkle proof, without decoding
+//! events or storage.
+
+use alloc::vec::Vec;
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::{ConsensusEngineId, Digest, DigestItem};
+
+/// Consensus engine ID of the Re-ML digest log
+pub const REML_ENGINE_ID: ConsensusEngineId = *b"REML";
+
+/// Digest format version
+pub const BATCH_DIGEST_VERSION: u8 = 1;
+
+/// Maximum batches summarized in one block's digest
+pub const MAX_BATCHES_PER_BLOCK: u32 = 64;
+
+/// One verified batch
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo, codec::MaxEncodedLen)]
+pub struct BatchDigestEntry {
+    pub batch_id: u64,
+    /// Merkle root of the batch's verified request IDs
+    pub requests_root: [u8; 32],
+    /// Number of signatures in the batch
+    pub signature_count: u32,
+}
+
+/// Payload of the [`REML_ENGINE_ID`] digest log
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct BatchDigest {
+    pub version: u8,
+    /// Batches in verification order
+    pub batches: Vec<BatchDigestEntry>,
+}
+
+impl BatchDigest {
+    /// Wrap as a header log item
+    pub fn to_digest_item(&self) -> DigestItem {
+        DigestItem::Consensus(REML_ENGINE_ID, self.encode())
+    }
+
+    /// Find and decode the Re-ML log in a header digest
+    pub fn find(digest: &Digest) -> Option<Self> {
+        digest.logs().iter().find_map(|item| {
+            item.consensus_try_to::<BatchDigest>(&REML_ENGINE_ID)
+                .filter(|d| d.version == BATCH_DIGEST_VERSION)
+        })
+    }
+}
@@ -0,0 +1,271 @@
+//! Groth16 verification over BN254 for SP1 proofs.
+//!
+//! SP1 wraps its STARK in a gnark Groth16 proof with two public inputs: the
+//! program's verification key hash and the SHA-256 digest of the committed
+//! public values, top three bits cleared so it fits the scalar field
+//! ([`hash_public_values`], named after the function in SP1's verifier
+//! contracts). Re-ML proofs commit the ABI encoding of their public values,
+//! so the pallet, precompile `0x29` and SP1's Solidity verifier all check
+//! the same proof against the same bytes.
+//!
+//! Encodings follow the EVM pairing precompile and SP1's Solidity verifier:
+//! coordinates are 32-byte big-endian, a G1 point is `x || y`, a G2 point is
+//! `x.c1 || x.c0 || y.c1 || y.c0`, and all-zero bytes are the point at
+//! infinity. A proof is `selector || A || B || C`, the 4-byte selector naming
+//! the verifying key it was made for.
+
+use bn::{pairing_batch, AffineG1, AffineG2, Fq, Fq2, Fr, Group, Gt, G1, G2};
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use frame_support::{traits::ConstU32, BoundedVec};
+use scale_info::TypeInfo;
+
+/// Size of an encoded G1 point
+pub const G1_SIZE: usize = 64;
+
+/// Size of an encoded G2 point
+pub const G2_SIZE: usize = 128;
+
+/// Size of the verifying key selector prefixed to proofs
+pub const SELECTOR_SIZE: usize = 4;
+
+/// Size of an encoded proof: selector, A, B and C
+pub const PROOF_SIZE: usize = SELECTOR_SIZE + G1_SIZE + G2_SIZE + G1_SIZE;
+
+/// Public inputs of an SP1 proof: program vkey hash, public values digest
+pub const SP1_PUBLIC_INPUTS: usize = 2;
+
+/// Most public inputs a verifying key can take
+pub const MAX_PUBLIC_INPUTS: u32 = 4;
+
+/// Groth16 verifying key
+#[derive(
+    Clone, Debug, PartialEq, Eq, Encode, Decode, DecodeWithMemTracking, TypeInfo, MaxEncodedLen,
+)]
+pub struct VerifyingKey {
+    /// First 4 bytes of SP1's hash of this key, prefixed to its proofs
+    pub selector: [u8; SELECTOR_SIZE],
+    pub alpha_g1: [u8; G1_SIZE],
+    pub beta_g2: [u8; G2_SIZE],
+    pub gamma_g2: [u8; G2_SIZE],
+    pub delta_g2: [u8; G2_SIZE],
+    /// One point per public input, after the constant term
+    pub ic: BoundedVec<[u8; G1_SIZE], ConstU32<{ MAX_PUBLIC_INPUTS + 1 }>>,
+}
+
+impl VerifyingKey {
+    /// Whether every point is on the curve (and in the subgroup for G2)
+    pub fn is_well_formed(&self) -> bool {
+        !self.ic.is_empty()
+            && decode_g1(&self.alpha_g1).is_some()
+            && decode_g2(&self.beta_g2).is_some()
+            && decode_g2(&self.gamma_g2).is_some()
+            && decode_g2(&self.delta_g2).is_some()
+            && self.ic.iter().all(|point| decode_g1(point).is_some())
+    }
+
+    /// Number of public inputs the key verifies
+    pub fn public_inputs(&self) -> usize {
+        self.ic.len().saturating_sub(1)
+    }
+}
+
+/// Digest of committed `public_values`: `sha256(public_values) & (2^253 - 1)`,
+/// as `hashPublicValues` in SP1's verifier contracts
+pub fn hash_public_values(public_values: &[u8]) -> [u8; 32] {
+    let mut digest = sp_core::hashing::sha2_256(public_values);
+    digest[0] &= 0x1f;
+    digest
+}
+
+/// Public inputs of an SP1 proof of `program_vkey` committing `public_values`
+pub fn sp1_public_inputs(
+    program_vkey: &[u8; 32],
+    public_values: &[u8],
+) -> [[u8; 32]; SP1_PUBLIC_INPUTS] {
+    [*program_vkey, hash_public_values(public_values)]
+}
+
+/// Verify `proof` against `vk` and big-endian scalar `inputs`
+///
+/// Checks `e(-A, B) · e(α, β) · e(vk_x, γ) · e(C, δ) == 1` with
+/// `vk_x = IC₀ + Σ inputsᵢ · ICᵢ₊₁`. Malformed proofs, points off the curve
+/// and inputs outside the scalar field all fail.
+pub fn verify(vk: &VerifyingKey, proof: &[u8], inputs: &[[u8; 32]]) -> bool {
+    if proof.len() != PROOF_SIZE || proof[..SELECTOR_SIZE] != vk.selector {
+        return false;
+    }
+    if inputs.len() != vk.public_inputs() {
+        return false;
+    }
+
+    let proof = &proof[SELECTOR_SIZE..];
+    let (Some(a), Some(b), Some(c)) = (
+        decode_g1(&proof[..G1_SIZE]),
+        decode_g2(&proof[G1_SIZE..G1_SIZE + G2_SIZE]),
+        decode_g1(&proof[G1_SIZE + G2_SIZE..]),
+    ) else {
+        return false;
+    };
+    let (Some(alpha), Some(beta), Some(gamma), Some(delta)) = (
+        decode_g1(&vk.alpha_g1),
+        decode_g2(&vk.beta_g2),
+        decode_g2(&vk.gamma_g2),
+        decode_g2(&vk.delta_g2),
+    ) else {
+        return false;
+    };
+
+    let Some(mut vk_x) = decode_g1(&vk.ic[0]) else {
+        return false;
+    };
+    for (input, point) in inputs.iter().zip(vk.ic.iter().skip(1)) {
+        let (Ok(scalar), Some(point)) = (Fr::from_slice(input), decode_g1(point)) else {
+            return false;
+        };
+        vk_x = vk_x + point * scalar;
+    }
+
+    pairing_batch(&[(-a, b), (alpha, beta), (vk_x, gamma), (c, delta)]) == Gt::one()
+}
+
+/// Decode a G1 point, `None` if off the curve
+fn decode_g1(bytes: &[u8]) -> Option<G1> {
+    let x = Fq::from_slice(&bytes[..32]).ok()?;
+    let y = Fq::from_slice(&bytes[32..64]).ok()?;
+    if x == Fq::zero() && y == Fq::zero() {
+        return Some(G1::zero());
+    }
+    AffineG1::new(x, y).ok().map(Into::into)
+}
+
+/// Decode a G2 point, `None` if off the curve or outside the subgroup
+fn decode_g2(bytes: &[u8]) -> Option<G2> {
+    let x_c1 = Fq::from_slice(&bytes[..32]).ok()?;
+    let x_c0 = Fq::from_slice(&bytes[32..64]).ok()?;
+    let y_c1 = Fq::from_slice(&bytes[64..96]).ok()?;
+    let y_c0 = Fq::from_slice(&bytes[96..128]).ok()?;
+    let (x, y) = (Fq2::new(x_c0, x_c1), Fq2::new(y_c0, y_c1));
+    if x.is_zero() && y.is_zero() {
+        return Some(G2::zero());
+    }
+    AffineG2::new(x, y).ok().map(Into::into)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode_g1(point: G1) -> [u8; G1_SIZE] {
+        let affine = AffineG1::from_jacobian(point).unwrap();
+        let mut out = [0u8; G1_SIZE];
+        affine.x().to_big_endian(&mut out[..32]).unwrap();
+        affine.y().to_big_endian(&mut out[32..]).unwrap();
+        out
+    }
+
+    fn encode_g2(point: G2) -> [u8; G2_SIZE] {
+        let affine = AffineG2::from_jacobian(point).unwrap();
+        let mut out = [0u8; G2_SIZE];
+        affine.x().imaginary().to_big_endian(&mut out[..32]).unwrap();
+        affine.x().real().to_big_endian(&mut out[32..64]).unwrap();
+        affine.y().imaginary().to_big_endian(&mut out[64..96]).unwrap();
+        affine.y().real().to_big_endian(&mut out[96..]).unwrap();
+        out
+    }
+
+    fn scalar(n: u64) -> Fr {
+        Fr::from_str(&n.to_string()).unwrap()
+    }
+
+    fn scalar_bytes(n: u64) -> [u8; 32] {
+        let mut out = [0u8; 32];
+        out[24..].copy_from_slice(&n.to_be_bytes());
+        out
+    }
+
+    /// Key with known discrete logs, and a proof of `inputs` built from them
+    ///
+    /// With A = a·G1, B = G2 and the key's logs α, β, γ, δ, icᵢ, the check
+    /// holds when a = αβ + vk_x·γ + c·δ.
+    fn key_and_proof(inputs: &[u64]) -> (VerifyingKey, [u8; PROOF_SIZE]) {
+        let (alpha, beta, gamma, delta) = (scalar(3), scalar(5), scalar(7), scalar(11));
+        let ic: alloc::vec::Vec<Fr> = (0..=inputs.len() as u64).map(|i| scalar(13 + i)).collect();
+        let c = scalar(17);
+
+        let vk_x =
+            inputs.iter().zip(&ic[1..]).fold(ic[0], |acc, (input, ic)| acc + scalar(*input) * *ic);
+        let a = alpha * beta + vk_x * gamma + c * delta;
+
+        let vk = VerifyingKey {
+            selector: *b"sp1!",
+            alpha_g1: encode_g1(G1::one() * alpha),
+            beta_g2: encode_g2(G2::one() * beta),
+            gamma_g2: encode_g2(G2::one() * gamma),
+            delta_g2: encode_g2(G2::one() * delta),
+            ic: BoundedVec::truncate_from(ic.iter().map(|ic| encode_g1(G1::one() * *ic)).collect()),
+        };
+
+        let mut proof = [0u8; PROOF_SIZE];
+        proof[..SELECTOR_SIZE].copy_from_slice(&vk.selector);
+        proof[SELECTOR_SIZE..SELECTOR_SIZE + G1_SIZE].copy_from_slice(&encode_g1(G1::one() * a));
+        proof[SELECTOR_SIZE + G1_SIZE..SELECTOR_SIZE + G1_SIZE + G2_SIZE]
+            .copy_from_slice(&encode_g2(G2::one()));
+        proof[SELECTOR_SIZE + G1_SIZE + G2_SIZE..].copy_from_slice(&encode_g1(G1::one() * c));
+        (vk, proof)
+    }
+
+    #[test]
+    fn valid_proof_verifies() {
+        let (vk, proof) = key_and_proof(&[21, 42]);
+        assert!(vk.is_well_formed());
+        assert!(verify(&vk, &proof, &[scalar_bytes(21), scalar_bytes(42)]));
+    }
+
+    #[test]
+    fn proof_is_bound_to_inputs_and_key() {
+        let (vk, proof) = key_and_proof(&[21, 42]);
+
+        assert!(!verify(&vk, &proof, &[scalar_bytes(21), scalar_bytes(43)]));
+        assert!(!verify(&vk, &proof, &[scalar_bytes(21)]));
+
+        let mut other_selector = proof;
+        other_selector[0] ^= 1;
+        assert!(!verify(&vk, &other_selector, &[scalar_bytes(21), scalar_bytes(42)]));
+
+        // A proof for another key with the same selector
+        let (mut other_vk, _) = key_and_proof(&[1, 2]);
+        other_vk.alpha_g1 = encode_g1(G1::one() * scalar(4));
+        assert!(!verify(&other_vk, &proof, &[scalar_bytes(21), scalar_bytes(42)]));
+
+        let mut off_curve = vk;
+        off_curve.delta_g2 = [1u8; G2_SIZE];
+        assert!(!off_curve.is_well_formed());
+    }
+
+    #[test]
+    fn malformed_proofs_fail() {
+        let (vk, proof) = key_and_proof(&[21, 42]);
+        let inputs = [scalar_bytes(21), scalar_bytes(42)];
+
+        assert!(!verify(&vk, &proof[..PROOF_SIZE - 1], &inputs));
+        assert!(!verify(&vk, &[0u8; 1024], &inputs));
+
+        // C off the curve
+        let mut off_curve = proof;
+        off_curve[PROOF_SIZE - 1] ^= 1;
+        assert!(!verify(&vk, &off_curve, &inputs));
+
+        // Input above the scalar field modulus
+        assert!(!verify(&vk, &proof, &[[0xFF; 32], scalar_bytes(42)]));
+    }
+
+    #[test]
+    fn sp1_public_inputs_fit_the_scalar_field() {
+        let [vkey, digest] = sp1_public_inputs(&[7u8; 32], b"public values");
+        assert_eq!(vkey, [7u8; 32]);
+        assert_eq!(digest, hash_public_values(b"public values"));
+        assert_eq!(digest[0] & 0xe0, 0);
+        assert_eq!(digest[1..], sp_core::hashing::sha2_256(b"public values")[1..]);
+        assert!(Fr::from_slice(&digest).is_ok());
+    }
+}
@@ -7,18 +7,59 @@
 //! ## Security Model
 //!
 //! The security of this pallet relies on:
-//! 1. **SP1 STARK Soundness**: The proof system guarantees computational integrity
+//! 1. **SP1 Soundness**: Once `AdminOrigin` sets a Groth16 verifying key with
+//!    `set_groth16_vk`, every proof is an SP1 Groth16 proof checked with a
+//!    BN254 pairing (see [`groth16`]); until then, proofs are rejected unless
+//!    the `lenient-testnet` feature enables structural checks, which is only
+//!    acceptable on testnets
 //! 2. **VKey Binding**: Proofs are tied to a specific verification key
 //! 3. **Public Output Commitment**: The proof commits to verified request IDs
+//! 4. **Epoch Binding**: Proofs commit to a `ProofEpoch`, so they can't be
+//!    replayed after an upgrade that bumps it
+//! 5. **Co-signing** (interim): with a `CoSignThreshold` of k, a proof is only
+//!    accepted once k distinct active aggregators approve it — the submitter
+//!    plus co-signatures over [`Pallet::cosign_payload`] — so a single
+//!    compromised aggregator can't push a batch alone
+//! 6. **Anchoring**: submissions name a recent block of this chain (within
+//!    `MaxAnchorAge`), so one lifted from a fork or replayed long after is
+//!    rejected. The anchor is not part of the proof commitment, so
+//!    re-anchoring a proof does not get it past `ProofAlreadyUsed`
 //!
 //! ## Verification Flow
 //!
-//! 1. Aggregator submits proof with claimed outputs
+//! 1. Aggregator submits proof with claimed outputs, or just the request ID
+//!    for a single-request batch (`submit_single_proof`)
 //! 2. Pallet verifies:
 //!    - VKey hash matches expected (program integrity)
 //!    - Proof structure is valid
 //!    - Public outputs are correctly committed
-//! 3. On success, request IDs are marked as verified
+//! 3. On success, request IDs are marked as verified. With
+//!    `RootOnlyBatches` on, only the requests root is stored, and each
+//!    request is marked on demand with `claim_request_verification` and a
+//!    Merkle inclusion proof
+//! 4. At the end of the block, verified batches are summarized in a header
+//!    digest (see [`digest`])
+//! 5. `ProofRetentionPeriod` blocks later the batch expires and is pruned,
+//!    in `on_idle` or by anyone through `prune_expired`
+//!
+//! ## Guest Programs
+//!
+//! Proofs name the guest program they are of. Re-ML
+//! ([`REML_PROGRAM_ID`]) proofs are checked against `ExpectedVKeyHash` and
+//! mark their requests in `VerifiedRequests`, which vaults read. Other
+//! programs committing the same public values layout (e.g. another
+//! signature scheme's verifier) are accepted when `Config::ProgramVKeys`
+//! accepts their vkey; their requests go to `ProgramRequests` under the
+//! program's ID, in the same batch ID space.
+//!
+//! ## Aggregate Proofs
+//!
+//! The aggregation guest ([`REML_AGGREGATION_PROGRAM_ID`], vkey from
+//! `Config::ProgramVKeys`) verifies up to [`MAX_AGGREGATED_BATCHES`] Re-ML
+//! batch proofs inside the zkVM and commits their roots
+//! ([`AggregatePublicValues`]). `submit_aggregate_proof` checks that one
+//! proof and records every sub-batch as a root-only Re-ML batch, whose
+//! requests are marked with `claim_request_verification`.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -26,6 +67,13 @@ extern crate alloc;
 
 pub use pallet::*;
 
+pub mod digest;
+pub mod groth16;
+pub mod migrations;
+pub mod runtime_api;
+
+use digest::{BatchDigest, BatchDigestEntry, BATCH_DIGEST_VERSION, MAX_BATCHES_PER_BLOCK};
+
 #[cfg(test)]
 // TODO: Add mock.rs for testing
 // mod mock;
@@ -43,7 +91,16 @@ pub use weights::*;
 // ═══════════════════════════════════════════════════════════════════════════
 
 /// Re-ML Protocol Version
-pub const REML_VERSION: u8 = 1;
+///
+/// 2: public values carry the blake2 requests root next to the keccak one
+/// 3: public values carry per-scheme verified counts (ML-DSA-44, Falcon-512)
+/// 4: public values carry the owner key hash of each verified request
+/// 5: public values carry the hash of the message each verified request's
+///    signature is over
+pub const REML_VERSION: u8 = 5;
+
+/// `tracing` target of the spans around proof submission
+pub const TRACE_TARGET: &str = "sanctuary::reml";
 
 /// Tesserax Chain ID
 pub const TESSERAX_CHAIN_ID: u32 = 13817;
@@ -58,16 +115,168 @@ pub const MAX_VERIFIED_REQUESTS: u32 = 1_000;
 pub const MIN_PROOF_SIZE: usize = 1024;
 
 /// SP1 Groth16 proof size (for compressed proofs)
-pub const GROTH16_PROOF_SIZE: usize = 260;
+pub const GROTH16_PROOF_SIZE: usize = groth16::PROOF_SIZE;
+
+/// Maximum batches returned per `list_batches` page
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+/// Rejected proof submissions kept in `RecentRejections`
+pub const MAX_RECENT_REJECTIONS: u32 = 64;
+
+/// Batches a single `prune_expired` call can prune
+pub const MAX_PRUNE_BATCHES: u32 = 32;
+
+/// Maximum siblings in a request inclusion proof, enough for
+/// `MAX_VERIFIED_REQUESTS` leaves
+pub const MAX_INCLUSION_PROOF_DEPTH: u32 = 10;
+
+/// Request IDs routed to the same aggregator by `suggested_aggregator`, a
+/// full batch's worth
+pub const ROUTING_RANGE_SIZE: u64 = 256;
+
+/// Watchtower attestations an upheld dispute cancels out in routing weights
+pub const DISPUTE_ROUTING_PENALTY: u32 = 4;
+
+/// Domain separator of routing draws (`blake2_256(ROUTING_CONTEXT || era || range)`)
+pub const ROUTING_CONTEXT: &[u8] = b"tesserax/reml-route/v1:";
+
+/// Domain separator of co-signed messages (`COSIGN_CONTEXT || proof_commitment`)
+pub const COSIGN_CONTEXT: &[u8] = b"tesserax/reml-cosign/v1:";
+
+/// Identifier of a guest program, as in pallet-program-registry
+pub type ProgramId = u32;
+
+/// The Re-ML guest (`reml/guest`)
+pub const REML_PROGRAM_ID: ProgramId = 1;
+
+/// The Re-ML aggregation guest (`reml/aggregation`), folding Re-ML
+/// batch proofs into one
+pub const REML_AGGREGATION_PROGRAM_ID: ProgramId = 2;
+
+/// Most batch proofs one aggregate proof folds
+pub const MAX_AGGREGATED_BATCHES: u32 = 16;
+
+/// Domain separator of aggregated batches' proof commitments, so they can't
+/// be matched by a single-batch `ProofSubmission`
+pub const AGGREGATE_COMMITMENT_CONTEXT: &[u8] = b"tesserax/reml-aggregate/v1:";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// BATCH HANDLERS
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Called for every batch accepted by `submit_proof`
+///
+/// Lets the runtime mirror verified batches elsewhere (e.g. EVM storage)
+/// in the same extrinsic.
+pub trait OnBatchVerified<AccountId, BlockNumber> {
+    fn on_batch_verified(batch_id: u64, info: &BatchInfo<AccountId, BlockNumber>);
+
+    /// Worst-case weight of `on_batch_verified`
+    fn weight() -> frame_support::weights::Weight;
+}
+
+impl<AccountId, BlockNumber> OnBatchVerified<AccountId, BlockNumber> for () {
+    fn on_batch_verified(_batch_id: u64, _info: &BatchInfo<AccountId, BlockNumber>) {}
+
+    fn weight() -> frame_support::weights::Weight {
+        frame_support::weights::Weight::zero()
+    }
+}
+
+impl<AccountId, BlockNumber, A, B> OnBatchVerified<AccountId, BlockNumber> for (A, B)
+where
+    A: OnBatchVerified<AccountId, BlockNumber>,
+    B: OnBatchVerified<AccountId, BlockNumber>,
+{
+    fn on_batch_verified(batch_id: u64, info: &BatchInfo<AccountId, BlockNumber>) {
+        A::on_batch_verified(batch_id, info);
+        B::on_batch_verified(batch_id, info);
+    }
+
+    fn weight() -> frame_support::weights::Weight {
+        A::weight().saturating_add(B::weight())
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// GUEST PROGRAMS
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Verification keys of the guest programs besides Re-ML
+pub trait ProgramVKeys {
+    /// Whether proofs of `program_id` may commit to `vkey_hash`
+    fn accepts(program_id: ProgramId, vkey_hash: &[u8; 32]) -> bool;
+}
+
+/// Only Re-ML proofs
+impl ProgramVKeys for () {
+    fn accepts(_program_id: ProgramId, _vkey_hash: &[u8; 32]) -> bool {
+        false
+    }
+}
+
+/// Verified requests as seen by other pallets
+///
+/// A request is verified once its batch is; consuming it lets a pallet act
+/// on that verification exactly once. Only the holder of the key that signed
+/// the request can consume it.
+pub trait RemlVerification<AccountId> {
+    /// Whether `request_id` belongs to a verified batch
+    fn is_request_verified(request_id: u64) -> bool;
+
+    /// Whether `request_id` was already consumed
+    fn is_request_consumed(request_id: u64) -> bool;
+
+    /// Check that `request_id` is verified, was signed by the key whose
+    /// `blake2_256` is `owner` and is not yet consumed, then mark it consumed
+    /// by `consumer`
+    fn consume_verified_request(
+        request_id: u64,
+        owner: &[u8; 32],
+        consumer: &AccountId,
+    ) -> frame_support::dispatch::DispatchResult;
+
+    /// `blake2_256` of the message the signature of `request_id` is over, if
+    /// its batch committed one
+    ///
+    /// Together with the owner, this says the request's key signed exactly
+    /// that message, so a pallet can accept the proof in place of the
+    /// signature.
+    fn request_message(request_id: u64) -> Option<[u8; 32]>;
+}
 
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
-    use frame_support::pallet_prelude::*;
+    use frame_support::{
+        pallet_prelude::*,
+        storage::with_transaction,
+        traits::{
+            BalanceStatus, Contains, Currency, ExistenceRequirement, OnUnbalanced,
+            ReservableCurrency,
+        },
+        PalletId,
+    };
     use frame_system::pallet_prelude::*;
     use sp_core::H256;
+    use sp_runtime::{
+        traits::{AccountIdConversion, IdentifyAccount, One, Saturating, Verify, Zero},
+        Perbill, SaturatedConversion, TransactionOutcome,
+    };
+
+    pub type BalanceOf<T> = <<T as Config>::RewardCurrency as Currency<
+        <T as frame_system::Config>::AccountId,
+    >>::Balance;
+    pub type NegativeImbalanceOf<T> = <<T as Config>::RewardCurrency as Currency<
+        <T as frame_system::Config>::AccountId,
+    >>::NegativeImbalance;
+
+    /// v1: `AggregatorInfo` gained `reputation`
+    /// v2: `AggregatorInfo` gained `server_key_hash`
+    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     // ═══════════════════════════════════════════════════════════════════════
@@ -79,13 +288,126 @@ pub mod pallet {
         /// Weight information for extrinsics
         type WeightInfo: WeightInfo;
 
+        /// Origin allowed to manage aggregators, the Groth16 verifying key
+        /// and the verifier's switches
+        type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
         /// Maximum number of authorized aggregators
         #[pallet::constant]
         type MaxAggregators: Get<u32>;
 
         /// Expected verification key hash for the Re-ML guest program
-        #[pallet::constant]
+        ///
+        /// Read on every submission, so it may come from storage, e.g. the
+        /// active Re-ML version in pallet-program-registry.
         type ExpectedVKeyHash: Get<[u8; 32]>;
+
+        /// Verification key hash of the previous guest program, still
+        /// accepted while provers switch to `ExpectedVKeyHash`
+        ///
+        /// E.g. the replaced Re-ML version during its transition window in
+        /// pallet-program-registry; `()` accepts only the expected hash.
+        type TransitionVKeyHash: Get<Option<[u8; 32]>>;
+
+        /// Vkey hashes accepted for guest programs other than Re-ML
+        ///
+        /// E.g. the active versions in pallet-program-registry; `()` only
+        /// accepts Re-ML proofs.
+        type ProgramVKeys: ProgramVKeys;
+
+        /// `VerifiedRequests` entries a proof submission writes itself
+        ///
+        /// The rest are queued in `PendingRequestMarks` and written in
+        /// `on_idle`, bounding the storage writes of one extrinsic. Use
+        /// `MAX_VERIFIED_REQUESTS` to write every entry inline.
+        #[pallet::constant]
+        type InlineRequestMarks: Get<u32>;
+
+        /// Replay-protection domain of accepted proofs
+        ///
+        /// Proofs commit to the epoch they were generated for; bump it in a
+        /// runtime upgrade that changes what a verified proof means, so proofs
+        /// generated before the upgrade are rejected.
+        #[pallet::constant]
+        type ProofEpoch: Get<u32>;
+
+        /// Handler for newly verified batches
+        type OnBatchVerified: OnBatchVerified<Self::AccountId, BlockNumberFor<Self>>;
+
+        /// Signature aggregators co-sign proof commitments with
+        type CoSignature: Parameter + Verify<Signer: IdentifyAccount<AccountId = Self::AccountId>>;
+
+        /// Currency aggregator rewards and tips are paid in, and stakes are
+        /// bonded in
+        type RewardCurrency: ReservableCurrency<Self::AccountId>;
+
+        /// Vault accounts, which may only move funds with a Dilithium signature
+        ///
+        /// They can't tip batches: anyone can bond an aggregator, so a tip
+        /// could move a vault's funds to an account its classical key controls.
+        type VaultCheck: Contains<Self::AccountId>;
+
+        /// Reward credited to an aggregator's ledger for each verified batch
+        ///
+        /// Paid out of the reward pot by `claim_earnings`, so the pot must be
+        /// funded for claims to succeed.
+        #[pallet::constant]
+        type BatchReward: Get<BalanceOf<Self>>;
+
+        /// Id of the reward pot account holding tips and funding rewards
+        #[pallet::constant]
+        type RewardPotId: Get<PalletId>;
+
+        /// Blocks per era, the period `MaxBatchesPerEra` applies to
+        #[pallet::constant]
+        type EraLength: Get<BlockNumberFor<Self>>;
+
+        /// Batches a single aggregator can have verified per era
+        ///
+        /// Keeps one bonded aggregator from filling verifier block space and
+        /// storage on its own.
+        #[pallet::constant]
+        type MaxBatchesPerEra: Get<u32>;
+
+        /// Minimum stake an aggregator must bond with `bond_aggregator`
+        #[pallet::constant]
+        type AggregatorBond: Get<BalanceOf<Self>>;
+
+        /// Blocks an unbonded stake stays reserved, and slashable, before it
+        /// can be withdrawn
+        ///
+        /// Should cover the time an invalid proof takes to be noticed and
+        /// reported.
+        #[pallet::constant]
+        type UnbondingPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Handler for slashed stake; `()` burns it
+        type Slash: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+        /// Blocks a verified batch is kept before it can be pruned
+        ///
+        /// Pruning forgets the batch, its requests and its proof commitment:
+        /// requests must be consumed within this period, and it should exceed
+        /// `UnbondingPeriod` so invalid proofs can still be reported.
+        #[pallet::constant]
+        type ProofRetentionPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Blocks a submission's anchor block may lag behind the current one
+        ///
+        /// Must not exceed `frame_system`'s `BlockHashCount`, past which the
+        /// anchor's hash is no longer known and submissions are rejected.
+        #[pallet::constant]
+        type MaxAnchorAge: Get<BlockNumberFor<Self>>;
+
+        /// Deposit reserved from the aggregator for each verified batch,
+        /// released when the batch is pruned
+        #[pallet::constant]
+        type SubmissionDeposit: Get<BalanceOf<Self>>;
+
+        /// Share of a batch's deposit paid to whoever prunes it with
+        /// `prune_expired`; the rest goes back to its aggregator
+        #[pallet::constant]
+        type PrunerShare: Get<Perbill>;
     }
 
     // ═══════════════════════════════════════════════════════════════════════
@@ -109,12 +431,78 @@ pub mod pallet {
     pub type VerifiedBatches<T: Config> =
         StorageMap<_, Twox64Concat, u64, BatchInfo<T::AccountId, BlockNumberFor<T>>, OptionQuery>;
 
+    /// Reverse index from a batch's `requests_root` to its batch ID
+    ///
+    /// The first batch verified with a given root keeps the entry.
+    #[pallet::storage]
+    pub type RootToBatch<T: Config> = StorageMap<_, Blake2_128Concat, [u8; 32], u64, OptionQuery>;
+
+    /// Blake2 requests root of each verified batch, for Substrate consumers
+    ///
+    /// `BatchInfo::requests_root` is the keccak root EVM consumers check.
+    /// Batches verified before protocol version 2 have no entry.
+    #[pallet::storage]
+    pub type BatchRootsBlake2<T: Config> = StorageMap<_, Twox64Concat, u64, [u8; 32], OptionQuery>;
+
     /// Individual request verification status
     #[pallet::storage]
     #[pallet::getter(fn verified_requests)]
     pub type VerifiedRequests<T: Config> =
         StorageMap<_, Twox64Concat, u64, (u64, BlockNumberFor<T>), OptionQuery>;
 
+    /// Requests verified by guest programs other than Re-ML, by program, with
+    /// their batch and the block it was verified in
+    #[pallet::storage]
+    pub type ProgramRequests<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        ProgramId,
+        Twox64Concat,
+        u64,
+        (u64, BlockNumberFor<T>),
+        OptionQuery,
+    >;
+
+    /// Guest program of each verified batch not proved by Re-ML
+    #[pallet::storage]
+    pub type BatchPrograms<T: Config> = StorageMap<_, Twox64Concat, u64, ProgramId, OptionQuery>;
+
+    /// Verified requests consumed through [`RemlVerification`], with their
+    /// consumer and the block they were consumed in
+    ///
+    /// Kept when the batch the request was verified by is pruned, so a later
+    /// batch proving the same request again can't make it consumable twice.
+    #[pallet::storage]
+    pub type ConsumedRequests<T: Config> =
+        StorageMap<_, Twox64Concat, u64, (T::AccountId, BlockNumberFor<T>), OptionQuery>;
+
+    /// Owner of each verified Re-ML request: `blake2_256` of the key that
+    /// signed it, as committed by the proof
+    ///
+    /// Written with `VerifiedRequests`. Requests marked from an inclusion
+    /// proof have no owner, so they can't be consumed.
+    #[pallet::storage]
+    #[pallet::getter(fn request_owner)]
+    pub type RequestOwners<T: Config> = StorageMap<_, Twox64Concat, u64, [u8; 32], OptionQuery>;
+
+    /// `blake2_256` of the message each verified Re-ML request's signature is
+    /// over, as committed by the proof
+    ///
+    /// Written with `RequestOwners`, for batches that commit messages.
+    #[pallet::storage]
+    #[pallet::getter(fn request_message)]
+    pub type RequestMessages<T: Config> = StorageMap<_, Twox64Concat, u64, [u8; 32], OptionQuery>;
+
+    /// [`PublicValues::owners_hash`] of each batch verified from its request
+    /// IDs, which `report_invalid_proof` checks submissions against
+    #[pallet::storage]
+    pub type BatchOwnersHashes<T: Config> = StorageMap<_, Twox64Concat, u64, [u8; 32], OptionQuery>;
+
+    /// [`PublicValues::messages_hash`] of the same batches, checked alike
+    #[pallet::storage]
+    pub type BatchMessagesHashes<T: Config> =
+        StorageMap<_, Twox64Concat, u64, [u8; 32], OptionQuery>;
+
     /// Total number of proofs verified
     #[pallet::storage]
     #[pallet::getter(fn total_proofs_verified)]
@@ -125,11 +513,177 @@ pub mod pallet {
     #[pallet::getter(fn total_signatures_verified)]
     pub type TotalSignaturesVerified<T: Config> = StorageValue<_, u64, ValueQuery>;
 
+    /// Batches verified in the current block, flushed to the header digest
+    /// in `on_finalize`
+    #[pallet::storage]
+    pub type BlockBatches<T: Config> = StorageValue<
+        _,
+        BoundedVec<BatchDigestEntry, ConstU32<MAX_BATCHES_PER_BLOCK>>,
+        ValueQuery,
+    >;
+
     /// Proof commitment storage (for replay prevention)
     #[pallet::storage]
     pub type ProofCommitments<T: Config> =
         StorageMap<_, Blake2_128Concat, H256, BlockNumberFor<T>, OptionQuery>;
 
+    /// Distinct active aggregators (submitter included) that must approve a
+    /// proof; 0 and 1 both mean the submitter alone
+    #[pallet::storage]
+    pub type CoSignThreshold<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Whether proof submission is paused
+    #[pallet::storage]
+    pub type VerifierPaused<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+    /// Request IDs of verified batches not yet written to `VerifiedRequests`,
+    /// with the block their batch was verified
+    #[pallet::storage]
+    pub type PendingRequestMarks<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        u64,
+        (BlockNumberFor<T>, BoundedVec<u64, ConstU32<MAX_VERIFIED_REQUESTS>>),
+        OptionQuery,
+    >;
+
+    /// Owners of the `PendingRequestMarks` requests, in the same order
+    #[pallet::storage]
+    pub type PendingRequestOwners<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        u64,
+        BoundedVec<[u8; 32], ConstU32<MAX_VERIFIED_REQUESTS>>,
+        ValueQuery,
+    >;
+
+    /// Message hashes of the `PendingRequestMarks` requests, in the same order
+    #[pallet::storage]
+    pub type PendingRequestMessages<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        u64,
+        BoundedVec<[u8; 32], ConstU32<MAX_VERIFIED_REQUESTS>>,
+        ValueQuery,
+    >;
+
+    /// ID of the most recently verified batch
+    #[pallet::storage]
+    pub type LastVerifiedBatch<T: Config> = StorageValue<_, u64, OptionQuery>;
+
+    /// Verifying key of SP1 Groth16 proofs; `None` leaves proofs unverified
+    #[pallet::storage]
+    pub type Groth16Vk<T: Config> = StorageValue<_, groth16::VerifyingKey, OptionQuery>;
+
+    /// Aggregator that claimed each batch ID with `claim_batch_id`, until the
+    /// batch is verified
+    #[pallet::storage]
+    pub type BatchIdClaims<T: Config> = StorageMap<_, Twox64Concat, u64, T::AccountId, OptionQuery>;
+
+    /// Block of the latest batch ID claim and the claims made in it
+    #[pallet::storage]
+    pub type BlockClaims<T: Config> = StorageValue<_, (BlockNumberFor<T>, u32), ValueQuery>;
+
+    /// Whether every proof must use a batch ID claimed by its submitter
+    #[pallet::storage]
+    pub type RequireClaimedBatchIds<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+    /// Whether verified batches store only their requests root
+    ///
+    /// Requests of such batches are marked one at a time with
+    /// `claim_request_verification`. Single-request batches are always
+    /// marked on submission.
+    #[pallet::storage]
+    pub type RootOnlyBatches<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+    /// What each aggregator has earned from its verified batches
+    #[pallet::storage]
+    pub type AggregatorLedger<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, AggregatorEarnings<BalanceOf<T>>, ValueQuery>;
+
+    /// What each verified batch earned its aggregator
+    #[pallet::storage]
+    pub type BatchLedger<T: Config> =
+        StorageMap<_, Twox64Concat, u64, BatchEarnings<BalanceOf<T>>, ValueQuery>;
+
+    /// Era of each aggregator's latest verified batch and how many of its
+    /// batches were verified in that era
+    #[pallet::storage]
+    pub type AggregatorEraBatches<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, (u32, u32), ValueQuery>;
+
+    /// Latest rejected proof submissions, oldest first
+    ///
+    /// Failed extrinsics roll back their storage and events, so the runtime
+    /// records these after dispatch through [`Pallet::note_rejection`].
+    #[pallet::storage]
+    pub type RecentRejections<T: Config> = StorageValue<
+        _,
+        BoundedVec<Rejection<T::AccountId, BlockNumberFor<T>>, ConstU32<MAX_RECENT_REJECTIONS>>,
+        ValueQuery,
+    >;
+
+    /// Stake each aggregator has bonded with `bond_aggregator`
+    #[pallet::storage]
+    pub type AggregatorStakes<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+    /// Unbonded stake of each aggregator and the block it can be withdrawn from
+    #[pallet::storage]
+    pub type AggregatorUnbonding<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        (BalanceOf<T>, BlockNumberFor<T>),
+        OptionQuery,
+    >;
+
+    /// Block each aggregator was last slashed in
+    ///
+    /// Slashed aggregators stay registered and inactive after withdrawing, so
+    /// bonding again does not reactivate them.
+    #[pallet::storage]
+    pub type SlashedAggregators<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>, OptionQuery>;
+
+    /// Batches proven invalid with `report_invalid_proof`, with the block of
+    /// the report
+    #[pallet::storage]
+    pub type InvalidBatches<T: Config> =
+        StorageMap<_, Twox64Concat, u64, BlockNumberFor<T>, OptionQuery>;
+
+    /// Request IDs of each verified batch, removed from `VerifiedRequests`
+    /// when the batch is pruned
+    #[pallet::storage]
+    pub type BatchRequests<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        u64,
+        BoundedVec<u64, ConstU32<MAX_VERIFIED_REQUESTS>>,
+        OptionQuery,
+    >;
+
+    /// `SubmissionDeposit` reserved from the aggregator of each verified batch
+    #[pallet::storage]
+    pub type BatchDeposits<T: Config> =
+        StorageMap<_, Twox64Concat, u64, BalanceOf<T>, OptionQuery>;
+
+    /// Verified batch IDs in verification order, pruned from the front in
+    /// `on_idle`
+    #[pallet::storage]
+    pub type PruneQueue<T: Config> = StorageMap<_, Twox64Concat, u64, u64, OptionQuery>;
+
+    /// Index of the first and one past the last `PruneQueue` entry
+    #[pallet::storage]
+    pub type PruneQueueBounds<T: Config> = StorageValue<_, (u64, u64), ValueQuery>;
+
+    /// Pruned batches, with the block they were pruned in
+    ///
+    /// Kept so a pruned batch's proof cannot be submitted again.
+    #[pallet::storage]
+    pub type PrunedBatches<T: Config> =
+        StorageMap<_, Twox64Concat, u64, BlockNumberFor<T>, OptionQuery>;
+
     // ═══════════════════════════════════════════════════════════════════════
     // TYPES
     // ═══════════════════════════════════════════════════════════════════════
@@ -141,6 +695,25 @@ pub mod pallet {
         pub registered_at: BlockNumber,
         pub proofs_submitted: u64,
         pub active: bool,
+        /// Track record reported by watchtowers
+        pub reputation: Reputation,
+        /// SHA-256 of the aggregator server's key, set with `set_server_key_hash`
+        ///
+        /// The DER-encoded TLS certificate of its HTTPS endpoint, or the raw
+        /// public key it signs responses with, so clients can authenticate the
+        /// server before sending it signature material.
+        pub server_key_hash: Option<[u8; 32]>,
+    }
+
+    /// Independent checks of an aggregator's batches
+    #[derive(
+        Clone, Debug, Default, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen,
+    )]
+    pub struct Reputation {
+        /// Watchtower attestations that re-verified one of its batches
+        pub attestations: u32,
+        /// Disputes against its batches that governance upheld
+        pub upheld_disputes: u32,
     }
 
     /// Verified batch information
@@ -154,6 +727,42 @@ pub mod pallet {
         pub proof_commitment: [u8; 32],
     }
 
+    /// Rejected proof submission
+    #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+    pub struct Rejection<AccountId, BlockNumber> {
+        pub batch_id: u64,
+        pub aggregator: AccountId,
+        pub reason: RejectReason,
+        pub rejected_at: BlockNumber,
+    }
+
+    /// Running totals of an aggregator's earnings
+    #[derive(Clone, Debug, Default, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+    pub struct AggregatorEarnings<Balance> {
+        /// `BatchReward`s credited for verified batches
+        pub rewards: Balance,
+        /// Tips paid for its batches
+        pub tips: Balance,
+        /// Vault transfer premiums paid on requests its batches verified
+        ///
+        /// Informational: the premiums go to the vault treasury and are not
+        /// part of `claimable`.
+        pub premiums_attributed: Balance,
+        /// Rewards and tips not yet claimed
+        pub claimable: Balance,
+        /// Rewards and tips paid out by `claim_earnings`
+        pub claimed: Balance,
+    }
+
+    /// What one verified batch earned its aggregator
+    #[derive(Clone, Debug, Default, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+    pub struct BatchEarnings<Balance> {
+        pub reward: Balance,
+        pub tips: Balance,
+        /// Vault transfer premiums paid on requests of the batch
+        pub premiums_attributed: Balance,
+    }
+
     /// Proof submission data
     #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, DecodeWithMemTracking, TypeInfo)]
     pub struct ProofSubmission {
@@ -164,6 +773,52 @@ pub mod pallet {
         pub public_values: PublicValues,
         /// Verification key hash
         pub vkey_hash: [u8; 32],
+        /// Guest program the proof is of, [`REML_PROGRAM_ID`] for Re-ML
+        pub program_id: ProgramId,
+        /// Recent block of this chain the submission is bound to, at most
+        /// `MaxAnchorAge` blocks old
+        pub anchor_block: u32,
+        /// Hash of `anchor_block`
+        pub anchor_hash: [u8; 32],
+    }
+
+    /// Proof of a batch holding exactly one request
+    ///
+    /// Carries only the request ID; the pallet rebuilds the public values
+    /// (`verified_count` 1, one-leaf `requests_root`, current epoch), so
+    /// urgent single requests need not wait for batch aggregation.
+    #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, DecodeWithMemTracking, TypeInfo)]
+    pub struct SingleProofSubmission {
+        pub batch_id: u64,
+        /// SP1 proof of the one-request batch
+        pub proof: BoundedVec<u8, ConstU32<102_400>>,
+        /// The request the proof verified
+        pub request_id: u64,
+        /// `blake2_256` of the key that signed the request
+        pub owner: [u8; 32],
+        /// `blake2_256` of the message the request's signature is over
+        pub message: [u8; 32],
+        /// Scheme the request was signed with
+        pub scheme: SignatureScheme,
+        /// Verification key hash
+        pub vkey_hash: [u8; 32],
+        /// Recent block of this chain the submission is bound to
+        pub anchor_block: u32,
+        /// Hash of `anchor_block`
+        pub anchor_hash: [u8; 32],
+    }
+
+    /// Merkle proof that a request is in a verified batch's blake2 requests
+    /// root (see [`tesserax_merkle::request_proof`])
+    #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, DecodeWithMemTracking, TypeInfo)]
+    pub struct RequestInclusionProof {
+        pub batch_id: u64,
+        /// Position of the request in the batch's `verified_request_ids`
+        pub leaf_index: u32,
+        /// Number of requests in the batch
+        pub leaf_count: u32,
+        /// Sibling hashes from the leaf up
+        pub siblings: BoundedVec<[u8; 32], ConstU32<MAX_INCLUSION_PROOF_DEPTH>>,
     }
 
     /// Public values structure (matches guest output)
@@ -171,10 +826,232 @@ pub mod pallet {
     pub struct PublicValues {
         pub version: u8,
         pub chain_id: u32,
+        /// Proof epoch (`Config::ProofEpoch`) the proof was generated for
+        pub epoch: u32,
         pub batch_id: u64,
         pub verified_count: u32,
+        /// How many of the verified signatures are ML-DSA-44
+        pub mldsa44_count: u32,
+        /// How many of the verified signatures are Falcon-512
+        pub falcon512_count: u32,
+        /// Keccak merkle root of `verified_request_ids`, for EVM consumers
         pub requests_root: [u8; 32],
+        /// Blake2 merkle root of the same tree, for Substrate consumers
+        pub requests_root_blake2: [u8; 32],
         pub verified_request_ids: BoundedVec<u64, ConstU32<1_000>>,
+        /// `blake2_256` of the key that signed each verified request, in
+        /// `verified_request_ids` order; empty if the program commits none
+        pub verified_request_owners: BoundedVec<[u8; 32], ConstU32<1_000>>,
+        /// `blake2_256` of the message each verified request's signature is
+        /// over (FIPS 204 M', with any context and pre-hash), in
+        /// `verified_request_ids` order; empty if the program commits none
+        pub verified_request_messages: BoundedVec<[u8; 32], ConstU32<1_000>>,
+    }
+
+    impl PublicValues {
+        /// `blake2_256` of the concatenated `verified_request_owners`
+        pub fn owners_hash(&self) -> [u8; 32] {
+            sp_core::blake2_256(&self.verified_request_owners.concat())
+        }
+
+        /// `blake2_256` of the concatenated `verified_request_messages`
+        pub fn messages_hash(&self) -> [u8; 32] {
+            sp_core::blake2_256(&self.verified_request_messages.concat())
+        }
+
+        /// Hash the proof must commit to:
+        /// `blake2_256(version || chain_id || epoch || batch_id || verified_count || mldsa44_count
+        /// || falcon512_count || requests_root || requests_root_blake2 || owners_hash
+        /// || messages_hash)`
+        pub fn binding_hash(&self) -> [u8; 32] {
+            let mut data = alloc::vec::Vec::new();
+            data.push(self.version);
+            data.extend_from_slice(&self.chain_id.to_le_bytes());
+            data.extend_from_slice(&self.epoch.to_le_bytes());
+            data.extend_from_slice(&self.batch_id.to_le_bytes());
+            data.extend_from_slice(&self.verified_count.to_le_bytes());
+            data.extend_from_slice(&self.mldsa44_count.to_le_bytes());
+            data.extend_from_slice(&self.falcon512_count.to_le_bytes());
+            data.extend_from_slice(&self.requests_root);
+            data.extend_from_slice(&self.requests_root_blake2);
+            data.extend_from_slice(&self.owners_hash());
+            data.extend_from_slice(&self.messages_hash());
+            sp_core::blake2_256(&data)
+        }
+
+        /// The guest's Solidity ABI commitment of these values, which Groth16
+        /// proofs commit:
+        /// `abi.encode(uint8, uint32, uint32, uint64, uint32, uint32, uint32, bytes32, bytes32,
+        /// uint64[], bytes32[], bytes32[])`
+        pub fn abi_encode(&self) -> alloc::vec::Vec<u8> {
+            let word = |value: u64| {
+                let mut word = [0u8; 32];
+                word[24..].copy_from_slice(&value.to_be_bytes());
+                word
+            };
+            let ids = &self.verified_request_ids;
+            let owners = &self.verified_request_owners;
+            let messages = &self.verified_request_messages;
+            let mut out = alloc::vec::Vec::with_capacity(
+                (15 + ids.len() + owners.len() + messages.len()) * 32,
+            );
+            out.extend_from_slice(&word(self.version as u64));
+            out.extend_from_slice(&word(self.chain_id as u64));
+            out.extend_from_slice(&word(self.epoch as u64));
+            out.extend_from_slice(&word(self.batch_id));
+            out.extend_from_slice(&word(self.verified_count as u64));
+            out.extend_from_slice(&word(self.mldsa44_count as u64));
+            out.extend_from_slice(&word(self.falcon512_count as u64));
+            out.extend_from_slice(&self.requests_root);
+            out.extend_from_slice(&self.requests_root_blake2);
+            // Offsets of the dynamic arrays: the IDs right after the 12 head
+            // words, the owners after the IDs and the messages after those
+            let owners_at = 13 + ids.len() as u64;
+            out.extend_from_slice(&word(12 * 32));
+            out.extend_from_slice(&word(owners_at * 32));
+            out.extend_from_slice(&word((owners_at + 1 + owners.len() as u64) * 32));
+            out.extend_from_slice(&word(ids.len() as u64));
+            for id in ids.iter() {
+                out.extend_from_slice(&word(*id));
+            }
+            out.extend_from_slice(&word(owners.len() as u64));
+            for owner in owners.iter() {
+                out.extend_from_slice(owner);
+            }
+            out.extend_from_slice(&word(messages.len() as u64));
+            for message in messages.iter() {
+                out.extend_from_slice(message);
+            }
+            out
+        }
+    }
+
+    /// One batch proof folded into an aggregate proof: its public values
+    /// without the request IDs, which stay behind the roots
+    #[derive(
+        Clone, Debug, PartialEq, Eq, Encode, Decode, DecodeWithMemTracking, TypeInfo, MaxEncodedLen,
+    )]
+    pub struct SubBatchValues {
+        pub batch_id: u64,
+        pub verified_count: u32,
+        pub mldsa44_count: u32,
+        pub falcon512_count: u32,
+        pub requests_root: [u8; 32],
+        pub requests_root_blake2: [u8; 32],
+    }
+
+    /// Public values of the aggregation guest (matches
+    /// `reml_lib::RemlAggregateOutput`)
+    ///
+    /// Every sub-batch proof was verified against `batch_vkey_hash` and
+    /// committed the shared `version`, `chain_id` and `epoch`; the counts
+    /// are the sub-batches' totals.
+    #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, DecodeWithMemTracking, TypeInfo)]
+    pub struct AggregatePublicValues {
+        pub version: u8,
+        pub chain_id: u32,
+        pub epoch: u32,
+        /// Vkey hash of the Re-ML guest the sub-batch proofs are of
+        pub batch_vkey_hash: [u8; 32],
+        pub verified_count: u32,
+        pub mldsa44_count: u32,
+        pub falcon512_count: u32,
+        pub sub_batches: BoundedVec<SubBatchValues, ConstU32<MAX_AGGREGATED_BATCHES>>,
+    }
+
+    impl AggregatePublicValues {
+        /// Hash the lenient structural checks look for in the proof:
+        /// `blake2_256` of the SCALE encoding
+        pub fn binding_hash(&self) -> [u8; 32] {
+            sp_core::blake2_256(&self.encode())
+        }
+
+        /// The aggregation guest's commitment:
+        /// `abi.encode(uint8, uint32, uint32, bytes32, uint32, uint32, uint32,
+        /// (uint64, uint32, uint32, uint32, bytes32, bytes32)[])`
+        pub fn abi_encode(&self) -> alloc::vec::Vec<u8> {
+            let word = |value: u64| {
+                let mut word = [0u8; 32];
+                word[24..].copy_from_slice(&value.to_be_bytes());
+                word
+            };
+            let batches = &self.sub_batches;
+            let mut out = alloc::vec::Vec::with_capacity((9 + 6 * batches.len()) * 32);
+            out.extend_from_slice(&word(self.version as u64));
+            out.extend_from_slice(&word(self.chain_id as u64));
+            out.extend_from_slice(&word(self.epoch as u64));
+            out.extend_from_slice(&self.batch_vkey_hash);
+            out.extend_from_slice(&word(self.verified_count as u64));
+            out.extend_from_slice(&word(self.mldsa44_count as u64));
+            out.extend_from_slice(&word(self.falcon512_count as u64));
+            // Offset of the dynamic array, right after the 8 head words
+            out.extend_from_slice(&word(8 * 32));
+            out.extend_from_slice(&word(batches.len() as u64));
+            // Static tuples are encoded in place
+            for batch in batches.iter() {
+                out.extend_from_slice(&word(batch.batch_id));
+                out.extend_from_slice(&word(batch.verified_count as u64));
+                out.extend_from_slice(&word(batch.mldsa44_count as u64));
+                out.extend_from_slice(&word(batch.falcon512_count as u64));
+                out.extend_from_slice(&batch.requests_root);
+                out.extend_from_slice(&batch.requests_root_blake2);
+            }
+            out
+        }
+
+        /// Public values `submit_proof` would have recorded for `batch`,
+        /// without its request IDs
+        pub fn sub_batch_values(&self, batch: &SubBatchValues) -> PublicValues {
+            PublicValues {
+                version: self.version,
+                chain_id: self.chain_id,
+                epoch: self.epoch,
+                batch_id: batch.batch_id,
+                verified_count: batch.verified_count,
+                mldsa44_count: batch.mldsa44_count,
+                falcon512_count: batch.falcon512_count,
+                requests_root: batch.requests_root,
+                requests_root_blake2: batch.requests_root_blake2,
+                verified_request_ids: BoundedVec::new(),
+                verified_request_owners: BoundedVec::new(),
+                verified_request_messages: BoundedVec::new(),
+            }
+        }
+    }
+
+    /// Aggregate proof submission data
+    #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, DecodeWithMemTracking, TypeInfo)]
+    pub struct AggregateProofSubmission {
+        /// SP1 proof of the aggregation guest
+        pub proof: BoundedVec<u8, ConstU32<102_400>>,
+        /// Public values committed in the proof
+        pub public_values: AggregatePublicValues,
+        /// Vkey hash of the aggregation guest
+        pub vkey_hash: [u8; 32],
+        /// Recent block of this chain the submission is bound to
+        pub anchor_block: u32,
+        /// Hash of `anchor_block`
+        pub anchor_hash: [u8; 32],
+    }
+
+    /// Post-quantum signature scheme of a request, as in `reml_lib`
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        Default,
+        PartialEq,
+        Eq,
+        Encode,
+        Decode,
+        DecodeWithMemTracking,
+        TypeInfo,
+        MaxEncodedLen,
+    )]
+    pub enum SignatureScheme {
+        #[default]
+        MlDsa44,
+        Falcon512,
     }
 
     /// Proof rejection reason
@@ -189,6 +1066,18 @@ pub mod pallet {
         ProofAlreadyUsed,
         StarkVerificationFailed,
         InvalidMerkleRoot,
+        InvalidAnchor,
+    }
+
+    /// Why a simulated proof submission would fail
+    #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+    pub struct ProofDryRunError {
+        /// Error the extrinsic would fail with
+        pub error: DispatchError,
+        /// Error name, e.g. `InvalidMerkleRoot`
+        pub name: alloc::vec::Vec<u8>,
+        /// Matching `ProofRejected` reason, if the error has one
+        pub reason: Option<RejectReason>,
     }
 
     // ═══════════════════════════════════════════════════════════════════════
@@ -215,8 +1104,121 @@ pub mod pallet {
             aggregator: T::AccountId,
             reason: RejectReason,
         },
-    }
-
+        CoSignThresholdSet {
+            threshold: u32,
+        },
+        /// Aggregators, besides the submitter, that co-signed a verified batch
+        ProofCoSigned {
+            batch_id: u64,
+            co_signers: alloc::vec::Vec<T::AccountId>,
+        },
+        /// Proof submission was paused or resumed
+        VerifierPausedSet {
+            paused: bool,
+        },
+        /// Batch ID reserved for `aggregator`'s next proof
+        BatchIdClaimed {
+            batch_id: u64,
+            aggregator: T::AccountId,
+        },
+        /// Claimed batch IDs were made mandatory or optional
+        ClaimedBatchIdsRequired {
+            required: bool,
+        },
+        /// `BatchReward` credited to the aggregator of a verified batch
+        BatchRewarded {
+            batch_id: u64,
+            aggregator: T::AccountId,
+            reward: BalanceOf<T>,
+        },
+        /// A verified batch was tipped
+        BatchTipped {
+            batch_id: u64,
+            tipper: T::AccountId,
+            aggregator: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// A vault transfer premium was attributed to the batch that verified
+        /// its request
+        PremiumAttributed {
+            batch_id: u64,
+            aggregator: T::AccountId,
+            request_id: u64,
+            amount: BalanceOf<T>,
+        },
+        /// An aggregator claimed its rewards and tips
+        EarningsClaimed {
+            aggregator: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// The Groth16 verifying key was set (its selector) or cleared
+        Groth16VerifyingKeySet {
+            selector: Option<[u8; 4]>,
+        },
+        /// A verified request was consumed
+        RequestConsumed {
+            request_id: u64,
+            consumer: T::AccountId,
+        },
+        /// Stake bonded by an aggregator, and its bonded total
+        AggregatorBonded {
+            aggregator: T::AccountId,
+            amount: BalanceOf<T>,
+            total: BalanceOf<T>,
+        },
+        /// An aggregator unbonded its stake, withdrawable from `unlock_at`
+        AggregatorUnbonding {
+            aggregator: T::AccountId,
+            amount: BalanceOf<T>,
+            unlock_at: BlockNumberFor<T>,
+        },
+        /// Unbonded stake was released to its aggregator
+        StakeWithdrawn {
+            aggregator: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// A verified batch's proof was shown not to verify
+        InvalidProofReported {
+            batch_id: u64,
+            aggregator: T::AccountId,
+            reporter: T::AccountId,
+        },
+        /// Stake slashed from an aggregator, which was deactivated
+        AggregatorSlashed {
+            aggregator: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// An expired batch was pruned; `pruner` got `reward` of its deposit
+        /// and the aggregator the rest
+        BatchPruned {
+            batch_id: u64,
+            pruner: Option<T::AccountId>,
+            reward: BalanceOf<T>,
+        },
+        /// Root-only batches were switched on or off
+        RootOnlyBatchesSet {
+            enabled: bool,
+        },
+        /// An aggregator committed to its server key, or cleared it
+        ServerKeyHashSet {
+            aggregator: T::AccountId,
+            key_hash: Option<[u8; 32]>,
+        },
+        /// A request of a root-only batch was marked verified from its
+        /// inclusion proof
+        RequestVerificationClaimed {
+            request_id: u64,
+            batch_id: u64,
+        },
+        /// An aggregate proof verified `batch_ids`, each also reported with
+        /// `ProofVerified`
+        AggregateProofVerified {
+            aggregator: T::AccountId,
+            batch_ids: alloc::vec::Vec<u64>,
+            signature_count: u32,
+        },
+    }
+
     // ═══════════════════════════════════════════════════════════════════════
     // ERRORS
     // ═══════════════════════════════════════════════════════════════════════
@@ -233,6 +1235,100 @@ pub mod pallet {
         InvalidPublicValues,
         ProofAlreadyUsed,
         InvalidMerkleRoot,
+        /// Proof was generated for a different `ProofEpoch`
+        StaleProofEpoch,
+        /// Anchor block is not a block of this chain within `MaxAnchorAge`
+        InvalidAnchor,
+        /// Block already holds `MAX_BATCHES_PER_BLOCK` verified batches
+        TooManyBatchesInBlock,
+        /// Fewer approving aggregators than `CoSignThreshold`
+        InsufficientCoSignatures,
+        /// Co-signer is not an active aggregator or its signature is invalid
+        InvalidCoSignature,
+        /// Co-signer listed twice, or the submitter co-signing its own proof
+        DuplicateCoSigner,
+        /// Threshold exceeds `MaxAggregators`
+        CoSignThresholdTooHigh,
+        /// Proof submission is paused
+        VerifierPaused,
+        /// Batch ID was claimed by another aggregator
+        BatchIdClaimedByOther,
+        /// Batch ID must be claimed with `claim_batch_id` first
+        BatchIdNotClaimed,
+        /// No verified batch with this ID
+        BatchNotFound,
+        /// Tip amount is zero
+        ZeroTip,
+        /// Vault accounts can't tip, as tips leave without a Dilithium signature
+        VaultCannotTip,
+        /// Nothing to claim
+        NothingToClaim,
+        /// Reward pot holds less than the claimable amount
+        InsufficientRewardPot,
+        /// Verifying key has points off the curve or the wrong number of inputs
+        InvalidVerifyingKey,
+        /// Aggregator already had `MaxBatchesPerEra` batches verified this era
+        EraBatchLimitReached,
+        /// Request does not belong to a verified batch
+        RequestNotVerified,
+        /// Request was already consumed
+        RequestAlreadyConsumed,
+        /// Bonded total would be below `AggregatorBond`
+        BondTooLow,
+        /// No bonded stake
+        NotBonded,
+        /// Stake is unbonding; withdraw it before bonding again
+        UnbondingInProgress,
+        /// No unbonded stake
+        NothingToWithdraw,
+        /// `UnbondingPeriod` has not passed yet
+        StakeStillLocked,
+        /// Reported proof is not the one the batch was verified with
+        ProofMismatch,
+        /// Reported proof verifies against the Groth16 verifying key
+        ProofIsValid,
+        /// Invalid proofs can only be reported with a Groth16 verifying key set
+        NoVerifyingKey,
+        /// Batch was already reported invalid
+        BatchAlreadyReported,
+        /// Batch is younger than `ProofRetentionPeriod`
+        BatchNotExpired,
+        /// Request is already marked verified
+        RequestAlreadyVerified,
+        /// Inclusion proof does not lead to the batch's blake2 requests root
+        InvalidInclusionProof,
+        /// Batch was proved by a guest program other than Re-ML
+        NotARemlBatch,
+        /// Request was signed by another key, or its batch committed no owner
+        NotRequestOwner,
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // HOOKS
+    // ═══════════════════════════════════════════════════════════════════════
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+            // Accounts for the digest flush in on_finalize
+            T::WeightInfo::on_finalize_digest()
+        }
+
+        fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            let used = Self::mark_pending_requests(remaining_weight);
+            used.saturating_add(Self::prune_expired_batches(remaining_weight.saturating_sub(used)))
+        }
+
+        fn on_finalize(_n: BlockNumberFor<T>) {
+            let batches = BlockBatches::<T>::take();
+            if batches.is_empty() {
+                return;
+            }
+
+            let digest =
+                BatchDigest { version: BATCH_DIGEST_VERSION, batches: batches.into_inner() };
+            frame_system::Pallet::<T>::deposit_log(digest.to_digest_item());
+        }
     }
 
     // ═══════════════════════════════════════════════════════════════════════
@@ -241,14 +1337,14 @@ pub mod pallet {
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
-        /// Register a new aggregator (sudo only)
+        /// Register a new aggregator (`AdminOrigin` only)
         #[pallet::call_index(0)]
         #[pallet::weight(T::WeightInfo::register_aggregator())]
         pub fn register_aggregator(
             origin: OriginFor<T>,
             aggregator: T::AccountId,
         ) -> DispatchResult {
-            ensure_root(origin)?;
+            T::AdminOrigin::ensure_origin(origin)?;
 
             ensure!(
                 !Aggregators::<T>::contains_key(&aggregator),
@@ -263,6 +1359,8 @@ pub mod pallet {
                     registered_at: current_block,
                     proofs_submitted: 0,
                     active: true,
+                    reputation: Reputation::default(),
+                    server_key_hash: None,
                 },
             );
 
@@ -277,7 +1375,7 @@ pub mod pallet {
             origin: OriginFor<T>,
             aggregator: T::AccountId,
         ) -> DispatchResult {
-            ensure_root(origin)?;
+            T::AdminOrigin::ensure_origin(origin)?;
 
             Aggregators::<T>::try_mutate(&aggregator, |maybe_info| -> DispatchResult {
                 let info = maybe_info.as_mut().ok_or(Error::<T>::AggregatorNotFound)?;
@@ -290,21 +1388,576 @@ pub mod pallet {
         }
 
         /// Submit and verify a STARK proof
+        ///
+        /// Fails with `InsufficientCoSignatures` once `CoSignThreshold` is
+        /// above 1; use `submit_cosigned_proof` then.
         #[pallet::call_index(2)]
-        #[pallet::weight(T::WeightInfo::submit_proof(submission.public_values.verified_request_ids.len() as u32))]
-        pub fn submit_proof(origin: OriginFor<T>, submission: ProofSubmission) -> DispatchResult {
+        #[pallet::weight(Self::submit_proof_weight(submission))]
+        pub fn submit_proof(
+            origin: OriginFor<T>,
+            submission: ProofSubmission,
+        ) -> DispatchResultWithPostInfo {
+            let aggregator = ensure_signed(origin)?;
+            let actual_weight = Self::root_only_submit_weight(&submission);
+            Self::do_submit_proof(aggregator, submission, &[])?;
+            Ok(actual_weight.into())
+        }
+
+        /// Set how many aggregators must approve each proof (`AdminOrigin` only)
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::set_cosign_threshold())]
+        pub fn set_cosign_threshold(origin: OriginFor<T>, threshold: u32) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            ensure!(threshold <= T::MaxAggregators::get(), Error::<T>::CoSignThresholdTooHigh);
+
+            CoSignThreshold::<T>::put(threshold);
+            Self::deposit_event(Event::CoSignThresholdSet { threshold });
+            Ok(())
+        }
+
+        /// Submit a proof together with other aggregators' co-signatures
+        ///
+        /// Each co-signer signs [`Pallet::cosign_payload`] of `submission`.
+        #[pallet::call_index(4)]
+        #[pallet::weight(
+            Self::submit_proof_weight(submission)
+                .saturating_add(T::WeightInfo::verify_co_signatures(co_signatures.len() as u32))
+        )]
+        pub fn submit_cosigned_proof(
+            origin: OriginFor<T>,
+            submission: ProofSubmission,
+            co_signatures: BoundedVec<(T::AccountId, T::CoSignature), T::MaxAggregators>,
+        ) -> DispatchResultWithPostInfo {
             let aggregator = ensure_signed(origin)?;
+            let actual_weight = Self::root_only_submit_weight(&submission).map(|weight| {
+                weight
+                    .saturating_add(T::WeightInfo::verify_co_signatures(co_signatures.len() as u32))
+            });
+            Self::do_submit_proof(aggregator, submission, &co_signatures)?;
+            Ok(actual_weight.into())
+        }
+
+        /// Pause or resume proof submission (`AdminOrigin` only)
+        ///
+        /// While paused, `submit_proof`, `submit_cosigned_proof` and
+        /// `submit_single_proof` fail with `VerifierPaused` and are rejected at
+        /// transaction pool admission.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::set_paused())]
+        pub fn set_paused(origin: OriginFor<T>, paused: bool) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            VerifierPaused::<T>::put(paused);
+            Self::deposit_event(Event::VerifierPausedSet { paused });
+            Ok(())
+        }
+
+        /// Reserve the next batch ID for the caller's proof (active aggregators only)
+        ///
+        /// IDs are `block_number << 32 | n` for the n-th claim in a block, so
+        /// they never collide and only the claimant can submit a proof for
+        /// one. The ID is in the `BatchIdClaimed` event.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::claim_batch_id())]
+        pub fn claim_batch_id(origin: OriginFor<T>) -> DispatchResult {
+            let aggregator = ensure_signed(origin)?;
+            ensure!(Self::is_aggregator(&aggregator), Error::<T>::NotAuthorized);
+
+            let current_block = frame_system::Pallet::<T>::block_number();
+            let (block, claims) = BlockClaims::<T>::get();
+            let index = if block == current_block { claims } else { 0 };
+
+            let block_num: u32 = current_block.try_into().unwrap_or(u32::MAX);
+            let batch_id = ((block_num as u64) << 32) | index as u64;
+            ensure!(
+                !VerifiedBatches::<T>::contains_key(batch_id)
+                    && !PrunedBatches::<T>::contains_key(batch_id),
+                Error::<T>::BatchAlreadyVerified
+            );
+
+            BlockClaims::<T>::put((current_block, index.saturating_add(1)));
+            BatchIdClaims::<T>::insert(batch_id, &aggregator);
+            Self::deposit_event(Event::BatchIdClaimed { batch_id, aggregator });
+            Ok(())
+        }
+
+        /// Require proofs to use claimed batch IDs (`AdminOrigin` only)
+        ///
+        /// Once required, a batch ID nobody claimed can no longer be submitted,
+        /// so it cannot be squatted before its honest aggregator submits.
+        /// Claimed IDs are reserved for their claimant either way.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::set_require_claimed_batch_ids())]
+        pub fn set_require_claimed_batch_ids(
+            origin: OriginFor<T>,
+            required: bool,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            RequireClaimedBatchIds::<T>::put(required);
+            Self::deposit_event(Event::ClaimedBatchIdsRequired { required });
+            Ok(())
+        }
+
+        /// Tip the aggregator of a verified batch
+        ///
+        /// The tip is paid into the reward pot and credited to the
+        /// aggregator's ledger, claimable with `claim_earnings`. Vault accounts
+        /// can't tip.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::tip_batch())]
+        pub fn tip_batch(
+            origin: OriginFor<T>,
+            batch_id: u64,
+            #[pallet::compact] amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let tipper = ensure_signed(origin)?;
+            ensure!(!amount.is_zero(), Error::<T>::ZeroTip);
+            ensure!(!T::VaultCheck::contains(&tipper), Error::<T>::VaultCannotTip);
+            let batch = VerifiedBatches::<T>::get(batch_id).ok_or(Error::<T>::BatchNotFound)?;
+
+            T::RewardCurrency::transfer(
+                &tipper,
+                &Self::reward_pot(),
+                amount,
+                ExistenceRequirement::KeepAlive,
+            )?;
+
+            BatchLedger::<T>::mutate(batch_id, |earnings| {
+                earnings.tips = earnings.tips.saturating_add(amount)
+            });
+            AggregatorLedger::<T>::mutate(&batch.aggregator, |earnings| {
+                earnings.tips = earnings.tips.saturating_add(amount);
+                earnings.claimable = earnings.claimable.saturating_add(amount);
+            });
+
+            Self::deposit_event(Event::BatchTipped {
+                batch_id,
+                tipper,
+                aggregator: batch.aggregator,
+                amount,
+            });
+            Ok(())
+        }
+
+        /// Pay out the caller's claimable rewards and tips from the reward pot
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::claim_earnings())]
+        pub fn claim_earnings(origin: OriginFor<T>) -> DispatchResult {
+            let aggregator = ensure_signed(origin)?;
+            let mut earnings = AggregatorLedger::<T>::get(&aggregator);
+            let amount = earnings.claimable;
+            ensure!(!amount.is_zero(), Error::<T>::NothingToClaim);
+
+            let pot = Self::reward_pot();
+            ensure!(
+                T::RewardCurrency::free_balance(&pot) >= amount,
+                Error::<T>::InsufficientRewardPot
+            );
+            T::RewardCurrency::transfer(
+                &pot,
+                &aggregator,
+                amount,
+                ExistenceRequirement::AllowDeath,
+            )?;
+
+            earnings.claimable = Zero::zero();
+            earnings.claimed = earnings.claimed.saturating_add(amount);
+            AggregatorLedger::<T>::insert(&aggregator, earnings);
+
+            Self::deposit_event(Event::EarningsClaimed { aggregator, amount });
+            Ok(())
+        }
+
+        /// Set or clear the SP1 Groth16 verifying key (`AdminOrigin` only)
+        ///
+        /// With a key, proofs must be SP1 Groth16 proofs that verify against
+        /// it; without one, they only get structural checks.
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::set_groth16_vk())]
+        pub fn set_groth16_vk(
+            origin: OriginFor<T>,
+            vk: Option<groth16::VerifyingKey>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            if let Some(vk) = &vk {
+                ensure!(
+                    vk.public_inputs() == groth16::SP1_PUBLIC_INPUTS && vk.is_well_formed(),
+                    Error::<T>::InvalidVerifyingKey
+                );
+            }
+
+            let selector = vk.as_ref().map(|vk| vk.selector);
+            Groth16Vk::<T>::set(vk);
+            Self::deposit_event(Event::Groth16VerifyingKeySet { selector });
+            Ok(())
+        }
+
+        /// Submit and verify the proof of a single-request batch
+        ///
+        /// Same checks and effects as `submit_proof` for a batch of exactly
+        /// one request, with the public values implied by `request_id`. Meant
+        /// for large, urgent vault withdrawals that cannot wait for a batch.
+        #[pallet::call_index(11)]
+        #[pallet::weight(Self::submit_single_proof_weight())]
+        pub fn submit_single_proof(
+            origin: OriginFor<T>,
+            submission: SingleProofSubmission,
+        ) -> DispatchResult {
+            let aggregator = ensure_signed(origin)?;
+            Self::do_submit_proof(aggregator, Self::single_proof_submission(submission), &[])
+        }
+
+        /// Bond stake as an aggregator, registering the caller if needed
+        ///
+        /// The bonded total must reach `AggregatorBond`. New aggregators are
+        /// registered active; deactivated or slashed ones stay inactive.
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::bond_aggregator())]
+        pub fn bond_aggregator(
+            origin: OriginFor<T>,
+            #[pallet::compact] amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let aggregator = ensure_signed(origin)?;
+            ensure!(
+                !AggregatorUnbonding::<T>::contains_key(&aggregator),
+                Error::<T>::UnbondingInProgress
+            );
+
+            let total = AggregatorStakes::<T>::get(&aggregator).saturating_add(amount);
+            ensure!(total >= T::AggregatorBond::get(), Error::<T>::BondTooLow);
+            T::RewardCurrency::reserve(&aggregator, amount)?;
+            AggregatorStakes::<T>::insert(&aggregator, total);
+
+            let registered = !Aggregators::<T>::contains_key(&aggregator);
+            if registered {
+                Aggregators::<T>::insert(
+                    &aggregator,
+                    AggregatorInfo {
+                        registered_at: frame_system::Pallet::<T>::block_number(),
+                        proofs_submitted: 0,
+                        active: true,
+                        reputation: Reputation::default(),
+                        server_key_hash: None,
+                    },
+                );
+            }
+
+            Self::deposit_event(Event::AggregatorBonded {
+                aggregator: aggregator.clone(),
+                amount,
+                total,
+            });
+            if registered {
+                Self::deposit_event(Event::AggregatorRegistered { aggregator });
+            }
+            Ok(())
+        }
+
+        /// Deactivate the caller and start unbonding its whole stake
+        ///
+        /// The stake stays reserved, and slashable, for `UnbondingPeriod`.
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::unbond_aggregator())]
+        pub fn unbond_aggregator(origin: OriginFor<T>) -> DispatchResult {
+            let aggregator = ensure_signed(origin)?;
+            ensure!(
+                !AggregatorUnbonding::<T>::contains_key(&aggregator),
+                Error::<T>::UnbondingInProgress
+            );
+            let amount = AggregatorStakes::<T>::take(&aggregator);
+            ensure!(!amount.is_zero(), Error::<T>::NotBonded);
+
+            let unlock_at =
+                frame_system::Pallet::<T>::block_number().saturating_add(T::UnbondingPeriod::get());
+            AggregatorUnbonding::<T>::insert(&aggregator, (amount, unlock_at));
+            Aggregators::<T>::mutate(&aggregator, |info| {
+                if let Some(info) = info {
+                    info.active = false;
+                }
+            });
+
+            Self::deposit_event(Event::AggregatorUnbonding { aggregator, amount, unlock_at });
+            Ok(())
+        }
+
+        /// Release the caller's unbonded stake once `UnbondingPeriod` is over
+        ///
+        /// Also deregisters the caller unless it was slashed.
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::withdraw_unbonded())]
+        pub fn withdraw_unbonded(origin: OriginFor<T>) -> DispatchResult {
+            let aggregator = ensure_signed(origin)?;
+            let (amount, unlock_at) =
+                AggregatorUnbonding::<T>::get(&aggregator).ok_or(Error::<T>::NothingToWithdraw)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() >= unlock_at,
+                Error::<T>::StakeStillLocked
+            );
+
+            AggregatorUnbonding::<T>::remove(&aggregator);
+            T::RewardCurrency::unreserve(&aggregator, amount);
+            if !SlashedAggregators::<T>::contains_key(&aggregator) {
+                Aggregators::<T>::remove(&aggregator);
+            }
+
+            Self::deposit_event(Event::StakeWithdrawn { aggregator, amount });
+            Ok(())
+        }
+
+        /// Prove that a verified batch's proof does not verify, slashing its
+        /// aggregator's whole stake
+        ///
+        /// `submission` must be the proof the batch was verified with. Needs
+        /// a `Groth16Vk`: batches verified without one only got structural
+        /// checks, and are reported by checking them against the key.
+        #[pallet::call_index(15)]
+        #[pallet::weight(
+            T::WeightInfo::report_invalid_proof(
+                submission.public_values.verified_request_ids.len() as u32
+            )
+            .saturating_add(T::WeightInfo::verify_groth16_proof())
+        )]
+        pub fn report_invalid_proof(
+            origin: OriginFor<T>,
+            submission: ProofSubmission,
+        ) -> DispatchResult {
+            let reporter = ensure_signed(origin)?;
+            let batch_id = submission.batch_id;
+            let batch = VerifiedBatches::<T>::get(batch_id).ok_or(Error::<T>::BatchNotFound)?;
+            ensure!(!InvalidBatches::<T>::contains_key(batch_id), Error::<T>::BatchAlreadyReported);
+
+            // The commitment binds the vkey, epoch, batch ID, root and proof;
+            // the remaining public values must be those `submit_proof` accepted
+            let public_values = &submission.public_values;
+            ensure!(
+                Self::compute_proof_commitment(&submission) == batch.proof_commitment
+                    && public_values.version == REML_VERSION
+                    && public_values.chain_id == TESSERAX_CHAIN_ID
+                    && public_values.batch_id == batch_id
+                    && public_values.verified_count == batch.signature_count
+                    && public_values.verified_count as usize
+                        == public_values.verified_request_ids.len()
+                    && Self::compute_merkle_root(&public_values.verified_request_ids)
+                        == batch.requests_root
+                    && BatchRootsBlake2::<T>::get(batch_id).is_none_or(|root| {
+                        Self::compute_blake2_root(&public_values.verified_request_ids) == root
+                    })
+                    && BatchOwnersHashes::<T>::get(batch_id)
+                        .is_none_or(|owners_hash| public_values.owners_hash() == owners_hash)
+                    && BatchMessagesHashes::<T>::get(batch_id).is_none_or(|messages_hash| {
+                        public_values.messages_hash() == messages_hash
+                    }),
+                Error::<T>::ProofMismatch
+            );
+
+            let vk = Groth16Vk::<T>::get().ok_or(Error::<T>::NoVerifyingKey)?;
+            ensure!(
+                !Self::verify_groth16_proof(
+                    &vk,
+                    &submission.proof,
+                    public_values,
+                    &submission.vkey_hash
+                ),
+                Error::<T>::ProofIsValid
+            );
+
+            InvalidBatches::<T>::insert(batch_id, frame_system::Pallet::<T>::block_number());
+            Self::deposit_event(Event::InvalidProofReported {
+                batch_id,
+                aggregator: batch.aggregator.clone(),
+                reporter,
+            });
+            Self::slash(&batch.aggregator, Self::total_stake(&batch.aggregator));
+            Ok(())
+        }
+
+        /// Slash up to `amount` of an aggregator's stake and deactivate it
+        /// (`AdminOrigin` only)
+        ///
+        /// Bonded stake is slashed before unbonding stake.
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::slash_aggregator())]
+        pub fn slash_aggregator(
+            origin: OriginFor<T>,
+            aggregator: T::AccountId,
+            #[pallet::compact] amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            ensure!(Aggregators::<T>::contains_key(&aggregator), Error::<T>::AggregatorNotFound);
+
+            Self::slash(&aggregator, amount);
+            Ok(())
+        }
+
+        /// Prune expired batches, paying the caller `PrunerShare` of their
+        /// submission deposits
+        ///
+        /// A batch expires `ProofRetentionPeriod` blocks after it was
+        /// verified; its requests then stop counting as verified. `on_idle`
+        /// prunes expired batches as well, refunding the whole deposit.
+        #[pallet::call_index(17)]
+        #[pallet::weight(
+            T::WeightInfo::prune_batch(MAX_VERIFIED_REQUESTS)
+                .saturating_mul(batch_ids.len() as u64)
+        )]
+        pub fn prune_expired(
+            origin: OriginFor<T>,
+            batch_ids: BoundedVec<u64, ConstU32<MAX_PRUNE_BATCHES>>,
+        ) -> DispatchResult {
+            let pruner = ensure_signed(origin)?;
+            let now = frame_system::Pallet::<T>::block_number();
+
+            for batch_id in batch_ids {
+                let batch = VerifiedBatches::<T>::get(batch_id).ok_or(Error::<T>::BatchNotFound)?;
+                ensure!(Self::is_expired(&batch, now), Error::<T>::BatchNotExpired);
+                Self::prune_batch(batch_id, batch, Some(&pruner));
+            }
+            Ok(())
+        }
+
+        /// Mark one request of a verified batch verified from a Merkle
+        /// inclusion proof against the batch's blake2 requests root
+        ///
+        /// For batches submitted while `RootOnlyBatches` is on, whose requests
+        /// are not marked on submission. Anyone can claim. The root commits
+        /// no owners, so a claimed request can't be consumed.
+        #[pallet::call_index(18)]
+        #[pallet::weight(T::WeightInfo::claim_request_verification(proof.siblings.len() as u32))]
+        pub fn claim_request_verification(
+            origin: OriginFor<T>,
+            request_id: u64,
+            proof: RequestInclusionProof,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+            ensure!(
+                !VerifiedRequests::<T>::contains_key(request_id),
+                Error::<T>::RequestAlreadyVerified
+            );
+            ensure!(
+                !ConsumedRequests::<T>::contains_key(request_id),
+                Error::<T>::RequestAlreadyConsumed
+            );
+
+            let batch_id = proof.batch_id;
+            let batch = VerifiedBatches::<T>::get(batch_id).ok_or(Error::<T>::BatchNotFound)?;
+            let root = BatchRootsBlake2::<T>::get(batch_id).ok_or(Error::<T>::BatchNotFound)?;
+            ensure!(!BatchPrograms::<T>::contains_key(batch_id), Error::<T>::NotARemlBatch);
+            ensure!(
+                proof.leaf_count <= MAX_VERIFIED_REQUESTS
+                    && tesserax_merkle::verify_blake2_request(
+                        request_id,
+                        proof.leaf_index as usize,
+                        proof.leaf_count as usize,
+                        &proof.siblings,
+                        &root,
+                    ),
+                Error::<T>::InvalidInclusionProof
+            );
+
+            // Recorded so pruning the batch unmarks the request again
+            BatchRequests::<T>::try_append(batch_id, request_id)
+                .map_err(|_| Error::<T>::InvalidInclusionProof)?;
+            VerifiedRequests::<T>::insert(request_id, (batch_id, batch.verified_at));
+
+            Self::deposit_event(Event::RequestVerificationClaimed { request_id, batch_id });
+            Ok(())
+        }
+
+        /// Store only the requests root of batches verified from now on
+        /// (`AdminOrigin` only)
+        ///
+        /// Skips the per-request writes of `submit_proof`, which then refunds
+        /// their weight; requests are marked with
+        /// `claim_request_verification` instead.
+        #[pallet::call_index(19)]
+        #[pallet::weight(T::WeightInfo::set_root_only_batches())]
+        pub fn set_root_only_batches(origin: OriginFor<T>, enabled: bool) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            RootOnlyBatches::<T>::put(enabled);
+            Self::deposit_event(Event::RootOnlyBatchesSet { enabled });
+            Ok(())
+        }
+
+        /// Commit to the SHA-256 of the caller's server key, or clear it
+        /// (registered aggregators only)
+        ///
+        /// See `AggregatorInfo::server_key_hash`. Set it again after rotating
+        /// the key or renewing the certificate.
+        #[pallet::call_index(20)]
+        #[pallet::weight(T::WeightInfo::set_server_key_hash())]
+        pub fn set_server_key_hash(
+            origin: OriginFor<T>,
+            key_hash: Option<[u8; 32]>,
+        ) -> DispatchResult {
+            let aggregator = ensure_signed(origin)?;
+            Aggregators::<T>::try_mutate(&aggregator, |maybe_info| -> DispatchResult {
+                let info = maybe_info.as_mut().ok_or(Error::<T>::AggregatorNotFound)?;
+                info.server_key_hash = key_hash;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::ServerKeyHashSet { aggregator, key_hash });
+            Ok(())
+        }
+
+        /// Submit one aggregation guest proof covering several Re-ML batches
+        ///
+        /// Each sub-batch is checked like a `submit_proof` batch (batch ID,
+        /// counts, era cap) and recorded root-only, its requests marked with
+        /// `claim_request_verification`. The sub-batch proofs must be of the
+        /// Re-ML vkey `submit_proof` accepts. Fails with
+        /// `InsufficientCoSignatures` once `CoSignThreshold` is above 1.
+        #[pallet::call_index(21)]
+        #[pallet::weight(Self::submit_aggregate_proof_weight(submission))]
+        pub fn submit_aggregate_proof(
+            origin: OriginFor<T>,
+            submission: AggregateProofSubmission,
+        ) -> DispatchResult {
+            let aggregator = ensure_signed(origin)?;
+            Self::do_submit_aggregate_proof(aggregator, submission)
+        }
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // HELPER FUNCTIONS
+    // ═══════════════════════════════════════════════════════════════════════
+
+    impl<T: Config> Pallet<T> {
+        /// Shared body of `submit_proof` and `submit_cosigned_proof`
+        fn do_submit_proof(
+            aggregator: T::AccountId,
+            submission: ProofSubmission,
+            co_signatures: &[(T::AccountId, T::CoSignature)],
+        ) -> DispatchResult {
+            let _span = sp_tracing::span!(
+                target: TRACE_TARGET,
+                sp_tracing::Level::DEBUG,
+                "submit_proof",
+                batch_id = submission.batch_id,
+                requests = submission.public_values.verified_request_ids.len(),
+                co_signatures = co_signatures.len()
+            )
+            .entered();
+            ensure!(!VerifierPaused::<T>::get(), Error::<T>::VerifierPaused);
 
             // Check authorization
             let mut aggregator_info =
                 Aggregators::<T>::get(&aggregator).ok_or(Error::<T>::NotAuthorized)?;
             ensure!(aggregator_info.active, Error::<T>::NotAuthorized);
 
-            // Validate batch not already verified
-            ensure!(
-                !VerifiedBatches::<T>::contains_key(submission.batch_id),
-                Error::<T>::BatchAlreadyVerified
-            );
+            // Per-era cap, checked before any expensive verification
+            let current_block = frame_system::Pallet::<T>::block_number();
+            let era = Self::era_of(current_block);
+            let era_batches = Self::era_batches(&aggregator, era);
+            ensure!(era_batches < T::MaxBatchesPerEra::get(), Error::<T>::EraBatchLimitReached);
+
+            Self::check_batch_id(&aggregator, submission.batch_id)?;
+
+            // Bound to a recent block of this chain, so a submission taken
+            // from a fork or held back for long is not accepted
+            Self::check_anchor(submission.anchor_block, &submission.anchor_hash, current_block)?;
 
             // Validate public values
             ensure!(
@@ -319,113 +1972,973 @@ pub mod pallet {
                 submission.public_values.batch_id == submission.batch_id,
                 Error::<T>::InvalidPublicValues
             );
+            // One owner and message per request, or none for programs that
+            // commit none
+            let requests = submission.public_values.verified_request_ids.len();
+            let owners = submission.public_values.verified_request_owners.len();
+            let messages = submission.public_values.verified_request_messages.len();
+            ensure!(
+                (owners == 0 || owners == requests) && (messages == 0 || messages == requests),
+                Error::<T>::InvalidPublicValues
+            );
+            ensure!(
+                submission.public_values.epoch == T::ProofEpoch::get(),
+                Error::<T>::StaleProofEpoch
+            );
 
             // Verify VKey hash
-            let expected_vkey = T::ExpectedVKeyHash::get();
-            if expected_vkey != [0u8; 32] {
+            // An unpinned (all-zero) Re-ML hash is only tolerated by
+            // `lenient-testnet` builds
+            if submission.program_id == REML_PROGRAM_ID {
                 ensure!(
-                    submission.vkey_hash == expected_vkey,
+                    Self::accepts_reml_vkey(&submission.vkey_hash),
                     Error::<T>::InvalidVKeyHash
                 );
+            } else {
+                ensure!(
+                    T::ProgramVKeys::accepts(submission.program_id, &submission.vkey_hash),
+                    Error::<T>::InvalidVKeyHash
+                );
+            }
+
+            // Compute proof commitment for replay prevention
+            let proof_commitment = Self::compute_proof_commitment(&submission);
+            let commitment_hash = H256::from_slice(&proof_commitment);
+
+            ensure!(
+                !ProofCommitments::<T>::contains_key(commitment_hash),
+                Error::<T>::ProofAlreadyUsed
+            );
+
+            // k-of-n approval: the submitter plus valid co-signatures
+            let co_signers =
+                Self::verify_co_signatures(&aggregator, &proof_commitment, co_signatures)?;
+            ensure!(
+                (co_signers.len() as u32).saturating_add(1) >= CoSignThreshold::<T>::get(),
+                Error::<T>::InsufficientCoSignatures
+            );
+
+            // Verify both merkle roots match claimed request IDs
+            let ids = &submission.public_values.verified_request_ids;
+            ensure!(
+                Self::compute_merkle_root(ids) == submission.public_values.requests_root
+                    && Self::compute_blake2_root(ids)
+                        == submission.public_values.requests_root_blake2,
+                Error::<T>::InvalidMerkleRoot
+            );
+
+            // ═══════════════════════════════════════════════════════════════
+            // STARK PROOF VERIFICATION
+            // ═══════════════════════════════════════════════════════════════
+
+            let proof_valid = Self::verify_sp1_proof(
+                &submission.proof,
+                &submission.public_values,
+                &submission.vkey_hash,
+            );
+
+            if !proof_valid {
+                Self::deposit_event(Event::ProofRejected {
+                    batch_id: submission.batch_id,
+                    aggregator: aggregator.clone(),
+                    reason: RejectReason::StarkVerificationFailed,
+                });
+                return Err(Error::<T>::ProofVerificationFailed.into());
+            }
+
+            // ═══════════════════════════════════════════════════════════════
+            // UPDATE STORAGE
+            // ═══════════════════════════════════════════════════════════════
+
+            let root_only = submission.program_id == REML_PROGRAM_ID
+                && Self::is_root_only(submission.public_values.verified_request_ids.len());
+            let reward = Self::record_batch(
+                &aggregator,
+                submission.program_id,
+                &submission.public_values,
+                root_only,
+                proof_commitment,
+                current_block,
+            )?;
+
+            // Update stats
+            aggregator_info.proofs_submitted += 1;
+            Aggregators::<T>::insert(&aggregator, aggregator_info);
+            AggregatorEraBatches::<T>::insert(&aggregator, (era, era_batches + 1));
+            TotalProofsVerified::<T>::mutate(|n| *n += 1);
+
+            Self::deposit_event(Event::ProofVerified {
+                batch_id: submission.batch_id,
+                aggregator: aggregator.clone(),
+                signature_count: submission.public_values.verified_count,
+                block_number: current_block,
+            });
+            if !co_signers.is_empty() {
+                Self::deposit_event(Event::ProofCoSigned {
+                    batch_id: submission.batch_id,
+                    co_signers,
+                });
+            }
+            if !reward.is_zero() {
+                Self::deposit_event(Event::BatchRewarded {
+                    batch_id: submission.batch_id,
+                    aggregator,
+                    reward,
+                });
+            }
+
+            Ok(())
+        }
+
+        /// Body of `submit_aggregate_proof`
+        fn do_submit_aggregate_proof(
+            aggregator: T::AccountId,
+            submission: AggregateProofSubmission,
+        ) -> DispatchResult {
+            let values = &submission.public_values;
+            let _span = sp_tracing::span!(
+                target: TRACE_TARGET,
+                sp_tracing::Level::DEBUG,
+                "submit_aggregate_proof",
+                batches = values.sub_batches.len()
+            )
+            .entered();
+            ensure!(!VerifierPaused::<T>::get(), Error::<T>::VerifierPaused);
+
+            let mut aggregator_info =
+                Aggregators::<T>::get(&aggregator).ok_or(Error::<T>::NotAuthorized)?;
+            ensure!(aggregator_info.active, Error::<T>::NotAuthorized);
+
+            // Every sub-batch counts against the per-era cap
+            let current_block = frame_system::Pallet::<T>::block_number();
+            let era = Self::era_of(current_block);
+            let era_batches = Self::era_batches(&aggregator, era);
+            let batches = values.sub_batches.len() as u32;
+            ensure!(
+                era_batches.saturating_add(batches) <= T::MaxBatchesPerEra::get(),
+                Error::<T>::EraBatchLimitReached
+            );
+
+            ensure!(!values.sub_batches.is_empty(), Error::<T>::InvalidPublicValues);
+            for (index, batch) in values.sub_batches.iter().enumerate() {
+                let earlier = &values.sub_batches[..index];
+                ensure!(
+                    earlier.iter().all(|other| other.batch_id != batch.batch_id),
+                    Error::<T>::InvalidPublicValues
+                );
+                Self::check_batch_id(&aggregator, batch.batch_id)?;
+            }
+
+            Self::check_anchor(submission.anchor_block, &submission.anchor_hash, current_block)?;
+
+            ensure!(
+                values.version == REML_VERSION && values.chain_id == TESSERAX_CHAIN_ID,
+                Error::<T>::InvalidPublicValues
+            );
+            ensure!(values.epoch == T::ProofEpoch::get(), Error::<T>::StaleProofEpoch);
+            ensure!(Self::aggregate_counts_add_up(values), Error::<T>::InvalidPublicValues);
+
+            // The aggregation guest, folding proofs of the Re-ML guest
+            ensure!(
+                T::ProgramVKeys::accepts(REML_AGGREGATION_PROGRAM_ID, &submission.vkey_hash)
+                    && Self::accepts_reml_vkey(&values.batch_vkey_hash),
+                Error::<T>::InvalidVKeyHash
+            );
+
+            let commitments: alloc::vec::Vec<[u8; 32]> = values
+                .sub_batches
+                .iter()
+                .map(|batch| Self::compute_aggregate_commitment(&submission, batch))
+                .collect();
+            for commitment in &commitments {
+                ensure!(
+                    !ProofCommitments::<T>::contains_key(H256::from_slice(commitment)),
+                    Error::<T>::ProofAlreadyUsed
+                );
+            }
+            ensure!(CoSignThreshold::<T>::get() <= 1, Error::<T>::InsufficientCoSignatures);
+
+            let proof_valid = match Groth16Vk::<T>::get() {
+                Some(vk) => {
+                    let inputs =
+                        groth16::sp1_public_inputs(&submission.vkey_hash, &values.abi_encode());
+                    groth16::verify(&vk, &submission.proof, &inputs)
+                },
+                None => Self::verify_proof_structure(
+                    &submission.proof,
+                    &values.binding_hash(),
+                    &submission.vkey_hash,
+                ),
+            };
+            ensure!(proof_valid, Error::<T>::ProofVerificationFailed);
+
+            let mut batch_ids = alloc::vec::Vec::with_capacity(values.sub_batches.len());
+            for (batch, commitment) in values.sub_batches.iter().zip(commitments) {
+                let reward = Self::record_batch(
+                    &aggregator,
+                    REML_PROGRAM_ID,
+                    &values.sub_batch_values(batch),
+                    true,
+                    commitment,
+                    current_block,
+                )?;
+                Self::deposit_event(Event::ProofVerified {
+                    batch_id: batch.batch_id,
+                    aggregator: aggregator.clone(),
+                    signature_count: batch.verified_count,
+                    block_number: current_block,
+                });
+                if !reward.is_zero() {
+                    Self::deposit_event(Event::BatchRewarded {
+                        batch_id: batch.batch_id,
+                        aggregator: aggregator.clone(),
+                        reward,
+                    });
+                }
+                batch_ids.push(batch.batch_id);
+            }
+
+            aggregator_info.proofs_submitted += 1;
+            Aggregators::<T>::insert(&aggregator, aggregator_info);
+            AggregatorEraBatches::<T>::insert(&aggregator, (era, era_batches + batches));
+            TotalProofsVerified::<T>::mutate(|n| *n += 1);
+
+            Self::deposit_event(Event::AggregateProofVerified {
+                aggregator,
+                batch_ids,
+                signature_count: values.verified_count,
+            });
+            Ok(())
+        }
+
+        /// Check that `aggregator` may submit a proof of `batch_id`: not
+        /// verified yet (pruned batches included), and claimed by it if
+        /// claimed at all
+        fn check_batch_id(aggregator: &T::AccountId, batch_id: u64) -> DispatchResult {
+            ensure!(
+                !VerifiedBatches::<T>::contains_key(batch_id)
+                    && !PrunedBatches::<T>::contains_key(batch_id),
+                Error::<T>::BatchAlreadyVerified
+            );
+
+            // Claimed IDs belong to their claimant
+            match BatchIdClaims::<T>::get(batch_id) {
+                Some(claimant) => {
+                    ensure!(claimant == *aggregator, Error::<T>::BatchIdClaimedByOther);
+                },
+                None => {
+                    ensure!(!RequireClaimedBatchIds::<T>::get(), Error::<T>::BatchIdNotClaimed);
+                },
+            }
+            Ok(())
+        }
+
+        /// Whether Re-ML proofs may commit to `vkey_hash`
+        ///
+        /// An unpinned (all-zero) `ExpectedVKeyHash` accepts any vkey in
+        /// `lenient-testnet` builds only.
+        fn accepts_reml_vkey(vkey_hash: &[u8; 32]) -> bool {
+            let expected_vkey = T::ExpectedVKeyHash::get();
+            (cfg!(feature = "lenient-testnet") && expected_vkey == [0u8; 32])
+                || *vkey_hash == expected_vkey
+                || Some(*vkey_hash) == T::TransitionVKeyHash::get()
+        }
+
+        /// Whether every sub-batch's counts add up, and the aggregate's are
+        /// their totals
+        fn aggregate_counts_add_up(values: &AggregatePublicValues) -> bool {
+            let mut totals = (0u32, 0u32, 0u32);
+            for batch in values.sub_batches.iter() {
+                if batch.verified_count == 0
+                    || batch.verified_count > MAX_VERIFIED_REQUESTS
+                    || batch.mldsa44_count.checked_add(batch.falcon512_count)
+                        != Some(batch.verified_count)
+                {
+                    return false;
+                }
+                totals.0 = totals.0.saturating_add(batch.verified_count);
+                totals.1 = totals.1.saturating_add(batch.mldsa44_count);
+                totals.2 = totals.2.saturating_add(batch.falcon512_count);
+            }
+            totals == (values.verified_count, values.mldsa44_count, values.falcon512_count)
+        }
+
+        /// Store a batch whose proof verified, returning the reward credited
+        ///
+        /// Requests of a Re-ML batch are marked inline up to
+        /// `InlineRequestMarks` and in `on_idle` past it, unless `root_only`
+        /// leaves them to `claim_request_verification`. Other programs'
+        /// requests are all written inline.
+        fn record_batch(
+            aggregator: &T::AccountId,
+            program_id: ProgramId,
+            public_values: &PublicValues,
+            root_only: bool,
+            proof_commitment: [u8; 32],
+            current_block: BlockNumberFor<T>,
+        ) -> Result<BalanceOf<T>, DispatchError> {
+            let batch_id = public_values.batch_id;
+
+            // Held until the batch is pruned
+            let deposit = T::SubmissionDeposit::get();
+            if !deposit.is_zero() {
+                T::RewardCurrency::reserve(aggregator, deposit)?;
+                BatchDeposits::<T>::insert(batch_id, deposit);
+            }
+
+            // Queue for this block's header digest
+            BlockBatches::<T>::try_append(BatchDigestEntry {
+                batch_id,
+                requests_root: public_values.requests_root,
+                signature_count: public_values.verified_count,
+            })
+            .map_err(|_| Error::<T>::TooManyBatchesInBlock)?;
+
+            // Store proof commitment
+            ProofCommitments::<T>::insert(H256::from_slice(&proof_commitment), current_block);
+
+            // Store batch info
+            let batch_info = BatchInfo {
+                aggregator: aggregator.clone(),
+                verified_at: current_block,
+                signature_count: public_values.verified_count,
+                requests_root: public_values.requests_root,
+                proof_commitment,
+            };
+            T::OnBatchVerified::on_batch_verified(batch_id, &batch_info);
+            VerifiedBatches::<T>::insert(batch_id, batch_info);
+            LastVerifiedBatch::<T>::put(batch_id);
+            BatchIdClaims::<T>::remove(batch_id);
+            if !RootToBatch::<T>::contains_key(public_values.requests_root) {
+                RootToBatch::<T>::insert(public_values.requests_root, batch_id);
+            }
+            BatchRootsBlake2::<T>::insert(batch_id, public_values.requests_root_blake2);
+            PruneQueueBounds::<T>::mutate(|(_, tail)| {
+                PruneQueue::<T>::insert(*tail, batch_id);
+                *tail += 1;
+            });
+
+            let request_ids = &public_values.verified_request_ids;
+            let owners = &public_values.verified_request_owners;
+            let messages = &public_values.verified_request_messages;
+            if program_id != REML_PROGRAM_ID {
+                BatchPrograms::<T>::insert(batch_id, program_id);
+                BatchRequests::<T>::insert(batch_id, request_ids);
+                for request_id in request_ids {
+                    ProgramRequests::<T>::insert(program_id, request_id, (batch_id, current_block));
+                }
+            } else if !root_only {
+                BatchRequests::<T>::insert(batch_id, request_ids);
+                BatchOwnersHashes::<T>::insert(batch_id, public_values.owners_hash());
+                BatchMessagesHashes::<T>::insert(batch_id, public_values.messages_hash());
+                let inline = (T::InlineRequestMarks::get() as usize).min(request_ids.len());
+                for (index, request_id) in request_ids[..inline].iter().enumerate() {
+                    Self::mark_request(
+                        *request_id,
+                        batch_id,
+                        current_block,
+                        owners.get(index),
+                        messages.get(index),
+                    );
+                }
+                if inline < request_ids.len() {
+                    PendingRequestMarks::<T>::insert(
+                        batch_id,
+                        (current_block, BoundedVec::truncate_from(request_ids[inline..].to_vec())),
+                    );
+                    if owners.len() > inline {
+                        PendingRequestOwners::<T>::insert(
+                            batch_id,
+                            BoundedVec::truncate_from(owners[inline..].to_vec()),
+                        );
+                    }
+                    if messages.len() > inline {
+                        PendingRequestMessages::<T>::insert(
+                            batch_id,
+                            BoundedVec::truncate_from(messages[inline..].to_vec()),
+                        );
+                    }
+                }
+            }
+
+            // Credit the batch reward
+            let reward = T::BatchReward::get();
+            if !reward.is_zero() {
+                BatchLedger::<T>::insert(batch_id, BatchEarnings { reward, ..Default::default() });
+                AggregatorLedger::<T>::mutate(aggregator, |earnings| {
+                    earnings.rewards = earnings.rewards.saturating_add(reward);
+                    earnings.claimable = earnings.claimable.saturating_add(reward);
+                });
+            }
+
+            TotalSignaturesVerified::<T>::mutate(|n| *n += public_values.verified_count as u64);
+            Ok(reward)
+        }
+
+        /// Era `block` falls in, counting from era 0 at genesis
+        pub fn era_of(block: BlockNumberFor<T>) -> u32 {
+            (block / T::EraLength::get().max(One::one())).saturated_into()
+        }
+
+        /// Batches of `aggregator` verified in `era`
+        pub fn era_batches(aggregator: &T::AccountId, era: u32) -> u32 {
+            match AggregatorEraBatches::<T>::get(aggregator) {
+                (latest_era, batches) if latest_era == era => batches,
+                _ => 0,
+            }
+        }
+
+        /// Account of the reward pot
+        pub fn reward_pot() -> T::AccountId {
+            T::RewardPotId::get().into_account_truncating()
+        }
+
+        /// Bonded plus unbonding stake of `aggregator`
+        pub fn total_stake(aggregator: &T::AccountId) -> BalanceOf<T> {
+            let unbonding = AggregatorUnbonding::<T>::get(aggregator)
+                .map(|(amount, _)| amount)
+                .unwrap_or_else(Zero::zero);
+            AggregatorStakes::<T>::get(aggregator).saturating_add(unbonding)
+        }
+
+        /// Slash up to `amount` of `aggregator`'s stake, bonded first, into
+        /// `Config::Slash`, and deactivate it
+        fn slash(aggregator: &T::AccountId, amount: BalanceOf<T>) {
+            let bonded = AggregatorStakes::<T>::get(aggregator);
+            let from_bonded = bonded.min(amount);
+            let from_unbonding = amount.saturating_sub(from_bonded);
+
+            let left = bonded.saturating_sub(from_bonded);
+            if left.is_zero() {
+                AggregatorStakes::<T>::remove(aggregator);
+            } else {
+                AggregatorStakes::<T>::insert(aggregator, left);
             }
+            let mut to_slash = from_bonded;
+            AggregatorUnbonding::<T>::mutate_exists(aggregator, |unbonding| {
+                if let Some((unbonding_amount, _)) = unbonding {
+                    let slashed = (*unbonding_amount).min(from_unbonding);
+                    *unbonding_amount = unbonding_amount.saturating_sub(slashed);
+                    to_slash = to_slash.saturating_add(slashed);
+                    if unbonding_amount.is_zero() {
+                        *unbonding = None;
+                    }
+                }
+            });
 
-            // Compute proof commitment for replay prevention
-            let proof_commitment = Self::compute_proof_commitment(&submission);
-            let commitment_hash = H256::from_slice(&proof_commitment);
+            let (imbalance, unslashed) = T::RewardCurrency::slash_reserved(aggregator, to_slash);
+            T::Slash::on_unbalanced(imbalance);
 
-            ensure!(
-                !ProofCommitments::<T>::contains_key(commitment_hash),
-                Error::<T>::ProofAlreadyUsed
-            );
+            Aggregators::<T>::mutate(aggregator, |info| {
+                if let Some(info) = info {
+                    info.active = false;
+                }
+            });
+            SlashedAggregators::<T>::insert(aggregator, frame_system::Pallet::<T>::block_number());
 
-            // Verify merkle root matches claimed request IDs
-            let computed_root =
-                Self::compute_merkle_root(&submission.public_values.verified_request_ids);
-            ensure!(
-                computed_root == submission.public_values.requests_root,
-                Error::<T>::InvalidMerkleRoot
-            );
+            Self::deposit_event(Event::AggregatorSlashed {
+                aggregator: aggregator.clone(),
+                amount: to_slash.saturating_sub(unslashed),
+            });
+        }
 
-            // ═══════════════════════════════════════════════════════════════
-            // STARK PROOF VERIFICATION
-            // ═══════════════════════════════════════════════════════════════
+        /// Attribute a vault transfer premium to the batch that verified `request_id`
+        ///
+        /// Only recorded in the ledgers; the premium itself stays wherever the
+        /// caller sent it. Does nothing for unverified requests.
+        pub fn attribute_premium(request_id: u64, amount: BalanceOf<T>) {
+            if amount.is_zero() {
+                return;
+            }
+            let Some((batch_id, _)) = VerifiedRequests::<T>::get(request_id) else {
+                return;
+            };
+            let Some(batch) = VerifiedBatches::<T>::get(batch_id) else {
+                return;
+            };
 
-            let proof_valid = Self::verify_sp1_proof(
-                &submission.proof,
-                &submission.public_values,
-                &submission.vkey_hash,
-            );
+            BatchLedger::<T>::mutate(batch_id, |earnings| {
+                earnings.premiums_attributed = earnings.premiums_attributed.saturating_add(amount)
+            });
+            AggregatorLedger::<T>::mutate(&batch.aggregator, |earnings| {
+                earnings.premiums_attributed = earnings.premiums_attributed.saturating_add(amount)
+            });
 
-            if !proof_valid {
-                Self::deposit_event(Event::ProofRejected {
+            Self::deposit_event(Event::PremiumAttributed {
+                batch_id,
+                aggregator: batch.aggregator,
+                request_id,
+                amount,
+            });
+        }
+
+        /// `submit_single_proof` weight: one request, marked inline if allowed
+        fn submit_single_proof_weight() -> Weight {
+            T::WeightInfo::submit_proof(1, T::InlineRequestMarks::get().min(1))
+                .saturating_add(T::WeightInfo::verify_groth16_proof())
+                .saturating_add(T::OnBatchVerified::weight())
+        }
+
+        /// Full submission of a single-request proof
+        ///
+        /// The public values are those the guest commits for a batch of just
+        /// `request_id` at the current `ProofEpoch`.
+        pub fn single_proof_submission(submission: SingleProofSubmission) -> ProofSubmission {
+            let ids = [submission.request_id];
+            let owners = [submission.owner];
+            let messages = [submission.message];
+            let falcon512 = submission.scheme == SignatureScheme::Falcon512;
+            ProofSubmission {
+                batch_id: submission.batch_id,
+                proof: submission.proof,
+                public_values: PublicValues {
+                    version: REML_VERSION,
+                    chain_id: TESSERAX_CHAIN_ID,
+                    epoch: T::ProofEpoch::get(),
                     batch_id: submission.batch_id,
-                    aggregator: aggregator.clone(),
-                    reason: RejectReason::StarkVerificationFailed,
-                });
-                return Err(Error::<T>::ProofVerificationFailed.into());
+                    verified_count: 1,
+                    mldsa44_count: u32::from(!falcon512),
+                    falcon512_count: u32::from(falcon512),
+                    requests_root: Self::compute_merkle_root(&ids),
+                    requests_root_blake2: Self::compute_blake2_root(&ids),
+                    verified_request_ids: BoundedVec::truncate_from(ids.to_vec()),
+                    verified_request_owners: BoundedVec::truncate_from(owners.to_vec()),
+                    verified_request_messages: BoundedVec::truncate_from(messages.to_vec()),
+                },
+                vkey_hash: submission.vkey_hash,
+                program_id: REML_PROGRAM_ID,
+                anchor_block: submission.anchor_block,
+                anchor_hash: submission.anchor_hash,
             }
+        }
 
-            // ═══════════════════════════════════════════════════════════════
-            // UPDATE STORAGE
-            // ═══════════════════════════════════════════════════════════════
+        /// Check that a submission anchored to `anchor_block` with
+        /// `anchor_hash` names a block of this chain at most `MaxAnchorAge`
+        /// blocks before `now`
+        fn check_anchor(
+            anchor_block: u32,
+            anchor_hash: &[u8; 32],
+            now: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let anchor = BlockNumberFor::<T>::from(anchor_block);
+            let known_hash = frame_system::BlockHash::<T>::try_get(anchor).ok();
+            ensure!(
+                anchor < now
+                    && now.saturating_sub(anchor) <= T::MaxAnchorAge::get()
+                    && known_hash.is_some_and(|hash| hash.as_ref() == &anchor_hash[..]),
+                Error::<T>::InvalidAnchor
+            );
+            Ok(())
+        }
 
-            let current_block = frame_system::Pallet::<T>::block_number();
+        /// `submit_proof` weight for `submission`, co-signatures excluded
+        fn submit_proof_weight(submission: &ProofSubmission) -> Weight {
+            let requests = submission.public_values.verified_request_ids.len() as u32;
+            let inline = match submission.program_id {
+                REML_PROGRAM_ID => requests.min(T::InlineRequestMarks::get()),
+                _ => requests,
+            };
+            T::WeightInfo::submit_proof(requests, inline)
+                .saturating_add(T::WeightInfo::verify_groth16_proof())
+                .saturating_add(T::OnBatchVerified::weight())
+        }
 
-            // Store proof commitment
-            ProofCommitments::<T>::insert(commitment_hash, current_block);
+        /// Weight of `submission` without request marks, if it will be stored
+        /// root-only
+        fn root_only_submit_weight(submission: &ProofSubmission) -> Option<Weight> {
+            let requests = submission.public_values.verified_request_ids.len();
+            let root_only =
+                submission.program_id == REML_PROGRAM_ID && Self::is_root_only(requests);
+            root_only.then(|| {
+                T::WeightInfo::submit_proof(requests as u32, 0)
+                    .saturating_add(T::WeightInfo::verify_groth16_proof())
+                    .saturating_add(T::OnBatchVerified::weight())
+            })
+        }
 
-            // Store batch info
-            VerifiedBatches::<T>::insert(
-                submission.batch_id,
-                BatchInfo {
-                    aggregator: aggregator.clone(),
-                    verified_at: current_block,
-                    signature_count: submission.public_values.verified_count,
-                    requests_root: submission.public_values.requests_root,
-                    proof_commitment,
-                },
-            );
+        /// `submit_aggregate_proof` weight for `submission`
+        fn submit_aggregate_proof_weight(submission: &AggregateProofSubmission) -> Weight {
+            let batches = submission.public_values.sub_batches.len() as u32;
+            T::WeightInfo::submit_aggregate_proof(batches)
+                .saturating_add(T::WeightInfo::verify_groth16_proof())
+                .saturating_add(T::OnBatchVerified::weight().saturating_mul(batches as u64))
+        }
+
+        /// Whether a batch of `requests` requests verified now stores only its root
+        fn is_root_only(requests: usize) -> bool {
+            requests > 1 && RootOnlyBatches::<T>::get()
+        }
+
+        /// Write queued `PendingRequestMarks` into `VerifiedRequests` within `limit`
+        fn mark_pending_requests(limit: Weight) -> Weight {
+            let mut used = Weight::zero();
+            loop {
+                // Reading (and rewriting) the next queued batch
+                let batch_weight = T::WeightInfo::mark_pending_requests(0);
+                if !limit.all_gte(used.saturating_add(batch_weight)) {
+                    return used;
+                }
+                let Some((batch_id, (verified_at, request_ids))) =
+                    PendingRequestMarks::<T>::iter().next()
+                else {
+                    return used.saturating_add(batch_weight);
+                };
+
+                let mut request_ids = request_ids.into_inner();
+                let mut owners = PendingRequestOwners::<T>::get(batch_id).into_inner();
+                let mut messages = PendingRequestMessages::<T>::get(batch_id).into_inner();
+                let mut count = 0u32;
+                while (count as usize) < request_ids.len()
+                    && limit.all_gte(
+                        used.saturating_add(T::WeightInfo::mark_pending_requests(count + 1)),
+                    )
+                {
+                    count += 1;
+                }
+                let marked_owners: alloc::vec::Vec<_> =
+                    owners.drain(..owners.len().min(count as usize)).collect();
+                let marked_messages: alloc::vec::Vec<_> =
+                    messages.drain(..messages.len().min(count as usize)).collect();
+                for (index, request_id) in request_ids.drain(..count as usize).enumerate() {
+                    Self::mark_request(
+                        request_id,
+                        batch_id,
+                        verified_at,
+                        marked_owners.get(index),
+                        marked_messages.get(index),
+                    );
+                }
+                if request_ids.is_empty() {
+                    PendingRequestMarks::<T>::remove(batch_id);
+                    PendingRequestOwners::<T>::remove(batch_id);
+                    PendingRequestMessages::<T>::remove(batch_id);
+                } else {
+                    PendingRequestMarks::<T>::insert(
+                        batch_id,
+                        (verified_at, BoundedVec::truncate_from(request_ids)),
+                    );
+                    if !owners.is_empty() {
+                        PendingRequestOwners::<T>::insert(
+                            batch_id,
+                            BoundedVec::truncate_from(owners),
+                        );
+                    }
+                    if !messages.is_empty() {
+                        PendingRequestMessages::<T>::insert(
+                            batch_id,
+                            BoundedVec::truncate_from(messages),
+                        );
+                    }
+                }
 
-            // Mark requests as verified
-            for request_id in submission.public_values.verified_request_ids.iter() {
-                VerifiedRequests::<T>::insert(request_id, (submission.batch_id, current_block));
+                used = used.saturating_add(T::WeightInfo::mark_pending_requests(count));
+                if count == 0 {
+                    return used;
+                }
             }
+        }
 
-            // Update stats
-            aggregator_info.proofs_submitted += 1;
-            Aggregators::<T>::insert(&aggregator, aggregator_info);
+        /// Mark `request_id` verified by `batch_id` at `verified_at`, owned by
+        /// `owner` and signed over the message hashing to `message`
+        ///
+        /// Skipped for a request that is already verified or was ever
+        /// consumed, so a later batch can't take over its owner or make it
+        /// consumable again.
+        fn mark_request(
+            request_id: u64,
+            batch_id: u64,
+            verified_at: BlockNumberFor<T>,
+            owner: Option<&[u8; 32]>,
+            message: Option<&[u8; 32]>,
+        ) {
+            if VerifiedRequests::<T>::contains_key(request_id)
+                || ConsumedRequests::<T>::contains_key(request_id)
+            {
+                return;
+            }
+            VerifiedRequests::<T>::insert(request_id, (batch_id, verified_at));
+            if let Some(owner) = owner {
+                RequestOwners::<T>::insert(request_id, owner);
+            }
+            if let Some(message) = message {
+                RequestMessages::<T>::insert(request_id, message);
+            }
+        }
 
-            TotalProofsVerified::<T>::mutate(|n| *n += 1);
-            TotalSignaturesVerified::<T>::mutate(|n| {
-                *n += submission.public_values.verified_count as u64
-            });
+        /// Prune expired batches from the front of `PruneQueue` within `limit`
+        ///
+        /// The queue is in verification order, so pruning stops at the first
+        /// batch that has not expired. Batches already pruned with
+        /// `prune_expired` are skipped.
+        fn prune_expired_batches(limit: Weight) -> Weight {
+            // Reading and rewriting the queue bounds
+            let mut used = T::DbWeight::get().reads_writes(1, 1);
+            if !limit.all_gte(used) {
+                return Weight::zero();
+            }
+            let (mut head, tail) = PruneQueueBounds::<T>::get();
+            let now = frame_system::Pallet::<T>::block_number();
+
+            // Reading the next queued batch and its request count
+            let lookup = T::WeightInfo::prune_batch(0);
+            while head < tail && limit.all_gte(used.saturating_add(lookup)) {
+                let queued = PruneQueue::<T>::get(head).and_then(|batch_id| {
+                    VerifiedBatches::<T>::get(batch_id).map(|batch| (batch_id, batch))
+                });
+                let Some((batch_id, batch)) = queued else {
+                    PruneQueue::<T>::remove(head);
+                    head += 1;
+                    used = used.saturating_add(lookup);
+                    continue;
+                };
+
+                let requests = BatchRequests::<T>::decode_len(batch_id).unwrap_or(0) as u32;
+                let weight = T::WeightInfo::prune_batch(requests);
+                if !Self::is_expired(&batch, now) || !limit.all_gte(used.saturating_add(weight)) {
+                    used = used.saturating_add(lookup);
+                    break;
+                }
 
-            Self::deposit_event(Event::ProofVerified {
-                batch_id: submission.batch_id,
-                aggregator,
-                signature_count: submission.public_values.verified_count,
-                block_number: current_block,
-            });
+                Self::prune_batch(batch_id, batch, None);
+                PruneQueue::<T>::remove(head);
+                head += 1;
+                used = used.saturating_add(weight);
+            }
 
-            Ok(())
+            PruneQueueBounds::<T>::put((head, tail));
+            used
         }
-    }
 
-    // ═══════════════════════════════════════════════════════════════════════
-    // HELPER FUNCTIONS
-    // ═══════════════════════════════════════════════════════════════════════
+        /// Whether `batch` is older than `ProofRetentionPeriod` at `now`
+        pub fn is_expired(
+            batch: &BatchInfo<T::AccountId, BlockNumberFor<T>>,
+            now: BlockNumberFor<T>,
+        ) -> bool {
+            now >= batch.verified_at.saturating_add(T::ProofRetentionPeriod::get())
+        }
+
+        /// Remove `batch_id` and everything stored for it, and release its
+        /// deposit
+        ///
+        /// `pruner`, if any, gets `PrunerShare` of the deposit and the
+        /// aggregator the rest. A request is only unmarked, and its owner and
+        /// message dropped, while it still points at this batch; its
+        /// consumption is kept.
+        fn prune_batch(
+            batch_id: u64,
+            batch: BatchInfo<T::AccountId, BlockNumberFor<T>>,
+            pruner: Option<&T::AccountId>,
+        ) {
+            VerifiedBatches::<T>::remove(batch_id);
+            BatchRootsBlake2::<T>::remove(batch_id);
+            BatchLedger::<T>::remove(batch_id);
+            PendingRequestMarks::<T>::remove(batch_id);
+            PendingRequestOwners::<T>::remove(batch_id);
+            PendingRequestMessages::<T>::remove(batch_id);
+            BatchOwnersHashes::<T>::remove(batch_id);
+            BatchMessagesHashes::<T>::remove(batch_id);
+            ProofCommitments::<T>::remove(H256::from(batch.proof_commitment));
+            if RootToBatch::<T>::get(batch.requests_root) == Some(batch_id) {
+                RootToBatch::<T>::remove(batch.requests_root);
+            }
+            let program_id = BatchPrograms::<T>::take(batch_id);
+            for request_id in BatchRequests::<T>::take(batch_id).unwrap_or_default() {
+                match program_id {
+                    Some(program_id) => {
+                        if ProgramRequests::<T>::get(program_id, request_id)
+                            .is_some_and(|(id, _)| id == batch_id)
+                        {
+                            ProgramRequests::<T>::remove(program_id, request_id);
+                        }
+                    },
+                    None => {
+                        if VerifiedRequests::<T>::get(request_id)
+                            .is_some_and(|(id, _)| id == batch_id)
+                        {
+                            VerifiedRequests::<T>::remove(request_id);
+                            RequestOwners::<T>::remove(request_id);
+                            RequestMessages::<T>::remove(request_id);
+                        }
+                    },
+                }
+            }
+            PrunedBatches::<T>::insert(batch_id, frame_system::Pallet::<T>::block_number());
+
+            let deposit = BatchDeposits::<T>::take(batch_id).unwrap_or_else(Zero::zero);
+            let reward = match pruner {
+                Some(pruner) => {
+                    let share = T::PrunerShare::get() * deposit;
+                    // `repatriate_reserved` returns what it could not move
+                    let unmoved = T::RewardCurrency::repatriate_reserved(
+                        &batch.aggregator,
+                        pruner,
+                        share,
+                        BalanceStatus::Free,
+                    )
+                    .unwrap_or(share);
+                    share.saturating_sub(unmoved)
+                },
+                None => Zero::zero(),
+            };
+            T::RewardCurrency::unreserve(&batch.aggregator, deposit.saturating_sub(reward));
+
+            Self::deposit_event(Event::BatchPruned {
+                batch_id,
+                pruner: pruner.cloned(),
+                reward,
+            });
+        }
 
-    impl<T: Config> Pallet<T> {
         /// Check if a request ID has been verified
+        ///
+        /// Requests past `InlineRequestMarks` in a batch only count once
+        /// `on_idle` has written them, normally at the end of the same block,
+        /// and stop counting once the batch is pruned.
         pub fn is_request_verified(request_id: u64) -> bool {
             VerifiedRequests::<T>::contains_key(request_id)
         }
 
+        /// Check if a request ID has been verified by a batch of `program_id`
+        pub fn is_program_request_verified(program_id: ProgramId, request_id: u64) -> bool {
+            match program_id {
+                REML_PROGRAM_ID => Self::is_request_verified(request_id),
+                _ => ProgramRequests::<T>::contains_key(program_id, request_id),
+            }
+        }
+
+        /// Check an SP1 Groth16 `proof` of the program with `program_vkey`
+        /// committing `public_values`, against the key set with `set_groth16_vk`
+        ///
+        /// For programs other than Re-ML, e.g. the ZK-coprocessor's; `false`
+        /// while no key is set, as there is no structural fallback for them.
+        pub fn verify_sp1_groth16(
+            program_vkey: &[u8; 32],
+            public_values: &[u8],
+            proof: &[u8],
+        ) -> bool {
+            let inputs = groth16::sp1_public_inputs(program_vkey, public_values);
+            Groth16Vk::<T>::get().is_some_and(|vk| groth16::verify(&vk, proof, &inputs))
+        }
+
+        /// Consumer of a verified request and the block it was consumed in
+        ///
+        /// Still set once the batch that verified the request is pruned.
+        pub fn consumed_by(request_id: u64) -> Option<(T::AccountId, BlockNumberFor<T>)> {
+            ConsumedRequests::<T>::get(request_id)
+        }
+
         /// Get verification info
         pub fn get_verification_info(request_id: u64) -> Option<(u64, BlockNumberFor<T>)> {
             VerifiedRequests::<T>::get(request_id)
         }
 
+        /// Page through verified batches in storage order
+        ///
+        /// Returns up to `limit` (capped at `MAX_PAGE_SIZE`) batches after
+        /// `from_id`; pass the last batch ID of a page to fetch the next one.
+        /// Storage order follows the key hash, not the numeric batch ID.
+        pub fn list_batches(
+            from_id: Option<u64>,
+            limit: u32,
+        ) -> alloc::vec::Vec<(u64, BatchInfo<T::AccountId, BlockNumberFor<T>>)> {
+            let iter = match from_id {
+                Some(id) => VerifiedBatches::<T>::iter_from(VerifiedBatches::<T>::hashed_key_for(id)),
+                None => VerifiedBatches::<T>::iter(),
+            };
+
+            iter.take(limit.min(MAX_PAGE_SIZE) as usize).collect()
+        }
+
+        /// Batch whose `requests_root` is `root`, with its metadata
+        pub fn batch_by_root(
+            root: [u8; 32],
+        ) -> Option<(u64, BatchInfo<T::AccountId, BlockNumberFor<T>>)> {
+            let batch_id = RootToBatch::<T>::get(root)?;
+            VerifiedBatches::<T>::get(batch_id).map(|info| (batch_id, info))
+        }
+
+        /// Whether proof submission is paused
+        pub fn is_paused() -> bool {
+            VerifierPaused::<T>::get()
+        }
+
+        /// Aggregator that claimed `batch_id` and has yet to submit its proof
+        pub fn batch_id_claimant(batch_id: u64) -> Option<T::AccountId> {
+            BatchIdClaims::<T>::get(batch_id)
+        }
+
+        /// Aggregators holding a claimed batch ID with no proof submitted yet,
+        /// with the ID; at most `MAX_PAGE_SIZE`, in storage order
+        pub fn pending_aggregators() -> alloc::vec::Vec<(T::AccountId, u64)> {
+            BatchIdClaims::<T>::iter()
+                .take(MAX_PAGE_SIZE as usize)
+                .map(|(batch_id, aggregator)| (aggregator, batch_id))
+                .collect()
+        }
+
+        /// Active aggregator that should prove `request_id` this era
+        ///
+        /// Request IDs are routed in ranges of `ROUTING_RANGE_SIZE`: each
+        /// range goes to one active aggregator, drawn with probability
+        /// proportional to its [`Self::routing_weight`] from a seed of the era
+        /// and the range. Every host computes the same assignment, so
+        /// operators split the load instead of proving the same requests.
+        /// Only a suggestion: submissions are not checked against it.
+        pub fn suggested_aggregator(request_id: u64) -> Option<T::AccountId> {
+            let era = Self::era_of(frame_system::Pallet::<T>::block_number());
+            let candidates: alloc::vec::Vec<(T::AccountId, u128)> = Aggregators::<T>::iter()
+                .filter(|(_, info)| info.active)
+                .take(T::MaxAggregators::get() as usize)
+                .map(|(aggregator, info)| {
+                    let weight = Self::routing_weight(&aggregator, &info);
+                    (aggregator, weight)
+                })
+                .collect();
+            let total =
+                candidates.iter().fold(0u128, |total, (_, weight)| total.saturating_add(*weight));
+            if total == 0 {
+                return None;
+            }
+
+            let mut data = ROUTING_CONTEXT.to_vec();
+            data.extend_from_slice(&era.to_le_bytes());
+            data.extend_from_slice(&(request_id / ROUTING_RANGE_SIZE).to_le_bytes());
+            let seed = sp_core::blake2_256(&data);
+            let mut draw =
+                u128::from_le_bytes(seed[..16].try_into().expect("16 of 32 bytes")) % total;
+            for (aggregator, weight) in candidates {
+                if draw < weight {
+                    return Some(aggregator);
+                }
+                draw -= weight;
+            }
+            None
+        }
+
+        /// Routing weight of `aggregator`: its bonded stake (at least 1),
+        /// scaled by `(a + 1) / (a + 1 + DISPUTE_ROUTING_PENALTY × d)` for `a`
+        /// watchtower attestations and `d` upheld disputes
+        pub fn routing_weight(
+            aggregator: &T::AccountId,
+            info: &AggregatorInfo<BlockNumberFor<T>>,
+        ) -> u128 {
+            let stake: u128 = AggregatorStakes::<T>::get(aggregator).saturated_into();
+            let record = info.reputation.attestations.saturating_add(1);
+            let penalty = info.reputation.upheld_disputes.saturating_mul(DISPUTE_ROUTING_PENALTY);
+            Perbill::from_rational(record, record.saturating_add(penalty)).mul_floor(stake.max(1))
+        }
+
+        /// Most recently verified batch, with its metadata
+        pub fn last_verified_batch() -> Option<(u64, BatchInfo<T::AccountId, BlockNumberFor<T>>)> {
+            let batch_id = LastVerifiedBatch::<T>::get()?;
+            VerifiedBatches::<T>::get(batch_id).map(|info| (batch_id, info))
+        }
+
+        /// Number of active aggregators
+        pub fn active_aggregator_count() -> u32 {
+            Aggregators::<T>::iter_values().filter(|info| info.active).count() as u32
+        }
+
         /// Check if account is active aggregator
         pub fn is_aggregator(account: &T::AccountId) -> bool {
             Aggregators::<T>::get(account)
@@ -433,92 +2946,213 @@ pub mod pallet {
                 .unwrap_or(false)
         }
 
+        /// Record a watchtower attestation of one of `aggregator`'s batches
+        pub fn note_attestation(aggregator: &T::AccountId) {
+            Aggregators::<T>::mutate(aggregator, |info| {
+                if let Some(info) = info {
+                    info.reputation.attestations = info.reputation.attestations.saturating_add(1);
+                }
+            });
+        }
+
+        /// Record an upheld dispute against one of `aggregator`'s batches
+        pub fn note_upheld_dispute(aggregator: &T::AccountId) {
+            Aggregators::<T>::mutate(aggregator, |info| {
+                if let Some(info) = info {
+                    info.reputation.upheld_disputes =
+                        info.reputation.upheld_disputes.saturating_add(1);
+                }
+            });
+        }
+
+        /// Run `submit_cosigned_proof` against current state and roll it back
+        ///
+        /// Applies every check of a real submission, SP1 verification included,
+        /// so aggregators can validate a bundle before paying for inclusion.
+        pub fn dry_run_submit_proof(
+            aggregator: T::AccountId,
+            submission: ProofSubmission,
+            co_signatures: &[(T::AccountId, T::CoSignature)],
+        ) -> DispatchResult {
+            with_transaction(|| {
+                let result = Self::do_submit_proof(aggregator, submission, co_signatures);
+                TransactionOutcome::Rollback(result)
+            })
+        }
+
+        /// `ProofRejected` reason for a submission error, if it has one
+        pub fn reject_reason(error: &DispatchError) -> Option<RejectReason> {
+            let reasons: [(DispatchError, RejectReason); 8] = [
+                (Error::<T>::ProofTooSmall.into(), RejectReason::InvalidProofFormat),
+                (Error::<T>::InvalidVKeyHash.into(), RejectReason::InvalidVKeyHash),
+                (Error::<T>::InvalidPublicValues.into(), RejectReason::InvalidPublicValues),
+                (Error::<T>::BatchAlreadyVerified.into(), RejectReason::BatchAlreadyVerified),
+                (Error::<T>::ProofAlreadyUsed.into(), RejectReason::ProofAlreadyUsed),
+                (Error::<T>::ProofVerificationFailed.into(), RejectReason::StarkVerificationFailed),
+                (Error::<T>::InvalidMerkleRoot.into(), RejectReason::InvalidMerkleRoot),
+                (Error::<T>::InvalidAnchor.into(), RejectReason::InvalidAnchor),
+            ];
+            reasons.into_iter().find(|(e, _)| e == error).map(|(_, reason)| reason)
+        }
+
+        /// Record a submission of `batch_id` by `aggregator` that failed with `error`
+        ///
+        /// Called by the runtime after a proof extrinsic failed, outside the
+        /// rolled back dispatch. Only errors with a `RejectReason` are kept;
+        /// the oldest rejection drops out once `MAX_RECENT_REJECTIONS` are held.
+        pub fn note_rejection(batch_id: u64, aggregator: T::AccountId, error: &DispatchError) {
+            let Some(reason) = Self::reject_reason(error) else {
+                return;
+            };
+            let rejection = Rejection {
+                batch_id,
+                aggregator,
+                reason,
+                rejected_at: frame_system::Pallet::<T>::block_number(),
+            };
+            RecentRejections::<T>::mutate(|rejections| {
+                if rejections.is_full() {
+                    rejections.remove(0);
+                }
+                let _ = rejections.try_push(rejection);
+            });
+        }
+
+        /// Latest rejected proof submissions, oldest first
+        pub fn recent_rejections() -> alloc::vec::Vec<Rejection<T::AccountId, BlockNumberFor<T>>> {
+            RecentRejections::<T>::get().into_inner()
+        }
+
+        /// Message a co-signer signs for `submission`:
+        /// `COSIGN_CONTEXT || proof_commitment`
+        pub fn cosign_payload(submission: &ProofSubmission) -> alloc::vec::Vec<u8> {
+            let mut payload = COSIGN_CONTEXT.to_vec();
+            payload.extend_from_slice(&Self::compute_proof_commitment(submission));
+            payload
+        }
+
+        /// Check co-signatures over `proof_commitment`, returning the co-signers
+        ///
+        /// Every co-signer must be a distinct active aggregator other than the
+        /// submitter.
+        fn verify_co_signatures(
+            submitter: &T::AccountId,
+            proof_commitment: &[u8; 32],
+            co_signatures: &[(T::AccountId, T::CoSignature)],
+        ) -> Result<alloc::vec::Vec<T::AccountId>, DispatchError> {
+            let _span = sp_tracing::span!(
+                target: TRACE_TARGET,
+                sp_tracing::Level::DEBUG,
+                "verify_co_signatures"
+            )
+            .entered();
+            let mut payload = COSIGN_CONTEXT.to_vec();
+            payload.extend_from_slice(proof_commitment);
+
+            let mut co_signers = alloc::vec::Vec::with_capacity(co_signatures.len());
+            for (signer, signature) in co_signatures {
+                ensure!(
+                    signer != submitter && !co_signers.contains(signer),
+                    Error::<T>::DuplicateCoSigner
+                );
+                ensure!(
+                    Self::is_aggregator(signer) && signature.verify(&payload[..], signer),
+                    Error::<T>::InvalidCoSignature
+                );
+                co_signers.push(signer.clone());
+            }
+
+            Ok(co_signers)
+        }
+
         /// Compute proof commitment hash
-        fn compute_proof_commitment(submission: &ProofSubmission) -> [u8; 32] {
+        pub fn compute_proof_commitment(submission: &ProofSubmission) -> [u8; 32] {
             use sp_core::blake2_256;
 
-            // Hash: vkey || batch_id || requests_root || proof_hash
+            // Hash: vkey || epoch || batch_id || requests_root || proof_hash
             let proof_hash = blake2_256(&submission.proof);
 
-            let mut data = [0u8; 32 + 8 + 32 + 32];
+            let mut data = [0u8; 32 + 4 + 8 + 32 + 32];
             data[..32].copy_from_slice(&submission.vkey_hash);
-            data[32..40].copy_from_slice(&submission.batch_id.to_le_bytes());
-            data[40..72].copy_from_slice(&submission.public_values.requests_root);
-            data[72..104].copy_from_slice(&proof_hash);
+            data[32..36].copy_from_slice(&submission.public_values.epoch.to_le_bytes());
+            data[36..44].copy_from_slice(&submission.batch_id.to_le_bytes());
+            data[44..76].copy_from_slice(&submission.public_values.requests_root);
+            data[76..108].copy_from_slice(&proof_hash);
 
             blake2_256(&data)
         }
 
-        /// Compute merkle root from request IDs
-        fn compute_merkle_root(ids: &[u64]) -> [u8; 32] {
+        /// Commitment recorded for `batch` of an aggregate proof
+        ///
+        /// `blake2_256(AGGREGATE_COMMITMENT_CONTEXT || vkey || epoch || batch_id
+        /// || requests_root || proof_hash)`, the aggregation guest's vkey and
+        /// proof standing in for the batch's own.
+        pub fn compute_aggregate_commitment(
+            submission: &AggregateProofSubmission,
+            batch: &SubBatchValues,
+        ) -> [u8; 32] {
             use sp_core::blake2_256;
 
-            if ids.is_empty() {
-                return [0u8; 32];
-            }
+            let proof_hash = blake2_256(&submission.proof);
 
-            // Hash leaves
-            let mut leaves: alloc::vec::Vec<[u8; 32]> = ids
-                .iter()
-                .map(|id| {
-                    let mut leaf = [0u8; 32];
-                    leaf[..8].copy_from_slice(&id.to_le_bytes());
-                    blake2_256(&leaf)
-                })
-                .collect();
+            let mut data = AGGREGATE_COMMITMENT_CONTEXT.to_vec();
+            data.extend_from_slice(&submission.vkey_hash);
+            data.extend_from_slice(&submission.public_values.epoch.to_le_bytes());
+            data.extend_from_slice(&batch.batch_id.to_le_bytes());
+            data.extend_from_slice(&batch.requests_root);
+            data.extend_from_slice(&proof_hash);
 
-            // Build tree
-            while leaves.len() > 1 {
-                let mut next = alloc::vec::Vec::with_capacity((leaves.len() + 1) / 2);
-
-                for i in (0..leaves.len()).step_by(2) {
-                    if i + 1 < leaves.len() {
-                        let mut combined = [0u8; 64];
-                        combined[..32].copy_from_slice(&leaves[i]);
-                        combined[32..].copy_from_slice(&leaves[i + 1]);
-                        next.push(blake2_256(&combined));
-                    } else {
-                        next.push(leaves[i]);
-                    }
-                }
+            blake2_256(&data)
+        }
 
-                leaves = next;
-            }
+        /// Compute merkle root from request IDs
+        ///
+        /// `tesserax_merkle`, the tree the guest commits through `reml_lib`.
+        pub fn compute_merkle_root(ids: &[u64]) -> [u8; 32] {
+            let _span = sp_tracing::span!(
+                target: TRACE_TARGET,
+                sp_tracing::Level::DEBUG,
+                "compute_merkle_root",
+                leaves = ids.len()
+            )
+            .entered();
+
+            tesserax_merkle::keccak_requests_root(ids)
+        }
 
-            leaves[0]
+        /// Compute the blake2 merkle root from request IDs
+        ///
+        /// The tree of [`Self::compute_merkle_root`] hashed with blake2_256,
+        /// the root Substrate consumers check (`BatchRootsBlake2`).
+        pub fn compute_blake2_root(ids: &[u64]) -> [u8; 32] {
+            let _span = sp_tracing::span!(
+                target: TRACE_TARGET,
+                sp_tracing::Level::DEBUG,
+                "compute_blake2_root",
+                leaves = ids.len()
+            )
+            .entered();
+
+            tesserax_merkle::blake2_requests_root(ids)
         }
 
         /// Verify SP1 proof
         ///
-        /// This verifies the proof structure and public commitments.
-        /// For full STARK verification, integrate with SP1's verifier.
+        /// With a `Groth16Vk`, the proof must be an SP1 Groth16 proof of
+        /// `vkey_hash` committing `public_values`. Without one, only the proof
+        /// structure and public commitments are checked.
         fn verify_sp1_proof(
             proof: &[u8],
             public_values: &PublicValues,
             vkey_hash: &[u8; 32],
         ) -> bool {
-            // ═══════════════════════════════════════════════════════════════
-            // SP1 PROOF STRUCTURE
-            // ═══════════════════════════════════════════════════════════════
-            //
-            // SP1 proofs contain:
-            // 1. Core proof data (STARK or compressed Groth16)
-            // 2. Public values commitment
-            // 3. Verification key commitment
-            //
-            // For full verification, we would:
-            // 1. Parse the proof format
-            // 2. Extract public inputs
-            // 3. Verify STARK constraints or Groth16 pairing
-            //
-            // Current implementation verifies structure and commitments.
-            // Full verification requires SP1 verifier contract integration.
-            // ═══════════════════════════════════════════════════════════════
-
-            // Check minimum proof size
-            if proof.len() < MIN_PROOF_SIZE && proof.len() != GROTH16_PROOF_SIZE {
-                return false;
-            }
+            let _span = sp_tracing::span!(
+                target: TRACE_TARGET,
+                sp_tracing::Level::DEBUG,
+                "verify_sp1_proof",
+                proof_size = proof.len()
+            )
+            .entered();
 
             // Verify public values are non-zero
             if public_values.verified_count == 0 {
@@ -530,6 +3164,48 @@ pub mod pallet {
                 return false;
             }
 
+            // Every verified signature is of exactly one scheme
+            if public_values.mldsa44_count.checked_add(public_values.falcon512_count)
+                != Some(public_values.verified_count)
+            {
+                return false;
+            }
+
+            if let Some(vk) = Groth16Vk::<T>::get() {
+                return Self::verify_groth16_proof(&vk, proof, public_values, vkey_hash);
+            }
+
+            Self::verify_proof_structure(proof, &public_values.binding_hash(), vkey_hash)
+        }
+
+        /// Without a verifying key, production builds reject every proof
+        #[cfg(not(feature = "lenient-testnet"))]
+        fn verify_proof_structure(
+            _proof: &[u8],
+            _binding_hash: &[u8; 32],
+            _vkey_hash: &[u8; 32],
+        ) -> bool {
+            false
+        }
+
+        /// Structural checks used when no Groth16 verifying key is set
+        ///
+        /// Heuristics only: they catch malformed bundles, not forged proofs.
+        /// Compiled in only with the `lenient-testnet` feature.
+        ///
+        /// `binding_hash` is the public values' `binding_hash`, which the
+        /// proof must contain.
+        #[cfg(feature = "lenient-testnet")]
+        fn verify_proof_structure(
+            proof: &[u8],
+            binding_hash: &[u8; 32],
+            vkey_hash: &[u8; 32],
+        ) -> bool {
+            // Check minimum proof size
+            if proof.len() < MIN_PROOF_SIZE && proof.len() != GROTH16_PROOF_SIZE {
+                return false;
+            }
+
             // Verify proof contains expected commitments
             // SP1 proofs start with a version byte and contain vkey commitment
             if proof.len() >= 33 {
@@ -564,16 +3240,7 @@ pub mod pallet {
 
             // Verify public values encoding is in proof
             // The proof should commit to the public values
-            use sp_core::blake2_256;
-            let public_hash = {
-                let mut data = alloc::vec::Vec::new();
-                data.push(public_values.version);
-                data.extend_from_slice(&public_values.chain_id.to_le_bytes());
-                data.extend_from_slice(&public_values.batch_id.to_le_bytes());
-                data.extend_from_slice(&public_values.verified_count.to_le_bytes());
-                data.extend_from_slice(&public_values.requests_root);
-                blake2_256(&data)
-            };
+            let public_hash = *binding_hash;
 
             // Check if proof contains or commits to public values
             // In real SP1 proofs, public values are cryptographically bound
@@ -608,5 +3275,69 @@ pub mod pallet {
 
             true
         }
+
+        /// Check an SP1 Groth16 proof against `vk`
+        ///
+        /// Groth16 proofs commit the ABI encoding of `public_values`, the
+        /// bytes SP1's Solidity verifier hashes and contracts `abi.decode`.
+        fn verify_groth16_proof(
+            vk: &groth16::VerifyingKey,
+            proof: &[u8],
+            public_values: &PublicValues,
+            vkey_hash: &[u8; 32],
+        ) -> bool {
+            let _span = sp_tracing::span!(
+                target: TRACE_TARGET,
+                sp_tracing::Level::DEBUG,
+                "verify_groth16_proof"
+            )
+            .entered();
+
+            let inputs = groth16::sp1_public_inputs(vkey_hash, &public_values.abi_encode());
+            groth16::verify(vk, proof, &inputs)
+        }
+    }
+}
+
+impl<T: Config> RemlVerification<T::AccountId> for Pallet<T> {
+    fn is_request_verified(request_id: u64) -> bool {
+        Pallet::<T>::is_request_verified(request_id)
+    }
+
+    fn is_request_consumed(request_id: u64) -> bool {
+        ConsumedRequests::<T>::contains_key(request_id)
+    }
+
+    fn consume_verified_request(
+        request_id: u64,
+        owner: &[u8; 32],
+        consumer: &T::AccountId,
+    ) -> frame_support::dispatch::DispatchResult {
+        frame_support::ensure!(
+            Pallet::<T>::is_request_verified(request_id),
+            Error::<T>::RequestNotVerified
+        );
+        // Only the signer's key may act on the request, so nobody else can
+        // use it up first
+        frame_support::ensure!(
+            RequestOwners::<T>::get(request_id).as_ref() == Some(owner),
+            Error::<T>::NotRequestOwner
+        );
+        frame_support::ensure!(
+            !ConsumedRequests::<T>::contains_key(request_id),
+            Error::<T>::RequestAlreadyConsumed
+        );
+
+        let now = frame_system::Pallet::<T>::block_number();
+        ConsumedRequests::<T>::insert(request_id, (consumer.clone(), now));
+        Pallet::<T>::deposit_event(Event::RequestConsumed {
+            request_id,
+            consumer: consumer.clone(),
+        });
+        Ok(())
+    }
+
+    fn request_message(request_id: u64) -> Option<[u8; 32]> {
+        RequestMessages::<T>::get(request_id)
     }
 }
@@ -0,0 +1,111 @@
+//! Storage migrations for pallet-reml-verifier
+
+/// v0 → v1: `AggregatorInfo` gains a zeroed `reputation`
+pub mod v1 {
+    use crate::{Config, Pallet, Reputation};
+    use codec::{Decode, Encode};
+    use frame_support::{
+        migrations::VersionedMigration,
+        traits::{Get, UncheckedOnRuntimeUpgrade},
+        weights::Weight,
+        Blake2_128Concat,
+    };
+    use frame_system::pallet_prelude::BlockNumberFor;
+
+    /// `AggregatorInfo` as stored before v1
+    #[derive(Encode, Decode)]
+    pub struct OldAggregatorInfo<BlockNumber> {
+        pub registered_at: BlockNumber,
+        pub proofs_submitted: u64,
+        pub active: bool,
+    }
+
+    /// `AggregatorInfo` as stored in v1
+    #[derive(Encode, Decode)]
+    pub struct AggregatorInfo<BlockNumber> {
+        pub registered_at: BlockNumber,
+        pub proofs_submitted: u64,
+        pub active: bool,
+        pub reputation: Reputation,
+    }
+
+    /// `Aggregators` with v1 values
+    #[frame_support::storage_alias]
+    pub type Aggregators<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        <T as frame_system::Config>::AccountId,
+        AggregatorInfo<BlockNumberFor<T>>,
+    >;
+
+    /// Unversioned body, use [`MigrateV0ToV1`]
+    pub struct InnerMigrateV0ToV1<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+            Aggregators::<T>::translate::<OldAggregatorInfo<BlockNumberFor<T>>, _>(|_, old| {
+                translated += 1;
+                Some(AggregatorInfo {
+                    registered_at: old.registered_at,
+                    proofs_submitted: old.proofs_submitted,
+                    active: old.active,
+                    reputation: Reputation::default(),
+                })
+            });
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+    }
+
+    /// Runs once, when the on-chain storage version is 0
+    pub type MigrateV0ToV1<T> = VersionedMigration<
+        0,
+        1,
+        InnerMigrateV0ToV1<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// v1 → v2: `AggregatorInfo` gains an unset `server_key_hash`
+pub mod v2 {
+    use super::v1;
+    use crate::{AggregatorInfo, Aggregators, Config, Pallet};
+    use frame_support::{
+        migrations::VersionedMigration,
+        traits::{Get, UncheckedOnRuntimeUpgrade},
+        weights::Weight,
+    };
+    use frame_system::pallet_prelude::BlockNumberFor;
+
+    /// Unversioned body, use [`MigrateV1ToV2`]
+    pub struct InnerMigrateV1ToV2<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV1ToV2<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+            Aggregators::<T>::translate::<v1::AggregatorInfo<BlockNumberFor<T>>, _>(|_, old| {
+                translated += 1;
+                Some(AggregatorInfo {
+                    registered_at: old.registered_at,
+                    proofs_submitted: old.proofs_submitted,
+                    active: old.active,
+                    reputation: old.reputation,
+                    server_key_hash: None,
+                })
+            });
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+    }
+
+    /// Runs once, when the on-chain storage version is 1
+    pub type MigrateV1ToV2<T> = VersionedMigration<
+        1,
+        2,
+        InnerMigrateV1ToV2<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
@@ -0,0 +1,61 @@
+//! Runtime API definition for the Re-ML Verifier pallet.
+
+use crate::{BatchInfo, ProgramId, ProofDryRunError, ProofSubmission, Rejection};
+use alloc::vec::Vec;
+use codec::Codec;
+
+sp_api::decl_runtime_apis! {
+    /// Re-ML verification queries for RPC, explorers and bridges
+    pub trait RemlVerifierApi<AccountId, BlockNumber, CoSignature>
+    where
+        AccountId: Codec,
+        BlockNumber: Codec,
+        CoSignature: Codec,
+    {
+        /// Up to `limit` verified batches after `from_id` (exclusive), in storage order
+        fn list_batches(from_id: Option<u64>, limit: u32) -> Vec<(u64, BatchInfo<AccountId, BlockNumber>)>;
+
+        /// Batch whose `requests_root` is `root`, with its metadata
+        fn batch_by_root(root: [u8; 32]) -> Option<(u64, BatchInfo<AccountId, BlockNumber>)>;
+
+        /// Simulate `submit_cosigned_proof` by `aggregator` against current state
+        ///
+        /// Nothing is submitted or persisted. Pass no co-signatures to simulate
+        /// a plain `submit_proof`.
+        fn dry_run_submit_proof(
+            aggregator: AccountId,
+            submission: ProofSubmission,
+            co_signatures: Vec<(AccountId, CoSignature)>,
+        ) -> Result<(), ProofDryRunError>;
+
+        /// Latest rejected proof submissions, oldest first
+        fn recent_rejections() -> Vec<Rejection<AccountId, BlockNumber>>;
+    }
+
+    /// Re-ML verification status for wallets and the aggregator host
+    ///
+    /// Small, fixed-size reads answered over `state_call`, without decoding
+    /// raw storage keys.
+    pub trait RemlApi<AccountId, BlockNumber>
+    where
+        AccountId: Codec,
+        BlockNumber: Codec,
+    {
+        /// Whether `request_id` is marked verified
+        fn is_request_verified(request_id: u64) -> bool;
+
+        /// Whether `request_id` is marked verified by a batch of `program_id`
+        fn is_program_request_verified(program_id: ProgramId, request_id: u64) -> bool;
+
+        /// Metadata of verified batch `batch_id`; `None` if unknown or pruned
+        fn get_batch_info(batch_id: u64) -> Option<BatchInfo<AccountId, BlockNumber>>;
+
+        /// Aggregators holding a claimed batch ID with no proof submitted yet,
+        /// with the ID; at most `MAX_PAGE_SIZE`
+        fn pending_aggregators() -> Vec<(AccountId, u64)>;
+
+        /// Active aggregator routed `request_id`'s range this era, weighted by
+        /// stake and track record; `None` without active aggregators
+        fn suggested_aggregator(request_id: u64) -> Option<AccountId>;
+    }
+}
@@ -20,7 +20,28 @@ use core::marker::PhantomData;
 pub trait WeightInfo {
     fn register_aggregator() -> Weight;
     fn deactivate_aggregator() -> Weight;
-    fn submit_proof(n: u32) -> Weight;
+    fn submit_proof(n: u32, m: u32) -> Weight;
+    fn on_finalize_digest() -> Weight;
+    fn set_cosign_threshold() -> Weight;
+    fn verify_co_signatures(c: u32) -> Weight;
+    fn set_paused() -> Weight;
+    fn claim_batch_id() -> Weight;
+    fn set_require_claimed_batch_ids() -> Weight;
+    fn mark_pending_requests(m: u32) -> Weight;
+    fn tip_batch() -> Weight;
+    fn claim_earnings() -> Weight;
+    fn set_groth16_vk() -> Weight;
+    fn verify_groth16_proof() -> Weight;
+    fn bond_aggregator() -> Weight;
+    fn unbond_aggregator() -> Weight;
+    fn withdraw_unbonded() -> Weight;
+    fn report_invalid_proof(n: u32) -> Weight;
+    fn slash_aggregator() -> Weight;
+    fn prune_batch(r: u32) -> Weight;
+    fn claim_request_verification(d: u32) -> Weight;
+    fn set_root_only_batches() -> Weight;
+    fn set_server_key_hash() -> Weight;
+    fn submit_aggregate_proof(b: u32) -> Weight;
 }
 
 /// Weights for pallet-reml-verifier using Substrate node
@@ -55,41 +76,309 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     /// - Aggregators (r:1 w:1)
     /// - VerifiedBatches (r:1 w:1)
     /// - ProofCommitments (r:1 w:1)
-    /// - VerifiedRequests (r:0 w:n)
+    /// - VerifiedRequests, ConsumedRequests (r:2m w:0), VerifiedRequests, RequestOwners,
+    ///   RequestMessages (r:0 w:3m), m = min(n, InlineRequestMarks),
+    ///   or ProgramRequests (r:0 w:m), m = n, for other guest programs
+    /// - BatchPrograms, BatchOwnersHashes, BatchMessagesHashes (r:0 w:3)
+    /// - PendingRequestMarks, PendingRequestOwners, PendingRequestMessages (r:0 w:3)
     /// - TotalProofsVerified (r:1 w:1)
     /// - TotalSignaturesVerified (r:1 w:1)
+    /// - BlockBatches (r:0 w:1)
+    /// - RootToBatch (r:1 w:1)
+    /// - CoSignThreshold (r:1 w:0)
+    /// - VerifierPaused (r:1 w:0)
+    /// - RootOnlyBatches (r:1 w:0)
+    /// - ExpectedVKeyHash, e.g. ProgramRegistry::ProgramVersions (r:1 w:0)
+    /// - AggregatorLedger (r:1 w:1)
+    /// - BatchLedger (r:0 w:1)
+    /// - PrunedBatches (r:1 w:0)
+    /// - BatchRequests, BatchDeposits (r:0 w:2)
+    /// - PruneQueue (r:0 w:1), PruneQueueBounds (r:1 w:1)
+    /// - System::Account (r:1 w:1)
     /// 
     /// Computation:
     /// - Proof parsing: O(proof_size)
     /// - Merkle root: O(n log n) where n = request count
     /// - Proof verification: O(proof_size)
     /// - Commitment hash: O(1)
-    fn submit_proof(n: u32) -> Weight {
+    fn submit_proof(n: u32, m: u32) -> Weight {
         // Base cost: proof parsing and verification
         // ~100 µs base + ~1 µs per 100 bytes of proof (avg proof ~10KB)
         let base_cost = 100_000_000u64;
         
-        // Merkle tree computation: O(n log n)
-        // ~5 µs per hash, ~2n hashes for tree
+        // Merkle tree computation, keccak and blake2 roots: O(n log n)
+        // ~5 µs per hash, ~2n hashes per tree (`cargo bench -p tesserax-merkle`)
         let merkle_cost = (n as u64)
-            .saturating_mul(10_000_000)  // 10 µs per request
+            .saturating_mul(20_000_000)  // 20 µs per request
             .saturating_add(
                 // Log factor for tree depth
-                (n as u64).checked_ilog2().unwrap_or(1) as u64 * 5_000_000
+                (n as u64).checked_ilog2().unwrap_or(1) as u64 * 10_000_000
             );
         
-        // Storage writes for each verified request
-        let per_request_storage = (n as u64).saturating_mul(5_000_000); // 5 µs per write
+        // Storage writes for each request marked inline, its owner and message
+        let per_request_storage = (m as u64).saturating_mul(15_000_000); // 3 writes of 5 µs
         
         let total_computation = base_cost
             .saturating_add(merkle_cost)
             .saturating_add(per_request_storage);
         
         Weight::from_parts(total_computation, 0)
-            // Reads: aggregator, batch, commitment, 2 counters
-            .saturating_add(T::DbWeight::get().reads(5_u64))
-            // Writes: aggregator, batch, commitment, 2 counters, n requests
-            .saturating_add(T::DbWeight::get().writes(5_u64.saturating_add(n as u64)))
+            // Reads: pause flag, aggregator, era batches, batch, pruned batch, claim, claim
+            // requirement, commitment, root index, threshold, 2 counters, ledger, queue
+            // bounds, deposit account, root-only flag, expected vkey, m requests and their
+            // consumptions
+            .saturating_add(T::DbWeight::get().reads(17_u64.saturating_add(2 * m as u64)))
+            // Writes: aggregator, era batches, batch, last batch, claim, commitment, block
+            // batches, root index, blake2 root, owners hash, messages hash, pending marks,
+            // pending owners, pending messages, 2 counters, ledger, batch ledger, batch
+            // requests, batch program, deposit, deposit account, queue entry, queue bounds,
+            // m requests, their owners and their messages
+            .saturating_add(T::DbWeight::get().writes(24_u64.saturating_add(3 * m as u64)))
+    }
+
+    /// Flush verified batches into the header digest
+    /// 
+    /// Storage: BlockBatches (r:1 w:1)
+    /// Complexity: O(b) where b <= MAX_BATCHES_PER_BLOCK
+    fn on_finalize_digest() -> Weight {
+        // Base: 10 µs + encoding up to 64 entries
+        Weight::from_parts(10_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    /// Set co-sign threshold
+    /// 
+    /// Storage: CoSignThreshold (r:0 w:1)
+    /// Complexity: O(1)
+    fn set_cosign_threshold() -> Weight {
+        // Base: 10 µs
+        Weight::from_parts(10_000_000, 0)
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    /// Check co-signatures on top of `submit_proof`
+    /// 
+    /// Storage: Aggregators (r:c w:0)
+    /// Complexity: O(c²) duplicate scan, c <= MaxAggregators
+    fn verify_co_signatures(c: u32) -> Weight {
+        // ~60 µs per signature verification (sr25519/ecdsa)
+        Weight::from_parts((c as u64).saturating_mul(60_000_000), 0)
+            .saturating_add(T::DbWeight::get().reads(c as u64))
+    }
+
+    /// Pause or resume proof submission
+    /// 
+    /// Storage: VerifierPaused (r:0 w:1)
+    /// Complexity: O(1)
+    fn set_paused() -> Weight {
+        // Base: 10 µs
+        Weight::from_parts(10_000_000, 0)
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    /// Claim the next batch ID
+    /// 
+    /// Storage: Aggregators (r:1), BlockClaims (r:1 w:1), VerifiedBatches (r:1),
+    /// PrunedBatches (r:1), BatchIdClaims (r:0 w:1)
+    /// Complexity: O(1)
+    fn claim_batch_id() -> Weight {
+        // Base: 20 µs
+        Weight::from_parts(20_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+
+    /// Require claimed batch IDs
+    /// 
+    /// Storage: RequireClaimedBatchIds (r:0 w:1)
+    /// Complexity: O(1)
+    fn set_require_claimed_batch_ids() -> Weight {
+        // Base: 10 µs
+        Weight::from_parts(10_000_000, 0)
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    /// Write m queued request marks of one batch in `on_idle`
+    /// 
+    /// Storage: PendingRequestMarks (r:1 w:1), PendingRequestOwners (r:1 w:1),
+    /// PendingRequestMessages (r:1 w:1), VerifiedRequests (r:m w:m), ConsumedRequests (r:m),
+    /// RequestOwners (r:0 w:m), RequestMessages (r:0 w:m)
+    /// Complexity: O(m)
+    fn mark_pending_requests(m: u32) -> Weight {
+        // Base: 10 µs + 5 µs per write
+        Weight::from_parts(10_000_000u64.saturating_add((m as u64).saturating_mul(15_000_000)), 0)
+            .saturating_add(T::DbWeight::get().reads(3_u64.saturating_add(2 * m as u64)))
+            .saturating_add(T::DbWeight::get().writes(3_u64.saturating_add(3 * m as u64)))
+    }
+
+    /// Tip a verified batch
+    /// 
+    /// Storage: VaultCheck (r:1), VerifiedBatches (r:1), System::Account (r:2 w:2),
+    /// BatchLedger (r:1 w:1), AggregatorLedger (r:1 w:1)
+    /// Complexity: O(1)
+    fn tip_batch() -> Weight {
+        // Base: 45 µs (includes the currency transfer)
+        Weight::from_parts(45_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+
+    /// Claim rewards and tips
+    /// 
+    /// Storage: AggregatorLedger (r:1 w:1), System::Account (r:2 w:2)
+    /// Complexity: O(1)
+    fn claim_earnings() -> Weight {
+        // Base: 40 µs (includes the currency transfer)
+        Weight::from_parts(40_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+
+    /// Set Groth16 verifying key
+    /// 
+    /// Storage: Groth16Vk (r:0 w:1)
+    /// Complexity: O(1), plus curve and subgroup checks of the key's points
+    fn set_groth16_vk() -> Weight {
+        // Base: 2 ms (G2 subgroup checks)
+        Weight::from_parts(2_000_000_000, 0)
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    /// Groth16 check of a proof, charged on every submission
+    /// 
+    /// Storage: Groth16Vk (r:1 w:0)
+    /// Complexity: two 4-pair BN254 pairings (bincode and ABI public values)
+    fn verify_groth16_proof() -> Weight {
+        // Base: 8 ms per pairing check, as Frontier prices the bn128 precompile
+        Weight::from_parts(16_000_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+    }
+
+    /// Bond aggregator stake
+    /// 
+    /// Storage: AggregatorUnbonding (r:1), AggregatorStakes (r:1 w:1),
+    /// System::Account (r:1 w:1), Aggregators (r:1 w:1)
+    /// Complexity: O(1)
+    fn bond_aggregator() -> Weight {
+        // Base: 35 µs (includes the reserve)
+        Weight::from_parts(35_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+
+    /// Start unbonding aggregator stake
+    /// 
+    /// Storage: AggregatorUnbonding (r:1 w:1), AggregatorStakes (r:1 w:1),
+    /// Aggregators (r:1 w:1)
+    /// Complexity: O(1)
+    fn unbond_aggregator() -> Weight {
+        // Base: 25 µs
+        Weight::from_parts(25_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+
+    /// Withdraw unbonded aggregator stake
+    /// 
+    /// Storage: AggregatorUnbonding (r:1 w:1), System::Account (r:1 w:1),
+    /// SlashedAggregators (r:1), Aggregators (r:0 w:1)
+    /// Complexity: O(1)
+    fn withdraw_unbonded() -> Weight {
+        // Base: 35 µs (includes the unreserve)
+        Weight::from_parts(35_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+
+    /// Report an invalid proof, excluding the Groth16 check
+    /// 
+    /// Storage: VerifiedBatches (r:1), BatchRootsBlake2 (r:1), InvalidBatches (r:1 w:1),
+    /// Groth16Vk (r:1), AggregatorStakes (r:1 w:1), AggregatorUnbonding (r:1 w:1),
+    /// System::Account (r:1 w:1), Aggregators (r:1 w:1), SlashedAggregators (r:0 w:1)
+    /// Complexity: O(n) merkle roots, n = request count
+    fn report_invalid_proof(n: u32) -> Weight {
+        // Base: 60 µs (commitment hash and slash) + 20 µs per request (both roots)
+        Weight::from_parts(60_000_000u64.saturating_add((n as u64).saturating_mul(20_000_000)), 0)
+            .saturating_add(T::DbWeight::get().reads(8_u64))
+            .saturating_add(T::DbWeight::get().writes(6_u64))
+    }
+
+    /// Slash an aggregator (root)
+    /// 
+    /// Storage: Aggregators (r:1 w:1), AggregatorStakes (r:1 w:1),
+    /// AggregatorUnbonding (r:1 w:1), System::Account (r:1 w:1),
+    /// SlashedAggregators (r:0 w:1)
+    /// Complexity: O(1)
+    fn slash_aggregator() -> Weight {
+        // Base: 40 µs (includes the reserved balance slash)
+        Weight::from_parts(40_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(5_u64))
+    }
+
+    /// Prune an expired batch of r requests
+    /// 
+    /// Storage: PruneQueue (r:1 w:1), VerifiedBatches (r:1 w:1), BatchRequests (r:1 w:1),
+    /// BatchPrograms (r:1 w:1), VerifiedRequests or ProgramRequests (r:r w:r),
+    /// RequestOwners, RequestMessages (r:0 w:2r), RootToBatch (r:1 w:1),
+    /// BatchDeposits (r:1 w:1), System::Account (r:2 w:2), BatchRootsBlake2, BatchLedger,
+    /// PendingRequestMarks, PendingRequestOwners, PendingRequestMessages, BatchOwnersHashes,
+    /// BatchMessagesHashes, ProofCommitments, PrunedBatches (r:0 w:9)
+    /// Complexity: O(r)
+    fn prune_batch(r: u32) -> Weight {
+        // Base: 40 µs (includes the deposit release) + 5 µs per request
+        Weight::from_parts(40_000_000u64.saturating_add((r as u64).saturating_mul(5_000_000)), 0)
+            .saturating_add(T::DbWeight::get().reads(8_u64.saturating_add(r as u64)))
+            .saturating_add(T::DbWeight::get().writes(17_u64.saturating_add(3 * r as u64)))
+    }
+
+    /// Mark one request of a root-only batch verified from a depth-d inclusion proof
+    /// 
+    /// Storage: VerifiedBatches (r:1), BatchRootsBlake2 (r:1), BatchPrograms (r:1),
+    /// VerifiedRequests (r:1 w:1), ConsumedRequests (r:1), BatchRequests (r:1 w:1)
+    /// Complexity: O(d), d <= MAX_INCLUSION_PROOF_DEPTH
+    fn claim_request_verification(d: u32) -> Weight {
+        // Base: 25 µs + 5 µs per blake2 hash (the leaf and one per level)
+        Weight::from_parts(30_000_000u64.saturating_add((d as u64).saturating_mul(5_000_000)), 0)
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+
+    /// Switch root-only batches on or off
+    /// 
+    /// Storage: RootOnlyBatches (r:0 w:1)
+    /// Complexity: O(1)
+    fn set_root_only_batches() -> Weight {
+        // Base: 10 µs
+        Weight::from_parts(10_000_000, 0)
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    /// Commit to or clear an aggregator's server key hash
+    /// 
+    /// Storage: Aggregators (r:1 w:1)
+    /// Complexity: O(1)
+    fn set_server_key_hash() -> Weight {
+        // Base: 10 µs
+        Weight::from_parts(10_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+
+    /// Submit an aggregate proof of b root-only sub-batches (proof check excluded)
+    /// 
+    /// Storage: pause flag, Aggregators, era batches, threshold, expected vkey, counters
+    /// (r:7 w:3); per sub-batch: VerifiedBatches, PrunedBatches, claim, claim requirement,
+    /// ProofCommitments, RootToBatch, deposit account (r:7) and batch, last batch,
+    /// claim, commitment, block batches, root index, blake2 root, ledgers, deposit,
+    /// deposit account, queue entry, queue bounds (r:0 w:14)
+    /// Complexity: O(b), b <= MAX_AGGREGATED_BATCHES
+    fn submit_aggregate_proof(b: u32) -> Weight {
+        // Base: 60 µs + 30 µs per sub-batch (commitment hash, ABI encoding, writes)
+        Weight::from_parts(60_000_000u64.saturating_add((b as u64).saturating_mul(30_000_000)), 0)
+            .saturating_add(T::DbWeight::get().reads(7_u64.saturating_add(7 * b as u64)))
+            .saturating_add(T::DbWeight::get().writes(3_u64.saturating_add(14 * b as u64)))
     }
 }
 
@@ -103,9 +392,93 @@ impl WeightInfo for () {
         Weight::from_parts(20_000_000, 0)
     }
 
-    fn submit_proof(n: u32) -> Weight {
+    fn submit_proof(n: u32, _m: u32) -> Weight {
         let base = 100_000_000u64;
-        let per_request = 15_000_000u64; // 15 µs per request
+        let per_request = 25_000_000u64; // 25 µs per request
         Weight::from_parts(base + (n as u64 * per_request), 0)
     }
+
+    fn on_finalize_digest() -> Weight {
+        Weight::from_parts(10_000_000, 0)
+    }
+
+    fn set_cosign_threshold() -> Weight {
+        Weight::from_parts(10_000_000, 0)
+    }
+
+    fn verify_co_signatures(c: u32) -> Weight {
+        Weight::from_parts(c as u64 * 60_000_000, 0)
+    }
+
+    fn set_paused() -> Weight {
+        Weight::from_parts(10_000_000, 0)
+    }
+
+    fn claim_batch_id() -> Weight {
+        Weight::from_parts(20_000_000, 0)
+    }
+
+    fn set_require_claimed_batch_ids() -> Weight {
+        Weight::from_parts(10_000_000, 0)
+    }
+
+    fn mark_pending_requests(m: u32) -> Weight {
+        Weight::from_parts(10_000_000 + m as u64 * 5_000_000, 0)
+    }
+
+    fn tip_batch() -> Weight {
+        Weight::from_parts(45_000_000, 0)
+    }
+
+    fn claim_earnings() -> Weight {
+        Weight::from_parts(40_000_000, 0)
+    }
+
+    fn set_groth16_vk() -> Weight {
+        Weight::from_parts(2_000_000_000, 0)
+    }
+
+    fn verify_groth16_proof() -> Weight {
+        Weight::from_parts(16_000_000_000, 0)
+    }
+
+    fn bond_aggregator() -> Weight {
+        Weight::from_parts(35_000_000, 0)
+    }
+
+    fn unbond_aggregator() -> Weight {
+        Weight::from_parts(25_000_000, 0)
+    }
+
+    fn withdraw_unbonded() -> Weight {
+        Weight::from_parts(35_000_000, 0)
+    }
+
+    fn report_invalid_proof(n: u32) -> Weight {
+        Weight::from_parts(60_000_000 + n as u64 * 20_000_000, 0)
+    }
+
+    fn slash_aggregator() -> Weight {
+        Weight::from_parts(40_000_000, 0)
+    }
+
+    fn prune_batch(r: u32) -> Weight {
+        Weight::from_parts(40_000_000 + r as u64 * 5_000_000, 0)
+    }
+
+    fn claim_request_verification(d: u32) -> Weight {
+        Weight::from_parts(30_000_000 + d as u64 * 5_000_000, 0)
+    }
+
+    fn set_root_only_batches() -> Weight {
+        Weight::from_parts(10_000_000, 0)
+    }
+
+    fn set_server_key_hash() -> Weight {
+        Weight::from_parts(10_000_000, 0)
+    }
+
+    fn submit_aggregate_proof(b: u32) -> Weight {
+        Weight::from_parts(60_000_000 + b as u64 * 30_000_000, 0)
+    }
 }
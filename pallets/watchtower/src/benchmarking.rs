@@ -0,0 +1,111 @@
+//! Benchmarking setup for pallet-watchtower
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as Watchtower;
+use frame_benchmarking::v2::*;
+use frame_support::traits::Currency;
+use frame_system::RawOrigin;
+use pallet_reml_verifier::{BatchInfo, VerifiedBatches};
+use sp_core::H256;
+
+/// Funded, registered watchtower
+fn registered_watchtower<T: Config>() -> T::AccountId {
+    let who: T::AccountId = whitelisted_caller();
+    T::Currency::make_free_balance_be(&who, T::WatchtowerBond::get() * 10u32.into());
+    Watchtower::<T>::register(RawOrigin::Signed(who.clone()).into()).unwrap();
+    who
+}
+
+/// Verified batch by a registered aggregator, at the current block
+fn verified_batch<T: Config>(batch_id: u64) {
+    let aggregator: T::AccountId = account("aggregator", 0, 0);
+    if !pallet_reml_verifier::Pallet::<T>::is_aggregator(&aggregator) {
+        pallet_reml_verifier::Pallet::<T>::register_aggregator(
+            RawOrigin::Root.into(),
+            aggregator.clone(),
+        )
+        .unwrap();
+    }
+    VerifiedBatches::<T>::insert(
+        batch_id,
+        BatchInfo {
+            aggregator,
+            verified_at: frame_system::Pallet::<T>::block_number(),
+            signature_count: 1,
+            requests_root: [0u8; 32],
+            proof_commitment: [0u8; 32],
+        },
+    );
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn register() {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, T::WatchtowerBond::get() * 10u32.into());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()));
+
+        assert!(Watchtowers::<T>::contains_key(&caller));
+    }
+
+    #[benchmark]
+    fn unregister() {
+        let caller = registered_watchtower::<T>();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()));
+
+        assert!(!Watchtowers::<T>::contains_key(&caller));
+    }
+
+    #[benchmark]
+    fn attest(n: Linear<1, { T::MaxBatchesPerAttestation::get() }>) {
+        let caller = registered_watchtower::<T>();
+        let batch_ids: alloc::vec::Vec<u64> = (0..n as u64).collect();
+        for &batch_id in &batch_ids {
+            verified_batch::<T>(batch_id);
+        }
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller.clone()),
+            batch_ids.try_into().unwrap(),
+        );
+
+        assert_eq!(Watchtowers::<T>::get(&caller).unwrap().attestations, n);
+    }
+
+    #[benchmark]
+    fn raise_dispute() {
+        let caller = registered_watchtower::<T>();
+        verified_batch::<T>(1);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), 1, H256::zero());
+
+        assert!(Disputes::<T>::contains_key(1));
+    }
+
+    /// Worst case: rejected, slashing the watchtower
+    #[benchmark]
+    fn resolve_dispute() {
+        let caller = registered_watchtower::<T>();
+        verified_batch::<T>(1);
+        Watchtower::<T>::raise_dispute(RawOrigin::Signed(caller.clone()).into(), 1, H256::zero())
+            .unwrap();
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, 1, false);
+
+        assert!(!Watchtowers::<T>::contains_key(&caller));
+    }
+
+    impl_benchmark_test_suite!(Watchtower, crate::mock::new_test_ext(), crate::mock::Test);
+}
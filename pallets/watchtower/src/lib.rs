@@ -0,0 +1,393 @@
+//! # Tesserax Watchtower Pallet
+//!
+//! Early-warning layer around the Re-ML verifier. Independent watchtowers
+//! bond `WatchtowerBond`, re-verify recently accepted batches off-chain (fetch
+//! the proof bundle, re-run the checks) and report back:
+//!
+//! - `attest(batch_ids)`: the batches re-verified fine. Each attestation adds
+//!   to the batch aggregator's `Reputation::attestations` in
+//!   pallet-reml-verifier.
+//! - `raise_dispute(batch_id, evidence)`: the batch looks wrong. `AdminOrigin`
+//!   resolves the dispute; an upheld one counts against the aggregator
+//!   (`Reputation::upheld_disputes`), a rejected one slashes the watchtower's
+//!   bond and removes it, so disputes can't be spammed for free.
+//!
+//! Only batches verified within the last `AttestationWindow` blocks can be
+//! attested or disputed.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub use pallet::*;
+
+/// Weight implementations
+pub mod weights;
+pub use weights::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::{
+        pallet_prelude::*,
+        traits::{Currency, ReservableCurrency},
+    };
+    use frame_system::pallet_prelude::*;
+    use pallet_reml_verifier::VerifiedBatches;
+    use sp_core::H256;
+    use sp_runtime::traits::Saturating;
+
+    pub type BalanceOf<T> =
+        <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// Configuration trait for the watchtower pallet.
+    #[pallet::config]
+    pub trait Config: frame_system::Config + pallet_reml_verifier::Config {
+        /// Currency the bond is reserved in
+        type Currency: ReservableCurrency<Self::AccountId>;
+
+        /// Bond reserved while registered, slashed by a rejected dispute
+        #[pallet::constant]
+        type WatchtowerBond: Get<BalanceOf<Self>>;
+
+        /// Maximum number of registered watchtowers
+        #[pallet::constant]
+        type MaxWatchtowers: Get<u32>;
+
+        /// How many blocks after verification a batch can be attested or disputed
+        #[pallet::constant]
+        type AttestationWindow: Get<BlockNumberFor<Self>>;
+
+        /// Maximum batches in one `attest` call
+        #[pallet::constant]
+        type MaxBatchesPerAttestation: Get<u32>;
+
+        /// Origin that resolves disputes
+        type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Weight information
+        type WeightInfo: WeightInfo;
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // TYPES
+    // ═══════════════════════════════════════════════════════════════════════
+
+    /// Registered watchtower
+    #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+    pub struct WatchtowerInfo<BlockNumber, Balance> {
+        pub registered_at: BlockNumber,
+        pub bond: Balance,
+        pub attestations: u32,
+        pub open_disputes: u32,
+        pub last_attested_at: Option<BlockNumber>,
+    }
+
+    /// Open dispute against a verified batch
+    #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+    pub struct Dispute<AccountId, BlockNumber> {
+        pub watchtower: AccountId,
+        pub raised_at: BlockNumber,
+        /// Hash of the off-chain evidence, e.g. the failing proof bundle
+        pub evidence: H256,
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // STORAGE
+    // ═══════════════════════════════════════════════════════════════════════
+
+    /// Registered watchtowers
+    #[pallet::storage]
+    pub type Watchtowers<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        WatchtowerInfo<BlockNumberFor<T>, BalanceOf<T>>,
+        OptionQuery,
+    >;
+
+    /// Number of registered watchtowers
+    #[pallet::storage]
+    pub type WatchtowerCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Block at which a watchtower attested a batch
+    #[pallet::storage]
+    pub type Attestations<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        u64,
+        Blake2_128Concat,
+        T::AccountId,
+        BlockNumberFor<T>,
+        OptionQuery,
+    >;
+
+    /// Open disputes by batch ID
+    #[pallet::storage]
+    pub type Disputes<T: Config> =
+        StorageMap<_, Twox64Concat, u64, Dispute<T::AccountId, BlockNumberFor<T>>, OptionQuery>;
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // EVENTS
+    // ═══════════════════════════════════════════════════════════════════════
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        WatchtowerRegistered {
+            watchtower: T::AccountId,
+            bond: BalanceOf<T>,
+        },
+        WatchtowerUnregistered {
+            watchtower: T::AccountId,
+        },
+        /// Batches re-verified off-chain by `watchtower`
+        BatchesAttested {
+            watchtower: T::AccountId,
+            batch_ids: alloc::vec::Vec<u64>,
+        },
+        DisputeRaised {
+            watchtower: T::AccountId,
+            batch_id: u64,
+            aggregator: T::AccountId,
+            evidence: H256,
+        },
+        DisputeResolved {
+            batch_id: u64,
+            watchtower: T::AccountId,
+            upheld: bool,
+        },
+        /// Bond lost over a rejected dispute; the watchtower is removed
+        WatchtowerSlashed {
+            watchtower: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // ERRORS
+    // ═══════════════════════════════════════════════════════════════════════
+
+    #[pallet::error]
+    pub enum Error<T> {
+        AlreadyRegistered,
+        NotRegistered,
+        TooManyWatchtowers,
+        /// No verified batch with this ID
+        BatchNotFound,
+        /// Batch was verified more than `AttestationWindow` blocks ago
+        BatchTooOld,
+        AlreadyAttested,
+        DisputeAlreadyOpen,
+        DisputeNotFound,
+        /// Watchtower can't leave while its disputes are open
+        HasOpenDisputes,
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // EXTRINSICS
+    // ═══════════════════════════════════════════════════════════════════════
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Register the caller as a watchtower, reserving `WatchtowerBond`
+        #[pallet::call_index(0)]
+        #[pallet::weight(<T as Config>::WeightInfo::register())]
+        pub fn register(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(
+                !Watchtowers::<T>::contains_key(&who),
+                Error::<T>::AlreadyRegistered
+            );
+            let count = WatchtowerCount::<T>::get();
+            ensure!(
+                count < T::MaxWatchtowers::get(),
+                Error::<T>::TooManyWatchtowers
+            );
+
+            let bond = T::WatchtowerBond::get();
+            T::Currency::reserve(&who, bond)?;
+
+            Watchtowers::<T>::insert(
+                &who,
+                WatchtowerInfo {
+                    registered_at: frame_system::Pallet::<T>::block_number(),
+                    bond,
+                    attestations: 0,
+                    open_disputes: 0,
+                    last_attested_at: None,
+                },
+            );
+            WatchtowerCount::<T>::put(count + 1);
+
+            Self::deposit_event(Event::WatchtowerRegistered {
+                watchtower: who,
+                bond,
+            });
+            Ok(())
+        }
+
+        /// Leave and get the bond back; requires no open disputes
+        #[pallet::call_index(1)]
+        #[pallet::weight(<T as Config>::WeightInfo::unregister())]
+        pub fn unregister(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let info = Watchtowers::<T>::get(&who).ok_or(Error::<T>::NotRegistered)?;
+            ensure!(info.open_disputes == 0, Error::<T>::HasOpenDisputes);
+
+            T::Currency::unreserve(&who, info.bond);
+            Watchtowers::<T>::remove(&who);
+            WatchtowerCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+
+            Self::deposit_event(Event::WatchtowerUnregistered { watchtower: who });
+            Ok(())
+        }
+
+        /// Attest that `batch_ids` were re-verified off-chain
+        #[pallet::call_index(2)]
+        #[pallet::weight(<T as Config>::WeightInfo::attest(batch_ids.len() as u32))]
+        pub fn attest(
+            origin: OriginFor<T>,
+            batch_ids: BoundedVec<u64, T::MaxBatchesPerAttestation>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let mut info = Watchtowers::<T>::get(&who).ok_or(Error::<T>::NotRegistered)?;
+            let now = frame_system::Pallet::<T>::block_number();
+
+            for &batch_id in batch_ids.iter() {
+                let aggregator = Self::recent_batch_aggregator(batch_id, now)?;
+                ensure!(
+                    !Attestations::<T>::contains_key(batch_id, &who),
+                    Error::<T>::AlreadyAttested
+                );
+
+                Attestations::<T>::insert(batch_id, &who, now);
+                pallet_reml_verifier::Pallet::<T>::note_attestation(&aggregator);
+            }
+
+            info.attestations = info.attestations.saturating_add(batch_ids.len() as u32);
+            info.last_attested_at = Some(now);
+            Watchtowers::<T>::insert(&who, info);
+
+            Self::deposit_event(Event::BatchesAttested {
+                watchtower: who,
+                batch_ids: batch_ids.into_inner(),
+            });
+            Ok(())
+        }
+
+        /// Dispute a recently verified batch
+        #[pallet::call_index(3)]
+        #[pallet::weight(<T as Config>::WeightInfo::raise_dispute())]
+        pub fn raise_dispute(
+            origin: OriginFor<T>,
+            batch_id: u64,
+            evidence: H256,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let mut info = Watchtowers::<T>::get(&who).ok_or(Error::<T>::NotRegistered)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            let aggregator = Self::recent_batch_aggregator(batch_id, now)?;
+            ensure!(
+                !Disputes::<T>::contains_key(batch_id),
+                Error::<T>::DisputeAlreadyOpen
+            );
+
+            Disputes::<T>::insert(
+                batch_id,
+                Dispute {
+                    watchtower: who.clone(),
+                    raised_at: now,
+                    evidence,
+                },
+            );
+            info.open_disputes = info.open_disputes.saturating_add(1);
+            Watchtowers::<T>::insert(&who, info);
+
+            Self::deposit_event(Event::DisputeRaised {
+                watchtower: who,
+                batch_id,
+                aggregator,
+                evidence,
+            });
+            Ok(())
+        }
+
+        /// Close a dispute
+        ///
+        /// Upheld: counts against the batch's aggregator. Rejected: the
+        /// watchtower's bond is slashed and it is removed.
+        #[pallet::call_index(4)]
+        #[pallet::weight(<T as Config>::WeightInfo::resolve_dispute())]
+        pub fn resolve_dispute(
+            origin: OriginFor<T>,
+            batch_id: u64,
+            upheld: bool,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            let dispute = Disputes::<T>::take(batch_id).ok_or(Error::<T>::DisputeNotFound)?;
+            let watchtower = dispute.watchtower;
+
+            if upheld {
+                if let Some(batch) = VerifiedBatches::<T>::get(batch_id) {
+                    pallet_reml_verifier::Pallet::<T>::note_upheld_dispute(&batch.aggregator);
+                }
+                Watchtowers::<T>::mutate(&watchtower, |info| {
+                    if let Some(info) = info {
+                        info.open_disputes = info.open_disputes.saturating_sub(1);
+                    }
+                });
+            } else if let Some(info) = Watchtowers::<T>::take(&watchtower) {
+                let (_, unslashed) = T::Currency::slash_reserved(&watchtower, info.bond);
+                WatchtowerCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+                Self::deposit_event(Event::WatchtowerSlashed {
+                    watchtower: watchtower.clone(),
+                    amount: info.bond.saturating_sub(unslashed),
+                });
+            }
+
+            Self::deposit_event(Event::DisputeResolved {
+                batch_id,
+                watchtower,
+                upheld,
+            });
+            Ok(())
+        }
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // HELPER FUNCTIONS
+    // ═══════════════════════════════════════════════════════════════════════
+
+    impl<T: Config> Pallet<T> {
+        /// Aggregator of `batch_id`, if it was verified within `AttestationWindow`
+        fn recent_batch_aggregator(
+            batch_id: u64,
+            now: BlockNumberFor<T>,
+        ) -> Result<T::AccountId, DispatchError> {
+            let batch = VerifiedBatches::<T>::get(batch_id).ok_or(Error::<T>::BatchNotFound)?;
+            ensure!(
+                now.saturating_sub(batch.verified_at) <= T::AttestationWindow::get(),
+                Error::<T>::BatchTooOld
+            );
+            Ok(batch.aggregator)
+        }
+    }
+}
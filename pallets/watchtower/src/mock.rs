@@ -0,0 +1,142 @@
+//! Mock runtime for testing pallet-watchtower
+
+use frame_support::{
+    assert_ok, derive_impl, parameter_types,
+    traits::{ConstU32, ConstU64, Nothing},
+    PalletId,
+};
+use frame_system::EnsureRoot;
+use pallet_reml_verifier::{BatchInfo, VerifiedBatches};
+use sp_runtime::{traits::IdentityLookup, BuildStorage};
+
+use crate as pallet_watchtower;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime for testing
+frame_support::construct_runtime!(
+    pub enum Test {
+        System: frame_system,
+        Balances: pallet_balances,
+        RemlVerifier: pallet_reml_verifier,
+        Watchtower: pallet_watchtower,
+    }
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+    type AccountId = u64;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type AccountData = pallet_balances::AccountData<u64>;
+}
+
+impl pallet_balances::Config for Test {
+    type MaxLocks = ConstU32<50>;
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+    type Balance = u64;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ConstU64<1>;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type FreezeIdentifier = ();
+    type MaxFreezes = ConstU32<0>;
+    type RuntimeHoldReason = ();
+    type RuntimeFreezeReason = ();
+    type DoneSlashHandler = ();
+}
+
+impl pallet_reml_verifier::Config for Test {
+    type WeightInfo = ();
+    type AdminOrigin = EnsureRoot<u64>;
+    type MaxAggregators = ConstU32<4>;
+    type ExpectedVKeyHash = ExpectedVKeyHash;
+    type TransitionVKeyHash = ();
+    type ProgramVKeys = ();
+    type InlineRequestMarks = ConstU32<{ pallet_reml_verifier::MAX_VERIFIED_REQUESTS }>;
+    type ProofEpoch = ConstU32<1>;
+    type OnBatchVerified = ();
+    type CoSignature = sp_runtime::testing::TestSignature;
+    type RewardCurrency = Balances;
+    type VaultCheck = Nothing;
+    type BatchReward = ConstU64<0>;
+    type RewardPotId = RewardPotId;
+    type EraLength = ConstU64<14_400>;
+    type MaxBatchesPerEra = ConstU32<{ u32::MAX }>;
+    type AggregatorBond = ConstU64<0>;
+    type UnbondingPeriod = ConstU64<0>;
+    type Slash = ();
+    type ProofRetentionPeriod = ConstU64<{ u64::MAX }>;
+    type MaxAnchorAge = ConstU64<10>;
+    type SubmissionDeposit = ConstU64<0>;
+    type PrunerShare = PrunerShare;
+}
+
+/// Registered Re-ML aggregator
+pub const AGGREGATOR: u64 = 1;
+/// Registered watchtowers
+pub const TOWER: u64 = 10;
+pub const OTHER_TOWER: u64 = 11;
+/// Funded, not registered
+pub const OUTSIDER: u64 = 12;
+
+pub const BOND: u64 = 100;
+pub const WINDOW: u64 = 50;
+
+parameter_types! {
+    pub const ExpectedVKeyHash: [u8; 32] = [0u8; 32];
+    pub const RewardPotId: PalletId = PalletId(*b"reml/pot");
+    pub const PrunerShare: sp_runtime::Perbill = sp_runtime::Perbill::zero();
+}
+
+impl pallet_watchtower::Config for Test {
+    type Currency = Balances;
+    type WatchtowerBond = ConstU64<BOND>;
+    type MaxWatchtowers = ConstU32<3>;
+    type AttestationWindow = ConstU64<WINDOW>;
+    type MaxBatchesPerAttestation = ConstU32<8>;
+    type AdminOrigin = EnsureRoot<u64>;
+    type WeightInfo = ();
+}
+
+/// Record a verified batch by `AGGREGATOR` at the current block
+pub fn verify_batch(batch_id: u64) {
+    VerifiedBatches::<Test>::insert(
+        batch_id,
+        BatchInfo {
+            aggregator: AGGREGATOR,
+            verified_at: System::block_number(),
+            signature_count: 4,
+            requests_root: [batch_id as u8; 32],
+            proof_commitment: [0u8; 32],
+        },
+    );
+}
+
+/// Block 1, `AGGREGATOR` registered, `TOWER` and `OTHER_TOWER` bonded
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(TOWER, 1_000), (OTHER_TOWER, 1_000), (OUTSIDER, 1_000)],
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(RemlVerifier::register_aggregator(
+            RuntimeOrigin::root(),
+            AGGREGATOR
+        ));
+        assert_ok!(Watchtower::register(RuntimeOrigin::signed(TOWER)));
+        assert_ok!(Watchtower::register(RuntimeOrigin::signed(OTHER_TOWER)));
+    });
+    ext
+}
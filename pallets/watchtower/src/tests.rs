@@ -0,0 +1,200 @@
+//! Unit tests for pallet-watchtower
+
+use crate::{mock::*, Attestations, Disputes, Error, Event, WatchtowerCount, Watchtowers};
+use frame_support::{assert_noop, assert_ok};
+use pallet_reml_verifier::Aggregators;
+use sp_core::H256;
+use sp_runtime::DispatchError;
+
+fn reputation() -> pallet_reml_verifier::Reputation {
+    Aggregators::<Test>::get(AGGREGATOR).unwrap().reputation
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// REGISTRATION TESTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn register_reserves_bond() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(Balances::reserved_balance(TOWER), BOND);
+        assert_eq!(WatchtowerCount::<Test>::get(), 2);
+        assert_noop!(
+            Watchtower::register(RuntimeOrigin::signed(TOWER)),
+            Error::<Test>::AlreadyRegistered
+        );
+
+        // MaxWatchtowers = 3
+        assert_ok!(Watchtower::register(RuntimeOrigin::signed(OUTSIDER)));
+        assert_noop!(
+            Watchtower::register(RuntimeOrigin::signed(OUTSIDER + 1)),
+            Error::<Test>::TooManyWatchtowers
+        );
+    });
+}
+
+#[test]
+fn unregister_returns_bond() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Watchtower::unregister(RuntimeOrigin::signed(TOWER)));
+
+        assert_eq!(Balances::reserved_balance(TOWER), 0);
+        assert!(!Watchtowers::<Test>::contains_key(TOWER));
+        assert_eq!(WatchtowerCount::<Test>::get(), 1);
+        System::assert_last_event(Event::WatchtowerUnregistered { watchtower: TOWER }.into());
+    });
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// ATTESTATION TESTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn attestations_feed_aggregator_reputation() {
+    new_test_ext().execute_with(|| {
+        verify_batch(1);
+        verify_batch(2);
+
+        assert_ok!(Watchtower::attest(
+            RuntimeOrigin::signed(TOWER),
+            vec![1, 2].try_into().unwrap()
+        ));
+        assert_ok!(Watchtower::attest(
+            RuntimeOrigin::signed(OTHER_TOWER),
+            vec![1].try_into().unwrap()
+        ));
+
+        assert_eq!(reputation().attestations, 3);
+        assert_eq!(Attestations::<Test>::get(1, TOWER), Some(1));
+        let info = Watchtowers::<Test>::get(TOWER).unwrap();
+        assert_eq!(info.attestations, 2);
+        assert_eq!(info.last_attested_at, Some(1));
+        System::assert_last_event(
+            Event::BatchesAttested {
+                watchtower: OTHER_TOWER,
+                batch_ids: vec![1],
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn attest_rejects_unknown_stale_and_repeated_batches() {
+    new_test_ext().execute_with(|| {
+        verify_batch(1);
+        let attest = |ids: Vec<u64>| {
+            Watchtower::attest(RuntimeOrigin::signed(TOWER), ids.try_into().unwrap())
+        };
+
+        assert_noop!(attest(vec![9]), Error::<Test>::BatchNotFound);
+        assert_noop!(
+            Watchtower::attest(RuntimeOrigin::signed(OUTSIDER), vec![1].try_into().unwrap()),
+            Error::<Test>::NotRegistered
+        );
+
+        assert_ok!(attest(vec![1]));
+        assert_noop!(attest(vec![1]), Error::<Test>::AlreadyAttested);
+
+        System::set_block_number(1 + WINDOW + 1);
+        verify_batch(2);
+        assert_noop!(
+            Watchtower::attest(
+                RuntimeOrigin::signed(OTHER_TOWER),
+                vec![1].try_into().unwrap()
+            ),
+            Error::<Test>::BatchTooOld
+        );
+        assert_ok!(attest(vec![2]));
+    });
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// DISPUTE TESTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn upheld_dispute_counts_against_aggregator() {
+    new_test_ext().execute_with(|| {
+        verify_batch(1);
+        let evidence = H256::repeat_byte(0xEE);
+
+        assert_ok!(Watchtower::raise_dispute(
+            RuntimeOrigin::signed(TOWER),
+            1,
+            evidence
+        ));
+        System::assert_last_event(
+            Event::DisputeRaised {
+                watchtower: TOWER,
+                batch_id: 1,
+                aggregator: AGGREGATOR,
+                evidence,
+            }
+            .into(),
+        );
+        assert_noop!(
+            Watchtower::raise_dispute(RuntimeOrigin::signed(OTHER_TOWER), 1, evidence),
+            Error::<Test>::DisputeAlreadyOpen
+        );
+        assert_noop!(
+            Watchtower::unregister(RuntimeOrigin::signed(TOWER)),
+            Error::<Test>::HasOpenDisputes
+        );
+
+        assert_ok!(Watchtower::resolve_dispute(RuntimeOrigin::root(), 1, true));
+
+        assert_eq!(reputation().upheld_disputes, 1);
+        assert!(!Disputes::<Test>::contains_key(1));
+        assert_eq!(Watchtowers::<Test>::get(TOWER).unwrap().open_disputes, 0);
+        assert_eq!(Balances::reserved_balance(TOWER), BOND);
+    });
+}
+
+#[test]
+fn rejected_dispute_slashes_watchtower() {
+    new_test_ext().execute_with(|| {
+        verify_batch(1);
+        assert_ok!(Watchtower::raise_dispute(
+            RuntimeOrigin::signed(TOWER),
+            1,
+            H256::zero()
+        ));
+
+        assert_ok!(Watchtower::resolve_dispute(RuntimeOrigin::root(), 1, false));
+
+        assert_eq!(reputation().upheld_disputes, 0);
+        assert!(!Watchtowers::<Test>::contains_key(TOWER));
+        assert_eq!(WatchtowerCount::<Test>::get(), 1);
+        assert_eq!(Balances::reserved_balance(TOWER), 0);
+        assert_eq!(Balances::free_balance(TOWER), 1_000 - BOND);
+        System::assert_has_event(
+            Event::WatchtowerSlashed {
+                watchtower: TOWER,
+                amount: BOND,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn resolve_dispute_requires_admin() {
+    new_test_ext().execute_with(|| {
+        verify_batch(1);
+        assert_ok!(Watchtower::raise_dispute(
+            RuntimeOrigin::signed(TOWER),
+            1,
+            H256::zero()
+        ));
+
+        assert_noop!(
+            Watchtower::resolve_dispute(RuntimeOrigin::signed(OTHER_TOWER), 1, false),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Watchtower::resolve_dispute(RuntimeOrigin::root(), 2, true),
+            Error::<Test>::DisputeNotFound
+        );
+    });
+}
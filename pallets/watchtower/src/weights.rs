@@ -0,0 +1,79 @@
+//! Weight information for pallet-watchtower
+//!
+//! In production, these should be generated using frame-benchmarking.
+
+use frame_support::pallet_prelude::Get;
+use frame_support::weights::Weight;
+
+/// Weight functions needed for pallet-watchtower
+pub trait WeightInfo {
+    fn register() -> Weight;
+    fn unregister() -> Weight;
+    fn attest(n: u32) -> Weight;
+    fn raise_dispute() -> Weight;
+    fn resolve_dispute() -> Weight;
+}
+
+/// Production weight implementations
+pub struct SubstrateWeight<T>(core::marker::PhantomData<T>);
+
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    /// Watchtowers, count, reserve
+    fn register() -> Weight {
+        Weight::from_parts(30_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+
+    /// Watchtowers, count, unreserve
+    fn unregister() -> Weight {
+        Weight::from_parts(30_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+
+    /// Per batch: batch, attestation, aggregator (r:3 w:2)
+    fn attest(n: u32) -> Weight {
+        Weight::from_parts(20_000_000, 0)
+            .saturating_add(Weight::from_parts(15_000_000, 0).saturating_mul(n as u64))
+            .saturating_add(T::DbWeight::get().reads(1 + 3 * n as u64))
+            .saturating_add(T::DbWeight::get().writes(1 + 2 * n as u64))
+    }
+
+    /// Watchtowers, batch, dispute
+    fn raise_dispute() -> Weight {
+        Weight::from_parts(25_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+
+    /// Dispute, batch, aggregator or watchtower, count, reserve
+    fn resolve_dispute() -> Weight {
+        Weight::from_parts(35_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().writes(4))
+    }
+}
+
+/// Unit testing weight implementations
+impl WeightInfo for () {
+    fn register() -> Weight {
+        Weight::from_parts(30_000_000, 0)
+    }
+
+    fn unregister() -> Weight {
+        Weight::from_parts(30_000_000, 0)
+    }
+
+    fn attest(n: u32) -> Weight {
+        Weight::from_parts(20_000_000 + 15_000_000 * n as u64, 0)
+    }
+
+    fn raise_dispute() -> Weight {
+        Weight::from_parts(25_000_000, 0)
+    }
+
+    fn resolve_dispute() -> Weight {
+        Weight::from_parts(35_000_000, 0)
+    }
+}
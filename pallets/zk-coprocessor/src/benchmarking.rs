@@ -0,0 +1,103 @@
+//! Benchmarking setup for pallet-zk-coprocessor
+//!
+//! `fulfill_request` is not benchmarked: it needs a proof the runtime's
+//! verifier accepts, and its weight adds `ProofVerifier::weight()` anyway.
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as ZkCoprocessor;
+use frame_benchmarking::v2::*;
+use frame_support::{
+    traits::{Currency, Get},
+    BoundedVec,
+};
+use frame_system::RawOrigin;
+use pallet_program_registry::{ProgramVersion, ProgramVersions};
+
+const PROGRAM: pallet_program_registry::ProgramId = 1_000;
+
+/// Register `PROGRAM`, active now
+fn register_program<T: Config>() {
+    let version = ProgramVersion {
+        version: 1,
+        vkey_hash: [1; 32],
+        elf_hash: [1; 32],
+        activation_block: frame_system::Pallet::<T>::block_number(),
+    };
+    ProgramVersions::<T>::insert(PROGRAM, BoundedVec::truncate_from(alloc::vec![version]));
+}
+
+/// Fund `who` for a few requests
+fn fund<T: Config>(who: &T::AccountId) {
+    let per_request = T::RequestDeposit::get() + T::Currency::minimum_balance();
+    T::Currency::make_free_balance_be(who, per_request * 10u32.into());
+}
+
+/// Funded caller with a pending request of the largest input
+fn pending_request<T: Config>() -> (T::AccountId, u64) {
+    register_program::<T>();
+    let caller: T::AccountId = whitelisted_caller();
+    fund::<T>(&caller);
+    let input = alloc::vec![0u8; T::MaxInputSize::get() as usize];
+    let request_id =
+        ZkCoprocessor::<T>::do_submit_request(caller.clone(), PROGRAM, input, 1u32.into()).unwrap();
+    (caller, request_id)
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn submit_request(n: Linear<0, { T::MaxInputSize::get() }>) {
+        register_program::<T>();
+        let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), PROGRAM, alloc::vec![0u8; n as usize], 1u32.into());
+
+        assert!(Requests::<T>::contains_key(0));
+    }
+
+    #[benchmark]
+    fn cancel_request() {
+        let (caller, request_id) = pending_request::<T>();
+        let expires_at = Requests::<T>::get(request_id).unwrap().expires_at;
+        frame_system::Pallet::<T>::set_block_number(expires_at + 1u32.into());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), request_id);
+
+        assert!(!Requests::<T>::contains_key(request_id));
+    }
+
+    #[benchmark]
+    fn clear_result() {
+        let (caller, request_id) = pending_request::<T>();
+        let request = Requests::<T>::take(request_id).unwrap();
+        Results::<T>::insert(
+            request_id,
+            ComputeResult {
+                requester: caller.clone(),
+                program_id: PROGRAM,
+                program_version: 1,
+                prover: caller.clone(),
+                output: BoundedVec::truncate_from(alloc::vec![
+                    0u8;
+                    T::MaxOutputSize::get() as usize
+                ]),
+                deposit: request.deposit,
+                fulfilled_at: frame_system::Pallet::<T>::block_number(),
+            },
+        );
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), request_id);
+
+        assert!(!Results::<T>::contains_key(request_id));
+    }
+
+    impl_benchmark_test_suite!(ZkCoprocessor, crate::mock::new_test_ext(), crate::mock::Test);
+}
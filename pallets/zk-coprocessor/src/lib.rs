@@ -0,0 +1,445 @@
+//! # Tesserax ZK-Coprocessor Pallet
+//!
+//! Proven off-chain computation, generalizing the Re-ML pipeline beyond
+//! signature batches. Anyone (or any contract, through the coprocessor
+//! precompiles) posts a request naming a guest program from the program
+//! registry and its input; a prover runs the program in SP1 and returns the
+//! output with a proof; once the proof checks out against the program's
+//! active vkey hash, the output is stored on-chain for Substrate and EVM
+//! consumers.
+//!
+//! ## Request Lifecycle
+//!
+//! 1. `submit_request(program_id, input, bounty)` reserves `bounty` plus
+//!    `RequestDeposit` from the requester. The program must have an active
+//!    version.
+//! 2. Within `RequestTimeout` blocks, a prover in `Provers` calls
+//!    `fulfill_request(request_id, output, proof)`. The bounty goes to the
+//!    prover and the output is stored in `Results`.
+//! 3. The requester reads the result and calls `clear_result` to get the
+//!    deposit back, or calls `cancel_request` once an unfulfilled request
+//!    expired to get both back.
+//!
+//! ## Public Values
+//!
+//! Guest programs must commit [`public_values`] of the request and their
+//! output: `COPROCESSOR_CONTEXT || request_id || keccak256(input) || output`,
+//! the request ID as a big-endian `u64`. This binds a proof to one request,
+//! so it can't be replayed for another request with the same input.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+pub use pallet::*;
+
+/// Weight implementations
+pub mod weights;
+pub use weights::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+/// Domain separator at the start of a request's public values
+pub const COPROCESSOR_CONTEXT: &[u8] = b"tesserax/zk-coprocessor/v1:";
+
+/// Maximum size of a fulfillment proof (an SP1 Groth16 proof is 260 bytes)
+pub const MAX_PROOF_SIZE: u32 = 1_024;
+
+/// Public values a guest proving `request_id` on `input` must commit
+pub fn public_values(request_id: u64, input: &[u8], output: &[u8]) -> Vec<u8> {
+    let mut values = Vec::with_capacity(COPROCESSOR_CONTEXT.len() + 8 + 32 + output.len());
+    values.extend_from_slice(COPROCESSOR_CONTEXT);
+    values.extend_from_slice(&request_id.to_be_bytes());
+    values.extend_from_slice(&sp_core::hashing::keccak_256(input));
+    values.extend_from_slice(output);
+    values
+}
+
+/// Checks zkVM proofs of a program
+pub trait ProofVerifier {
+    /// Whether `proof` proves a run of the program with `vkey_hash` that
+    /// committed `public_values`
+    fn verify(vkey_hash: &[u8; 32], public_values: &[u8], proof: &[u8]) -> bool;
+
+    /// Worst-case weight of `verify`
+    fn weight() -> frame_support::weights::Weight;
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::{
+        pallet_prelude::*,
+        traits::{BalanceStatus, Contains, Currency, ReservableCurrency},
+    };
+    use frame_system::pallet_prelude::*;
+    use pallet_program_registry::ProgramId;
+    use sp_runtime::traits::{Saturating, Zero};
+
+    pub type BalanceOf<T> =
+        <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// Configuration trait for the ZK-coprocessor pallet.
+    #[pallet::config]
+    pub trait Config: frame_system::Config + pallet_program_registry::Config {
+        /// Currency bounties and deposits are reserved in
+        type Currency: ReservableCurrency<Self::AccountId>;
+
+        /// Accounts allowed to fulfill requests
+        type Provers: Contains<Self::AccountId>;
+
+        /// Vault accounts, which may only move funds with a Dilithium signature
+        ///
+        /// They can't submit requests: a prover the vault's classical key
+        /// controls could fulfill one and collect its bounty.
+        type VaultCheck: Contains<Self::AccountId>;
+
+        /// Checks fulfillment proofs
+        type ProofVerifier: ProofVerifier;
+
+        /// Reserved from the requester until the result is cleared
+        #[pallet::constant]
+        type RequestDeposit: Get<BalanceOf<Self>>;
+
+        /// Blocks a request can be fulfilled in before it can be cancelled
+        #[pallet::constant]
+        type RequestTimeout: Get<BlockNumberFor<Self>>;
+
+        /// Maximum size of a request's input
+        #[pallet::constant]
+        type MaxInputSize: Get<u32>;
+
+        /// Maximum size of a request's output
+        #[pallet::constant]
+        type MaxOutputSize: Get<u32>;
+
+        /// Weight information
+        type WeightInfo: WeightInfo;
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // TYPES
+    // ═══════════════════════════════════════════════════════════════════════
+
+    /// A computation waiting for a proof
+    #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+    pub struct ComputeRequest<AccountId, Balance, BlockNumber, Input> {
+        pub requester: AccountId,
+        pub program_id: ProgramId,
+        pub input: Input,
+        /// Paid to the prover that fulfills the request
+        pub bounty: Balance,
+        /// Reserved until the result is cleared
+        pub deposit: Balance,
+        /// Last block the request can be fulfilled in
+        pub expires_at: BlockNumber,
+    }
+
+    /// Proven output of a fulfilled request
+    #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+    pub struct ComputeResult<AccountId, Balance, BlockNumber, Output> {
+        pub requester: AccountId,
+        pub program_id: ProgramId,
+        /// Program version the proof was checked against
+        pub program_version: u32,
+        pub prover: AccountId,
+        pub output: Output,
+        /// Returned to the requester by `clear_result`
+        pub deposit: Balance,
+        pub fulfilled_at: BlockNumber,
+    }
+
+    pub type ComputeRequestOf<T> = ComputeRequest<
+        <T as frame_system::Config>::AccountId,
+        BalanceOf<T>,
+        BlockNumberFor<T>,
+        BoundedVec<u8, <T as Config>::MaxInputSize>,
+    >;
+
+    pub type ComputeResultOf<T> = ComputeResult<
+        <T as frame_system::Config>::AccountId,
+        BalanceOf<T>,
+        BlockNumberFor<T>,
+        BoundedVec<u8, <T as Config>::MaxOutputSize>,
+    >;
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // STORAGE
+    // ═══════════════════════════════════════════════════════════════════════
+
+    /// ID of the next request
+    #[pallet::storage]
+    pub type NextRequestId<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// Requests waiting for a proof
+    #[pallet::storage]
+    pub type Requests<T: Config> =
+        StorageMap<_, Twox64Concat, u64, ComputeRequestOf<T>, OptionQuery>;
+
+    /// Results of fulfilled requests, until their requester clears them
+    #[pallet::storage]
+    pub type Results<T: Config> = StorageMap<_, Twox64Concat, u64, ComputeResultOf<T>, OptionQuery>;
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // EVENTS
+    // ═══════════════════════════════════════════════════════════════════════
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// `requester` asked for a proven run of `program_id`
+        RequestSubmitted {
+            request_id: u64,
+            requester: T::AccountId,
+            program_id: ProgramId,
+            input_hash: [u8; 32],
+            bounty: BalanceOf<T>,
+            expires_at: BlockNumberFor<T>,
+        },
+        /// `prover` proved the output of `request_id` and earned its bounty
+        RequestFulfilled {
+            request_id: u64,
+            prover: T::AccountId,
+            program_version: u32,
+            output_hash: [u8; 32],
+        },
+        /// Expired `request_id` was withdrawn by its requester
+        RequestCancelled { request_id: u64 },
+        /// Result of `request_id` was removed by its requester
+        ResultCleared { request_id: u64 },
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // ERRORS
+    // ═══════════════════════════════════════════════════════════════════════
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Program has no active version in the program registry
+        UnknownProgram,
+        /// Input is larger than `MaxInputSize`
+        InputTooLarge,
+        /// Output is larger than `MaxOutputSize`
+        OutputTooLarge,
+        /// No pending request with this ID
+        UnknownRequest,
+        /// Request expired and can only be cancelled
+        RequestExpired,
+        /// Request can still be fulfilled
+        RequestNotExpired,
+        /// Caller is not the requester
+        NotRequester,
+        /// Caller is not in `Provers`
+        NotProver,
+        /// Vault accounts can't pay bounties, which leave without a Dilithium
+        /// signature
+        VaultRequester,
+        /// Proof does not prove the output for this request
+        ProofVerificationFailed,
+        /// No result stored for this ID
+        UnknownResult,
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // EXTRINSICS
+    // ═══════════════════════════════════════════════════════════════════════
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Request a proven run of `program_id` on `input`
+        ///
+        /// Reserves `bounty` plus `RequestDeposit`; the request can be
+        /// fulfilled for `RequestTimeout` blocks. Vault accounts can't submit
+        /// requests.
+        #[pallet::call_index(0)]
+        #[pallet::weight(<T as Config>::WeightInfo::submit_request(input.len() as u32))]
+        pub fn submit_request(
+            origin: OriginFor<T>,
+            program_id: ProgramId,
+            input: Vec<u8>,
+            bounty: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_submit_request(who, program_id, input, bounty).map(|_| ())
+        }
+
+        /// Fulfill `request_id` with the program's `output` and its `proof`
+        ///
+        /// The proof is checked against the program's active version and
+        /// [`public_values`] of the request and `output`.
+        ///
+        /// # Errors
+        /// * `NotProver` - Caller is not in `Provers`
+        /// * `RequestExpired` - Request is past its `expires_at`
+        /// * `UnknownProgram` - Program has no active version anymore
+        /// * `ProofVerificationFailed` - Proof does not check out
+        #[pallet::call_index(1)]
+        #[pallet::weight(
+            <T as Config>::WeightInfo::fulfill_request(output.len() as u32)
+                .saturating_add(T::ProofVerifier::weight())
+        )]
+        pub fn fulfill_request(
+            origin: OriginFor<T>,
+            request_id: u64,
+            output: Vec<u8>,
+            proof: BoundedVec<u8, ConstU32<MAX_PROOF_SIZE>>,
+        ) -> DispatchResult {
+            let prover = ensure_signed(origin)?;
+            ensure!(T::Provers::contains(&prover), Error::<T>::NotProver);
+
+            let request = Requests::<T>::get(request_id).ok_or(Error::<T>::UnknownRequest)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(now <= request.expires_at, Error::<T>::RequestExpired);
+
+            let output: BoundedVec<u8, T::MaxOutputSize> =
+                output.try_into().map_err(|_| Error::<T>::OutputTooLarge)?;
+            let program = pallet_program_registry::Pallet::<T>::active_version(request.program_id)
+                .ok_or(Error::<T>::UnknownProgram)?;
+
+            let values = public_values(request_id, &request.input, &output);
+            ensure!(
+                T::ProofVerifier::verify(&program.vkey_hash, &values, &proof),
+                Error::<T>::ProofVerificationFailed
+            );
+
+            let deficit = T::Currency::repatriate_reserved(
+                &request.requester,
+                &prover,
+                request.bounty,
+                BalanceStatus::Free,
+            )?;
+            debug_assert!(deficit.is_zero(), "bounty reserved on submission");
+
+            let output_hash = sp_core::hashing::keccak_256(&output);
+            Requests::<T>::remove(request_id);
+            Results::<T>::insert(
+                request_id,
+                ComputeResult {
+                    requester: request.requester,
+                    program_id: request.program_id,
+                    program_version: program.version,
+                    prover: prover.clone(),
+                    output,
+                    deposit: request.deposit,
+                    fulfilled_at: now,
+                },
+            );
+
+            Self::deposit_event(Event::RequestFulfilled {
+                request_id,
+                prover,
+                program_version: program.version,
+                output_hash,
+            });
+            Ok(())
+        }
+
+        /// Withdraw an expired, unfulfilled request, unreserving its bounty
+        /// and deposit
+        #[pallet::call_index(2)]
+        #[pallet::weight(<T as Config>::WeightInfo::cancel_request())]
+        pub fn cancel_request(origin: OriginFor<T>, request_id: u64) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let request = Requests::<T>::get(request_id).ok_or(Error::<T>::UnknownRequest)?;
+            ensure!(request.requester == who, Error::<T>::NotRequester);
+            ensure!(
+                frame_system::Pallet::<T>::block_number() > request.expires_at,
+                Error::<T>::RequestNotExpired
+            );
+
+            T::Currency::unreserve(&who, request.bounty.saturating_add(request.deposit));
+            Requests::<T>::remove(request_id);
+
+            Self::deposit_event(Event::RequestCancelled { request_id });
+            Ok(())
+        }
+
+        /// Remove the result of `request_id`, unreserving its deposit
+        #[pallet::call_index(3)]
+        #[pallet::weight(<T as Config>::WeightInfo::clear_result())]
+        pub fn clear_result(origin: OriginFor<T>, request_id: u64) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let result = Results::<T>::get(request_id).ok_or(Error::<T>::UnknownResult)?;
+            ensure!(result.requester == who, Error::<T>::NotRequester);
+
+            T::Currency::unreserve(&who, result.deposit);
+            Results::<T>::remove(request_id);
+
+            Self::deposit_event(Event::ResultCleared { request_id });
+            Ok(())
+        }
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // HELPER FUNCTIONS
+    // ═══════════════════════════════════════════════════════════════════════
+
+    impl<T: Config> Pallet<T> {
+        /// Submit a request for `requester`, returning its ID
+        ///
+        /// Shared by `submit_request` and the EVM precompile, which submits
+        /// on behalf of the calling contract.
+        pub fn do_submit_request(
+            requester: T::AccountId,
+            program_id: ProgramId,
+            input: Vec<u8>,
+            bounty: BalanceOf<T>,
+        ) -> Result<u64, DispatchError> {
+            ensure!(!T::VaultCheck::contains(&requester), Error::<T>::VaultRequester);
+            let input: BoundedVec<u8, T::MaxInputSize> =
+                input.try_into().map_err(|_| Error::<T>::InputTooLarge)?;
+            ensure!(
+                pallet_program_registry::Pallet::<T>::active_version(program_id).is_some(),
+                Error::<T>::UnknownProgram
+            );
+
+            let deposit = T::RequestDeposit::get();
+            T::Currency::reserve(&requester, bounty.saturating_add(deposit))?;
+
+            let request_id = NextRequestId::<T>::mutate(|next| {
+                let id = *next;
+                *next = next.saturating_add(1);
+                id
+            });
+            let expires_at =
+                frame_system::Pallet::<T>::block_number().saturating_add(T::RequestTimeout::get());
+            let input_hash = sp_core::hashing::keccak_256(&input);
+
+            Requests::<T>::insert(
+                request_id,
+                ComputeRequest {
+                    requester: requester.clone(),
+                    program_id,
+                    input,
+                    bounty,
+                    deposit,
+                    expires_at,
+                },
+            );
+
+            Self::deposit_event(Event::RequestSubmitted {
+                request_id,
+                requester,
+                program_id,
+                input_hash,
+                bounty,
+                expires_at,
+            });
+            Ok(request_id)
+        }
+    }
+}
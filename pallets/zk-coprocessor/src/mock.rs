@@ -0,0 +1,139 @@
+//! Mock runtime for testing pallet-zk-coprocessor
+
+use frame_support::{
+    assert_ok, derive_impl, parameter_types,
+    traits::{ConstU32, ConstU64, IsInVec},
+    weights::Weight,
+    BoundedVec,
+};
+use frame_system::EnsureRoot;
+use pallet_program_registry::ProgramId;
+use sp_runtime::{traits::IdentityLookup, BuildStorage};
+
+use crate as pallet_zk_coprocessor;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime for testing
+frame_support::construct_runtime!(
+    pub enum Test {
+        System: frame_system,
+        Balances: pallet_balances,
+        ProgramRegistry: pallet_program_registry,
+        ZkCoprocessor: pallet_zk_coprocessor,
+    }
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+    type AccountId = u64;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type AccountData = pallet_balances::AccountData<u64>;
+}
+
+impl pallet_balances::Config for Test {
+    type MaxLocks = ConstU32<50>;
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+    type Balance = u64;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ConstU64<1>;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type FreezeIdentifier = ();
+    type MaxFreezes = ConstU32<0>;
+    type RuntimeHoldReason = ();
+    type RuntimeFreezeReason = ();
+    type DoneSlashHandler = ();
+}
+
+impl pallet_program_registry::Config for Test {
+    type AdminOrigin = EnsureRoot<u64>;
+    type MaxVersions = ConstU32<4>;
+    type TransitionPeriod = ConstU64<0>;
+    type WeightInfo = ();
+}
+
+/// Funded requester
+pub const REQUESTER: u64 = 1;
+/// Funded, not a prover
+pub const OUTSIDER: u64 = 2;
+/// Allowed prover, no balance
+pub const PROVER: u64 = 10;
+/// Funded vault account
+pub const VAULT: u64 = 3;
+
+/// Registered program and its vkey hash
+pub const PROGRAM: ProgramId = 7;
+pub const VKEY: [u8; 32] = [7; 32];
+
+pub const DEPOSIT: u64 = 10;
+pub const TIMEOUT: u64 = 20;
+pub const MAX_INPUT: u32 = 64;
+pub const MAX_OUTPUT: u32 = 32;
+
+parameter_types! {
+    pub Provers: Vec<u64> = vec![PROVER];
+    pub Vaults: Vec<u64> = vec![VAULT];
+}
+
+/// Accepts `proof(vkey_hash, public_values)`
+pub struct MockVerifier;
+impl crate::ProofVerifier for MockVerifier {
+    fn verify(vkey_hash: &[u8; 32], public_values: &[u8], proof: &[u8]) -> bool {
+        proof == prove(vkey_hash, public_values).as_slice()
+    }
+
+    fn weight() -> Weight {
+        Weight::from_parts(1_000_000, 0)
+    }
+}
+
+/// The proof `MockVerifier` accepts
+pub fn prove(
+    vkey_hash: &[u8; 32],
+    public_values: &[u8],
+) -> BoundedVec<u8, ConstU32<{ crate::MAX_PROOF_SIZE }>> {
+    let proof = sp_core::hashing::blake2_256(&[&vkey_hash[..], public_values].concat());
+    BoundedVec::truncate_from(proof.to_vec())
+}
+
+impl pallet_zk_coprocessor::Config for Test {
+    type Currency = Balances;
+    type Provers = IsInVec<Provers>;
+    type VaultCheck = IsInVec<Vaults>;
+    type ProofVerifier = MockVerifier;
+    type RequestDeposit = ConstU64<DEPOSIT>;
+    type RequestTimeout = ConstU64<TIMEOUT>;
+    type MaxInputSize = ConstU32<MAX_INPUT>;
+    type MaxOutputSize = ConstU32<MAX_OUTPUT>;
+    type WeightInfo = ();
+}
+
+/// Block 1, `PROGRAM` registered with `VKEY`
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(REQUESTER, 1_000), (OUTSIDER, 1_000), (VAULT, 1_000)],
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(ProgramRegistry::register_program_version(
+            RuntimeOrigin::root(),
+            PROGRAM,
+            1,
+            VKEY,
+            [0; 32],
+            1
+        ));
+    });
+    ext
+}
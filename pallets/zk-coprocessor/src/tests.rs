@@ -0,0 +1,306 @@
+//! Unit tests for pallet-zk-coprocessor
+
+use crate::{mock::*, public_values, ComputeResult, Error, Event, Requests, Results};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError;
+
+const INPUT: &[u8] = b"fib(20)";
+const OUTPUT: &[u8] = b"6765";
+const BOUNTY: u64 = 100;
+
+/// Submit `INPUT` to `PROGRAM` from `REQUESTER`, returning the request ID
+fn submit() -> u64 {
+    let request_id = crate::NextRequestId::<Test>::get();
+    assert_ok!(ZkCoprocessor::submit_request(
+        RuntimeOrigin::signed(REQUESTER),
+        PROGRAM,
+        INPUT.to_vec(),
+        BOUNTY
+    ));
+    request_id
+}
+
+/// Fulfill `request_id` with `output` proven against `VKEY`
+fn fulfill(prover: u64, request_id: u64, output: &[u8]) -> sp_runtime::DispatchResult {
+    let proof = prove(&VKEY, &public_values(request_id, INPUT, output));
+    ZkCoprocessor::fulfill_request(
+        RuntimeOrigin::signed(prover),
+        request_id,
+        output.to_vec(),
+        proof,
+    )
+}
+
+#[test]
+fn submit_request_reserves_bounty_and_deposit() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(submit(), 0);
+        assert_eq!(submit(), 1);
+
+        System::assert_last_event(
+            Event::RequestSubmitted {
+                request_id: 1,
+                requester: REQUESTER,
+                program_id: PROGRAM,
+                input_hash: sp_core::hashing::keccak_256(INPUT),
+                bounty: BOUNTY,
+                expires_at: 1 + TIMEOUT,
+            }
+            .into(),
+        );
+        assert_eq!(Balances::reserved_balance(REQUESTER), 2 * (BOUNTY + DEPOSIT));
+        let request = Requests::<Test>::get(0).unwrap();
+        assert_eq!(request.input.as_slice(), INPUT);
+        assert_eq!(request.deposit, DEPOSIT);
+    });
+}
+
+#[test]
+fn submit_request_rejects_unknown_program_and_large_input() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ZkCoprocessor::submit_request(
+                RuntimeOrigin::signed(REQUESTER),
+                PROGRAM + 1,
+                INPUT.to_vec(),
+                BOUNTY
+            ),
+            Error::<Test>::UnknownProgram
+        );
+        assert_noop!(
+            ZkCoprocessor::submit_request(
+                RuntimeOrigin::signed(REQUESTER),
+                PROGRAM,
+                vec![0; MAX_INPUT as usize + 1],
+                BOUNTY
+            ),
+            Error::<Test>::InputTooLarge
+        );
+        // Bounty the requester can't cover
+        assert_noop!(
+            ZkCoprocessor::submit_request(
+                RuntimeOrigin::signed(REQUESTER),
+                PROGRAM,
+                INPUT.to_vec(),
+                1_000
+            ),
+            pallet_balances::Error::<Test>::InsufficientBalance
+        );
+    });
+}
+
+#[test]
+fn submit_request_rejects_vaults() {
+    new_test_ext().execute_with(|| {
+        // A prover the vault's classical key controls would collect the bounty
+        assert_noop!(
+            ZkCoprocessor::submit_request(
+                RuntimeOrigin::signed(VAULT),
+                PROGRAM,
+                INPUT.to_vec(),
+                BOUNTY
+            ),
+            Error::<Test>::VaultRequester
+        );
+        assert_noop!(
+            ZkCoprocessor::do_submit_request(VAULT, PROGRAM, INPUT.to_vec(), BOUNTY),
+            Error::<Test>::VaultRequester
+        );
+        assert_eq!(Balances::reserved_balance(VAULT), 0);
+    });
+}
+
+#[test]
+fn fulfill_pays_prover_and_stores_result() {
+    new_test_ext().execute_with(|| {
+        let request_id = submit();
+        System::set_block_number(5);
+
+        assert_ok!(fulfill(PROVER, request_id, OUTPUT));
+        System::assert_last_event(
+            Event::RequestFulfilled {
+                request_id,
+                prover: PROVER,
+                program_version: 1,
+                output_hash: sp_core::hashing::keccak_256(OUTPUT),
+            }
+            .into(),
+        );
+
+        assert!(!Requests::<Test>::contains_key(request_id));
+        assert_eq!(
+            Results::<Test>::get(request_id),
+            Some(ComputeResult {
+                requester: REQUESTER,
+                program_id: PROGRAM,
+                program_version: 1,
+                prover: PROVER,
+                output: OUTPUT.to_vec().try_into().unwrap(),
+                deposit: DEPOSIT,
+                fulfilled_at: 5,
+            })
+        );
+        assert_eq!(Balances::free_balance(PROVER), BOUNTY);
+        assert_eq!(Balances::reserved_balance(REQUESTER), DEPOSIT);
+
+        // Fulfilled once
+        assert_noop!(fulfill(PROVER, request_id, OUTPUT), Error::<Test>::UnknownRequest);
+    });
+}
+
+#[test]
+fn fulfill_rejects_proofs_of_anything_else() {
+    new_test_ext().execute_with(|| {
+        let request_id = submit();
+        let other_request = submit();
+
+        // Proof of a different output
+        let proof = prove(&VKEY, &public_values(request_id, INPUT, b"6766"));
+        assert_noop!(
+            ZkCoprocessor::fulfill_request(
+                RuntimeOrigin::signed(PROVER),
+                request_id,
+                OUTPUT.to_vec(),
+                proof
+            ),
+            Error::<Test>::ProofVerificationFailed
+        );
+
+        // Proof of the same run for another request
+        let proof = prove(&VKEY, &public_values(other_request, INPUT, OUTPUT));
+        assert_noop!(
+            ZkCoprocessor::fulfill_request(
+                RuntimeOrigin::signed(PROVER),
+                request_id,
+                OUTPUT.to_vec(),
+                proof
+            ),
+            Error::<Test>::ProofVerificationFailed
+        );
+
+        // Proof of another program
+        let proof = prove(&[8; 32], &public_values(request_id, INPUT, OUTPUT));
+        assert_noop!(
+            ZkCoprocessor::fulfill_request(
+                RuntimeOrigin::signed(PROVER),
+                request_id,
+                OUTPUT.to_vec(),
+                proof
+            ),
+            Error::<Test>::ProofVerificationFailed
+        );
+
+        assert_noop!(
+            fulfill(PROVER, request_id, &[0; MAX_OUTPUT as usize + 1]),
+            Error::<Test>::OutputTooLarge
+        );
+    });
+}
+
+#[test]
+fn fulfill_checks_the_active_program_version() {
+    new_test_ext().execute_with(|| {
+        let request_id = submit();
+        assert_ok!(ProgramRegistry::register_program_version(
+            RuntimeOrigin::root(),
+            PROGRAM,
+            2,
+            [9; 32],
+            [0; 32],
+            3
+        ));
+
+        let upgraded = || {
+            let proof = prove(&[9; 32], &public_values(request_id, INPUT, OUTPUT));
+            ZkCoprocessor::fulfill_request(
+                RuntimeOrigin::signed(PROVER),
+                request_id,
+                OUTPUT.to_vec(),
+                proof,
+            )
+        };
+
+        // Version 1 until block 3
+        assert_noop!(upgraded(), Error::<Test>::ProofVerificationFailed);
+
+        System::set_block_number(3);
+        assert_noop!(fulfill(PROVER, request_id, OUTPUT), Error::<Test>::ProofVerificationFailed);
+        assert_ok!(upgraded());
+        assert_eq!(Results::<Test>::get(request_id).unwrap().program_version, 2);
+    });
+}
+
+#[test]
+fn fulfill_requires_prover_before_expiry() {
+    new_test_ext().execute_with(|| {
+        let request_id = submit();
+
+        assert_noop!(fulfill(OUTSIDER, request_id, OUTPUT), Error::<Test>::NotProver);
+        assert_noop!(fulfill(PROVER, request_id + 1, OUTPUT), Error::<Test>::UnknownRequest);
+
+        System::set_block_number(2 + TIMEOUT);
+        assert_noop!(fulfill(PROVER, request_id, OUTPUT), Error::<Test>::RequestExpired);
+    });
+}
+
+#[test]
+fn cancel_request_after_expiry_unreserves_everything() {
+    new_test_ext().execute_with(|| {
+        let request_id = submit();
+
+        assert_noop!(
+            ZkCoprocessor::cancel_request(RuntimeOrigin::signed(REQUESTER), request_id),
+            Error::<Test>::RequestNotExpired
+        );
+
+        System::set_block_number(2 + TIMEOUT);
+        assert_noop!(
+            ZkCoprocessor::cancel_request(RuntimeOrigin::signed(OUTSIDER), request_id),
+            Error::<Test>::NotRequester
+        );
+        assert_ok!(ZkCoprocessor::cancel_request(RuntimeOrigin::signed(REQUESTER), request_id));
+        System::assert_last_event(Event::RequestCancelled { request_id }.into());
+
+        assert!(!Requests::<Test>::contains_key(request_id));
+        assert_eq!(Balances::reserved_balance(REQUESTER), 0);
+        assert_eq!(Balances::free_balance(REQUESTER), 1_000);
+    });
+}
+
+#[test]
+fn clear_result_returns_deposit() {
+    new_test_ext().execute_with(|| {
+        let request_id = submit();
+        assert_ok!(fulfill(PROVER, request_id, OUTPUT));
+
+        assert_noop!(
+            ZkCoprocessor::clear_result(RuntimeOrigin::signed(OUTSIDER), request_id),
+            Error::<Test>::NotRequester
+        );
+        assert_ok!(ZkCoprocessor::clear_result(RuntimeOrigin::signed(REQUESTER), request_id));
+        System::assert_last_event(Event::ResultCleared { request_id }.into());
+
+        assert!(!Results::<Test>::contains_key(request_id));
+        assert_eq!(Balances::reserved_balance(REQUESTER), 0);
+        assert_eq!(Balances::free_balance(REQUESTER), 1_000 - BOUNTY);
+
+        assert_noop!(
+            ZkCoprocessor::clear_result(RuntimeOrigin::signed(REQUESTER), request_id),
+            Error::<Test>::UnknownResult
+        );
+    });
+}
+
+#[test]
+fn coprocessor_calls_require_signed_origin() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ZkCoprocessor::submit_request(RuntimeOrigin::root(), PROGRAM, INPUT.to_vec(), BOUNTY),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            ZkCoprocessor::cancel_request(RuntimeOrigin::none(), 0),
+            DispatchError::BadOrigin
+        );
+    });
+}
@@ -0,0 +1,70 @@
+//! Weight information for pallet-zk-coprocessor
+//!
+//! In production, these should be generated using frame-benchmarking.
+
+use frame_support::pallet_prelude::Get;
+use frame_support::weights::Weight;
+
+/// Weight functions needed for pallet-zk-coprocessor
+pub trait WeightInfo {
+    fn submit_request(n: u32) -> Weight;
+    fn fulfill_request(n: u32) -> Weight;
+    fn cancel_request() -> Weight;
+    fn clear_result() -> Weight;
+}
+
+/// Production weight implementations
+pub struct SubstrateWeight<T>(core::marker::PhantomData<T>);
+
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    /// Vault check, program versions, reserve, next ID, request (r:4 w:3),
+    /// plus hashing `n` input bytes
+    fn submit_request(n: u32) -> Weight {
+        Weight::from_parts(30_000_000, 0)
+            .saturating_add(Weight::from_parts(2_000, 0).saturating_mul(n as u64))
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+
+    /// Request, program versions, requester and prover accounts, result
+    /// (r:4 w:4), plus hashing `n` output bytes; proof verification excluded
+    fn fulfill_request(n: u32) -> Weight {
+        Weight::from_parts(40_000_000, 0)
+            .saturating_add(Weight::from_parts(2_000, 0).saturating_mul(n as u64))
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(4))
+    }
+
+    /// Request, unreserve (r:2 w:2)
+    fn cancel_request() -> Weight {
+        Weight::from_parts(25_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+
+    /// Result, unreserve (r:2 w:2)
+    fn clear_result() -> Weight {
+        Weight::from_parts(25_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+}
+
+/// Unit testing weight implementations
+impl WeightInfo for () {
+    fn submit_request(n: u32) -> Weight {
+        Weight::from_parts(30_000_000 + 2_000 * n as u64, 0)
+    }
+
+    fn fulfill_request(n: u32) -> Weight {
+        Weight::from_parts(40_000_000 + 2_000 * n as u64, 0)
+    }
+
+    fn cancel_request() -> Weight {
+        Weight::from_parts(25_000_000, 0)
+    }
+
+    fn clear_result() -> Weight {
+        Weight::from_parts(25_000_000, 0)
+    }
+}
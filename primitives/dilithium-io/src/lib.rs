@@ -0,0 +1,134 @@
+//! # Dilithium Host Functions
+//!
+//! `pqc_dilithium` does not build for the Wasm runtime, so Dilithium
+//! verification is a host function: the runtime calls [`dilithium::verify`]
+//! (Dilithium2, ML-DSA-44) or [`dilithium::verify_at_level`] (any NIST level)
+//! and the node runs it natively. Native builds, such as pallet tests, call the
+//! same functions directly.
+//!
+//! Every executor that runs the runtime must register
+//! [`dilithium::HostFunctions`], or the runtime fails to instantiate.
+//!
+//! [`portable::verify`] checks the same signatures inside the runtime, to
+//! cross-check the host functions on testnets.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub mod portable;
+
+use sp_runtime_interface::{pass_by::PassFatPointerAndRead, runtime_interface};
+
+/// Dilithium2 public key size
+pub const PUBLIC_KEY_SIZE: usize = 1312;
+
+/// Dilithium2 signature size
+pub const SIGNATURE_SIZE: usize = 2420;
+
+/// Public key and signature sizes of Dilithium at NIST `level` (2, 3 or 5)
+pub fn sizes(level: u8) -> Option<(usize, usize)> {
+    match level {
+        2 => Some((PUBLIC_KEY_SIZE, SIGNATURE_SIZE)),
+        3 => Some((1952, 3293)),
+        5 => Some((2592, 4595)),
+        _ => None,
+    }
+}
+
+#[runtime_interface]
+pub trait Dilithium {
+    /// Whether `signature` is a valid Dilithium2 signature of `message` by `public_key`
+    ///
+    /// Keys and signatures of the wrong size are invalid.
+    fn verify(
+        public_key: PassFatPointerAndRead<&[u8]>,
+        message: PassFatPointerAndRead<&[u8]>,
+        signature: PassFatPointerAndRead<&[u8]>,
+    ) -> bool {
+        public_key.len() == PUBLIC_KEY_SIZE
+            && signature.len() == SIGNATURE_SIZE
+            && pqc_dilithium::verify(signature, message, public_key).is_ok()
+    }
+
+    /// Whether `signature` is a valid Dilithium signature of `message` by
+    /// `public_key` at NIST `level` (2, 3 or 5)
+    ///
+    /// Unknown levels, and keys and signatures of the wrong size, are invalid.
+    fn verify_at_level(
+        level: u8,
+        public_key: PassFatPointerAndRead<&[u8]>,
+        message: PassFatPointerAndRead<&[u8]>,
+        signature: PassFatPointerAndRead<&[u8]>,
+    ) -> bool {
+        use crystals_dilithium::{dilithium3, dilithium5};
+
+        if sizes(level) != Some((public_key.len(), signature.len())) {
+            return false;
+        }
+        match level {
+            2 => pqc_dilithium::verify(signature, message, public_key).is_ok(),
+            3 => dilithium3::PublicKey::from_bytes(public_key).verify(message, signature),
+            5 => dilithium5::PublicKey::from_bytes(public_key).verify(message, signature),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verifies_only_matching_signatures() {
+        let keypair = pqc_dilithium::Keypair::generate();
+        let signature = keypair.sign(b"vault payload");
+
+        assert!(dilithium::verify(&keypair.public, b"vault payload", &signature));
+        assert!(!dilithium::verify(&keypair.public, b"other payload", &signature));
+        assert!(!dilithium::verify(&keypair.public, b"vault payload", &signature[1..]));
+        assert!(!dilithium::verify(&keypair.public[1..], b"vault payload", &signature));
+    }
+
+    #[test]
+    fn verifies_each_level_with_its_own_parameter_set() {
+        let level2 = pqc_dilithium::Keypair::generate();
+        let level3 = crystals_dilithium::dilithium3::Keypair::generate(None);
+        let level5 = crystals_dilithium::dilithium5::Keypair::generate(None);
+        let keys = [
+            (2, level2.public.to_vec(), level2.sign(b"vault payload").to_vec()),
+            (3, level3.public.to_bytes().to_vec(), level3.sign(b"vault payload").to_vec()),
+            (5, level5.public.to_bytes().to_vec(), level5.sign(b"vault payload").to_vec()),
+        ];
+
+        for (level, public_key, signature) in &keys {
+            assert!(dilithium::verify_at_level(*level, public_key, b"vault payload", signature));
+            assert!(!dilithium::verify_at_level(*level, public_key, b"other payload", signature));
+        }
+        assert!(!dilithium::verify_at_level(3, &keys[2].1, b"vault payload", &keys[2].2));
+        assert!(!dilithium::verify_at_level(4, &keys[0].1, b"vault payload", &keys[0].2));
+    }
+
+    #[test]
+    fn portable_verifier_agrees_with_host_functions() {
+        let level2 = pqc_dilithium::Keypair::generate();
+        let level3 = crystals_dilithium::dilithium3::Keypair::generate(None);
+        let level5 = crystals_dilithium::dilithium5::Keypair::generate(None);
+        let keys = [
+            (2, level2.public.to_vec(), level2.sign(b"vault payload").to_vec()),
+            (3, level3.public.to_bytes().to_vec(), level3.sign(b"vault payload").to_vec()),
+            (5, level5.public.to_bytes().to_vec(), level5.sign(b"vault payload").to_vec()),
+        ];
+
+        for (level, public_key, signature) in &keys {
+            assert!(portable::verify(*level, public_key, b"vault payload", signature));
+            assert!(!portable::verify(*level, public_key, b"other payload", signature));
+
+            let mut tampered = signature.clone();
+            tampered[40] ^= 1;
+            assert!(!portable::verify(*level, public_key, b"vault payload", &tampered));
+            assert!(!dilithium::verify_at_level(*level, public_key, b"vault payload", &tampered));
+        }
+        assert!(!portable::verify(3, &keys[2].1, b"vault payload", &keys[2].2));
+    }
+}
@@ -0,0 +1,387 @@
+//! # In-Runtime Dilithium Verification
+//!
+//! A pure-Rust Dilithium (round 3) verifier that compiles into the Wasm
+//! runtime and never calls the node. It is much slower than the host
+//! functions and is not used to accept signatures: runtimes built with the
+//! `double-verification` feature of `pallet-quantum-vault` run it next to the
+//! host function and reject the call when the two verdicts differ, so a node
+//! whose native verifier drifts from the specification is caught on testnets.
+//!
+//! Follows the reference implementation's `crypto_sign_verify`, for the
+//! parameter sets of NIST levels 2, 3 and 5.
+
+use alloc::{vec, vec::Vec};
+use sha3::{
+    digest::{ExtendableOutput, Update, XofReader},
+    Shake128, Shake256,
+};
+
+/// Coefficients per polynomial
+const N: usize = 256;
+
+/// Modulus q
+const Q: i32 = 8_380_417;
+
+/// q⁻¹ mod 2³²
+const QINV: i32 = 58_728_449;
+
+/// Bits dropped from t
+const D: u32 = 13;
+
+/// Size of ρ, tr and c̃
+const SEED_BYTES: usize = 32;
+
+/// Size of µ
+const CRH_BYTES: usize = 64;
+
+/// Packed size of a t1 polynomial (10-bit coefficients)
+const T1_BYTES: usize = N * 10 / 8;
+
+/// Powers of the 512th root of unity 1753 in bit-reversed order, in the
+/// Montgomery domain
+#[rustfmt::skip]
+const ZETAS: [i32; N] = [
+    0, 25847, -2608894, -518909, 237124, -777960, -876248, 466468,
+    1826347, 2353451, -359251, -2091905, 3119733, -2884855, 3111497, 2680103,
+    2725464, 1024112, -1079900, 3585928, -549488, -1119584, 2619752, -2108549,
+    -2118186, -3859737, -1399561, -3277672, 1757237, -19422, 4010497, 280005,
+    2706023, 95776, 3077325, 3530437, -1661693, -3592148, -2537516, 3915439,
+    -3861115, -3043716, 3574422, -2867647, 3539968, -300467, 2348700, -539299,
+    -1699267, -1643818, 3505694, -3821735, 3507263, -2140649, -1600420, 3699596,
+    811944, 531354, 954230, 3881043, 3900724, -2556880, 2071892, -2797779,
+    -3930395, -1528703, -3677745, -3041255, -1452451, 3475950, 2176455, -1585221,
+    -1257611, 1939314, -4083598, -1000202, -3190144, -3157330, -3632928, 126922,
+    3412210, -983419, 2147896, 2715295, -2967645, -3693493, -411027, -2477047,
+    -671102, -1228525, -22981, -1308169, -381987, 1349076, 1852771, -1430430,
+    -3343383, 264944, 508951, 3097992, 44288, -1100098, 904516, 3958618,
+    -3724342, -8578, 1653064, -3249728, 2389356, -210977, 759969, -1316856,
+    189548, -3553272, 3159746, -1851402, -2409325, -177440, 1315589, 1341330,
+    1285669, -1584928, -812732, -1439742, -3019102, -3881060, -3628969, 3839961,
+    2091667, 3407706, 2316500, 3817976, -3342478, 2244091, -2446433, -3562462,
+    266997, 2434439, -1235728, 3513181, -3520352, -3759364, -1197226, -3193378,
+    900702, 1859098, 909542, 819034, 495491, -1613174, -43260, -522500,
+    -655327, -3122442, 2031748, 3207046, -3556995, -525098, -768622, -3595838,
+    342297, 286988, -2437823, 4108315, 3437287, -3342277, 1735879, 203044,
+    2842341, 2691481, -2590150, 1265009, 4055324, 1247620, 2486353, 1595974,
+    -3767016, 1250494, 2635921, -3548272, -2994039, 1869119, 1903435, -1050970,
+    -1333058, 1237275, -3318210, -1430225, -451100, 1312455, 3306115, -1962642,
+    -1279661, 1917081, -2546312, -1374803, 1500165, 777191, 2235880, 3406031,
+    -542412, -2831860, -1671176, -1846953, -2584293, -3724270, 594136, -3776993,
+    -2013608, 2432395, 2454455, -164721, 1957272, 3369112, 185531, -1207385,
+    -3183426, 162844, 1616392, 3014001, 810149, 1652634, -3694233, -1799107,
+    -3038916, 3523897, 3866901, 269760, 2213111, -975884, 1717735, 472078,
+    -426683, 1723600, -1803090, 1910376, -1667432, -1104333, -260646, -3833893,
+    -2939036, -2235985, -420899, -2286327, 183443, -976891, 1612842, -3545687,
+    -554416, 3919660, -48306, -1362209, 3937738, 1400424, -846154, 1976782,
+];
+
+/// A polynomial of R_q
+type Poly = [i32; N];
+
+/// Dilithium parameters of one NIST level
+struct Params {
+    /// Rows of A
+    k: usize,
+    /// Columns of A
+    l: usize,
+    /// Nonzero coefficients of c
+    tau: usize,
+    /// τ·η
+    beta: i32,
+    /// log2 γ1
+    gamma1_bits: u32,
+    /// Low-order rounding range γ2
+    gamma2: i32,
+    /// Maximum hint bits
+    omega: usize,
+}
+
+impl Params {
+    fn at_level(level: u8) -> Option<Self> {
+        let (k, l, tau, beta, gamma1_bits, gamma2, omega) = match level {
+            2 => (4, 4, 39, 78, 17, (Q - 1) / 88, 80),
+            3 => (6, 5, 49, 196, 19, (Q - 1) / 32, 55),
+            5 => (8, 7, 60, 120, 19, (Q - 1) / 32, 75),
+            _ => return None,
+        };
+        Some(Self { k, l, tau, beta, gamma1_bits, gamma2, omega })
+    }
+
+    fn gamma1(&self) -> i32 {
+        1 << self.gamma1_bits
+    }
+
+    /// Packed size of a z polynomial
+    fn z_bytes(&self) -> usize {
+        N * (self.gamma1_bits as usize + 1) / 8
+    }
+
+    /// Bits per packed w1 coefficient
+    fn w1_bits(&self) -> u32 {
+        if self.gamma2 == (Q - 1) / 88 {
+            6
+        } else {
+            4
+        }
+    }
+}
+
+/// Whether `signature` is a valid Dilithium signature of `message` by
+/// `public_key` at NIST `level` (2, 3 or 5)
+///
+/// Gives the same verdict as [`crate::dilithium::verify_at_level`].
+pub fn verify(level: u8, public_key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+    let Some(params) = Params::at_level(level) else {
+        return false;
+    };
+    if crate::sizes(level) != Some((public_key.len(), signature.len())) {
+        return false;
+    }
+
+    let (rho, t1_bytes) = public_key.split_at(SEED_BYTES);
+    let (c_tilde, rest) = signature.split_at(SEED_BYTES);
+    let (z_bytes, hint_bytes) = rest.split_at(params.l * params.z_bytes());
+
+    let mut z: Vec<Poly> = z_bytes
+        .chunks(params.z_bytes())
+        .map(|bytes| {
+            let mut poly = unpack(bytes, params.gamma1_bits + 1);
+            poly.iter_mut().for_each(|c| *c = params.gamma1() - *c);
+            poly
+        })
+        .collect();
+    if z.iter().flatten().any(|c| c.abs() >= params.gamma1() - params.beta) {
+        return false;
+    }
+    let Some(hints) = unpack_hints(&params, hint_bytes) else {
+        return false;
+    };
+
+    // µ = CRH(H(pk) || M)
+    let mut tr = [0u8; SEED_BYTES];
+    shake256(&[public_key], &mut tr);
+    let mut mu = [0u8; CRH_BYTES];
+    shake256(&[&tr, message], &mut mu);
+
+    // w1 = UseHint(h, A·z − c·t1·2^d), one row of A at a time
+    let mut c = sample_in_ball(&params, c_tilde);
+    ntt(&mut c);
+    z.iter_mut().for_each(ntt);
+    let mut w1_bytes = Vec::with_capacity(params.k * N * params.w1_bits() as usize / 8);
+    for (row, (t1_bytes, hints)) in t1_bytes.chunks(T1_BYTES).zip(&hints).enumerate() {
+        let mut w = [0i32; N];
+        for (column, z) in z.iter().enumerate() {
+            let a = expand_a(rho, row, column);
+            for (w, (a, z)) in w.iter_mut().zip(a.iter().zip(z)) {
+                *w += montgomery_reduce(*a as i64 * *z as i64);
+            }
+        }
+
+        let mut t1 = unpack(t1_bytes, 10);
+        t1.iter_mut().for_each(|c| *c <<= D);
+        ntt(&mut t1);
+        for (w, (c, t1)) in w.iter_mut().zip(c.iter().zip(&t1)) {
+            *w = reduce32(*w - montgomery_reduce(*c as i64 * *t1 as i64));
+        }
+        invntt_tomont(&mut w);
+        for (w, &hint) in w.iter_mut().zip(hints) {
+            *w = use_hint(&params, caddq(*w), hint);
+        }
+        pack(&w, params.w1_bits(), &mut w1_bytes);
+    }
+
+    let mut c_prime = [0u8; SEED_BYTES];
+    shake256(&[&mu, &w1_bytes], &mut c_prime);
+    c_prime == c_tilde
+}
+
+/// SHAKE256 of the concatenated `inputs`, filling `output`
+fn shake256(inputs: &[&[u8]], output: &mut [u8]) {
+    let mut hasher = Shake256::default();
+    inputs.iter().for_each(|input| hasher.update(input));
+    hasher.finalize_xof().read(output);
+}
+
+/// Entry (row, column) of A, in the NTT domain, by rejection sampling
+fn expand_a(rho: &[u8], row: usize, column: usize) -> Poly {
+    let mut hasher = Shake128::default();
+    hasher.update(rho);
+    hasher.update(&[column as u8, row as u8]);
+    let mut reader = hasher.finalize_xof();
+
+    let mut poly = [0; N];
+    let mut filled = 0;
+    let mut bytes = [0u8; 3];
+    while filled < N {
+        reader.read(&mut bytes);
+        let t = i32::from_le_bytes([bytes[0], bytes[1], bytes[2] & 0x7F, 0]);
+        if t < Q {
+            poly[filled] = t;
+            filled += 1;
+        }
+    }
+    poly
+}
+
+/// Challenge c with τ coefficients ±1, from c̃
+fn sample_in_ball(params: &Params, seed: &[u8]) -> Poly {
+    let mut hasher = Shake256::default();
+    hasher.update(seed);
+    let mut reader = hasher.finalize_xof();
+
+    let mut signs = [0u8; 8];
+    reader.read(&mut signs);
+    let mut signs = u64::from_le_bytes(signs);
+
+    let mut c = [0; N];
+    let mut byte = [0u8; 1];
+    for i in N - params.tau..N {
+        let j = loop {
+            reader.read(&mut byte);
+            if byte[0] as usize <= i {
+                break byte[0] as usize;
+            }
+        };
+        c[i] = c[j];
+        c[j] = 1 - 2 * (signs & 1) as i32;
+        signs >>= 1;
+    }
+    c
+}
+
+/// Hint bits, `None` if the encoding is not canonical
+fn unpack_hints(params: &Params, bytes: &[u8]) -> Option<Vec<[bool; N]>> {
+    let (indices, ends) = bytes.split_at(params.omega);
+    let mut hints = vec![[false; N]; params.k];
+    let mut start = 0;
+    for (hint, &end) in hints.iter_mut().zip(ends) {
+        let end = end as usize;
+        if end < start || end > params.omega {
+            return None;
+        }
+        // Indices ascend within a polynomial, for strong unforgeability
+        let row = &indices[start..end];
+        if row.windows(2).any(|pair| pair[1] <= pair[0]) {
+            return None;
+        }
+        row.iter().for_each(|&index| hint[index as usize] = true);
+        start = end;
+    }
+    // Unused slots are zero
+    indices[start..].iter().all(|&index| index == 0).then_some(hints)
+}
+
+/// Coefficients of `bits` bits each, least significant first
+fn unpack(bytes: &[u8], bits: u32) -> Poly {
+    let mut poly = [0; N];
+    let mut bytes = bytes.iter();
+    let (mut buffer, mut buffered) = (0u64, 0);
+    for coefficient in poly.iter_mut() {
+        while buffered < bits {
+            buffer |= u64::from(bytes.next().copied().unwrap_or(0)) << buffered;
+            buffered += 8;
+        }
+        *coefficient = (buffer & ((1 << bits) - 1)) as i32;
+        buffer >>= bits;
+        buffered -= bits;
+    }
+    poly
+}
+
+/// Append the coefficients of `poly`, `bits` bits each, least significant first
+fn pack(poly: &Poly, bits: u32, output: &mut Vec<u8>) {
+    let (mut buffer, mut buffered) = (0u64, 0);
+    for &coefficient in poly {
+        buffer |= (coefficient as u64) << buffered;
+        buffered += bits;
+        while buffered >= 8 {
+            output.push(buffer as u8);
+            buffer >>= 8;
+            buffered -= 8;
+        }
+    }
+}
+
+/// High bits of `a` (in [0, q)), corrected by `hint`
+fn use_hint(params: &Params, a: i32, hint: bool) -> i32 {
+    let (a1, a0) = decompose(params.gamma2, a);
+    if !hint {
+        return a1;
+    }
+    let buckets = (Q - 1) / (2 * params.gamma2);
+    if a0 > 0 {
+        (a1 + 1) % buckets
+    } else {
+        (a1 - 1 + buckets) % buckets
+    }
+}
+
+/// (a1, a0) with a = a1·2γ2 + a0 mod q and a0 in (−γ2, γ2]
+fn decompose(gamma2: i32, a: i32) -> (i32, i32) {
+    let mut a1 = (a + 127) >> 7;
+    if gamma2 == (Q - 1) / 32 {
+        a1 = ((a1 * 1025 + (1 << 21)) >> 22) & 15;
+    } else {
+        a1 = (a1 * 11275 + (1 << 23)) >> 24;
+        a1 ^= ((43 - a1) >> 31) & a1;
+    }
+    let mut a0 = a - a1 * 2 * gamma2;
+    a0 -= (((Q - 1) / 2 - a0) >> 31) & Q;
+    (a1, a0)
+}
+
+/// a·2⁻³² mod q, for |a| < q·2³¹
+fn montgomery_reduce(a: i64) -> i32 {
+    let t = (a as i32).wrapping_mul(QINV);
+    ((a - t as i64 * Q as i64) >> 32) as i32
+}
+
+/// a mod q, in (−q, q)
+fn reduce32(a: i32) -> i32 {
+    a - ((a + (1 << 22)) >> 23) * Q
+}
+
+/// a mod q, in [0, q), for a in (−q, q)
+fn caddq(a: i32) -> i32 {
+    a + ((a >> 31) & Q)
+}
+
+/// Forward NTT, in place
+fn ntt(a: &mut Poly) {
+    let mut k = 0;
+    let mut len = N / 2;
+    while len > 0 {
+        for start in (0..N).step_by(2 * len) {
+            k += 1;
+            let zeta = ZETAS[k] as i64;
+            for j in start..start + len {
+                let t = montgomery_reduce(zeta * a[j + len] as i64);
+                a[j + len] = a[j] - t;
+                a[j] += t;
+            }
+        }
+        len >>= 1;
+    }
+}
+
+/// Inverse NTT, in place, multiplying by 2³² to leave the Montgomery domain
+fn invntt_tomont(a: &mut Poly) {
+    // 2⁶⁴ / 256 mod q
+    const F: i64 = 41_978;
+
+    let mut k = N;
+    let mut len = 1;
+    while len < N {
+        for start in (0..N).step_by(2 * len) {
+            k -= 1;
+            let zeta = -ZETAS[k] as i64;
+            for j in start..start + len {
+                let t = a[j];
+                a[j] = t + a[j + len];
+                a[j + len] = montgomery_reduce(zeta * (t - a[j + len]) as i64);
+            }
+        }
+        len <<= 1;
+    }
+    a.iter_mut().for_each(|c| *c = montgomery_reduce(F * *c as i64));
+}
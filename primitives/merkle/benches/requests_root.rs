@@ -0,0 +1,26 @@
+//! Requests root cost by batch size, for the `submit_proof` weight.
+//!
+//! ```bash
+//! cargo bench -p tesserax-merkle
+//! ```
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use sha3::{Digest, Keccak256};
+
+fn keccak(data: &[u8]) -> [u8; 32] {
+    Keccak256::digest(data).into()
+}
+
+fn requests_root(c: &mut Criterion) {
+    let mut group = c.benchmark_group("requests_root");
+    for n in [1u64, 16, 64, 256, 1024] {
+        let ids: Vec<u64> = (0..n).collect();
+        group.bench_with_input(BenchmarkId::from_parameter(n), &ids, |b, ids| {
+            b.iter(|| tesserax_merkle::requests_root(ids, keccak))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, requests_root);
+criterion_main!(benches);
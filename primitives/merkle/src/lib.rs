@@ -0,0 +1,278 @@
+//! # Request Merkle Roots
+//!
+//! The one implementation of the Re-ML requests roots. The guest commits
+//! them, `pallet-reml-verifier` recomputes them from the submitted request
+//! IDs, and any other pallet that needs to check membership in a verified
+//! batch uses the same function, so the tree shape cannot drift between them.
+//!
+//! ## Tree
+//!
+//! ```text
+//! leaf = H(id as u64 little-endian)
+//! node = H(left || right)
+//! ```
+//!
+//! Levels are built pairwise; an odd node at the end of a level is promoted
+//! unchanged. No IDs give the zero root.
+//!
+//! An inclusion proof for one leaf is its sibling at each level, bottom up,
+//! with no entry for levels where the leaf's ancestor is promoted. Checking
+//! it needs the leaf's index and the leaf count, which fix those levels.
+//!
+//! Every batch has two roots over this tree: `H` = keccak256 for EVM
+//! consumers and `H` = blake2b-256 for Substrate consumers, each the hash
+//! its environment verifies cheaply.
+//!
+//! The hash is a parameter so each environment brings its own: `sha3` and
+//! `blake2` inside the zkVM, `sp_io::hashing` in the runtime (host calls from
+//! Wasm, see [`keccak_requests_root`] and [`blake2_requests_root`] with the
+//! `sp-io` feature).
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+/// Root of an empty tree
+pub const EMPTY_ROOT: [u8; 32] = [0u8; 32];
+
+/// Root of the tree over `leaves`
+pub fn merkle_root(mut leaves: Vec<[u8; 32]>, hash: impl Fn(&[u8]) -> [u8; 32]) -> [u8; 32] {
+    if leaves.is_empty() {
+        return EMPTY_ROOT;
+    }
+
+    while leaves.len() > 1 {
+        leaves = next_level(&leaves, &hash);
+    }
+
+    leaves[0]
+}
+
+/// Inclusion proof for the leaf at `index`, or `None` past the last leaf
+pub fn merkle_proof(
+    mut leaves: Vec<[u8; 32]>,
+    mut index: usize,
+    hash: impl Fn(&[u8]) -> [u8; 32],
+) -> Option<Vec<[u8; 32]>> {
+    if index >= leaves.len() {
+        return None;
+    }
+
+    let mut proof = Vec::new();
+    while leaves.len() > 1 {
+        if let Some(sibling) = leaves.get(index ^ 1) {
+            proof.push(*sibling);
+        }
+        leaves = next_level(&leaves, &hash);
+        index /= 2;
+    }
+
+    Some(proof)
+}
+
+/// Whether `proof` places `leaf` at `index` of a `leaf_count`-leaf tree
+/// with root `root`
+pub fn verify_proof(
+    leaf: [u8; 32],
+    mut index: usize,
+    mut leaf_count: usize,
+    proof: &[[u8; 32]],
+    root: &[u8; 32],
+    hash: impl Fn(&[u8]) -> [u8; 32],
+) -> bool {
+    if index >= leaf_count {
+        return false;
+    }
+
+    let mut node = leaf;
+    let mut siblings = proof.iter();
+    while leaf_count > 1 {
+        if index ^ 1 < leaf_count {
+            let Some(sibling) = siblings.next() else {
+                return false;
+            };
+            node = if index % 2 == 0 {
+                hash_pair(&node, sibling, &hash)
+            } else {
+                hash_pair(sibling, &node, &hash)
+            };
+        }
+        index /= 2;
+        leaf_count = leaf_count.div_ceil(2);
+    }
+
+    siblings.next().is_none() && node == *root
+}
+
+fn next_level(nodes: &[[u8; 32]], hash: &impl Fn(&[u8]) -> [u8; 32]) -> Vec<[u8; 32]> {
+    let mut next = Vec::with_capacity(nodes.len().div_ceil(2));
+    for pair in nodes.chunks(2) {
+        match pair {
+            [left, right] => next.push(hash_pair(left, right, hash)),
+            [odd] => next.push(*odd),
+            _ => unreachable!("chunks(2) yields one or two leaves"),
+        }
+    }
+    next
+}
+
+fn hash_pair(left: &[u8; 32], right: &[u8; 32], hash: &impl Fn(&[u8]) -> [u8; 32]) -> [u8; 32] {
+    let mut combined = [0u8; 64];
+    combined[..32].copy_from_slice(left);
+    combined[32..].copy_from_slice(right);
+    hash(&combined)
+}
+
+/// Requests root over `ids`, in order
+pub fn requests_root(ids: &[u64], hash: impl Fn(&[u8]) -> [u8; 32]) -> [u8; 32] {
+    let leaves = ids.iter().map(|id| hash(&id.to_le_bytes())).collect();
+    merkle_root(leaves, hash)
+}
+
+/// Inclusion proof for `ids[index]` in the requests root over `ids`
+pub fn request_proof(
+    ids: &[u64],
+    index: usize,
+    hash: impl Fn(&[u8]) -> [u8; 32],
+) -> Option<Vec<[u8; 32]>> {
+    let leaves = ids.iter().map(|id| hash(&id.to_le_bytes())).collect();
+    merkle_proof(leaves, index, hash)
+}
+
+/// Whether `proof` places request `id` at `index` of a requests root over
+/// `leaf_count` IDs
+pub fn verify_request(
+    id: u64,
+    index: usize,
+    leaf_count: usize,
+    proof: &[[u8; 32]],
+    root: &[u8; 32],
+    hash: impl Fn(&[u8]) -> [u8; 32],
+) -> bool {
+    verify_proof(hash(&id.to_le_bytes()), index, leaf_count, proof, root, hash)
+}
+
+/// Requests root hashed with `sp_io::hashing::keccak_256`
+#[cfg(feature = "sp-io")]
+pub fn keccak_requests_root(ids: &[u64]) -> [u8; 32] {
+    requests_root(ids, sp_io::hashing::keccak_256)
+}
+
+/// Requests root hashed with `sp_io::hashing::blake2_256`
+#[cfg(feature = "sp-io")]
+pub fn blake2_requests_root(ids: &[u64]) -> [u8; 32] {
+    requests_root(ids, sp_io::hashing::blake2_256)
+}
+
+/// [`verify_request`] hashed with `sp_io::hashing::blake2_256`
+#[cfg(feature = "sp-io")]
+pub fn verify_blake2_request(
+    id: u64,
+    index: usize,
+    leaf_count: usize,
+    proof: &[[u8; 32]],
+    root: &[u8; 32],
+) -> bool {
+    verify_request(id, index, leaf_count, proof, root, sp_io::hashing::blake2_256)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Cheap stand-in hash that still distinguishes inputs and order
+    fn toy_hash(data: &[u8]) -> [u8; 32] {
+        let mut out = [0u8; 32];
+        for (i, byte) in data.iter().enumerate() {
+            out[i % 32] = out[i % 32].rotate_left(3) ^ byte.wrapping_add(i as u8);
+        }
+        out[31] ^= data.len() as u8;
+        out
+    }
+
+    fn pair(left: [u8; 32], right: [u8; 32]) -> [u8; 32] {
+        toy_hash(&[left, right].concat())
+    }
+
+    fn leaf(id: u64) -> [u8; 32] {
+        toy_hash(&id.to_le_bytes())
+    }
+
+    #[test]
+    fn empty_and_single() {
+        assert_eq!(requests_root(&[], toy_hash), EMPTY_ROOT);
+        assert_eq!(requests_root(&[7], toy_hash), leaf(7));
+    }
+
+    #[test]
+    fn odd_nodes_are_promoted() {
+        let root = requests_root(&[1, 2, 3], toy_hash);
+        assert_eq!(root, pair(pair(leaf(1), leaf(2)), leaf(3)));
+
+        let root = requests_root(&[1, 2, 3, 4, 5], toy_hash);
+        let left = pair(pair(leaf(1), leaf(2)), pair(leaf(3), leaf(4)));
+        assert_eq!(root, pair(left, leaf(5)));
+    }
+
+    #[test]
+    fn order_matters() {
+        assert_ne!(requests_root(&[1, 2], toy_hash), requests_root(&[2, 1], toy_hash));
+    }
+
+    #[test]
+    fn proofs_verify_for_every_leaf() {
+        for count in 1..=9u64 {
+            let ids: Vec<u64> = (100..100 + count).collect();
+            let root = requests_root(&ids, toy_hash);
+            for (index, id) in ids.iter().enumerate() {
+                let proof = request_proof(&ids, index, toy_hash).unwrap();
+                assert!(verify_request(*id, index, ids.len(), &proof, &root, toy_hash));
+            }
+            assert_eq!(request_proof(&ids, ids.len(), toy_hash), None);
+        }
+    }
+
+    #[test]
+    fn promoted_levels_have_no_sibling() {
+        let ids = [1, 2, 3, 4, 5];
+        assert_eq!(request_proof(&ids, 4, toy_hash).unwrap().len(), 1);
+        assert_eq!(request_proof(&ids, 0, toy_hash).unwrap().len(), 3);
+    }
+
+    #[test]
+    fn proofs_reject_wrong_inputs() {
+        let ids = [1, 2, 3, 4, 5];
+        let root = requests_root(&ids, toy_hash);
+        let proof = request_proof(&ids, 2, toy_hash).unwrap();
+
+        assert!(verify_request(3, 2, 5, &proof, &root, toy_hash));
+        assert!(!verify_request(4, 2, 5, &proof, &root, toy_hash));
+        assert!(!verify_request(3, 3, 5, &proof, &root, toy_hash));
+        assert!(!verify_request(3, 5, 5, &proof, &root, toy_hash));
+        assert!(!verify_request(3, 2, 5, &proof[..2], &root, toy_hash));
+        assert!(!verify_request(3, 2, 5, &[proof.clone(), proof].concat(), &root, toy_hash));
+    }
+
+    #[cfg(feature = "sp-io")]
+    #[test]
+    fn sp_io_matches_sha3() {
+        use sha3::{Digest, Keccak256};
+        let sha3 = |data: &[u8]| -> [u8; 32] { Keccak256::digest(data).into() };
+
+        let ids: Vec<u64> = (0..37).collect();
+        assert_eq!(keccak_requests_root(&ids), requests_root(&ids, sha3));
+    }
+
+    #[cfg(feature = "sp-io")]
+    #[test]
+    fn sp_io_matches_blake2() {
+        use blake2::{digest::consts::U32, Blake2b, Digest};
+        let blake2 = |data: &[u8]| -> [u8; 32] { Blake2b::<U32>::digest(data).into() };
+
+        let ids: Vec<u64> = (0..37).collect();
+        assert_eq!(blake2_requests_root(&ids), requests_root(&ids, blake2));
+        assert_ne!(blake2_requests_root(&ids), keccak_requests_root(&ids));
+    }
+}
@@ -0,0 +1,68 @@
+//! # SPHINCS+ Host Functions
+//!
+//! Hash-based signatures for vaults whose owners would rather not rely on
+//! lattice assumptions. Like Dilithium (see `tesserax_dilithium_io`),
+//! SPHINCS+-SHA2-128s-simple is verified by a host function: the runtime
+//! calls [`sphincs::verify`] and the node runs PQClean's implementation
+//! natively. Native builds, such as pallet tests, call it directly.
+//!
+//! Every executor that runs the runtime must register
+//! [`sphincs::HostFunctions`], or the runtime fails to instantiate.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use sp_runtime_interface::{pass_by::PassFatPointerAndRead, runtime_interface};
+
+/// SPHINCS+-SHA2-128s public key size
+pub const PUBLIC_KEY_SIZE: usize = 32;
+
+/// SPHINCS+-SHA2-128s signature size
+pub const SIGNATURE_SIZE: usize = 7856;
+
+#[runtime_interface]
+pub trait Sphincs {
+    /// Whether `signature` is a valid SPHINCS+-SHA2-128s-simple signature of
+    /// `message` by `public_key`
+    ///
+    /// Keys and signatures of the wrong size are invalid.
+    fn verify(
+        public_key: PassFatPointerAndRead<&[u8]>,
+        message: PassFatPointerAndRead<&[u8]>,
+        signature: PassFatPointerAndRead<&[u8]>,
+    ) -> bool {
+        use pqcrypto_sphincsplus::sphincssha2128ssimple::{
+            verify_detached_signature, DetachedSignature, PublicKey,
+        };
+        use pqcrypto_traits::sign::{DetachedSignature as _, PublicKey as _};
+
+        if public_key.len() != PUBLIC_KEY_SIZE || signature.len() != SIGNATURE_SIZE {
+            return false;
+        }
+        match (PublicKey::from_bytes(public_key), DetachedSignature::from_bytes(signature)) {
+            (Ok(public_key), Ok(signature)) => {
+                verify_detached_signature(&signature, message, &public_key).is_ok()
+            },
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pqcrypto_sphincsplus::sphincssha2128ssimple::{detached_sign, keypair};
+    use pqcrypto_traits::sign::{DetachedSignature, PublicKey};
+
+    #[test]
+    fn verifies_only_matching_signatures() {
+        let (public_key, secret_key) = keypair();
+        let signature = detached_sign(b"vault payload", &secret_key);
+        let (public_key, signature) = (public_key.as_bytes(), signature.as_bytes());
+        assert_eq!((public_key.len(), signature.len()), (PUBLIC_KEY_SIZE, SIGNATURE_SIZE));
+
+        assert!(sphincs::verify(public_key, b"vault payload", signature));
+        assert!(!sphincs::verify(public_key, b"other payload", signature));
+        assert!(!sphincs::verify(public_key, b"vault payload", &signature[1..]));
+        assert!(!sphincs::verify(&public_key[1..], b"vault payload", signature));
+    }
+}
@@ -0,0 +1,320 @@
+//! # Quantum Vault Signing Payloads
+//!
+//! Canonical byte strings that vault keys sign. The pallet rebuilds them to
+//! verify signatures; the node's `vault-sign` command and `reml-prover sign`
+//! build them to produce signatures. All three use this crate, so they cannot
+//! drift apart.
+//!
+//! ## Format (version 1)
+//!
+//! ```text
+//! payload = "TESSERAX_VAULT" || version: u8 || kind: u8 || field*
+//! field   = len: u32 (little-endian) || bytes
+//! ```
+//!
+//! Every field is length-prefixed, so no two field lists encode to the same
+//! payload even when fields have variable length. Accounts and balances are
+//! passed SCALE-encoded, nonces are little-endian `u64` (their SCALE encoding).
+//! The policy's daily limit and whitelist are SCALE-encoded `Option<Balance>`
+//! and `Option<Vec<AccountId>>`.
+//!
+//! | Kind | Byte | Fields |
+//! |------|------|--------|
+//! | Transfer | 0 | from, to, amount, nonce |
+//! | Destroy | 1 | account, nonce |
+//! | Rotate | 2 | account, new public key hash, nonce |
+//! | Freeze | 3 | account, nonce |
+//! | Accept | 4 | target, funder, public key hash, initial deposit, target account nonce |
+//! | Approve | 5 | vault, spender, limit, nonce |
+//! | Announce | 6 | vault, message, nonce |
+//! | Policy | 7 | vault, daily limit, recipient whitelist, nonce |
+//!
+//! `test-vectors.json` holds reference payloads, generated independently by
+//! `scripts/generate_payload_vectors.py`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+/// Prefix shared by all vault signing payloads
+pub const PAYLOAD_MAGIC: &[u8] = b"TESSERAX_VAULT";
+
+/// Current payload format version
+pub const PAYLOAD_VERSION: u8 = 1;
+
+/// Vault operation a payload authorizes
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u8)]
+pub enum PayloadKind {
+    /// `vault_transfer` / `vault_transfer_with_nonce`
+    Transfer = 0,
+    /// `destroy_vault`
+    Destroy = 1,
+    /// Key rotation
+    Rotate = 2,
+    /// Vault freeze
+    Freeze = 3,
+    /// Consent to a vault created by another account (`create_vault_for`)
+    Accept = 4,
+    /// Allowance for a spender (`vault_approve`)
+    Approve = 5,
+    /// Signed on-chain announcement (pallet-announcements)
+    Announce = 6,
+    /// Spending limit and recipient whitelist (`set_vault_policy`)
+    Policy = 7,
+}
+
+impl PayloadKind {
+    /// Kind for its payload byte
+    pub fn from_byte(byte: u8) -> Option<Self> {
+        match byte {
+            0 => Some(Self::Transfer),
+            1 => Some(Self::Destroy),
+            2 => Some(Self::Rotate),
+            3 => Some(Self::Freeze),
+            4 => Some(Self::Accept),
+            5 => Some(Self::Approve),
+            6 => Some(Self::Announce),
+            7 => Some(Self::Policy),
+            _ => None,
+        }
+    }
+
+    /// Number of fields in payloads of this kind
+    pub fn field_count(self) -> usize {
+        match self {
+            Self::Transfer | Self::Approve | Self::Policy => 4,
+            Self::Destroy | Self::Freeze => 2,
+            Self::Rotate | Self::Announce => 3,
+            Self::Accept => 5,
+        }
+    }
+}
+
+/// Why a byte string is not a valid payload
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DecodeError {
+    /// Does not start with `PAYLOAD_MAGIC`
+    BadMagic,
+    /// Version other than `PAYLOAD_VERSION`
+    UnsupportedVersion(u8),
+    /// Unknown kind byte
+    UnknownKind(u8),
+    /// Field length runs past the end
+    Truncated,
+    /// Wrong number of fields for the kind
+    FieldCount,
+}
+
+/// Payload split into its parts
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Payload<'a> {
+    /// Operation the payload authorizes
+    pub kind: PayloadKind,
+    /// Field bytes, without length prefixes
+    pub fields: Vec<&'a [u8]>,
+}
+
+/// Encode a payload of `kind` with `fields`
+pub fn encode(kind: PayloadKind, fields: &[&[u8]]) -> Vec<u8> {
+    let len = fields.iter().map(|field| 4 + field.len()).sum::<usize>();
+    let mut payload = Vec::with_capacity(PAYLOAD_MAGIC.len() + 2 + len);
+    payload.extend_from_slice(PAYLOAD_MAGIC);
+    payload.push(PAYLOAD_VERSION);
+    payload.push(kind as u8);
+    for field in fields {
+        payload.extend_from_slice(&(field.len() as u32).to_le_bytes());
+        payload.extend_from_slice(field);
+    }
+    payload
+}
+
+/// Split a payload into its kind and fields
+pub fn decode(payload: &[u8]) -> Result<Payload<'_>, DecodeError> {
+    let rest = payload.strip_prefix(PAYLOAD_MAGIC).ok_or(DecodeError::BadMagic)?;
+    let (&version, rest) = rest.split_first().ok_or(DecodeError::Truncated)?;
+    if version != PAYLOAD_VERSION {
+        return Err(DecodeError::UnsupportedVersion(version));
+    }
+    let (&kind, mut rest) = rest.split_first().ok_or(DecodeError::Truncated)?;
+    let kind = PayloadKind::from_byte(kind).ok_or(DecodeError::UnknownKind(kind))?;
+
+    let mut fields = Vec::with_capacity(kind.field_count());
+    while !rest.is_empty() {
+        let (len, tail) = rest.split_first_chunk::<4>().ok_or(DecodeError::Truncated)?;
+        let len = u32::from_le_bytes(*len) as usize;
+        if tail.len() < len {
+            return Err(DecodeError::Truncated);
+        }
+        let (field, tail) = tail.split_at(len);
+        fields.push(field);
+        rest = tail;
+    }
+    if fields.len() != kind.field_count() {
+        return Err(DecodeError::FieldCount);
+    }
+    Ok(Payload { kind, fields })
+}
+
+/// Payload signed to transfer `amount` from vault `from` to `to`
+pub fn transfer(from: &[u8], to: &[u8], amount: &[u8], nonce: u64) -> Vec<u8> {
+    encode(PayloadKind::Transfer, &[from, to, amount, &nonce.to_le_bytes()])
+}
+
+/// Payload signed to destroy vault `account`
+pub fn destroy(account: &[u8], nonce: u64) -> Vec<u8> {
+    encode(PayloadKind::Destroy, &[account, &nonce.to_le_bytes()])
+}
+
+/// Payload signed to move vault `account` to the key hashing to `new_public_key_hash`
+pub fn rotate(account: &[u8], new_public_key_hash: &[u8; 32], nonce: u64) -> Vec<u8> {
+    encode(PayloadKind::Rotate, &[account, new_public_key_hash, &nonce.to_le_bytes()])
+}
+
+/// Payload signed to freeze vault `account`
+pub fn freeze(account: &[u8], nonce: u64) -> Vec<u8> {
+    encode(PayloadKind::Freeze, &[account, &nonce.to_le_bytes()])
+}
+
+/// Payload `target` signs to accept a vault set up and funded by `funder`
+///
+/// `account_nonce` is the target's SCALE-encoded `frame_system` account nonce.
+pub fn accept(
+    target: &[u8],
+    funder: &[u8],
+    public_key_hash: &[u8; 32],
+    initial_deposit: &[u8],
+    account_nonce: &[u8],
+) -> Vec<u8> {
+    encode(PayloadKind::Accept, &[target, funder, public_key_hash, initial_deposit, account_nonce])
+}
+
+/// Payload signed to let `spender` move up to `limit` out of `vault`
+pub fn approve(vault: &[u8], spender: &[u8], limit: &[u8], nonce: u64) -> Vec<u8> {
+    encode(PayloadKind::Approve, &[vault, spender, limit, &nonce.to_le_bytes()])
+}
+
+/// Payload signed to publish `message` as an announcement by vault `vault`
+pub fn announce(vault: &[u8], message: &[u8], nonce: u64) -> Vec<u8> {
+    encode(PayloadKind::Announce, &[vault, message, &nonce.to_le_bytes()])
+}
+
+/// Payload signed to set vault `vault`'s daily spending limit and recipient whitelist
+pub fn policy(vault: &[u8], daily_limit: &[u8], whitelist: &[u8], nonce: u64) -> Vec<u8> {
+    encode(PayloadKind::Policy, &[vault, daily_limit, whitelist, &nonce.to_le_bytes()])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+
+    fn field(vector: &Value, name: &str) -> Vec<u8> {
+        hex::decode(vector[name].as_str().unwrap()).unwrap()
+    }
+
+    fn nonce(vector: &Value) -> u64 {
+        vector["nonce"].as_u64().unwrap()
+    }
+
+    fn hash(vector: &Value, name: &str) -> [u8; 32] {
+        field(vector, name).try_into().unwrap()
+    }
+
+    #[test]
+    fn matches_test_vectors() {
+        let vectors: Value = serde_json::from_str(include_str!("../test-vectors.json")).unwrap();
+        let vectors = vectors["vectors"].as_array().unwrap();
+        assert!(!vectors.is_empty());
+
+        for vector in vectors {
+            let payload = match vector["kind"].as_str().unwrap() {
+                "transfer" => transfer(
+                    &field(vector, "from"),
+                    &field(vector, "to"),
+                    &field(vector, "amount"),
+                    nonce(vector),
+                ),
+                "destroy" => destroy(&field(vector, "account"), nonce(vector)),
+                "rotate" => rotate(
+                    &field(vector, "account"),
+                    &hash(vector, "new_public_key_hash"),
+                    nonce(vector),
+                ),
+                "freeze" => freeze(&field(vector, "account"), nonce(vector)),
+                "accept" => accept(
+                    &field(vector, "target"),
+                    &field(vector, "funder"),
+                    &hash(vector, "public_key_hash"),
+                    &field(vector, "initial_deposit"),
+                    &field(vector, "account_nonce"),
+                ),
+                "approve" => approve(
+                    &field(vector, "vault"),
+                    &field(vector, "spender"),
+                    &field(vector, "limit"),
+                    nonce(vector),
+                ),
+                "announce" => {
+                    announce(&field(vector, "vault"), &field(vector, "message"), nonce(vector))
+                },
+                "policy" => policy(
+                    &field(vector, "vault"),
+                    &field(vector, "daily_limit"),
+                    &field(vector, "whitelist"),
+                    nonce(vector),
+                ),
+                other => panic!("unknown kind {other}"),
+            };
+            let name = vector["name"].as_str().unwrap();
+            assert_eq!(hex::encode(&payload), vector["payload"].as_str().unwrap(), "{name}");
+            assert!(decode(&payload).is_ok(), "{name}");
+        }
+    }
+
+    #[test]
+    fn decode_round_trips_fields() {
+        let payload = transfer(&[1; 32], &[2; 32], &7u128.to_le_bytes(), 3);
+        let decoded = decode(&payload).unwrap();
+        assert_eq!(decoded.kind, PayloadKind::Transfer);
+        assert_eq!(
+            decoded.fields,
+            vec![&[1u8; 32][..], &[2u8; 32][..], &7u128.to_le_bytes()[..], &3u64.to_le_bytes()[..]]
+        );
+    }
+
+    #[test]
+    fn decode_rejects_malformed_payloads() {
+        let payload = destroy(&[1; 32], 0);
+
+        assert_eq!(decode(b"not a payload"), Err(DecodeError::BadMagic));
+        // Pre-versioning messages fail on the version byte
+        assert_eq!(decode(b"TESSERAX_VAULT_DESTROY:"), Err(DecodeError::UnsupportedVersion(b'_')));
+
+        let mut other_version = payload.clone();
+        other_version[PAYLOAD_MAGIC.len()] = PAYLOAD_VERSION + 1;
+        assert_eq!(decode(&other_version), Err(DecodeError::UnsupportedVersion(2)));
+
+        let mut unknown_kind = payload.clone();
+        unknown_kind[PAYLOAD_MAGIC.len() + 1] = 9;
+        assert_eq!(decode(&unknown_kind), Err(DecodeError::UnknownKind(9)));
+
+        assert_eq!(decode(&payload[..payload.len() - 1]), Err(DecodeError::Truncated));
+
+        // A destroy payload with a transfer's fields
+        let mut wrong_fields = transfer(&[1; 32], &[2; 32], &[0; 16], 0);
+        wrong_fields[PAYLOAD_MAGIC.len() + 1] = PayloadKind::Destroy as u8;
+        assert_eq!(decode(&wrong_fields), Err(DecodeError::FieldCount));
+    }
+
+    #[test]
+    fn length_prefixes_separate_fields() {
+        // Same concatenated bytes, different field boundaries
+        assert_ne!(
+            encode(PayloadKind::Destroy, &[&[1, 2], &[3]]),
+            encode(PayloadKind::Destroy, &[&[1], &[2, 3]])
+        );
+    }
+}
@@ -0,0 +1,191 @@
+//! # Re-ML Aggregation Guest
+//!
+//! Recursion over Re-ML batch proofs: the host proves several small batches
+//! with the Re-ML guest (compressed), and this program verifies those proofs
+//! inside the zkVM, so one proof covers thousands of signatures.
+//!
+//! [`aggregate`] folds up to `MAX_AGGREGATED_BATCHES` batches into a
+//! [`RemlAggregateOutput`]: their shared version, chain ID and epoch, the Re-ML
+//! guest's vkey hash, the summed counts and each batch's roots. The pallet
+//! records every batch by its roots (`submit_aggregate_proof`).
+//!
+//! ## Layout
+//!
+//! As with the batch guest, the logic lives in this library so it can run
+//! natively with any proof check; the zkVM entry point (`main.rs`, feature
+//! `zkvm`) passes `sp1_zkvm::lib::verify::verify_sp1_proof`.
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use reml_lib::{
+    OutputFormat, RemlAggregateOutput, RemlAggregationInput, RemlProofOutput, SubBatchOutput,
+    MAX_AGGREGATED_BATCHES,
+};
+use sha2::{Digest, Sha256};
+
+/// Verify and fold the batch proofs of `input`
+///
+/// `verify_proof(vkey, public_values_digest)` must abort unless the next
+/// proof on the proof stdin is a valid proof of `vkey` committing public
+/// values with that SHA-256 digest.
+///
+/// # Panics
+///
+/// When there are no proofs or more than `MAX_AGGREGATED_BATCHES`, public
+/// values don't decode, the batches disagree on version, chain ID or epoch,
+/// a batch repeats, or a batch verified no signatures. The pallet would
+/// reject such an aggregate anyway.
+pub fn aggregate(
+    input: &RemlAggregationInput,
+    verify_proof: impl Fn(&[u32; 8], &[u8; 32]),
+) -> RemlAggregateOutput {
+    assert!(
+        !input.proofs.is_empty() && input.proofs.len() <= MAX_AGGREGATED_BATCHES,
+        "Aggregate proofs fold 1 to MAX_AGGREGATED_BATCHES batches"
+    );
+    
+    let mut outputs = Vec::with_capacity(input.proofs.len());
+    for proof in &input.proofs {
+        let digest: [u8; 32] = Sha256::digest(&proof.public_values).into();
+        verify_proof(&input.batch_vkey, &digest);
+        let output = decode_public_values(&proof.public_values, proof.output_format)
+            .expect("Batch public values do not decode");
+        outputs.push(output);
+    }
+    
+    let first = &outputs[0];
+    let mut aggregate = RemlAggregateOutput {
+        version: first.version,
+        chain_id: first.chain_id,
+        epoch: first.epoch,
+        batch_vkey_hash: vkey_hash_bytes(&input.batch_vkey),
+        verified_count: 0,
+        mldsa44_count: 0,
+        falcon512_count: 0,
+        sub_batches: Vec::with_capacity(outputs.len()),
+    };
+    for output in &outputs {
+        assert!(
+            output.version == aggregate.version
+                && output.chain_id == aggregate.chain_id
+                && output.epoch == aggregate.epoch,
+            "Batches disagree on version, chain ID or epoch"
+        );
+        assert!(output.verified_count > 0, "Batch verified no signatures");
+        assert!(
+            aggregate.sub_batches.iter().all(|batch| batch.batch_id != output.batch_id),
+            "Batch folded twice"
+        );
+        
+        // At most 16 batches of 256 signatures
+        aggregate.verified_count += output.verified_count;
+        aggregate.mldsa44_count += output.mldsa44_count;
+        aggregate.falcon512_count += output.falcon512_count;
+        aggregate.sub_batches.push(SubBatchOutput::from(output));
+    }
+    
+    aggregate
+}
+
+/// Decode a batch proof's public values, committed in `format`
+fn decode_public_values(bytes: &[u8], format: OutputFormat) -> Option<RemlProofOutput> {
+    match format {
+        OutputFormat::Bincode => bincode::deserialize(bytes).ok(),
+        OutputFormat::Abi => RemlProofOutput::abi_decode(bytes),
+    }
+}
+
+/// `vk.hash_bytes()` of the vkey with digest `vkey`: its words, big-endian
+fn vkey_hash_bytes(vkey: &[u32; 8]) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    for (chunk, word) in bytes.chunks_mut(4).zip(vkey) {
+        chunk.copy_from_slice(&word.to_be_bytes());
+    }
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use core::cell::RefCell;
+    use reml_lib::{
+        compute_requests_root, compute_requests_root_blake2, AggregatedProof, REML_PROOF_EPOCH,
+    };
+    
+    fn batch_output(epoch: u32, batch_id: u64, ids: &[u64]) -> RemlProofOutput {
+        RemlProofOutput::new(
+            epoch,
+            batch_id,
+            ids.len() as u32,
+            compute_requests_root(ids),
+            compute_requests_root_blake2(ids),
+            ids.to_vec(),
+        )
+    }
+    
+    fn input(outputs: &[RemlProofOutput]) -> RemlAggregationInput {
+        // Mix both encodings
+        let proofs = outputs
+            .iter()
+            .enumerate()
+            .map(|(i, output)| match i % 2 {
+                0 => AggregatedProof {
+                    public_values: bincode::serialize(output).unwrap(),
+                    output_format: OutputFormat::Bincode,
+                },
+                _ => AggregatedProof {
+                    public_values: output.abi_encode(),
+                    output_format: OutputFormat::Abi,
+                },
+            })
+            .collect();
+        RemlAggregationInput { batch_vkey: [1, 2, 3, 4, 5, 6, 7, 0x0800_0000], proofs }
+    }
+    
+    #[test]
+    fn test_aggregate_verifies_every_proof_and_sums_counts() {
+        let outputs = [
+            batch_output(REML_PROOF_EPOCH, 1, &[1, 2]),
+            batch_output(REML_PROOF_EPOCH, 2, &[3, 4, 5]).with_scheme_counts(1, 2),
+        ];
+        let input = input(&outputs);
+        let checked = RefCell::new(Vec::new());
+        
+        let aggregate = aggregate(&input, |vkey, digest| {
+            assert_eq!(vkey, &input.batch_vkey);
+            checked.borrow_mut().push(*digest);
+        });
+        
+        let digests: Vec<[u8; 32]> = input
+            .proofs
+            .iter()
+            .map(|proof| Sha256::digest(&proof.public_values).into())
+            .collect();
+        assert_eq!(checked.into_inner(), digests);
+        assert_eq!(aggregate.epoch, REML_PROOF_EPOCH);
+        assert_eq!(&aggregate.batch_vkey_hash[..4], &[0, 0, 0, 1]);
+        assert_eq!(&aggregate.batch_vkey_hash[28..], &[8, 0, 0, 0]);
+        assert_eq!(
+            (aggregate.verified_count, aggregate.mldsa44_count, aggregate.falcon512_count),
+            (5, 3, 2)
+        );
+        assert_eq!(
+            aggregate.sub_batches,
+            vec![SubBatchOutput::from(&outputs[0]), SubBatchOutput::from(&outputs[1])]
+        );
+    }
+    
+    #[test]
+    #[should_panic(expected = "Batches disagree")]
+    fn test_aggregate_rejects_mixed_epochs() {
+        let outputs = [
+            batch_output(REML_PROOF_EPOCH, 1, &[1]),
+            batch_output(REML_PROOF_EPOCH + 1, 2, &[2]),
+        ];
+        aggregate(&input(&outputs), |_, _| {});
+    }
+}
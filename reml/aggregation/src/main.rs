@@ -0,0 +1,23 @@
+//! # Re-ML Aggregation Guest Program
+//!
+//! zkVM entry point: reads a [`RemlAggregationInput`] from the host, checks
+//! every batch proof on the proof stdin with SP1's recursion precompile and
+//! commits the ABI encoded [`reml_lib::RemlAggregateOutput`].
+
+#![no_main]
+#![no_std]
+
+use reml_lib::RemlAggregationInput;
+
+sp1_zkvm::entrypoint!(main);
+
+pub fn main() {
+    let input: RemlAggregationInput = sp1_zkvm::io::read();
+    
+    let output = reml_aggregation::aggregate(&input, |vkey, public_values_digest| {
+        sp1_zkvm::lib::verify::verify_sp1_proof(vkey, public_values_digest)
+    });
+    
+    // The pallet checks the ABI layout, whichever encoding the batches used
+    sp1_zkvm::io::commit_slice(&output.abi_encode());
+}
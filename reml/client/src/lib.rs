@@ -0,0 +1,546 @@
+//! # Re-ML Aggregator Client
+//!
+//! Typed async client for the HTTP API of `reml-prover serve`.
+//!
+//! ```no_run
+//! # async fn run(request: reml_lib::SignatureRequest) -> reml_client::Result<()> {
+//! let client = reml_client::Client::new("http://127.0.0.1:8080");
+//!
+//! let accepted = client.submit_request(&request).await?;
+//! let batch_id = client.await_verification(accepted.request_id).await?;
+//! println!("request {} proved in batch {}", accepted.request_id, batch_id);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Every call sends `Accept-Version` with the API version this crate follows
+//! (see `/schema` on the server). Non-2xx answers become [`Error`]s: a 429
+//! is [`Error::Saturated`] with the server's retry estimate, other errors
+//! keep the server's message and, for rejected submissions, the JSON Pointer
+//! of the offending field.
+//!
+//! `await_verification` resolves once the aggregator has proved the
+//! request's batch. Settlement on-chain is reported by `reml-prover watch`.
+//!
+//! Aggregators can commit to the SHA-256 of their TLS certificate on chain
+//! (`server_key_hash` of their `AggregatorInfo`). [`Client::with_server_key_hash`]
+//! pins that hash, so signature material only reaches the server holding
+//! the committed certificate, whoever signed it.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+pub use reml_lib::SignatureRequest;
+use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use rustls::crypto::{verify_tls12_signature, verify_tls13_signature, WebPkiSupportedAlgorithms};
+use rustls::pki_types::{CertificateDer, ServerName, UnixTime};
+use rustls::{CertificateError, DigitallySignedStruct, SignatureScheme};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Aggregator API version this client follows
+pub const API_VERSION: u32 = 1;
+
+/// How often `await_verification` polls the aggregator by default
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+// ═══════════════════════════════════════════════════════════════════════════
+// ERRORS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The aggregator couldn't be reached or sent an unreadable answer
+    #[error("HTTP request failed: {0}")]
+    Http(#[from] reqwest::Error),
+
+    /// The aggregator answered with an error status
+    #[error("aggregator answered {status}: {message}")]
+    Api {
+        status: u16,
+        message: String,
+        /// JSON Pointer of the request field that failed validation
+        path: Option<String>,
+    },
+
+    /// The prover is saturated; submit again after `retry_after_secs`
+    #[error("aggregator saturated, retry in {retry_after_secs}s")]
+    Saturated { retry_after_secs: u64 },
+
+    /// The aggregator has no record of the request
+    #[error("aggregator doesn't know request {0}")]
+    UnknownRequest(u64),
+
+    /// Proving the request's batch failed
+    #[error("proving batch {batch_id} of request {request_id} failed")]
+    ProofFailed { request_id: u64, batch_id: u64 },
+}
+
+pub type Result<T> = core::result::Result<T, Error>;
+
+// ═══════════════════════════════════════════════════════════════════════════
+// RESPONSES
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Answer to `POST /submit`
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SubmitResponse {
+    pub request_id: u64,
+    /// Requests waiting for a batch, this one included
+    #[serde(default)]
+    pub pending: Option<u64>,
+    /// Batch the request completed, now being proved
+    #[serde(default)]
+    pub batch_triggered: Option<u64>,
+}
+
+/// Answer to `GET /status`
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Status {
+    pub pending_requests: u64,
+    pub batch_size: u64,
+    pub min_batch_size: u64,
+    pub max_batch_size: u64,
+    pub target_latency_secs: Option<u64>,
+    pub last_proof_secs: Option<f64>,
+    pub in_flight_proofs: u64,
+    pub max_in_flight: u64,
+    pub max_pending: u64,
+    /// Whether `/submit` currently answers 429
+    pub saturated: bool,
+    pub retry_after_secs: Option<u64>,
+    pub batches_completed: u64,
+}
+
+/// Answer to `GET /batch`
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BatchInfo {
+    pub pending_count: u64,
+    pub batch_size: u64,
+    pub request_ids: Vec<u64>,
+}
+
+/// Where a submitted request is
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RequestState {
+    /// Waiting for its batch to fill up
+    Pending,
+    /// Its batch is being proved
+    Proving,
+    /// Its batch was proved
+    Proved,
+    /// Proving its batch failed
+    Failed,
+}
+
+/// Answer to `GET /request/<id>`
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RequestStatus {
+    pub request_id: u64,
+    pub state: RequestState,
+    /// Batch of the request, `None` while pending
+    pub batch_id: Option<u64>,
+}
+
+/// Body of error answers
+#[derive(Debug, Default, Deserialize)]
+struct ErrorBody {
+    error: String,
+    #[serde(default)]
+    path: Option<String>,
+    #[serde(default)]
+    retry_after_secs: Option<u64>,
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// CLIENT
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Client of one aggregator
+#[derive(Clone, Debug)]
+pub struct Client {
+    http: reqwest::Client,
+    base_url: String,
+    poll_interval: Duration,
+}
+
+impl Client {
+    /// Client of the aggregator at `base_url`, e.g. `http://127.0.0.1:8080`
+    pub fn new(base_url: impl Into<String>) -> Self {
+        let http = http_builder()
+            .build()
+            .expect("client without TLS roots or proxies configured always builds");
+        Self {
+            http,
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            poll_interval: DEFAULT_POLL_INTERVAL,
+        }
+    }
+
+    /// Only talk to a server presenting the certificate with SHA-256 `hash`
+    ///
+    /// `hash` is the aggregator's on-chain `server_key_hash`, computed over
+    /// the DER certificate (`openssl x509 -in cert.pem -outform der |
+    /// sha256sum`). The certificate chain and name aren't checked, so a
+    /// self-signed certificate works. Requests to `http://` URLs fail.
+    pub fn with_server_key_hash(mut self, hash: [u8; 32]) -> Self {
+        let provider = Arc::new(rustls::crypto::ring::default_provider());
+        let verifier = PinnedCertificate {
+            sha256: hash,
+            algorithms: provider.signature_verification_algorithms,
+        };
+        let tls = rustls::ClientConfig::builder_with_provider(provider)
+            .with_safe_default_protocol_versions()
+            .expect("ring supports the default protocol versions")
+            .dangerous()
+            .with_custom_certificate_verifier(Arc::new(verifier))
+            .with_no_client_auth();
+        self.http = http_builder()
+            .use_preconfigured_tls(tls)
+            .https_only(true)
+            .build()
+            .expect("client with a rustls config and no proxies always builds");
+        self
+    }
+
+    /// Poll every `interval` in `await_verification`
+    pub fn with_poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// Queue `request` for the next batch
+    pub async fn submit_request(&self, request: &SignatureRequest) -> Result<SubmitResponse> {
+        self.send(self.http.post(self.url("/submit")).json(request))
+            .await
+    }
+
+    /// Batch sizing, backpressure and progress of the aggregator
+    pub async fn get_status(&self) -> Result<Status> {
+        self.send(self.http.get(self.url("/status"))).await
+    }
+
+    /// Requests waiting for the next batch
+    pub async fn get_batch(&self) -> Result<BatchInfo> {
+        self.send(self.http.get(self.url("/batch"))).await
+    }
+
+    /// State of a submitted request
+    pub async fn get_request(&self, request_id: u64) -> Result<RequestStatus> {
+        let url = self.url(&format!("/request/{}", request_id));
+        match self.send(self.http.get(url)).await {
+            Err(Error::Api { status: 404, .. }) => Err(Error::UnknownRequest(request_id)),
+            result => result,
+        }
+    }
+
+    /// Wait until the batch of `request_id` is proved and return its ID
+    ///
+    /// Fails with [`Error::ProofFailed`] if proving the batch fails, and
+    /// with [`Error::UnknownRequest`] once the aggregator no longer knows
+    /// the request (never submitted, or forgotten after a restart).
+    pub async fn await_verification(&self, request_id: u64) -> Result<u64> {
+        loop {
+            let status = self.get_request(request_id).await?;
+            match (status.state, status.batch_id) {
+                (RequestState::Proved, Some(batch_id)) => return Ok(batch_id),
+                (RequestState::Failed, Some(batch_id)) => {
+                    return Err(Error::ProofFailed {
+                        request_id,
+                        batch_id,
+                    })
+                }
+                _ => tokio::time::sleep(self.poll_interval).await,
+            }
+        }
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+
+    async fn send<T: DeserializeOwned>(&self, request: reqwest::RequestBuilder) -> Result<T> {
+        let response = request
+            .header("Accept-Version", API_VERSION.to_string())
+            .send()
+            .await?;
+        let status = response.status();
+        if status.is_success() {
+            return Ok(response.json().await?);
+        }
+
+        let body: ErrorBody = response.json().await.unwrap_or_else(|_| ErrorBody {
+            error: status.to_string(),
+            ..Default::default()
+        });
+        match (status.as_u16(), body.retry_after_secs) {
+            (429, Some(retry_after_secs)) => Err(Error::Saturated { retry_after_secs }),
+            (status, _) => Err(Error::Api {
+                status,
+                message: body.error,
+                path: body.path,
+            }),
+        }
+    }
+}
+
+fn http_builder() -> reqwest::ClientBuilder {
+    // The aggregator closes every connection after its answer
+    reqwest::Client::builder().pool_max_idle_per_host(0)
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// CERTIFICATE PINNING
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Accepts the one server certificate whose SHA-256 is `sha256`
+///
+/// Handshake signatures are still verified against that certificate, so
+/// the server must hold its private key.
+#[derive(Debug)]
+struct PinnedCertificate {
+    sha256: [u8; 32],
+    algorithms: WebPkiSupportedAlgorithms,
+}
+
+impl ServerCertVerifier for PinnedCertificate {
+    fn verify_server_cert(
+        &self,
+        end_entity: &CertificateDer<'_>,
+        _intermediates: &[CertificateDer<'_>],
+        _server_name: &ServerName<'_>,
+        _ocsp_response: &[u8],
+        _now: UnixTime,
+    ) -> core::result::Result<ServerCertVerified, rustls::Error> {
+        if Sha256::digest(end_entity.as_ref()).as_slice() == self.sha256 {
+            Ok(ServerCertVerified::assertion())
+        } else {
+            Err(rustls::Error::InvalidCertificate(
+                CertificateError::ApplicationVerificationFailure,
+            ))
+        }
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> core::result::Result<HandshakeSignatureValid, rustls::Error> {
+        verify_tls12_signature(message, cert, dss, &self.algorithms)
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> core::result::Result<HandshakeSignatureValid, rustls::Error> {
+        verify_tls13_signature(message, cert, dss, &self.algorithms)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.algorithms.supported_schemes()
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TESTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reml_lib::{MLDSA_PUBLIC_KEY_SIZE, MLDSA_SIGNATURE_SIZE};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// Aggregator stand-in answering successive connections with `answers`
+    ///
+    /// Returns its base URL and the requests it received.
+    async fn serve(
+        answers: Vec<(u16, &'static str)>,
+    ) -> (String, tokio::task::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = tokio::spawn(async move {
+            let mut received = Vec::new();
+            for (status, body) in answers {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                received.push(read_request(&mut socket).await);
+                let response = format!(
+                    "HTTP/1.1 {} X\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+                socket.write_all(response.as_bytes()).await.unwrap();
+            }
+            received
+        });
+        (base_url, handle)
+    }
+
+    async fn read_request(socket: &mut tokio::net::TcpStream) -> String {
+        let mut buf = Vec::new();
+        let mut chunk = [0u8; 4096];
+        loop {
+            let n = socket.read(&mut chunk).await.unwrap();
+            buf.extend_from_slice(&chunk[..n]);
+            let request = String::from_utf8_lossy(&buf).to_string();
+            if let Some((head, body)) = request.split_once("\r\n\r\n") {
+                let length = head
+                    .lines()
+                    .find_map(|line| {
+                        line.to_ascii_lowercase()
+                            .strip_prefix("content-length:")
+                            .map(|v| v.trim().parse::<usize>().unwrap())
+                    })
+                    .unwrap_or(0);
+                if body.len() >= length {
+                    return request;
+                }
+            }
+            if n == 0 {
+                return request;
+            }
+        }
+    }
+
+    fn request() -> SignatureRequest {
+        SignatureRequest::new(
+            [7u8; 32],
+            vec![1u8; MLDSA_PUBLIC_KEY_SIZE],
+            vec![2u8; MLDSA_SIGNATURE_SIZE],
+            42,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_submit_request() {
+        let (base_url, server) = serve(vec![(
+            200,
+            r#"{"status": "accepted", "request_id": 42, "pending": 3}"#,
+        )])
+        .await;
+
+        let accepted = Client::new(base_url)
+            .submit_request(&request())
+            .await
+            .unwrap();
+        assert_eq!(
+            accepted,
+            SubmitResponse {
+                request_id: 42,
+                pending: Some(3),
+                batch_triggered: None
+            }
+        );
+
+        let received = server.await.unwrap().remove(0);
+        assert!(received.starts_with("POST /submit HTTP/1.1"));
+        assert!(received
+            .to_ascii_lowercase()
+            .contains(&format!("accept-version: {}", API_VERSION)));
+        let body = received.split_once("\r\n\r\n").unwrap().1;
+        assert_eq!(
+            serde_json::from_str::<SignatureRequest>(body).unwrap(),
+            request()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_error_answers() {
+        let (base_url, _server) = serve(vec![
+            (
+                429,
+                r#"{"error": "Prover saturated", "retry_after_secs": 120}"#,
+            ),
+            (
+                400,
+                r#"{"error": "must match ^(0x)?[0-9a-fA-F]{4840}$", "path": "/signature"}"#,
+            ),
+            (404, r#"{"error": "Unknown request"}"#),
+        ])
+        .await;
+        let client = Client::new(base_url);
+
+        assert!(matches!(
+            client.submit_request(&request()).await,
+            Err(Error::Saturated {
+                retry_after_secs: 120
+            })
+        ));
+        match client.submit_request(&request()).await {
+            Err(Error::Api { status, path, .. }) => {
+                assert_eq!((status, path.as_deref()), (400, Some("/signature")))
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+        assert!(matches!(
+            client.get_request(9).await,
+            Err(Error::UnknownRequest(9))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_await_verification_polls_until_proved() {
+        let (base_url, server) = serve(vec![
+            (
+                200,
+                r#"{"request_id": 42, "state": "pending", "batch_id": null}"#,
+            ),
+            (
+                200,
+                r#"{"request_id": 42, "state": "proving", "batch_id": 5}"#,
+            ),
+            (
+                200,
+                r#"{"request_id": 42, "state": "proved", "batch_id": 5}"#,
+            ),
+            (
+                200,
+                r#"{"request_id": 43, "state": "failed", "batch_id": 6}"#,
+            ),
+        ])
+        .await;
+        let client = Client::new(base_url).with_poll_interval(Duration::from_millis(1));
+
+        assert_eq!(client.await_verification(42).await.unwrap(), 5);
+        assert!(matches!(
+            client.await_verification(43).await,
+            Err(Error::ProofFailed {
+                request_id: 43,
+                batch_id: 6
+            })
+        ));
+
+        let received = server.await.unwrap();
+        assert!(received[0].starts_with("GET /request/42 HTTP/1.1"));
+        assert!(received[3].starts_with("GET /request/43 HTTP/1.1"));
+    }
+
+    #[test]
+    fn test_pinned_certificate() {
+        let cert = CertificateDer::from(vec![0x30, 0x82, 1, 2, 3]);
+        let verifier = PinnedCertificate {
+            sha256: Sha256::digest(cert.as_ref()).into(),
+            algorithms: rustls::crypto::ring::default_provider().signature_verification_algorithms,
+        };
+        let name = ServerName::try_from("aggregator.example").unwrap();
+        let verify = |cert: &CertificateDer<'_>| {
+            verifier.verify_server_cert(cert, &[], &name, &[], UnixTime::now())
+        };
+
+        assert!(verify(&cert).is_ok());
+        assert!(verify(&CertificateDer::from(vec![0x30, 0x82, 1, 2, 4])).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_pinned_client_refuses_plain_http() {
+        let client = Client::new("http://127.0.0.1:1").with_server_key_hash([0u8; 32]);
+        assert!(matches!(client.get_status().await, Err(Error::Http(_))));
+    }
+}
@@ -0,0 +1,584 @@
+//! # Aggregator API
+//!
+//! JSON bodies of the `serve` endpoints, versioned so clients in other
+//! languages can be generated from them and stay compatible.
+//!
+//! `GET /schema` serves a JSON Schema (draft 2020-12) with every request and
+//! response body under `$defs` and the endpoints using them under
+//! `endpoints`. `POST /submit` bodies are validated against it before they
+//! are parsed, and a mismatch is reported with the JSON Pointer of the
+//! offending value.
+//!
+//! Clients pick the API version with an `Accept-Version` header (`1`, `v1`,
+//! a comma-separated list of acceptable versions, or `*`); without one the
+//! latest is used. Every response carries the version it follows in an
+//! `API-Version` header, and a request accepting no supported version is
+//! answered `406 Not Acceptable`.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::sync::OnceLock;
+
+use regex::Regex;
+use reml_lib::{
+    FALCON512_MIN_SIGNATURE_SIZE, FALCON512_PUBLIC_KEY_SIZE, MAX_CONTEXT_SIZE,
+    MLDSA_PUBLIC_KEY_SIZE, MLDSA_SIGNATURE_SIZE,
+};
+use serde_json::{json, Number, Value};
+
+/// Latest API version, used when a request has no `Accept-Version`
+pub const API_VERSION: u32 = 1;
+
+/// API versions this server can answer in
+pub const SUPPORTED_VERSIONS: &[u32] = &[1];
+
+// ═══════════════════════════════════════════════════════════════════════════
+// VERSIONS
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Version to answer `request` in, from its `Accept-Version` header
+///
+/// Returns the header value when it accepts none of `SUPPORTED_VERSIONS`.
+pub fn negotiate_version(request: &str) -> Result<u32, String> {
+    let Some(accepted) = header(request, "Accept-Version") else {
+        return Ok(API_VERSION);
+    };
+
+    accepted
+        .split(',')
+        .map(str::trim)
+        .filter_map(|version| match version {
+            "*" => Some(API_VERSION),
+            _ => version.trim_start_matches(['v', 'V']).parse().ok(),
+        })
+        .filter(|version| SUPPORTED_VERSIONS.contains(version))
+        .max()
+        .ok_or_else(|| accepted.to_string())
+}
+
+/// Body of the `406 Not Acceptable` answer to an unsupported `Accept-Version`
+pub fn unsupported_version(requested: &str) -> String {
+    json!({
+        "error": format!("Unsupported API version: {}", requested),
+        "supported_versions": SUPPORTED_VERSIONS,
+    })
+    .to_string()
+}
+
+/// Value of the first `name` header of an HTTP request, matched case-insensitively
+fn header<'a>(request: &'a str, name: &str) -> Option<&'a str> {
+    let head = request.split("\r\n\r\n").next()?;
+    head.lines().skip(1).find_map(|line| {
+        let (key, value) = line.split_once(':')?;
+        key.trim().eq_ignore_ascii_case(name).then(|| value.trim())
+    })
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SCHEMA
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// JSON Schema of the API bodies, as served at `/schema`
+pub fn schema() -> &'static Value {
+    static SCHEMA: OnceLock<Value> = OnceLock::new();
+    SCHEMA.get_or_init(build_schema)
+}
+
+fn build_schema() -> Value {
+    let error = reference("Error");
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": "Re-ML aggregator API",
+        "version": API_VERSION,
+        "endpoints": {
+            "POST /submit": {
+                "request": reference("SignatureRequest"),
+                "responses": {
+                    "200": reference("SubmitAccepted"),
+                    "400": error,
+                    "421": error,
+                    "429": error,
+                },
+            },
+            "GET /status": { "responses": { "200": reference("Status") } },
+            "GET /batch": { "responses": { "200": reference("Batch") } },
+            "GET /request/{request_id}": {
+                "responses": { "200": reference("RequestStatus"), "400": error, "404": error },
+            },
+            "GET /schema": { "responses": { "200": { "type": "object" } } },
+        },
+        "$defs": {
+            "SignatureRequest": {
+                "description": "An ML-DSA or Falcon-512 signature to verify in the next batch",
+                "type": "object",
+                "required": ["message", "public_key", "signature", "request_id"],
+                "additionalProperties": false,
+                "properties": {
+                    "message": {
+                        "description": "Message hash, 32 bytes",
+                        "type": "array",
+                        "items": { "type": "integer", "minimum": 0, "maximum": 255 },
+                        "minItems": 32,
+                        "maxItems": 32,
+                    },
+                    // Exact sizes depend on `scheme` and are checked after parsing
+                    "public_key": hex_bytes("Dilithium2 or Falcon-512 public key", FALCON512_PUBLIC_KEY_SIZE, MLDSA_PUBLIC_KEY_SIZE),
+                    "signature": hex_bytes("Dilithium2 or Falcon-512 signature", FALCON512_MIN_SIGNATURE_SIZE, MLDSA_SIGNATURE_SIZE),
+                    "request_id": u64_schema("Unique request identifier"),
+                    "mode": {
+                        "description": "How `message` and `context` form the signed message",
+                        "enum": ["raw", "pure", "pre_hash_sha256", "pre_hash_sha3_256"],
+                        "default": "raw",
+                    },
+                    "context": hex_bytes("ML-DSA context string, empty in raw mode", 0, MAX_CONTEXT_SIZE),
+                    "scheme": {
+                        "description": "Signature scheme; Falcon-512 requests must be raw",
+                        "enum": ["ml_dsa44", "falcon512"],
+                        "default": "ml_dsa44",
+                    },
+                },
+            },
+            "SubmitAccepted": {
+                "type": "object",
+                "required": ["status", "request_id"],
+                "properties": {
+                    "status": { "enum": ["accepted"] },
+                    "request_id": u64_schema("ID of the accepted request"),
+                    "pending": u64_schema("Requests waiting for a batch, this one included"),
+                    "batch_triggered": u64_schema("Batch the request completed, now being proved"),
+                },
+            },
+            "Status": {
+                "type": "object",
+                "required": [
+                    "status", "pending_requests", "batch_size", "min_batch_size", "max_batch_size",
+                    "target_latency_secs", "last_proof_secs", "in_flight_proofs", "max_in_flight",
+                    "max_pending", "saturated", "retry_after_secs", "batches_completed",
+                ],
+                "properties": {
+                    "status": { "enum": ["running"] },
+                    "pending_requests": u64_schema("Requests waiting for a batch"),
+                    "batch_size": u64_schema("Size of the next batch"),
+                    "min_batch_size": u64_schema("Smallest batch size"),
+                    "max_batch_size": u64_schema("Largest batch size"),
+                    "target_latency_secs": { "type": ["integer", "null"], "minimum": 0 },
+                    "last_proof_secs": { "type": ["number", "null"], "minimum": 0 },
+                    "in_flight_proofs": u64_schema("Proofs being generated"),
+                    "max_in_flight": u64_schema("Proofs generated concurrently"),
+                    "max_pending": u64_schema("Pending requests above which `/submit` answers 429"),
+                    "saturated": { "type": "boolean" },
+                    "retry_after_secs": { "type": ["integer", "null"], "minimum": 0 },
+                    "batches_completed": u64_schema("Batches started since the server started"),
+                },
+            },
+            "Batch": {
+                "type": "object",
+                "required": ["pending_count", "batch_size", "request_ids"],
+                "properties": {
+                    "pending_count": u64_schema("Requests waiting for a batch"),
+                    "batch_size": u64_schema("Size of the next batch"),
+                    "request_ids": { "type": "array", "items": u64_schema("Pending request ID") },
+                },
+            },
+            "RequestStatus": {
+                "type": "object",
+                "required": ["request_id", "state", "batch_id"],
+                "properties": {
+                    "request_id": u64_schema("ID of the request"),
+                    "state": {
+                        "description": "Waiting for a batch, or the proving state of its batch",
+                        "enum": ["pending", "proving", "proved", "failed"],
+                    },
+                    "batch_id": {
+                        "description": "Batch of the request, null while pending",
+                        "type": ["integer", "null"],
+                        "minimum": 0,
+                    },
+                },
+            },
+            "Error": {
+                "type": "object",
+                "required": ["error"],
+                "properties": {
+                    "error": { "type": "string" },
+                    "path": {
+                        "description": "JSON Pointer of the request value that failed validation",
+                        "type": "string",
+                    },
+                    "retry_after_secs": u64_schema("Seconds to wait before retrying"),
+                    "supported_versions": { "type": "array", "items": u64_schema("API version") },
+                    "suggested_aggregator": {
+                        "description": "Aggregator the chain routes the request to, on a 421",
+                        "type": "string",
+                        "pattern": "^0x[0-9a-f]{64}$",
+                    },
+                },
+            },
+        },
+    })
+}
+
+fn reference(definition: &str) -> Value {
+    json!({ "$ref": format!("#/$defs/{}", definition) })
+}
+
+fn u64_schema(description: &str) -> Value {
+    json!({ "description": description, "type": "integer", "minimum": 0, "maximum": u64::MAX })
+}
+
+/// Hex string of `min..=max` bytes, optionally `0x`-prefixed
+fn hex_bytes(description: &str, min: usize, max: usize) -> Value {
+    let digits = if min == max {
+        format!("[0-9a-fA-F]{{{}}}", 2 * min)
+    } else {
+        format!("([0-9a-fA-F]{{2}}){{{},{}}}", min, max)
+    };
+    json!({
+        "description": format!("{}, hex", description),
+        "type": "string",
+        "pattern": format!("^(0x)?{}$", digits),
+    })
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// VALIDATION
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// A value that doesn't match the schema
+#[derive(Debug, PartialEq, Eq)]
+pub struct ValidationError {
+    /// JSON Pointer of the value, empty for the whole body
+    pub path: String,
+    pub message: String,
+}
+
+impl ValidationError {
+    /// Body of the `400 Bad Request` answer
+    pub fn to_json(&self) -> String {
+        json!({ "error": self.message, "path": self.path }).to_string()
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {}",
+            if self.path.is_empty() {
+                "/"
+            } else {
+                &self.path
+            },
+            self.message
+        )
+    }
+}
+
+/// Check `value` against `#/$defs/<definition>` of the schema
+///
+/// Supports the keywords the schema uses: `$ref`, `type`, `enum`,
+/// `minimum`/`maximum`, `pattern`, `items`, `minItems`/`maxItems`,
+/// `properties`, `required` and `additionalProperties: false`.
+pub fn validate(value: &Value, definition: &str) -> Result<(), ValidationError> {
+    check(value, &reference(definition), "")
+}
+
+fn check(value: &Value, schema: &Value, path: &str) -> Result<(), ValidationError> {
+    let fail = |message: String| {
+        Err(ValidationError {
+            path: path.to_string(),
+            message,
+        })
+    };
+
+    if let Some(target) = schema.get("$ref").and_then(Value::as_str) {
+        let resolved = target
+            .strip_prefix('#')
+            .and_then(|pointer| self::schema().pointer(pointer))
+            .unwrap_or_else(|| panic!("unresolvable schema reference {}", target));
+        return check(value, resolved, path);
+    }
+
+    if let Some(expected) = schema.get("type") {
+        let types: Vec<&str> = match expected {
+            Value::Array(types) => types.iter().filter_map(Value::as_str).collect(),
+            _ => expected.as_str().into_iter().collect(),
+        };
+        if !types.iter().any(|ty| has_type(value, ty)) {
+            return fail(format!(
+                "expected {}, found {}",
+                types.join(" or "),
+                type_name(value)
+            ));
+        }
+    }
+
+    if let Some(allowed) = schema.get("enum").and_then(Value::as_array) {
+        if !allowed.contains(value) {
+            return fail(format!("expected one of {}", Value::from(allowed.clone())));
+        }
+    }
+
+    if let Value::Number(number) = value {
+        if let Some(Value::Number(minimum)) = schema.get("minimum") {
+            if compare(number, minimum) == Ordering::Less {
+                return fail(format!("must be at least {}", minimum));
+            }
+        }
+        if let Some(Value::Number(maximum)) = schema.get("maximum") {
+            if compare(number, maximum) == Ordering::Greater {
+                return fail(format!("must be at most {}", maximum));
+            }
+        }
+    }
+
+    if let (Value::String(string), Some(pattern)) =
+        (value, schema.get("pattern").and_then(Value::as_str))
+    {
+        let regex = Regex::new(pattern).expect("schema patterns are valid");
+        if !regex.is_match(string) {
+            return fail(format!("must match {}", pattern));
+        }
+    }
+
+    if let Value::Array(items) = value {
+        if let Some(min) = schema.get("minItems").and_then(Value::as_u64) {
+            if (items.len() as u64) < min {
+                return fail(format!(
+                    "expected at least {} items, found {}",
+                    min,
+                    items.len()
+                ));
+            }
+        }
+        if let Some(max) = schema.get("maxItems").and_then(Value::as_u64) {
+            if items.len() as u64 > max {
+                return fail(format!(
+                    "expected at most {} items, found {}",
+                    max,
+                    items.len()
+                ));
+            }
+        }
+        if let Some(item_schema) = schema.get("items") {
+            for (index, item) in items.iter().enumerate() {
+                check(item, item_schema, &format!("{}/{}", path, index))?;
+            }
+        }
+    }
+
+    if let Value::Object(object) = value {
+        let properties = schema.get("properties").and_then(Value::as_object);
+        for required in schema
+            .get("required")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+        {
+            let Some(key) = required.as_str() else {
+                continue;
+            };
+            if !object.contains_key(key) {
+                return Err(ValidationError {
+                    path: child_path(path, key),
+                    message: "missing required property".to_string(),
+                });
+            }
+        }
+        for (key, field) in object {
+            match properties.and_then(|properties| properties.get(key)) {
+                Some(field_schema) => check(field, field_schema, &child_path(path, key))?,
+                None if schema.get("additionalProperties") == Some(&Value::Bool(false)) => {
+                    return Err(ValidationError {
+                        path: child_path(path, key),
+                        message: "unknown property".to_string(),
+                    });
+                }
+                None => {}
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// JSON Pointer of the `key` member of the value at `path`
+fn child_path(path: &str, key: &str) -> String {
+    format!("{}/{}", path, key.replace('~', "~0").replace('/', "~1"))
+}
+
+fn has_type(value: &Value, ty: &str) -> bool {
+    match ty {
+        "integer" => value.as_u64().is_some() || value.as_i64().is_some(),
+        _ => type_name(value) == ty || (ty == "number" && value.is_number()),
+    }
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(number) if number.is_f64() => "number",
+        Value::Number(_) => "integer",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+/// Order of two JSON numbers, exact for integers
+fn compare(a: &Number, b: &Number) -> Ordering {
+    if let (Some(a), Some(b)) = (a.as_u64(), b.as_u64()) {
+        return a.cmp(&b);
+    }
+    if let (Some(a), Some(b)) = (a.as_i64(), b.as_i64()) {
+        return a.cmp(&b);
+    }
+    match (a.as_i64(), b.as_u64()) {
+        (Some(_), Some(_)) => Ordering::Less,
+        _ => match (a.as_u64(), b.as_i64()) {
+            (Some(_), Some(_)) => Ordering::Greater,
+            _ => a
+                .as_f64()
+                .partial_cmp(&b.as_f64())
+                .unwrap_or(Ordering::Equal),
+        },
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TESTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reml_lib::{MessageMode, SignatureRequest, SignatureScheme};
+
+    fn request() -> Value {
+        let request = SignatureRequest::new(
+            [7u8; 32],
+            vec![1u8; MLDSA_PUBLIC_KEY_SIZE],
+            vec![2u8; MLDSA_SIGNATURE_SIZE],
+            42,
+        )
+        .with_mode(MessageMode::Pure, b"wallet".to_vec());
+        serde_json::to_value(request).unwrap()
+    }
+
+    fn error_at(value: &Value) -> String {
+        validate(value, "SignatureRequest").unwrap_err().path
+    }
+
+    #[test]
+    fn test_serialized_requests_match_schema() {
+        assert_eq!(validate(&request(), "SignatureRequest"), Ok(()));
+
+        // `mode`, `context` and `scheme` are optional, hex may be 0x-prefixed
+        let mut minimal = request();
+        minimal.as_object_mut().unwrap().remove("mode");
+        minimal.as_object_mut().unwrap().remove("context");
+        minimal.as_object_mut().unwrap().remove("scheme");
+        minimal["public_key"] = format!("0x{}", minimal["public_key"].as_str().unwrap()).into();
+        assert_eq!(validate(&minimal, "SignatureRequest"), Ok(()));
+
+        let falcon = SignatureRequest::new(
+            [7u8; 32],
+            vec![1u8; FALCON512_PUBLIC_KEY_SIZE],
+            vec![2u8; 666],
+            43,
+        )
+        .with_scheme(SignatureScheme::Falcon512);
+        assert_eq!(validate(&serde_json::to_value(falcon).unwrap(), "SignatureRequest"), Ok(()));
+    }
+
+    #[test]
+    fn test_errors_point_at_the_offending_value() {
+        let mut value = request();
+        value["message"][3] = 256.into();
+        assert_eq!(error_at(&value), "/message/3");
+
+        let mut value = request();
+        value["message"].as_array_mut().unwrap().pop();
+        assert_eq!(error_at(&value), "/message");
+
+        let mut value = request();
+        value["signature"] = "abcd".into();
+        let error = validate(&value, "SignatureRequest").unwrap_err();
+        assert_eq!(error.path, "/signature");
+        assert!(error.message.starts_with("must match"));
+
+        let mut value = request();
+        value["request_id"] = (-1).into();
+        assert_eq!(error_at(&value), "/request_id");
+
+        let mut value = request();
+        value["mode"] = "prehash".into();
+        assert_eq!(error_at(&value), "/mode");
+
+        let mut value = request();
+        value["scheme"] = "sphincs".into();
+        assert_eq!(error_at(&value), "/scheme");
+
+        let mut value = request();
+        value.as_object_mut().unwrap().remove("public_key");
+        let error = validate(&value, "SignatureRequest").unwrap_err();
+        assert_eq!(error.to_string(), "/public_key: missing required property");
+
+        let mut value = request();
+        value["requestId"] = 42.into();
+        assert_eq!(error_at(&value), "/requestId");
+
+        assert_eq!(error_at(&json!([])), "");
+    }
+
+    #[test]
+    fn test_response_bodies_match_schema() {
+        let accepted = json!({ "status": "accepted", "request_id": 42, "pending": 3 });
+        assert_eq!(validate(&accepted, "SubmitAccepted"), Ok(()));
+
+        let rejected: Value = serde_json::from_str(&unsupported_version("2")).unwrap();
+        assert_eq!(validate(&rejected, "Error"), Ok(()));
+        assert_eq!(rejected["supported_versions"], json!([1]));
+
+        let invalid = ValidationError {
+            path: "/mode".into(),
+            message: "bad".into(),
+        };
+        let body: Value = serde_json::from_str(&invalid.to_json()).unwrap();
+        assert_eq!(validate(&body, "Error"), Ok(()));
+    }
+
+    #[test]
+    fn test_schema_references_resolve() {
+        let endpoints = schema()["endpoints"].as_object().unwrap();
+        for endpoint in endpoints.values() {
+            for reference in endpoint["responses"].as_object().unwrap().values() {
+                if let Some(target) = reference.get("$ref").and_then(Value::as_str) {
+                    assert!(schema().pointer(&target[1..]).is_some(), "{}", target);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_accept_version_negotiation() {
+        let request = |accept: &str| format!("GET /status HTTP/1.1\r\n{}\r\n\r\n", accept);
+
+        assert_eq!(
+            negotiate_version("GET /status HTTP/1.1\r\n\r\n"),
+            Ok(API_VERSION)
+        );
+        assert_eq!(negotiate_version(&request("Accept-Version: 1")), Ok(1));
+        assert_eq!(negotiate_version(&request("accept-version: v1")), Ok(1));
+        assert_eq!(negotiate_version(&request("Accept-Version: 3, 1")), Ok(1));
+        assert_eq!(
+            negotiate_version(&request("Accept-Version: *")),
+            Ok(API_VERSION)
+        );
+        assert_eq!(
+            negotiate_version(&request("Accept-Version: 2")),
+            Err("2".to_string())
+        );
+    }
+}
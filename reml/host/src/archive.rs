@@ -0,0 +1,196 @@
+//! # Proof Archive
+//!
+//! Retention of the proofs `serve` mode writes to `--output-dir`.
+//!
+//! A proof JSON is 100 KB to several MB, so a long-running aggregator would
+//! eventually fill its disk. With `--export-to`, every proof is also copied
+//! to a [`ProofStore`] as dispute evidence:
+//!
+//! | Target | Store |
+//! |--------|-------|
+//! | `s3://bucket/prefix` | Uploaded with the `aws` CLI (`aws s3 cp`), using its credentials |
+//! | `file:///path` or a plain path | Copied into that directory, e.g. a mounted volume |
+//!
+//! With `--retain-proofs N`, only the newest N `proof_<batch>.json` files
+//! are kept in `--output-dir`. A proof whose export failed is renamed to
+//! `proof_<batch>.unexported.json` instead, which retention never deletes.
+
+use anyhow::{Context, Result, bail};
+use std::fs;
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+/// Destination proofs are exported to
+pub trait ProofStore: Send + Sync {
+    /// Store `data` under `name`
+    fn put(&self, name: &str, data: &[u8]) -> Result<()>;
+
+    /// Where the store puts its files, for logs
+    fn describe(&self) -> String;
+}
+
+/// Store for an `--export-to` target
+pub fn parse_export_target(target: &str) -> Result<Box<dyn ProofStore>> {
+    if let Some(location) = target.strip_prefix("s3://") {
+        let (bucket, prefix) = location.split_once('/').unwrap_or((location, ""));
+        if bucket.is_empty() {
+            bail!("Missing bucket in {}", target);
+        }
+        return Ok(Box::new(S3Store {
+            bucket: bucket.to_string(),
+            prefix: prefix.trim_end_matches('/').to_string(),
+        }));
+    }
+    if let Some((scheme, _)) = target.split_once("://") {
+        if scheme != "file" {
+            bail!("Unsupported export target {}, use s3:// or a directory", target);
+        }
+    }
+
+    let dir = PathBuf::from(target.strip_prefix("file://").unwrap_or(target));
+    fs::create_dir_all(&dir)
+        .with_context(|| format!("Failed to create export directory {:?}", dir))?;
+    Ok(Box::new(DirectoryStore { dir }))
+}
+
+/// Copies proofs into a local directory
+struct DirectoryStore {
+    dir: PathBuf,
+}
+
+impl ProofStore for DirectoryStore {
+    fn put(&self, name: &str, data: &[u8]) -> Result<()> {
+        fs::write(self.dir.join(name), data).context("Failed to write exported proof")
+    }
+
+    fn describe(&self) -> String {
+        format!("{:?}", self.dir)
+    }
+}
+
+/// Uploads proofs with `aws s3 cp - s3://bucket/prefix/name`
+struct S3Store {
+    bucket: String,
+    prefix: String,
+}
+
+impl S3Store {
+    fn url(&self, name: &str) -> String {
+        match self.prefix.as_str() {
+            "" => format!("s3://{}/{}", self.bucket, name),
+            prefix => format!("s3://{}/{}/{}", self.bucket, prefix, name),
+        }
+    }
+}
+
+impl ProofStore for S3Store {
+    fn put(&self, name: &str, data: &[u8]) -> Result<()> {
+        let mut child = Command::new("aws")
+            .args(["s3", "cp", "-", &self.url(name)])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .spawn()
+            .context("Failed to run the aws CLI")?;
+        child
+            .stdin
+            .take()
+            .context("aws CLI stdin unavailable")?
+            .write_all(data)
+            .context("Failed to stream proof to the aws CLI")?;
+
+        let status = child.wait()?;
+        if !status.success() {
+            bail!("aws s3 cp exited with {}", status);
+        }
+        Ok(())
+    }
+
+    fn describe(&self) -> String {
+        self.url("")
+    }
+}
+
+/// File name of the proof of `batch_id` in the output directory
+pub fn proof_file_name(batch_id: u64) -> String {
+    format!("proof_{}.json", batch_id)
+}
+
+/// Keep a proof that could not be exported out of retention's reach
+pub fn mark_unexported(dir: &Path, batch_id: u64) -> Result<PathBuf> {
+    let kept = dir.join(format!("proof_{}.unexported.json", batch_id));
+    fs::rename(dir.join(proof_file_name(batch_id)), &kept)?;
+    Ok(kept)
+}
+
+/// Delete all but the newest `retain` proofs in `dir`, by batch ID
+///
+/// Returns the batch IDs deleted.
+pub fn prune_proofs(dir: &Path, retain: usize) -> Result<Vec<u64>> {
+    let mut batches: Vec<u64> = fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let name = entry.file_name().into_string().ok()?;
+            name.strip_prefix("proof_")?.strip_suffix(".json")?.parse().ok()
+        })
+        .collect();
+    batches.sort_unstable();
+
+    let excess = batches.len().saturating_sub(retain);
+    let pruned: Vec<u64> = batches.drain(..excess).collect();
+    for batch_id in &pruned {
+        // Another proof task may have pruned it first
+        if let Err(e) = fs::remove_file(dir.join(proof_file_name(*batch_id))) {
+            if e.kind() != ErrorKind::NotFound {
+                return Err(e.into());
+            }
+        }
+    }
+    Ok(pruned)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Fresh directory under the system temp dir
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("reml-archive-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_parse_export_target() {
+        let s3 = parse_export_target("s3://evidence/reml/proofs/").unwrap();
+        assert_eq!(s3.describe(), "s3://evidence/reml/proofs/");
+        assert_eq!(parse_export_target("s3://evidence").unwrap().describe(), "s3://evidence/");
+        assert!(parse_export_target("s3://").is_err());
+        assert!(parse_export_target("gs://evidence").is_err());
+
+        let dir = temp_dir("export");
+        let store = parse_export_target(&format!("file://{}", dir.display())).unwrap();
+        store.put("proof_1.json", b"{}").unwrap();
+        assert_eq!(fs::read(dir.join("proof_1.json")).unwrap(), b"{}");
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_prune_keeps_newest_and_unexported() {
+        let dir = temp_dir("prune");
+        for batch_id in [1, 2, 3, 10] {
+            fs::write(dir.join(proof_file_name(batch_id)), b"{}").unwrap();
+        }
+        mark_unexported(&dir, 1).unwrap();
+
+        // Batch 10 sorts after 3, not between 1 and 2
+        assert_eq!(prune_proofs(&dir, 2).unwrap(), vec![2]);
+        assert!(dir.join("proof_1.unexported.json").exists());
+        assert!(dir.join(proof_file_name(3)).exists());
+        assert!(dir.join(proof_file_name(10)).exists());
+
+        assert_eq!(prune_proofs(&dir, 2).unwrap(), Vec::<u64>::new());
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,345 @@
+//! # Key Management
+//!
+//! Dilithium key handling for the prover CLI (`keygen`, `sign`).
+//!
+//! ## Key Hygiene
+//!
+//! - Secret key bytes live in `Zeroizing` buffers and are wiped on drop
+//! - Key files are encrypted with an age scrypt passphrase by default
+//! - `--insecure-plaintext` writes the secret key as raw hex (test keys only)
+//!
+//! The passphrase is read from `REML_KEY_PASSPHRASE` if set, otherwise it is
+//! prompted for on the terminal.
+//!
+//! ## Mnemonic Derivation
+//!
+//! `keygen --mnemonic` derives the keypair deterministically from a BIP39
+//! mnemonic along `m/13817'/account'/index'` (see `reml_lib::derive_mldsa_seed`),
+//! so a vault key can be recovered from the phrase alone.
+//!
+//! Note: `pqcrypto` key types do not zeroize themselves, so they are only
+//! materialized transiently inside `generate()` and `sign()`.
+
+use age::secrecy::{Secret, SecretString};
+use anyhow::{Context, Result, bail};
+use pqcrypto_dilithium::dilithium2;
+use pqcrypto_traits::sign::{DetachedSignature, PublicKey, SecretKey};
+use reml_lib::{derive_mldsa_seed, MLDSA_PUBLIC_KEY_SIZE};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::{Read, Write};
+use std::path::Path;
+use zeroize::Zeroizing;
+
+/// Environment variable consulted before prompting for a passphrase
+pub const PASSPHRASE_ENV: &str = "REML_KEY_PASSPHRASE";
+
+/// Environment variable consulted before prompting for a mnemonic
+pub const MNEMONIC_ENV: &str = "REML_MNEMONIC";
+
+/// Optional BIP39 passphrase ("25th word") applied to the mnemonic
+pub const MNEMONIC_PASSPHRASE_ENV: &str = "REML_MNEMONIC_PASSPHRASE";
+
+/// Key file format version
+const KEY_FILE_VERSION: u8 = 1;
+
+/// Algorithm identifier written into key files
+const KEY_ALGORITHM: &str = "ml-dsa-44";
+
+// ═══════════════════════════════════════════════════════════════════════════
+// KEYPAIR
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Dilithium2 keypair whose secret half is wiped on drop
+pub struct DilithiumKeypair {
+    public_key: Vec<u8>,
+    secret_key: Zeroizing<Vec<u8>>,
+}
+
+impl DilithiumKeypair {
+    /// Generate a fresh random keypair
+    pub fn generate() -> Self {
+        let (pk, sk) = dilithium2::keypair();
+        Self {
+            public_key: pk.as_bytes().to_vec(),
+            secret_key: Zeroizing::new(sk.as_bytes().to_vec()),
+        }
+    }
+
+    /// Derive a keypair from a BIP39 mnemonic at `m/13817'/account'/index'`
+    pub fn from_mnemonic(
+        mnemonic: &bip39::Mnemonic,
+        passphrase: &str,
+        account: u32,
+        index: u32,
+    ) -> Self {
+        let bip39_seed = Zeroizing::new(mnemonic.to_seed(passphrase));
+        let seed = Zeroizing::new(derive_mldsa_seed(&bip39_seed, account, index));
+
+        let keypair = crystals_dilithium::dilithium2::Keypair::generate(Some(&seed[..]));
+        let bytes = Zeroizing::new(keypair.to_bytes());
+        let sk_len = crystals_dilithium::dilithium2::SECRETKEYBYTES;
+
+        Self {
+            public_key: bytes[sk_len..].to_vec(),
+            secret_key: Zeroizing::new(bytes[..sk_len].to_vec()),
+        }
+    }
+
+    /// Rebuild a keypair from raw bytes
+    pub fn from_bytes(public_key: Vec<u8>, secret_key: Zeroizing<Vec<u8>>) -> Result<Self> {
+        if public_key.len() != MLDSA_PUBLIC_KEY_SIZE {
+            bail!(
+                "Invalid public key size: {} (expected {})",
+                public_key.len(),
+                MLDSA_PUBLIC_KEY_SIZE
+            );
+        }
+        dilithium2::SecretKey::from_bytes(&secret_key)
+            .map_err(|e| anyhow::anyhow!("Invalid secret key: {:?}", e))?;
+
+        Ok(Self { public_key, secret_key })
+    }
+
+    /// Public key bytes
+    pub fn public_key(&self) -> &[u8] {
+        &self.public_key
+    }
+
+    /// Produce a detached signature over `message`
+    pub fn sign(&self, message: &[u8]) -> Vec<u8> {
+        let sk = dilithium2::SecretKey::from_bytes(&self.secret_key)
+            .expect("secret key validated on construction; qed");
+        dilithium2::detached_sign(message, &sk).as_bytes().to_vec()
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// KEY FILES
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// On-disk key file (JSON)
+#[derive(Serialize, Deserialize)]
+struct KeyFile {
+    version: u8,
+    algorithm: String,
+    /// Public key (hex)
+    public_key: String,
+    /// Whether `secret_key` is age-encrypted
+    encrypted: bool,
+    /// Secret key: hex of the age ciphertext, or raw hex if not encrypted
+    secret_key: String,
+}
+
+/// Write a keypair to `path`
+///
+/// With a passphrase the secret key is age/scrypt encrypted; without one it
+/// is written as plaintext hex.
+pub fn save_keypair(
+    keypair: &DilithiumKeypair,
+    path: &Path,
+    passphrase: Option<&SecretString>,
+) -> Result<()> {
+    let secret_hex = Zeroizing::new(hex::encode(keypair.secret_key.as_slice()));
+
+    let (encrypted, secret_key) = match passphrase {
+        Some(passphrase) => (true, hex::encode(encrypt(secret_hex.as_bytes(), passphrase)?)),
+        None => (false, secret_hex.to_string()),
+    };
+
+    let key_file = KeyFile {
+        version: KEY_FILE_VERSION,
+        algorithm: KEY_ALGORITHM.into(),
+        public_key: hex::encode(&keypair.public_key),
+        encrypted,
+        secret_key,
+    };
+
+    let json = Zeroizing::new(
+        serde_json::to_string_pretty(&key_file).context("Failed to serialize key file")?,
+    );
+    write_private(path, json.as_bytes())
+}
+
+/// Load a keypair from `path`, prompting for a passphrase if it is encrypted
+pub fn load_keypair(path: &Path) -> Result<DilithiumKeypair> {
+    let json = Zeroizing::new(fs::read_to_string(path).context("Failed to read key file")?);
+    let key_file: KeyFile = serde_json::from_str(&json).context("Failed to parse key file")?;
+
+    if key_file.version != KEY_FILE_VERSION {
+        bail!("Unsupported key file version: {}", key_file.version);
+    }
+    if key_file.algorithm != KEY_ALGORITHM {
+        bail!("Unsupported key algorithm: {}", key_file.algorithm);
+    }
+
+    let public_key = hex::decode(&key_file.public_key).context("Invalid public key hex")?;
+
+    let secret_hex = if key_file.encrypted {
+        let ciphertext = hex::decode(&key_file.secret_key).context("Invalid ciphertext hex")?;
+        let passphrase = read_passphrase(false)?;
+        decrypt(&ciphertext, &passphrase)?
+    } else {
+        Zeroizing::new(key_file.secret_key.clone().into_bytes())
+    };
+
+    let secret_key = Zeroizing::new(
+        hex::decode(secret_hex.as_slice()).context("Invalid secret key hex")?,
+    );
+
+    DilithiumKeypair::from_bytes(public_key, secret_key)
+}
+
+/// Read a passphrase from `REML_KEY_PASSPHRASE` or the terminal
+///
+/// When `confirm` is set (new key files), the prompt asks twice.
+pub fn read_passphrase(confirm: bool) -> Result<SecretString> {
+    if let Ok(passphrase) = std::env::var(PASSPHRASE_ENV) {
+        if passphrase.is_empty() {
+            bail!("{} is set but empty", PASSPHRASE_ENV);
+        }
+        return Ok(Secret::new(passphrase));
+    }
+
+    let passphrase = rpassword::prompt_password("Key passphrase: ")
+        .context("Failed to read passphrase")?;
+    if passphrase.is_empty() {
+        bail!("Passphrase must not be empty (use --insecure-plaintext for test keys)");
+    }
+
+    if confirm {
+        let again = Zeroizing::new(
+            rpassword::prompt_password("Confirm passphrase: ")
+                .context("Failed to read passphrase")?,
+        );
+        if passphrase != *again {
+            bail!("Passphrases do not match");
+        }
+    }
+
+    Ok(Secret::new(passphrase))
+}
+
+/// Read a mnemonic from `REML_MNEMONIC` or the terminal
+pub fn read_mnemonic() -> Result<bip39::Mnemonic> {
+    let phrase = match std::env::var(MNEMONIC_ENV) {
+        Ok(phrase) => Zeroizing::new(phrase),
+        Err(_) => Zeroizing::new(
+            rpassword::prompt_password("Mnemonic: ").context("Failed to read mnemonic")?,
+        ),
+    };
+
+    bip39::Mnemonic::parse_normalized(phrase.trim())
+        .map_err(|e| anyhow::anyhow!("Invalid mnemonic: {}", e))
+}
+
+/// Generate a fresh 24-word mnemonic
+pub fn generate_mnemonic() -> Result<bip39::Mnemonic> {
+    bip39::Mnemonic::generate(24).map_err(|e| anyhow::anyhow!("Mnemonic generation failed: {}", e))
+}
+
+/// BIP39 passphrase from `REML_MNEMONIC_PASSPHRASE` (empty if unset)
+pub fn mnemonic_passphrase() -> Zeroizing<String> {
+    Zeroizing::new(std::env::var(MNEMONIC_PASSPHRASE_ENV).unwrap_or_default())
+}
+
+fn encrypt(plaintext: &[u8], passphrase: &SecretString) -> Result<Vec<u8>> {
+    let encryptor = age::Encryptor::with_user_passphrase(passphrase.clone());
+
+    let mut ciphertext = Vec::new();
+    let mut writer = encryptor
+        .wrap_output(&mut ciphertext)
+        .context("Failed to initialize encryption")?;
+    writer.write_all(plaintext)?;
+    writer.finish()?;
+
+    Ok(ciphertext)
+}
+
+fn decrypt(ciphertext: &[u8], passphrase: &SecretString) -> Result<Zeroizing<Vec<u8>>> {
+    let decryptor = match age::Decryptor::new(ciphertext).context("Invalid key ciphertext")? {
+        age::Decryptor::Passphrase(d) => d,
+        _ => bail!("Key file is not passphrase-encrypted"),
+    };
+
+    let mut plaintext = Zeroizing::new(Vec::new());
+    let mut reader = decryptor
+        .decrypt(passphrase, None)
+        .map_err(|_| anyhow::anyhow!("Wrong passphrase or corrupted key file"))?;
+    reader.read_to_end(&mut plaintext)?;
+
+    Ok(plaintext)
+}
+
+/// Write a file readable only by the current user
+fn write_private(path: &Path, contents: &[u8]) -> Result<()> {
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create_new(true);
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+
+    let mut file = options
+        .open(path)
+        .with_context(|| format!("Failed to create key file {:?} (refusing to overwrite)", path))?;
+    file.write_all(contents).context("Failed to write key file")?;
+
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TESTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reml_lib::MLDSA_SIGNATURE_SIZE;
+
+    #[test]
+    fn test_sign_produces_detached_signature() {
+        let keypair = DilithiumKeypair::generate();
+        let signature = keypair.sign(&[7u8; 32]);
+
+        assert_eq!(keypair.public_key().len(), MLDSA_PUBLIC_KEY_SIZE);
+        assert_eq!(signature.len(), MLDSA_SIGNATURE_SIZE);
+    }
+
+    #[test]
+    fn test_mnemonic_derivation_is_deterministic() {
+        let mnemonic = bip39::Mnemonic::parse_normalized(
+            "abandon abandon abandon abandon abandon abandon \
+             abandon abandon abandon abandon abandon about",
+        )
+        .unwrap();
+
+        let a = DilithiumKeypair::from_mnemonic(&mnemonic, "", 0, 0);
+        let b = DilithiumKeypair::from_mnemonic(&mnemonic, "", 0, 0);
+        let c = DilithiumKeypair::from_mnemonic(&mnemonic, "", 0, 1);
+
+        assert_eq!(a.public_key().len(), MLDSA_PUBLIC_KEY_SIZE);
+        assert_eq!(a.public_key(), b.public_key());
+        assert_ne!(a.public_key(), c.public_key());
+
+        // Derived keys sign with the regular signing path
+        let message = [9u8; 32];
+        let signature = dilithium2::DetachedSignature::from_bytes(&a.sign(&message)).unwrap();
+        let pk = dilithium2::PublicKey::from_bytes(a.public_key()).unwrap();
+        assert!(dilithium2::verify_detached_signature(&signature, &message, &pk).is_ok());
+    }
+
+    #[test]
+    fn test_encrypt_roundtrip() {
+        let passphrase: SecretString = Secret::new("correct horse".into());
+        let ciphertext = encrypt(b"secret bytes", &passphrase).unwrap();
+
+        assert_ne!(&ciphertext[..], b"secret bytes");
+        assert_eq!(decrypt(&ciphertext, &passphrase).unwrap().as_slice(), b"secret bytes");
+
+        let wrong: SecretString = Secret::new("battery staple".into());
+        assert!(decrypt(&ciphertext, &wrong).is_err());
+    }
+}
@@ -7,7 +7,8 @@
 //! - **Proof Generation**: Invokes SP1 prover on signature batches
 //! - **Test Data Generation**: Creates valid ML-DSA signatures for testing
 //! - **Local Verification**: Verifies proofs before on-chain submission
-//! - **Aggregator Server**: HTTP server for receiving signature requests
+//! - **Aggregator Server**: HTTP server for receiving signature requests,
+//!   with a versioned JSON API described at `/schema`
 //!
 //! ## Usage
 //!
@@ -18,31 +19,100 @@
 //! # Generate proof
 //! reml-prover prove --input batch.json --output proof.json
 //!
+//! # Commit ABI-encoded public values, also verifiable by an SP1 Solidity verifier
+//! reml-prover prove --input batch.json --output proof.json --output-format abi
+//!
+//! # Fold compressed batch proofs into one (`RemlVerifier.submit_aggregate_proof`)
+//! reml-prover prove --input a.json --output proof_a.json --batch-id 1 --compressed stark
+//! reml-prover prove --input b.json --output proof_b.json --batch-id 2 --compressed stark
+//! reml-prover aggregate --proofs proof_a.json proof_b.json --output agg.json
+//!
+//! # Wrap the proof in a 260-byte Groth16 proof for cheap on-chain verification
+//! reml-prover prove --input batch.json --output proof.json --output-format abi --compressed groth16
+//!
+//! # Prove one urgent request on its own (`RemlVerifier.submit_single_proof`)
+//! reml-prover prove-single --request request.json --output proof.json --batch-id 7
+//!
 //! # Verify proof locally
 //! reml-prover verify --proof proof.json
 //!
+//! # ...and check that its proof commitment is still unused on-chain
+//! reml-prover verify --proof proof.json --url ws://127.0.0.1:9944
+//!
 //! # Run aggregator server
 //! reml-prover serve --port 8080
+//!
+//! # Keep the newest 100 proofs on disk, archiving every proof to S3
+//! reml-prover serve --port 8080 --retain-proofs 100 --export-to s3://evidence/reml
+//!
+//! # Submit every proof on-chain as it is generated (secret URI from REML_SIGNER_SURI)
+//! reml-prover serve --port 8080 --submit-url ws://127.0.0.1:9944
+//!
+//! # Only prove the requests the chain routes to this aggregator
+//! reml-prover serve --port 8080 --route-url ws://127.0.0.1:9944 --aggregator <hex>
+//!
+//! # Size batches so each proof takes about 5 minutes
+//! reml-prover serve --port 8080 --target-latency 300 --min-batch-size 16 --max-batch-size 256
+//!
+//! # Notify a webhook when manifest requests are verified on-chain
+//! reml-prover watch --url ws://127.0.0.1:9944 --manifest requests.json \
+//!     --webhook https://merchant.example/reml
+//!
+//! # Generate an encrypted signing key and sign a message hash
+//! reml-prover keygen --output vault.key
+//! reml-prover keygen --output vault.key --mnemonic --account 0 --index 0
+//! reml-prover sign --key vault.key --message <hex> --request-id 1 --output request.json
+//!
+//! # Sign a vault transfer (SHA3-256 of its canonical payload)
+//! reml-prover sign --key vault.key --transfer-from <hex> --transfer-to <hex> \
+//!     --amount 1000 --nonce 0 --request-id 1 --output request.json
+//!
+//! # Sign as HashML-DSA with a context string, like FIPS 204 wallets and HSMs
+//! reml-prover sign --key vault.key --message <hex> --mode pre_hash_sha3_256 \
+//!     --context <hex> --request-id 1 --output request.json
 //! ```
 
 use anyhow::{Context, Result, bail};
-use clap::{Parser, Subcommand};
-use pqcrypto_dilithium::dilithium2;
-use pqcrypto_traits::sign::{PublicKey, SecretKey};
+use clap::{Parser, Subcommand, ValueEnum};
 use reml_lib::{
-    RemlProofBundle, RemlProofInput, RemlProofOutput, SignatureRequest,
-    compute_requests_root, MLDSA_SIGNATURE_SIZE, MLDSA_PUBLIC_KEY_SIZE,
+    AggregatedProof, MessageMode, OutputFormat, ProofType, RemlAggregateBundle, RemlAggregateOutput,
+    RemlAggregationInput, RemlProofBundle, RemlProofInput, RemlProofOutput, SignatureRequest,
+    compute_requests_root, compute_requests_root_blake2, MAX_AGGREGATED_BATCHES, MAX_BATCH_SIZE,
+    MAX_CONTEXT_SIZE, MLDSA_SIGNATURE_SIZE, MLDSA_PUBLIC_KEY_SIZE, REML_PROOF_EPOCH,
+    GROTH16_PROOF_SIZE,
+};
+use sp1_sdk::{
+    ProverClient, SP1Proof, SP1ProofWithPublicValues, SP1Stdin, SP1VerifyingKey, HashableKey,
 };
-use sp1_sdk::{ProverClient, SP1Stdin, HashableKey};
+use sp1_verifier::Groth16Verifier;
+use std::collections::VecDeque;
 use std::fs;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
 use tracing::{info, warn, error};
 
+mod api;
+mod archive;
+mod keys;
+mod routing;
+mod sizing;
+mod submit;
+mod watch;
+
+use archive::ProofStore;
+use keys::DilithiumKeypair;
+use routing::Router;
+use sizing::BatchSizer;
+use submit::Submitter;
+
 /// The ELF binary of the guest program
 const GUEST_ELF: &[u8] = include_bytes!("../../target/elf/riscv32im-succinct-zkvm-elf");
 
+/// The ELF binary of the aggregation guest program
+const AGGREGATION_ELF: &[u8] = include_bytes!("../../target/elf/reml-aggregation-elf");
+
 // ═══════════════════════════════════════════════════════════════════════════
 // CLI INTERFACE
 // ═══════════════════════════════════════════════════════════════════════════
@@ -76,6 +146,68 @@ enum Commands {
         #[arg(short, long, default_value = "1")]
         batch_id: u64,
         
+        /// Proof epoch the target runtime accepts (`RemlProofEpoch`)
+        #[arg(long, default_value_t = REML_PROOF_EPOCH)]
+        epoch: u32,
+        
+        /// Public values encoding: `bincode`, or `abi` for Groth16 and SP1 Solidity verifiers
+        #[arg(long, default_value = "bincode")]
+        output_format: OutputFormat,
+        
+        /// Compress the proof: `stark` for a constant-size proof `aggregate` can
+        /// fold, `groth16` for a 260-byte proof checked with a pairing check
+        #[arg(long)]
+        compressed: Option<Compression>,
+        
+        /// Use mock prover (faster, for testing)
+        #[arg(long)]
+        mock: bool,
+    },
+    
+    /// Fold compressed batch proofs into one aggregate proof
+    ///
+    /// The aggregation guest verifies every batch proof inside the zkVM;
+    /// submit the bundle with `RemlVerifier.submit_aggregate_proof`.
+    Aggregate {
+        /// Proof bundles from `prove --compressed stark` (JSON), at most 16
+        #[arg(long, num_args = 1.., required = true)]
+        proofs: Vec<PathBuf>,
+        
+        /// Output file for the aggregate proof bundle (JSON)
+        #[arg(short, long)]
+        output: PathBuf,
+        
+        /// Use mock prover (faster, for testing; skips the batch proof checks)
+        #[arg(long)]
+        mock: bool,
+    },
+    
+    /// Prove a single signature request without waiting for a batch
+    ///
+    /// For large, urgent vault withdrawals: the bundle is submitted with
+    /// `RemlVerifier.submit_single_proof`, which only needs the request ID
+    /// and the owner key and message hashes from the bundle.
+    ProveSingle {
+        /// Signature request file produced by `sign` (JSON)
+        #[arg(short, long)]
+        request: PathBuf,
+        
+        /// Output file for the proof bundle (JSON)
+        #[arg(short, long)]
+        output: PathBuf,
+        
+        /// Batch ID for this proof
+        #[arg(short, long, default_value = "1")]
+        batch_id: u64,
+        
+        /// Proof epoch the target runtime accepts (`RemlProofEpoch`)
+        #[arg(long, default_value_t = REML_PROOF_EPOCH)]
+        epoch: u32,
+        
+        /// Public values encoding: `bincode`, or `abi` for Groth16 and SP1 Solidity verifiers
+        #[arg(long, default_value = "bincode")]
+        output_format: OutputFormat,
+        
         /// Use mock prover (faster, for testing)
         #[arg(long)]
         mock: bool,
@@ -86,6 +218,10 @@ enum Commands {
         /// Proof bundle file (JSON)
         #[arg(short, long)]
         proof: PathBuf,
+        
+        /// Node WebSocket URL; fails if the chain already used the proof commitment
+        #[arg(long)]
+        url: Option<String>,
     },
     
     /// Generate a test batch with real ML-DSA signatures
@@ -109,17 +245,164 @@ enum Commands {
         #[arg(short, long, default_value = "8080")]
         port: u16,
         
-        /// Batch size before generating proof
+        /// Batch size before generating proof (at most MAX_BATCH_SIZE = 256)
+        ///
+        /// The starting size when `--target-latency` is set.
         #[arg(long, default_value = "100")]
         batch_size: usize,
         
+        /// Adjust the batch size so each proof takes about this many seconds
+        #[arg(long)]
+        target_latency: Option<u64>,
+        
+        /// Smallest batch size `--target-latency` may pick
+        #[arg(long, default_value = "1", requires = "target_latency")]
+        min_batch_size: usize,
+        
+        /// Largest batch size `--target-latency` may pick
+        #[arg(long, default_value_t = MAX_BATCH_SIZE, requires = "target_latency")]
+        max_batch_size: usize,
+        
+        /// Proofs generated concurrently
+        #[arg(long, default_value = "1")]
+        max_in_flight: usize,
+        
+        /// Pending requests above which `/submit` answers 429 Too Many Requests
+        #[arg(long, default_value_t = 4 * MAX_BATCH_SIZE)]
+        max_pending: usize,
+        
         /// Output directory for proofs
         #[arg(long, default_value = "./proofs")]
         output_dir: PathBuf,
+        
+        /// Keep only the newest N proofs in the output directory
+        #[arg(long)]
+        retain_proofs: Option<usize>,
+        
+        /// Also export every proof to `s3://bucket/prefix` or a directory
+        #[arg(long)]
+        export_to: Option<String>,
+        
+        /// Public values encoding: `bincode`, or `abi` for Groth16 and SP1 Solidity verifiers
+        #[arg(long, default_value = "bincode")]
+        output_format: OutputFormat,
+        
+        /// Node WebSocket URL; only accept requests the chain routes to `--aggregator`
+        #[arg(long, requires = "aggregator")]
+        route_url: Option<String>,
+        
+        /// This aggregator's account (32 bytes, hex), for `--route-url`
+        #[arg(long, requires = "route_url")]
+        aggregator: Option<String>,
+        
+        /// Node WebSocket URL to submit every generated proof to (`RemlVerifier.submit_proof`)
+        #[arg(long)]
+        submit_url: Option<String>,
+        
+        /// Secret URI of the submitting account, e.g. `//Alice` (else `REML_SIGNER_SURI`)
+        #[arg(long, requires = "submit_url")]
+        signer_suri: Option<String>,
+    },
+    
+    /// Notify webhooks when requests from a manifest settle on-chain
+    Watch {
+        /// Node WebSocket RPC endpoint
+        #[arg(long, default_value = "ws://127.0.0.1:9944")]
+        url: String,
+        
+        /// JSON array of request IDs (or `{request_id, batch_id}` objects), re-read on change
+        #[arg(short, long)]
+        manifest: PathBuf,
+        
+        /// URL to POST each notification to (repeatable)
+        #[arg(long = "webhook")]
+        webhooks: Vec<String>,
+        
+        /// File to append each notification to as a JSON line
+        #[arg(long)]
+        queue_file: Option<PathBuf>,
     },
     
     /// Get verification key hash for the guest program
-    VKeyHash,
+    VKeyHash {
+        /// Print only the `0x…` hash (used by `cargo xtask vkey`)
+        #[arg(long)]
+        hex: bool,
+    },
+    
+    /// Generate a Dilithium2 signing key file
+    Keygen {
+        /// Output key file (JSON, created with 0600 permissions)
+        #[arg(short, long)]
+        output: PathBuf,
+        
+        /// Write the secret key unencrypted (test keys only)
+        #[arg(long)]
+        insecure_plaintext: bool,
+        
+        /// Derive the key from a BIP39 mnemonic (REML_MNEMONIC or prompt)
+        #[arg(long, conflicts_with = "generate_mnemonic")]
+        mnemonic: bool,
+        
+        /// Generate a new 24-word mnemonic, print it once and derive from it
+        #[arg(long)]
+        generate_mnemonic: bool,
+        
+        /// Derivation path account: m/13817'/<account>'/<index>'
+        #[arg(long, default_value = "0")]
+        account: u32,
+        
+        /// Derivation path index: m/13817'/<account>'/<index>'
+        #[arg(long, default_value = "0")]
+        index: u32,
+    },
+    
+    /// Sign a 32-byte message hash and emit a signature request
+    ///
+    /// With `--transfer-from`, the message is the SHA3-256 digest of the
+    /// canonical vault transfer payload instead.
+    Sign {
+        /// Key file produced by `keygen`
+        #[arg(short, long)]
+        key: PathBuf,
+        
+        /// Message hash to sign (32 bytes, hex)
+        #[arg(short, long, required_unless_present = "transfer_from")]
+        message: Option<String>,
+        
+        /// Vault account of the transfer to sign (32 bytes, hex)
+        #[arg(long, conflicts_with = "message", requires_all = ["transfer_to", "amount", "nonce"])]
+        transfer_from: Option<String>,
+        
+        /// Transfer recipient (32 bytes, hex)
+        #[arg(long)]
+        transfer_to: Option<String>,
+        
+        /// Transfer amount in planck
+        #[arg(long)]
+        amount: Option<u128>,
+        
+        /// Vault nonce of the transfer
+        #[arg(long)]
+        nonce: Option<u64>,
+        
+        /// Request ID to attach
+        #[arg(short, long)]
+        request_id: u64,
+        
+        /// Signed message: raw, pure (ML-DSA), pre_hash_sha256 or
+        /// pre_hash_sha3_256 (HashML-DSA over the 32-byte digest)
+        #[arg(long, default_value = "raw")]
+        mode: MessageMode,
+        
+        /// ML-DSA context string (hex, up to 255 bytes; not in raw mode)
+        #[arg(long, default_value = "")]
+        context: String,
+        
+        /// Output file for the signature request (JSON)
+        #[arg(short, long)]
+        output: PathBuf,
+    },
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
@@ -139,20 +422,86 @@ async fn main() -> Result<()> {
         .init();
     
     match cli.command {
-        Commands::Prove { input, output, batch_id, mock } => {
-            prove_batch(&input, &output, batch_id, mock).await?;
+        Commands::Prove { input, output, batch_id, epoch, output_format, compressed, mock } => {
+            prove_batch(&input, &output, batch_id, epoch, output_format, compressed, mock).await?;
         }
-        Commands::Verify { proof } => {
-            verify_proof(&proof).await?;
+        Commands::Aggregate { proofs, output, mock } => {
+            aggregate_proofs(&proofs, &output, mock).await?;
+        }
+        Commands::ProveSingle { request, output, batch_id, epoch, output_format, mock } => {
+            prove_single(&request, &output, batch_id, epoch, output_format, mock).await?;
+        }
+        Commands::Verify { proof, url } => {
+            verify_proof(&proof, url.as_deref()).await?;
         }
         Commands::GenTest { count, output, include_invalid } => {
             generate_test_batch(count, &output, include_invalid)?;
         }
-        Commands::Serve { port, batch_size, output_dir } => {
-            run_server(port, batch_size, output_dir).await?;
+        Commands::Serve {
+            port,
+            batch_size,
+            target_latency,
+            min_batch_size,
+            max_batch_size,
+            max_in_flight,
+            max_pending,
+            output_dir,
+            retain_proofs,
+            export_to,
+            output_format,
+            route_url,
+            aggregator,
+            submit_url,
+            signer_suri,
+        } => {
+            let sizer = batch_sizer(batch_size, min_batch_size, max_batch_size, target_latency)?;
+            let archive = ProofRetention::new(retain_proofs, export_to.as_deref())?;
+            let router = match (route_url, aggregator) {
+                (Some(url), Some(aggregator)) => Some(
+                    Router::connect(&url, parse_bytes32(&aggregator, "--aggregator")?).await?,
+                ),
+                _ => None,
+            };
+            let submitter = match submit_url {
+                Some(url) => Some(Submitter::connect(&url, signer_suri.as_deref()).await?),
+                None => None,
+            };
+            run_server(
+                port,
+                sizer,
+                max_in_flight,
+                max_pending,
+                output_dir,
+                archive,
+                output_format,
+                router,
+                submitter,
+            )
+            .await?;
+        }
+        Commands::Watch { url, manifest, webhooks, queue_file } => {
+            watch::run(&url, manifest, webhooks, queue_file).await?;
         }
-        Commands::VKeyHash => {
-            print_vkey_hash()?;
+        Commands::VKeyHash { hex } => {
+            print_vkey_hash(hex)?;
+        }
+        Commands::Keygen { output, insecure_plaintext, mnemonic, generate_mnemonic, account, index } => {
+            let derivation = (mnemonic || generate_mnemonic).then_some((generate_mnemonic, account, index));
+            generate_key_file(&output, insecure_plaintext, derivation)?;
+        }
+        Commands::Sign {
+            key, message, transfer_from, transfer_to, amount, nonce, request_id, mode, context, output,
+        } => {
+            let message = match (message, transfer_from, transfer_to, amount, nonce) {
+                (Some(message), ..) => parse_bytes32(&message, "message")?,
+                (None, Some(from), Some(to), Some(amount), Some(nonce)) => {
+                    transfer_digest(&from, &to, amount, nonce)?
+                }
+                _ => bail!("Provide --message or the vault transfer to sign"),
+            };
+            let context = hex::decode(context.trim_start_matches("0x"))
+                .context("Invalid context hex")?;
+            sign_message(&key, message, request_id, mode, context, &output)?;
         }
     }
     
@@ -167,6 +516,9 @@ async fn prove_batch(
     input_path: &PathBuf,
     output_path: &PathBuf,
     batch_id: u64,
+    epoch: u32,
+    output_format: OutputFormat,
+    compression: Option<Compression>,
     use_mock: bool,
 ) -> Result<()> {
     info!("Loading signature requests from {:?}", input_path);
@@ -178,11 +530,51 @@ async fn prove_batch(
     
     info!("Loaded {} signature requests", requests.len());
     
-    let proof_input = RemlProofInput::new(requests, batch_id);
-    let bundle = generate_proof(proof_input, use_mock).await?;
+    let proof_input = RemlProofInput::new(requests, batch_id)
+        .with_epoch(epoch)
+        .with_output_format(output_format);
+    let bundle = generate_proof(proof_input, use_mock, compression).await?;
+    save_bundle(&bundle, output_path)
+}
+
+async fn prove_single(
+    request_path: &PathBuf,
+    output_path: &PathBuf,
+    batch_id: u64,
+    epoch: u32,
+    output_format: OutputFormat,
+    use_mock: bool,
+) -> Result<()> {
+    info!("Loading signature request from {:?}", request_path);
     
-    // Save proof
-    let output_json = serde_json::to_string_pretty(&bundle)
+    let request_json = fs::read_to_string(request_path)
+        .context("Failed to read request file")?;
+    let request: SignatureRequest = serde_json::from_str(&request_json)
+        .context("Failed to parse request JSON")?;
+    
+    // The guest would just leave an invalid request out, and a proof of an
+    // empty batch cannot be submitted as a single proof
+    if !request.validate_sizes() || !reml_lib::test_batch::verify_request(&request) {
+        bail!("Request {} does not carry a valid signature", request.request_id);
+    }
+    
+    let request_id = request.request_id;
+    let proof_input = RemlProofInput::new(vec![request], batch_id)
+        .with_epoch(epoch)
+        .with_output_format(output_format);
+    let bundle = generate_proof(proof_input, use_mock, None).await?;
+    if bundle.output.verified_request_ids != [request_id] {
+        bail!("Guest did not verify request {}", request_id);
+    }
+    
+    save_bundle(&bundle, output_path)?;
+    info!("   Submit with RemlVerifier.submit_single_proof (request {})", request_id);
+    Ok(())
+}
+
+/// Write `bundle` to `output_path` and log its summary
+fn save_bundle(bundle: &RemlProofBundle, output_path: &PathBuf) -> Result<()> {
+    let output_json = serde_json::to_string_pretty(bundle)
         .context("Failed to serialize proof bundle")?;
     fs::write(output_path, output_json)
         .context("Failed to write output file")?;
@@ -191,11 +583,49 @@ async fn prove_batch(
     info!("   Verified: {} signatures", bundle.output.verified_count);
     info!("   Proof size: {} bytes", bundle.proof_size());
     info!("   Compression ratio: {:.1}x", bundle.compression_ratio());
+    info!("   Proof commitment: 0x{}", hex::encode(bundle.proof_commitment()));
     
     Ok(())
 }
 
-async fn generate_proof(input: RemlProofInput, use_mock: bool) -> Result<RemlProofBundle> {
+/// Proof `prove --compressed` generates instead of a core STARK
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum Compression {
+    /// Recursively compressed STARK of constant size, which `aggregate` folds
+    Stark,
+    /// The compressed STARK wrapped in a Groth16 proof by SP1's wrapper
+    Groth16,
+}
+
+impl Compression {
+    /// Proof type recorded in the bundle
+    fn proof_type(compression: Option<Self>) -> ProofType {
+        match compression {
+            None => ProofType::Core,
+            Some(Self::Stark) => ProofType::Compressed,
+            Some(Self::Groth16) => ProofType::Groth16,
+        }
+    }
+}
+
+async fn generate_proof(
+    input: RemlProofInput,
+    use_mock: bool,
+    compression: Option<Compression>,
+) -> Result<RemlProofBundle> {
+    // The guest asserts this bound too; fail fast instead of burning prover time
+    if !input.is_within_batch_limit() {
+        bail!(
+            "Batch size {} exceeds maximum of {} requests",
+            input.batch_size(),
+            MAX_BATCH_SIZE
+        );
+    }
+    // The pallet checks Groth16 proofs against the ABI encoding
+    if compression == Some(Compression::Groth16) && input.output_format != OutputFormat::Abi {
+        bail!("Groth16 proofs must commit ABI public values, add --output-format abi");
+    }
+    
     info!("Initializing SP1 prover client...");
     
     let client = if use_mock {
@@ -219,35 +649,154 @@ async fn generate_proof(input: RemlProofInput, use_mock: bool) -> Result<RemlPro
     info!("Verification key hash: 0x{}", hex::encode(vk.hash_bytes()));
     
     // Generate proof
-    let proof = client.prove(&pk, &stdin)
-        .run()
-        .context("Proof generation failed")?;
+    let prover = client.prove(&pk, &stdin);
+    let prover = match compression {
+        Some(Compression::Stark) => prover.compressed(),
+        Some(Compression::Groth16) => prover.groth16(),
+        None => prover,
+    };
+    let proof = prover.run().context("Proof generation failed")?;
     
     // Extract output
-    let output: RemlProofOutput = proof.public_values.read();
+    let output = decode_public_values(proof.public_values.as_slice(), input.output_format)?;
     
-    // Get vkey hash
-    let vkey_hash_bytes = vk.hash_bytes();
-    let mut vkey_hash = [0u8; 32];
-    vkey_hash.copy_from_slice(&vkey_hash_bytes[..32]);
-    
-    // Serialize proof
-    let proof_bytes = bincode::serialize(&proof)
-        .context("Failed to serialize proof")?;
+    let proof_type = Compression::proof_type(compression);
+    let (proof_bytes, vkey_hash) = if proof_type == ProofType::Groth16 {
+        // Submitted as is: `selector || A || B || C`, whose public inputs
+        // take the vkey's BN254 digest
+        let proof_bytes = proof.bytes();
+        if proof_bytes.len() != GROTH16_PROOF_SIZE {
+            bail!("Groth16 proof is {} bytes, expected {}", proof_bytes.len(), GROTH16_PROOF_SIZE);
+        }
+        (proof_bytes, vk.bytes32_raw())
+    } else {
+        // Get vkey hash
+        let vkey_hash_bytes = vk.hash_bytes();
+        let mut vkey_hash = [0u8; 32];
+        vkey_hash.copy_from_slice(&vkey_hash_bytes[..32]);
+        
+        // Serialize proof
+        let proof_bytes = bincode::serialize(&proof)
+            .context("Failed to serialize proof")?;
+        (proof_bytes, vkey_hash)
+    };
     
     info!("✅ Proof generated successfully!");
     info!("   Public output: {} verified, root: 0x{}",
           output.verified_count,
           hex::encode(&output.requests_root[..8]));
     
-    Ok(RemlProofBundle::new(proof_bytes, output, vkey_hash))
+    Ok(RemlProofBundle::new(proof_bytes, output, vkey_hash)
+        .with_output_format(input.output_format)
+        .with_proof_type(proof_type))
+}
+
+/// Decode the public values committed by the guest in `format`
+fn decode_public_values(bytes: &[u8], format: OutputFormat) -> Result<RemlProofOutput> {
+    match format {
+        OutputFormat::Bincode => {
+            bincode::deserialize(bytes).context("Failed to decode bincode public values")
+        }
+        OutputFormat::Abi => RemlProofOutput::abi_decode(bytes)
+            .context("Failed to decode ABI public values"),
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// PROOF AGGREGATION
+// ═══════════════════════════════════════════════════════════════════════════
+
+async fn aggregate_proofs(
+    proof_paths: &[PathBuf],
+    output_path: &PathBuf,
+    use_mock: bool,
+) -> Result<()> {
+    if proof_paths.len() > MAX_AGGREGATED_BATCHES {
+        bail!(
+            "{} batch proofs exceed the maximum of {} per aggregate proof",
+            proof_paths.len(),
+            MAX_AGGREGATED_BATCHES
+        );
+    }
+    
+    let client = if use_mock {
+        info!("Using mock prover for faster testing");
+        ProverClient::builder().mock().build()
+    } else {
+        info!("Using real SP1 prover (this may take a while)");
+        ProverClient::from_env()
+    };
+    let (_, batch_vk) = client.setup(GUEST_ELF);
+    
+    // Batch proofs go on the proof stdin, their public values in the input
+    let mut stdin = SP1Stdin::new();
+    let mut proofs = Vec::with_capacity(proof_paths.len());
+    for path in proof_paths {
+        let bundle_json = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read proof file {:?}", path))?;
+        let bundle: RemlProofBundle = serde_json::from_str(&bundle_json)
+            .with_context(|| format!("Failed to parse proof JSON {:?}", path))?;
+        if bundle.proof_type != ProofType::Compressed {
+            bail!("{:?} is a {:?} proof; prove it with --compressed stark", path, bundle.proof_type);
+        }
+        if bundle.vkey_hash[..] != batch_vk.hash_bytes()[..32] {
+            bail!("{:?} was generated with a different guest program version", path);
+        }
+        
+        let proof: SP1ProofWithPublicValues = bincode::deserialize(&bundle.proof)
+            .context("Failed to deserialize proof")?;
+        let SP1Proof::Compressed(reduce_proof) = proof.proof else {
+            bail!("{:?} does not hold a compressed proof", path);
+        };
+        info!(
+            "Folding batch {} ({} signatures)",
+            bundle.output.batch_id, bundle.output.verified_count
+        );
+        stdin.write_proof(*reduce_proof, batch_vk.vk.clone());
+        proofs.push(AggregatedProof {
+            public_values: proof.public_values.to_vec(),
+            output_format: bundle.output_format,
+        });
+    }
+    stdin.write(&RemlAggregationInput { batch_vkey: batch_vk.hash_u32(), proofs });
+    
+    let (pk, vk) = client.setup(AGGREGATION_ELF);
+    let mut vkey_hash = [0u8; 32];
+    vkey_hash.copy_from_slice(&vk.hash_bytes()[..32]);
+    info!("Aggregation verification key hash: 0x{}", hex::encode(vkey_hash));
+    
+    // Mock batch proofs carry no real recursion proof to check
+    let proof = client
+        .prove(&pk, &stdin)
+        .compressed()
+        .deferred_proof_verification(!use_mock)
+        .run()
+        .context("Aggregate proof generation failed")?;
+    let output = RemlAggregateOutput::abi_decode(proof.public_values.as_slice())
+        .context("Failed to decode aggregate public values")?;
+    let proof_bytes = bincode::serialize(&proof)
+        .context("Failed to serialize proof")?;
+    let bundle = RemlAggregateBundle::new(proof_bytes, output, vkey_hash);
+    
+    let output_json = serde_json::to_string_pretty(&bundle)
+        .context("Failed to serialize aggregate proof bundle")?;
+    fs::write(output_path, output_json)
+        .context("Failed to write output file")?;
+    
+    info!("✅ Aggregate proof saved to {:?}", output_path);
+    info!("   Batches: {:?}", bundle.output.sub_batches.iter().map(|b| b.batch_id).collect::<Vec<_>>());
+    info!("   Verified: {} signatures", bundle.output.verified_count);
+    info!("   Proof size: {} bytes", bundle.proof.len());
+    info!("   Submit with RemlVerifier.submit_aggregate_proof");
+    
+    Ok(())
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
 // PROOF VERIFICATION
 // ═══════════════════════════════════════════════════════════════════════════
 
-async fn verify_proof(proof_path: &PathBuf) -> Result<()> {
+async fn verify_proof(proof_path: &PathBuf, url: Option<&str>) -> Result<()> {
     info!("Loading proof from {:?}", proof_path);
     
     let proof_json = fs::read_to_string(proof_path)
@@ -257,35 +806,82 @@ async fn verify_proof(proof_path: &PathBuf) -> Result<()> {
     
     info!("Proof details:");
     info!("  Batch ID: {}", bundle.output.batch_id);
+    info!("  Proof epoch: {}", bundle.output.epoch);
     info!("  Verified signatures: {}", bundle.output.verified_count);
     info!("  Requests root: 0x{}", hex::encode(&bundle.output.requests_root[..8]));
+    info!("  Requests root (blake2): 0x{}", hex::encode(&bundle.output.requests_root_blake2[..8]));
     info!("  Proof size: {} bytes", bundle.proof_size());
+    info!("  Public values: {:?}", bundle.output_format);
+    info!("  Proof type: {:?}", bundle.proof_type);
     info!("  VKey hash: 0x{}", hex::encode(&bundle.vkey_hash[..8]));
+    info!("  Proof commitment: 0x{}", hex::encode(bundle.proof_commitment()));
+    
+    // A used commitment would only be rejected with `ProofAlreadyUsed`
+    if let Some(url) = url {
+        if let Some(block) = watch::proof_commitment_block(url, bundle.proof_commitment()).await? {
+            bail!("Proof commitment already used on-chain at block {}", block);
+        }
+        info!("  Proof commitment unused on {}", url);
+    }
     
     // Verify with SP1
     let client = ProverClient::from_env();
     let (_, vk) = client.setup(GUEST_ELF);
     
-    // Check vkey matches
-    let expected_vkey = vk.hash_bytes();
-    if bundle.vkey_hash[..] != expected_vkey[..32] {
-        bail!("VKey hash mismatch! Proof was generated with different program version.");
+    if bundle.proof_type == ProofType::Groth16 {
+        verify_groth16_bundle(&bundle, &vk)?;
+    } else {
+        // Check vkey matches
+        let expected_vkey = vk.hash_bytes();
+        if bundle.vkey_hash[..] != expected_vkey[..32] {
+            bail!("VKey hash mismatch! Proof was generated with different program version.");
+        }
+        
+        // Deserialize and verify
+        let proof: sp1_sdk::SP1ProofWithPublicValues = bincode::deserialize(&bundle.proof)
+            .context("Failed to deserialize proof")?;
+        
+        info!("Verifying proof...");
+        client.verify(&proof, &vk)
+            .context("Proof verification failed")?;
+        
+        // The bundle's output must be what the proof actually commits
+        let committed = decode_public_values(proof.public_values.as_slice(), bundle.output_format)?;
+        if committed != bundle.output {
+            bail!("Bundle output does not match the public values committed in the proof");
+        }
     }
     
-    // Deserialize and verify
-    let proof: sp1_sdk::SP1ProofWithPublicValues = bincode::deserialize(&bundle.proof)
-        .context("Failed to deserialize proof")?;
-    
-    info!("Verifying proof...");
-    client.verify(&proof, &vk)
-        .context("Proof verification failed")?;
-    
     info!("✅ Proof is VALID!");
     info!("   All {} signatures have been correctly verified in zkVM", bundle.output.verified_count);
     
     Ok(())
 }
 
+/// Pairing check of a Groth16 bundle against SP1's Groth16 verifying key,
+/// over the same public inputs as the pallet
+fn verify_groth16_bundle(bundle: &RemlProofBundle, vk: &SP1VerifyingKey) -> Result<()> {
+    if bundle.vkey_hash != vk.bytes32_raw() {
+        bail!("VKey hash mismatch! Proof was generated with different program version.");
+    }
+    if bundle.output_format != OutputFormat::Abi {
+        bail!("Groth16 bundle does not commit ABI public values");
+    }
+    if bundle.proof_size() != GROTH16_PROOF_SIZE {
+        bail!("Groth16 proof is {} bytes, expected {}", bundle.proof_size(), GROTH16_PROOF_SIZE);
+    }
+    
+    // Checked over the encoding of `output`, so a mismatched output fails too
+    info!("Verifying Groth16 proof...");
+    Groth16Verifier::verify(
+        &bundle.proof,
+        &bundle.output.abi_encode(),
+        &vk.bytes32(),
+        *sp1_verifier::GROTH16_VK_BYTES,
+    )
+    .map_err(|e| anyhow::anyhow!("Proof verification failed: {:?}", e))
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // TEST DATA GENERATION
 // ═══════════════════════════════════════════════════════════════════════════
@@ -293,52 +889,8 @@ async fn verify_proof(proof_path: &PathBuf) -> Result<()> {
 fn generate_test_batch(count: usize, output_path: &PathBuf, include_invalid: bool) -> Result<()> {
     info!("Generating {} test signatures...", count);
     
-    let mut requests = Vec::with_capacity(count);
     let invalid_count = if include_invalid { count / 10 } else { 0 };
-    
-    for i in 0..count {
-        // Generate keypair
-        let (pk, sk) = dilithium2::keypair();
-        
-        // Create message (simulated transaction hash)
-        let mut message = [0u8; 32];
-        message[0..8].copy_from_slice(&(i as u64).to_le_bytes());
-        // Add some entropy
-        for j in 8..32 {
-            message[j] = ((i * 7 + j * 13) % 256) as u8;
-        }
-        
-        // Sign
-        let signed_message = dilithium2::sign(&message, &sk);
-        let signed_bytes = signed_message.as_bytes();
-        
-        // Extract detached signature (message is prepended)
-        let signature = if i < invalid_count {
-            // Create invalid signature for testing
-            let mut invalid_sig = signed_bytes[32..].to_vec();
-            invalid_sig[0] ^= 0xFF; // Corrupt first byte
-            invalid_sig
-        } else {
-            signed_bytes[32..].to_vec()
-        };
-        
-        assert_eq!(signature.len(), MLDSA_SIGNATURE_SIZE,
-                   "Unexpected signature size: {} (expected {})", 
-                   signature.len(), MLDSA_SIGNATURE_SIZE);
-        
-        let request = SignatureRequest::new(
-            message,
-            pk.as_bytes().to_vec(),
-            signature,
-            i as u64,
-        );
-        
-        requests.push(request);
-        
-        if (i + 1) % 10 == 0 || i + 1 == count {
-            info!("  Generated {}/{} signatures", i + 1, count);
-        }
-    }
+    let requests = reml_lib::test_batch::generate_requests(count, invalid_count);
     
     // Calculate expected sizes
     let raw_size = count * (32 + MLDSA_PUBLIC_KEY_SIZE + MLDSA_SIGNATURE_SIZE);
@@ -359,34 +911,364 @@ fn generate_test_batch(count: usize, output_path: &PathBuf, include_invalid: boo
     Ok(())
 }
 
+// ═══════════════════════════════════════════════════════════════════════════
+// KEY MANAGEMENT
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// `derivation` is `Some((generate_new, account, index))` for mnemonic-derived keys
+fn generate_key_file(
+    output_path: &PathBuf,
+    insecure_plaintext: bool,
+    derivation: Option<(bool, u32, u32)>,
+) -> Result<()> {
+    let passphrase = if insecure_plaintext {
+        warn!("⚠️  Writing secret key UNENCRYPTED (--insecure-plaintext)");
+        None
+    } else {
+        Some(keys::read_passphrase(true)?)
+    };
+    
+    let keypair = match derivation {
+        Some((generate_new, account, index)) => {
+            let mnemonic = if generate_new {
+                let mnemonic = keys::generate_mnemonic()?;
+                // Printed to stdout (not the log) so it can be written down once
+                println!("{}", mnemonic);
+                warn!("⚠️  Write down the mnemonic above; it is the only backup of this key");
+                mnemonic
+            } else {
+                keys::read_mnemonic()?
+            };
+            info!("🔑 Deriving key at m/13817'/{}'/{}'", account, index);
+            DilithiumKeypair::from_mnemonic(&mnemonic, &keys::mnemonic_passphrase(), account, index)
+        }
+        None => DilithiumKeypair::generate(),
+    };
+    keys::save_keypair(&keypair, output_path, passphrase.as_ref())?;
+    
+    info!("🔐 Key file saved to {:?}", output_path);
+    info!("   Public key: 0x{}...", hex::encode(&keypair.public_key()[..16]));
+    info!("   Encrypted: {}", !insecure_plaintext);
+    
+    Ok(())
+}
+
+fn parse_bytes32(value_hex: &str, name: &str) -> Result<[u8; 32]> {
+    let bytes = hex::decode(value_hex.trim_start_matches("0x"))
+        .with_context(|| format!("Invalid {} hex", name))?;
+    bytes.try_into()
+        .map_err(|v: Vec<u8>| anyhow::anyhow!("{} must be 32 bytes, got {}", name, v.len()))
+}
+
+/// SHA3-256 of the canonical vault transfer payload (`tesserax_vault_payload`)
+fn transfer_digest(from_hex: &str, to_hex: &str, amount: u128, nonce: u64) -> Result<[u8; 32]> {
+    use sha3::{Digest, Sha3_256};
+    
+    // AccountId32 and u128 balances SCALE-encode to their raw / little-endian bytes
+    let from = parse_bytes32(from_hex, "transfer-from")?;
+    let to = parse_bytes32(to_hex, "transfer-to")?;
+    let payload = tesserax_vault_payload::transfer(&from, &to, &amount.to_le_bytes(), nonce);
+    info!("   Payload: 0x{}", hex::encode(&payload));
+    
+    Ok(Sha3_256::digest(&payload).into())
+}
+
+fn sign_message(
+    key_path: &PathBuf,
+    message: [u8; 32],
+    request_id: u64,
+    mode: MessageMode,
+    context: Vec<u8>,
+    output_path: &PathBuf,
+) -> Result<()> {
+    if context.len() > MAX_CONTEXT_SIZE {
+        bail!("Context is {} bytes, at most {} allowed", context.len(), MAX_CONTEXT_SIZE);
+    }
+    if mode == MessageMode::Raw && !context.is_empty() {
+        bail!("--context needs --mode pure or a pre-hash mode");
+    }
+    
+    let keypair = keys::load_keypair(key_path)?;
+    
+    let mut request = SignatureRequest::new(
+        message,
+        keypair.public_key().to_vec(),
+        Vec::new(),
+        request_id,
+    )
+    .with_mode(mode, context);
+    request.signature = keypair.sign(&request.signed_message());
+    
+    let json = serde_json::to_string_pretty(&request)
+        .context("Failed to serialize request")?;
+    fs::write(output_path, json)
+        .context("Failed to write output file")?;
+    
+    info!("✅ Signature request {} saved to {:?}", request_id, output_path);
+    
+    Ok(())
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // AGGREGATOR SERVER
 // ═══════════════════════════════════════════════════════════════════════════
 
+/// Proving time assumed for `Retry-After` before the first proof completes
+const DEFAULT_PROOF_TIME: Duration = Duration::from_secs(60);
+
+/// Batches whose requests `/request/<id>` still knows
+const RECENT_BATCHES: usize = 1024;
+
+/// Proving state of a batch
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum BatchOutcome {
+    Proving,
+    Proved,
+    Failed,
+}
+
+impl BatchOutcome {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Proving => "proving",
+            Self::Proved => "proved",
+            Self::Failed => "failed",
+        }
+    }
+}
+
+/// A batch handed to the prover and the requests in it
+struct BatchRecord {
+    batch_id: u64,
+    request_ids: Vec<u64>,
+    outcome: BatchOutcome,
+}
+
+/// What happens to proofs once saved to the output directory
+#[derive(Clone, Default)]
+struct ProofRetention {
+    /// Newest proofs kept in the output directory, all if `None`
+    retain: Option<usize>,
+    /// Where every proof is exported to
+    export: Option<Arc<dyn ProofStore>>,
+}
+
+impl ProofRetention {
+    fn new(retain: Option<usize>, export_to: Option<&str>) -> Result<Self> {
+        if retain == Some(0) {
+            bail!("--retain-proofs must be at least 1");
+        }
+        let export = export_to.map(archive::parse_export_target).transpose()?;
+        Ok(Self { retain, export: export.map(Arc::from) })
+    }
+    
+    /// Export the saved proof of `batch_id`, then prune the output directory
+    async fn apply(&self, output_dir: PathBuf, batch_id: u64, json: String) {
+        if self.retain.is_none() && self.export.is_none() {
+            return;
+        }
+        let this = self.clone();
+        let result = tokio::task::spawn_blocking(move || -> Result<()> {
+            if let Some(store) = &this.export {
+                match store.put(&archive::proof_file_name(batch_id), json.as_bytes()) {
+                    Ok(()) => info!("📦 Proof {} exported to {}", batch_id, store.describe()),
+                    Err(e) => {
+                        let kept = archive::mark_unexported(&output_dir, batch_id)?;
+                        error!("Failed to export proof {}: {:#}, kept as {:?}", batch_id, e, kept);
+                    }
+                }
+            }
+            if let Some(retain) = this.retain {
+                let pruned = archive::prune_proofs(&output_dir, retain)?;
+                if !pruned.is_empty() {
+                    info!("Pruned {} old proofs, keeping the newest {}", pruned.len(), retain);
+                }
+            }
+            Ok(())
+        })
+        .await;
+        
+        match result {
+            Ok(Ok(())) => {}
+            Ok(Err(e)) => error!("Failed to archive proof {}: {:#}", batch_id, e),
+            Err(e) => error!("Proof archiving task failed: {}", e),
+        }
+    }
+}
+
 /// Aggregator state
 struct AggregatorState {
     pending_requests: Vec<SignatureRequest>,
-    batch_size: usize,
+    sizer: BatchSizer,
     output_dir: PathBuf,
+    retention: ProofRetention,
+    output_format: OutputFormat,
     batch_counter: u64,
+    /// Proofs being generated
+    in_flight: usize,
+    /// Proofs generated concurrently; full batches beyond it wait
+    max_in_flight: usize,
+    /// Pending requests above which `/submit` answers 429
+    max_pending: usize,
+    /// Latest `RECENT_BATCHES` batches, oldest first
+    recent_batches: VecDeque<BatchRecord>,
+    /// Turns away requests routed to other aggregators, if set
+    router: Option<Arc<Router>>,
+    /// Submits generated proofs on-chain, if set
+    submitter: Option<Arc<Submitter>>,
+}
+
+impl AggregatorState {
+    /// Whether `/submit` should turn requests away: the queue is full, or
+    /// every prover slot is busy and a full batch is already waiting
+    fn is_saturated(&self) -> bool {
+        let pending = self.pending_requests.len();
+        pending >= self.max_pending
+            || (self.in_flight >= self.max_in_flight && pending >= self.sizer.current())
+    }
+    
+    /// Estimated seconds until the prover has room for new requests
+    ///
+    /// One proving time (the last proof's, else the latency target) per
+    /// round of waiting batches across the prover slots.
+    fn retry_after_secs(&self) -> u64 {
+        let per_proof = self.sizer.last_proof_time()
+            .or(self.sizer.target())
+            .unwrap_or(DEFAULT_PROOF_TIME);
+        let waiting_batches = self.pending_requests.len().div_ceil(self.sizer.current());
+        let rounds = waiting_batches.div_ceil(self.max_in_flight).max(1);
+        (per_proof.as_secs_f64() * rounds as f64).ceil().max(1.0) as u64
+    }
+    
+    /// Remember that `request_ids` are being proved in `batch_id`
+    fn record_batch(&mut self, batch_id: u64, request_ids: Vec<u64>) {
+        if self.recent_batches.len() == RECENT_BATCHES {
+            self.recent_batches.pop_front();
+        }
+        self.recent_batches.push_back(BatchRecord {
+            batch_id,
+            request_ids,
+            outcome: BatchOutcome::Proving,
+        });
+    }
+    
+    fn finish_batch(&mut self, batch_id: u64, outcome: BatchOutcome) {
+        if let Some(record) = self.recent_batches.iter_mut().find(|record| record.batch_id == batch_id) {
+            record.outcome = outcome;
+        }
+    }
+    
+    /// State of a submitted request and its batch, `None` if unknown
+    ///
+    /// A request ID submitted again reports its latest submission.
+    fn request_state(&self, request_id: u64) -> Option<(&'static str, Option<u64>)> {
+        if self.pending_requests.iter().any(|request| request.request_id == request_id) {
+            return Some(("pending", None));
+        }
+        self.recent_batches
+            .iter()
+            .rev()
+            .find(|record| record.request_ids.contains(&request_id))
+            .map(|record| (record.outcome.as_str(), Some(record.batch_id)))
+    }
 }
 
-async fn run_server(port: u16, batch_size: usize, output_dir: PathBuf) -> Result<()> {
+/// Check the `serve` batch size options and build the sizer
+fn batch_sizer(
+    batch_size: usize,
+    min_batch_size: usize,
+    max_batch_size: usize,
+    target_latency: Option<u64>,
+) -> Result<BatchSizer> {
+    if batch_size == 0 || batch_size > MAX_BATCH_SIZE {
+        bail!("--batch-size must be between 1 and {}", MAX_BATCH_SIZE);
+    }
+    
+    let Some(target_latency) = target_latency else {
+        return Ok(BatchSizer::new(batch_size, batch_size, batch_size, None));
+    };
+    if target_latency == 0 {
+        bail!("--target-latency must be at least 1 second");
+    }
+    if min_batch_size == 0 || min_batch_size > max_batch_size || max_batch_size > MAX_BATCH_SIZE {
+        bail!(
+            "--min-batch-size and --max-batch-size must satisfy 1 <= min <= max <= {}",
+            MAX_BATCH_SIZE
+        );
+    }
+    
+    Ok(BatchSizer::new(
+        batch_size,
+        min_batch_size,
+        max_batch_size,
+        Some(Duration::from_secs(target_latency)),
+    ))
+}
+
+async fn run_server(
+    port: u16,
+    sizer: BatchSizer,
+    max_in_flight: usize,
+    max_pending: usize,
+    output_dir: PathBuf,
+    retention: ProofRetention,
+    output_format: OutputFormat,
+    router: Option<Router>,
+    submitter: Option<Submitter>,
+) -> Result<()> {
     use tokio::net::TcpListener;
     use tokio::io::{AsyncReadExt, AsyncWriteExt};
     
+    if max_in_flight == 0 {
+        bail!("--max-in-flight must be at least 1");
+    }
+    if max_pending < sizer.max() {
+        bail!("--max-pending must be at least the largest batch size ({})", sizer.max());
+    }
+    
     info!("🚀 Starting Re-ML Aggregator Server on port {}...", port);
-    info!("   Batch size: {} signatures", batch_size);
+    match sizer.target() {
+        Some(target) => info!(
+            "   Batch size: {} signatures, adjusted within {}..={} for {}s proofs",
+            sizer.current(),
+            sizer.min(),
+            sizer.max(),
+            target.as_secs()
+        ),
+        None => info!("   Batch size: {} signatures", sizer.current()),
+    }
+    info!("   Backpressure: {} proofs in flight, {} pending requests", max_in_flight, max_pending);
     info!("   Output directory: {:?}", output_dir);
+    if let Some(retain) = retention.retain {
+        info!("   Retention: newest {} proofs", retain);
+    }
+    if let Some(store) = &retention.export {
+        info!("   Export: {}", store.describe());
+    }
+    info!("   Public values: {:?}", output_format);
+    if let Some(router) = &router {
+        info!("   Routing: requests routed to 0x{}", hex::encode(router.aggregator()));
+    }
+    if let Some(submitter) = &submitter {
+        info!("   Submission: proofs submitted by 0x{}", hex::encode(submitter.account()));
+    }
     
     // Create output directory
     fs::create_dir_all(&output_dir)?;
     
     let state = Arc::new(RwLock::new(AggregatorState {
         pending_requests: Vec::new(),
-        batch_size,
+        sizer,
         output_dir,
+        retention,
+        output_format,
         batch_counter: 0,
+        in_flight: 0,
+        max_in_flight,
+        max_pending,
+        recent_batches: VecDeque::new(),
+        router: router.map(Arc::new),
+        submitter: submitter.map(Arc::new),
     }));
     
     let listener = TcpListener::bind(format!("0.0.0.0:{}", port)).await?;
@@ -396,6 +1278,8 @@ async fn run_server(port: u16, batch_size: usize, output_dir: PathBuf) -> Result
     info!("  POST /submit - Submit a signature request");
     info!("  GET /status  - Get aggregator status");
     info!("  GET /batch   - Get current batch info");
+    info!("  GET /request/<id> - Get the state of a submitted request");
+    info!("  GET /schema  - JSON Schema of the API (version {})", api::API_VERSION);
     info!("");
     
     loop {
@@ -410,12 +1294,18 @@ async fn run_server(port: u16, batch_size: usize, output_dir: PathBuf) -> Result
                     let request = String::from_utf8_lossy(&buf[..n]);
                     
                     // Parse HTTP request
-                    let response = if request.starts_with("POST /submit") {
+                    let response = if let Err(requested) = api::negotiate_version(&request) {
+                        http_response(406, "Not Acceptable", &api::unsupported_version(&requested))
+                    } else if request.starts_with("POST /submit") {
                         handle_submit(&request, &state).await
                     } else if request.starts_with("GET /status") {
                         handle_status(&state).await
                     } else if request.starts_with("GET /batch") {
                         handle_batch_info(&state).await
+                    } else if request.starts_with("GET /request/") {
+                        handle_request_status(&request, &state).await
+                    } else if request.starts_with("GET /schema") {
+                        http_response(200, "OK", &api::schema().to_string())
                     } else {
                         http_response(404, "Not Found", r#"{"error": "Not found"}"#)
                     };
@@ -437,12 +1327,22 @@ async fn handle_submit(request: &str, state: &Arc<RwLock<AggregatorState>>) -> S
     // Extract body from HTTP request
     let body = request.split("\r\n\r\n").nth(1).unwrap_or("");
     
-    // Parse signature request
-    let sig_request: SignatureRequest = match serde_json::from_str(body) {
+    // Parse signature request, checking it against the published schema first
+    let value: serde_json::Value = match serde_json::from_str(body) {
+        Ok(value) => value,
+        Err(e) => {
+            return http_response(400, "Bad Request", 
+                &serde_json::json!({ "error": format!("Invalid JSON: {}", e) }).to_string());
+        }
+    };
+    if let Err(e) = api::validate(&value, "SignatureRequest") {
+        return http_response(400, "Bad Request", &e.to_json());
+    }
+    let sig_request: SignatureRequest = match serde_json::from_value(value) {
         Ok(r) => r,
         Err(e) => {
             return http_response(400, "Bad Request", 
-                &format!(r#"{{"error": "Invalid JSON: {}"}}"#, e));
+                &serde_json::json!({ "error": format!("Invalid request: {}", e) }).to_string());
         }
     };
     
@@ -452,49 +1352,47 @@ async fn handle_submit(request: &str, state: &Arc<RwLock<AggregatorState>>) -> S
             r#"{"error": "Invalid signature or public key size"}"#);
     }
     
+    // Leave requests routed to another aggregator to it
+    let router = state.read().await.router.clone();
+    if let Some(router) = router {
+        match router.redirect(sig_request.request_id).await {
+            Ok(Some(other)) => {
+                return http_response(421, "Misdirected Request", &serde_json::json!({
+                    "error": "Request routed to another aggregator",
+                    "suggested_aggregator": format!("0x{}", hex::encode(other)),
+                }).to_string());
+            }
+            Ok(None) => {}
+            Err(e) => warn!(
+                "Routing request {} failed, accepting it: {:#}",
+                sig_request.request_id,
+                e
+            ),
+        }
+    }
+    
+    let shared = Arc::clone(state);
     let mut state = state.write().await;
+    if state.is_saturated() {
+        let retry_after = state.retry_after_secs();
+        warn!(
+            "Prover saturated ({} pending, {} proofs in flight), asking clients to retry in {}s",
+            state.pending_requests.len(),
+            state.in_flight,
+            retry_after
+        );
+        return too_many_requests(retry_after);
+    }
+    
     let request_id = sig_request.request_id;
     state.pending_requests.push(sig_request);
     
     let pending = state.pending_requests.len();
-    let batch_size = state.batch_size;
+    let batch_size = state.sizer.current();
     
     info!("Received signature request {} (pending: {}/{})", request_id, pending, batch_size);
     
-    // Check if batch is complete
-    if pending >= batch_size {
-        // Trigger proof generation
-        let requests = std::mem::take(&mut state.pending_requests);
-        state.batch_counter += 1;
-        let batch_id = state.batch_counter;
-        let output_dir = state.output_dir.clone();
-        drop(state); // Release lock before async work
-        
-        tokio::spawn(async move {
-            info!("Batch {} complete, generating proof...", batch_id);
-            
-            let input = RemlProofInput::new(requests, batch_id);
-            
-            match generate_proof(input, false).await {
-                Ok(bundle) => {
-                    let output_path = output_dir.join(format!("proof_{}.json", batch_id));
-                    match serde_json::to_string_pretty(&bundle) {
-                        Ok(json) => {
-                            if let Err(e) = fs::write(&output_path, json) {
-                                error!("Failed to save proof: {}", e);
-                            } else {
-                                info!("✅ Proof {} saved to {:?}", batch_id, output_path);
-                            }
-                        }
-                        Err(e) => error!("Failed to serialize proof: {}", e),
-                    }
-                }
-                Err(e) => {
-                    error!("Failed to generate proof for batch {}: {}", batch_id, e);
-                }
-            }
-        });
-        
+    if let Some(batch_id) = start_next_batch(&shared, &mut state) {
         return http_response(200, "OK", 
             &format!(r#"{{"status": "accepted", "request_id": {}, "batch_triggered": {}}}"#, 
                      request_id, batch_id));
@@ -505,6 +1403,89 @@ async fn handle_submit(request: &str, state: &Arc<RwLock<AggregatorState>>) -> S
                  request_id, pending))
 }
 
+/// Prove the next batch in the background if a full one is pending and a
+/// prover slot is free
+///
+/// Returns the ID of the started batch. When the proof finishes, the next
+/// waiting batch takes its slot.
+fn start_next_batch(
+    shared: &Arc<RwLock<AggregatorState>>,
+    state: &mut AggregatorState,
+) -> Option<u64> {
+    let batch_size = state.sizer.current();
+    if state.pending_requests.len() < batch_size || state.in_flight >= state.max_in_flight {
+        return None;
+    }
+    
+    let requests: Vec<SignatureRequest> = state.pending_requests.drain(..batch_size).collect();
+    state.batch_counter += 1;
+    state.in_flight += 1;
+    let batch_id = state.batch_counter;
+    state.record_batch(batch_id, requests.iter().map(|r| r.request_id).collect());
+    let output_dir = state.output_dir.clone();
+    let retention = state.retention.clone();
+    let output_format = state.output_format;
+    let submitter = state.submitter.clone();
+    let shared = Arc::clone(shared);
+    
+    tokio::spawn(async move {
+        info!("Batch {} complete, generating proof...", batch_id);
+        
+        let size = requests.len();
+        let input = RemlProofInput::new(requests, batch_id).with_output_format(output_format);
+        
+        let started = Instant::now();
+        let outcome = match generate_proof(input, false, None).await {
+            Ok(bundle) => {
+                let elapsed = started.elapsed();
+                let next = shared.write().await.sizer.record(size, elapsed);
+                info!(
+                    "Batch {} ({} signatures) proved in {:.1}s, next batch size: {}",
+                    batch_id,
+                    size,
+                    elapsed.as_secs_f64(),
+                    next
+                );
+                
+                let output_path = output_dir.join(archive::proof_file_name(batch_id));
+                match serde_json::to_string_pretty(&bundle) {
+                    Ok(json) => {
+                        if let Err(e) = fs::write(&output_path, &json) {
+                            error!("Failed to save proof: {}", e);
+                        } else {
+                            info!("✅ Proof {} saved to {:?}", batch_id, output_path);
+                            retention.apply(output_dir, batch_id, json).await;
+                        }
+                    }
+                    Err(e) => error!("Failed to serialize proof: {}", e),
+                }
+                
+                // Finalization takes a while; don't hold the prover slot for it
+                if let Some(submitter) = submitter {
+                    tokio::spawn(async move {
+                        if let Err(e) = submitter.submit(&bundle).await {
+                            error!("{:#}", e);
+                        }
+                    });
+                }
+                BatchOutcome::Proved
+            }
+            Err(e) => {
+                error!("Failed to generate proof for batch {}: {}", batch_id, e);
+                BatchOutcome::Failed
+            }
+        };
+        
+        // Free the slot for a batch that filled up meanwhile
+        let mut state = shared.write().await;
+        state.finish_batch(batch_id, outcome);
+        state.in_flight -= 1;
+        start_next_batch(&shared, &mut state);
+    });
+    
+    Some(batch_id)
+}
+
 async fn handle_status(state: &Arc<RwLock<AggregatorState>>) -> String {
     let state = state.read().await;
     
@@ -512,10 +1493,28 @@ async fn handle_status(state: &Arc<RwLock<AggregatorState>>) -> String {
   "status": "running",
   "pending_requests": {},
   "batch_size": {},
+  "min_batch_size": {},
+  "max_batch_size": {},
+  "target_latency_secs": {},
+  "last_proof_secs": {},
+  "in_flight_proofs": {},
+  "max_in_flight": {},
+  "max_pending": {},
+  "saturated": {},
+  "retry_after_secs": {},
   "batches_completed": {}
 }}"#, 
         state.pending_requests.len(),
-        state.batch_size,
+        state.sizer.current(),
+        state.sizer.min(),
+        state.sizer.max(),
+        json_option(state.sizer.target().map(|target| target.as_secs())),
+        json_option(state.sizer.last_proof_time().map(|elapsed| format!("{:.1}", elapsed.as_secs_f64()))),
+        state.in_flight,
+        state.max_in_flight,
+        state.max_pending,
+        state.is_saturated(),
+        json_option(state.is_saturated().then(|| state.retry_after_secs())),
         state.batch_counter
     );
     
@@ -535,17 +1534,58 @@ async fn handle_batch_info(state: &Arc<RwLock<AggregatorState>>) -> String {
   "request_ids": {:?}
 }}"#,
         state.pending_requests.len(),
-        state.batch_size,
+        state.sizer.current(),
         request_ids
     );
     
     http_response(200, "OK", &json)
 }
 
+async fn handle_request_status(request: &str, state: &Arc<RwLock<AggregatorState>>) -> String {
+    let request_id = request
+        .split_whitespace()
+        .nth(1)
+        .and_then(|path| path.strip_prefix("/request/"))
+        .and_then(|id| id.parse::<u64>().ok());
+    let Some(request_id) = request_id else {
+        return http_response(400, "Bad Request", r#"{"error": "Invalid request ID"}"#);
+    };
+    
+    let state = state.read().await;
+    match state.request_state(request_id) {
+        Some((request_state, batch_id)) => {
+            let json = serde_json::json!({
+                "request_id": request_id,
+                "state": request_state,
+                "batch_id": batch_id,
+            });
+            http_response(200, "OK", &json.to_string())
+        }
+        None => http_response(404, "Not Found", r#"{"error": "Unknown request"}"#),
+    }
+}
+
+/// `value` as a JSON number, or `null`
+fn json_option(value: Option<impl std::fmt::Display>) -> String {
+    value.map_or_else(|| "null".to_string(), |value| value.to_string())
+}
+
 fn http_response(status: u16, status_text: &str, body: &str) -> String {
     format!(
-        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
-        status, status_text, body.len(), body
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nAPI-Version: {}\r\nContent-Length: {}\r\n\r\n{}",
+        status, status_text, api::API_VERSION, body.len(), body
+    )
+}
+
+/// 429 response asking the client to retry in `retry_after` seconds
+fn too_many_requests(retry_after: u64) -> String {
+    let body = format!(
+        r#"{{"error": "Prover saturated", "retry_after_secs": {}}}"#,
+        retry_after
+    );
+    format!(
+        "HTTP/1.1 429 Too Many Requests\r\nContent-Type: application/json\r\nAPI-Version: {}\r\nRetry-After: {}\r\nContent-Length: {}\r\n\r\n{}",
+        api::API_VERSION, retry_after, body.len(), body
     )
 }
 
@@ -553,7 +1593,7 @@ fn http_response(status: u16, status_text: &str, body: &str) -> String {
 // UTILITY
 // ═══════════════════════════════════════════════════════════════════════════
 
-fn print_vkey_hash() -> Result<()> {
+fn print_vkey_hash(hex_only: bool) -> Result<()> {
     info!("Computing verification key hash for guest program...");
     
     let client = ProverClient::from_env();
@@ -561,6 +1601,11 @@ fn print_vkey_hash() -> Result<()> {
     
     let hash = vk.hash_bytes();
     
+    if hex_only {
+        println!("0x{}", hex::encode(&hash));
+        return Ok(());
+    }
+    
     println!();
     println!("═══════════════════════════════════════════════════════════════════");
     println!("  Re-ML Guest Program Verification Key");
@@ -581,9 +1626,10 @@ fn print_vkey_hash() -> Result<()> {
     }
     println!("]");
     println!();
-    println!("  Use this hash in runtime/src/configs/mod.rs:");
+    println!("  Pin it in the runtime with `cargo xtask vkey`, or by hand in");
+    println!("  runtime/src/configs/reml_vkey.rs:");
     println!();
-    println!("    pub ExpectedVKeyHash: [u8; 32] = [");
+    println!("    pub const REML_VKEY_HASH: [u8; 32] = [");
     for i in (0..32).step_by(8) {
         print!("        ");
         for j in i..(i + 8).min(32) {
@@ -596,6 +1642,11 @@ fn print_vkey_hash() -> Result<()> {
     }
     println!("    ];");
     println!();
+    println!("  Groth16 proofs (`prove --compressed groth16`) commit the BN254");
+    println!("  digest instead; pin that when the runtime has a Groth16 key:");
+    println!();
+    println!("    0x{}", hex::encode(vk.bytes32_raw()));
+    println!();
     println!("═══════════════════════════════════════════════════════════════════");
     
     Ok(())
@@ -608,6 +1659,10 @@ fn print_vkey_hash() -> Result<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use pqcrypto_dilithium::dilithium2;
+    use pqcrypto_falcon::falcon512;
+    use pqcrypto_traits::sign::{DetachedSignature, PublicKey, SignedMessage};
+    use reml_lib::SignatureScheme;
     
     #[test]
     fn test_generate_signature() {
@@ -635,11 +1690,207 @@ mod tests {
         assert_eq!(parsed.public_key.len(), MLDSA_PUBLIC_KEY_SIZE);
     }
     
+    #[test]
+    fn test_request_mode_defaults_to_raw() {
+        let request = SignatureRequest::new(
+            [0u8; 32],
+            vec![1u8; MLDSA_PUBLIC_KEY_SIZE],
+            vec![2u8; MLDSA_SIGNATURE_SIZE],
+            1,
+        );
+        let mut json = serde_json::to_value(&request).unwrap();
+        json.as_object_mut().unwrap().remove("mode");
+        json.as_object_mut().unwrap().remove("context");
+        json.as_object_mut().unwrap().remove("scheme");
+        
+        // Requests written before message modes and schemes existed still parse
+        let parsed: SignatureRequest = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed, request);
+        
+        let pure = request.with_mode(MessageMode::Pure, b"wallet".to_vec());
+        let json = serde_json::to_string(&pure).unwrap();
+        assert!(json.contains(r#""mode":"pure""#));
+        assert!(json.contains(r#""context":"77616c6c6574""#));
+        assert_eq!(serde_json::from_str::<SignatureRequest>(&json).unwrap(), pure);
+    }
+    
+    #[test]
+    fn test_signature_binds_mode_and_context() {
+        let (pk, sk) = dilithium2::keypair();
+        let mut request = SignatureRequest::new([5u8; 32], pk.as_bytes().to_vec(), Vec::new(), 1)
+            .with_mode(MessageMode::PreHashSha256, b"hsm".to_vec());
+        request.signature = dilithium2::detached_sign(&request.signed_message(), &sk)
+            .as_bytes()
+            .to_vec();
+        assert!(reml_lib::test_batch::verify_request(&request));
+        
+        // The same signature doesn't verify under another mode or context
+        let other_mode = request.clone().with_mode(MessageMode::PreHashSha3_256, b"hsm".to_vec());
+        assert!(!reml_lib::test_batch::verify_request(&other_mode));
+        let other_context = request.clone().with_mode(MessageMode::PreHashSha256, b"app".to_vec());
+        assert!(!reml_lib::test_batch::verify_request(&other_context));
+        let raw = request.with_mode(MessageMode::Raw, Vec::new());
+        assert!(!reml_lib::test_batch::verify_request(&raw));
+    }
+    
+    #[test]
+    fn test_falcon_request_verifies() {
+        let (pk, sk) = falcon512::keypair();
+        let message = [9u8; 32];
+        let signature = falcon512::detached_sign(&message, &sk).as_bytes().to_vec();
+        let request = SignatureRequest::new(message, pk.as_bytes().to_vec(), signature, 1)
+            .with_scheme(SignatureScheme::Falcon512);
+        assert!(request.validate_sizes());
+        assert!(reml_lib::test_batch::verify_request(&request));
+        
+        // Not as an ML-DSA signature, nor over another message
+        let as_mldsa = request.clone().with_scheme(SignatureScheme::MlDsa44);
+        assert!(!as_mldsa.validate_sizes());
+        assert!(!reml_lib::test_batch::verify_request(&as_mldsa));
+        let mut other_message = request;
+        other_message.message[0] ^= 1;
+        assert!(!reml_lib::test_batch::verify_request(&other_message));
+    }
+    
+    #[test]
+    fn test_decode_public_values() {
+        let output = RemlProofOutput::new(
+            REML_PROOF_EPOCH,
+            1,
+            1,
+            compute_requests_root(&[9]),
+            compute_requests_root_blake2(&[9]),
+            vec![9],
+        )
+        .with_request_owners(vec![[7u8; 32]])
+        .with_request_messages(vec![[8u8; 32]]);
+        
+        let bincode_bytes = bincode::serialize(&output).unwrap();
+        assert_eq!(decode_public_values(&bincode_bytes, OutputFormat::Bincode).unwrap(), output);
+        assert_eq!(decode_public_values(&output.abi_encode(), OutputFormat::Abi).unwrap(), output);
+        assert!(decode_public_values(&bincode_bytes, OutputFormat::Abi).is_err());
+    }
+    
+    #[test]
+    fn test_batch_sizer_options() {
+        let fixed = batch_sizer(100, 1, MAX_BATCH_SIZE, None).unwrap();
+        assert_eq!((fixed.min(), fixed.current(), fixed.max()), (100, 100, 100));
+        
+        let dynamic = batch_sizer(100, 16, 128, Some(300)).unwrap();
+        assert_eq!((dynamic.min(), dynamic.current(), dynamic.max()), (16, 100, 128));
+        assert_eq!(dynamic.target(), Some(Duration::from_secs(300)));
+        
+        assert!(batch_sizer(0, 1, MAX_BATCH_SIZE, None).is_err());
+        assert!(batch_sizer(100, 1, MAX_BATCH_SIZE, Some(0)).is_err());
+        assert!(batch_sizer(100, 64, 32, Some(300)).is_err());
+        assert!(batch_sizer(100, 1, MAX_BATCH_SIZE + 1, Some(300)).is_err());
+    }
+    
+    #[test]
+    fn test_saturation_and_retry_after() {
+        let request = SignatureRequest::new(
+            [0u8; 32],
+            vec![1u8; MLDSA_PUBLIC_KEY_SIZE],
+            vec![2u8; MLDSA_SIGNATURE_SIZE],
+            1,
+        );
+        let mut state = AggregatorState {
+            pending_requests: vec![request; 9],
+            sizer: BatchSizer::new(10, 10, 10, None),
+            output_dir: PathBuf::new(),
+            retention: ProofRetention::default(),
+            output_format: OutputFormat::Bincode,
+            batch_counter: 0,
+            in_flight: 1,
+            max_in_flight: 1,
+            max_pending: 40,
+            recent_batches: VecDeque::new(),
+            router: None,
+            submitter: None,
+        };
+        
+        // Slot busy, next batch not full yet
+        assert!(!state.is_saturated());
+        
+        // Slot busy and a full batch waiting
+        state.pending_requests.push(state.pending_requests[0].clone());
+        assert!(state.is_saturated());
+        assert_eq!(state.retry_after_secs(), DEFAULT_PROOF_TIME.as_secs());
+        
+        // Free slot: only the queue limit applies
+        state.in_flight = 0;
+        assert!(!state.is_saturated());
+        state.pending_requests = vec![state.pending_requests[0].clone(); 40];
+        assert!(state.is_saturated());
+        
+        // Four batches waiting for one slot at 30 s per proof
+        state.sizer.record(10, Duration::from_secs(30));
+        assert_eq!(state.retry_after_secs(), 120);
+        
+        let response = too_many_requests(120);
+        assert!(response.starts_with("HTTP/1.1 429 Too Many Requests"));
+        assert!(response.contains("Retry-After: 120\r\n"));
+        assert!(response.contains(r#""retry_after_secs": 120"#));
+    }
+    
+    #[tokio::test]
+    async fn test_responses_parse_with_client_types() {
+        let request = |request_id| SignatureRequest::new(
+            [0u8; 32],
+            vec![1u8; MLDSA_PUBLIC_KEY_SIZE],
+            vec![2u8; MLDSA_SIGNATURE_SIZE],
+            request_id,
+        );
+        let mut state = AggregatorState {
+            pending_requests: vec![request(3)],
+            sizer: BatchSizer::new(2, 2, 2, None),
+            output_dir: PathBuf::new(),
+            retention: ProofRetention::default(),
+            output_format: OutputFormat::Bincode,
+            batch_counter: 2,
+            in_flight: 1,
+            max_in_flight: 1,
+            max_pending: 8,
+            recent_batches: VecDeque::new(),
+            router: None,
+            submitter: None,
+        };
+        state.record_batch(1, vec![1, 2]);
+        state.finish_batch(1, BatchOutcome::Proved);
+        state.record_batch(2, vec![4, 5]);
+        let state = Arc::new(RwLock::new(state));
+        
+        fn body<T: serde::de::DeserializeOwned>(response: String) -> T {
+            serde_json::from_str(response.split("\r\n\r\n").nth(1).unwrap()).unwrap()
+        }
+        let request_status = |request_id| {
+            let state = Arc::clone(&state);
+            async move {
+                let request = format!("GET /request/{} HTTP/1.1\r\n\r\n", request_id);
+                handle_request_status(&request, &state).await
+            }
+        };
+        
+        let proved: reml_client::RequestStatus = body(request_status(2).await);
+        assert_eq!((proved.state, proved.batch_id), (reml_client::RequestState::Proved, Some(1)));
+        let proving: reml_client::RequestStatus = body(request_status(5).await);
+        assert_eq!((proving.state, proving.batch_id), (reml_client::RequestState::Proving, Some(2)));
+        let pending: reml_client::RequestStatus = body(request_status(3).await);
+        assert_eq!((pending.state, pending.batch_id), (reml_client::RequestState::Pending, None));
+        assert!(request_status(9).await.starts_with("HTTP/1.1 404"));
+        
+        let status: reml_client::Status = body(handle_status(&state).await);
+        assert_eq!((status.pending_requests, status.batches_completed), (1, 2));
+        let batch: reml_client::BatchInfo = body(handle_batch_info(&state).await);
+        assert_eq!(batch.request_ids, vec![3]);
+    }
+    
     #[test]
     fn test_http_response() {
         let response = http_response(200, "OK", r#"{"test": true}"#);
         assert!(response.contains("HTTP/1.1 200 OK"));
         assert!(response.contains("Content-Type: application/json"));
+        assert!(response.contains(&format!("API-Version: {}\r\n", api::API_VERSION)));
         assert!(response.contains(r#"{"test": true}"#));
     }
 }
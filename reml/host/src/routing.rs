@@ -0,0 +1,78 @@
+//! # Request Routing
+//!
+//! With several aggregators taking requests from the same clients, each
+//! request should be proved once. `serve --route-url` asks the chain which
+//! aggregator a request is routed to (`RemlApi_suggested_aggregator`) and
+//! only queues the requests routed to `--aggregator`:
+//!
+//! - The runtime routes ranges of 256 request IDs, a full batch, to one
+//!   active aggregator per era, drawn by bonded stake and watchtower track
+//!   record, so hosts agree on the assignment without talking to each other.
+//! - `/submit` answers a request routed elsewhere with `421 Misdirected
+//!   Request` and the `suggested_aggregator` to send it to.
+//! - A request is accepted when the chain routes it nowhere (no active
+//!   aggregator) or can't be reached, so routing never stalls proving.
+
+use anyhow::{Context, Result};
+use subxt::{OnlineClient, PolkadotConfig};
+
+/// Runtime API function routing a request ID
+const SUGGESTED_AGGREGATOR: &str = "RemlApi_suggested_aggregator";
+
+/// Chain connection answering which aggregator a request is routed to
+pub struct Router {
+    client: OnlineClient<PolkadotConfig>,
+    /// This host's aggregator account
+    aggregator: [u8; 32],
+}
+
+impl Router {
+    /// Connect to the node at `url`, routing for `aggregator`
+    pub async fn connect(url: &str, aggregator: [u8; 32]) -> Result<Self> {
+        let client = OnlineClient::<PolkadotConfig>::from_url(url)
+            .await
+            .with_context(|| format!("Failed to connect to {}", url))?;
+        Ok(Self { client, aggregator })
+    }
+
+    /// This host's aggregator account
+    pub fn aggregator(&self) -> [u8; 32] {
+        self.aggregator
+    }
+
+    /// Aggregator the best block routes `request_id` to, if any is active
+    pub async fn suggested_aggregator(&self, request_id: u64) -> Result<Option<[u8; 32]>> {
+        let params = request_id.to_le_bytes();
+        self.client
+            .runtime_api()
+            .at_latest()
+            .await?
+            .call_raw(SUGGESTED_AGGREGATOR, Some(&params))
+            .await
+            .context("Routing query failed")
+    }
+
+    /// Aggregator to send `request_id` to instead, `None` if it is ours
+    pub async fn redirect(&self, request_id: u64) -> Result<Option<[u8; 32]>> {
+        let suggested = self.suggested_aggregator(request_id).await?;
+        Ok(elsewhere(self.aggregator, suggested))
+    }
+}
+
+/// `suggested` unless it is `aggregator` or nobody
+fn elsewhere(aggregator: [u8; 32], suggested: Option<[u8; 32]>) -> Option<[u8; 32]> {
+    suggested.filter(|suggested| *suggested != aggregator)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_requests_routed_elsewhere_redirect() {
+        let ours = [1u8; 32];
+        assert_eq!(elsewhere(ours, Some(ours)), None);
+        assert_eq!(elsewhere(ours, None), None);
+        assert_eq!(elsewhere(ours, Some([2u8; 32])), Some([2u8; 32]));
+    }
+}
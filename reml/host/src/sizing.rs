@@ -0,0 +1,158 @@
+//! # Batch Sizing
+//!
+//! Effective batch size of `serve` mode.
+//!
+//! With `--target-latency`, the aggregator times every proof it generates
+//! and resizes the next batch so proving it takes about the target: the
+//! recent proofs give a proving time per signature, and the target divided
+//! by it is the new size, kept within `--min-batch-size`/`--max-batch-size`.
+//! Each adjustment at most halves or doubles the size, so a single slow or
+//! fast proof doesn't swing it across the whole range.
+//!
+//! Without a target the size stays at `--batch-size`.
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+/// Proofs the proving time estimate is averaged over
+const WINDOW: usize = 8;
+
+/// Picks the size of the next batch from recent proving times
+pub struct BatchSizer {
+    min: usize,
+    max: usize,
+    target: Option<Duration>,
+    current: usize,
+    /// Recent proofs as (batch size, proving time), oldest first
+    samples: VecDeque<(usize, Duration)>,
+}
+
+impl BatchSizer {
+    /// Sizer starting at `initial`, adjusted toward `target` within `min..=max`
+    ///
+    /// `initial` is clamped into the bounds.
+    pub fn new(initial: usize, min: usize, max: usize, target: Option<Duration>) -> Self {
+        Self {
+            min,
+            max,
+            target,
+            current: initial.clamp(min, max),
+            samples: VecDeque::with_capacity(WINDOW),
+        }
+    }
+
+    /// Size of the next batch
+    pub fn current(&self) -> usize {
+        self.current
+    }
+
+    pub fn min(&self) -> usize {
+        self.min
+    }
+
+    pub fn max(&self) -> usize {
+        self.max
+    }
+
+    pub fn target(&self) -> Option<Duration> {
+        self.target
+    }
+
+    /// Proving time of the most recent proof
+    pub fn last_proof_time(&self) -> Option<Duration> {
+        self.samples.back().map(|(_, elapsed)| *elapsed)
+    }
+
+    /// Record that a batch of `size` signatures took `elapsed` to prove
+    ///
+    /// Returns the new batch size.
+    pub fn record(&mut self, size: usize, elapsed: Duration) -> usize {
+        if size == 0 {
+            return self.current;
+        }
+        if self.samples.len() == WINDOW {
+            self.samples.pop_front();
+        }
+        self.samples.push_back((size, elapsed));
+
+        let Some(target) = self.target else {
+            return self.current;
+        };
+
+        let signatures: usize = self.samples.iter().map(|(size, _)| size).sum();
+        let total: Duration = self.samples.iter().map(|(_, elapsed)| *elapsed).sum();
+        let per_signature = total.as_secs_f64() / signatures as f64;
+        let ideal = if per_signature > 0.0 {
+            (target.as_secs_f64() / per_signature).floor() as usize
+        } else {
+            self.max
+        };
+
+        let step_min = (self.current / 2).max(1);
+        let step_max = self.current.saturating_mul(2);
+        self.current = ideal.clamp(step_min, step_max).clamp(self.min, self.max);
+        self.current
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TESTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECOND: Duration = Duration::from_secs(1);
+
+    #[test]
+    fn test_static_without_target() {
+        let mut sizer = BatchSizer::new(100, 1, 256, None);
+        assert_eq!(sizer.record(100, 600 * SECOND), 100);
+        assert_eq!(sizer.record(100, SECOND), 100);
+        assert_eq!(sizer.last_proof_time(), Some(SECOND));
+    }
+
+    #[test]
+    fn test_shrinks_when_proving_is_slow() {
+        // 6 s per signature, 300 s target: 50 signatures
+        let mut sizer = BatchSizer::new(100, 10, 256, Some(300 * SECOND));
+        assert_eq!(sizer.record(100, 600 * SECOND), 50);
+        assert_eq!(sizer.record(50, 300 * SECOND), 50);
+    }
+
+    #[test]
+    fn test_grows_at_most_double_per_step() {
+        // 1 s per signature, 300 s target: 300 signatures, capped at 256
+        let mut sizer = BatchSizer::new(40, 10, 256, Some(300 * SECOND));
+        assert_eq!(sizer.record(40, 40 * SECOND), 80);
+        assert_eq!(sizer.record(80, 80 * SECOND), 160);
+        assert_eq!(sizer.record(160, 160 * SECOND), 256);
+    }
+
+    #[test]
+    fn test_stays_within_bounds() {
+        let mut sizer = BatchSizer::new(20, 16, 64, Some(SECOND));
+        assert_eq!(sizer.current(), 20);
+        assert_eq!(sizer.record(20, 100 * SECOND), 16);
+
+        let mut sizer = BatchSizer::new(500, 1, 64, Some(SECOND));
+        assert_eq!(sizer.current(), 64);
+        assert_eq!(sizer.record(64, Duration::ZERO), 64);
+    }
+
+    #[test]
+    fn test_estimate_uses_recent_window() {
+        let mut sizer = BatchSizer::new(100, 1, 256, Some(100 * SECOND));
+        for _ in 0..WINDOW {
+            sizer.record(100, 400 * SECOND);
+        }
+        assert_eq!(sizer.current(), 25);
+
+        // Once the slow proofs leave the window, the size recovers
+        for _ in 0..WINDOW {
+            sizer.record(sizer.current(), Duration::from_secs(sizer.current() as u64));
+        }
+        assert_eq!(sizer.current(), 100);
+    }
+}
@@ -0,0 +1,257 @@
+//! # Proof Submission
+//!
+//! With `serve --submit-url`, every proof the aggregator generates is sent
+//! to the node as a `RemlVerifier.submit_proof` extrinsic, signed with the
+//! sr25519 account of `--signer-suri` (or `REML_SIGNER_SURI`, which keeps
+//! the secret out of the process list), instead of only being written to
+//! `--output-dir`:
+//!
+//! - The submission is anchored to the latest finalized block, well within
+//!   the runtime's `MaxAnchorAge`.
+//! - Nonces are handed out locally, so proofs finishing together don't
+//!   collide in the transaction pool. After a failed attempt the next one
+//!   asks the node again.
+//! - Transport and pool failures are retried with exponential backoff. A
+//!   submission the runtime rejected (e.g. `BatchAlreadyVerified`) is not.
+//!
+//! The proof file stays in `--output-dir` either way, so a proof that could
+//! not be submitted can still be sent by hand.
+
+use anyhow::{Context, Result, bail};
+use reml_lib::RemlProofBundle;
+use std::str::FromStr;
+use std::time::Duration;
+use subxt::config::polkadot::PolkadotExtrinsicParamsBuilder;
+use subxt::dynamic::{self, Value};
+use subxt::{OnlineClient, PolkadotConfig};
+use subxt_signer::SecretUri;
+use subxt_signer::sr25519::Keypair;
+use tokio::sync::Mutex;
+use tracing::{info, warn};
+
+/// Pallet name of the verifier in the runtime
+const PALLET: &str = "RemlVerifier";
+
+/// Environment variable holding the signer's secret URI
+const SIGNER_SURI_ENV: &str = "REML_SIGNER_SURI";
+
+/// Program ID of Re-ML batch proofs (`REML_PROGRAM_ID`)
+const REML_PROGRAM_ID: u32 = 1;
+
+/// Largest proof `ProofSubmission` holds
+const MAX_PROOF_SIZE: usize = 102_400;
+
+/// Submission attempts before a proof is given up on
+const SUBMIT_ATTEMPTS: u32 = 5;
+
+/// Chain connection submitting proofs as one aggregator account
+pub struct Submitter {
+    client: OnlineClient<PolkadotConfig>,
+    signer: Keypair,
+    /// Nonce of the next extrinsic, `None` to ask the node
+    next_nonce: Mutex<Option<u64>>,
+}
+
+impl Submitter {
+    /// Connect to the node at `url`, signing with `suri` or `REML_SIGNER_SURI`
+    pub async fn connect(url: &str, suri: Option<&str>) -> Result<Self> {
+        let suri = match suri {
+            Some(suri) => suri.to_string(),
+            None => std::env::var(SIGNER_SURI_ENV)
+                .with_context(|| format!("--signer-suri or {} is required", SIGNER_SURI_ENV))?,
+        };
+        let uri = SecretUri::from_str(&suri).context("Invalid signer secret URI")?;
+        let signer = Keypair::from_uri(&uri).context("Invalid signer secret URI")?;
+
+        let client = OnlineClient::<PolkadotConfig>::from_url(url)
+            .await
+            .with_context(|| format!("Failed to connect to {}", url))?;
+        Ok(Self { client, signer, next_nonce: Mutex::new(None) })
+    }
+
+    /// The signing account
+    pub fn account(&self) -> [u8; 32] {
+        self.signer.public_key().0
+    }
+
+    /// Submit `bundle` and wait until it is finalized, retrying with backoff
+    ///
+    /// Returns the block number the proof was included in.
+    pub async fn submit(&self, bundle: &RemlProofBundle) -> Result<u32> {
+        let batch_id = bundle.output.batch_id;
+        if bundle.proof.len() > MAX_PROOF_SIZE {
+            bail!(
+                "Proof of batch {} is {} bytes, above the {} bytes a submission holds",
+                batch_id,
+                bundle.proof.len(),
+                MAX_PROOF_SIZE
+            );
+        }
+
+        let mut delay = Duration::from_secs(1);
+        for attempt in 1..=SUBMIT_ATTEMPTS {
+            match self.try_submit(bundle).await {
+                Ok(block_number) => return Ok(block_number),
+                Err(e) if attempt < SUBMIT_ATTEMPTS && retryable(&e) => {
+                    warn!(
+                        "Submitting proof {} failed (attempt {}): {}, retrying",
+                        batch_id, attempt, e
+                    );
+                    tokio::time::sleep(delay).await;
+                    delay *= 2;
+                }
+                Err(e) => {
+                    return Err(e).with_context(|| format!("Failed to submit proof {}", batch_id));
+                }
+            }
+        }
+        unreachable!("the last attempt returns")
+    }
+
+    async fn try_submit(&self, bundle: &RemlProofBundle) -> Result<u32, subxt::Error> {
+        let anchor = self.client.blocks().at_latest().await?;
+        let call = dynamic::tx(
+            PALLET,
+            "submit_proof",
+            vec![submission(bundle, anchor.number(), anchor.hash().0)],
+        );
+
+        // Hold the nonce until the pool has the extrinsic, so concurrent
+        // proofs get consecutive nonces
+        let progress = {
+            let mut next_nonce = self.next_nonce.lock().await;
+            let nonce = match *next_nonce {
+                Some(nonce) => nonce,
+                None => {
+                    let account = self.signer.public_key().to_account_id();
+                    self.client.tx().account_nonce(&account).await?
+                }
+            };
+            let params =
+                PolkadotExtrinsicParamsBuilder::<PolkadotConfig>::new().nonce(nonce).build();
+
+            let submitted = async {
+                self.client
+                    .tx()
+                    .create_signed(&call, &self.signer, params)
+                    .await?
+                    .submit_and_watch()
+                    .await
+            }
+            .await;
+            *next_nonce = submitted.is_ok().then_some(nonce + 1);
+            submitted?
+        };
+
+        match progress.wait_for_finalized_success().await {
+            Ok(events) => {
+                let block = self.client.blocks().at(events.block_hash()).await?;
+                info!(
+                    "⛓️  Proof {} finalized in block #{}",
+                    bundle.output.batch_id,
+                    block.number()
+                );
+                Ok(block.number())
+            }
+            Err(e) => {
+                // Dropped or invalidated in the pool: its nonce may be unused
+                *self.next_nonce.lock().await = None;
+                Err(e)
+            }
+        }
+    }
+}
+
+/// `ProofSubmission` of `bundle`, anchored to `anchor_block`
+fn submission(bundle: &RemlProofBundle, anchor_block: u32, anchor_hash: [u8; 32]) -> Value {
+    let output = &bundle.output;
+    let request_ids =
+        output.verified_request_ids.iter().map(|request_id| Value::u128(*request_id as u128));
+    let request_owners = output.verified_request_owners.iter().map(Value::from_bytes);
+    let request_messages = output.verified_request_messages.iter().map(Value::from_bytes);
+
+    Value::named_composite([
+        ("batch_id", Value::u128(output.batch_id as u128)),
+        ("proof", Value::from_bytes(&bundle.proof)),
+        (
+            "public_values",
+            Value::named_composite([
+                ("version", Value::u128(output.version as u128)),
+                ("chain_id", Value::u128(output.chain_id as u128)),
+                ("epoch", Value::u128(output.epoch as u128)),
+                ("batch_id", Value::u128(output.batch_id as u128)),
+                ("verified_count", Value::u128(output.verified_count as u128)),
+                ("mldsa44_count", Value::u128(output.mldsa44_count as u128)),
+                ("falcon512_count", Value::u128(output.falcon512_count as u128)),
+                ("requests_root", Value::from_bytes(output.requests_root)),
+                ("requests_root_blake2", Value::from_bytes(output.requests_root_blake2)),
+                ("verified_request_ids", Value::unnamed_composite(request_ids)),
+                ("verified_request_owners", Value::unnamed_composite(request_owners)),
+                ("verified_request_messages", Value::unnamed_composite(request_messages)),
+            ]),
+        ),
+        ("vkey_hash", Value::from_bytes(bundle.vkey_hash)),
+        ("program_id", Value::u128(REML_PROGRAM_ID as u128)),
+        ("anchor_block", Value::u128(anchor_block as u128)),
+        ("anchor_hash", Value::from_bytes(anchor_hash)),
+    ])
+}
+
+/// Whether a failed attempt may succeed when repeated
+///
+/// Connection and transaction pool failures are; a dispatch error would
+/// only be rejected again.
+fn retryable(error: &subxt::Error) -> bool {
+    matches!(error, subxt::Error::Io(_) | subxt::Error::Rpc(_) | subxt::Error::Transaction(_))
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TESTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reml_lib::RemlProofOutput;
+    use subxt::ext::scale_value::{At, Composite, ValueDef};
+
+    #[test]
+    fn test_submission_matches_proof_submission() {
+        let output = RemlProofOutput::new(1, 7, 2, [0u8; 32], [0u8; 32], vec![3, 5])
+            .with_request_owners(vec![[4u8; 32], [6u8; 32]])
+            .with_request_messages(vec![[5u8; 32], [8u8; 32]]);
+        let bundle = RemlProofBundle::new(vec![0xab; 4], output, [9u8; 32]);
+        let value = submission(&bundle, 120, [1u8; 32]);
+
+        let field = |name: &str| value.at(name).unwrap();
+        assert_eq!(field("batch_id").as_u128(), Some(7));
+        assert_eq!(field("program_id").as_u128(), Some(REML_PROGRAM_ID as u128));
+        assert_eq!(field("anchor_block").as_u128(), Some(120));
+        assert_eq!(field("anchor_hash").at(31).unwrap().as_u128(), Some(1));
+        assert_eq!(field("vkey_hash").at(0).unwrap().as_u128(), Some(9));
+
+        let public_values = field("public_values");
+        assert_eq!(public_values.at("verified_count").unwrap().as_u128(), Some(2));
+        let ValueDef::Composite(Composite::Unnamed(ids)) =
+            &public_values.at("verified_request_ids").unwrap().value
+        else {
+            panic!("verified_request_ids is not a sequence");
+        };
+        assert_eq!(ids.iter().map(|id| id.as_u128().unwrap()).collect::<Vec<_>>(), vec![3, 5]);
+        let owner = public_values.at("verified_request_owners").unwrap().at(1).unwrap();
+        assert_eq!(owner.at(0).unwrap().as_u128(), Some(6));
+        let message = public_values.at("verified_request_messages").unwrap().at(1).unwrap();
+        assert_eq!(message.at(0).unwrap().as_u128(), Some(8));
+    }
+
+    #[test]
+    fn test_only_transport_and_pool_failures_are_retried() {
+        use subxt::error::TransactionError;
+
+        assert!(retryable(&subxt::Error::Transaction(TransactionError::Dropped(
+            "pool full".to_string()
+        ))));
+        assert!(retryable(&subxt::Error::Io(std::io::ErrorKind::ConnectionReset.into())));
+        assert!(!retryable(&subxt::Error::Other("bad metadata".to_string())));
+    }
+}
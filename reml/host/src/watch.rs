@@ -0,0 +1,413 @@
+//! # Chain Watch
+//!
+//! `reml-prover watch` follows the finalized blocks of a node and notifies
+//! when Re-ML batches settle requests listed in a local manifest, so a
+//! merchant gets a push instead of polling for its signature requests.
+//!
+//! ## Matching
+//!
+//! `ProofVerified` and `ProofRejected` only carry the batch ID. For a block
+//! with a `ProofVerified` event, every pending manifest request is looked up
+//! in `RemlVerifier::VerifiedRequests` at that block; requests verified by
+//! one of the block's batches are reported as `verified`. `ProofRejected`
+//! events are matched against the `batch_id` of manifest entries that have
+//! one (e.g. from the aggregator's `batch_triggered` response). Today the
+//! runtime reverts a failed submission together with its `ProofRejected`
+//! event, so in practice only verifications are reported.
+//!
+//! On start, requests already verified at the finalized head are reported
+//! too. Delivery is at-least-once: a restart reports them again, so
+//! receivers should deduplicate by `request_id`.
+//!
+//! ## Manifest
+//!
+//! A JSON array of request IDs or `{ "request_id": .., "batch_id": .. }`
+//! objects, re-read whenever the file changes:
+//!
+//! ```json
+//! [17, { "request_id": 18, "batch_id": 4 }]
+//! ```
+//!
+//! ## Sinks
+//!
+//! Each notification is POSTed as JSON to every `--webhook` (retried with
+//! backoff) and appended as one line to `--queue-file`, a spool file that a
+//! message queue forwarder can tail.
+
+use anyhow::{Context, Result, bail};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+use subxt::blocks::Block;
+use subxt::dynamic::{self, Value};
+use subxt::ext::scale_value::{At, Composite};
+use subxt::{OnlineClient, PolkadotConfig};
+use tracing::{error, info, warn};
+
+/// Pallet name of the verifier in the runtime
+const PALLET: &str = "RemlVerifier";
+
+/// Webhook delivery attempts before a notification is dropped
+const WEBHOOK_ATTEMPTS: u32 = 3;
+
+type Client = OnlineClient<PolkadotConfig>;
+
+/// Request ID, or a request ID with the batch it was submitted in
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum ManifestEntry {
+    Id(u64),
+    Request {
+        request_id: u64,
+        #[serde(default)]
+        batch_id: Option<u64>,
+    },
+}
+
+/// Requests to watch, with their batch ID if known
+#[derive(Debug, Default, PartialEq)]
+struct Manifest {
+    requests: BTreeMap<u64, Option<u64>>,
+}
+
+impl Manifest {
+    fn parse(json: &str) -> Result<Self> {
+        let entries: Vec<ManifestEntry> =
+            serde_json::from_str(json).context("Failed to parse manifest JSON")?;
+        let requests = entries
+            .into_iter()
+            .map(|entry| match entry {
+                ManifestEntry::Id(request_id) => (request_id, None),
+                ManifestEntry::Request { request_id, batch_id } => (request_id, batch_id),
+            })
+            .collect();
+        Ok(Self { requests })
+    }
+}
+
+/// Manifest file, re-read when its modification time changes
+struct ManifestFile {
+    path: PathBuf,
+    modified: Option<SystemTime>,
+    manifest: Manifest,
+}
+
+impl ManifestFile {
+    fn load(path: PathBuf) -> Result<Self> {
+        let mut file = Self { path, modified: None, manifest: Manifest::default() };
+        file.reload()?;
+        Ok(file)
+    }
+
+    /// Re-read the manifest if it changed; returns whether it did
+    fn reload(&mut self) -> Result<bool> {
+        let modified = fs::metadata(&self.path)
+            .and_then(|metadata| metadata.modified())
+            .with_context(|| format!("Failed to stat manifest {:?}", self.path))?;
+        if self.modified == Some(modified) {
+            return Ok(false);
+        }
+
+        let json = fs::read_to_string(&self.path)
+            .with_context(|| format!("Failed to read manifest {:?}", self.path))?;
+        self.manifest = Manifest::parse(&json)?;
+        self.modified = Some(modified);
+        Ok(true)
+    }
+}
+
+/// What happened to a watched request
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum Outcome {
+    Verified,
+    Rejected,
+}
+
+/// Payload delivered to the sinks
+#[derive(Debug, Serialize)]
+struct Notification {
+    event: Outcome,
+    request_id: u64,
+    batch_id: u64,
+    /// Block the batch was verified or rejected in
+    block_number: u32,
+    /// Finalized block the outcome was observed at (`0x…`)
+    finalized_hash: String,
+}
+
+/// Webhooks and the queue file notifications go to
+struct Sinks {
+    http: reqwest::Client,
+    webhooks: Vec<String>,
+    queue_file: Option<PathBuf>,
+}
+
+impl Sinks {
+    async fn deliver(&self, notification: &Notification) {
+        let body = match serde_json::to_string(notification) {
+            Ok(body) => body,
+            Err(e) => {
+                error!("Failed to serialize notification: {}", e);
+                return;
+            }
+        };
+
+        for url in &self.webhooks {
+            self.post(url, &body).await;
+        }
+
+        if let Some(path) = &self.queue_file {
+            let appended = fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)
+                .and_then(|mut file| writeln!(file, "{}", body));
+            if let Err(e) = appended {
+                error!("Failed to append to queue file {:?}: {}", path, e);
+            }
+        }
+    }
+
+    /// POST `body` to `url`, retrying with exponential backoff
+    async fn post(&self, url: &str, body: &str) {
+        let mut delay = Duration::from_secs(1);
+        for attempt in 1..=WEBHOOK_ATTEMPTS {
+            let result = self
+                .http
+                .post(url)
+                .header("Content-Type", "application/json")
+                .body(body.to_string())
+                .send()
+                .await
+                .and_then(|response| response.error_for_status());
+            match result {
+                Ok(_) => return,
+                Err(e) if attempt < WEBHOOK_ATTEMPTS => {
+                    warn!("Webhook {} failed (attempt {}): {}, retrying", url, attempt, e);
+                    tokio::time::sleep(delay).await;
+                    delay *= 2;
+                }
+                Err(e) => error!("Webhook {} failed, dropping notification: {}", url, e),
+            }
+        }
+    }
+}
+
+/// Follow finalized blocks at `url` and notify about `manifest` requests
+pub async fn run(
+    url: &str,
+    manifest: PathBuf,
+    webhooks: Vec<String>,
+    queue_file: Option<PathBuf>,
+) -> Result<()> {
+    if webhooks.is_empty() && queue_file.is_none() {
+        bail!("Configure at least one --webhook or a --queue-file");
+    }
+
+    let mut manifest = ManifestFile::load(manifest)?;
+    let sinks = Sinks { http: reqwest::Client::new(), webhooks, queue_file };
+    let api = Client::from_url(url)
+        .await
+        .with_context(|| format!("Failed to connect to {}", url))?;
+
+    info!("👀 Watching {} for {} manifest requests", url, manifest.manifest.requests.len());
+
+    // Requests already reported; a changed manifest only adds new ones
+    let mut settled: BTreeSet<u64> = BTreeSet::new();
+    let mut first = true;
+    let mut blocks = api.blocks().subscribe_finalized().await?;
+
+    while let Some(block) = blocks.next().await {
+        let block = block?;
+        let reloaded = manifest.reload()?;
+        if reloaded && !first {
+            info!("Manifest reloaded: {} requests", manifest.manifest.requests.len());
+        }
+
+        let (verified, rejected) = proof_events(&block).await?;
+        let pending: Vec<u64> = manifest
+            .manifest
+            .requests
+            .keys()
+            .filter(|id| !settled.contains(id))
+            .copied()
+            .collect();
+        let finalized_hash = format!("0x{}", hex::encode(block.hash()));
+
+        // A new manifest may list requests verified in earlier blocks
+        if first || reloaded || !verified.is_empty() {
+            for request_id in &pending {
+                let Some((batch_id, block_number)) = verified_request(&block, *request_id).await?
+                else {
+                    continue;
+                };
+                if !(first || reloaded) && !verified.contains(&batch_id) {
+                    continue;
+                }
+
+                info!("✅ Request {} verified in batch {} (#{})", request_id, batch_id, block_number);
+                settled.insert(*request_id);
+                sinks
+                    .deliver(&Notification {
+                        event: Outcome::Verified,
+                        request_id: *request_id,
+                        batch_id,
+                        block_number,
+                        finalized_hash: finalized_hash.clone(),
+                    })
+                    .await;
+            }
+        }
+
+        for request_id in &pending {
+            let Some(Some(batch_id)) = manifest.manifest.requests.get(request_id) else {
+                continue;
+            };
+            if settled.contains(request_id) || !rejected.contains(batch_id) {
+                continue;
+            }
+
+            warn!("❌ Request {} rejected with batch {}", request_id, batch_id);
+            settled.insert(*request_id);
+            sinks
+                .deliver(&Notification {
+                    event: Outcome::Rejected,
+                    request_id: *request_id,
+                    batch_id: *batch_id,
+                    block_number: block.number(),
+                    finalized_hash: finalized_hash.clone(),
+                })
+                .await;
+        }
+
+        first = false;
+    }
+
+    bail!("Finalized block subscription ended")
+}
+
+/// Block at which the finalized chain at `url` recorded `commitment` in
+/// `RemlVerifier::ProofCommitments`, `None` if it is unused
+pub async fn proof_commitment_block(url: &str, commitment: [u8; 32]) -> Result<Option<u32>> {
+    let api = Client::from_url(url)
+        .await
+        .with_context(|| format!("Failed to connect to {}", url))?;
+    let query = dynamic::storage(PALLET, "ProofCommitments", vec![Value::from_bytes(commitment)]);
+    let Some(entry) = api.storage().at_latest().await?.fetch(&query).await? else {
+        return Ok(None);
+    };
+
+    match entry.to_value()?.as_u128() {
+        Some(block_number) => Ok(Some(block_number as u32)),
+        None => bail!("Unexpected ProofCommitments entry"),
+    }
+}
+
+/// Batch IDs of the block's `ProofVerified` and `ProofRejected` events
+async fn proof_events(
+    block: &Block<PolkadotConfig, Client>,
+) -> Result<(BTreeSet<u64>, BTreeSet<u64>)> {
+    let mut verified = BTreeSet::new();
+    let mut rejected = BTreeSet::new();
+
+    for event in block.events().await?.iter() {
+        let event = event?;
+        if event.pallet_name() != PALLET {
+            continue;
+        }
+        let target = match event.variant_name() {
+            "ProofVerified" => &mut verified,
+            "ProofRejected" => &mut rejected,
+            _ => continue,
+        };
+        if let Some(batch_id) = named_u64(&event.field_values()?, "batch_id") {
+            target.insert(batch_id);
+        }
+    }
+
+    Ok((verified, rejected))
+}
+
+/// `VerifiedRequests` entry of `request_id` at `block`: (batch ID, block number)
+async fn verified_request(
+    block: &Block<PolkadotConfig, Client>,
+    request_id: u64,
+) -> Result<Option<(u64, u32)>> {
+    let query =
+        dynamic::storage(PALLET, "VerifiedRequests", vec![Value::u128(request_id as u128)]);
+    let Some(entry) = block.storage().fetch(&query).await? else {
+        return Ok(None);
+    };
+
+    let value = entry.to_value()?;
+    let batch_id = value.at(0).and_then(|v| v.as_u128());
+    let block_number = value.at(1).and_then(|v| v.as_u128());
+    match (batch_id, block_number) {
+        (Some(batch_id), Some(block_number)) => Ok(Some((batch_id as u64, block_number as u32))),
+        _ => bail!("Unexpected VerifiedRequests entry for request {}", request_id),
+    }
+}
+
+/// Unsigned integer field `name` of an event
+fn named_u64(fields: &Composite<u32>, name: &str) -> Option<u64> {
+    match fields {
+        Composite::Named(fields) => fields
+            .iter()
+            .find(|(field, _)| field == name)
+            .and_then(|(_, value)| value.as_u128())
+            .and_then(|value| u64::try_from(value).ok()),
+        Composite::Unnamed(_) => None,
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TESTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manifest_mixes_ids_and_requests() {
+        let manifest = Manifest::parse(r#"[17, { "request_id": 18, "batch_id": 4 }, { "request_id": 19 }]"#)
+            .unwrap();
+
+        assert_eq!(
+            manifest.requests,
+            BTreeMap::from([(17, None), (18, Some(4)), (19, None)])
+        );
+        assert!(Manifest::parse(r#"[{ "batch_id": 4 }]"#).is_err());
+    }
+
+    #[test]
+    fn test_named_u64() {
+        let fields = Composite::Named(vec![
+            ("batch_id".to_string(), Value::u128(7).map_context(|_| 0u32)),
+            ("signature_count".to_string(), Value::u128(3).map_context(|_| 0u32)),
+        ]);
+
+        assert_eq!(named_u64(&fields, "batch_id"), Some(7));
+        assert_eq!(named_u64(&fields, "aggregator"), None);
+    }
+
+    #[test]
+    fn test_notification_json() {
+        let notification = Notification {
+            event: Outcome::Verified,
+            request_id: 17,
+            batch_id: 4,
+            block_number: 120,
+            finalized_hash: "0xab".to_string(),
+        };
+
+        assert_eq!(
+            serde_json::to_string(&notification).unwrap(),
+            r#"{"event":"verified","request_id":17,"batch_id":4,"block_number":120,"finalized_hash":"0xab"}"#
+        );
+    }
+}
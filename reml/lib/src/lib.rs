@@ -4,10 +4,17 @@
 //!
 //! ## Components
 //!
-//! - **SignatureRequest**: A single ML-DSA signature verification request
+//! - **SignatureRequest**: A single ML-DSA or Falcon signature verification request
+//! - **SignatureScheme**: Which post-quantum scheme a request is signed with
+//! - **MessageMode**: How the signed message is built (raw, ML-DSA, HashML-DSA)
 //! - **RemlProofInput**: Input to the zkVM guest program
 //! - **RemlProofOutput**: Public output committed in the proof
+//! - **OutputFormat**: How the output is encoded (bincode or Solidity ABI)
+//! - **ProofType**: Which SP1 proof a bundle carries (core, compressed, Groth16)
 //! - **RemlProofBundle**: Complete proof with metadata for on-chain submission
+//! - **RemlAggregationInput** / **RemlAggregateOutput**: Input and public
+//!   output of the aggregation guest, which folds several batch proofs into one
+//! - **test_batch** (`full-crypto`): Signed test batches for provers and harnesses
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -16,6 +23,9 @@ extern crate alloc;
 use alloc::vec::Vec;
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "full-crypto")]
+pub mod test_batch;
+
 // ═══════════════════════════════════════════════════════════════════════════
 // CONSTANTS
 // ═══════════════════════════════════════════════════════════════════════════
@@ -26,22 +36,58 @@ pub const MLDSA_PUBLIC_KEY_SIZE: usize = 1312;
 /// ML-DSA (Dilithium2) signature size: 2420 bytes
 pub const MLDSA_SIGNATURE_SIZE: usize = 2420;
 
+/// Falcon-512 public key size: 897 bytes (header byte and 512 14-bit coefficients)
+pub const FALCON512_PUBLIC_KEY_SIZE: usize = 897;
+
+/// Longest Falcon-512 signature: 752 bytes
+///
+/// Signatures are compressed, so their length varies: every coefficient
+/// takes at least nine bits after the header byte and 40-byte nonce.
+pub const FALCON512_MAX_SIGNATURE_SIZE: usize = 752;
+
+/// Shortest well-formed Falcon-512 signature
+pub const FALCON512_MIN_SIGNATURE_SIZE: usize = 1 + FALCON512_NONCE_SIZE + 512 * 9 / 8;
+
+/// Falcon nonce size: 40 bytes
+pub const FALCON512_NONCE_SIZE: usize = 40;
+
 /// Maximum signatures per batch (limited by proof size and time)
 pub const MAX_BATCH_SIZE: usize = 256;
 
+/// Most batch proofs one aggregate proof folds (`MAX_AGGREGATED_BATCHES` on-chain)
+pub const MAX_AGGREGATED_BATCHES: usize = 16;
+
+/// SP1 Groth16 proof size: 4-byte verifying key selector, A, B and C
+pub const GROTH16_PROOF_SIZE: usize = 260;
+
+/// Longest ML-DSA context string (FIPS 204 §5.2)
+pub const MAX_CONTEXT_SIZE: usize = 255;
+
 /// Re-ML protocol version
-pub const REML_VERSION: u8 = 1;
+///
+/// 2: public values carry the blake2 requests root next to the keccak one
+/// 3: requests name their signature scheme, and public values carry
+///    per-scheme verified counts
+/// 4: public values carry the owner key hash of each verified request
+/// 5: public values carry the message hash of each verified request
+pub const REML_VERSION: u8 = 5;
 
 /// Tesserax chain ID (derived from floor(π × e × φ × 10^3))
 pub const TESSERAX_CHAIN_ID: u32 = 13817;
 
+/// Proof epoch accepted by the runtime (`RemlProofEpoch`)
+///
+/// Committed in every proof so it can't be replayed after a runtime upgrade
+/// that bumps the epoch.
+pub const REML_PROOF_EPOCH: u32 = 1;
+
 // ═══════════════════════════════════════════════════════════════════════════
 // SIGNATURE REQUEST
 // ═══════════════════════════════════════════════════════════════════════════
 
 /// A signature verification request
 ///
-/// Contains all data needed to verify an ML-DSA signature:
+/// Contains all data needed to verify an ML-DSA or Falcon signature:
 /// - The message hash being signed
 /// - The signer's public key
 /// - The signature bytes
@@ -51,16 +97,28 @@ pub struct SignatureRequest {
     /// Message hash (32 bytes, typically keccak256 of transaction)
     pub message: [u8; 32],
     
-    /// ML-DSA public key (1312 bytes for Dilithium2)
+    /// Public key (1312 bytes for Dilithium2, 897 for Falcon-512)
     #[serde(with = "hex_serde")]
     pub public_key: Vec<u8>,
     
-    /// ML-DSA signature (2420 bytes for Dilithium2)
+    /// Signature (2420 bytes for Dilithium2, at most 752 for Falcon-512)
     #[serde(with = "hex_serde")]
     pub signature: Vec<u8>,
     
     /// Unique request identifier
     pub request_id: u64,
+    
+    /// How `message` and `context` form the signed message
+    #[serde(default)]
+    pub mode: MessageMode,
+    
+    /// ML-DSA context string (at most 255 bytes, empty in `Raw` mode)
+    #[serde(default, with = "hex_serde")]
+    pub context: Vec<u8>,
+    
+    /// Scheme `signature` is in
+    #[serde(default)]
+    pub scheme: SignatureScheme,
 }
 
 impl SignatureRequest {
@@ -76,21 +134,220 @@ impl SignatureRequest {
             public_key,
             signature,
             request_id,
+            mode: MessageMode::Raw,
+            context: Vec::new(),
+            scheme: SignatureScheme::MlDsa44,
         }
     }
     
-    /// Validate that sizes match expected ML-DSA parameters
+    /// Sign `message` in `mode` with `context` instead of raw
+    pub fn with_mode(mut self, mode: MessageMode, context: Vec<u8>) -> Self {
+        self.mode = mode;
+        self.context = context;
+        self
+    }
+    
+    /// Signed with `scheme` instead of ML-DSA
+    pub fn with_scheme(mut self, scheme: SignatureScheme) -> Self {
+        self.scheme = scheme;
+        self
+    }
+    
+    /// Validate that sizes match the scheme's parameters
+    ///
+    /// Also rejects context strings longer than `MAX_CONTEXT_SIZE` and any
+    /// context in `Raw` mode, which has nowhere to bind it. Falcon has no
+    /// context strings or pre-hash modes, so its requests must be `Raw`.
     pub fn validate_sizes(&self) -> bool {
-        self.public_key.len() == MLDSA_PUBLIC_KEY_SIZE
-            && self.signature.len() == MLDSA_SIGNATURE_SIZE
+        let sizes_match = match self.scheme {
+            SignatureScheme::MlDsa44 => {
+                self.public_key.len() == MLDSA_PUBLIC_KEY_SIZE
+                    && self.signature.len() == MLDSA_SIGNATURE_SIZE
+            }
+            SignatureScheme::Falcon512 => {
+                self.public_key.len() == FALCON512_PUBLIC_KEY_SIZE
+                    && (FALCON512_MIN_SIGNATURE_SIZE..=FALCON512_MAX_SIGNATURE_SIZE)
+                        .contains(&self.signature.len())
+                    && self.mode == MessageMode::Raw
+            }
+        };
+        sizes_match
+            && self.context.len() <= MAX_CONTEXT_SIZE
+            && (self.mode != MessageMode::Raw || self.context.is_empty())
+    }
+    
+    /// The message M' the signature is over (FIPS 204 Algorithms 2 and 4)
+    ///
+    /// ```text
+    /// Raw:      message
+    /// Pure:     0x00 || len(ctx) || ctx || message
+    /// PreHash*: 0x01 || len(ctx) || ctx || OID(hash) || message
+    /// ```
+    ///
+    /// In the pre-hash modes `message` is the wallet's digest of the data.
+    /// Only meaningful once `validate_sizes` holds.
+    pub fn signed_message(&self) -> Vec<u8> {
+        let oid = self.mode.hash_oid();
+        let mut out = Vec::with_capacity(2 + self.context.len() + oid.len() + 32);
+        match self.mode {
+            MessageMode::Raw => {}
+            MessageMode::Pure => out.push(0),
+            MessageMode::PreHashSha256 | MessageMode::PreHashSha3_256 => out.push(1),
+        }
+        if self.mode != MessageMode::Raw {
+            out.push(self.context.len() as u8);
+            out.extend_from_slice(&self.context);
+            out.extend_from_slice(oid);
+        }
+        out.extend_from_slice(&self.message);
+        out
+    }
+    
+    /// Message hash of the request: blake2b-256 of [`Self::signed_message`]
+    ///
+    /// What a vault hashes the message it checks a signature against to, so
+    /// the runtime can tell a verified request authorizes exactly that
+    /// message.
+    pub fn message_hash(&self) -> [u8; 32] {
+        use blake2::{digest::consts::U32, Blake2b, Digest};
+        
+        Blake2b::<U32>::digest(self.signed_message()).into()
     }
     
     /// Get raw data size (for compression ratio calculation)
     pub fn raw_size(&self) -> usize {
-        32 + self.public_key.len() + self.signature.len() + 8
+        32 + self.public_key.len() + self.signature.len() + 8 + self.context.len()
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SIGNATURE SCHEME
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Post-quantum signature scheme of a request
+///
+/// A batch may mix schemes; the proof commits how many of each it verified.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SignatureScheme {
+    /// ML-DSA-44 (Dilithium2), FIPS 204
+    #[default]
+    MlDsa44,
+    /// Falcon-512, as submitted to NIST round 3 (compressed signatures)
+    Falcon512,
+}
+
+impl core::str::FromStr for SignatureScheme {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ml_dsa44" => Ok(Self::MlDsa44),
+            "falcon512" => Ok(Self::Falcon512),
+            _ => Err("expected `ml_dsa44` or `falcon512`"),
+        }
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// MESSAGE MODE
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// DER encoding of id-sha256 (2.16.840.1.101.3.4.2.1)
+const SHA256_OID: [u8; 11] = [0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x01];
+
+/// DER encoding of id-sha3-256 (2.16.840.1.101.3.4.2.8)
+const SHA3_256_OID: [u8; 11] = [0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x08];
+
+/// How a request's 32-byte `message` becomes the signed message
+///
+/// Wallets and HSMs differ: Dilithium round 3 libraries sign the bytes as
+/// given, FIPS 204 ML-DSA prefixes a domain byte and a context string, and
+/// HashML-DSA signs a digest tagged with the hash's OID.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MessageMode {
+    /// The 32 bytes themselves, as signed by `reml-prover sign`
+    #[default]
+    Raw,
+    /// Pure ML-DSA over the 32 bytes, with a context string
+    Pure,
+    /// HashML-DSA, `message` being the SHA-256 digest of the data
+    PreHashSha256,
+    /// HashML-DSA, `message` being the SHA3-256 digest of the data
+    PreHashSha3_256,
+}
+
+impl MessageMode {
+    /// OID of the pre-hash function, empty for the non-hashed modes
+    fn hash_oid(self) -> &'static [u8] {
+        match self {
+            Self::Raw | Self::Pure => &[],
+            Self::PreHashSha256 => &SHA256_OID,
+            Self::PreHashSha3_256 => &SHA3_256_OID,
+        }
+    }
+}
+
+impl core::str::FromStr for MessageMode {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "raw" => Ok(Self::Raw),
+            "pure" => Ok(Self::Pure),
+            "pre_hash_sha256" => Ok(Self::PreHashSha256),
+            "pre_hash_sha3_256" => Ok(Self::PreHashSha3_256),
+            _ => Err("expected `raw`, `pure`, `pre_hash_sha256` or `pre_hash_sha3_256`"),
+        }
     }
 }
 
+// ═══════════════════════════════════════════════════════════════════════════
+// OUTPUT FORMAT
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Encoding of the public values committed by the guest
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    /// `bincode` of [`RemlProofOutput`], read by `reml-prover`
+    #[default]
+    Bincode,
+    /// Solidity ABI encoding ([`RemlProofOutput::abi_encode`]), so the same
+    /// proof can also be checked by an SP1 verifier contract on Ethereum.
+    /// Groth16 proofs must use it: the pallet hashes these bytes too
+    Abi,
+}
+
+impl core::str::FromStr for OutputFormat {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "bincode" => Ok(Self::Bincode),
+            "abi" => Ok(Self::Abi),
+            _ => Err("expected `bincode` or `abi`"),
+        }
+    }
+}
+
+/// SP1 proof carried by a [`RemlProofBundle`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProofType {
+    /// `bincode` of `SP1ProofWithPublicValues` with the core STARK shard proofs
+    #[default]
+    Core,
+    /// `bincode` of `SP1ProofWithPublicValues` with one recursively
+    /// compressed STARK, which the aggregation guest can verify
+    Compressed,
+    /// SP1 Groth16 wrapping of the compressed STARK: the raw
+    /// [`GROTH16_PROOF_SIZE`]-byte proof the pallet checks with a BN254
+    /// pairing check. Its vkey hash is the BN254 digest (`vk.bytes32()`)
+    Groth16,
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // PROOF INPUT (for zkVM guest)
 // ═══════════════════════════════════════════════════════════════════════════
@@ -104,11 +361,18 @@ pub struct RemlProofInput {
     /// Chain ID (must match on-chain config)
     pub chain_id: u32,
     
+    /// Proof epoch (must match on-chain config)
+    pub epoch: u32,
+    
     /// Batch identifier
     pub batch_id: u64,
     
     /// List of signature requests to verify
     pub requests: Vec<SignatureRequest>,
+    
+    /// Encoding of the committed public values
+    #[serde(default)]
+    pub output_format: OutputFormat,
 }
 
 impl RemlProofInput {
@@ -117,16 +381,38 @@ impl RemlProofInput {
         Self {
             version: REML_VERSION,
             chain_id: TESSERAX_CHAIN_ID,
+            epoch: REML_PROOF_EPOCH,
             batch_id,
             requests,
+            output_format: OutputFormat::Bincode,
         }
     }
     
+    /// Target a proof epoch other than `REML_PROOF_EPOCH`
+    pub fn with_epoch(mut self, epoch: u32) -> Self {
+        self.epoch = epoch;
+        self
+    }
+    
+    /// Commit the public values in `format` instead of bincode
+    pub fn with_output_format(mut self, format: OutputFormat) -> Self {
+        self.output_format = format;
+        self
+    }
+    
     /// Number of requests in batch
     pub fn batch_size(&self) -> usize {
         self.requests.len()
     }
     
+    /// Check that the batch does not exceed `MAX_BATCH_SIZE`
+    ///
+    /// The guest asserts the same bound, so hosts should reject oversized
+    /// batches before spending any time on proving.
+    pub fn is_within_batch_limit(&self) -> bool {
+        self.requests.len() <= MAX_BATCH_SIZE
+    }
+    
     /// Total raw data size
     pub fn raw_size(&self) -> usize {
         self.requests.iter().map(|r| r.raw_size()).sum()
@@ -141,7 +427,7 @@ impl RemlProofInput {
 ///
 /// These values are cryptographically committed in the proof
 /// and verified on-chain.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RemlProofOutput {
     /// Protocol version
     pub version: u8,
@@ -149,37 +435,229 @@ pub struct RemlProofOutput {
     /// Chain ID
     pub chain_id: u32,
     
+    /// Proof epoch, copied from the input
+    pub epoch: u32,
+    
     /// Batch identifier
     pub batch_id: u64,
     
     /// Number of successfully verified signatures
     pub verified_count: u32,
     
-    /// Merkle root of verified request IDs
+    /// How many of them are ML-DSA-44 signatures
+    pub mldsa44_count: u32,
+    
+    /// How many of them are Falcon-512 signatures
+    pub falcon512_count: u32,
+    
+    /// Keccak merkle root of verified request IDs, for EVM consumers
     #[serde(with = "hex_serde_array")]
     pub requests_root: [u8; 32],
     
+    /// Blake2 merkle root of the same IDs, for Substrate consumers
+    #[serde(with = "hex_serde_array")]
+    pub requests_root_blake2: [u8; 32],
+    
     /// List of verified request IDs
     pub verified_request_ids: Vec<u64>,
+    
+    /// [`owner_key_hash`] of the key that signed each verified request, in
+    /// `verified_request_ids` order
+    #[serde(default, with = "hex_serde_array_list")]
+    pub verified_request_owners: Vec<[u8; 32]>,
+    
+    /// [`SignatureRequest::message_hash`] of each verified request, in
+    /// `verified_request_ids` order
+    #[serde(default, with = "hex_serde_array_list")]
+    pub verified_request_messages: Vec<[u8; 32]>,
 }
 
 impl RemlProofOutput {
     /// Create new proof output
+    ///
+    /// Counts every verified signature as ML-DSA-44; see
+    /// [`Self::with_scheme_counts`] for mixed batches.
     pub fn new(
+        epoch: u32,
         batch_id: u64,
         verified_count: u32,
         requests_root: [u8; 32],
+        requests_root_blake2: [u8; 32],
         verified_request_ids: Vec<u64>,
     ) -> Self {
         Self {
             version: REML_VERSION,
             chain_id: TESSERAX_CHAIN_ID,
+            epoch,
             batch_id,
             verified_count,
+            mldsa44_count: verified_count,
+            falcon512_count: 0,
             requests_root,
+            requests_root_blake2,
             verified_request_ids,
+            verified_request_owners: Vec::new(),
+            verified_request_messages: Vec::new(),
         }
     }
+    
+    /// Record the owner key hash of each verified request
+    pub fn with_request_owners(mut self, verified_request_owners: Vec<[u8; 32]>) -> Self {
+        self.verified_request_owners = verified_request_owners;
+        self
+    }
+    
+    /// Record the message hash of each verified request
+    pub fn with_request_messages(mut self, verified_request_messages: Vec<[u8; 32]>) -> Self {
+        self.verified_request_messages = verified_request_messages;
+        self
+    }
+    
+    /// Record how many verified signatures are of each scheme
+    pub fn with_scheme_counts(mut self, mldsa44_count: u32, falcon512_count: u32) -> Self {
+        self.mldsa44_count = mldsa44_count;
+        self.falcon512_count = falcon512_count;
+        self
+    }
+    
+    /// Solidity ABI encoding of the output
+    ///
+    /// Same bytes as
+    /// `abi.encode(uint8 version, uint32 chainId, uint32 epoch, uint64 batchId,
+    /// uint32 verifiedCount, uint32 mldsa44Count, uint32 falcon512Count,
+    /// bytes32 requestsRoot, bytes32 requestsRootBlake2, uint64[] verifiedRequestIds,
+    /// bytes32[] verifiedRequestOwners, bytes32[] verifiedRequestMessages)`,
+    /// so a contract can `abi.decode` the public values of an SP1 proof.
+    pub fn abi_encode(&self) -> Vec<u8> {
+        let ids = &self.verified_request_ids;
+        let owners = &self.verified_request_owners;
+        let messages = &self.verified_request_messages;
+        let mut out = Vec::with_capacity(
+            (ABI_HEAD_WORDS + 3 + ids.len() + owners.len() + messages.len()) * 32,
+        );
+        
+        out.extend_from_slice(&abi_word(self.version as u64));
+        out.extend_from_slice(&abi_word(self.chain_id as u64));
+        out.extend_from_slice(&abi_word(self.epoch as u64));
+        out.extend_from_slice(&abi_word(self.batch_id));
+        out.extend_from_slice(&abi_word(self.verified_count as u64));
+        out.extend_from_slice(&abi_word(self.mldsa44_count as u64));
+        out.extend_from_slice(&abi_word(self.falcon512_count as u64));
+        out.extend_from_slice(&self.requests_root);
+        out.extend_from_slice(&self.requests_root_blake2);
+        // Offsets of the dynamic arrays: the IDs right after the head, the
+        // owners after the IDs and the messages after the owners
+        let owners_at = ABI_HEAD_WORDS + 1 + ids.len();
+        out.extend_from_slice(&abi_word((ABI_HEAD_WORDS * 32) as u64));
+        out.extend_from_slice(&abi_word((owners_at * 32) as u64));
+        out.extend_from_slice(&abi_word(((owners_at + 1 + owners.len()) * 32) as u64));
+        out.extend_from_slice(&abi_word(ids.len() as u64));
+        for id in ids {
+            out.extend_from_slice(&abi_word(*id));
+        }
+        out.extend_from_slice(&abi_word(owners.len() as u64));
+        for owner in owners {
+            out.extend_from_slice(owner);
+        }
+        out.extend_from_slice(&abi_word(messages.len() as u64));
+        for message in messages {
+            out.extend_from_slice(message);
+        }
+        
+        out
+    }
+    
+    /// Decode [`Self::abi_encode`] output, `None` if not canonical
+    pub fn abi_decode(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() % 32 != 0 || bytes.len() < (ABI_HEAD_WORDS + 3) * 32 {
+            return None;
+        }
+        let words: Vec<&[u8]> = bytes.chunks(32).collect();
+        
+        let version = abi_uint(words[0], u8::MAX as u64)? as u8;
+        let chain_id = abi_uint(words[1], u32::MAX as u64)? as u32;
+        let epoch = abi_uint(words[2], u32::MAX as u64)? as u32;
+        let batch_id = abi_uint(words[3], u64::MAX)?;
+        let verified_count = abi_uint(words[4], u32::MAX as u64)? as u32;
+        let mldsa44_count = abi_uint(words[5], u32::MAX as u64)? as u32;
+        let falcon512_count = abi_uint(words[6], u32::MAX as u64)? as u32;
+        let mut requests_root = [0u8; 32];
+        requests_root.copy_from_slice(words[7]);
+        let mut requests_root_blake2 = [0u8; 32];
+        requests_root_blake2.copy_from_slice(words[8]);
+        
+        if abi_uint(words[9], u64::MAX)? != (ABI_HEAD_WORDS * 32) as u64 {
+            return None;
+        }
+        let ids_len = abi_uint(words[ABI_HEAD_WORDS], MAX_BATCH_SIZE as u64)? as usize;
+        let owners_at = ABI_HEAD_WORDS + 1 + ids_len;
+        if abi_uint(words[10], u64::MAX)? != (owners_at * 32) as u64 || words.len() <= owners_at {
+            return None;
+        }
+        let owners_len = abi_uint(words[owners_at], MAX_BATCH_SIZE as u64)? as usize;
+        let messages_at = owners_at + 1 + owners_len;
+        if abi_uint(words[11], u64::MAX)? != (messages_at * 32) as u64
+            || words.len() <= messages_at
+        {
+            return None;
+        }
+        let messages_len = abi_uint(words[messages_at], MAX_BATCH_SIZE as u64)? as usize;
+        if words.len() != messages_at + 1 + messages_len {
+            return None;
+        }
+        let verified_request_ids = words[ABI_HEAD_WORDS + 1..owners_at]
+            .iter()
+            .map(|word| abi_uint(word, u64::MAX))
+            .collect::<Option<Vec<u64>>>()?;
+        let hashes = |words: &[&[u8]]| {
+            words
+                .iter()
+                .map(|word| {
+                    let mut hash = [0u8; 32];
+                    hash.copy_from_slice(word);
+                    hash
+                })
+                .collect()
+        };
+        let verified_request_owners = hashes(&words[owners_at + 1..messages_at]);
+        let verified_request_messages = hashes(&words[messages_at + 1..]);
+        
+        Some(Self {
+            version,
+            chain_id,
+            epoch,
+            batch_id,
+            verified_count,
+            mldsa44_count,
+            falcon512_count,
+            requests_root,
+            requests_root_blake2,
+            verified_request_ids,
+            verified_request_owners,
+            verified_request_messages,
+        })
+    }
+}
+
+/// Static head words of the ABI encoding: nine values and the three array offsets
+const ABI_HEAD_WORDS: usize = 12;
+
+/// Big-endian, left-padded 32-byte ABI word
+fn abi_word(value: u64) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[24..].copy_from_slice(&value.to_be_bytes());
+    word
+}
+
+/// Read an ABI uint word, rejecting dirty padding and values above `max`
+fn abi_uint(word: &[u8], max: u64) -> Option<u64> {
+    if word[..24].iter().any(|b| *b != 0) {
+        return None;
+    }
+    let mut value = [0u8; 8];
+    value.copy_from_slice(&word[24..]);
+    let value = u64::from_be_bytes(value);
+    (value <= max).then_some(value)
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
@@ -202,40 +680,68 @@ pub struct RemlProofBundle {
     
     /// Timestamp when proof was generated
     pub generated_at: u64,
+    
+    /// Encoding of the public values committed in `proof`
+    #[serde(default)]
+    pub output_format: OutputFormat,
+    
+    /// Kind of SP1 proof in `proof`
+    #[serde(default)]
+    pub proof_type: ProofType,
 }
 
 impl RemlProofBundle {
     /// Create new proof bundle
     pub fn new(proof: Vec<u8>, output: RemlProofOutput, vkey_hash: [u8; 32]) -> Self {
-        use core::time::Duration;
-        
-        // Get timestamp (Unix epoch seconds)
-        #[cfg(feature = "std")]
-        let generated_at = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .map(|d| d.as_secs())
-            .unwrap_or(0);
-        
-        #[cfg(not(feature = "std"))]
-        let generated_at = 0u64;
-        
         Self {
             proof,
             output,
             vkey_hash,
-            generated_at,
+            generated_at: unix_time(),
+            output_format: OutputFormat::Bincode,
+            proof_type: ProofType::Core,
         }
     }
     
+    /// Record that the proof commits its public values in `format`
+    pub fn with_output_format(mut self, format: OutputFormat) -> Self {
+        self.output_format = format;
+        self
+    }
+    
+    /// Record that `proof` is a `proof_type` proof
+    pub fn with_proof_type(mut self, proof_type: ProofType) -> Self {
+        self.proof_type = proof_type;
+        self
+    }
+    
     /// Get proof size in bytes
     pub fn proof_size(&self) -> usize {
         self.proof.len()
     }
     
+    /// Commitment `pallet-reml-verifier` records for this bundle
+    ///
+    /// See [`compute_proof_commitment`]; the pallet rejects a second
+    /// submission with the same commitment with `ProofAlreadyUsed`.
+    pub fn proof_commitment(&self) -> [u8; 32] {
+        compute_proof_commitment(
+            &self.vkey_hash,
+            self.output.epoch,
+            self.output.batch_id,
+            &self.output.requests_root,
+            &self.proof,
+        )
+    }
+    
     /// Calculate compression ratio compared to raw signatures
+    ///
+    /// Falcon signatures are counted at their maximum size.
     pub fn compression_ratio(&self) -> f64 {
-        let raw_size = self.output.verified_count as usize 
-            * (32 + MLDSA_PUBLIC_KEY_SIZE + MLDSA_SIGNATURE_SIZE);
+        let raw_size = self.output.mldsa44_count as usize
+            * (32 + MLDSA_PUBLIC_KEY_SIZE + MLDSA_SIGNATURE_SIZE)
+            + self.output.falcon512_count as usize
+                * (32 + FALCON512_PUBLIC_KEY_SIZE + FALCON512_MAX_SIGNATURE_SIZE);
         
         if self.proof.len() > 0 {
             raw_size as f64 / self.proof.len() as f64
@@ -246,55 +752,342 @@ impl RemlProofBundle {
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
-// MERKLE ROOT COMPUTATION
+// AGGREGATION (recursive proofs of several batches)
 // ═══════════════════════════════════════════════════════════════════════════
 
-/// Compute merkle root from request IDs
+/// A batch proof the aggregation guest verifies
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AggregatedProof {
+    /// Public values the batch proof commits
+    #[serde(with = "hex_serde")]
+    pub public_values: Vec<u8>,
+    
+    /// Their encoding
+    pub output_format: OutputFormat,
+}
+
+/// Input to the aggregation guest program
 ///
-/// Uses keccak256 as the hash function for compatibility with EVM.
-pub fn compute_requests_root(ids: &[u64]) -> [u8; 32] {
-    use sha3::{Digest, Keccak256};
+/// The proofs themselves reach the guest through SP1's proof stdin
+/// (`SP1Stdin::write_proof`), in the same order.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RemlAggregationInput {
+    /// Vkey digest (`vk.hash_u32()`) of the Re-ML guest every proof is of
+    pub batch_vkey: [u32; 8],
+    
+    /// Batch proofs to fold, at most `MAX_AGGREGATED_BATCHES`
+    pub proofs: Vec<AggregatedProof>,
+}
+
+/// What an aggregate proof commits about one of its batches
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SubBatchOutput {
+    /// Batch identifier
+    pub batch_id: u64,
+    
+    /// Number of verified signatures
+    pub verified_count: u32,
+    
+    /// How many of them are ML-DSA-44 signatures
+    pub mldsa44_count: u32,
+    
+    /// How many of them are Falcon-512 signatures
+    pub falcon512_count: u32,
+    
+    /// Keccak merkle root of the batch's verified request IDs
+    #[serde(with = "hex_serde_array")]
+    pub requests_root: [u8; 32],
+    
+    /// Blake2 merkle root of the same IDs
+    #[serde(with = "hex_serde_array")]
+    pub requests_root_blake2: [u8; 32],
+}
+
+impl From<&RemlProofOutput> for SubBatchOutput {
+    fn from(output: &RemlProofOutput) -> Self {
+        Self {
+            batch_id: output.batch_id,
+            verified_count: output.verified_count,
+            mldsa44_count: output.mldsa44_count,
+            falcon512_count: output.falcon512_count,
+            requests_root: output.requests_root,
+            requests_root_blake2: output.requests_root_blake2,
+        }
+    }
+}
+
+/// Public output of the aggregation guest
+///
+/// Request IDs are left out: each batch is recorded on-chain by its roots
+/// only, and requests are claimed with inclusion proofs.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RemlAggregateOutput {
+    /// Protocol version, shared by every batch
+    pub version: u8,
     
-    if ids.is_empty() {
-        return [0u8; 32];
+    /// Chain ID, shared by every batch
+    pub chain_id: u32,
+    
+    /// Proof epoch, shared by every batch
+    pub epoch: u32,
+    
+    /// Vkey hash (`vk.hash_bytes()`) of the Re-ML guest the batches were proved with
+    #[serde(with = "hex_serde_array")]
+    pub batch_vkey_hash: [u8; 32],
+    
+    /// Verified signatures across all batches
+    pub verified_count: u32,
+    
+    /// How many of them are ML-DSA-44 signatures
+    pub mldsa44_count: u32,
+    
+    /// How many of them are Falcon-512 signatures
+    pub falcon512_count: u32,
+    
+    /// The batches, in proof order
+    pub sub_batches: Vec<SubBatchOutput>,
+}
+
+impl RemlAggregateOutput {
+    /// Solidity ABI encoding of the output, which the aggregation guest commits
+    ///
+    /// Same bytes as
+    /// `abi.encode(uint8 version, uint32 chainId, uint32 epoch, bytes32 batchVKeyHash,
+    /// uint32 verifiedCount, uint32 mldsa44Count, uint32 falcon512Count,
+    /// (uint64 batchId, uint32 verifiedCount, uint32 mldsa44Count, uint32 falcon512Count,
+    /// bytes32 requestsRoot, bytes32 requestsRootBlake2)[] subBatches)`,
+    /// the layout `pallet_reml_verifier::AggregatePublicValues` checks.
+    pub fn abi_encode(&self) -> Vec<u8> {
+        let batches = &self.sub_batches;
+        let mut out = Vec::with_capacity(
+            (AGGREGATE_ABI_HEAD_WORDS + 1 + SUB_BATCH_ABI_WORDS * batches.len()) * 32,
+        );
+        
+        out.extend_from_slice(&abi_word(self.version as u64));
+        out.extend_from_slice(&abi_word(self.chain_id as u64));
+        out.extend_from_slice(&abi_word(self.epoch as u64));
+        out.extend_from_slice(&self.batch_vkey_hash);
+        out.extend_from_slice(&abi_word(self.verified_count as u64));
+        out.extend_from_slice(&abi_word(self.mldsa44_count as u64));
+        out.extend_from_slice(&abi_word(self.falcon512_count as u64));
+        // Offset of the dynamic array, right after the head
+        out.extend_from_slice(&abi_word((AGGREGATE_ABI_HEAD_WORDS * 32) as u64));
+        out.extend_from_slice(&abi_word(batches.len() as u64));
+        // Static tuples are encoded in place
+        for batch in batches {
+            out.extend_from_slice(&abi_word(batch.batch_id));
+            out.extend_from_slice(&abi_word(batch.verified_count as u64));
+            out.extend_from_slice(&abi_word(batch.mldsa44_count as u64));
+            out.extend_from_slice(&abi_word(batch.falcon512_count as u64));
+            out.extend_from_slice(&batch.requests_root);
+            out.extend_from_slice(&batch.requests_root_blake2);
+        }
+        
+        out
     }
     
-    // Hash each ID to create leaves
-    let mut leaves: Vec<[u8; 32]> = ids
-        .iter()
-        .map(|id| {
-            let mut hasher = Keccak256::new();
-            hasher.update(id.to_le_bytes());
-            let result = hasher.finalize();
-            let mut leaf = [0u8; 32];
-            leaf.copy_from_slice(&result);
-            leaf
-        })
-        .collect();
-    
-    // Build merkle tree
-    while leaves.len() > 1 {
-        let mut next_level = Vec::with_capacity((leaves.len() + 1) / 2);
-        
-        for i in (0..leaves.len()).step_by(2) {
-            if i + 1 < leaves.len() {
-                let mut hasher = Keccak256::new();
-                hasher.update(&leaves[i]);
-                hasher.update(&leaves[i + 1]);
-                let result = hasher.finalize();
-                let mut node = [0u8; 32];
-                node.copy_from_slice(&result);
-                next_level.push(node);
-            } else {
-                // Odd node - promote to next level
-                next_level.push(leaves[i]);
-            }
+    /// Decode [`Self::abi_encode`] output, `None` if not canonical
+    pub fn abi_decode(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() % 32 != 0 || bytes.len() < (AGGREGATE_ABI_HEAD_WORDS + 1) * 32 {
+            return None;
+        }
+        let words: Vec<&[u8]> = bytes.chunks(32).collect();
+        let word32 = |word: &[u8]| {
+            let mut value = [0u8; 32];
+            value.copy_from_slice(word);
+            value
+        };
+        
+        let version = abi_uint(words[0], u8::MAX as u64)? as u8;
+        let chain_id = abi_uint(words[1], u32::MAX as u64)? as u32;
+        let epoch = abi_uint(words[2], u32::MAX as u64)? as u32;
+        let batch_vkey_hash = word32(words[3]);
+        let verified_count = abi_uint(words[4], u32::MAX as u64)? as u32;
+        let mldsa44_count = abi_uint(words[5], u32::MAX as u64)? as u32;
+        let falcon512_count = abi_uint(words[6], u32::MAX as u64)? as u32;
+        
+        if abi_uint(words[7], u64::MAX)? != (AGGREGATE_ABI_HEAD_WORDS * 32) as u64 {
+            return None;
         }
+        let len = abi_uint(words[AGGREGATE_ABI_HEAD_WORDS], MAX_AGGREGATED_BATCHES as u64)?;
+        if words.len() != AGGREGATE_ABI_HEAD_WORDS + 1 + SUB_BATCH_ABI_WORDS * len as usize {
+            return None;
+        }
+        let sub_batches = words[AGGREGATE_ABI_HEAD_WORDS + 1..]
+            .chunks(SUB_BATCH_ABI_WORDS)
+            .map(|batch| {
+                Some(SubBatchOutput {
+                    batch_id: abi_uint(batch[0], u64::MAX)?,
+                    verified_count: abi_uint(batch[1], u32::MAX as u64)? as u32,
+                    mldsa44_count: abi_uint(batch[2], u32::MAX as u64)? as u32,
+                    falcon512_count: abi_uint(batch[3], u32::MAX as u64)? as u32,
+                    requests_root: word32(batch[4]),
+                    requests_root_blake2: word32(batch[5]),
+                })
+            })
+            .collect::<Option<Vec<_>>>()?;
         
-        leaves = next_level;
+        Some(Self {
+            version,
+            chain_id,
+            epoch,
+            batch_vkey_hash,
+            verified_count,
+            mldsa44_count,
+            falcon512_count,
+            sub_batches,
+        })
+    }
+}
+
+/// Static head words of the aggregate ABI encoding: seven values and the array offset
+const AGGREGATE_ABI_HEAD_WORDS: usize = 8;
+
+/// Words of one sub-batch tuple
+const SUB_BATCH_ABI_WORDS: usize = 6;
+
+/// Aggregate proof bundle, submitted with `RemlVerifier.submit_aggregate_proof`
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RemlAggregateBundle {
+    /// Serialized SP1 proof of the aggregation guest
+    #[serde(with = "hex_serde")]
+    pub proof: Vec<u8>,
+    
+    /// Public output values, committed ABI encoded
+    pub output: RemlAggregateOutput,
+    
+    /// Verification key hash of the aggregation guest
+    #[serde(with = "hex_serde_array")]
+    pub vkey_hash: [u8; 32],
+    
+    /// Timestamp when proof was generated
+    pub generated_at: u64,
+}
+
+impl RemlAggregateBundle {
+    /// Create new aggregate proof bundle
+    pub fn new(proof: Vec<u8>, output: RemlAggregateOutput, vkey_hash: [u8; 32]) -> Self {
+        Self { proof, output, vkey_hash, generated_at: unix_time() }
     }
+}
+
+/// Current Unix time in seconds, 0 without `std`
+fn unix_time() -> u64 {
+    #[cfg(feature = "std")]
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    
+    #[cfg(not(feature = "std"))]
+    let now = 0u64;
     
-    leaves[0]
+    now
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// PROOF COMMITMENT
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Compute the replay-prevention commitment of a proof submission
+///
+/// Mirrors `pallet_reml_verifier::Pallet::compute_proof_commitment`, so the
+/// host can look the commitment up in `RemlVerifier::ProofCommitments`
+/// before submitting:
+///
+/// ```text
+/// proof_hash = BLAKE2b-256(proof)
+/// commitment = BLAKE2b-256(vkey_hash || epoch_le32 || batch_id_le64
+///                          || requests_root || proof_hash)
+/// ```
+///
+/// `requests_root` is the keccak root, and `proof` the bytes submitted
+/// on-chain, i.e. [`RemlProofBundle::proof`].
+pub fn compute_proof_commitment(
+    vkey_hash: &[u8; 32],
+    epoch: u32,
+    batch_id: u64,
+    requests_root: &[u8; 32],
+    proof: &[u8],
+) -> [u8; 32] {
+    use blake2::{digest::consts::U32, Blake2b, Digest};
+    
+    let proof_hash: [u8; 32] = Blake2b::<U32>::digest(proof).into();
+    
+    let mut hasher = Blake2b::<U32>::new();
+    hasher.update(vkey_hash);
+    hasher.update(epoch.to_le_bytes());
+    hasher.update(batch_id.to_le_bytes());
+    hasher.update(requests_root);
+    hasher.update(proof_hash);
+    hasher.finalize().into()
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// KEY DERIVATION
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Domain separator for deriving ML-DSA key generation seeds
+pub const MLDSA_SEED_DOMAIN: &[u8] = b"TESSERAX_MLDSA44_SEED";
+
+/// Derive the 32-byte Dilithium2 key generation seed for a vault key
+///
+/// Derivation path `m/13817'/account'/index'`:
+///
+/// ```text
+/// bip39_seed = PBKDF2-HMAC-SHA512(mnemonic, "mnemonic" || passphrase, 2048)
+/// seed       = SHA3-256(MLDSA_SEED_DOMAIN || bip39_seed || account_le32 || index_le32)
+/// keypair    = Dilithium2.KeyGen(seed)
+/// ```
+///
+/// The node's `vault-key` subcommand implements the same derivation, and its
+/// tests pin the same vector as `test_derive_mldsa_seed_vector`.
+pub fn derive_mldsa_seed(bip39_seed: &[u8; 64], account: u32, index: u32) -> [u8; 32] {
+    use sha3::{Digest, Sha3_256};
+    
+    let mut hasher = Sha3_256::new();
+    hasher.update(MLDSA_SEED_DOMAIN);
+    hasher.update(bip39_seed);
+    hasher.update(account.to_le_bytes());
+    hasher.update(index.to_le_bytes());
+    
+    let mut seed = [0u8; 32];
+    seed.copy_from_slice(&hasher.finalize());
+    seed
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// MERKLE ROOT COMPUTATION
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Compute merkle root from request IDs
+///
+/// Uses keccak256 as the hash function for compatibility with EVM. The tree
+/// is `tesserax_merkle`'s, which `pallet-reml-verifier` recomputes on-chain.
+pub fn compute_requests_root(ids: &[u64]) -> [u8; 32] {
+    use sha3::{Digest, Keccak256};
+    
+    tesserax_merkle::requests_root(ids, |data| Keccak256::digest(data).into())
+}
+
+/// Compute the blake2 merkle root from request IDs
+///
+/// Same tree as [`compute_requests_root`] hashed with blake2b-256, Substrate's
+/// native hash (`sp_io::hashing::blake2_256`).
+pub fn compute_requests_root_blake2(ids: &[u64]) -> [u8; 32] {
+    use blake2::{digest::consts::U32, Blake2b, Digest};
+    
+    tesserax_merkle::requests_root(ids, |data| Blake2b::<U32>::digest(data).into())
+}
+
+/// Owner key hash of a request signed with `public_key`: its blake2b-256
+///
+/// The same hash `pallet-quantum-vault` keeps of a vault's key, so the
+/// runtime can check a verified request was signed by the vault consuming it.
+pub fn owner_key_hash(public_key: &[u8]) -> [u8; 32] {
+    use blake2::{digest::consts::U32, Blake2b, Digest};
+    
+    Blake2b::<U32>::digest(public_key).into()
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
@@ -353,6 +1146,34 @@ mod hex_serde_array {
     }
 }
 
+mod hex_serde_array_list {
+    use alloc::string::String;
+    use alloc::vec::Vec;
+    use serde::{Deserialize, Deserializer, Serializer};
+    
+    pub fn serialize<S>(list: &[[u8; 32]], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(list.iter().map(hex::encode))
+    }
+    
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<[u8; 32]>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Vec::<String>::deserialize(deserializer)?
+            .iter()
+            .map(|s| {
+                let s = s.strip_prefix("0x").unwrap_or(s);
+                let bytes = hex::decode(s).map_err(serde::de::Error::custom)?;
+                <[u8; 32]>::try_from(bytes.as_slice())
+                    .map_err(|_| serde::de::Error::custom("expected 32 bytes"))
+            })
+            .collect()
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // TESTS
 // ═══════════════════════════════════════════════════════════════════════════
@@ -378,6 +1199,50 @@ mod tests {
             2,
         );
         assert!(!invalid.validate_sizes());
+        
+        // Falcon signatures vary in length, but the key size is fixed
+        let falcon = SignatureRequest::new(
+            [0u8; 32],
+            vec![0u8; FALCON512_PUBLIC_KEY_SIZE],
+            vec![0u8; 666],
+            3,
+        )
+        .with_scheme(SignatureScheme::Falcon512);
+        assert!(falcon.validate_sizes());
+        assert!(!valid.clone().with_scheme(SignatureScheme::Falcon512).validate_sizes());
+        assert!(!falcon.clone().with_scheme(SignatureScheme::MlDsa44).validate_sizes());
+        assert!(!falcon.with_mode(MessageMode::Pure, Vec::new()).validate_sizes());
+    }
+    
+    #[test]
+    fn test_signed_message_modes() {
+        let request = SignatureRequest::new(
+            [7u8; 32],
+            vec![0u8; MLDSA_PUBLIC_KEY_SIZE],
+            vec![0u8; MLDSA_SIGNATURE_SIZE],
+            1,
+        );
+        assert_eq!(request.signed_message(), vec![7u8; 32]);
+        
+        let pure = request.clone().with_mode(MessageMode::Pure, b"ctx".to_vec());
+        assert!(pure.validate_sizes());
+        assert_eq!(&pure.signed_message()[..5], &[0, 3, b'c', b't', b'x']);
+        assert_eq!(pure.signed_message().len(), 5 + 32);
+        
+        let hashed = request.clone().with_mode(MessageMode::PreHashSha3_256, Vec::new());
+        let m = hashed.signed_message();
+        assert_eq!(&m[..2], &[1, 0]);
+        assert_eq!(&m[2..13], &SHA3_256_OID);
+        assert_eq!(&m[13..], &[7u8; 32]);
+        // The message hash covers M', mode and context included
+        assert_eq!(hashed.message_hash(), owner_key_hash(&m));
+        assert_ne!(hashed.message_hash(), request.message_hash());
+        
+        // Context only binds in the FIPS 204 modes, and only up to 255 bytes
+        assert!(!request.clone().with_mode(MessageMode::Raw, b"ctx".to_vec()).validate_sizes());
+        assert!(!request
+            .with_mode(MessageMode::Pure, vec![0u8; MAX_CONTEXT_SIZE + 1])
+            .validate_sizes());
     }
     
     #[test]
@@ -402,6 +1267,30 @@ mod tests {
         assert_eq!(root, [0u8; 32]);
     }
     
+    #[test]
+    fn test_blake2_root_shares_the_tree() {
+        assert_eq!(compute_requests_root_blake2(&[]), [0u8; 32]);
+        assert_ne!(compute_requests_root_blake2(&[1, 2, 3]), compute_requests_root(&[1, 2, 3]));
+        assert_ne!(
+            compute_requests_root_blake2(&[1, 2, 3]),
+            compute_requests_root_blake2(&[1, 3, 2])
+        );
+    }
+    
+    #[test]
+    fn test_proof_commitment_vector() {
+        let commitment = compute_proof_commitment(&[1u8; 32], 1, 7, &[2u8; 32], &[3u8; 260]);
+        assert_eq!(
+            hex::encode(commitment),
+            "0305a9d54042a12c44235c18753e4e747c30100a4e559a492762d13bb647507a"
+        );
+        
+        // Every field binds
+        assert_ne!(compute_proof_commitment(&[1u8; 32], 2, 7, &[2u8; 32], &[3u8; 260]), commitment);
+        assert_ne!(compute_proof_commitment(&[1u8; 32], 1, 8, &[2u8; 32], &[3u8; 260]), commitment);
+        assert_ne!(compute_proof_commitment(&[1u8; 32], 1, 7, &[2u8; 32], &[3u8; 261]), commitment);
+    }
+    
     #[test]
     fn test_proof_input_creation() {
         let requests = vec![
@@ -415,16 +1304,156 @@ mod tests {
         
         assert_eq!(input.version, REML_VERSION);
         assert_eq!(input.chain_id, TESSERAX_CHAIN_ID);
+        assert_eq!(input.epoch, REML_PROOF_EPOCH);
         assert_eq!(input.batch_id, 42);
+        assert_eq!(input.clone().with_epoch(2).epoch, 2);
         assert_eq!(input.batch_size(), 2);
+        assert!(input.is_within_batch_limit());
+    }
+    
+    #[test]
+    fn test_batch_limit() {
+        let request = SignatureRequest::new(
+            [0u8; 32],
+            vec![0u8; MLDSA_PUBLIC_KEY_SIZE],
+            vec![0u8; MLDSA_SIGNATURE_SIZE],
+            0,
+        );
+        
+        let at_limit = RemlProofInput::new(vec![request.clone(); MAX_BATCH_SIZE], 1);
+        assert!(at_limit.is_within_batch_limit());
+        
+        let over_limit = RemlProofInput::new(vec![request; MAX_BATCH_SIZE + 1], 2);
+        assert!(!over_limit.is_within_batch_limit());
+    }
+    
+    #[test]
+    fn test_derive_mldsa_seed_vector() {
+        // BIP39 seed of "abandon ... about" with an empty passphrase
+        let bip39_seed: [u8; 64] = hex::decode(
+            "5eb00bbddcf069084889a8ab9155568165f5c453ccb85e70811aaed6f6da5fc1\
+             9a5ac40b389cd370d086206dec8aa6c43daea6690f20ad3d8d48b2d2ce9e38e4",
+        )
+        .unwrap()
+        .try_into()
+        .unwrap();
+        
+        assert_eq!(
+            hex::encode(derive_mldsa_seed(&bip39_seed, 0, 0)),
+            "f7f0be590e995a3a1a8e076f0d6909388370dd022ef349bb4d43f8b9a42c4d13"
+        );
+        assert_eq!(
+            hex::encode(derive_mldsa_seed(&bip39_seed, 0, 1)),
+            "4598f43f9375ba980e4fecb8b36e6cb9a1b72cafeb0466c25474476d1fe1627f"
+        );
+    }
+    
+    #[test]
+    fn test_abi_output_round_trips() {
+        let output = RemlProofOutput::new(
+            REML_PROOF_EPOCH,
+            7,
+            2,
+            compute_requests_root(&[3, 5]),
+            compute_requests_root_blake2(&[3, 5]),
+            vec![3, 5],
+        )
+        .with_scheme_counts(1, 1)
+        .with_request_owners(vec![owner_key_hash(b"alice"), owner_key_hash(b"bob")])
+        .with_request_messages(vec![[1u8; 32], [2u8; 32]]);
+        
+        let abi = output.abi_encode();
+        assert_eq!(abi.len(), (12 + 1 + 2 + 1 + 2 + 1 + 2) * 32);
+        // uint8 version, the per-scheme counts and the array offsets, right-aligned
+        assert_eq!(abi[31], REML_VERSION);
+        assert_eq!(abi[5 * 32 + 31], 1);
+        assert_eq!(abi[6 * 32 + 31], 1);
+        assert_eq!(abi[9 * 32 + 31], 128);
+        assert_eq!(abi[9 * 32 + 30], 1);
+        assert_eq!(abi[10 * 32 + 31], 224);
+        assert_eq!(abi[10 * 32 + 30], 1);
+        assert_eq!(abi[11 * 32 + 31], 64);
+        assert_eq!(abi[11 * 32 + 30], 2);
+        assert_eq!(&abi[7 * 32..8 * 32], &output.requests_root);
+        assert_eq!(&abi[8 * 32..9 * 32], &output.requests_root_blake2);
+        assert_eq!(&abi[17 * 32..18 * 32], &output.verified_request_owners[1]);
+        assert_eq!(&abi[20 * 32..], &output.verified_request_messages[1]);
+        assert_eq!(RemlProofOutput::abi_decode(&abi), Some(output.clone()));
+        
+        // Dirty padding and truncated arrays are rejected
+        let mut dirty = abi.clone();
+        dirty[0] = 1;
+        assert_eq!(RemlProofOutput::abi_decode(&dirty), None);
+        assert_eq!(RemlProofOutput::abi_decode(&abi[..abi.len() - 32]), None);
+        
+        assert_eq!("abi".parse(), Ok(OutputFormat::Abi));
+        assert!("json".parse::<OutputFormat>().is_err());
+    }
+    
+    #[test]
+    fn test_bundle_proof_type_defaults_to_core() {
+        let output = RemlProofOutput::new(REML_PROOF_EPOCH, 1, 1, [1u8; 32], [2u8; 32], vec![4])
+            .with_request_owners(vec![[5u8; 32]]);
+        let bundle = RemlProofBundle::new(vec![0u8; GROTH16_PROOF_SIZE], output, [3u8; 32])
+            .with_output_format(OutputFormat::Abi)
+            .with_proof_type(ProofType::Groth16);
+        
+        let mut json = serde_json::to_value(&bundle).unwrap();
+        assert_eq!(json["proof_type"], "groth16");
+        assert_eq!(json["output"]["verified_request_owners"][0], hex::encode([5u8; 32]));
+        let decoded: RemlProofBundle = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(decoded.output, bundle.output);
+        
+        // Bundles written before the tag hold core proofs
+        json.as_object_mut().unwrap().remove("proof_type");
+        let legacy: RemlProofBundle = serde_json::from_value(json).unwrap();
+        assert_eq!(legacy.proof_type, ProofType::Core);
+    }
+    
+    #[test]
+    fn test_abi_aggregate_output_round_trips() {
+        let batch = |batch_id: u64, ids: &[u64]| {
+            SubBatchOutput::from(&RemlProofOutput::new(
+                REML_PROOF_EPOCH,
+                batch_id,
+                ids.len() as u32,
+                compute_requests_root(ids),
+                compute_requests_root_blake2(ids),
+                ids.to_vec(),
+            ))
+        };
+        let output = RemlAggregateOutput {
+            version: REML_VERSION,
+            chain_id: TESSERAX_CHAIN_ID,
+            epoch: REML_PROOF_EPOCH,
+            batch_vkey_hash: [9u8; 32],
+            verified_count: 5,
+            mldsa44_count: 5,
+            falcon512_count: 0,
+            sub_batches: vec![batch(1, &[3, 5]), batch(2, &[6, 7, 8])],
+        };
+        
+        let abi = output.abi_encode();
+        assert_eq!(abi.len(), (8 + 1 + 2 * 6) * 32);
+        assert_eq!(&abi[3 * 32..4 * 32], &[9u8; 32]);
+        // Offset of the tuple array, and the second batch's ID and blake2 root
+        assert_eq!(abi[7 * 32 + 30], 1);
+        assert_eq!(abi[15 * 32 + 31], 2);
+        assert_eq!(&abi[20 * 32..], &output.sub_batches[1].requests_root_blake2);
+        assert_eq!(RemlAggregateOutput::abi_decode(&abi), Some(output.clone()));
+        
+        assert_eq!(RemlAggregateOutput::abi_decode(&abi[..abi.len() - 32]), None);
+        assert_eq!(RemlAggregateOutput::abi_decode(&output.sub_batches[0].requests_root), None);
     }
     
     #[test]
     fn test_compression_ratio() {
         let output = RemlProofOutput::new(
+            REML_PROOF_EPOCH,
             1,
             100, // 100 signatures
             [0u8; 32],
+            [0u8; 32],
             (0..100).collect(),
         );
         
@@ -434,6 +1463,8 @@ mod tests {
             output,
             vkey_hash: [0u8; 32],
             generated_at: 0,
+            output_format: OutputFormat::Bincode,
+            proof_type: ProofType::Core,
         };
         
         let ratio = bundle.compression_ratio();
@@ -0,0 +1,68 @@
+//! # Test Batches
+//!
+//! Host-side generation and checking of signature batches with real
+//! Dilithium2 keys, and checking of Falcon-512 requests. Used by
+//! `reml-prover gen-test` and the end-to-end harness in `tests/reml-e2e`.
+
+use crate::{SignatureRequest, SignatureScheme, MLDSA_SIGNATURE_SIZE};
+use alloc::vec::Vec;
+use pqcrypto_dilithium::dilithium2;
+use pqcrypto_falcon::falcon512;
+use pqcrypto_traits::sign::{DetachedSignature, PublicKey};
+
+/// Generate `count` signed requests with IDs `0..count`
+///
+/// The first `invalid_count` requests carry a corrupted signature.
+pub fn generate_requests(count: usize, invalid_count: usize) -> Vec<SignatureRequest> {
+    (0..count)
+        .map(|i| {
+            let (pk, sk) = dilithium2::keypair();
+
+            // Simulated transaction hash
+            let mut message = [0u8; 32];
+            message[0..8].copy_from_slice(&(i as u64).to_le_bytes());
+            for (j, byte) in message.iter_mut().enumerate().skip(8) {
+                *byte = ((i * 7 + j * 13) % 256) as u8;
+            }
+
+            let mut signature = dilithium2::detached_sign(&message, &sk).as_bytes().to_vec();
+            assert_eq!(signature.len(), MLDSA_SIGNATURE_SIZE, "Unexpected signature size");
+            if i < invalid_count {
+                signature[0] ^= 0xFF;
+            }
+
+            SignatureRequest::new(message, pk.as_bytes().to_vec(), signature, i as u64)
+        })
+        .collect()
+}
+
+/// Verify a request with the reference implementation of its scheme
+///
+/// Checks the signature over [`SignatureRequest::signed_message`], as the
+/// guest does.
+pub fn verify_request(request: &SignatureRequest) -> bool {
+    if request.scheme == SignatureScheme::Falcon512 {
+        return verify_falcon_request(request);
+    }
+
+    let (Ok(pk), Ok(signature)) = (
+        dilithium2::PublicKey::from_bytes(&request.public_key),
+        dilithium2::DetachedSignature::from_bytes(&request.signature),
+    ) else {
+        return false;
+    };
+
+    dilithium2::verify_detached_signature(&signature, &request.signed_message(), &pk).is_ok()
+}
+
+/// Verify a Falcon-512 request with the reference implementation
+fn verify_falcon_request(request: &SignatureRequest) -> bool {
+    let (Ok(pk), Ok(signature)) = (
+        falcon512::PublicKey::from_bytes(&request.public_key),
+        falcon512::DetachedSignature::from_bytes(&request.signature),
+    ) else {
+        return false;
+    };
+
+    falcon512::verify_detached_signature(&signature, &request.signed_message(), &pk).is_ok()
+}
@@ -0,0 +1,76 @@
+//! # Account Views
+//!
+//! Substrate (AccountId32) and EVM (H160) views of the same account.
+//!
+//! The runtime maps addresses by truncation and padding:
+//! - AccountId32 → H160: first 20 bytes (`FindAuthorTruncated`, `EnsureAddressTruncated`)
+//! - H160 → AccountId32: H160 followed by 12 zero bytes (`HashedAddressMapping`)
+//!
+//! An sr25519 account therefore only round-trips if its last 12 bytes are
+//! zero; otherwise its H160 view is backed by a *different* AccountId32.
+//! `AccountViewsApi` exposes both views so integrators (and the node's
+//! `sanctuary_accountViews` RPC) don't need to re-implement the mapping.
+
+use crate::{configs::HashedAddressMapping, AccountId, Nonce, Runtime};
+use codec::{Decode, Encode};
+use pallet_evm::AddressMapping;
+use scale_info::TypeInfo;
+use sp_core::{H160, U256};
+
+/// Both representations of an account, plus vault and nonce state
+#[derive(Clone, Encode, Decode, TypeInfo, PartialEq, Eq, Debug)]
+pub struct AccountViews {
+    /// Substrate account
+    pub account_id: AccountId,
+    /// EVM address (truncated `account_id`)
+    pub h160: H160,
+    /// Substrate account the EVM address maps to (debited by EVM transactions)
+    pub evm_account_id: AccountId,
+    /// Whether `account_id` is a quantum vault
+    pub is_vault: bool,
+    /// Whether `evm_account_id` is a quantum vault
+    pub evm_account_is_vault: bool,
+    /// Substrate nonce of `account_id`
+    pub substrate_nonce: Nonce,
+    /// EVM nonce of `h160`
+    pub evm_nonce: U256,
+}
+
+sp_api::decl_runtime_apis! {
+    /// Dual-view account queries
+    pub trait AccountViewsApi {
+        /// Views for a Substrate account
+        fn account_views(account_id: AccountId) -> AccountViews;
+
+        /// Views for an EVM address (the account is the mapped AccountId32)
+        fn account_views_by_h160(address: H160) -> AccountViews;
+    }
+}
+
+/// Truncate an AccountId32 to its H160 view
+pub fn truncate_to_h160(account_id: &AccountId) -> H160 {
+    let bytes: &[u8; 32] = account_id.as_ref();
+    H160::from_slice(&bytes[0..20])
+}
+
+/// Build the views for `account_id`
+pub fn account_views(account_id: AccountId) -> AccountViews {
+    let h160 = truncate_to_h160(&account_id);
+    let evm_account_id = HashedAddressMapping::into_account_id(h160);
+    let (evm_account, _) = pallet_evm::Pallet::<Runtime>::account_basic(&h160);
+
+    AccountViews {
+        is_vault: pallet_quantum_vault::Pallet::<Runtime>::is_vault(&account_id),
+        evm_account_is_vault: pallet_quantum_vault::Pallet::<Runtime>::is_vault(&evm_account_id),
+        substrate_nonce: frame_system::Pallet::<Runtime>::account_nonce(&account_id),
+        evm_nonce: evm_account.nonce,
+        account_id,
+        h160,
+        evm_account_id,
+    }
+}
+
+/// Build the views for an EVM address
+pub fn account_views_by_h160(address: H160) -> AccountViews {
+    account_views(HashedAddressMapping::into_account_id(address))
+}
@@ -49,9 +49,9 @@ use pallet_evm::{Account as EVMAccount, Runner};
 
 // Local module imports
 use super::{
-    AccountId, Aura, Balance, Block, BlockNumber, Executive, Grandpa, InherentDataExt, Nonce,
-    Runtime, RuntimeCall, RuntimeGenesisConfig, SessionKeys, System, TransactionPayment,
-    UncheckedExtrinsic, VERSION,
+    AccountId, Aura, Balance, Block, BlockNumber, Emission, Executive, Grandpa, InherentDataExt,
+    Nonce, Runtime, RuntimeCall, RuntimeGenesisConfig, SessionKeys, Signature, System,
+    TransactionPayment, UncheckedExtrinsic, VERSION,
 };
 
 impl_runtime_apis! {
@@ -276,6 +276,11 @@ impl_runtime_apis! {
         ) -> Result<pallet_evm::CallInfo, sp_runtime::DispatchError> {
             use pallet_evm::GasWeightMapping as _;
 
+            // Fail fast with a readable reason for value transfers out of vaults
+            if crate::vault_blocker::is_blocked_evm_value_transfer(&from, value) {
+                return Ok(crate::vault_blocker::blocked_call_info());
+            }
+
             let config = if estimate {
                 let mut config = <Runtime as pallet_evm::Config>::config().clone();
                 config.estimate = true;
@@ -335,6 +340,10 @@ impl_runtime_apis! {
         ) -> Result<pallet_evm::CreateInfo, sp_runtime::DispatchError> {
             use pallet_evm::GasWeightMapping as _;
 
+            if crate::vault_blocker::is_blocked_evm_value_transfer(&from, value) {
+                return Ok(crate::vault_blocker::blocked_create_info());
+            }
+
             let config = if estimate {
                 let mut config = <Runtime as pallet_evm::Config>::config().clone();
                 config.estimate = true;
@@ -448,6 +457,219 @@ impl_runtime_apis! {
         }
     }
 
+    impl pallet_emission::runtime_api::EmissionApi<Block, BlockNumber> for Runtime {
+        fn current_era() -> u32 {
+            Emission::current_era(System::block_number())
+        }
+
+        fn reward_for_current_block() -> u128 {
+            Emission::reward_for_block(System::block_number())
+        }
+
+        fn total_emitted() -> u128 {
+            Emission::total_emitted(System::block_number())
+        }
+
+        fn emission_ends_at_block() -> BlockNumber {
+            Emission::emission_ends_at_block()
+        }
+
+        fn reward_schedule(start_era: u32, limit: u32) -> Vec<u128> {
+            Emission::reward_schedule(start_era, limit)
+        }
+    }
+
+    impl pallet_quantum_vault::runtime_api::QuantumVaultApi<Block, AccountId, Balance, BlockNumber>
+        for Runtime
+    {
+        fn is_vault(account: AccountId) -> bool {
+            pallet_quantum_vault::Pallet::<Runtime>::is_vault(&account)
+        }
+
+        fn vault_nonce(account: AccountId) -> Option<u64> {
+            pallet_quantum_vault::Pallet::<Runtime>::vault_nonce(&account)
+        }
+
+        fn vault_transfer_payload(
+            from: AccountId,
+            to: AccountId,
+            amount: Balance,
+        ) -> Option<Vec<u8>> {
+            pallet_quantum_vault::Pallet::<Runtime>::vault_transfer_payload(&from, &to, amount)
+        }
+
+        fn vault_descriptor(account: AccountId) -> Option<pallet_quantum_vault::VaultDescriptor> {
+            pallet_quantum_vault::Pallet::<Runtime>::vault_descriptor(&account)
+        }
+
+        fn vault_info(
+            account: AccountId,
+        ) -> Option<pallet_quantum_vault::VaultInfo<AccountId, Balance, BlockNumber>> {
+            pallet_quantum_vault::Pallet::<Runtime>::vault_info(&account)
+        }
+
+        fn list_vaults(
+            start_key: Option<AccountId>,
+            limit: u32,
+        ) -> Vec<pallet_quantum_vault::VaultSummary<AccountId>> {
+            pallet_quantum_vault::Pallet::<Runtime>::list_vaults(start_key, limit)
+        }
+
+        fn value_locked(
+            start_key: Option<AccountId>,
+            limit: u32,
+        ) -> pallet_quantum_vault::ValueLockedPage<AccountId, Balance> {
+            pallet_quantum_vault::Pallet::<Runtime>::value_locked(start_key, limit)
+        }
+
+        fn dry_run_transfer(
+            from: AccountId,
+            signature: Vec<u8>,
+            to: AccountId,
+            amount: Balance,
+            request_id: Option<u64>,
+        ) -> pallet_quantum_vault::TransferDryRun<Balance> {
+            use codec::Encode;
+
+            let call = RuntimeCall::QuantumVault(pallet_quantum_vault::Call::vault_transfer {
+                signature: signature.clone(),
+                to: to.clone(),
+                amount,
+                request_id,
+            });
+            let len = call.encoded_size() as u32;
+            let inclusion_fee = TransactionPayment::query_call_info(call, len).partial_fee;
+
+            let outcome = pallet_quantum_vault::Pallet::<Runtime>::dry_run_transfer(
+                from, signature, to, amount, request_id,
+            )
+            .map_err(|error| pallet_quantum_vault::TransferDryRunError {
+                name: <&'static str>::from(error).as_bytes().to_vec(),
+                error,
+            });
+
+            pallet_quantum_vault::TransferDryRun { inclusion_fee, outcome }
+        }
+
+        fn query_call_fees(
+            who: AccountId,
+            call: Vec<u8>,
+        ) -> Option<pallet_quantum_vault::CallFeeQuote<Balance>> {
+            use codec::DecodeLimit;
+            type QuantumVault = pallet_quantum_vault::Pallet<Runtime>;
+
+            let len = call.len() as u32;
+            let call = RuntimeCall::decode_all_with_depth_limit(
+                sp_api::MAX_EXTRINSIC_DEPTH,
+                &mut &call[..],
+            )
+            .ok()?;
+            let (premium_fee, creation_fee) = match &call {
+                RuntimeCall::QuantumVault(call) => {
+                    (QuantumVault::call_premium(&who, call), QuantumVault::call_creation_fee(call))
+                },
+                _ => (0, 0),
+            };
+            let (base_fee, len_fee, adjusted_weight_fee) =
+                match TransactionPayment::query_call_fee_details(call, len).inclusion_fee {
+                    Some(fee) => (fee.base_fee, fee.len_fee, fee.adjusted_weight_fee),
+                    None => (0, 0, 0),
+                };
+
+            Some(pallet_quantum_vault::CallFeeQuote {
+                base_fee,
+                len_fee,
+                adjusted_weight_fee,
+                premium_fee,
+                creation_fee,
+            })
+        }
+
+        fn era_stats() -> Vec<pallet_quantum_vault::EraStats<Balance>> {
+            pallet_quantum_vault::Pallet::<Runtime>::era_stats()
+        }
+    }
+
+    impl pallet_reml_verifier::runtime_api::RemlVerifierApi<Block, AccountId, BlockNumber, Signature>
+        for Runtime
+    {
+        fn list_batches(
+            from_id: Option<u64>,
+            limit: u32,
+        ) -> Vec<(u64, pallet_reml_verifier::BatchInfo<AccountId, BlockNumber>)> {
+            pallet_reml_verifier::Pallet::<Runtime>::list_batches(from_id, limit)
+        }
+
+        fn batch_by_root(
+            root: [u8; 32],
+        ) -> Option<(u64, pallet_reml_verifier::BatchInfo<AccountId, BlockNumber>)> {
+            pallet_reml_verifier::Pallet::<Runtime>::batch_by_root(root)
+        }
+
+        fn dry_run_submit_proof(
+            aggregator: AccountId,
+            submission: pallet_reml_verifier::ProofSubmission,
+            co_signatures: Vec<(AccountId, Signature)>,
+        ) -> Result<(), pallet_reml_verifier::ProofDryRunError> {
+            type RemlVerifier = pallet_reml_verifier::Pallet<Runtime>;
+
+            RemlVerifier::dry_run_submit_proof(aggregator, submission, &co_signatures).map_err(
+                |error| pallet_reml_verifier::ProofDryRunError {
+                    name: <&'static str>::from(error).as_bytes().to_vec(),
+                    reason: RemlVerifier::reject_reason(&error),
+                    error,
+                },
+            )
+        }
+
+        fn recent_rejections() -> Vec<pallet_reml_verifier::Rejection<AccountId, BlockNumber>> {
+            pallet_reml_verifier::Pallet::<Runtime>::recent_rejections()
+        }
+    }
+
+    impl pallet_reml_verifier::runtime_api::RemlApi<Block, AccountId, BlockNumber> for Runtime {
+        fn is_request_verified(request_id: u64) -> bool {
+            pallet_reml_verifier::Pallet::<Runtime>::is_request_verified(request_id)
+        }
+
+        fn is_program_request_verified(
+            program_id: pallet_reml_verifier::ProgramId,
+            request_id: u64,
+        ) -> bool {
+            pallet_reml_verifier::Pallet::<Runtime>::is_program_request_verified(program_id, request_id)
+        }
+
+        fn get_batch_info(
+            batch_id: u64,
+        ) -> Option<pallet_reml_verifier::BatchInfo<AccountId, BlockNumber>> {
+            pallet_reml_verifier::Pallet::<Runtime>::verified_batches(batch_id)
+        }
+
+        fn pending_aggregators() -> Vec<(AccountId, u64)> {
+            pallet_reml_verifier::Pallet::<Runtime>::pending_aggregators()
+        }
+
+        fn suggested_aggregator(request_id: u64) -> Option<AccountId> {
+            pallet_reml_verifier::Pallet::<Runtime>::suggested_aggregator(request_id)
+        }
+    }
+
+    impl crate::account_views::AccountViewsApi<Block> for Runtime {
+        fn account_views(account_id: AccountId) -> crate::account_views::AccountViews {
+            crate::account_views::account_views(account_id)
+        }
+
+        fn account_views_by_h160(address: H160) -> crate::account_views::AccountViews {
+            crate::account_views::account_views_by_h160(address)
+        }
+    }
+
+    impl crate::chain_health::ChainHealthApi<Block> for Runtime {
+        fn chain_health() -> crate::chain_health::ChainHealth {
+            crate::chain_health::chain_health()
+        }
+    }
+
     #[cfg(feature = "runtime-benchmarks")]
     impl frame_benchmarking::Benchmark<Block> for Runtime {
         fn benchmark_metadata(extra: bool) -> (
@@ -41,9 +41,17 @@ frame_benchmarking::define_benchmarks!(
     [pallet_balances, Balances]
     [pallet_timestamp, Timestamp]
     [pallet_sudo, Sudo]
+    [pallet_collective, Council]
+    [pallet_membership, CouncilMembership]
     [pallet_template, Template]
 
     // ── Tesserax Custom Pallets ──
     [pallet_quantum_vault, QuantumVault]
     [pallet_emission, Emission]
+    [pallet_chain_parameters, ChainParameters]
+    [pallet_watchtower, Watchtower]
+    [pallet_announcements, Announcements]
+    [pallet_reml_incentives, RemlIncentives]
+    [pallet_program_registry, ProgramRegistry]
+    [pallet_zk_coprocessor, ZkCoprocessor]
 );
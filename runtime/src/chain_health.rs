@@ -0,0 +1,51 @@
+//! # Chain Health
+//!
+//! On-chain half of the node's `sanctuary_health` RPC: the latest emission,
+//! Re-ML verification and vault state in one runtime call, so uptime monitors
+//! don't need to scrape each pallet. The node adds finality lag and
+//! transaction pool size, which only it can see.
+
+use crate::{AccountId, Balance, BlockNumber, Emission, QuantumVault, RemlVerifier};
+use codec::{Decode, Encode};
+use pallet_reml_verifier::BatchInfo;
+use scale_info::TypeInfo;
+
+/// Snapshot of the custom pallets' state
+#[derive(Clone, Encode, Decode, TypeInfo)]
+pub struct ChainHealth {
+    /// Block and amount of the most recent emission mint
+    pub last_reward: Option<(BlockNumber, Balance)>,
+    /// Most recently verified Re-ML batch
+    pub last_batch: Option<(u64, BatchInfo<AccountId, BlockNumber>)>,
+    /// Registered aggregators that are active
+    pub active_aggregators: u32,
+    /// Re-ML proofs verified since genesis
+    pub total_proofs_verified: u64,
+    /// Whether Re-ML proof submission is paused
+    pub verifier_paused: bool,
+    /// Number of quantum vaults
+    pub vault_count: u32,
+    /// Whether vault operations are paused
+    pub vaults_paused: bool,
+}
+
+sp_api::decl_runtime_apis! {
+    /// Chain health queries for monitoring
+    pub trait ChainHealthApi {
+        /// State of emission, Re-ML verification and vaults
+        fn chain_health() -> ChainHealth;
+    }
+}
+
+/// Build the chain health snapshot
+pub fn chain_health() -> ChainHealth {
+    ChainHealth {
+        last_reward: Emission::last_reward(),
+        last_batch: RemlVerifier::last_verified_batch(),
+        active_aggregators: RemlVerifier::active_aggregator_count(),
+        total_proofs_verified: RemlVerifier::total_proofs_verified(),
+        verifier_paused: RemlVerifier::is_paused(),
+        vault_count: QuantumVault::total_vaults(),
+        vaults_paused: QuantumVault::is_paused(),
+    }
+}
@@ -23,14 +23,18 @@
 //
 // For more information, please refer to <http://unlicense.org>
 
+#[rustfmt::skip]
+mod reml_vkey;
+
 // Substrate and Polkadot dependencies
 use frame_support::{
     derive_impl, parameter_types,
     traits::{ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, VariantCountOf},
     weights::{
         constants::{RocksDbWeight, WEIGHT_REF_TIME_PER_SECOND},
-        IdentityFee, Weight,
+        ConstantMultiplier, IdentityFee, Weight,
     },
+    PalletId,
 };
 use frame_system::limits::{BlockLength, BlockWeights};
 use pallet_transaction_payment::{ConstFeeMultiplier, FungibleAdapter, Multiplier};
@@ -42,7 +46,7 @@ use sp_version::RuntimeVersion;
 use super::{
     AccountId, Aura, Balance, Balances, Block, BlockNumber, Hash, Nonce, PalletInfo, Runtime,
     RuntimeCall, RuntimeEvent, RuntimeFreezeReason, RuntimeHoldReason, RuntimeOrigin, RuntimeTask,
-    System, Timestamp, EXISTENTIAL_DEPOSIT, SLOT_DURATION, VERSION,
+    Signature, System, Timestamp, EXISTENTIAL_DEPOSIT, SLOT_DURATION, VERSION,
 };
 
 const NORMAL_DISPATCH_RATIO: Perbill = Perbill::from_percent(75);
@@ -127,7 +131,7 @@ impl pallet_balances::Config for Runtime {
     /// The ubiquitous event type.
     type RuntimeEvent = RuntimeEvent;
     type DustRemoval = ();
-    type ExistentialDeposit = ConstU128<EXISTENTIAL_DEPOSIT>;
+    type ExistentialDeposit = pallet_chain_parameters::getters::ExistentialDeposit<Runtime>;
     type AccountStore = System;
     type WeightInfo = pallet_balances::weights::SubstrateWeight<Runtime>;
     type FreezeIdentifier = RuntimeFreezeReason;
@@ -146,7 +150,8 @@ impl pallet_transaction_payment::Config for Runtime {
     type OnChargeTransaction = FungibleAdapter<Balances, ()>;
     type OperationalFeeMultiplier = ConstU8<5>;
     type WeightToFee = IdentityFee<Balance>;
-    type LengthToFee = IdentityFee<Balance>;
+    type LengthToFee =
+        ConstantMultiplier<Balance, pallet_chain_parameters::getters::TransactionByteFee<Runtime>>;
     type FeeMultiplierUpdate = ConstFeeMultiplier<FeeMultiplier>;
     type WeightInfo = pallet_transaction_payment::weights::SubstrateWeight<Runtime>;
 }
@@ -203,6 +208,7 @@ impl pallet_authorship::Config for Runtime {
 impl pallet_emission::Config for Runtime {
     type Currency = Balances;
     type FindAuthor = AuraAccountAdapter;
+    type AdminOrigin = frame_system::EnsureRoot<AccountId>;
     type WeightInfo = ();
 }
 
@@ -319,6 +325,13 @@ where
 /// - 0x20: Verify STARK commitment (lightweight check)
 /// - 0x21: Check if request ID is verified via Re-ML
 /// - 0x22: Get batch information
+/// - 0x23: Verify a Dilithium2 signature
+/// - 0x24: Verify a batch of ML-DSA signatures
+/// - 0x25: Submit a ZK-coprocessor request
+/// - 0x26: Get a ZK-coprocessor result
+/// - 0x27: Check if an address is a quantum vault
+/// - 0x28: Get a quantum vault's nonce
+/// - 0x29: Verify an SP1 Groth16 proof (SP1's `ISP1Verifier`)
 pub struct TesseraxPrecompiles<R>(PhantomData<R>);
 
 impl<R> TesseraxPrecompiles<R>
@@ -340,16 +353,41 @@ where
         ]
     }
 
-    /// ZK-Coprocessor precompile addresses (0x20-0x22)
-    pub fn zk_addresses() -> [H160; 3] {
+    /// ZK-Coprocessor precompile addresses (0x20-0x22, 0x29)
+    pub fn zk_addresses() -> [H160; 4] {
         [
             hash(0x20), // VerifyStarkCommitment
             hash(0x21), // IsRequestVerified
             hash(0x22), // GetBatchInfo
+            hash(0x29), // VerifySp1Proof
+        ]
+    }
+
+    /// Post-quantum signature precompile addresses
+    pub fn pqc_addresses() -> [H160; 2] {
+        [
+            hash(0x23), // VerifyDilithium
+            hash(0x24), // BatchVerifyMlDsa
+        ]
+    }
+
+    /// ZK-coprocessor request precompile addresses
+    pub fn coprocessor_addresses() -> [H160; 2] {
+        [
+            hash(0x25), // SubmitComputeRequest
+            hash(0x26), // GetComputeResult
         ]
     }
 
-    pub fn used_addresses() -> [H160; 8] {
+    /// Quantum-vault state precompile addresses
+    pub fn vault_addresses() -> [H160; 2] {
+        [
+            hash(0x27), // IsVault
+            hash(0x28), // GetVaultNonce
+        ]
+    }
+
+    pub fn used_addresses() -> [H160; 15] {
         [
             // Standard Ethereum precompiles
             hash(1), // ECRecover
@@ -361,6 +399,16 @@ where
             hash(0x20), // VerifyStarkCommitment
             hash(0x21), // IsRequestVerified
             hash(0x22), // GetBatchInfo
+            hash(0x29), // VerifySp1Proof
+            // Post-quantum signature precompiles
+            hash(0x23), // VerifyDilithium
+            hash(0x24), // BatchVerifyMlDsa
+            // ZK-coprocessor request precompiles
+            hash(0x25), // SubmitComputeRequest
+            hash(0x26), // GetComputeResult
+            // Quantum-vault state precompiles
+            hash(0x27), // IsVault
+            hash(0x28), // GetVaultNonce
         ]
     }
 }
@@ -381,10 +429,16 @@ fn hash(a: u64) -> H160 {
 
 impl<R> PrecompileSet for TesseraxPrecompiles<R>
 where
-    R: pallet_evm::Config + pallet_reml_verifier::Config,
+    R: pallet_evm::Config
+        + pallet_reml_verifier::Config
+        + pallet_quantum_vault::Config
+        + pallet_zk_coprocessor::Config,
 {
     fn execute(&self, handle: &mut impl PrecompileHandle) -> Option<PrecompileResult> {
-        use crate::precompiles::{GetBatchInfo, IsRequestVerified, VerifyStarkCommitment};
+        use crate::precompiles::{
+            BatchVerifyMlDsa, GetBatchInfo, GetComputeResult, GetVaultNonce, IsRequestVerified,
+            IsVault, SubmitComputeRequest, VerifyDilithium, VerifySp1Proof, VerifyStarkCommitment,
+        };
 
         match handle.code_address() {
             // ═══════════════════════════════════════════════════════════════
@@ -413,6 +467,38 @@ where
             a if a == hash(0x21) => Some(IsRequestVerified::<R>::execute(handle)),
             // Get verified batch information
             a if a == hash(0x22) => Some(GetBatchInfo::<R>::execute(handle)),
+            // SP1 Groth16 proof, as SP1's ISP1Verifier checks it
+            a if a == hash(0x29) => Some(VerifySp1Proof::<R>::execute(handle)),
+
+            // ═══════════════════════════════════════════════════════════════
+            // POST-QUANTUM SIGNATURE PRECOMPILES (0x23 - 0x24)
+            // ═══════════════════════════════════════════════════════════════
+
+            // Verify one Dilithium2 signature
+            a if a == hash(0x23) => Some(VerifyDilithium::<R>::execute(handle)),
+
+            // Verify up to MAX_BATCH_SIGNATURES ML-DSA signatures in one call
+            a if a == hash(0x24) => Some(BatchVerifyMlDsa::<R>::execute(handle)),
+
+            // ═══════════════════════════════════════════════════════════════
+            // ZK-COPROCESSOR REQUEST PRECOMPILES (0x25 - 0x26)
+            // ═══════════════════════════════════════════════════════════════
+
+            // Request a proven run of a registered program
+            a if a == hash(0x25) => Some(SubmitComputeRequest::<R>::execute(handle)),
+
+            // Status and output of a request
+            a if a == hash(0x26) => Some(GetComputeResult::<R>::execute(handle)),
+
+            // ═══════════════════════════════════════════════════════════════
+            // QUANTUM-VAULT STATE PRECOMPILES (0x27 - 0x28)
+            // ═══════════════════════════════════════════════════════════════
+
+            // Whether the mapped account is a vault
+            a if a == hash(0x27) => Some(IsVault::<R>::execute(handle)),
+
+            // Nonce the vault's next transfer signs
+            a if a == hash(0x28) => Some(GetVaultNonce::<R>::execute(handle)),
 
             _ => None,
         }
@@ -454,8 +540,8 @@ impl pallet_evm::Config for Runtime {
     type PrecompilesValue = PrecompilesValue;
     type ChainId = ChainId;
     type BlockGasLimit = BlockGasLimit;
-    type Runner = pallet_evm::runner::stack::Runner<Self>;
-    type OnChargeTransaction = ();
+    type Runner = crate::vault_blocker::VaultGuardRunner;
+    type OnChargeTransaction = EvmFeeHandler;
     type OnCreate = ();
     type FindAuthor = FindAuthorTruncated<AuraAccountAdapter>;
     type GasLimitPovSizeRatio = GasLimitPovSizeRatio;
@@ -497,6 +583,97 @@ impl pallet_dynamic_fee::Config for Runtime {
     type MinGasPriceBoundDivisor = BoundDivision;
 }
 
+// ═══════════════════════════════════════════════════════════════════════════
+// EVM FEE DISTRIBUTION
+// ═══════════════════════════════════════════════════════════════════════════
+//
+// EIP-1559 split, without burning (supply is fixed by the emission curve):
+// - Base fee: EvmBaseFeeTreasuryShare to the treasury, remainder to the author
+// - Priority fee (tip): block author
+//
+// Frontier's default adapter pays tips to the *mapped* author address
+// (AccountId32 → H160 → padded AccountId32), which is not the validator's
+// account, so tips are routed via pallet_authorship instead.
+// ═══════════════════════════════════════════════════════════════════════════
+
+use frame_support::traits::{
+    fungible::{Balanced, Credit},
+    Imbalance, OnUnbalanced,
+};
+use pallet_evm::{EVMFungibleAdapter, OnChargeEVMTransaction};
+use sp_runtime::Percent;
+
+parameter_types! {
+    /// Share of the EVM base fee credited to the treasury (remainder to the block author)
+    pub const EvmBaseFeeTreasuryShare: Percent = Percent::from_percent(100);
+    /// Treasury pot receiving EVM base fees
+    pub EvmFeeTreasuryAccount: AccountId = TreasuryAccountId::get();
+}
+
+/// Deposit a fee credit into `who`, falling back to the treasury
+///
+/// A credit below the existential deposit cannot create a new account; it is
+/// then dropped (burned) as a last resort.
+fn resolve_fee_credit(who: &AccountId, credit: Credit<AccountId, Balances>) {
+    if let Err(credit) = Balances::resolve(who, credit) {
+        if let Err(credit) = Balances::resolve(&EvmFeeTreasuryAccount::get(), credit) {
+            log::warn!(
+                target: "evm-fees",
+                "🔥 Unable to deposit EVM fee of {} (below existential deposit), dropping",
+                credit.peek()
+            );
+        }
+    }
+}
+
+/// Splits the EVM base fee between the treasury and the block author
+pub struct DealWithEvmBaseFee;
+impl OnUnbalanced<Credit<AccountId, Balances>> for DealWithEvmBaseFee {
+    fn on_nonzero_unbalanced(amount: Credit<AccountId, Balances>) {
+        let treasury_share = EvmBaseFeeTreasuryShare::get().deconstruct() as u32;
+        let (to_treasury, to_author) = amount.ration(treasury_share, 100 - treasury_share);
+
+        resolve_fee_credit(&EvmFeeTreasuryAccount::get(), to_treasury);
+        if to_author.peek() > 0 {
+            match pallet_authorship::Pallet::<Runtime>::author() {
+                Some(author) => resolve_fee_credit(&author, to_author),
+                None => resolve_fee_credit(&EvmFeeTreasuryAccount::get(), to_author),
+            }
+        }
+    }
+}
+
+/// EVM fee handler: base fee via [`DealWithEvmBaseFee`], tips to the block author
+pub struct EvmFeeHandler;
+
+type EvmFungibleFees = EVMFungibleAdapter<Balances, DealWithEvmBaseFee>;
+
+impl OnChargeEVMTransaction<Runtime> for EvmFeeHandler {
+    type LiquidityInfo = <EvmFungibleFees as OnChargeEVMTransaction<Runtime>>::LiquidityInfo;
+
+    fn withdraw_fee(who: &H160, fee: U256) -> Result<Self::LiquidityInfo, pallet_evm::Error<Runtime>> {
+        EvmFungibleFees::withdraw_fee(who, fee)
+    }
+
+    fn correct_and_deposit_fee(
+        who: &H160,
+        corrected_fee: U256,
+        base_fee: U256,
+        already_withdrawn: Self::LiquidityInfo,
+    ) -> Self::LiquidityInfo {
+        EvmFungibleFees::correct_and_deposit_fee(who, corrected_fee, base_fee, already_withdrawn)
+    }
+
+    fn pay_priority_fee(tip: Self::LiquidityInfo) {
+        if let Some(tip) = tip {
+            match pallet_authorship::Pallet::<Runtime>::author() {
+                Some(author) => resolve_fee_credit(&author, tip),
+                None => resolve_fee_credit(&EvmFeeTreasuryAccount::get(), tip),
+            }
+        }
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // QUANTUM VAULT CONFIGURATION
 // ═══════════════════════════════════════════════════════════════════════════
@@ -521,6 +698,9 @@ impl pallet_dynamic_fee::Config for Runtime {
 use super::TSRX;
 
 parameter_types! {
+    // The fee values below are defaults: chain specs override them through
+    // pallet-chain-parameters (see CHAIN PARAMETERS CONFIGURATION).
+
     /// Fee to create a quantum vault: 2 TSRX (reduced from 10 TSRX)
     /// Rationale: With ~13.82M supply, lower fee improves retail adoption
     /// while still preventing spam (whitepaper v3.0)
@@ -535,33 +715,74 @@ parameter_types! {
     /// Combined with 10x multiplier = 0.1 TSRX per transfer
     pub const VaultTransferBaseFee: Balance = TSRX / 100;
 
-    /// Maximum public key size: Dilithium2 = 1312 bytes
-    pub const MaxPublicKeySize: u32 = 1312;
-    /// Maximum signature size: Dilithium2 = 2420 bytes
-    pub const MaxSignatureSize: u32 = 2420;
+    /// Premium of `PremiumMode::Percentage` vaults: 0.1% of the amount
+    pub const VaultTransferPremiumRate: Permill = Permill::from_parts(1_000);
+    /// Cap of the percentage premium: 1 TSRX
+    pub const VaultTransferPremiumCap: Balance = TSRX;
+
+    /// Share of the premium per authorization path: in-band transfers pay it
+    /// in full, Re-ML proof-backed ones 20%, as the batch proof replaces
+    /// their in-block Dilithium verification
+    pub const VaultPremiumSchedule: pallet_chain_parameters::PremiumSchedule =
+        pallet_chain_parameters::PremiumSchedule {
+            in_band: Permill::one(),
+            proof_backed: Permill::from_percent(20),
+        };
+    /// Governance can't lower any share of the premium below 5%
+    pub const MinVaultPremiumShare: Permill = Permill::from_percent(5);
+
+    /// Vault-to-vault transfers pay half the premium, to keep funds in PQC accounts
+    pub const VaultToVaultPremiumDiscount: Permill = Permill::from_percent(50);
+
+    /// Maximum public key size: Dilithium5 = 2592 bytes (Dilithium2 = 1312)
+    pub const MaxPublicKeySize: u32 = 2592;
+    /// Maximum signature size: SPHINCS+-SHA2-128s = 7856 bytes (Dilithium2 = 2420)
+    pub const MaxSignatureSize: u32 = 7856;
 
     /// Protocol treasury account for vault fees
-    /// Uses a deterministic address: "tesserax/vault_treasury"
+    /// Uses a deterministic address: "sanctuary/vault_treasury" padded to 32 bytes
+    /// (funds of the former "tesserax/vault_treasury" move via `naming::MigrateLegacyTreasury`)
     /// Future: Can be changed to governance-controlled multisig
-    pub TreasuryAccountId: AccountId = {
-        // Create a deterministic treasury address
-        // Format: "tesserax/vault_treasury" padded to 32 bytes
-        let mut bytes = [0u8; 32];
-        let prefix = b"tesserax/vault_treasury";
-        bytes[..prefix.len().min(32)].copy_from_slice(&prefix[..prefix.len().min(32)]);
-        AccountId::from(bytes)
-    };
+    pub TreasuryAccountId: AccountId = crate::naming::seed_account(crate::naming::TREASURY_SEED);
+
+    /// A fifth of every transfer premium pays the Re-ML aggregators proving
+    /// vault signatures
+    pub const VaultIncentivePoolShare: Permill = Permill::from_percent(20);
+    pub VaultIncentivePool: AccountId = crate::RemlIncentives::pool();
+
+    /// Keys of destroyed vaults kept for forensic attribution
+    pub const MaxArchivedVaultKeys: u32 = 10_000;
+
+    /// Vault daily spending limits apply over any 24 hours of blocks
+    pub const VaultSpendingWindow: BlockNumber = DAYS;
+
+    /// A year of vault usage statistics, one entry per emission era
+    pub const MaxVaultStatsEras: u32 = 365 * DAYS / pallet_emission::BLOCKS_PER_ERA;
 }
 
 impl pallet_quantum_vault::Config for Runtime {
     type Currency = Balances;
     type WeightInfo = pallet_quantum_vault::weights::SubstrateWeight<Self>;
-    type VaultCreationFee = VaultCreationFee;
-    type VaultTransferFeeMultiplier = VaultTransferFeeMultiplier;
-    type VaultTransferBaseFee = VaultTransferBaseFee;
+    type VaultCreationFee = pallet_chain_parameters::getters::VaultCreationFee<Runtime>;
+    type VaultTransferFeeMultiplier =
+        pallet_chain_parameters::getters::VaultTransferFeeMultiplier<Runtime>;
+    type VaultTransferBaseFee = pallet_chain_parameters::getters::VaultTransferBaseFee<Runtime>;
+    type VaultTransferPremiumRate =
+        pallet_chain_parameters::getters::VaultTransferPremiumRate<Runtime>;
+    type VaultTransferPremiumCap = pallet_chain_parameters::getters::VaultTransferPremiumCap<Runtime>;
+    type VaultToVaultPremiumDiscount = VaultToVaultPremiumDiscount;
+    type InBandPremiumShare = pallet_chain_parameters::getters::InBandPremiumShare<Runtime>;
+    type ProofBackedPremiumShare =
+        pallet_chain_parameters::getters::ProofBackedPremiumShare<Runtime>;
     type MaxPublicKeySize = MaxPublicKeySize;
     type MaxSignatureSize = MaxSignatureSize;
     type TreasuryAccount = TreasuryAccountId;
+    type IncentivePoolShare = VaultIncentivePoolShare;
+    type IncentivePool = VaultIncentivePool;
+    type MaxArchivedKeys = MaxArchivedVaultKeys;
+    type SpendingWindow = VaultSpendingWindow;
+    type EraLength = ConstU32<{ pallet_emission::BLOCKS_PER_ERA }>;
+    type MaxStatsEras = MaxVaultStatsEras;
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
@@ -578,32 +799,379 @@ impl pallet_quantum_vault::Config for Runtime {
 // - STARK proof verification
 // - Request ID tracking for verified signatures
 // - Integration with Quantum Vault for transfer authorization
+// - Verified batch roots mirrored into EVM storage (see evm_attestations)
 // ═══════════════════════════════════════════════════════════════════════════
 
+use super::HOURS;
+
 parameter_types! {
     /// Maximum number of authorized aggregators
     /// Smaller number = tighter control, larger = more decentralization
     pub const MaxAggregators: u32 = 16;
 
-    /// Expected verification key hash for the Re-ML guest program
-    /// This ensures only proofs from the correct SP1 program are accepted
+    /// Re-ML guest verification key hash until the program registry has an
+    /// active Re-ML version
     ///
-    /// Development Mode: [0u8; 32] accepts any vkey for testing
-    /// Production Mode: Set to actual vkey hash from `reml-prover vkey-hash`
+    /// Kept in `reml_vkey.rs`, regenerated from the guest ELF with
+    /// `cargo xtask vkey`. All zeros accepts any vkey in `lenient-testnet`
+    /// builds and none otherwise.
+    pub RemlFallbackVKeyHash: [u8; 32] = reml_vkey::REML_VKEY_HASH;
+
+    /// Re-ML guest ID in the program registry
+    pub const RemlProgramId: pallet_program_registry::ProgramId =
+        pallet_program_registry::REML_PROGRAM_ID;
+
+    /// Re-ML proof epoch, must match `REML_PROOF_EPOCH` in reml-lib
     ///
-    /// To get the production vkey hash:
-    /// 1. Build guest: `cd reml && cargo prove build`
-    /// 2. Get hash: `cargo run --bin reml-prover -- vkey-hash`
-    /// 3. Replace the value below with the output
-    pub ExpectedVKeyHash: [u8; 32] = {
-        // Development/Testnet: Accept any vkey for testing
-        // For production, replace with actual vkey hash bytes
-        [0u8; 32]
-    };
+    /// Bump (together with reml-lib) in any upgrade that changes proof
+    /// semantics, so proofs generated for the old rules can't be replayed.
+    pub const RemlProofEpoch: u32 = 1;
+
+    /// Verified request IDs written by `submit_proof` itself; the rest of a
+    /// batch (up to 1,000) is written in `on_idle`
+    pub const InlineRequestMarks: u32 = 256;
+
+    /// Credited to an aggregator's ledger for each verified batch, paid
+    /// from the reward pot on `claim_earnings`
+    pub const RemlBatchReward: Balance = TSRX / 10;
+
+    /// Reward pot holding aggregator tips and funding batch rewards
+    pub const RemlRewardPotId: PalletId = PalletId(*b"reml/pot");
+
+    /// Batches one aggregator can have verified per emission era (one every
+    /// ten blocks on average)
+    pub const RemlMaxBatchesPerEra: u32 = pallet_emission::BLOCKS_PER_ERA / 10;
+
+    /// Stake an aggregator bonds to submit proofs: 1,000 TSRX
+    pub const RemlAggregatorBond: Balance = 1_000 * TSRX;
+
+    /// Unbonded aggregator stake stays slashable for 14 days
+    pub const RemlUnbondingPeriod: BlockNumber = 14 * DAYS;
+
+    /// Verified batches are pruned after 30 days, well past
+    /// `RemlUnbondingPeriod` so invalid proofs can still be reported
+    pub const RemlProofRetentionPeriod: BlockNumber = 30 * DAYS;
+
+    /// Submissions must be anchored to one of the last hour's blocks, well
+    /// within `BlockHashCount`
+    pub const RemlMaxAnchorAge: BlockNumber = HOURS;
+
+    /// Reserved per verified batch until it is pruned: 0.01 TSRX
+    pub const RemlSubmissionDeposit: Balance = TSRX / 100;
+
+    /// Share of a batch's deposit paid to the `prune_expired` caller
+    pub const RemlPrunerShare: Perbill = Perbill::from_percent(20);
+}
+
+/// Pays slashed aggregator stake into the Re-ML reward pot, funding the
+/// rewards of honest aggregators
+pub struct RemlSlashToRewardPot;
+impl OnUnbalanced<pallet_balances::NegativeImbalance<Runtime>> for RemlSlashToRewardPot {
+    fn on_nonzero_unbalanced(amount: pallet_balances::NegativeImbalance<Runtime>) {
+        <Balances as frame_support::traits::Currency<AccountId>>::resolve_creating(
+            &pallet_reml_verifier::Pallet::<Runtime>::reward_pot(),
+            amount,
+        );
+    }
+}
+
+/// Vault accounts, barred from pallet calls that would move their funds
+/// without a Dilithium signature
+pub struct QuantumVaults;
+impl frame_support::traits::Contains<AccountId> for QuantumVaults {
+    fn contains(who: &AccountId) -> bool {
+        pallet_quantum_vault::Pallet::<Runtime>::is_vault(who)
+    }
 }
 
 impl pallet_reml_verifier::Config for Runtime {
     type WeightInfo = pallet_reml_verifier::weights::SubstrateWeight<Self>;
+    type AdminOrigin = RootOrCouncilSupermajority;
     type MaxAggregators = MaxAggregators;
     type ExpectedVKeyHash = ExpectedVKeyHash;
+    type TransitionVKeyHash = TransitionVKeyHash;
+    type ProgramVKeys = RegisteredProgramVKeys;
+    type InlineRequestMarks = InlineRequestMarks;
+    type ProofEpoch = RemlProofEpoch;
+    /// Mirror verified batches into EVM storage at 0x…0800
+    type OnBatchVerified = (crate::evm_attestations::EvmBatchMirror, crate::RemlIncentives);
+    /// Aggregators co-sign with their account keys
+    type CoSignature = Signature;
+    type RewardCurrency = Balances;
+    type VaultCheck = QuantumVaults;
+    type BatchReward = RemlBatchReward;
+    type RewardPotId = RemlRewardPotId;
+    type EraLength = ConstU32<{ pallet_emission::BLOCKS_PER_ERA }>;
+    type MaxBatchesPerEra = RemlMaxBatchesPerEra;
+    type AggregatorBond = RemlAggregatorBond;
+    type UnbondingPeriod = RemlUnbondingPeriod;
+    type Slash = RemlSlashToRewardPot;
+    type ProofRetentionPeriod = RemlProofRetentionPeriod;
+    type MaxAnchorAge = RemlMaxAnchorAge;
+    type SubmissionDeposit = RemlSubmissionDeposit;
+    type PrunerShare = RemlPrunerShare;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// CHAIN PARAMETERS CONFIGURATION
+// ═══════════════════════════════════════════════════════════════════════════
+//
+// Existential deposit and fee parameters live in storage so each chain spec
+// can set its own values at genesis (e.g. a low ED for faucet-driven testnets).
+//
+// - Existential deposit: genesis-only
+// - Vault and byte fees: genesis, then updatable by root
+// ═══════════════════════════════════════════════════════════════════════════
+
+parameter_types! {
+    /// Per-byte transaction fee: 1 planck per encoded byte
+    pub const TransactionByteFee: Balance = 1;
+}
+
+impl pallet_chain_parameters::Config for Runtime {
+    type Balance = Balance;
+    type AdminOrigin = frame_system::EnsureRoot<AccountId>;
+    type DefaultExistentialDeposit = ConstU128<EXISTENTIAL_DEPOSIT>;
+    type DefaultVaultCreationFee = VaultCreationFee;
+    type DefaultVaultTransferBaseFee = VaultTransferBaseFee;
+    type DefaultVaultTransferFeeMultiplier = VaultTransferFeeMultiplier;
+    type DefaultTransactionByteFee = TransactionByteFee;
+    type DefaultVaultTransferPremiumRate = VaultTransferPremiumRate;
+    type DefaultVaultTransferPremiumCap = VaultTransferPremiumCap;
+    type DefaultVaultPremiumSchedule = VaultPremiumSchedule;
+    type MinPremiumShare = MinVaultPremiumShare;
+    type WeightInfo = pallet_chain_parameters::weights::SubstrateWeight<Runtime>;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// WATCHTOWER CONFIGURATION
+// ═══════════════════════════════════════════════════════════════════════════
+//
+// Bonded third-party monitors re-verify Re-ML batches off-chain, attest to
+// them (aggregator reputation) or dispute them (resolved by root).
+// ═══════════════════════════════════════════════════════════════════════════
+
+use super::DAYS;
+
+parameter_types! {
+    /// Bond per watchtower: 100 TSRX, lost on a rejected dispute
+    pub const WatchtowerBond: Balance = 100 * TSRX;
+    pub const MaxWatchtowers: u32 = 64;
+    /// Batches can be attested or disputed for 7 days after verification
+    pub const AttestationWindow: BlockNumber = 7 * DAYS;
+    pub const MaxBatchesPerAttestation: u32 = 32;
+}
+
+impl pallet_watchtower::Config for Runtime {
+    type Currency = Balances;
+    type WatchtowerBond = WatchtowerBond;
+    type MaxWatchtowers = MaxWatchtowers;
+    type AttestationWindow = AttestationWindow;
+    type MaxBatchesPerAttestation = MaxBatchesPerAttestation;
+    type AdminOrigin = frame_system::EnsureRoot<AccountId>;
+    type WeightInfo = pallet_watchtower::weights::SubstrateWeight<Runtime>;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// ANNOUNCEMENTS CONFIGURATION
+// ═══════════════════════════════════════════════════════════════════════════
+//
+// Validator and operations announcements, signed with Dilithium vault keys.
+// ═══════════════════════════════════════════════════════════════════════════
+
+parameter_types! {
+    pub const MaxAnnouncementLength: u32 = 1024;
+    /// Older announcements are only in events
+    pub const MaxAnnouncements: u32 = 256;
+}
+
+impl pallet_announcements::Config for Runtime {
+    type MaxMessageLength = MaxAnnouncementLength;
+    type MaxAnnouncements = MaxAnnouncements;
+    type WeightInfo = pallet_announcements::weights::SubstrateWeight<Runtime>;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// RE-ML INCENTIVES CONFIGURATION
+// ═══════════════════════════════════════════════════════════════════════════
+//
+// Pool fed by `VaultIncentivePoolShare` of vault transfer premiums, paid out
+// per verifier era to active aggregators by signatures proven.
+// ═══════════════════════════════════════════════════════════════════════════
+
+parameter_types! {
+    pub const RemlIncentivesPotId: PalletId = PalletId(*b"reml/inc");
+    /// Era payouts stay claimable for 30 eras (days)
+    pub const RemlIncentivesClaimWindow: u32 = 30;
+}
+
+impl pallet_reml_incentives::Config for Runtime {
+    type Currency = Balances;
+    type PalletId = RemlIncentivesPotId;
+    type ClaimWindow = RemlIncentivesClaimWindow;
+    type WeightInfo = pallet_reml_incentives::weights::SubstrateWeight<Runtime>;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// PROGRAM REGISTRY CONFIGURATION
+// ═══════════════════════════════════════════════════════════════════════════
+//
+// zkVM guest programs the chain trusts, by version. Verifier pallets take the
+// vkey hash of the active version, so a guest upgrade is a governance call
+// instead of a runtime upgrade.
+// ═══════════════════════════════════════════════════════════════════════════
+
+parameter_types! {
+    /// Versions kept per program
+    pub const MaxProgramVersions: u32 = 16;
+    /// Proofs against the replaced version are accepted for a day after
+    /// a new version activates
+    pub const ProgramTransitionPeriod: BlockNumber = DAYS;
+}
+
+/// Vkey hash Re-ML proofs must commit to: the active Re-ML version in the
+/// program registry, `RemlFallbackVKeyHash` before one is registered
+pub type ExpectedVKeyHash =
+    pallet_program_registry::getters::ActiveVKeyHash<Runtime, RemlProgramId, RemlFallbackVKeyHash>;
+
+/// Vkey hash of the Re-ML version the active one replaced, during its
+/// transition window
+pub type TransitionVKeyHash =
+    pallet_program_registry::getters::TransitionVKeyHash<Runtime, RemlProgramId>;
+
+/// Other guest programs submitting to the Re-ML verifier: their active
+/// version, or the one it replaced during its transition window
+pub struct RegisteredProgramVKeys;
+impl pallet_reml_verifier::ProgramVKeys for RegisteredProgramVKeys {
+    fn accepts(program_id: pallet_reml_verifier::ProgramId, vkey_hash: &[u8; 32]) -> bool {
+        let versions = [
+            ProgramRegistry::active_version(program_id),
+            ProgramRegistry::transition_version(program_id),
+        ];
+        versions.into_iter().flatten().any(|version| &version.vkey_hash == vkey_hash)
+    }
+}
+
+impl pallet_program_registry::Config for Runtime {
+    type AdminOrigin = RootOrCouncilSupermajority;
+    type MaxVersions = MaxProgramVersions;
+    type TransitionPeriod = ProgramTransitionPeriod;
+    type WeightInfo = pallet_program_registry::weights::SubstrateWeight<Runtime>;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// ZK-COPROCESSOR CONFIGURATION
+// ═══════════════════════════════════════════════════════════════════════════
+//
+// Computation requests against registered guest programs, fulfilled by the
+// active Re-ML aggregators with SP1 Groth16 proofs checked against the
+// verifier's Groth16 key. Until root sets that key, nothing can be fulfilled.
+// ═══════════════════════════════════════════════════════════════════════════
+
+parameter_types! {
+    /// Held per request until its result is cleared
+    pub const CoprocessorRequestDeposit: Balance = TSRX;
+    /// Requests can be fulfilled for a day
+    pub const CoprocessorRequestTimeout: BlockNumber = DAYS;
+    pub const CoprocessorMaxInputSize: u32 = 16 * 1024;
+    pub const CoprocessorMaxOutputSize: u32 = 4 * 1024;
+}
+
+/// Active Re-ML aggregators, the coprocessor's provers
+pub struct ActiveRemlAggregators;
+impl frame_support::traits::Contains<AccountId> for ActiveRemlAggregators {
+    fn contains(who: &AccountId) -> bool {
+        pallet_reml_verifier::Pallet::<Runtime>::is_aggregator(who)
+    }
+}
+
+/// SP1 Groth16 proofs, against the Re-ML verifier's Groth16 key
+pub struct Sp1Groth16Verifier;
+impl pallet_zk_coprocessor::ProofVerifier for Sp1Groth16Verifier {
+    fn verify(vkey_hash: &[u8; 32], public_values: &[u8], proof: &[u8]) -> bool {
+        pallet_reml_verifier::Pallet::<Runtime>::verify_sp1_groth16(vkey_hash, public_values, proof)
+    }
+
+    fn weight() -> Weight {
+        use pallet_reml_verifier::WeightInfo;
+        // Priced for two pairing checks, one more than needed here
+        pallet_reml_verifier::weights::SubstrateWeight::<Runtime>::verify_groth16_proof()
+    }
+}
+
+impl pallet_zk_coprocessor::Config for Runtime {
+    type Currency = Balances;
+    type Provers = ActiveRemlAggregators;
+    type VaultCheck = QuantumVaults;
+    type ProofVerifier = Sp1Groth16Verifier;
+    type RequestDeposit = CoprocessorRequestDeposit;
+    type RequestTimeout = CoprocessorRequestTimeout;
+    type MaxInputSize = CoprocessorMaxInputSize;
+    type MaxOutputSize = CoprocessorMaxOutputSize;
+    type WeightInfo = pallet_zk_coprocessor::weights::SubstrateWeight<Runtime>;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// COUNCIL CONFIGURATION
+// ═══════════════════════════════════════════════════════════════════════════
+//
+// A collective that takes over Re-ML administration from the sudo key:
+// aggregator registration, slashing, the Groth16 key, verifier switches and
+// Re-ML program versions (and with them `ExpectedVKeyHash`).
+//
+// - Council motions passing with two thirds of members act as `AdminOrigin`
+// - Root keeps the same powers, so chains with sudo work as before
+// - Membership is managed by the council itself through pallet-membership
+// ═══════════════════════════════════════════════════════════════════════════
+
+use frame_support::traits::EitherOfDiverse;
+use frame_system::EnsureRoot;
+
+parameter_types! {
+    /// Motions expire after three days without reaching a threshold
+    pub const CouncilMotionDuration: BlockNumber = 3 * DAYS;
+    pub const CouncilMaxProposals: u32 = 100;
+    pub const CouncilMaxMembers: u32 = 13;
+    /// A motion may dispatch a call of up to half a block
+    pub MaxCouncilProposalWeight: Weight =
+        Perbill::from_percent(50) * RuntimeBlockWeights::get().max_block;
+}
+
+/// Instance of pallet-collective and pallet-membership forming the council
+pub type CouncilInstance = pallet_collective::Instance1;
+
+/// Root, or a council motion passed by two thirds of the members
+pub type RootOrCouncilSupermajority = EitherOfDiverse<
+    EnsureRoot<AccountId>,
+    pallet_collective::EnsureProportionAtLeast<AccountId, CouncilInstance, 2, 3>,
+>;
+
+impl pallet_collective::Config<CouncilInstance> for Runtime {
+    type RuntimeOrigin = RuntimeOrigin;
+    type Proposal = RuntimeCall;
+    type RuntimeEvent = RuntimeEvent;
+    type MotionDuration = CouncilMotionDuration;
+    type MaxProposals = CouncilMaxProposals;
+    type MaxMembers = CouncilMaxMembers;
+    type DefaultVote = pallet_collective::PrimeDefaultVote;
+    type WeightInfo = pallet_collective::weights::SubstrateWeight<Runtime>;
+    /// Members come from pallet-membership
+    type SetMembersOrigin = EnsureRoot<AccountId>;
+    type MaxProposalWeight = MaxCouncilProposalWeight;
+    type DisapproveOrigin = RootOrCouncilSupermajority;
+    type KillOrigin = RootOrCouncilSupermajority;
+    type Consideration = ();
+}
+
+impl pallet_membership::Config<CouncilInstance> for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type AddOrigin = RootOrCouncilSupermajority;
+    type RemoveOrigin = RootOrCouncilSupermajority;
+    type SwapOrigin = RootOrCouncilSupermajority;
+    type ResetOrigin = RootOrCouncilSupermajority;
+    type PrimeOrigin = RootOrCouncilSupermajority;
+    type MembershipInitialized = crate::Council;
+    type MembershipChanged = crate::Council;
+    type MaxMembers = CouncilMaxMembers;
+    type WeightInfo = pallet_membership::weights::SubstrateWeight<Runtime>;
 }
@@ -0,0 +1,14 @@
+//! Re-ML guest verification key hash.
+//!
+//! Generated by `cargo xtask vkey` from the built guest ELF; do not edit by
+//! hand. `cargo xtask vkey --check` fails when this no longer matches the
+//! guest or is all zeros. All zeros means unpinned: `lenient-testnet` builds
+//! accept any vkey, every other build rejects every proof against it.
+
+/// SP1 `vk.hash_bytes()` of the Re-ML guest program
+pub const REML_VKEY_HASH: [u8; 32] = [
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+];
@@ -0,0 +1,101 @@
+//! # Re-ML Batch Attestations on the EVM Side
+//!
+//! Mirrors every verified Re-ML batch into the storage of a fixed EVM contract,
+//! so Solidity dApps read fresh results with plain `SLOAD`-backed calls instead
+//! of decoding precompile output.
+//!
+//! ## Contract
+//!
+//! The contract at [`ATTESTATIONS_ADDRESS`] is a storage reader: calling it with
+//! a 32-byte slot returns the 32-byte word stored there. Its layout follows
+//! Solidity rules, as if declared as:
+//!
+//! ```solidity
+//! contract ReMLAttestations {
+//!     uint64 latestBatchId;                         // slot 0
+//!     uint64 batchCount;                            // slot 1
+//!     mapping(uint64 => bytes32) requestsRoot;      // slot 2
+//!     mapping(uint64 => uint256) batchMeta;         // slot 3: signatureCount << 64 | verifiedAt
+//! }
+//! ```
+//!
+//! `ReMLAttestationsLib` in `contracts/ReMLVerifier.sol` wraps the slot math.
+
+use crate::{AccountId, BlockNumber, Runtime};
+use alloc::vec::Vec;
+use frame_support::{traits::Get, weights::Weight};
+use pallet_reml_verifier::{BatchInfo, OnBatchVerified};
+use sp_core::{hashing::keccak_256, H160, H256, U256};
+
+/// Address of the attestations contract (`0x…0800`)
+pub const ATTESTATIONS_ADDRESS: H160 = H160([
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x08, 0x00,
+]);
+
+/// Runtime code of the storage reader:
+/// `PUSH1 0 CALLDATALOAD SLOAD PUSH1 0 MSTORE PUSH1 32 PUSH1 0 RETURN`
+pub const ATTESTATIONS_CODE: [u8; 12] =
+    [0x60, 0x00, 0x35, 0x54, 0x60, 0x00, 0x52, 0x60, 0x20, 0x60, 0x00, 0xf3];
+
+/// Slot of the most recently verified batch ID
+pub const LATEST_BATCH_SLOT: u64 = 0;
+/// Slot of the number of mirrored batches
+pub const BATCH_COUNT_SLOT: u64 = 1;
+/// Base slot of `batch_id => requests_root`
+pub const REQUESTS_ROOT_MAPPING: u64 = 2;
+/// Base slot of `batch_id => signature_count << 64 | verified_at`
+pub const BATCH_META_MAPPING: u64 = 3;
+
+/// Storage key of a plain slot
+pub fn slot(index: u64) -> H256 {
+    H256(U256::from(index).to_big_endian())
+}
+
+/// Storage key of `mapping[batch_id]`: `keccak256(abi.encode(batch_id, mapping))`
+pub fn mapping_slot(batch_id: u64, mapping: u64) -> H256 {
+    let mut preimage = Vec::with_capacity(64);
+    preimage.extend_from_slice(&U256::from(batch_id).to_big_endian());
+    preimage.extend_from_slice(&U256::from(mapping).to_big_endian());
+    H256(keccak_256(&preimage))
+}
+
+fn word(value: U256) -> H256 {
+    H256(value.to_big_endian())
+}
+
+/// Writes verified batches into the attestations contract storage
+pub struct EvmBatchMirror;
+
+impl OnBatchVerified<AccountId, BlockNumber> for EvmBatchMirror {
+    fn on_batch_verified(batch_id: u64, info: &BatchInfo<AccountId, BlockNumber>) {
+        // Install the reader on first use, so existing chains need no migration
+        if !pallet_evm::AccountCodes::<Runtime>::contains_key(ATTESTATIONS_ADDRESS) {
+            pallet_evm::AccountCodes::<Runtime>::insert(
+                ATTESTATIONS_ADDRESS,
+                ATTESTATIONS_CODE.to_vec(),
+            );
+        }
+
+        let meta = (U256::from(info.signature_count) << 64) | U256::from(info.verified_at);
+        let count_slot = slot(BATCH_COUNT_SLOT);
+        let count = U256::from_big_endian(
+            pallet_evm::AccountStorages::<Runtime>::get(ATTESTATIONS_ADDRESS, count_slot)
+                .as_bytes(),
+        );
+
+        let writes = [
+            (mapping_slot(batch_id, REQUESTS_ROOT_MAPPING), H256(info.requests_root)),
+            (mapping_slot(batch_id, BATCH_META_MAPPING), word(meta)),
+            (slot(LATEST_BATCH_SLOT), word(U256::from(batch_id))),
+            (count_slot, word(count.saturating_add(U256::one()))),
+        ];
+        for (key, value) in writes {
+            pallet_evm::AccountStorages::<Runtime>::insert(ATTESTATIONS_ADDRESS, key, value);
+        }
+    }
+
+    fn weight() -> Weight {
+        // Reads: code, count. Writes: code (first time), 4 slots.
+        <Runtime as frame_system::Config>::DbWeight::get().reads_writes(2, 5)
+    }
+}
@@ -13,7 +13,8 @@
 //! - Remaining supply is emitted over time via Sigmoid curve
 
 use crate::{
-    tesserax_constants::DEV_ENDOWMENT, AccountId, BalancesConfig, RuntimeGenesisConfig, SudoConfig,
+    tesserax_constants::DEV_ENDOWMENT, AccountId, Balance, BalancesConfig, ChainParametersConfig,
+    CouncilMembershipConfig, RuntimeGenesisConfig, SudoConfig, MILLI_TSRX,
 };
 use alloc::{vec, vec::Vec};
 use frame_support::build_struct_json_patch;
@@ -38,16 +39,23 @@ use sp_keyring::Sr25519Keyring;
 /// - Majority of tokens emitted via the Sigmoid emission curve
 /// ═══════════════════════════════════════════════════════════════════════════
 
+/// Existential deposit for development and local testnet presets: 0.001 TSRX
+pub const DEV_EXISTENTIAL_DEPOSIT: Balance = MILLI_TSRX;
+
 /// Build the genesis configuration for testnet/development networks.
 ///
 /// # Arguments
 /// * `initial_authorities` - BABE (Aura) and GRANDPA validator keypairs
 /// * `endowed_accounts` - Accounts that receive initial token allocation
 /// * `root` - The sudo (admin) account
+/// * `council` - Initial council members, who can administer Re-ML without sudo
+/// * `existential_deposit_override` - ED override (`None` keeps the runtime default of 1 TSRX)
 fn tesserax_genesis(
     initial_authorities: Vec<(AuraId, GrandpaId)>,
     endowed_accounts: Vec<AccountId>,
     root: AccountId,
+    council: Vec<AccountId>,
+    existential_deposit_override: Option<Balance>,
 ) -> Value {
     // Calculate per-account endowment
     let per_account = if !endowed_accounts.is_empty() {
@@ -77,6 +85,12 @@ fn tesserax_genesis(
                 .collect::<Vec<_>>(),
         },
         sudo: SudoConfig { key: Some(root) },
+        council_membership: CouncilMembershipConfig {
+            members: council.try_into().expect("council fits CouncilMaxMembers"),
+        },
+        chain_parameters: ChainParametersConfig {
+            existential_deposit: existential_deposit_override,
+        },
         // Note: pallet-emission is stateless - no genesis config needed
     })
 }
@@ -113,6 +127,10 @@ pub fn development_config_genesis() -> Value {
         ],
         // Sudo: Alice
         sp_keyring::Sr25519Keyring::Alice.to_account_id(),
+        // Council: Alice
+        vec![Sr25519Keyring::Alice.to_account_id()],
+        // 0.001 TSRX ED so faucet drips and micro-accounts stay alive
+        Some(DEV_EXISTENTIAL_DEPOSIT),
     )
 }
 
@@ -146,6 +164,13 @@ pub fn local_config_genesis() -> Value {
             .collect::<Vec<_>>(),
         // Sudo: Alice
         Sr25519Keyring::Alice.to_account_id(),
+        // Council: Alice, Bob and Charlie
+        vec![
+            Sr25519Keyring::Alice.to_account_id(),
+            Sr25519Keyring::Bob.to_account_id(),
+            Sr25519Keyring::Charlie.to_account_id(),
+        ],
+        Some(DEV_EXISTENTIAL_DEPOSIT),
     )
 }
 
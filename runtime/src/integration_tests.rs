@@ -176,6 +176,58 @@ fn integration_existential_deposit() {
     assert_eq!(EXISTENTIAL_DEPOSIT, TSRX);
 }
 
+#[test]
+fn integration_chain_parameters_drive_balances_and_vault_fees() {
+    use frame_support::traits::{fungible::Inspect, Get};
+    use sp_runtime::Permill;
+
+    let mut storage = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+    pallet_chain_parameters::GenesisConfig::<Runtime> {
+        existential_deposit: Some(MILLI_TSRX),
+        vault_creation_fee: Some(TSRX),
+        ..Default::default()
+    }
+    .assimilate_storage(&mut storage)
+    .unwrap();
+
+    sp_io::TestExternalities::new(storage).execute_with(|| {
+        // Balances reads the chain-spec ED, not the compile-time default
+        assert_eq!(<Balances as Inspect<AccountId>>::minimum_balance(), MILLI_TSRX);
+        assert_eq!(
+            <Runtime as pallet_quantum_vault::Config>::VaultCreationFee::get(),
+            TSRX
+        );
+        // Unset parameters keep the runtime defaults
+        assert_eq!(
+            <Runtime as pallet_quantum_vault::Config>::VaultTransferBaseFee::get(),
+            configs::VaultTransferBaseFee::get()
+        );
+
+        // The vault reads the premium schedule governance sets
+        assert_eq!(
+            <Runtime as pallet_quantum_vault::Config>::ProofBackedPremiumShare::get(),
+            configs::VaultPremiumSchedule::get().proof_backed
+        );
+        frame_support::assert_ok!(ChainParameters::set_vault_premium_schedule(
+            RuntimeOrigin::root(),
+            pallet_chain_parameters::PremiumSchedule {
+                in_band: Permill::from_percent(80),
+                proof_backed: Permill::from_percent(10),
+            },
+        ));
+        assert_eq!(
+            <Runtime as pallet_quantum_vault::Config>::InBandPremiumShare::get(),
+            Permill::from_percent(80)
+        );
+        assert_eq!(
+            <Runtime as pallet_quantum_vault::Config>::ProofBackedPremiumShare::get(),
+            Permill::from_percent(10)
+        );
+    });
+}
+
 #[test]
 fn integration_version_info() {
     // Verify runtime version
@@ -185,7 +237,2995 @@ fn integration_version_info() {
     );
     assert_eq!(
         VERSION.impl_name,
-        alloc::borrow::Cow::Borrowed("tesserax-runtime")
+        alloc::borrow::Cow::Borrowed("sanctuary-runtime")
+    );
+    assert_eq!(VERSION.spec_name, naming::SPEC_NAME);
+    assert_eq!(VERSION.impl_name, naming::IMPL_NAME);
+    assert_eq!(VERSION.spec_version, 101);
+    assert_eq!(VERSION.transaction_version, 2);
+}
+
+#[test]
+fn integration_legacy_treasury_migration_moves_funds() {
+    use frame_support::{
+        assert_ok,
+        traits::{fungible::Mutate, Get, OnRuntimeUpgrade},
+    };
+
+    let storage = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+
+    sp_io::TestExternalities::new(storage).execute_with(|| {
+        let legacy = naming::seed_account(naming::LEGACY_TREASURY_SEED);
+        let treasury = configs::TreasuryAccountId::get();
+        assert_eq!(treasury, naming::seed_account(naming::TREASURY_SEED));
+        assert_ne!(legacy, treasury);
+
+        assert_ok!(Balances::mint_into(&legacy, 50 * TSRX));
+        naming::MigrateLegacyTreasury::on_runtime_upgrade();
+
+        assert_eq!(Balances::free_balance(&legacy), 0);
+        assert_eq!(Balances::free_balance(&treasury), 50 * TSRX);
+
+        // Nothing left to move on later upgrades
+        naming::MigrateLegacyTreasury::on_runtime_upgrade();
+        assert_eq!(Balances::free_balance(&treasury), 50 * TSRX);
+
+        // The try-runtime checks pass around a real move too
+        #[cfg(feature = "try-runtime")]
+        {
+            assert_ok!(Balances::mint_into(&legacy, 5 * TSRX));
+            assert_ok!(naming::MigrateLegacyTreasury::try_on_runtime_upgrade(true));
+            assert_eq!(Balances::free_balance(&treasury), 55 * TSRX);
+        }
+    });
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// ADDRESS MAPPING INTEGRATION TESTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn integration_account_views_address_mapping() {
+    use crate::account_views::truncate_to_h160;
+    use pallet_evm::AddressMapping;
+
+    // Padded H160 accounts round-trip
+    let h160 = sp_core::H160::repeat_byte(0xAB);
+    let padded = configs::HashedAddressMapping::into_account_id(h160);
+    assert_eq!(truncate_to_h160(&padded), h160);
+
+    // Full 32-byte accounts do not: the H160 view maps to another account
+    let account = AccountId::from([0xCD; 32]);
+    let view = truncate_to_h160(&account);
+    assert_eq!(view, sp_core::H160::repeat_byte(0xCD));
+    assert_ne!(configs::HashedAddressMapping::into_account_id(view), account);
+}
+
+#[test]
+fn integration_vault_evm_revert_data_is_abi_error_string() {
+    use crate::vault_blocker::{vault_evm_revert_data, VAULT_EVM_TRANSFER_BLOCKED_REASON};
+
+    let data = vault_evm_revert_data();
+    let reason = VAULT_EVM_TRANSFER_BLOCKED_REASON.as_bytes();
+
+    // Error(string) selector, offset 32, length, padded reason
+    assert_eq!(&data[0..4], &[0x08, 0xc3, 0x79, 0xa0]);
+    assert_eq!(sp_core::U256::from_big_endian(&data[4..36]), sp_core::U256::from(32));
+    assert_eq!(sp_core::U256::from_big_endian(&data[36..68]), sp_core::U256::from(reason.len()));
+    assert_eq!(&data[68..68 + reason.len()], reason);
+    assert_eq!((data.len() - 4) % 32, 0);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// EVM FEE DISTRIBUTION INTEGRATION TESTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn integration_evm_base_fee_goes_to_treasury() {
+    use codec::Encode;
+    use configs::{
+        EvmBaseFeeTreasuryShare, EvmFeeHandler, EvmFeeTreasuryAccount, HashedAddressMapping,
+        TreasuryAccountId,
+    };
+    use frame_support::{assert_ok, traits::fungible::Mutate};
+    use pallet_evm::{AddressMapping, OnChargeEVMTransaction};
+    use sp_core::{H160, U256};
+    use sp_keyring::Sr25519Keyring::Alice;
+    use sp_runtime::DigestItem;
+
+    // Base fees are not burned: they go to the same pot as vault fees
+    assert_eq!(
+        EvmBaseFeeTreasuryShare::get(),
+        sp_runtime::Percent::from_percent(100)
+    );
+    assert_eq!(EvmFeeTreasuryAccount::get(), TreasuryAccountId::get());
+
+    let storage = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+
+    sp_io::TestExternalities::new(storage).execute_with(|| {
+        System::set_block_number(1);
+
+        // Alice authors the block, per its Aura pre-runtime digest
+        let author = Alice.to_account_id();
+        pallet_aura::Authorities::<Runtime>::put(frame_support::BoundedVec::truncate_from(vec![
+            Alice.public().into(),
+        ]));
+        System::deposit_log(DigestItem::PreRuntime(
+            sp_consensus_aura::AURA_ENGINE_ID,
+            sp_consensus_aura::Slot::from(0).encode(),
+        ));
+        assert_eq!(Authorship::author(), Some(author.clone()));
+
+        let treasury = TreasuryAccountId::get();
+        let caller = H160::repeat_byte(0x11);
+        let caller_account = HashedAddressMapping::into_account_id(caller);
+        assert_ok!(Balances::mint_into(&caller_account, 100 * TSRX));
+
+        // The gas limit is paid up front; the unused part is refunded and
+        // what was used splits into the base fee and the priority tip
+        let withdrawn = EvmFeeHandler::withdraw_fee(&caller, U256::from(30 * TSRX)).unwrap();
+        let tip = EvmFeeHandler::correct_and_deposit_fee(
+            &caller,
+            U256::from(20 * TSRX),
+            U256::from(15 * TSRX),
+            withdrawn,
+        );
+        EvmFeeHandler::pay_priority_fee(tip);
+
+        assert_eq!(Balances::free_balance(&caller_account), 80 * TSRX);
+        assert_eq!(Balances::free_balance(&treasury), 15 * TSRX);
+        assert_eq!(Balances::free_balance(&author), 5 * TSRX);
+    });
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// RE-ML INTEGRATION TESTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn integration_reml_batch_digest_roundtrips_through_header() {
+    use pallet_reml_verifier::digest::{
+        BatchDigest, BatchDigestEntry, BATCH_DIGEST_VERSION, REML_ENGINE_ID,
+    };
+    use sp_runtime::{Digest, DigestItem};
+
+    let batch_digest = BatchDigest {
+        version: BATCH_DIGEST_VERSION,
+        batches: vec![BatchDigestEntry {
+            batch_id: 7,
+            requests_root: [0xAA; 32],
+            signature_count: 3,
+        }],
+    };
+
+    // Mixed in with other consensus logs, as in a real Aura header
+    let digest = Digest {
+        logs: vec![
+            DigestItem::PreRuntime(*b"aura", vec![0u8; 8]),
+            batch_digest.to_digest_item(),
+        ],
+    };
+
+    assert_eq!(BatchDigest::find(&digest), Some(batch_digest));
+    assert_eq!(REML_ENGINE_ID, *b"REML");
+    assert_eq!(BatchDigest::find(&Digest::default()), None);
+}
+
+#[test]
+fn integration_verified_batches_are_mirrored_to_evm_storage() {
+    use crate::evm_attestations::*;
+    use pallet_reml_verifier::{BatchInfo, OnBatchVerified};
+
+    let storage = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+
+    sp_io::TestExternalities::new(storage).execute_with(|| {
+        let info = BatchInfo {
+            aggregator: AccountId::from([1u8; 32]),
+            verified_at: 42,
+            signature_count: 9,
+            requests_root: [0xAB; 32],
+            proof_commitment: [0u8; 32],
+        };
+        EvmBatchMirror::on_batch_verified(7, &info);
+        EvmBatchMirror::on_batch_verified(8, &info);
+
+        let read = |key| pallet_evm::AccountStorages::<Runtime>::get(ATTESTATIONS_ADDRESS, key);
+        let word = |v: u64| sp_core::H256(sp_core::U256::from(v).to_big_endian());
+
+        assert_eq!(
+            pallet_evm::AccountCodes::<Runtime>::get(ATTESTATIONS_ADDRESS),
+            ATTESTATIONS_CODE.to_vec()
+        );
+        assert_eq!(read(mapping_slot(7, REQUESTS_ROOT_MAPPING)), sp_core::H256([0xAB; 32]));
+        let meta = (sp_core::U256::from(9) << 64) | sp_core::U256::from(42);
+        assert_eq!(read(mapping_slot(7, BATCH_META_MAPPING)), sp_core::H256(meta.to_big_endian()));
+        assert_eq!(read(slot(LATEST_BATCH_SLOT)), word(8));
+        assert_eq!(read(slot(BATCH_COUNT_SLOT)), word(2));
+    });
+}
+
+#[test]
+fn integration_verified_batches_credit_reml_incentives() {
+    use crate::evm_attestations::*;
+    use pallet_reml_verifier::{BatchInfo, OnBatchVerified};
+
+    let storage = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+
+    sp_io::TestExternalities::new(storage).execute_with(|| {
+        let aggregator = AccountId::from([1u8; 32]);
+        let info = BatchInfo {
+            aggregator: aggregator.clone(),
+            verified_at: DAYS + 1,
+            signature_count: 9,
+            requests_root: [0xAB; 32],
+            proof_commitment: [0u8; 32],
+        };
+        <Runtime as pallet_reml_verifier::Config>::OnBatchVerified::on_batch_verified(7, &info);
+
+        // Mirrored to EVM storage and credited for era 1
+        let latest = pallet_evm::AccountStorages::<Runtime>::get(
+            ATTESTATIONS_ADDRESS,
+            slot(LATEST_BATCH_SLOT),
+        );
+        assert_eq!(
+            latest,
+            sp_core::H256(sp_core::U256::from(7).to_big_endian())
+        );
+        assert_eq!(
+            pallet_reml_incentives::EraSignatures::<Runtime>::get(1, &aggregator),
+            9
+        );
+
+        // Vault premiums feed the same pool
+        assert_eq!(configs::VaultIncentivePool::get(), RemlIncentives::pool());
+    });
+}
+
+#[test]
+fn integration_reml_batch_lookup_by_requests_root() {
+    use pallet_reml_verifier::{BatchInfo, RootToBatch, VerifiedBatches};
+
+    let storage = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+
+    sp_io::TestExternalities::new(storage).execute_with(|| {
+        let info = BatchInfo {
+            aggregator: AccountId::from([1u8; 32]),
+            verified_at: 42,
+            signature_count: 9,
+            requests_root: [0xCD; 32],
+            proof_commitment: [0u8; 32],
+        };
+        VerifiedBatches::<Runtime>::insert(11, info);
+        RootToBatch::<Runtime>::insert([0xCD; 32], 11);
+
+        let (batch_id, found) = RemlVerifier::batch_by_root([0xCD; 32]).unwrap();
+        assert_eq!(batch_id, 11);
+        assert_eq!(found.signature_count, 9);
+        assert!(RemlVerifier::batch_by_root([0xEE; 32]).is_none());
+    });
+}
+
+#[test]
+fn integration_reml_cosigned_proof_requires_threshold() {
+    use frame_support::{
+        assert_noop, assert_ok,
+        traits::{fungible::Mutate, Get},
+    };
+    use pallet_reml_verifier::{
+        Error, Event, ProofSubmission, PublicValues, MIN_PROOF_SIZE, REML_PROGRAM_ID, REML_VERSION,
+        TESSERAX_CHAIN_ID,
+    };
+    use sp_keyring::Sr25519Keyring::{self, Alice, Bob, Charlie};
+
+    let storage = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+
+    sp_io::TestExternalities::new(storage).execute_with(|| {
+        System::set_block_number(1);
+        for aggregator in [Alice, Bob] {
+            assert_ok!(RemlVerifier::register_aggregator(
+                RuntimeOrigin::root(),
+                aggregator.to_account_id()
+            ));
+        }
+        // Covers the submission deposit
+        assert_ok!(Balances::mint_into(&Alice.to_account_id(), 10 * TSRX));
+        assert_ok!(RemlVerifier::set_cosign_threshold(RuntimeOrigin::root(), 2));
+
+        // Single-request batch: the requests root is the leaf hash
+        let public_values = PublicValues {
+            version: REML_VERSION,
+            chain_id: TESSERAX_CHAIN_ID,
+            epoch: configs::RemlProofEpoch::get(),
+            batch_id: 1,
+            verified_count: 1,
+            mldsa44_count: 1,
+            falcon512_count: 0,
+            requests_root: sp_io::hashing::keccak_256(&42u64.to_le_bytes()),
+            requests_root_blake2: sp_io::hashing::blake2_256(&42u64.to_le_bytes()),
+            verified_request_ids: vec![42].try_into().unwrap(),
+            verified_request_owners: Default::default(),
+            verified_request_messages: Default::default(),
+        };
+        let mut proof = vec![0x01];
+        proof.extend_from_slice(&public_values.binding_hash());
+        proof.resize(MIN_PROOF_SIZE, 0);
+        let submission = ProofSubmission {
+            batch_id: 1,
+            proof: proof.try_into().unwrap(),
+            public_values,
+            vkey_hash: configs::ExpectedVKeyHash::get(),
+            program_id: REML_PROGRAM_ID,
+            anchor_block: 0,
+            anchor_hash: System::block_hash(0).into(),
+        };
+
+        let payload = RemlVerifier::cosign_payload(&submission);
+        let cosign =
+            |key: Sr25519Keyring| (key.to_account_id(), Signature::from(key.sign(&payload)));
+        let submit = |co_signatures: Vec<(AccountId, Signature)>| {
+            RemlVerifier::submit_cosigned_proof(
+                RuntimeOrigin::signed(Alice.to_account_id()),
+                submission.clone(),
+                co_signatures.try_into().unwrap(),
+            )
+        };
+
+        // The submitter alone is below the threshold
+        assert_noop!(
+            RemlVerifier::submit_proof(
+                RuntimeOrigin::signed(Alice.to_account_id()),
+                submission.clone()
+            ),
+            Error::<Runtime>::InsufficientCoSignatures
+        );
+        // Co-signers must be other, registered aggregators signing this proof
+        assert_noop!(submit(vec![cosign(Alice)]), Error::<Runtime>::DuplicateCoSigner);
+        assert_noop!(submit(vec![cosign(Charlie)]), Error::<Runtime>::InvalidCoSignature);
+        assert_noop!(
+            submit(vec![(Bob.to_account_id(), Signature::from(Bob.sign(b"another proof")))]),
+            Error::<Runtime>::InvalidCoSignature
+        );
+
+        assert_ok!(submit(vec![cosign(Bob)]));
+        assert!(RemlVerifier::is_request_verified(42));
+        System::assert_last_event(
+            Event::<Runtime>::ProofCoSigned { batch_id: 1, co_signers: vec![Bob.to_account_id()] }
+                .into(),
+        );
+    });
+}
+
+#[test]
+fn integration_reml_aggregator_migration_adds_reputation() {
+    use codec::Encode;
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+    use pallet_reml_verifier::{migrations::v1, Aggregators};
+
+    let storage = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+
+    sp_io::TestExternalities::new(storage).execute_with(|| {
+        // An aggregator stored before `reputation` existed
+        let aggregator = AccountId::from([3u8; 32]);
+        let old = v1::OldAggregatorInfo::<BlockNumber> {
+            registered_at: 5,
+            proofs_submitted: 2,
+            active: true,
+        };
+        frame_support::storage::unhashed::put_raw(
+            &Aggregators::<Runtime>::hashed_key_for(&aggregator),
+            &old.encode(),
+        );
+        StorageVersion::new(0).put::<RemlVerifier>();
+
+        v1::MigrateV0ToV1::<Runtime>::on_runtime_upgrade();
+
+        let info = v1::Aggregators::<Runtime>::get(&aggregator).unwrap();
+        assert_eq!(info.proofs_submitted, 2);
+        assert!(info.active);
+        assert_eq!(info.reputation, Default::default());
+        assert_eq!(RemlVerifier::on_chain_storage_version(), 1);
+    });
+}
+
+#[test]
+fn integration_reml_aggregator_migration_adds_server_key_hash() {
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+    use pallet_reml_verifier::{
+        migrations::{v1, v2},
+        Aggregators,
+    };
+
+    let storage = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+
+    sp_io::TestExternalities::new(storage).execute_with(|| {
+        // An aggregator stored before `server_key_hash` existed
+        let aggregator = AccountId::from([3u8; 32]);
+        v1::Aggregators::<Runtime>::insert(
+            &aggregator,
+            v1::AggregatorInfo::<BlockNumber> {
+                registered_at: 5,
+                proofs_submitted: 2,
+                active: true,
+                reputation: Default::default(),
+            },
+        );
+        StorageVersion::new(1).put::<RemlVerifier>();
+
+        v2::MigrateV1ToV2::<Runtime>::on_runtime_upgrade();
+
+        let info = Aggregators::<Runtime>::get(&aggregator).unwrap();
+        assert_eq!(info.proofs_submitted, 2);
+        assert!(info.active);
+        assert_eq!(info.server_key_hash, None);
+        assert_eq!(RemlVerifier::on_chain_storage_version(), 2);
+    });
+}
+
+#[test]
+fn integration_reml_dry_run_reports_reject_reason() {
+    use frame_support::{
+        assert_ok,
+        traits::{fungible::Mutate, Get},
+    };
+    use pallet_reml_verifier::{
+        Error, ProofSubmission, PublicValues, RejectReason, MIN_PROOF_SIZE, REML_PROGRAM_ID,
+        REML_VERSION, TESSERAX_CHAIN_ID,
+    };
+    use sp_keyring::Sr25519Keyring::Alice;
+
+    let storage = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+
+    sp_io::TestExternalities::new(storage).execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(RemlVerifier::register_aggregator(
+            RuntimeOrigin::root(),
+            Alice.to_account_id()
+        ));
+        assert_ok!(Balances::mint_into(&Alice.to_account_id(), 10 * TSRX));
+        let events = System::events().len();
+
+        let public_values = PublicValues {
+            version: REML_VERSION,
+            chain_id: TESSERAX_CHAIN_ID,
+            epoch: configs::RemlProofEpoch::get(),
+            batch_id: 1,
+            verified_count: 1,
+            mldsa44_count: 1,
+            falcon512_count: 0,
+            requests_root: sp_io::hashing::keccak_256(&42u64.to_le_bytes()),
+            requests_root_blake2: sp_io::hashing::blake2_256(&42u64.to_le_bytes()),
+            verified_request_ids: vec![42].try_into().unwrap(),
+            verified_request_owners: Default::default(),
+            verified_request_messages: Default::default(),
+        };
+        let mut proof = vec![0x01];
+        proof.extend_from_slice(&public_values.binding_hash());
+        proof.resize(MIN_PROOF_SIZE, 0);
+        let submission = ProofSubmission {
+            batch_id: 1,
+            proof: proof.try_into().unwrap(),
+            public_values,
+            vkey_hash: configs::ExpectedVKeyHash::get(),
+            program_id: REML_PROGRAM_ID,
+            anchor_block: 0,
+            anchor_hash: System::block_hash(0).into(),
+        };
+        let dry_run = |submission: &ProofSubmission| {
+            RemlVerifier::dry_run_submit_proof(Alice.to_account_id(), submission.clone(), &[])
+        };
+
+        // A valid bundle passes and leaves no trace
+        assert_ok!(dry_run(&submission));
+        assert!(!RemlVerifier::is_request_verified(42));
+        assert_eq!(Balances::reserved_balance(&Alice.to_account_id()), 0);
+        assert_eq!(System::events().len(), events);
+
+        let mut wrong_root = submission.clone();
+        wrong_root.public_values.requests_root = [0xEE; 32];
+        let error = dry_run(&wrong_root).unwrap_err();
+        assert_eq!(error, Error::<Runtime>::InvalidMerkleRoot.into());
+        assert_eq!(RemlVerifier::reject_reason(&error), Some(RejectReason::InvalidMerkleRoot));
+
+        // The blake2 root is checked as strictly as the keccak one
+        let mut wrong_blake2_root = submission.clone();
+        wrong_blake2_root.public_values.requests_root_blake2 = [0xEE; 32];
+        assert_eq!(
+            dry_run(&wrong_blake2_root).unwrap_err(),
+            Error::<Runtime>::InvalidMerkleRoot.into()
+        );
+
+        let mut bad_proof = submission.clone();
+        let mut proof = bad_proof.proof.to_vec();
+        proof[1] ^= 0xFF;
+        bad_proof.proof = proof.try_into().unwrap();
+        let error = dry_run(&bad_proof).unwrap_err();
+        assert_eq!(
+            RemlVerifier::reject_reason(&error),
+            Some(RejectReason::StarkVerificationFailed)
+        );
+        // The `ProofRejected` event was rolled back too
+        assert_eq!(System::events().len(), events);
+
+        // Errors without a `ProofRejected` counterpart
+        let error = RemlVerifier::dry_run_submit_proof(AccountId::from([9u8; 32]), submission, &[])
+            .unwrap_err();
+        assert_eq!(error, Error::<Runtime>::NotAuthorized.into());
+        assert_eq!(RemlVerifier::reject_reason(&error), None);
+    });
+}
+
+#[test]
+fn integration_reml_rejected_submissions_are_recorded_after_dispatch() {
+    use crate::vault_blocker::CheckVaultTransfer;
+    use frame_support::{
+        assert_ok,
+        dispatch::GetDispatchInfo,
+        traits::{fungible::Mutate, Get},
+    };
+    use pallet_reml_verifier::{
+        ProofSubmission, PublicValues, RejectReason, Rejection, MAX_RECENT_REJECTIONS,
+        MIN_PROOF_SIZE, REML_PROGRAM_ID, REML_VERSION, TESSERAX_CHAIN_ID,
+    };
+    use sp_keyring::Sr25519Keyring::Alice;
+    use sp_runtime::traits::{Dispatchable, TransactionExtension};
+
+    let storage = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+
+    sp_io::TestExternalities::new(storage).execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(RemlVerifier::register_aggregator(
+            RuntimeOrigin::root(),
+            Alice.to_account_id()
+        ));
+        assert_ok!(Balances::mint_into(&Alice.to_account_id(), 10 * TSRX));
+
+        // Dispatch `call` from Alice between the extension's prepare and post_dispatch
+        let apply = |call: RuntimeCall| {
+            let origin = RuntimeOrigin::signed(Alice.to_account_id());
+            let info = call.get_dispatch_info();
+            let pre = CheckVaultTransfer::new()
+                .prepare((), &origin, &call, &info, 0)
+                .unwrap();
+            let outcome = call.dispatch(origin);
+            let result = outcome.as_ref().map(|_| ()).map_err(|e| e.error);
+            let mut post_info = outcome.unwrap_or_else(|e| e.post_info);
+            CheckVaultTransfer::post_dispatch(pre, &info, &mut post_info, 0, &result).unwrap();
+            result
+        };
+        let root = |batch_id: u64| sp_io::hashing::keccak_256(&batch_id.to_le_bytes());
+        let submit = |batch_id: u64, requests_root: [u8; 32], epoch: u32| {
+            let public_values = PublicValues {
+                version: REML_VERSION,
+                chain_id: TESSERAX_CHAIN_ID,
+                epoch,
+                batch_id,
+                verified_count: 1,
+                mldsa44_count: 1,
+                falcon512_count: 0,
+                requests_root,
+                requests_root_blake2: sp_io::hashing::blake2_256(&batch_id.to_le_bytes()),
+                verified_request_ids: vec![batch_id].try_into().unwrap(),
+                verified_request_owners: Default::default(),
+                verified_request_messages: Default::default(),
+            };
+            let mut proof = vec![0x01];
+            proof.extend_from_slice(&public_values.binding_hash());
+            proof.resize(MIN_PROOF_SIZE, 0);
+            RuntimeCall::RemlVerifier(pallet_reml_verifier::Call::submit_proof {
+                submission: ProofSubmission {
+                    batch_id,
+                    proof: proof.try_into().unwrap(),
+                    public_values,
+                    vkey_hash: configs::ExpectedVKeyHash::get(),
+                    program_id: REML_PROGRAM_ID,
+                    anchor_block: 0,
+                    anchor_hash: System::block_hash(0).into(),
+                },
+            })
+        };
+
+        // Accepted proofs leave no rejection
+        let epoch = configs::RemlProofEpoch::get();
+        assert_ok!(apply(submit(1, root(1), epoch)));
+        assert!(RemlVerifier::recent_rejections().is_empty());
+
+        System::set_block_number(2);
+        assert!(apply(submit(2, [0xEE; 32], epoch)).is_err());
+        assert_eq!(
+            RemlVerifier::recent_rejections(),
+            vec![Rejection {
+                batch_id: 2,
+                aggregator: Alice.to_account_id(),
+                reason: RejectReason::InvalidMerkleRoot,
+                rejected_at: 2,
+            }]
+        );
+
+        // Failures without a `RejectReason` are not recorded
+        assert!(apply(submit(3, root(3), epoch + 1)).is_err());
+        assert_eq!(RemlVerifier::recent_rejections().len(), 1);
+
+        // Oldest rejections drop out
+        for batch_id in 3..=MAX_RECENT_REJECTIONS as u64 + 2 {
+            assert!(apply(submit(batch_id, [0xEE; 32], epoch)).is_err());
+        }
+        let rejections = RemlVerifier::recent_rejections();
+        assert_eq!(rejections.len(), MAX_RECENT_REJECTIONS as usize);
+        assert_eq!(rejections[0].batch_id, 3);
+    });
+}
+
+#[test]
+fn integration_reml_claimed_batch_ids_cannot_be_squatted() {
+    use frame_support::{
+        assert_noop, assert_ok,
+        traits::{fungible::Mutate, Get},
+    };
+    use pallet_reml_verifier::{
+        Error, Event, ProofSubmission, PublicValues, MIN_PROOF_SIZE, REML_PROGRAM_ID, REML_VERSION,
+        TESSERAX_CHAIN_ID,
+    };
+    use sp_keyring::Sr25519Keyring::{Alice, Bob, Charlie};
+
+    let storage = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+
+    sp_io::TestExternalities::new(storage).execute_with(|| {
+        System::set_block_number(5);
+        for aggregator in [Alice, Bob] {
+            assert_ok!(RemlVerifier::register_aggregator(
+                RuntimeOrigin::root(),
+                aggregator.to_account_id()
+            ));
+        }
+        assert_ok!(Balances::mint_into(&Alice.to_account_id(), 10 * TSRX));
+
+        let submission = |batch_id: u64| {
+            let public_values = PublicValues {
+                version: REML_VERSION,
+                chain_id: TESSERAX_CHAIN_ID,
+                epoch: configs::RemlProofEpoch::get(),
+                batch_id,
+                verified_count: 1,
+                mldsa44_count: 1,
+                falcon512_count: 0,
+                requests_root: sp_io::hashing::keccak_256(&42u64.to_le_bytes()),
+                requests_root_blake2: sp_io::hashing::blake2_256(&42u64.to_le_bytes()),
+                verified_request_ids: vec![42].try_into().unwrap(),
+                verified_request_owners: Default::default(),
+                verified_request_messages: Default::default(),
+            };
+            let mut proof = vec![0x01];
+            proof.extend_from_slice(&public_values.binding_hash());
+            proof.resize(MIN_PROOF_SIZE, 0);
+            ProofSubmission {
+                batch_id,
+                proof: proof.try_into().unwrap(),
+                public_values,
+                vkey_hash: configs::ExpectedVKeyHash::get(),
+                program_id: REML_PROGRAM_ID,
+                anchor_block: 0,
+                anchor_hash: System::block_hash(0).into(),
+            }
+        };
+        let submit = |who: AccountId, batch_id: u64| {
+            RemlVerifier::submit_proof(RuntimeOrigin::signed(who), submission(batch_id))
+        };
+
+        assert_noop!(
+            RemlVerifier::claim_batch_id(RuntimeOrigin::signed(Charlie.to_account_id())),
+            Error::<Runtime>::NotAuthorized
+        );
+        assert_ok!(RemlVerifier::claim_batch_id(RuntimeOrigin::signed(
+            Alice.to_account_id()
+        )));
+        assert_ok!(RemlVerifier::claim_batch_id(RuntimeOrigin::signed(
+            Alice.to_account_id()
+        )));
+        let batch_id = 5u64 << 32;
+        System::assert_last_event(
+            Event::<Runtime>::BatchIdClaimed {
+                batch_id: batch_id | 1,
+                aggregator: Alice.to_account_id(),
+            }
+            .into(),
+        );
+        let mut pending = RemlVerifier::pending_aggregators();
+        pending.sort_by_key(|(_, id)| *id);
+        assert_eq!(
+            pending,
+            vec![(Alice.to_account_id(), batch_id), (Alice.to_account_id(), batch_id | 1)]
+        );
+
+        // Bob cannot front-run Alice's claimed ID
+        assert_noop!(
+            submit(Bob.to_account_id(), batch_id),
+            Error::<Runtime>::BatchIdClaimedByOther
+        );
+
+        // Once required, unclaimed IDs are refused
+        assert_ok!(RemlVerifier::set_require_claimed_batch_ids(
+            RuntimeOrigin::root(),
+            true
+        ));
+        assert_noop!(
+            submit(Bob.to_account_id(), 7),
+            Error::<Runtime>::BatchIdNotClaimed
+        );
+
+        assert_ok!(submit(Alice.to_account_id(), batch_id));
+        assert!(RemlVerifier::is_request_verified(42));
+        assert_eq!(RemlVerifier::batch_id_claimant(batch_id), None);
+        assert_eq!(
+            RemlVerifier::batch_id_claimant(batch_id | 1),
+            Some(Alice.to_account_id())
+        );
+        assert_eq!(
+            RemlVerifier::pending_aggregators(),
+            vec![(Alice.to_account_id(), batch_id | 1)]
+        );
+    });
+}
+
+#[test]
+fn integration_reml_aggregator_batches_are_capped_per_era() {
+    use frame_support::{
+        assert_noop, assert_ok,
+        traits::{fungible::Mutate, Get},
+    };
+    use pallet_emission::BLOCKS_PER_ERA;
+    use pallet_reml_verifier::{
+        AggregatorEraBatches, Error, ProofSubmission, PublicValues, MIN_PROOF_SIZE,
+        REML_PROGRAM_ID, REML_VERSION, TESSERAX_CHAIN_ID,
+    };
+    use sp_keyring::Sr25519Keyring::{Alice, Bob};
+
+    let storage = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+
+    sp_io::TestExternalities::new(storage).execute_with(|| {
+        // Initialized blocks record their parent's hash, the submissions' anchor
+        System::initialize(&5, &sp_core::H256::repeat_byte(4), &Default::default());
+        for aggregator in [Alice, Bob] {
+            assert_ok!(RemlVerifier::register_aggregator(
+                RuntimeOrigin::root(),
+                aggregator.to_account_id()
+            ));
+            assert_ok!(Balances::mint_into(&aggregator.to_account_id(), 10 * TSRX));
+        }
+
+        let submit = |who: AccountId, batch_id: u64| {
+            let public_values = PublicValues {
+                version: REML_VERSION,
+                chain_id: TESSERAX_CHAIN_ID,
+                epoch: configs::RemlProofEpoch::get(),
+                batch_id,
+                verified_count: 1,
+                mldsa44_count: 1,
+                falcon512_count: 0,
+                requests_root: sp_io::hashing::keccak_256(&42u64.to_le_bytes()),
+                requests_root_blake2: sp_io::hashing::blake2_256(&42u64.to_le_bytes()),
+                verified_request_ids: vec![42].try_into().unwrap(),
+                verified_request_owners: Default::default(),
+                verified_request_messages: Default::default(),
+            };
+            let mut proof = vec![0x01];
+            proof.extend_from_slice(&public_values.binding_hash());
+            proof.resize(MIN_PROOF_SIZE, 0);
+            let submission = ProofSubmission {
+                batch_id,
+                proof: proof.try_into().unwrap(),
+                public_values,
+                vkey_hash: configs::ExpectedVKeyHash::get(),
+                program_id: REML_PROGRAM_ID,
+                anchor_block: System::block_number() - 1,
+                anchor_hash: System::parent_hash().into(),
+            };
+            RemlVerifier::submit_proof(RuntimeOrigin::signed(who), submission)
+        };
+
+        // Alice is one batch short of the cap
+        let cap = configs::RemlMaxBatchesPerEra::get();
+        AggregatorEraBatches::<Runtime>::insert(Alice.to_account_id(), (0, cap - 1));
+        assert_ok!(submit(Alice.to_account_id(), 1));
+        assert_noop!(
+            submit(Alice.to_account_id(), 2),
+            Error::<Runtime>::EraBatchLimitReached
+        );
+
+        // The cap is per aggregator
+        assert_ok!(submit(Bob.to_account_id(), 2));
+        assert_eq!(RemlVerifier::era_batches(&Bob.to_account_id(), 0), 1);
+
+        // and resets with the era
+        System::initialize(&BLOCKS_PER_ERA, &sp_core::H256::repeat_byte(5), &Default::default());
+        assert_ok!(submit(Alice.to_account_id(), 3));
+        assert_eq!(
+            AggregatorEraBatches::<Runtime>::get(Alice.to_account_id()),
+            (1, 1)
+        );
+    });
+}
+
+#[test]
+fn integration_reml_submissions_are_anchored_to_recent_blocks() {
+    use frame_support::{
+        assert_noop, assert_ok,
+        traits::{fungible::Mutate, Get},
+    };
+    use pallet_reml_verifier::{
+        Error, ProofSubmission, PublicValues, RejectReason, MIN_PROOF_SIZE, REML_PROGRAM_ID,
+        REML_VERSION, TESSERAX_CHAIN_ID,
+    };
+    use sp_core::H256;
+    use sp_keyring::Sr25519Keyring::Alice;
+
+    let storage = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+
+    sp_io::TestExternalities::new(storage).execute_with(|| {
+        let parent = H256::repeat_byte(9);
+        System::initialize(&10, &parent, &Default::default());
+        let aggregator = Alice.to_account_id();
+        assert_ok!(RemlVerifier::register_aggregator(
+            RuntimeOrigin::root(),
+            aggregator.clone()
+        ));
+        assert_ok!(Balances::mint_into(&aggregator, 10 * TSRX));
+
+        let submission = |anchor_block: u32, anchor_hash: H256| {
+            let public_values = PublicValues {
+                version: REML_VERSION,
+                chain_id: TESSERAX_CHAIN_ID,
+                epoch: configs::RemlProofEpoch::get(),
+                batch_id: 1,
+                verified_count: 1,
+                mldsa44_count: 1,
+                falcon512_count: 0,
+                requests_root: sp_io::hashing::keccak_256(&42u64.to_le_bytes()),
+                requests_root_blake2: sp_io::hashing::blake2_256(&42u64.to_le_bytes()),
+                verified_request_ids: vec![42].try_into().unwrap(),
+                verified_request_owners: Default::default(),
+                verified_request_messages: Default::default(),
+            };
+            let mut proof = vec![0x01];
+            proof.extend_from_slice(&public_values.binding_hash());
+            proof.resize(MIN_PROOF_SIZE, 0);
+            ProofSubmission {
+                batch_id: 1,
+                proof: proof.try_into().unwrap(),
+                public_values,
+                vkey_hash: configs::ExpectedVKeyHash::get(),
+                program_id: REML_PROGRAM_ID,
+                anchor_block,
+                anchor_hash: anchor_hash.into(),
+            }
+        };
+        let submit = |anchor_block, anchor_hash| {
+            RemlVerifier::submit_proof(
+                RuntimeOrigin::signed(aggregator.clone()),
+                submission(anchor_block, anchor_hash),
+            )
+        };
+
+        // Another fork's block 9, the current block, and a block with no known hash
+        assert_noop!(submit(9, H256::repeat_byte(8)), Error::<Runtime>::InvalidAnchor);
+        assert_noop!(submit(10, parent), Error::<Runtime>::InvalidAnchor);
+        assert_noop!(submit(5, H256::zero()), Error::<Runtime>::InvalidAnchor);
+        assert_eq!(
+            RemlVerifier::reject_reason(&Error::<Runtime>::InvalidAnchor.into()),
+            Some(RejectReason::InvalidAnchor)
+        );
+
+        // Genesis is too old once `RemlMaxAnchorAge` has passed
+        let genesis = System::block_hash(0);
+        let max_age = configs::RemlMaxAnchorAge::get();
+        System::set_block_number(max_age + 1);
+        assert_noop!(submit(0, genesis), Error::<Runtime>::InvalidAnchor);
+        System::set_block_number(max_age);
+        assert_ok!(submit(0, genesis));
+    });
+}
+
+#[test]
+fn integration_reml_verified_requests_are_consumed_once() {
+    use frame_support::{assert_noop, assert_ok};
+    use pallet_reml_verifier::{Error, RemlVerification, RequestOwners, VerifiedRequests};
+    use sp_keyring::Sr25519Keyring::{Alice, Bob};
+
+    let storage = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+
+    sp_io::TestExternalities::new(storage).execute_with(|| {
+        System::set_block_number(5);
+        // Request 42 was signed by the key hashing to `owner`
+        let owner = [4u8; 32];
+        VerifiedRequests::<Runtime>::insert(42, (1, 4));
+        RequestOwners::<Runtime>::insert(42, owner);
+
+        assert_noop!(
+            RemlVerifier::consume_verified_request(7, &owner, &Alice.to_account_id()),
+            Error::<Runtime>::RequestNotVerified
+        );
+
+        // Nobody else can use it up first
+        assert_noop!(
+            RemlVerifier::consume_verified_request(42, &[5u8; 32], &Bob.to_account_id()),
+            Error::<Runtime>::NotRequestOwner
+        );
+
+        assert!(!RemlVerifier::is_request_consumed(42));
+        assert_ok!(RemlVerifier::consume_verified_request(
+            42,
+            &owner,
+            &Alice.to_account_id()
+        ));
+        assert!(RemlVerifier::is_request_consumed(42));
+        assert_eq!(
+            RemlVerifier::consumed_by(42),
+            Some((Alice.to_account_id(), 5))
+        );
+
+        // Consuming leaves the verification itself in place
+        assert!(RemlVerifier::is_request_verified(42));
+        assert_noop!(
+            RemlVerifier::consume_verified_request(42, &owner, &Bob.to_account_id()),
+            Error::<Runtime>::RequestAlreadyConsumed
+        );
+    });
+}
+
+#[test]
+fn integration_reml_pending_request_marks_drain_in_on_idle() {
+    use frame_support::{traits::Hooks, weights::Weight};
+    use pallet_reml_verifier::{weights::WeightInfo, PendingRequestMarks};
+
+    let storage = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+
+    sp_io::TestExternalities::new(storage).execute_with(|| {
+        PendingRequestMarks::<Runtime>::insert(3, (7, vec![10, 11, 12].try_into().unwrap()));
+        type Weights = <Runtime as pallet_reml_verifier::Config>::WeightInfo;
+
+        // Room for two marks: the third stays queued
+        let limit = Weights::mark_pending_requests(2);
+        let used = RemlVerifier::on_idle(8, limit);
+        assert!(limit.all_gte(used));
+        assert_eq!(RemlVerifier::get_verification_info(10), Some((3, 7)));
+        assert!(RemlVerifier::is_request_verified(11));
+        assert!(!RemlVerifier::is_request_verified(12));
+        let (_, remaining) = PendingRequestMarks::<Runtime>::get(3).unwrap();
+        assert_eq!(remaining.into_inner(), vec![12]);
+
+        RemlVerifier::on_idle(8, Weight::MAX);
+        assert!(RemlVerifier::is_request_verified(12));
+        assert!(!PendingRequestMarks::<Runtime>::contains_key(3));
+    });
+}
+
+#[test]
+fn integration_reml_aggregator_earnings_are_claimable() {
+    use frame_support::{
+        assert_noop, assert_ok,
+        traits::{fungible::Mutate, Get},
+    };
+    use pallet_reml_verifier::{
+        AggregatorLedger, BatchLedger, Error, ProofSubmission, PublicValues, MIN_PROOF_SIZE,
+        REML_PROGRAM_ID, REML_VERSION, TESSERAX_CHAIN_ID,
+    };
+    use sp_keyring::Sr25519Keyring::{Alice, Bob};
+
+    let storage = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+
+    sp_io::TestExternalities::new(storage).execute_with(|| {
+        System::set_block_number(1);
+        let aggregator = Alice.to_account_id();
+        assert_ok!(RemlVerifier::register_aggregator(
+            RuntimeOrigin::root(),
+            aggregator.clone()
+        ));
+        assert_ok!(Balances::mint_into(&aggregator, 10 * TSRX));
+
+        let public_values = PublicValues {
+            version: REML_VERSION,
+            chain_id: TESSERAX_CHAIN_ID,
+            epoch: configs::RemlProofEpoch::get(),
+            batch_id: 1,
+            verified_count: 1,
+            mldsa44_count: 1,
+            falcon512_count: 0,
+            requests_root: sp_io::hashing::keccak_256(&42u64.to_le_bytes()),
+            requests_root_blake2: sp_io::hashing::blake2_256(&42u64.to_le_bytes()),
+            verified_request_ids: vec![42].try_into().unwrap(),
+            verified_request_owners: Default::default(),
+            verified_request_messages: Default::default(),
+        };
+        let mut proof = vec![0x01];
+        proof.extend_from_slice(&public_values.binding_hash());
+        proof.resize(MIN_PROOF_SIZE, 0);
+        assert_ok!(RemlVerifier::submit_proof(
+            RuntimeOrigin::signed(aggregator.clone()),
+            ProofSubmission {
+                batch_id: 1,
+                proof: proof.try_into().unwrap(),
+                public_values,
+                vkey_hash: configs::ExpectedVKeyHash::get(),
+                program_id: REML_PROGRAM_ID,
+                anchor_block: 0,
+                anchor_hash: System::block_hash(0).into(),
+            }
+        ));
+
+        let reward = configs::RemlBatchReward::get();
+        assert_eq!(BatchLedger::<Runtime>::get(1).reward, reward);
+        assert_eq!(AggregatorLedger::<Runtime>::get(&aggregator).claimable, reward);
+
+        // Tips go to the pot and are credited to the batch's aggregator
+        assert_ok!(Balances::mint_into(&Bob.to_account_id(), 10 * TSRX));
+        assert_noop!(
+            RemlVerifier::tip_batch(RuntimeOrigin::signed(Bob.to_account_id()), 2, TSRX),
+            Error::<Runtime>::BatchNotFound
+        );
+        assert_ok!(RemlVerifier::tip_batch(
+            RuntimeOrigin::signed(Bob.to_account_id()),
+            1,
+            2 * TSRX
+        ));
+        assert_eq!(Balances::free_balance(&RemlVerifier::reward_pot()), 2 * TSRX);
+
+        // Premiums are only recorded
+        RemlVerifier::attribute_premium(42, TSRX / 100);
+        RemlVerifier::attribute_premium(43, TSRX / 100);
+        assert_eq!(BatchLedger::<Runtime>::get(1).premiums_attributed, TSRX / 100);
+
+        let earnings = AggregatorLedger::<Runtime>::get(&aggregator);
+        assert_eq!(earnings.rewards, reward);
+        assert_eq!(earnings.tips, 2 * TSRX);
+        assert_eq!(earnings.premiums_attributed, TSRX / 100);
+        assert_eq!(earnings.claimable, reward + 2 * TSRX);
+
+        // Rewards are paid from the pot, so it must cover them
+        assert_noop!(
+            RemlVerifier::claim_earnings(RuntimeOrigin::signed(aggregator.clone())),
+            Error::<Runtime>::InsufficientRewardPot
+        );
+        assert_ok!(Balances::mint_into(&RemlVerifier::reward_pot(), reward));
+        assert_ok!(RemlVerifier::claim_earnings(RuntimeOrigin::signed(
+            aggregator.clone()
+        )));
+        let deposit = configs::RemlSubmissionDeposit::get();
+        assert_eq!(
+            Balances::free_balance(&aggregator),
+            10 * TSRX - deposit + reward + 2 * TSRX
+        );
+
+        let earnings = AggregatorLedger::<Runtime>::get(&aggregator);
+        assert_eq!(earnings.claimable, 0);
+        assert_eq!(earnings.claimed, reward + 2 * TSRX);
+        assert_noop!(
+            RemlVerifier::claim_earnings(RuntimeOrigin::signed(aggregator)),
+            Error::<Runtime>::NothingToClaim
+        );
+    });
+}
+
+#[test]
+fn integration_reml_groth16_key_rejects_forged_proofs() {
+    use frame_support::{
+        assert_noop, assert_ok,
+        traits::{fungible::Mutate, Get},
+    };
+    use pallet_reml_verifier::{
+        groth16::{VerifyingKey, PROOF_SIZE},
+        Error, Groth16Vk, ProofSubmission, PublicValues, MIN_PROOF_SIZE, REML_PROGRAM_ID,
+        REML_VERSION, TESSERAX_CHAIN_ID,
+    };
+    use sp_keyring::Sr25519Keyring::Alice;
+
+    // BN254 generators in the EVM pairing precompile encoding
+    let g1: [u8; 64] = hex_literal::hex!(
+        "0000000000000000000000000000000000000000000000000000000000000001"
+        "0000000000000000000000000000000000000000000000000000000000000002"
+    );
+    let g2: [u8; 128] = hex_literal::hex!(
+        "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2"
+        "1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed"
+        "090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b"
+        "12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa"
+    );
+    let vk = VerifyingKey {
+        selector: *b"sp1!",
+        alpha_g1: g1,
+        beta_g2: g2,
+        gamma_g2: g2,
+        delta_g2: g2,
+        ic: vec![g1; 3].try_into().unwrap(),
+    };
+
+    let storage = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+
+    sp_io::TestExternalities::new(storage).execute_with(|| {
+        System::set_block_number(1);
+        let aggregator = Alice.to_account_id();
+        assert_ok!(RemlVerifier::register_aggregator(
+            RuntimeOrigin::root(),
+            aggregator.clone()
+        ));
+        assert_ok!(Balances::mint_into(&aggregator, 10 * TSRX));
+
+        // Root only, and the key must take SP1's two public inputs
+        assert_noop!(
+            RemlVerifier::set_groth16_vk(
+                RuntimeOrigin::signed(aggregator.clone()),
+                Some(vk.clone())
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        let mut wrong_inputs = vk.clone();
+        wrong_inputs.ic = vec![g1; 2].try_into().unwrap();
+        assert_noop!(
+            RemlVerifier::set_groth16_vk(RuntimeOrigin::root(), Some(wrong_inputs)),
+            Error::<Runtime>::InvalidVerifyingKey
+        );
+        let mut off_curve = vk.clone();
+        off_curve.alpha_g1[63] = 3;
+        assert_noop!(
+            RemlVerifier::set_groth16_vk(RuntimeOrigin::root(), Some(off_curve)),
+            Error::<Runtime>::InvalidVerifyingKey
+        );
+        assert_ok!(RemlVerifier::set_groth16_vk(
+            RuntimeOrigin::root(),
+            Some(vk.clone())
+        ));
+        assert_eq!(Groth16Vk::<Runtime>::get(), Some(vk.clone()));
+
+        let public_values = PublicValues {
+            version: REML_VERSION,
+            chain_id: TESSERAX_CHAIN_ID,
+            epoch: configs::RemlProofEpoch::get(),
+            batch_id: 1,
+            verified_count: 1,
+            mldsa44_count: 1,
+            falcon512_count: 0,
+            requests_root: sp_io::hashing::keccak_256(&42u64.to_le_bytes()),
+            requests_root_blake2: sp_io::hashing::blake2_256(&42u64.to_le_bytes()),
+            verified_request_ids: vec![42].try_into().unwrap(),
+            verified_request_owners: Default::default(),
+            verified_request_messages: Default::default(),
+        };
+        let submission = |proof: Vec<u8>| ProofSubmission {
+            batch_id: 1,
+            proof: proof.try_into().unwrap(),
+            public_values: public_values.clone(),
+            vkey_hash: configs::ExpectedVKeyHash::get(),
+            program_id: REML_PROGRAM_ID,
+            anchor_block: 0,
+            anchor_hash: System::block_hash(0).into(),
+        };
+
+        // A bundle that passes the structural checks is no longer enough
+        let mut structural = vec![0x01];
+        structural.extend_from_slice(&public_values.binding_hash());
+        structural.resize(MIN_PROOF_SIZE, 0);
+        assert_noop!(
+            RemlVerifier::submit_proof(
+                RuntimeOrigin::signed(aggregator.clone()),
+                submission(structural.clone())
+            ),
+            Error::<Runtime>::ProofVerificationFailed
+        );
+
+        // Well-formed points that don't satisfy the pairing equation
+        let mut forged = b"sp1!".to_vec();
+        forged.extend_from_slice(&g1);
+        forged.extend_from_slice(&g2);
+        forged.extend_from_slice(&g1);
+        assert_eq!(forged.len(), PROOF_SIZE);
+        assert_noop!(
+            RemlVerifier::submit_proof(
+                RuntimeOrigin::signed(aggregator.clone()),
+                submission(forged)
+            ),
+            Error::<Runtime>::ProofVerificationFailed
+        );
+
+        // Clearing the key restores the testnet behaviour
+        assert_ok!(RemlVerifier::set_groth16_vk(RuntimeOrigin::root(), None));
+        assert_ok!(RemlVerifier::submit_proof(
+            RuntimeOrigin::signed(aggregator),
+            submission(structural)
+        ));
+    });
+}
+
+#[test]
+fn integration_reml_aggregator_stake_bonds_unbonds_and_slashes() {
+    use frame_support::{
+        assert_noop, assert_ok,
+        traits::{fungible::Mutate, Get, ReservableCurrency},
+    };
+    use pallet_reml_verifier::{AggregatorStakes, AggregatorUnbonding, Aggregators, Error};
+    use sp_keyring::Sr25519Keyring::{Alice, Bob};
+
+    let storage = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+
+    sp_io::TestExternalities::new(storage).execute_with(|| {
+        System::set_block_number(1);
+        let bond = configs::RemlAggregatorBond::get();
+        let aggregator = Alice.to_account_id();
+        assert_ok!(Balances::mint_into(&aggregator, 3 * bond));
+
+        // Bonding the minimum registers an active aggregator
+        assert_noop!(
+            RemlVerifier::bond_aggregator(RuntimeOrigin::signed(aggregator.clone()), bond - 1),
+            Error::<Runtime>::BondTooLow
+        );
+        assert_ok!(RemlVerifier::bond_aggregator(
+            RuntimeOrigin::signed(aggregator.clone()),
+            bond
+        ));
+        assert!(RemlVerifier::is_aggregator(&aggregator));
+        assert_eq!(Balances::reserved_balance(&aggregator), bond);
+
+        // Unbonding deactivates at once and locks the stake for UnbondingPeriod
+        assert_ok!(RemlVerifier::unbond_aggregator(RuntimeOrigin::signed(
+            aggregator.clone()
+        )));
+        assert!(!RemlVerifier::is_aggregator(&aggregator));
+        let unlock_at = 1 + configs::RemlUnbondingPeriod::get();
+        assert_eq!(
+            AggregatorUnbonding::<Runtime>::get(&aggregator),
+            Some((bond, unlock_at))
+        );
+        assert_noop!(
+            RemlVerifier::bond_aggregator(RuntimeOrigin::signed(aggregator.clone()), bond),
+            Error::<Runtime>::UnbondingInProgress
+        );
+        assert_noop!(
+            RemlVerifier::withdraw_unbonded(RuntimeOrigin::signed(aggregator.clone())),
+            Error::<Runtime>::StakeStillLocked
+        );
+
+        System::set_block_number(unlock_at);
+        assert_ok!(RemlVerifier::withdraw_unbonded(RuntimeOrigin::signed(
+            aggregator.clone()
+        )));
+        assert_eq!(Balances::reserved_balance(&aggregator), 0);
+        assert!(!Aggregators::<Runtime>::contains_key(&aggregator));
+        assert_noop!(
+            RemlVerifier::withdraw_unbonded(RuntimeOrigin::signed(aggregator.clone())),
+            Error::<Runtime>::NothingToWithdraw
+        );
+
+        // Governance slashes into the reward pot and deactivates
+        assert_ok!(RemlVerifier::bond_aggregator(
+            RuntimeOrigin::signed(aggregator.clone()),
+            2 * bond
+        ));
+        assert_noop!(
+            RemlVerifier::slash_aggregator(
+                RuntimeOrigin::signed(Bob.to_account_id()),
+                aggregator.clone(),
+                bond
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(RemlVerifier::slash_aggregator(
+            RuntimeOrigin::root(),
+            aggregator.clone(),
+            bond
+        ));
+        assert_eq!(AggregatorStakes::<Runtime>::get(&aggregator), bond);
+        assert_eq!(Balances::reserved_balance(&aggregator), bond);
+        assert_eq!(Balances::free_balance(&RemlVerifier::reward_pot()), bond);
+        assert!(!RemlVerifier::is_aggregator(&aggregator));
+
+        // The rest can be withdrawn, but a slashed aggregator stays deactivated
+        assert_ok!(RemlVerifier::unbond_aggregator(RuntimeOrigin::signed(
+            aggregator.clone()
+        )));
+        System::set_block_number(System::block_number() + configs::RemlUnbondingPeriod::get());
+        assert_ok!(RemlVerifier::withdraw_unbonded(RuntimeOrigin::signed(
+            aggregator.clone()
+        )));
+        assert_ok!(RemlVerifier::bond_aggregator(
+            RuntimeOrigin::signed(aggregator.clone()),
+            bond
+        ));
+        assert!(!RemlVerifier::is_aggregator(&aggregator));
+    });
+}
+
+#[test]
+fn integration_reml_requests_are_routed_by_stake() {
+    use frame_support::{
+        assert_ok,
+        traits::{fungible::Mutate, Get},
+    };
+    use pallet_reml_verifier::{Aggregators, ROUTING_RANGE_SIZE};
+    use sp_keyring::Sr25519Keyring::{Alice, Bob, Charlie};
+
+    let storage = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+
+    sp_io::TestExternalities::new(storage).execute_with(|| {
+        System::set_block_number(1);
+        assert_eq!(RemlVerifier::suggested_aggregator(7), None);
+
+        // Alice bonds the minimum, Bob nine times more, Charlie nothing
+        let bond = configs::RemlAggregatorBond::get();
+        let (alice, bob, charlie) =
+            (Alice.to_account_id(), Bob.to_account_id(), Charlie.to_account_id());
+        for (aggregator, stake) in [(&alice, bond), (&bob, 9 * bond)] {
+            assert_ok!(Balances::mint_into(aggregator, 2 * stake));
+            assert_ok!(RemlVerifier::bond_aggregator(
+                RuntimeOrigin::signed(aggregator.clone()),
+                stake
+            ));
+        }
+        assert_ok!(RemlVerifier::register_aggregator(RuntimeOrigin::root(), charlie.clone()));
+
+        // One aggregator per range, drawn by stake
+        let route = |range: u64| RemlVerifier::suggested_aggregator(range * ROUTING_RANGE_SIZE);
+        let ranges: Vec<_> = (0..200).map(route).collect();
+        for (range, suggested) in ranges.iter().enumerate() {
+            let last_id = (range as u64 + 1) * ROUTING_RANGE_SIZE - 1;
+            assert_eq!(&RemlVerifier::suggested_aggregator(last_id), suggested);
+        }
+        let count = |account| ranges.iter().filter(|s| s.as_ref() == Some(account)).count();
+        assert!(count(&bob) > 4 * count(&alice), "{} vs {}", count(&bob), count(&alice));
+        assert!(count(&alice) > 0);
+        assert_eq!(count(&alice) + count(&bob) + count(&charlie), ranges.len());
+
+        // Upheld disputes weigh against an aggregator's stake
+        let mut info = Aggregators::<Runtime>::get(&bob).unwrap();
+        assert_eq!(RemlVerifier::routing_weight(&bob, &info), 9 * bond);
+        info.reputation.upheld_disputes = 1;
+        assert_eq!(RemlVerifier::routing_weight(&bob, &info), 9 * bond / 5);
+        info.reputation.attestations = 3;
+        assert_eq!(RemlVerifier::routing_weight(&bob, &info), 9 * bond / 2);
+
+        // The assignment changes with the era, and skips inactive aggregators
+        System::set_block_number(BLOCKS_PER_ERA.into());
+        assert_ne!((0..200).map(route).collect::<Vec<_>>(), ranges);
+        assert_ok!(RemlVerifier::unbond_aggregator(RuntimeOrigin::signed(bob.clone())));
+        assert!((0..200).all(|range| route(range) != Some(bob.clone())));
+    });
+}
+
+#[test]
+fn integration_reml_aggregator_commits_to_server_key_hash() {
+    use frame_support::{assert_noop, assert_ok};
+    use pallet_reml_verifier::{Aggregators, Error, Event};
+    use sp_keyring::Sr25519Keyring::{Alice, Bob};
+
+    let storage = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+
+    sp_io::TestExternalities::new(storage).execute_with(|| {
+        System::set_block_number(1);
+        let key_hash = Some(sp_io::hashing::sha2_256(b"aggregator certificate"));
+
+        assert_noop!(
+            RemlVerifier::set_server_key_hash(RuntimeOrigin::signed(Bob.to_account_id()), key_hash),
+            Error::<Runtime>::AggregatorNotFound
+        );
+
+        assert_ok!(RemlVerifier::register_aggregator(
+            RuntimeOrigin::root(),
+            Alice.to_account_id()
+        ));
+        assert_eq!(
+            Aggregators::<Runtime>::get(Alice.to_account_id()).unwrap().server_key_hash,
+            None
+        );
+        assert_ok!(RemlVerifier::set_server_key_hash(
+            RuntimeOrigin::signed(Alice.to_account_id()),
+            key_hash
+        ));
+        assert_eq!(
+            Aggregators::<Runtime>::get(Alice.to_account_id()).unwrap().server_key_hash,
+            key_hash
+        );
+        System::assert_last_event(
+            Event::<Runtime>::ServerKeyHashSet { aggregator: Alice.to_account_id(), key_hash }
+                .into(),
+        );
+
+        assert_ok!(RemlVerifier::set_server_key_hash(
+            RuntimeOrigin::signed(Alice.to_account_id()),
+            None
+        ));
+        assert_eq!(
+            Aggregators::<Runtime>::get(Alice.to_account_id()).unwrap().server_key_hash,
+            None
+        );
+    });
+}
+
+#[test]
+fn integration_reml_invalid_proof_report_slashes_whole_stake() {
+    use frame_support::{
+        assert_noop, assert_ok,
+        traits::{fungible::Mutate, Get, ReservableCurrency},
+    };
+    use pallet_reml_verifier::{
+        groth16::VerifyingKey, AggregatorStakes, AggregatorUnbonding, Error, Event,
+        ProofSubmission, PublicValues, MIN_PROOF_SIZE, REML_PROGRAM_ID, REML_VERSION,
+        TESSERAX_CHAIN_ID,
+    };
+    use sp_keyring::Sr25519Keyring::{Alice, Bob};
+
+    // BN254 generators in the EVM pairing precompile encoding
+    let g1: [u8; 64] = hex_literal::hex!(
+        "0000000000000000000000000000000000000000000000000000000000000001"
+        "0000000000000000000000000000000000000000000000000000000000000002"
     );
-    assert_eq!(VERSION.spec_version, 100);
+    let g2: [u8; 128] = hex_literal::hex!(
+        "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2"
+        "1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed"
+        "090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b"
+        "12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa"
+    );
+    let vk = VerifyingKey {
+        selector: *b"sp1!",
+        alpha_g1: g1,
+        beta_g2: g2,
+        gamma_g2: g2,
+        delta_g2: g2,
+        ic: vec![g1; 3].try_into().unwrap(),
+    };
+
+    let storage = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+
+    sp_io::TestExternalities::new(storage).execute_with(|| {
+        System::set_block_number(1);
+        let bond = configs::RemlAggregatorBond::get();
+        let aggregator = Alice.to_account_id();
+        let reporter = Bob.to_account_id();
+        assert_ok!(Balances::mint_into(&aggregator, 2 * bond));
+        assert_ok!(RemlVerifier::bond_aggregator(
+            RuntimeOrigin::signed(aggregator.clone()),
+            bond
+        ));
+
+        // Without a verifying key only the structure of the proof is checked
+        let public_values = PublicValues {
+            version: REML_VERSION,
+            chain_id: TESSERAX_CHAIN_ID,
+            epoch: configs::RemlProofEpoch::get(),
+            batch_id: 1,
+            verified_count: 1,
+            mldsa44_count: 1,
+            falcon512_count: 0,
+            requests_root: sp_io::hashing::keccak_256(&42u64.to_le_bytes()),
+            requests_root_blake2: sp_io::hashing::blake2_256(&42u64.to_le_bytes()),
+            verified_request_ids: vec![42].try_into().unwrap(),
+            verified_request_owners: Default::default(),
+            verified_request_messages: Default::default(),
+        };
+        let mut proof = vec![0x01];
+        proof.extend_from_slice(&public_values.binding_hash());
+        proof.resize(MIN_PROOF_SIZE, 0);
+        let submission = ProofSubmission {
+            batch_id: 1,
+            proof: proof.clone().try_into().unwrap(),
+            public_values,
+            vkey_hash: configs::ExpectedVKeyHash::get(),
+            program_id: REML_PROGRAM_ID,
+            anchor_block: 0,
+            anchor_hash: System::block_hash(0).into(),
+        };
+        assert_ok!(RemlVerifier::submit_proof(
+            RuntimeOrigin::signed(aggregator.clone()),
+            submission.clone()
+        ));
+
+        // Unbonding stake stays slashable
+        assert_ok!(RemlVerifier::unbond_aggregator(RuntimeOrigin::signed(
+            aggregator.clone()
+        )));
+
+        assert_noop!(
+            RemlVerifier::report_invalid_proof(
+                RuntimeOrigin::signed(reporter.clone()),
+                submission.clone()
+            ),
+            Error::<Runtime>::NoVerifyingKey
+        );
+        assert_ok!(RemlVerifier::set_groth16_vk(
+            RuntimeOrigin::root(),
+            Some(vk)
+        ));
+
+        // The report must carry the proof the batch was verified with
+        let mut other_proof = submission.clone();
+        proof[MIN_PROOF_SIZE - 1] = 1;
+        other_proof.proof = proof.try_into().unwrap();
+        assert_noop!(
+            RemlVerifier::report_invalid_proof(
+                RuntimeOrigin::signed(reporter.clone()),
+                other_proof
+            ),
+            Error::<Runtime>::ProofMismatch
+        );
+        let mut other_version = submission.clone();
+        other_version.public_values.version += 1;
+        assert_noop!(
+            RemlVerifier::report_invalid_proof(
+                RuntimeOrigin::signed(reporter.clone()),
+                other_version
+            ),
+            Error::<Runtime>::ProofMismatch
+        );
+
+        assert_ok!(RemlVerifier::report_invalid_proof(
+            RuntimeOrigin::signed(reporter.clone()),
+            submission.clone()
+        ));
+        assert_eq!(AggregatorStakes::<Runtime>::get(&aggregator), 0);
+        assert_eq!(AggregatorUnbonding::<Runtime>::get(&aggregator), None);
+        // Only the batch's submission deposit is left reserved
+        assert_eq!(
+            Balances::reserved_balance(&aggregator),
+            configs::RemlSubmissionDeposit::get()
+        );
+        assert_eq!(Balances::free_balance(&RemlVerifier::reward_pot()), bond);
+        assert!(!RemlVerifier::is_aggregator(&aggregator));
+        System::assert_has_event(
+            Event::<Runtime>::InvalidProofReported {
+                batch_id: 1,
+                aggregator: aggregator.clone(),
+                reporter: reporter.clone(),
+            }
+            .into(),
+        );
+        System::assert_last_event(
+            Event::<Runtime>::AggregatorSlashed {
+                aggregator,
+                amount: bond,
+            }
+            .into(),
+        );
+
+        assert_noop!(
+            RemlVerifier::report_invalid_proof(RuntimeOrigin::signed(reporter), submission),
+            Error::<Runtime>::BatchAlreadyReported
+        );
+    });
+}
+
+#[test]
+fn integration_reml_expired_batches_are_pruned() {
+    use frame_support::{
+        assert_noop, assert_ok,
+        traits::{fungible::Mutate, Get, Hooks, ReservableCurrency},
+        weights::Weight,
+    };
+    use pallet_reml_verifier::{
+        Error, Event, ProofSubmission, PruneQueueBounds, PublicValues, RemlVerification,
+        MIN_PROOF_SIZE, REML_PROGRAM_ID, REML_VERSION, TESSERAX_CHAIN_ID,
+    };
+    use sp_keyring::Sr25519Keyring::{Alice, Bob};
+
+    let storage = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+
+    sp_io::TestExternalities::new(storage).execute_with(|| {
+        System::set_block_number(1);
+        let aggregator = Alice.to_account_id();
+        let pruner = Bob.to_account_id();
+        assert_ok!(RemlVerifier::register_aggregator(
+            RuntimeOrigin::root(),
+            aggregator.clone()
+        ));
+        assert_ok!(Balances::mint_into(&aggregator, 10 * TSRX));
+        assert_ok!(Balances::mint_into(&pruner, 10 * TSRX));
+
+        let submission = |batch_id: u64, request_id: u64, owner: u8| {
+            let public_values = PublicValues {
+                version: REML_VERSION,
+                chain_id: TESSERAX_CHAIN_ID,
+                epoch: configs::RemlProofEpoch::get(),
+                batch_id,
+                verified_count: 1,
+                mldsa44_count: 1,
+                falcon512_count: 0,
+                requests_root: sp_io::hashing::keccak_256(&request_id.to_le_bytes()),
+                requests_root_blake2: sp_io::hashing::blake2_256(&request_id.to_le_bytes()),
+                verified_request_ids: vec![request_id].try_into().unwrap(),
+                verified_request_owners: vec![[owner; 32]].try_into().unwrap(),
+                verified_request_messages: Default::default(),
+            };
+            let mut proof = vec![0x01];
+            proof.extend_from_slice(&public_values.binding_hash());
+            proof.resize(MIN_PROOF_SIZE, 0);
+            ProofSubmission {
+                batch_id,
+                proof: proof.try_into().unwrap(),
+                public_values,
+                vkey_hash: configs::ExpectedVKeyHash::get(),
+                program_id: REML_PROGRAM_ID,
+                anchor_block: 0,
+                anchor_hash: System::block_hash(0).into(),
+            }
+        };
+        let deposit = configs::RemlSubmissionDeposit::get();
+        let retention = configs::RemlProofRetentionPeriod::get();
+
+        // Batch 1 verifies request 41 at block 1, batch 2 request 42 at block 2
+        assert_ok!(RemlVerifier::submit_proof(
+            RuntimeOrigin::signed(aggregator.clone()),
+            submission(1, 41, 41)
+        ));
+        System::set_block_number(2);
+        assert_ok!(RemlVerifier::submit_proof(
+            RuntimeOrigin::signed(aggregator.clone()),
+            submission(2, 42, 42)
+        ));
+        assert_eq!(Balances::reserved_balance(&aggregator), 2 * deposit);
+        assert_ok!(RemlVerifier::consume_verified_request(41, &[41u8; 32], &aggregator));
+
+        assert_noop!(
+            RemlVerifier::prune_expired(
+                RuntimeOrigin::signed(pruner.clone()),
+                vec![1].try_into().unwrap()
+            ),
+            Error::<Runtime>::BatchNotExpired
+        );
+        assert_noop!(
+            RemlVerifier::prune_expired(
+                RuntimeOrigin::signed(pruner.clone()),
+                vec![9].try_into().unwrap()
+            ),
+            Error::<Runtime>::BatchNotFound
+        );
+
+        // Anyone can prune an expired batch for a share of its deposit
+        System::set_block_number(1 + retention);
+        assert_ok!(RemlVerifier::prune_expired(
+            RuntimeOrigin::signed(pruner.clone()),
+            vec![1].try_into().unwrap()
+        ));
+        let reward = configs::RemlPrunerShare::get() * deposit;
+        System::assert_last_event(
+            Event::<Runtime>::BatchPruned { batch_id: 1, pruner: Some(pruner.clone()), reward }
+                .into(),
+        );
+        assert_eq!(Balances::free_balance(&pruner), 10 * TSRX + reward);
+        assert_eq!(Balances::reserved_balance(&aggregator), deposit);
+        assert!(RemlVerifier::verified_batches(1).is_none());
+        assert!(!RemlVerifier::is_request_verified(41));
+        assert!(RemlVerifier::batch_by_root(sp_io::hashing::keccak_256(&41u64.to_le_bytes()))
+            .is_none());
+        // Its requests' owners go with it, but not their consumption
+        assert!(RemlVerifier::request_owner(41).is_none());
+        assert!(RemlVerifier::is_request_consumed(41));
+        assert_eq!(RemlVerifier::consumed_by(41), Some((aggregator.clone(), 2)));
+
+        // A pruned batch can't be verified again
+        assert_noop!(
+            RemlVerifier::submit_proof(
+                RuntimeOrigin::signed(aggregator.clone()),
+                submission(1, 41, 41)
+            ),
+            Error::<Runtime>::BatchAlreadyVerified
+        );
+
+        // Nor can a later batch make a consumed request consumable again, or
+        // take over a request that is still verified
+        frame_system::BlockHash::<Runtime>::insert(retention, sp_core::H256([1; 32]));
+        assert_ok!(RemlVerifier::submit_proof(
+            RuntimeOrigin::signed(aggregator.clone()),
+            ProofSubmission {
+                anchor_block: retention,
+                anchor_hash: [1; 32],
+                ..submission(3, 41, 3)
+            }
+        ));
+        assert!(!RemlVerifier::is_request_verified(41));
+        assert!(RemlVerifier::request_owner(41).is_none());
+        assert_noop!(
+            RemlVerifier::consume_verified_request(41, &[3u8; 32], &pruner),
+            Error::<Runtime>::RequestNotVerified
+        );
+        assert_ok!(RemlVerifier::submit_proof(
+            RuntimeOrigin::signed(aggregator.clone()),
+            ProofSubmission {
+                anchor_block: retention,
+                anchor_hash: [1; 32],
+                ..submission(4, 42, 4)
+            }
+        ));
+        assert_eq!(RemlVerifier::get_verification_info(42), Some((2, 2)));
+        assert_eq!(RemlVerifier::request_owner(42), Some([42u8; 32]));
+        assert_noop!(
+            RemlVerifier::consume_verified_request(42, &[4u8; 32], &pruner),
+            Error::<Runtime>::NotRequestOwner
+        );
+
+        // on_idle prunes in verification order, stopping at unexpired batches
+        RemlVerifier::on_idle(1 + retention, Weight::MAX);
+        assert!(RemlVerifier::is_request_verified(42));
+        System::set_block_number(2 + retention);
+        RemlVerifier::on_idle(2 + retention, Weight::MAX);
+        assert!(!RemlVerifier::is_request_verified(42));
+        assert_eq!(Balances::reserved_balance(&aggregator), 2 * deposit);
+        assert_eq!(PruneQueueBounds::<Runtime>::get(), (2, 4));
+    });
+}
+
+#[test]
+fn integration_reml_other_programs_verify_into_their_own_requests() {
+    use frame_support::{
+        assert_noop, assert_ok,
+        traits::{fungible::Mutate, Get},
+    };
+    use pallet_reml_verifier::{
+        BatchPrograms, Error, ProgramRequests, ProofSubmission, PublicValues, MIN_PROOF_SIZE,
+        REML_VERSION, TESSERAX_CHAIN_ID,
+    };
+    use sp_keyring::Sr25519Keyring::Alice;
+
+    let storage = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+
+    sp_io::TestExternalities::new(storage).execute_with(|| {
+        System::set_block_number(1);
+        let aggregator = Alice.to_account_id();
+        assert_ok!(RemlVerifier::register_aggregator(
+            RuntimeOrigin::root(),
+            aggregator.clone()
+        ));
+        assert_ok!(Balances::mint_into(&aggregator, 10 * TSRX));
+
+        // A second guest program, e.g. another signature scheme's verifier
+        let program_id = 3;
+        let vkey_hash = [5u8; 32];
+        assert_ok!(ProgramRegistry::register_program_version(
+            RuntimeOrigin::root(),
+            program_id,
+            1,
+            vkey_hash,
+            [6u8; 32],
+            1
+        ));
+
+        let submission = |vkey_hash: [u8; 32]| {
+            let public_values = PublicValues {
+                version: REML_VERSION,
+                chain_id: TESSERAX_CHAIN_ID,
+                epoch: configs::RemlProofEpoch::get(),
+                batch_id: 1,
+                verified_count: 1,
+                mldsa44_count: 1,
+                falcon512_count: 0,
+                requests_root: sp_io::hashing::keccak_256(&42u64.to_le_bytes()),
+                requests_root_blake2: sp_io::hashing::blake2_256(&42u64.to_le_bytes()),
+                verified_request_ids: vec![42].try_into().unwrap(),
+                verified_request_owners: Default::default(),
+                verified_request_messages: Default::default(),
+            };
+            let mut proof = vec![0x01];
+            proof.extend_from_slice(&public_values.binding_hash());
+            proof.resize(MIN_PROOF_SIZE, 0);
+            ProofSubmission {
+                batch_id: 1,
+                proof: proof.try_into().unwrap(),
+                public_values,
+                vkey_hash,
+                program_id,
+                anchor_block: 0,
+                anchor_hash: System::block_hash(0).into(),
+            }
+        };
+
+        // Re-ML's vkey is not the program's
+        assert_noop!(
+            RemlVerifier::submit_proof(
+                RuntimeOrigin::signed(aggregator.clone()),
+                submission(configs::ExpectedVKeyHash::get())
+            ),
+            Error::<Runtime>::InvalidVKeyHash
+        );
+
+        assert_ok!(RemlVerifier::submit_proof(
+            RuntimeOrigin::signed(aggregator.clone()),
+            submission(vkey_hash)
+        ));
+        assert_eq!(BatchPrograms::<Runtime>::get(1), Some(program_id));
+        assert_eq!(ProgramRequests::<Runtime>::get(program_id, 42), Some((1, 1)));
+        assert!(RemlVerifier::is_program_request_verified(program_id, 42));
+        // Not a Re-ML verification, so vaults don't see it
+        assert!(!RemlVerifier::is_request_verified(42));
+        assert!(!RemlVerifier::is_program_request_verified(program_id, 43));
+
+        // Pruning clears the program's requests
+        System::set_block_number(1 + configs::RemlProofRetentionPeriod::get());
+        assert_ok!(RemlVerifier::prune_expired(
+            RuntimeOrigin::signed(aggregator.clone()),
+            vec![1].try_into().unwrap()
+        ));
+        assert!(!BatchPrograms::<Runtime>::contains_key(1));
+        assert!(!RemlVerifier::is_program_request_verified(program_id, 42));
+    });
+}
+
+#[test]
+fn integration_reml_root_only_batches_are_claimed_with_inclusion_proofs() {
+    use frame_support::{
+        assert_noop, assert_ok,
+        traits::{fungible::Mutate, Get},
+    };
+    use pallet_reml_verifier::{
+        BatchRequests, Error, Event, ProofSubmission, PublicValues, RequestInclusionProof,
+        MIN_PROOF_SIZE, REML_PROGRAM_ID, REML_VERSION, TESSERAX_CHAIN_ID,
+    };
+    use sp_keyring::Sr25519Keyring::{Alice, Bob};
+
+    let storage = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+
+    sp_io::TestExternalities::new(storage).execute_with(|| {
+        System::set_block_number(1);
+        let aggregator = Alice.to_account_id();
+        let claimer = Bob.to_account_id();
+        assert_ok!(RemlVerifier::register_aggregator(
+            RuntimeOrigin::root(),
+            aggregator.clone()
+        ));
+        assert_ok!(Balances::mint_into(&aggregator, 10 * TSRX));
+        assert_ok!(RemlVerifier::set_root_only_batches(RuntimeOrigin::root(), true));
+
+        let ids: Vec<u64> = (100..105).collect();
+        let public_values = PublicValues {
+            version: REML_VERSION,
+            chain_id: TESSERAX_CHAIN_ID,
+            epoch: configs::RemlProofEpoch::get(),
+            batch_id: 1,
+            verified_count: ids.len() as u32,
+            mldsa44_count: ids.len() as u32,
+            falcon512_count: 0,
+            requests_root: tesserax_merkle::keccak_requests_root(&ids),
+            requests_root_blake2: tesserax_merkle::blake2_requests_root(&ids),
+            verified_request_ids: ids.clone().try_into().unwrap(),
+            verified_request_owners: Default::default(),
+            verified_request_messages: Default::default(),
+        };
+        let mut proof = vec![0x01];
+        proof.extend_from_slice(&public_values.binding_hash());
+        proof.resize(MIN_PROOF_SIZE, 0);
+        assert_ok!(RemlVerifier::submit_proof(
+            RuntimeOrigin::signed(aggregator.clone()),
+            ProofSubmission {
+                batch_id: 1,
+                proof: proof.try_into().unwrap(),
+                public_values,
+                vkey_hash: configs::ExpectedVKeyHash::get(),
+                program_id: REML_PROGRAM_ID,
+                anchor_block: 0,
+                anchor_hash: System::block_hash(0).into(),
+            }
+        ));
+
+        // Only the root is stored
+        assert!(RemlVerifier::verified_batches(1).is_some());
+        assert!(ids.iter().all(|id| !RemlVerifier::is_request_verified(*id)));
+        assert!(BatchRequests::<Runtime>::get(1).is_none());
+
+        let inclusion = |index: usize| RequestInclusionProof {
+            batch_id: 1,
+            leaf_index: index as u32,
+            leaf_count: ids.len() as u32,
+            siblings: tesserax_merkle::request_proof(&ids, index, sp_io::hashing::blake2_256)
+                .unwrap()
+                .try_into()
+                .unwrap(),
+        };
+
+        // A proof for another leaf doesn't verify the request
+        assert_noop!(
+            RemlVerifier::claim_request_verification(
+                RuntimeOrigin::signed(claimer.clone()),
+                102,
+                inclusion(3)
+            ),
+            Error::<Runtime>::InvalidInclusionProof
+        );
+        assert_noop!(
+            RemlVerifier::claim_request_verification(
+                RuntimeOrigin::signed(claimer.clone()),
+                102,
+                RequestInclusionProof { batch_id: 9, ..inclusion(2) }
+            ),
+            Error::<Runtime>::BatchNotFound
+        );
+
+        // Anyone can mark a request with its inclusion proof, once
+        assert_ok!(RemlVerifier::claim_request_verification(
+            RuntimeOrigin::signed(claimer.clone()),
+            102,
+            inclusion(2)
+        ));
+        System::assert_last_event(
+            Event::<Runtime>::RequestVerificationClaimed { request_id: 102, batch_id: 1 }.into(),
+        );
+        assert_eq!(RemlVerifier::get_verification_info(102), Some((1, 1)));
+        assert!(!RemlVerifier::is_request_verified(103));
+        assert_eq!(BatchRequests::<Runtime>::get(1).unwrap().into_inner(), vec![102]);
+        assert_noop!(
+            RemlVerifier::claim_request_verification(
+                RuntimeOrigin::signed(claimer.clone()),
+                102,
+                inclusion(2)
+            ),
+            Error::<Runtime>::RequestAlreadyVerified
+        );
+
+        // The promoted last leaf has a shorter path
+        assert_ok!(RemlVerifier::claim_request_verification(
+            RuntimeOrigin::signed(claimer),
+            104,
+            inclusion(4)
+        ));
+        assert!(RemlVerifier::is_request_verified(104));
+    });
+}
+
+#[test]
+fn integration_reml_aggregate_proofs_record_root_only_sub_batches() {
+    use frame_support::{
+        assert_noop, assert_ok,
+        traits::{fungible::Mutate, Get},
+    };
+    use pallet_reml_verifier::{
+        AggregateProofSubmission, AggregatePublicValues, Error, Event, RequestInclusionProof,
+        SubBatchValues, MIN_PROOF_SIZE, REML_AGGREGATION_PROGRAM_ID, REML_VERSION,
+        TESSERAX_CHAIN_ID,
+    };
+    use sp_keyring::Sr25519Keyring::{Alice, Bob};
+
+    let storage = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+
+    sp_io::TestExternalities::new(storage).execute_with(|| {
+        System::set_block_number(1);
+        let aggregator = Alice.to_account_id();
+        assert_ok!(RemlVerifier::register_aggregator(
+            RuntimeOrigin::root(),
+            aggregator.clone()
+        ));
+        assert_ok!(Balances::mint_into(&aggregator, 10 * TSRX));
+
+        let aggregation_vkey = [5u8; 32];
+        assert_ok!(ProgramRegistry::register_program_version(
+            RuntimeOrigin::root(),
+            REML_AGGREGATION_PROGRAM_ID,
+            1,
+            aggregation_vkey,
+            [6u8; 32],
+            1
+        ));
+
+        // Two batches of 256 requests, the second mixing in Falcon-512
+        let ids: Vec<Vec<u64>> = vec![(0..256).collect(), (256..512).collect()];
+        let sub_batch = |batch_id: u64, ids: &[u64], falcon512_count: u32| SubBatchValues {
+            batch_id,
+            verified_count: ids.len() as u32,
+            mldsa44_count: ids.len() as u32 - falcon512_count,
+            falcon512_count,
+            requests_root: tesserax_merkle::keccak_requests_root(ids),
+            requests_root_blake2: tesserax_merkle::blake2_requests_root(ids),
+        };
+        let public_values = AggregatePublicValues {
+            version: REML_VERSION,
+            chain_id: TESSERAX_CHAIN_ID,
+            epoch: configs::RemlProofEpoch::get(),
+            batch_vkey_hash: configs::ExpectedVKeyHash::get(),
+            verified_count: 512,
+            mldsa44_count: 502,
+            falcon512_count: 10,
+            sub_batches: vec![sub_batch(1, &ids[0], 0), sub_batch(2, &ids[1], 10)]
+                .try_into()
+                .unwrap(),
+        };
+        let submission = |public_values: AggregatePublicValues, vkey_hash: [u8; 32]| {
+            let mut proof = vec![0x01];
+            proof.extend_from_slice(&public_values.binding_hash());
+            proof.resize(MIN_PROOF_SIZE, 0);
+            AggregateProofSubmission {
+                proof: proof.try_into().unwrap(),
+                public_values,
+                vkey_hash,
+                anchor_block: 0,
+                anchor_hash: System::block_hash(0).into(),
+            }
+        };
+        let submit = |submission: AggregateProofSubmission| {
+            RemlVerifier::submit_aggregate_proof(
+                RuntimeOrigin::signed(aggregator.clone()),
+                submission,
+            )
+        };
+
+        // The aggregation guest's vkey, not Re-ML's
+        assert_noop!(
+            submit(submission(public_values.clone(), configs::ExpectedVKeyHash::get())),
+            Error::<Runtime>::InvalidVKeyHash
+        );
+        // Totals must be the sub-batches' sums
+        assert_noop!(
+            submit(submission(
+                AggregatePublicValues { verified_count: 513, ..public_values.clone() },
+                aggregation_vkey
+            )),
+            Error::<Runtime>::InvalidPublicValues
+        );
+        // A batch folded twice
+        let mut twice = public_values.clone();
+        twice.sub_batches[1].batch_id = 1;
+        assert_noop!(
+            submit(submission(twice, aggregation_vkey)),
+            Error::<Runtime>::InvalidPublicValues
+        );
+
+        assert_ok!(submit(submission(public_values.clone(), aggregation_vkey)));
+        System::assert_last_event(
+            Event::<Runtime>::AggregateProofVerified {
+                aggregator: aggregator.clone(),
+                batch_ids: vec![1, 2],
+                signature_count: 512,
+            }
+            .into(),
+        );
+        assert_eq!(RemlVerifier::verified_batches(2).unwrap().signature_count, 256);
+        assert_eq!(RemlVerifier::era_batches(&aggregator, 0), 2);
+        // Both batches are root-only; requests are claimed from the roots
+        assert!(!RemlVerifier::is_request_verified(300));
+        assert_ok!(RemlVerifier::claim_request_verification(
+            RuntimeOrigin::signed(Bob.to_account_id()),
+            300,
+            RequestInclusionProof {
+                batch_id: 2,
+                leaf_index: 44,
+                leaf_count: 256,
+                siblings: tesserax_merkle::request_proof(&ids[1], 44, sp_io::hashing::blake2_256)
+                    .unwrap()
+                    .try_into()
+                    .unwrap(),
+            }
+        ));
+        assert!(RemlVerifier::is_request_verified(300));
+
+        // The batches can't be verified again, alone or aggregated
+        assert_noop!(
+            submit(submission(public_values, aggregation_vkey)),
+            Error::<Runtime>::BatchAlreadyVerified
+        );
+    });
+}
+
+#[test]
+fn integration_reml_vkey_follows_program_registry() {
+    use frame_support::{assert_ok, traits::Get};
+    use pallet_program_registry::REML_PROGRAM_ID;
+
+    let storage = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+
+    sp_io::TestExternalities::new(storage).execute_with(|| {
+        System::set_block_number(1);
+        assert_eq!(configs::ExpectedVKeyHash::get(), configs::RemlFallbackVKeyHash::get());
+
+        assert_ok!(ProgramRegistry::register_program_version(
+            RuntimeOrigin::root(),
+            REML_PROGRAM_ID,
+            2,
+            [7u8; 32],
+            [8u8; 32],
+            10
+        ));
+        assert_eq!(configs::ExpectedVKeyHash::get(), configs::RemlFallbackVKeyHash::get());
+
+        System::set_block_number(10);
+        assert_eq!(configs::ExpectedVKeyHash::get(), [7u8; 32]);
+        assert_eq!(configs::TransitionVKeyHash::get(), None);
+
+        // A later version keeps the one it replaces for the transition period
+        assert_ok!(ProgramRegistry::register_program_version(
+            RuntimeOrigin::root(),
+            REML_PROGRAM_ID,
+            3,
+            [9u8; 32],
+            [10u8; 32],
+            20
+        ));
+        System::set_block_number(20);
+        assert_eq!(configs::ExpectedVKeyHash::get(), [9u8; 32]);
+        assert_eq!(configs::TransitionVKeyHash::get(), Some([7u8; 32]));
+        System::set_block_number(20 + configs::ProgramTransitionPeriod::get());
+        assert_eq!(configs::TransitionVKeyHash::get(), None);
+
+        assert_ok!(ProgramRegistry::remove_program_version(
+            RuntimeOrigin::root(),
+            REML_PROGRAM_ID,
+            3
+        ));
+        assert_eq!(configs::ExpectedVKeyHash::get(), [7u8; 32]);
+
+        assert_ok!(ProgramRegistry::remove_program_version(
+            RuntimeOrigin::root(),
+            REML_PROGRAM_ID,
+            2
+        ));
+        assert_eq!(configs::ExpectedVKeyHash::get(), configs::RemlFallbackVKeyHash::get());
+    });
+}
+
+#[test]
+fn integration_coprocessor_requests_need_an_aggregator_and_groth16_key() {
+    use frame_support::{assert_noop, assert_ok, traits::fungible::Mutate};
+    use pallet_zk_coprocessor::Error;
+
+    let storage = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+
+    sp_io::TestExternalities::new(storage).execute_with(|| {
+        System::set_block_number(1);
+        let requester = AccountId::from([1u8; 32]);
+        let aggregator = AccountId::from([2u8; 32]);
+        Balances::set_balance(&requester, 100 * TSRX);
+        assert_ok!(ProgramRegistry::register_program_version(
+            RuntimeOrigin::root(),
+            2,
+            1,
+            [7u8; 32],
+            [8u8; 32],
+            1
+        ));
+
+        assert_ok!(ZkCoprocessor::submit_request(
+            RuntimeOrigin::signed(requester.clone()),
+            2,
+            b"input".to_vec(),
+            10 * TSRX
+        ));
+        assert_eq!(Balances::reserved_balance(&requester), 10 * TSRX + TSRX);
+
+        let fulfill = |who: &AccountId| {
+            ZkCoprocessor::fulfill_request(
+                RuntimeOrigin::signed(who.clone()),
+                0,
+                b"output".to_vec(),
+                vec![0u8; pallet_reml_verifier::GROTH16_PROOF_SIZE].try_into().unwrap(),
+            )
+        };
+        assert_noop!(fulfill(&aggregator), Error::<Runtime>::NotProver);
+
+        // An aggregator, but no Groth16 key to check the proof with
+        assert_ok!(RemlVerifier::register_aggregator(RuntimeOrigin::root(), aggregator.clone()));
+        assert_noop!(fulfill(&aggregator), Error::<Runtime>::ProofVerificationFailed);
+    });
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// LIGHT-CLIENT INTEGRATION TESTS
+// ═══════════════════════════════════════════════════════════════════════════
+//
+// A smoldot wallet needs: vault status + nonce (QuantumVaultApi) and
+// TaggedTransactionQueue accepting a vault_transfer from a vault while the
+// pool still rejects plain balance transfers from it. The end-to-end smoldot
+// check lives in scripts/light-client.
+
+#[test]
+fn integration_reml_single_proof_implies_one_request_batch() {
+    use frame_support::{
+        assert_noop, assert_ok,
+        traits::{fungible::Mutate, Get},
+    };
+    use pallet_reml_verifier::{
+        Error, PublicValues, SignatureScheme, SingleProofSubmission, MIN_PROOF_SIZE, REML_VERSION,
+        TESSERAX_CHAIN_ID,
+    };
+    use sp_keyring::Sr25519Keyring::Alice;
+
+    let storage = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+
+    sp_io::TestExternalities::new(storage).execute_with(|| {
+        System::set_block_number(1);
+        let aggregator = Alice.to_account_id();
+        assert_ok!(RemlVerifier::register_aggregator(
+            RuntimeOrigin::root(),
+            aggregator.clone()
+        ));
+        assert_ok!(Balances::mint_into(&aggregator, 10 * TSRX));
+
+        // The public values the guest commits for a batch of just request 42
+        let public_values = PublicValues {
+            version: REML_VERSION,
+            chain_id: TESSERAX_CHAIN_ID,
+            epoch: configs::RemlProofEpoch::get(),
+            batch_id: 5,
+            verified_count: 1,
+            mldsa44_count: 1,
+            falcon512_count: 0,
+            requests_root: sp_io::hashing::keccak_256(&42u64.to_le_bytes()),
+            requests_root_blake2: sp_io::hashing::blake2_256(&42u64.to_le_bytes()),
+            verified_request_ids: vec![42].try_into().unwrap(),
+            verified_request_owners: vec![[7u8; 32]].try_into().unwrap(),
+            verified_request_messages: vec![[8u8; 32]].try_into().unwrap(),
+        };
+        let single = |request_id: u64| {
+            let mut proof = vec![0x01];
+            proof.extend_from_slice(&public_values.binding_hash());
+            proof.resize(MIN_PROOF_SIZE, 0);
+            SingleProofSubmission {
+                batch_id: 5,
+                proof: proof.try_into().unwrap(),
+                request_id,
+                owner: [7u8; 32],
+                message: [8u8; 32],
+                scheme: SignatureScheme::MlDsa44,
+                vkey_hash: configs::ExpectedVKeyHash::get(),
+                anchor_block: 0,
+                anchor_hash: System::block_hash(0).into(),
+            }
+        };
+        assert_eq!(
+            RemlVerifier::single_proof_submission(single(42)).public_values,
+            public_values
+        );
+
+        // A proof of an ML-DSA-44 signature does not verify a Falcon-512 one
+        let falcon = SingleProofSubmission { scheme: SignatureScheme::Falcon512, ..single(42) };
+        assert_eq!(
+            RemlVerifier::single_proof_submission(falcon.clone()).public_values.falcon512_count,
+            1
+        );
+        assert_noop!(
+            RemlVerifier::submit_single_proof(RuntimeOrigin::signed(aggregator.clone()), falcon),
+            Error::<Runtime>::ProofVerificationFailed
+        );
+
+        // A proof of request 42 does not verify request 43
+        assert_noop!(
+            RemlVerifier::submit_single_proof(
+                RuntimeOrigin::signed(aggregator.clone()),
+                single(43)
+            ),
+            Error::<Runtime>::ProofVerificationFailed
+        );
+
+        // Nor the same request signed by another key
+        assert_noop!(
+            RemlVerifier::submit_single_proof(
+                RuntimeOrigin::signed(aggregator.clone()),
+                SingleProofSubmission { owner: [8u8; 32], ..single(42) }
+            ),
+            Error::<Runtime>::ProofVerificationFailed
+        );
+
+        // Nor a signature over another message
+        assert_noop!(
+            RemlVerifier::submit_single_proof(
+                RuntimeOrigin::signed(aggregator.clone()),
+                SingleProofSubmission { message: [9u8; 32], ..single(42) }
+            ),
+            Error::<Runtime>::ProofVerificationFailed
+        );
+
+        assert_ok!(RemlVerifier::submit_single_proof(
+            RuntimeOrigin::signed(aggregator.clone()),
+            single(42)
+        ));
+        assert_eq!(RemlVerifier::get_verification_info(42), Some((5, 1)));
+        assert_eq!(RemlVerifier::request_owner(42), Some([7u8; 32]));
+        assert_eq!(RemlVerifier::request_message(42), Some([8u8; 32]));
+        assert_eq!(
+            RemlVerifier::verified_batches(5).unwrap().signature_count,
+            1
+        );
+        assert_eq!(
+            pallet_reml_verifier::BatchRootsBlake2::<Runtime>::get(5),
+            Some(sp_io::hashing::blake2_256(&42u64.to_le_bytes()))
+        );
+
+        assert_noop!(
+            RemlVerifier::submit_single_proof(RuntimeOrigin::signed(aggregator), single(42)),
+            Error::<Runtime>::BatchAlreadyVerified
+        );
+    });
+}
+
+#[test]
+fn integration_light_client_vault_wallet_surface() {
+    use crate::vault_blocker::CheckVaultTransfer;
+    use frame_support::{dispatch::GetDispatchInfo, pallet_prelude::TransactionSource};
+    use sp_runtime::traits::TransactionExtension;
+
+    let vault = AccountId::from([0x11; 32]);
+    let recipient = AccountId::from([0x22; 32]);
+
+    let storage = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+
+    sp_io::TestExternalities::new(storage).execute_with(|| {
+        // Not a vault yet
+        assert!(!QuantumVault::is_vault(&vault));
+        assert_eq!(QuantumVault::vault_nonce(&vault), None);
+
+        pallet_quantum_vault::Vaults::<Runtime>::insert(
+            &vault,
+            frame_support::BoundedVec::truncate_from(vec![0u8; 1312]),
+        );
+        pallet_quantum_vault::VaultNonces::<Runtime>::insert(&vault, 4u64);
+
+        assert!(QuantumVault::is_vault(&vault));
+        assert_eq!(QuantumVault::vault_nonce(&vault), Some(4));
+
+        let validate = |call: RuntimeCall| {
+            CheckVaultTransfer::new()
+                .validate(
+                    RuntimeOrigin::signed(vault.clone()),
+                    &call,
+                    &call.get_dispatch_info(),
+                    0,
+                    (),
+                    &(),
+                    TransactionSource::External,
+                )
+                .is_ok()
+        };
+
+        // Vault transfers reach the pool
+        assert!(validate(RuntimeCall::QuantumVault(
+            pallet_quantum_vault::Call::vault_transfer {
+                signature: vec![0u8; 2420],
+                to: recipient.clone(),
+                amount: TSRX,
+                request_id: None,
+            }
+        )));
+
+        // Plain balance transfers from the vault do not
+        assert!(!validate(RuntimeCall::Balances(
+            pallet_balances::Call::transfer_keep_alive { dest: recipient.into(), value: TSRX }
+        )));
+    });
+}
+
+#[test]
+fn integration_wrapped_transfers_from_vaults_are_blocked() {
+    use crate::vault_blocker::CheckVaultTransfer;
+    use frame_support::{dispatch::GetDispatchInfo, pallet_prelude::TransactionSource};
+    use sp_runtime::traits::TransactionExtension;
+
+    let vault = AccountId::from([0x11; 32]);
+    let other = AccountId::from([0x22; 32]);
+    let sudo_key = AccountId::from([0x33; 32]);
+
+    let storage = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+
+    sp_io::TestExternalities::new(storage).execute_with(|| {
+        pallet_quantum_vault::Vaults::<Runtime>::insert(
+            &vault,
+            frame_support::BoundedVec::truncate_from(vec![0u8; 1312]),
+        );
+
+        let transfer = RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
+            dest: other.clone().into(),
+            value: TSRX,
+        });
+        let sudo_as = |who: &AccountId, call: RuntimeCall| {
+            RuntimeCall::Sudo(pallet_sudo::Call::sudo_as {
+                who: who.clone().into(),
+                call: Box::new(call),
+            })
+        };
+        let sudo =
+            |call: RuntimeCall| RuntimeCall::Sudo(pallet_sudo::Call::sudo { call: Box::new(call) });
+        let validate = |signer: &AccountId, call: RuntimeCall| {
+            CheckVaultTransfer::new()
+                .validate(
+                    RuntimeOrigin::signed(signer.clone()),
+                    &call,
+                    &call.get_dispatch_info(),
+                    0,
+                    (),
+                    &(),
+                    TransactionSource::External,
+                )
+                .is_ok()
+        };
+
+        // The inner transfer runs as the vault
+        assert!(!validate(&sudo_key, sudo_as(&vault, transfer.clone())));
+        assert!(!validate(&sudo_key, sudo(sudo_as(&vault, transfer.clone()))));
+        assert!(validate(&sudo_key, sudo_as(&other, transfer.clone())));
+
+        // A vault's sudo call runs as root, not as the vault
+        assert!(validate(&vault, sudo(transfer.clone())));
+
+        // Nesting beyond the inspected depth is refused
+        let mut nested = transfer;
+        for _ in 0..6 {
+            nested = sudo(nested);
+        }
+        assert!(!validate(&other, nested));
+    });
+}
+
+#[test]
+fn integration_vaults_cannot_tip_batches() {
+    use crate::vault_blocker::CheckVaultTransfer;
+    use frame_support::{
+        assert_noop, assert_ok, dispatch::GetDispatchInfo, pallet_prelude::TransactionSource,
+        traits::fungible::Mutate,
+    };
+    use pallet_reml_verifier::Error;
+    use sp_runtime::traits::TransactionExtension;
+
+    let vault = AccountId::from([0x11; 32]);
+    let other = AccountId::from([0x22; 32]);
+
+    let storage = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+
+    sp_io::TestExternalities::new(storage).execute_with(|| {
+        pallet_quantum_vault::Vaults::<Runtime>::insert(
+            &vault,
+            frame_support::BoundedVec::truncate_from(vec![0u8; 1312]),
+        );
+        assert_ok!(Balances::mint_into(&vault, 10 * TSRX));
+        assert_ok!(Balances::mint_into(&other, 10 * TSRX));
+
+        // A tip would reach an aggregator the vault's classical key may control
+        assert_noop!(
+            RemlVerifier::tip_batch(RuntimeOrigin::signed(vault.clone()), 1, TSRX),
+            Error::<Runtime>::VaultCannotTip
+        );
+        assert_noop!(
+            RemlVerifier::tip_batch(RuntimeOrigin::signed(other.clone()), 1, TSRX),
+            Error::<Runtime>::BatchNotFound
+        );
+
+        // Rejected before the pool, like other transfers out of a vault
+        let tip = RuntimeCall::RemlVerifier(pallet_reml_verifier::Call::tip_batch {
+            batch_id: 1,
+            amount: TSRX,
+        });
+        let validate = |signer: &AccountId| {
+            CheckVaultTransfer::new()
+                .validate(
+                    RuntimeOrigin::signed(signer.clone()),
+                    &tip,
+                    &tip.get_dispatch_info(),
+                    0,
+                    (),
+                    &(),
+                    TransactionSource::External,
+                )
+                .is_ok()
+        };
+        assert!(!validate(&vault));
+        assert!(validate(&other));
+    });
+}
+
+#[test]
+fn integration_vaults_cannot_submit_compute_requests() {
+    use crate::vault_blocker::CheckVaultTransfer;
+    use frame_support::{
+        assert_noop, assert_ok, dispatch::GetDispatchInfo, pallet_prelude::TransactionSource,
+        traits::fungible::Mutate,
+    };
+    use pallet_zk_coprocessor::Error;
+    use sp_runtime::traits::TransactionExtension;
+
+    let vault = AccountId::from([0x11; 32]);
+
+    let storage = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+
+    sp_io::TestExternalities::new(storage).execute_with(|| {
+        System::set_block_number(1);
+        pallet_quantum_vault::Vaults::<Runtime>::insert(
+            &vault,
+            frame_support::BoundedVec::truncate_from(vec![0u8; 1312]),
+        );
+        Balances::set_balance(&vault, 100 * TSRX);
+        assert_ok!(ProgramRegistry::register_program_version(
+            RuntimeOrigin::root(),
+            2,
+            1,
+            [7u8; 32],
+            [8u8; 32],
+            1
+        ));
+
+        // An aggregator the vault's classical key bonded would collect the bounty
+        assert_noop!(
+            ZkCoprocessor::submit_request(
+                RuntimeOrigin::signed(vault.clone()),
+                2,
+                b"input".to_vec(),
+                10 * TSRX
+            ),
+            Error::<Runtime>::VaultRequester
+        );
+
+        let call = RuntimeCall::ZkCoprocessor(pallet_zk_coprocessor::Call::submit_request {
+            program_id: 2,
+            input: b"input".to_vec(),
+            bounty: 10 * TSRX,
+        });
+        assert!(CheckVaultTransfer::new()
+            .validate(
+                RuntimeOrigin::signed(vault),
+                &call,
+                &call.get_dispatch_info(),
+                0,
+                (),
+                &(),
+                TransactionSource::External,
+            )
+            .is_err());
+    });
+}
+
+#[test]
+fn integration_evm_value_transfers_from_vaults_are_blocked() {
+    use crate::vault_blocker::{CheckVaultTransfer, VaultGuardRunner};
+    use frame_support::{dispatch::GetDispatchInfo, pallet_prelude::TransactionSource};
+    use pallet_evm::{AddressMapping, Runner};
+    use sp_core::{H160, U256};
+    use sp_runtime::traits::TransactionExtension;
+
+    // A vault whose account is the mapping of an EVM address
+    let source = H160::repeat_byte(0x33);
+    let vault = configs::HashedAddressMapping::into_account_id(source);
+
+    let storage = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+
+    sp_io::TestExternalities::new(storage).execute_with(|| {
+        pallet_quantum_vault::Vaults::<Runtime>::insert(
+            &vault,
+            frame_support::BoundedVec::truncate_from(vec![0u8; 1312]),
+        );
+
+        let evm_call = |value: U256| {
+            RuntimeCall::EVM(pallet_evm::Call::call {
+                source,
+                target: H160::repeat_byte(0x44),
+                input: Vec::new(),
+                value,
+                gas_limit: 21_000,
+                max_fee_per_gas: U256::from(1_000_000_000u64),
+                max_priority_fee_per_gas: None,
+                nonce: None,
+                access_list: Vec::new(),
+                authorization_list: Vec::new(),
+            })
+        };
+        let validate = |call: RuntimeCall| {
+            CheckVaultTransfer::new()
+                .validate(
+                    RuntimeOrigin::signed(vault.clone()),
+                    &call,
+                    &call.get_dispatch_info(),
+                    0,
+                    (),
+                    &(),
+                    TransactionSource::External,
+                )
+                .is_ok()
+        };
+
+        // Value-bearing EVM calls are refused at pool admission...
+        assert!(!validate(evm_call(U256::from(TSRX))));
+        assert!(validate(evm_call(U256::zero())));
+
+        // ...and by the runner, which Ethereum transactions go through too
+        let error = VaultGuardRunner::call(
+            source,
+            H160::repeat_byte(0x44),
+            Vec::new(),
+            U256::from(TSRX),
+            21_000,
+            None,
+            None,
+            None,
+            Vec::new(),
+            Vec::new(),
+            true,
+            true,
+            None,
+            None,
+            <Runtime as pallet_evm::Config>::config(),
+        )
+        .unwrap_err();
+        assert_eq!(error.error, pallet_evm::Error::<Runtime>::BalanceLow);
+    });
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// POOL ADMISSION INTEGRATION TESTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn integration_paused_pallets_reject_calls_at_pool_admission() {
+    use crate::vault_blocker::CheckVaultTransfer;
+    use frame_support::{
+        assert_ok, dispatch::GetDispatchInfo, pallet_prelude::TransactionSource,
+    };
+    use pallet_reml_verifier::{
+        ProofSubmission, PublicValues, REML_PROGRAM_ID, REML_VERSION, TESSERAX_CHAIN_ID,
+    };
+    use sp_runtime::{
+        traits::TransactionExtension,
+        transaction_validity::{InvalidTransaction, TransactionValidityError},
+    };
+
+    let sender = AccountId::from([0x11; 32]);
+    let recipient = AccountId::from([0x22; 32]);
+
+    let storage = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+
+    sp_io::TestExternalities::new(storage).execute_with(|| {
+        let validate = |call: RuntimeCall| -> Option<TransactionValidityError> {
+            CheckVaultTransfer::new()
+                .validate(
+                    RuntimeOrigin::signed(sender.clone()),
+                    &call,
+                    &call.get_dispatch_info(),
+                    0,
+                    (),
+                    &(),
+                    TransactionSource::External,
+                )
+                .err()
+        };
+
+        let vault_transfer =
+            RuntimeCall::QuantumVault(pallet_quantum_vault::Call::vault_transfer {
+                signature: vec![0u8; 2420],
+                to: recipient.clone(),
+                amount: TSRX,
+                request_id: None,
+            });
+        let submit_proof = RuntimeCall::RemlVerifier(pallet_reml_verifier::Call::submit_proof {
+            submission: ProofSubmission {
+                batch_id: 1,
+                proof: vec![0u8; 32].try_into().unwrap(),
+                public_values: PublicValues {
+                    version: REML_VERSION,
+                    chain_id: TESSERAX_CHAIN_ID,
+                    epoch: 0,
+                    batch_id: 1,
+                    verified_count: 0,
+                    mldsa44_count: 0,
+                    falcon512_count: 0,
+                    requests_root: [0u8; 32],
+                    requests_root_blake2: [0u8; 32],
+                    verified_request_ids: Default::default(),
+                    verified_request_owners: Default::default(),
+                    verified_request_messages: Default::default(),
+                },
+                vkey_hash: [0u8; 32],
+                program_id: REML_PROGRAM_ID,
+                anchor_block: 0,
+                anchor_hash: System::block_hash(0).into(),
+            },
+        });
+        let balance_transfer = RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
+            dest: recipient.clone().into(),
+            value: TSRX,
+        });
+
+        assert_eq!(validate(vault_transfer.clone()), None);
+        assert_eq!(validate(submit_proof.clone()), None);
+
+        assert_ok!(QuantumVault::set_paused(RuntimeOrigin::root(), true));
+        assert_eq!(
+            validate(vault_transfer.clone()),
+            Some(InvalidTransaction::Custom(101).into())
+        );
+        assert_eq!(validate(submit_proof.clone()), None);
+        // Unrelated calls are unaffected
+        assert_eq!(validate(balance_transfer), None);
+
+        assert_ok!(RemlVerifier::set_paused(RuntimeOrigin::root(), true));
+        assert_eq!(
+            validate(submit_proof.clone()),
+            Some(InvalidTransaction::Custom(102).into())
+        );
+
+        assert_ok!(QuantumVault::set_paused(RuntimeOrigin::root(), false));
+        assert_ok!(RemlVerifier::set_paused(RuntimeOrigin::root(), false));
+        assert_eq!(validate(vault_transfer), None);
+        assert_eq!(validate(submit_proof), None);
+    });
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// CHAIN HEALTH INTEGRATION TESTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn integration_chain_health_reports_latest_activity() {
+    use pallet_reml_verifier::{AggregatorInfo, BatchInfo, LastVerifiedBatch, VerifiedBatches};
+
+    let storage = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+
+    sp_io::TestExternalities::new(storage).execute_with(|| {
+        let empty = crate::chain_health::chain_health();
+        assert!(empty.last_reward.is_none());
+        assert!(empty.last_batch.is_none());
+        assert_eq!(empty.vault_count, 0);
+
+        let aggregator = AccountId::from([1u8; 32]);
+        pallet_reml_verifier::Aggregators::<Runtime>::insert(
+            &aggregator,
+            AggregatorInfo {
+                registered_at: 1,
+                proofs_submitted: 1,
+                active: true,
+                reputation: Default::default(),
+                server_key_hash: None,
+            },
+        );
+        VerifiedBatches::<Runtime>::insert(
+            5,
+            BatchInfo {
+                aggregator,
+                verified_at: 42,
+                signature_count: 9,
+                requests_root: [0u8; 32],
+                proof_commitment: [0u8; 32],
+            },
+        );
+        LastVerifiedBatch::<Runtime>::put(5);
+        pallet_emission::LastReward::<Runtime>::put((42, TSRX));
+        pallet_quantum_vault::TotalVaults::<Runtime>::put(3);
+
+        let health = crate::chain_health::chain_health();
+        assert_eq!(health.last_reward, Some((42, TSRX)));
+        let (batch_id, batch) = health.last_batch.unwrap();
+        assert_eq!((batch_id, batch.verified_at), (5, 42));
+        assert_eq!(health.active_aggregators, 1);
+        assert_eq!(health.vault_count, 3);
+        assert!(!health.vaults_paused);
+    });
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// QUANTUM VAULT INTEGRATION TESTS
+// ═══════════════════════════════════════════════════════════════════════════
+//
+// Signed extrinsics go through the runtime's full `TxExtension` pipeline, as
+// in block import, so a vault blocker missing from it fails here.
+
+#[test]
+fn integration_vault_lifecycle_through_signed_extrinsics() {
+    use codec::Encode;
+    use frame_support::{assert_ok, dispatch::GetDispatchInfo, traits::fungible::Mutate};
+    use sp_keyring::Sr25519Keyring::{Alice, Bob};
+    use sp_runtime::{
+        traits::{Applyable, Checkable},
+        transaction_validity::InvalidTransaction,
+    };
+
+    let vault = Alice.to_account_id();
+    let recipient = Bob.to_account_id();
+    let keypair = pqc_dilithium::Keypair::generate();
+
+    // Sign `call` as Alice and apply it like block import does
+    let apply = |call: RuntimeCall, nonce: Nonce| {
+        let tx_ext: TxExtension = (
+            frame_system::CheckNonZeroSender::<Runtime>::new(),
+            frame_system::CheckSpecVersion::<Runtime>::new(),
+            frame_system::CheckTxVersion::<Runtime>::new(),
+            frame_system::CheckGenesis::<Runtime>::new(),
+            frame_system::CheckEra::<Runtime>::from(sp_runtime::generic::Era::Immortal),
+            frame_system::CheckNonce::<Runtime>::from(nonce),
+            frame_system::CheckWeight::<Runtime>::new(),
+            pallet_transaction_payment::ChargeTransactionPayment::<Runtime>::from(0),
+            frame_metadata_hash_extension::CheckMetadataHash::<Runtime>::new(false),
+            frame_system::WeightReclaim::<Runtime>::new(),
+            vault_blocker::CheckVaultTransfer::new(),
+        );
+        let payload = SignedPayload::new(call.clone(), tx_ext.clone()).unwrap();
+        let signature = payload.using_encoded(|payload| Alice.sign(payload));
+        let xt = UncheckedExtrinsic::new_signed(
+            call,
+            vault.clone().into(),
+            Signature::Sr25519(signature),
+            tx_ext,
+        );
+
+        let info = xt.get_dispatch_info();
+        let len = xt.encoded_size();
+        let checked = xt
+            .check(&frame_system::ChainContext::<Runtime>::default())
+            .unwrap();
+        checked.apply::<Runtime>(&info, len)
+    };
+    let vault_transfer = |amount: Balance, nonce: u64| {
+        let message = tesserax_vault_payload::transfer(
+            &vault.encode(),
+            &recipient.encode(),
+            &amount.encode(),
+            nonce,
+        );
+        RuntimeCall::QuantumVault(pallet_quantum_vault::Call::vault_transfer {
+            signature: keypair.sign(&message).to_vec(),
+            to: recipient.clone(),
+            amount,
+            request_id: None,
+        })
+    };
+
+    let storage = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+
+    sp_io::TestExternalities::new(storage).execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(Balances::mint_into(&vault, 10_000 * TSRX));
+
+        assert_ok!(apply(
+            RuntimeCall::QuantumVault(pallet_quantum_vault::Call::create_vault {
+                public_key: keypair.public.to_vec(),
+            }),
+            0
+        )
+        .unwrap());
+        assert!(QuantumVault::is_vault(&vault));
+
+        // The vault's account key can no longer move funds
+        assert_eq!(
+            apply(
+                RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
+                    dest: recipient.clone().into(),
+                    value: TSRX,
+                }),
+                1
+            ),
+            Err(InvalidTransaction::Custom(100).into())
+        );
+        assert_eq!(Balances::free_balance(&recipient), 0);
+        assert_eq!(System::account_nonce(&vault), 1);
+
+        // A vault transfer signed with the Dilithium key does
+        assert_ok!(apply(vault_transfer(TSRX, 0), 1).unwrap());
+        assert_eq!(Balances::free_balance(&recipient), TSRX);
+        assert_eq!(QuantumVault::vault_nonce(&vault), Some(1));
+
+        // Its signature is bound to the vault nonce
+        let replay = apply(vault_transfer(TSRX, 0), 2).unwrap();
+        assert_eq!(
+            replay.map_err(|e| e.error),
+            Err(pallet_quantum_vault::Error::<Runtime>::SignatureVerificationFailed.into())
+        );
+        assert_eq!(Balances::free_balance(&recipient), TSRX);
+    });
+}
+
+#[test]
+fn integration_council_supermajority_administers_reml() {
+    use codec::Encode;
+    use frame_support::{assert_noop, assert_ok, dispatch::GetDispatchInfo};
+    use sp_runtime::traits::Hash;
+
+    let members: Vec<AccountId> = (1..=3u8).map(|i| AccountId::from([i; 32])).collect();
+    let aggregator = AccountId::from([9u8; 32]);
+
+    let mut storage = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+    pallet_membership::GenesisConfig::<Runtime, pallet_membership::Instance1> {
+        members: members.clone().try_into().unwrap(),
+        ..Default::default()
+    }
+    .assimilate_storage(&mut storage)
+    .unwrap();
+
+    sp_io::TestExternalities::new(storage).execute_with(|| {
+        System::set_block_number(1);
+        assert_eq!(pallet_collective::Members::<Runtime, configs::CouncilInstance>::get(), members);
+
+        let call = RuntimeCall::RemlVerifier(pallet_reml_verifier::Call::register_aggregator {
+            aggregator: aggregator.clone(),
+        });
+        let length = call.encoded_size() as u32;
+        let is_registered = || RemlVerifier::aggregators(&aggregator).is_some();
+
+        // A single member is not the admin origin, directly or as a motion
+        assert_noop!(
+            RemlVerifier::register_aggregator(
+                RuntimeOrigin::signed(members[0].clone()),
+                aggregator.clone()
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(Council::propose(
+            RuntimeOrigin::signed(members[0].clone()),
+            1,
+            Box::new(call.clone()),
+            length
+        ));
+        assert!(!is_registered());
+
+        // Two of three members are
+        let hash = <Runtime as frame_system::Config>::Hashing::hash_of(&call);
+        assert_ok!(Council::propose(
+            RuntimeOrigin::signed(members[0].clone()),
+            2,
+            Box::new(call.clone()),
+            length
+        ));
+        assert_ok!(Council::vote(RuntimeOrigin::signed(members[1].clone()), hash, 0, true));
+        assert_ok!(Council::close(
+            RuntimeOrigin::signed(members[2].clone()),
+            hash,
+            0,
+            call.get_dispatch_info().call_weight,
+            length
+        ));
+        assert!(is_registered());
+    });
 }
@@ -3,10 +3,14 @@
 #[cfg(feature = "std")]
 include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
+pub mod account_views;
 pub mod apis;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarks;
+pub mod chain_health;
 pub mod configs;
+pub mod evm_attestations;
+pub mod naming;
 pub mod precompiles;
 pub mod vault_blocker;
 
@@ -165,17 +169,18 @@ impl_opaque_keys! {
 #[sp_version::runtime_version]
 pub const VERSION: RuntimeVersion = RuntimeVersion {
     spec_name: alloc::borrow::Cow::Borrowed("tesserax-runtime"),
-    impl_name: alloc::borrow::Cow::Borrowed("tesserax-runtime"),
+    // Literals must match `naming::SPEC_NAME` and `naming::IMPL_NAME`
+    impl_name: alloc::borrow::Cow::Borrowed("sanctuary-runtime"),
     authoring_version: 1,
     // The version of the runtime specification. A full node will not attempt to use its native
     //   runtime in substitute for the on-chain Wasm runtime unless all of `spec_name`,
     //   `spec_version`, and `authoring_version` are the same between Wasm and native.
     // This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
     //   the compatible custom types.
-    spec_version: 100,
+    spec_version: 101,
     impl_version: 1,
     apis: apis::RUNTIME_API_VERSIONS,
-    transaction_version: 1,
+    transaction_version: 2,
     system_version: 1,
 };
 
@@ -217,11 +222,14 @@ pub const UNIT: Balance = TSRX;
 pub const MILLI_UNIT: Balance = MILLI_TSRX;
 pub const MICRO_UNIT: Balance = MICRO_TSRX;
 
-/// Existential deposit - minimum balance to keep account alive
-/// Set to 1 TSRX to prevent dust accounts and encourage meaningful participation
+/// Default existential deposit - minimum balance to keep account alive
+/// Set to 1 TSRX to prevent dust accounts and encourage meaningful participation.
+/// Chain specs can override it via `chainParameters.existentialDeposit`.
 pub const EXISTENTIAL_DEPOSIT: Balance = TSRX;
 
 // Re-export tesserax constants for external use
+/// Sanctuary name of [`tesserax_constants`]
+pub use tesserax_constants as sanctuary_constants;
 pub use tesserax_constants::*;
 
 /// The version information used to identify this runtime when compiled natively.
@@ -324,7 +332,11 @@ pub type SignedPayload = generic::SignedPayload<RuntimeCall, TxExtension>;
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
 #[allow(unused_parens)]
-type Migrations = ();
+type Migrations = (
+    pallet_reml_verifier::migrations::v1::MigrateV0ToV1<Runtime>,
+    pallet_reml_verifier::migrations::v2::MigrateV1ToV2<Runtime>,
+    naming::MigrateLegacyTreasury,
+);
 
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
@@ -424,4 +436,56 @@ mod runtime {
 
     #[runtime::pallet_index(16)]
     pub type RemlVerifier = pallet_reml_verifier;
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // CHAIN PARAMETERS (Chain-spec configurable ED and fees)
+    // ═══════════════════════════════════════════════════════════════════════
+
+    #[runtime::pallet_index(17)]
+    pub type ChainParameters = pallet_chain_parameters;
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // WATCHTOWERS (Independent monitors of Re-ML batches)
+    // ═══════════════════════════════════════════════════════════════════════
+
+    #[runtime::pallet_index(18)]
+    pub type Watchtower = pallet_watchtower;
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // ANNOUNCEMENTS (Messages signed with vault keys)
+    // ═══════════════════════════════════════════════════════════════════════
+
+    #[runtime::pallet_index(19)]
+    pub type Announcements = pallet_announcements;
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // RE-ML INCENTIVES (Vault premium pool paid to aggregators)
+    // ═══════════════════════════════════════════════════════════════════════
+
+    #[runtime::pallet_index(20)]
+    pub type RemlIncentives = pallet_reml_incentives;
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // PROGRAM REGISTRY (Trusted zkVM guest programs)
+    // ═══════════════════════════════════════════════════════════════════════
+
+    #[runtime::pallet_index(21)]
+    pub type ProgramRegistry = pallet_program_registry;
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // ZK-COPROCESSOR (Proven computation requests on registered programs)
+    // ═══════════════════════════════════════════════════════════════════════
+
+    #[runtime::pallet_index(22)]
+    pub type ZkCoprocessor = pallet_zk_coprocessor;
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // COUNCIL (Re-ML administration without a sudo key)
+    // ═══════════════════════════════════════════════════════════════════════
+
+    #[runtime::pallet_index(23)]
+    pub type Council = pallet_collective<pallet_collective::Instance1>;
+
+    #[runtime::pallet_index(24)]
+    pub type CouncilMembership = pallet_membership<pallet_membership::Instance1>;
 }
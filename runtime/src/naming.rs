@@ -0,0 +1,103 @@
+//! # Naming
+//!
+//! One place for the names the chain exposes while the project moves from
+//! Tesserax to Sanctuary.
+//!
+//! Identifiers nodes have already agreed on keep their Tesserax value:
+//! `frame_system` rejects a code upgrade whose `spec_name` differs, and a new
+//! protocol id or chain id splits the network into old and new nodes. Names
+//! only shown to people move to Sanctuary. The vault treasury is derived from
+//! a seed carrying the name, so [`MigrateLegacyTreasury`] moves its funds to
+//! the Sanctuary-derived account on upgrade.
+
+use crate::{AccountId, Balance, Balances};
+use frame_support::{
+    traits::{Currency, ExistenceRequirement, Get, OnRuntimeUpgrade},
+    weights::Weight,
+};
+
+/// `spec_name` of the runtime; never changes on a live chain
+pub const SPEC_NAME: &str = "tesserax-runtime";
+
+/// `impl_name` of the runtime
+pub const IMPL_NAME: &str = "sanctuary-runtime";
+
+/// libp2p protocol id of the built-in chain specs; never changes on a live chain
+pub const PROTOCOL_ID: &str = "tesserax";
+
+/// Id of the development chain spec; names its database directory
+pub const DEV_CHAIN_ID: &str = "tesserax_dev";
+
+/// Id of the local testnet chain spec; names its database directory
+pub const LOCAL_CHAIN_ID: &str = "tesserax_local";
+
+/// SS58 prefix advertised to wallets (generic Substrate)
+pub const SS58_FORMAT: u16 = 42;
+
+/// Seed of the vault treasury account
+pub const TREASURY_SEED: &[u8] = b"sanctuary/vault_treasury";
+
+/// Seed of the vault treasury account before the rename
+pub const LEGACY_TREASURY_SEED: &[u8] = b"tesserax/vault_treasury";
+
+/// Account whose id is `seed` zero-padded to 32 bytes
+pub fn seed_account(seed: &[u8]) -> AccountId {
+    let mut bytes = [0u8; 32];
+    let len = seed.len().min(32);
+    bytes[..len].copy_from_slice(&seed[..len]);
+    AccountId::from(bytes)
+}
+
+/// Moves the balance of the legacy treasury account to the current one
+///
+/// Idempotent: once the legacy account is empty it only costs one read, so it
+/// can stay in the migrations until every network has upgraded. Under
+/// `try-runtime` it checks the legacy account ends up empty and the treasury
+/// gains exactly its balance.
+pub struct MigrateLegacyTreasury;
+
+impl OnRuntimeUpgrade for MigrateLegacyTreasury {
+    fn on_runtime_upgrade() -> Weight {
+        let db = <crate::Runtime as frame_system::Config>::DbWeight::get();
+        let legacy = seed_account(LEGACY_TREASURY_SEED);
+        let balance: Balance = Balances::free_balance(&legacy);
+        if balance == 0 {
+            return db.reads(1);
+        }
+
+        let treasury = seed_account(TREASURY_SEED);
+        if let Err(e) = <Balances as Currency<AccountId>>::transfer(
+            &legacy,
+            &treasury,
+            balance,
+            ExistenceRequirement::AllowDeath,
+        ) {
+            log::error!(target: "runtime::naming", "Legacy treasury not migrated: {e:?}");
+        }
+        db.reads_writes(3, 3)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<alloc::vec::Vec<u8>, sp_runtime::TryRuntimeError> {
+        use codec::Encode;
+        let legacy: Balance = Balances::free_balance(seed_account(LEGACY_TREASURY_SEED));
+        let treasury: Balance = Balances::free_balance(seed_account(TREASURY_SEED));
+        Ok((legacy, treasury).encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: alloc::vec::Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+        use codec::Decode;
+        let (legacy, treasury) = <(Balance, Balance)>::decode(&mut &state[..])
+            .map_err(|_| "Legacy treasury pre-upgrade state undecodable")?;
+        frame_support::ensure!(
+            Balances::free_balance(seed_account(LEGACY_TREASURY_SEED)) == 0,
+            "Legacy treasury not emptied"
+        );
+        frame_support::ensure!(
+            Balances::free_balance(seed_account(TREASURY_SEED)) == treasury.saturating_add(legacy),
+            "Treasury did not receive the legacy balance"
+        );
+        Ok(())
+    }
+}
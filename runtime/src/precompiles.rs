@@ -9,34 +9,46 @@
 //! | 0x20 | verify_stark_commitment | 50,000 base + 100/byte |
 //! | 0x21 | is_request_verified | 10,000 |
 //! | 0x22 | get_batch_info | 15,000 |
+//! | 0x23 | verify_dilithium | 3,000 base + 3/byte + 40,000 |
+//! | 0x24 | batch_verify_ml_dsa | 5,000 base + 3/byte + 40,000/signature |
+//! | 0x25 | submit_compute_request | `submit_request` weight |
+//! | 0x26 | get_compute_result | 15,000 base + 3/output byte |
+//! | 0x27 | is_vault | 5,000 |
+//! | 0x28 | get_vault_nonce | 5,000 |
+//! | 0x29 | verify_sp1_proof | `verify_groth16_proof` weight + 3/byte |
 //!
 //! ## Usage from Solidity
 //!
+//! Arguments and results are standard ABI words, so `contracts/ReMLVerifier.sol`
+//! calls each precompile with `abi.encode` of its arguments:
+//!
 //! ```solidity
-//! interface IReMLVerifier {
-//!     function isRequestVerified(uint64 requestId) external view returns (bool);
-//!     function getBatchInfo(uint64 batchId) external view returns (bytes);
-//! }
+//! import {ReMLVerifierLib} from "./ReMLVerifier.sol";
 //!
 //! contract QuantumSafe {
-//!     address constant REML_VERIFIER = address(0x21);
-//!     
 //!     function requireQuantumProof(uint64 requestId) internal view {
-//!         (bool success, bytes memory data) = REML_VERIFIER.staticcall(
-//!             abi.encodePacked(requestId)
-//!         );
-//!         require(success && abi.decode(data, (bool)), "Not quantum verified");
+//!         require(ReMLVerifierLib.isRequestVerified(requestId), "Not quantum verified");
 //!     }
 //! }
 //! ```
+//!
+//! 0x21 and 0x22 also accept a call through the `IReMLVerifier` interface
+//! (selector first) and, for contracts deployed before the ABI switch, the
+//! legacy 8-byte little-endian ID (see [`u64_id_arg`]).
 
 use alloc::vec;
 use alloc::vec::Vec;
 use core::marker::PhantomData;
 use fp_evm::{
-    ExitError, ExitSucceed, Precompile, PrecompileFailure, PrecompileHandle, PrecompileOutput,
-    PrecompileResult,
+    ExitError, ExitRevert, ExitSucceed, Precompile, PrecompileFailure, PrecompileHandle,
+    PrecompileOutput, PrecompileResult,
 };
+use pallet_evm::{AddressMapping, GasWeightMapping};
+use pallet_quantum_vault::{DILITHIUM_PUBLIC_KEY_SIZE, DILITHIUM_SIGNATURE_SIZE};
+use pallet_reml_verifier::WeightInfo as _;
+use pallet_zk_coprocessor::WeightInfo as _;
+use sp_core::{hashing::keccak_256, H160};
+use sp_runtime::SaturatedConversion;
 
 // ═══════════════════════════════════════════════════════════════════════════
 // CONSTANTS
@@ -54,6 +66,39 @@ const IS_REQUEST_VERIFIED_GAS: u64 = 10_000;
 /// Gas cost for getting batch info
 const GET_BATCH_INFO_GAS: u64 = 15_000;
 
+/// Base gas cost for single Dilithium2 verification
+const VERIFY_DILITHIUM_BASE_GAS: u64 = 3_000;
+
+/// Gas cost per byte of Dilithium2 verification input (ABI decoding, message copy)
+const VERIFY_DILITHIUM_PER_BYTE_GAS: u64 = 3;
+
+/// Base gas cost for batched ML-DSA verification
+const BATCH_VERIFY_BASE_GAS: u64 = 5_000;
+
+/// Gas cost per byte of batched ML-DSA input (key hashing, message copies)
+const BATCH_VERIFY_PER_BYTE_GAS: u64 = 3;
+
+/// Gas cost per ML-DSA signature verified
+const ML_DSA_VERIFY_GAS: u64 = 40_000;
+
+/// Maximum keys, signatures and items in one batched ML-DSA call
+pub const MAX_BATCH_SIGNATURES: usize = 32;
+
+/// Base gas cost for reading a ZK-coprocessor result
+const GET_COMPUTE_RESULT_GAS: u64 = 15_000;
+
+/// Gas cost per byte of ZK-coprocessor output returned
+const GET_COMPUTE_RESULT_PER_BYTE_GAS: u64 = 3;
+
+/// Gas cost for checking whether an address is a quantum vault
+const IS_VAULT_GAS: u64 = 5_000;
+
+/// Gas cost for reading a vault's nonce
+const GET_VAULT_NONCE_GAS: u64 = 5_000;
+
+/// Gas cost per byte of SP1 proof input (ABI decoding, public values hashing)
+const VERIFY_SP1_PROOF_PER_BYTE_GAS: u64 = 3;
+
 // ═══════════════════════════════════════════════════════════════════════════
 // PRECOMPILE: Verify STARK Commitment (0x20)
 // ═══════════════════════════════════════════════════════════════════════════
@@ -164,10 +209,12 @@ fn verify_commitment_structure(
 /// Checks if a specific request ID has been verified via Re-ML.
 ///
 /// ## Input Format
-/// - bytes[0..8]: Request ID (little-endian u64)
+/// - `abi.encode(uint64 requestId)`, or a call to
+///   `IReMLVerifier.isRequestVerified(uint64)`
+/// - Legacy: bytes[0..8] request ID (little-endian u64), under 32 bytes
 ///
 /// ## Output
-/// - bytes[0..32]: 0x01 (verified) or 0x00 (not verified)
+/// - ABI-encoded `bool`: whether the request is verified
 ///
 /// Note: This precompile queries the pallet-reml-verifier storage.
 pub struct IsRequestVerified<Runtime>(PhantomData<Runtime>);
@@ -182,22 +229,16 @@ where
 
         handle.record_cost(IS_REQUEST_VERIFIED_GAS)?;
 
-        // Validate input length (8 bytes for u64)
-        if input.len() < 8 {
+        let Some((request_id, _)) = u64_id_arg(&input, IS_REQUEST_VERIFIED_SIGNATURE) else {
             return Err(PrecompileFailure::Error {
                 exit_status: ExitError::Other("Invalid request ID".into()),
             });
-        }
-
-        // Parse request ID (little-endian)
-        let mut bytes = [0u8; 8];
-        bytes.copy_from_slice(&input[0..8]);
-        let request_id = u64::from_le_bytes(bytes);
+        };
 
         // Query pallet storage
         let is_verified = pallet_reml_verifier::Pallet::<Runtime>::is_request_verified(request_id);
 
-        // Return result (32 bytes, right-padded for EVM compatibility)
+        // ABI-encoded bool
         let mut output = [0u8; 32];
         if is_verified {
             output[31] = 1;
@@ -217,13 +258,15 @@ where
 /// Gets information about a verified batch.
 ///
 /// ## Input Format
-/// - bytes[0..8]: Batch ID (little-endian u64)
+/// - `abi.encode(uint64 batchId)`, or a call to `IReMLVerifier.getBatchInfo(uint64)`
+/// - Legacy: bytes[0..8] batch ID (little-endian u64), under 32 bytes
 ///
 /// ## Output
-/// - bytes[0..32]: Requests root hash
-/// - bytes[32..36]: Signature count (big-endian u32)
-/// - bytes[36..44]: Block number verified (big-endian u64)
-/// - bytes[44..]: Padding to 64 bytes
+/// - `abi.encode(bytes32 requestsRoot, uint32 signatureCount, uint64 verifiedAtBlock)`;
+///   all zeros if the batch is unknown. The root is the keccak256 root EVM
+///   contracts check; the blake2 root of the batch is in `BatchRootsBlake2`
+/// - Legacy input gets the legacy 64-byte layout: root, then the signature
+///   count (big-endian u32) and block (big-endian u64), zero-padded
 pub struct GetBatchInfo<Runtime>(PhantomData<Runtime>);
 
 impl<Runtime> Precompile for GetBatchInfo<Runtime>
@@ -236,61 +279,621 @@ where
 
         handle.record_cost(GET_BATCH_INFO_GAS)?;
 
-        // Validate input length
-        if input.len() < 8 {
+        let Some((batch_id, encoding)) = u64_id_arg(&input, GET_BATCH_INFO_SIGNATURE) else {
             return Err(PrecompileFailure::Error {
                 exit_status: ExitError::Other("Invalid batch ID".into()),
             });
+        };
+
+        // Unknown batches are all zeros
+        let (requests_root, signature_count, verified_at) =
+            match pallet_reml_verifier::VerifiedBatches::<Runtime>::get(batch_id) {
+                Some(info) => {
+                    (info.requests_root, info.signature_count, info.verified_at.saturated_into())
+                }
+                None => ([0u8; 32], 0, 0),
+            };
+
+        Ok(PrecompileOutput {
+            exit_status: ExitSucceed::Returned,
+            output: encode_batch_info(requests_root, signature_count, verified_at, encoding),
+        })
+    }
+}
+
+/// Function signatures of the `IReMLVerifier` calls 0x21 and 0x22 accept
+const IS_REQUEST_VERIFIED_SIGNATURE: &[u8] = b"isRequestVerified(uint64)";
+const GET_BATCH_INFO_SIGNATURE: &[u8] = b"getBatchInfo(uint64)";
+
+/// How the ID argument of 0x21 or 0x22 was encoded
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum IdEncoding {
+    /// A 32-byte big-endian ABI word
+    Abi,
+    /// 8 little-endian bytes, from before the precompiles took ABI input
+    LegacyLe,
+}
+
+/// The `uint64` ID argument of 0x21 or 0x22 and its encoding
+///
+/// Input of 32 bytes or more is `abi.encode(uint64)`, optionally after the
+/// 4-byte selector of `signature` when called through `IReMLVerifier`.
+/// Shorter input is the legacy encoding: a little-endian u64 in the first 8
+/// bytes, as contracts built with `abi.encodePacked` and a byte swap sent it.
+fn u64_id_arg(input: &[u8], signature: &[u8]) -> Option<(u64, IdEncoding)> {
+    if input.len() < 32 {
+        let bytes = input.get(..8)?.try_into().ok()?;
+        return Some((u64::from_le_bytes(bytes), IdEncoding::LegacyLe));
+    }
+
+    let args = match input.strip_prefix(&keccak_256(signature)[..4]) {
+        Some(args) if args.len() % 32 == 0 => args,
+        _ => input,
+    };
+    let id = u64::try_from(abi_uint_arg(args, 0)?).ok()?;
+    Some((id, IdEncoding::Abi))
+}
+
+/// Output of 0x22 in the encoding its input used
+fn encode_batch_info(
+    requests_root: [u8; 32],
+    signature_count: u32,
+    verified_at: u64,
+    encoding: IdEncoding,
+) -> Vec<u8> {
+    match encoding {
+        IdEncoding::Abi => {
+            let mut output = vec![0u8; 96];
+            output[..32].copy_from_slice(&requests_root);
+            output[60..64].copy_from_slice(&signature_count.to_be_bytes());
+            output[88..96].copy_from_slice(&verified_at.to_be_bytes());
+            output
+        }
+        IdEncoding::LegacyLe => {
+            let mut output = vec![0u8; 64];
+            output[..32].copy_from_slice(&requests_root);
+            output[32..36].copy_from_slice(&signature_count.to_be_bytes());
+            output[36..44].copy_from_slice(&verified_at.to_be_bytes());
+            output
+        }
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// PRECOMPILE: Verify Dilithium (0x23)
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Verifies one Dilithium2 (ML-DSA-44) signature.
+///
+/// Same verification as vault signatures, through the Dilithium host
+/// functions, so contracts check post-quantum signatures without a pallet
+/// extrinsic.
+///
+/// ## Input Format
+/// - `abi.encode(bytes message, bytes publicKey, bytes signature)`
+///
+/// ## Output
+/// - ABI-encoded `bool`: whether `signature` is `publicKey`'s signature of
+///   `message`. Keys or signatures of the wrong size are `false`; input that
+///   is not a valid encoding reverts.
+pub struct VerifyDilithium<Runtime>(PhantomData<Runtime>);
+
+impl<Runtime> Precompile for VerifyDilithium<Runtime>
+where
+    Runtime: pallet_quantum_vault::Config + pallet_evm::Config,
+{
+    fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+        let input: Vec<u8> = handle.input().to_vec();
+
+        handle.record_cost(
+            VERIFY_DILITHIUM_BASE_GAS
+                .saturating_add(input.len() as u64 * VERIFY_DILITHIUM_PER_BYTE_GAS)
+                .saturating_add(ML_DSA_VERIFY_GAS),
+        )?;
+
+        let decode = |index| {
+            abi_bytes_arg(&input, index).ok_or_else(|| PrecompileFailure::Error {
+                exit_status: ExitError::Other("Invalid ABI input".into()),
+            })
+        };
+        let (message, public_key, signature) = (decode(0)?, decode(1)?, decode(2)?);
+
+        let valid = public_key.len() == DILITHIUM_PUBLIC_KEY_SIZE
+            && signature.len() == DILITHIUM_SIGNATURE_SIZE
+            && pallet_quantum_vault::Pallet::<Runtime>::verify_ml_dsa(
+                public_key, message, signature,
+            );
+
+        let mut output = vec![0u8; 32];
+        output[31] = valid as u8;
+        Ok(PrecompileOutput {
+            exit_status: ExitSucceed::Returned,
+            output,
+        })
+    }
+}
+
+/// The `bytes` argument at `index` of ABI-encoded call data; `None` if its
+/// offset or length points outside `input`
+fn abi_bytes_arg(input: &[u8], index: usize) -> Option<&[u8]> {
+    let word = |at: usize| -> Option<usize> {
+        let word = input.get(at..at.checked_add(32)?)?;
+        // Offsets and lengths past 2^64 can't be in bounds
+        if word[..24].iter().any(|byte| *byte != 0) {
+            return None;
         }
+        usize::try_from(u64::from_be_bytes(word[24..].try_into().ok()?)).ok()
+    };
+    let offset = word(index.checked_mul(32)?)?;
+    let len = word(offset)?;
+    let start = offset.checked_add(32)?;
+    input.get(start..start.checked_add(len)?)
+}
 
-        // Parse batch ID
-        let mut bytes = [0u8; 8];
-        bytes.copy_from_slice(&input[0..8]);
-        let batch_id = u64::from_le_bytes(bytes);
+// ═══════════════════════════════════════════════════════════════════════════
+// PRECOMPILE: Batch Verify ML-DSA (0x24)
+// ═══════════════════════════════════════════════════════════════════════════
 
-        // Query pallet storage
-        let batch_info = pallet_reml_verifier::VerifiedBatches::<Runtime>::get(batch_id);
-
-        match batch_info {
-            Some(info) => {
-                // Found - return batch info
-                let mut output = Vec::with_capacity(64);
-
-                // Requests root (32 bytes)
-                output.extend_from_slice(&info.requests_root);
-
-                // Signature count (4 bytes, big-endian)
-                output.extend_from_slice(&info.signature_count.to_be_bytes());
-
-                // Block number - use raw encoded value (8 bytes)
-                // BlockNumber is typically u32 or u64, we'll encode as 8 bytes
-                let block_bytes = frame_support::pallet_prelude::Encode::encode(&info.verified_at);
-                let mut block_num_bytes = [0u8; 8];
-                let copy_len = block_bytes.len().min(8);
-                block_num_bytes[8 - copy_len..].copy_from_slice(&block_bytes[..copy_len]);
-                output.extend_from_slice(&block_num_bytes);
-
-                // Pad to 64 bytes for EVM alignment
-                while output.len() < 64 {
-                    output.push(0);
+/// Verifies up to `MAX_BATCH_SIGNATURES` ML-DSA-44 signatures in one call.
+///
+/// Keys and signatures are supplied once; each item names its key by
+/// `keccak256(public_key)` and its signature by index, so a multisig contract
+/// checks all its signers for the overhead of a single call.
+///
+/// ## Input Format (big-endian integers)
+/// - bytes[0..2]: Key count `k`
+/// - bytes[2..4]: Signature count `s`
+/// - next `k` × 1312 bytes: Public keys
+/// - next `s` × 2420 bytes: Signatures
+/// - items until the end of input, each:
+///   - 32 bytes: keccak256 of the public key
+///   - 2 bytes: Signature index
+///   - 4 bytes: Message length `m`
+///   - `m` bytes: Message
+///
+/// ## Output
+/// - ABI-encoded `bool[]` with one result per item. Items whose key hash
+///   matches no supplied key, or whose index has no signature, are `false`.
+pub struct BatchVerifyMlDsa<Runtime>(PhantomData<Runtime>);
+
+impl<Runtime> Precompile for BatchVerifyMlDsa<Runtime>
+where
+    Runtime: pallet_quantum_vault::Config + pallet_evm::Config,
+{
+    fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+        let input: Vec<u8> = handle.input().to_vec();
+
+        handle.record_cost(
+            BATCH_VERIFY_BASE_GAS.saturating_add(input.len() as u64 * BATCH_VERIFY_PER_BYTE_GAS),
+        )?;
+
+        let batch = parse_signature_batch(&input).ok_or_else(|| PrecompileFailure::Error {
+            exit_status: ExitError::Other("Invalid signature batch".into()),
+        })?;
+
+        // Charge for every verification before doing any
+        handle.record_cost(ML_DSA_VERIFY_GAS.saturating_mul(batch.items.len() as u64))?;
+
+        let key_hashes: Vec<[u8; 32]> = batch.keys.iter().map(|key| keccak_256(key)).collect();
+        let results = batch.items.iter().map(|item| {
+            let key = key_hashes.iter().position(|hash| *hash == item.key_hash);
+            match (key, batch.signatures.get(item.signature_index)) {
+                (Some(key), Some(signature)) => {
+                    pallet_quantum_vault::Pallet::<Runtime>::verify_ml_dsa(
+                        batch.keys[key],
+                        item.message,
+                        signature,
+                    )
                 }
+                _ => false,
+            }
+        });
+
+        Ok(PrecompileOutput {
+            exit_status: ExitSucceed::Returned,
+            output: encode_bool_array(results),
+        })
+    }
+}
+
+/// Input of `BatchVerifyMlDsa`, borrowing from the call data
+struct SignatureBatch<'a> {
+    keys: Vec<&'a [u8]>,
+    signatures: Vec<&'a [u8]>,
+    items: Vec<BatchItem<'a>>,
+}
+
+/// One signature check of a batch
+struct BatchItem<'a> {
+    key_hash: [u8; 32],
+    signature_index: usize,
+    message: &'a [u8],
+}
+
+/// Parse batched ML-DSA input; `None` if malformed, empty or over the limits
+fn parse_signature_batch(input: &[u8]) -> Option<SignatureBatch<'_>> {
+    let mut rest = input;
+    let key_count = u16::from_be_bytes(take(&mut rest, 2)?.try_into().ok()?) as usize;
+    let signature_count = u16::from_be_bytes(take(&mut rest, 2)?.try_into().ok()?) as usize;
+    if key_count > MAX_BATCH_SIGNATURES || signature_count > MAX_BATCH_SIGNATURES {
+        return None;
+    }
+
+    let keys = (0..key_count)
+        .map(|_| take(&mut rest, DILITHIUM_PUBLIC_KEY_SIZE))
+        .collect::<Option<Vec<_>>>()?;
+    let signatures = (0..signature_count)
+        .map(|_| take(&mut rest, DILITHIUM_SIGNATURE_SIZE))
+        .collect::<Option<Vec<_>>>()?;
+
+    let mut items = Vec::new();
+    while !rest.is_empty() {
+        if items.len() == MAX_BATCH_SIGNATURES {
+            return None;
+        }
+        let key_hash = take(&mut rest, 32)?.try_into().ok()?;
+        let signature_index = u16::from_be_bytes(take(&mut rest, 2)?.try_into().ok()?) as usize;
+        let message_len = u32::from_be_bytes(take(&mut rest, 4)?.try_into().ok()?) as usize;
+        let message = take(&mut rest, message_len)?;
+        items.push(BatchItem {
+            key_hash,
+            signature_index,
+            message,
+        });
+    }
+    if items.is_empty() {
+        return None;
+    }
+
+    Some(SignatureBatch {
+        keys,
+        signatures,
+        items,
+    })
+}
+
+/// Split `len` bytes off the front of `input`
+fn take<'a>(input: &mut &'a [u8], len: usize) -> Option<&'a [u8]> {
+    if input.len() < len {
+        return None;
+    }
+    let (head, tail) = input.split_at(len);
+    *input = tail;
+    Some(head)
+}
+
+/// ABI encoding of a `bool[]` return value
+fn encode_bool_array(values: impl ExactSizeIterator<Item = bool>) -> Vec<u8> {
+    let mut output = vec![0u8; 64 + 32 * values.len()];
+    // Offset of the array data, then its length
+    output[31] = 0x20;
+    output[56..64].copy_from_slice(&(values.len() as u64).to_be_bytes());
+    for (i, value) in values.enumerate() {
+        output[64 + 32 * i + 31] = value as u8;
+    }
+    output
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// PRECOMPILE: Submit Compute Request (0x25)
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Submits a ZK-coprocessor request on behalf of the calling contract.
+///
+/// The caller's mapped account pays the bounty and request deposit, and gets
+/// them back through the coprocessor pallet like any other requester. Vaults
+/// cannot pay from the EVM; the pallet rejects vault requesters.
+///
+/// ## Input Format
+/// - `abi.encode(uint32 programId, bytes input, uint256 bounty)`
+///
+/// ## Output
+/// - ABI-encoded `uint256` request ID
+pub struct SubmitComputeRequest<Runtime>(PhantomData<Runtime>);
+
+impl<Runtime> Precompile for SubmitComputeRequest<Runtime>
+where
+    Runtime: pallet_zk_coprocessor::Config + pallet_evm::Config,
+{
+    fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+        let input: Vec<u8> = handle.input().to_vec();
 
-                Ok(PrecompileOutput {
-                    exit_status: ExitSucceed::Returned,
-                    output,
-                })
+        if handle.is_static() {
+            return Err(PrecompileFailure::Error {
+                exit_status: ExitError::Other("Cannot submit in a static call".into()),
+            });
+        }
+
+        let weight = <Runtime as pallet_zk_coprocessor::Config>::WeightInfo::submit_request(
+            input.len() as u32,
+        );
+        handle.record_cost(Runtime::GasWeightMapping::weight_to_gas(weight))?;
+
+        let invalid = || PrecompileFailure::Error {
+            exit_status: ExitError::Other("Invalid ABI input".into()),
+        };
+        let program_id = abi_uint_arg(&input, 0)
+            .and_then(|id| pallet_program_registry::ProgramId::try_from(id).ok())
+            .ok_or_else(invalid)?;
+        let request_input = abi_bytes_arg(&input, 1).ok_or_else(invalid)?;
+        let bounty = abi_uint_arg(&input, 2)
+            .and_then(|bounty| bounty.try_into().ok())
+            .ok_or_else(invalid)?;
+
+        let requester = Runtime::AddressMapping::into_account_id(handle.context().caller);
+        let request_id = pallet_zk_coprocessor::Pallet::<Runtime>::do_submit_request(
+            requester,
+            program_id,
+            request_input.to_vec(),
+            bounty,
+        )
+        .map_err(|error| {
+            let vault: sp_runtime::DispatchError =
+                pallet_zk_coprocessor::Error::<Runtime>::VaultRequester.into();
+            let reason = if error == vault {
+                "Vaults cannot pay from the EVM"
+            } else {
+                "Request rejected"
+            };
+            PrecompileFailure::Error {
+                exit_status: ExitError::Other(reason.into()),
             }
-            None => {
-                // Not found - return empty (all zeros)
-                Ok(PrecompileOutput {
-                    exit_status: ExitSucceed::Returned,
-                    output: vec![0u8; 64],
-                })
+        })?;
+
+        let mut output = vec![0u8; 32];
+        output[24..].copy_from_slice(&request_id.to_be_bytes());
+        Ok(PrecompileOutput {
+            exit_status: ExitSucceed::Returned,
+            output,
+        })
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// PRECOMPILE: Get Compute Result (0x26)
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Statuses returned by `GetComputeResult`
+const COMPUTE_UNKNOWN: u8 = 0;
+const COMPUTE_PENDING: u8 = 1;
+const COMPUTE_FULFILLED: u8 = 2;
+
+/// Gets the status and proven output of a ZK-coprocessor request.
+///
+/// ## Input Format
+/// - `abi.encode(uint64 requestId)`
+///
+/// ## Output
+/// - `abi.encode(uint8 status, bytes output)`: status 0 for unknown (never
+///   submitted, cancelled or cleared), 1 for pending, 2 for fulfilled; output
+///   is empty unless fulfilled
+pub struct GetComputeResult<Runtime>(PhantomData<Runtime>);
+
+impl<Runtime> Precompile for GetComputeResult<Runtime>
+where
+    Runtime: pallet_zk_coprocessor::Config + pallet_evm::Config,
+{
+    fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+        let input: Vec<u8> = handle.input().to_vec();
+
+        handle.record_cost(GET_COMPUTE_RESULT_GAS)?;
+
+        let Some(request_id) = abi_uint_arg(&input, 0).and_then(|id| u64::try_from(id).ok()) else {
+            return Err(PrecompileFailure::Error {
+                exit_status: ExitError::Other("Invalid request ID".into()),
+            });
+        };
+
+        let (status, result) = match pallet_zk_coprocessor::Results::<Runtime>::get(request_id) {
+            Some(result) => (COMPUTE_FULFILLED, result.output.into_inner()),
+            None if pallet_zk_coprocessor::Requests::<Runtime>::contains_key(request_id) => {
+                (COMPUTE_PENDING, Vec::new())
             }
+            None => (COMPUTE_UNKNOWN, Vec::new()),
+        };
+        handle.record_cost(result.len() as u64 * GET_COMPUTE_RESULT_PER_BYTE_GAS)?;
+
+        Ok(PrecompileOutput {
+            exit_status: ExitSucceed::Returned,
+            output: encode_status_and_bytes(status, &result),
+        })
+    }
+}
+
+/// The `uint` argument at `index` of ABI-encoded call data; `None` if it is
+/// missing or does not fit a `u128`
+fn abi_uint_arg(input: &[u8], index: usize) -> Option<u128> {
+    let start = index.checked_mul(32)?;
+    let word = input.get(start..start.checked_add(32)?)?;
+    if word[..16].iter().any(|byte| *byte != 0) {
+        return None;
+    }
+    Some(u128::from_be_bytes(word[16..].try_into().ok()?))
+}
+
+/// `abi.encode(uint8 status, bytes data)`
+fn encode_status_and_bytes(status: u8, data: &[u8]) -> Vec<u8> {
+    let mut output = vec![0u8; 96];
+    output[31] = status;
+    output[63] = 0x40;
+    output[88..96].copy_from_slice(&(data.len() as u64).to_be_bytes());
+    output.extend_from_slice(data);
+    output.resize(output.len().next_multiple_of(32), 0);
+    output
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// PRECOMPILE: Is Vault (0x27)
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Checks whether an address is locked as a quantum vault.
+///
+/// Vault accounts can only move funds with a Dilithium-signed
+/// `vault_transfer`, so contracts (e.g. a DEX) can refuse or reroute them.
+/// The address is mapped to its account with the runtime's `AddressMapping`.
+///
+/// ## Input Format
+/// - `abi.encode(address account)`
+///
+/// ## Output
+/// - ABI-encoded `bool`
+pub struct IsVault<Runtime>(PhantomData<Runtime>);
+
+impl<Runtime> Precompile for IsVault<Runtime>
+where
+    Runtime: pallet_quantum_vault::Config + pallet_evm::Config,
+{
+    fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+        let input: Vec<u8> = handle.input().to_vec();
+
+        handle.record_cost(IS_VAULT_GAS)?;
+
+        let Some(address) = abi_address_arg(&input, 0) else {
+            return Err(PrecompileFailure::Error {
+                exit_status: ExitError::Other("Invalid address".into()),
+            });
+        };
+
+        let account = Runtime::AddressMapping::into_account_id(address);
+        let mut output = vec![0u8; 32];
+        output[31] = pallet_quantum_vault::Pallet::<Runtime>::is_vault(&account) as u8;
+
+        Ok(PrecompileOutput {
+            exit_status: ExitSucceed::Returned,
+            output,
+        })
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// PRECOMPILE: Get Vault Nonce (0x28)
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Gets the nonce the next `vault_transfer` of a vault signs.
+///
+/// ## Input Format
+/// - `abi.encode(address vault)`
+///
+/// ## Output
+/// - ABI-encoded `uint256` nonce; reverts if the address is not a vault
+pub struct GetVaultNonce<Runtime>(PhantomData<Runtime>);
+
+impl<Runtime> Precompile for GetVaultNonce<Runtime>
+where
+    Runtime: pallet_quantum_vault::Config + pallet_evm::Config,
+{
+    fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+        let input: Vec<u8> = handle.input().to_vec();
+
+        handle.record_cost(GET_VAULT_NONCE_GAS)?;
+
+        let Some(address) = abi_address_arg(&input, 0) else {
+            return Err(PrecompileFailure::Error {
+                exit_status: ExitError::Other("Invalid address".into()),
+            });
+        };
+
+        let account = Runtime::AddressMapping::into_account_id(address);
+        let Some(nonce) = pallet_quantum_vault::Pallet::<Runtime>::vault_nonce(&account) else {
+            return Err(PrecompileFailure::Error {
+                exit_status: ExitError::Other("Not a vault".into()),
+            });
+        };
+
+        let mut output = vec![0u8; 32];
+        output[24..].copy_from_slice(&nonce.to_be_bytes());
+        Ok(PrecompileOutput {
+            exit_status: ExitSucceed::Returned,
+            output,
+        })
+    }
+}
+
+/// The `address` argument at `index` of ABI-encoded call data; `None` if it
+/// is missing or has dirty upper bytes
+fn abi_address_arg(input: &[u8], index: usize) -> Option<H160> {
+    let start = index.checked_mul(32)?;
+    let word = input.get(start..start.checked_add(32)?)?;
+    if word[..12].iter().any(|byte| *byte != 0) {
+        return None;
+    }
+    Some(H160::from_slice(&word[12..]))
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// PRECOMPILE: Verify SP1 Proof (0x29)
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Verifies an SP1 Groth16 proof the way SP1's `ISP1Verifier` does.
+///
+/// Same check as the Re-ML verifier and the ZK-coprocessor make, against the
+/// key set with `set_groth16_vk`: public inputs are `programVKey` and
+/// `hashPublicValues(publicValues)`. A contract written against SP1's
+/// verifier gateway can use `ISP1Verifier(address(0x29))` unchanged, and a
+/// Re-ML proof's `publicValues` are the ABI-encoded batch it also submits
+/// to the pallet.
+///
+/// ## Input Format
+/// - A call to `ISP1Verifier.verifyProof(bytes32 programVKey,
+///   bytes publicValues, bytes proofBytes)`, or the same arguments
+///   `abi.encode`d without the selector
+///
+/// ## Output
+/// - Empty if the proof verifies. Otherwise reverts with SP1's
+///   `InvalidProof()`, including while no Groth16 key is set
+pub struct VerifySp1Proof<Runtime>(PhantomData<Runtime>);
+
+impl<Runtime> Precompile for VerifySp1Proof<Runtime>
+where
+    Runtime: pallet_reml_verifier::Config + pallet_evm::Config,
+{
+    fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+        let input: Vec<u8> = handle.input().to_vec();
+
+        let weight = <Runtime as pallet_reml_verifier::Config>::WeightInfo::verify_groth16_proof();
+        handle.record_cost(
+            Runtime::GasWeightMapping::weight_to_gas(weight)
+                .saturating_add(input.len() as u64 * VERIFY_SP1_PROOF_PER_BYTE_GAS),
+        )?;
+
+        let Some((program_vkey, public_values, proof)) = sp1_proof_args(&input) else {
+            return Err(PrecompileFailure::Error {
+                exit_status: ExitError::Other("Invalid ABI input".into()),
+            });
+        };
+
+        if !pallet_reml_verifier::Pallet::<Runtime>::verify_sp1_groth16(
+            &program_vkey,
+            public_values,
+            proof,
+        ) {
+            return Err(PrecompileFailure::Revert {
+                exit_status: ExitRevert::Reverted,
+                output: keccak_256(INVALID_PROOF_SIGNATURE)[..4].to_vec(),
+            });
         }
+
+        Ok(PrecompileOutput {
+            exit_status: ExitSucceed::Returned,
+            output: Vec::new(),
+        })
     }
 }
 
+/// Function signature of `ISP1Verifier.verifyProof`, which 0x29 implements
+const VERIFY_PROOF_SIGNATURE: &[u8] = b"verifyProof(bytes32,bytes,bytes)";
+
+/// Error SP1's verifier contracts revert with on a proof that fails
+const INVALID_PROOF_SIGNATURE: &[u8] = b"InvalidProof()";
+
+/// `(programVKey, publicValues, proofBytes)` of a 0x29 call, with or without
+/// the `verifyProof` selector
+fn sp1_proof_args(input: &[u8]) -> Option<([u8; 32], &[u8], &[u8])> {
+    let args = input.strip_prefix(&keccak_256(VERIFY_PROOF_SIGNATURE)[..4]).unwrap_or(input);
+    let program_vkey = args.get(..32)?.try_into().ok()?;
+    Some((program_vkey, abi_bytes_arg(args, 1)?, abi_bytes_arg(args, 2)?))
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // HELPER: Standalone Precompile (No Runtime Access)
 // ═══════════════════════════════════════════════════════════════════════════
@@ -305,7 +908,7 @@ impl Precompile for IsRequestVerifiedStandalone {
 
         handle.record_cost(IS_REQUEST_VERIFIED_GAS)?;
 
-        if input.len() < 8 {
+        if u64_id_arg(&input, IS_REQUEST_VERIFIED_SIGNATURE).is_none() {
             return Err(PrecompileFailure::Error {
                 exit_status: ExitError::Other("Invalid request ID".into()),
             });
@@ -329,16 +932,16 @@ impl Precompile for GetBatchInfoStandalone {
 
         handle.record_cost(GET_BATCH_INFO_GAS)?;
 
-        if input.len() < 8 {
+        let Some((_, encoding)) = u64_id_arg(&input, GET_BATCH_INFO_SIGNATURE) else {
             return Err(PrecompileFailure::Error {
                 exit_status: ExitError::Other("Invalid batch ID".into()),
             });
-        }
+        };
 
         // In standalone mode, return empty
         Ok(PrecompileOutput {
             exit_status: ExitSucceed::Returned,
-            output: vec![0u8; 64],
+            output: encode_batch_info([0u8; 32], 0, 0, encoding),
         })
     }
 }
@@ -393,4 +996,198 @@ mod tests {
             &bad_version
         ));
     }
+
+    /// Batch input with `keys` and `signatures`, then `items`
+    fn batch_input(keys: u16, signatures: u16, items: &[([u8; 32], u16, &[u8])]) -> Vec<u8> {
+        let mut input = Vec::new();
+        input.extend(keys.to_be_bytes());
+        input.extend(signatures.to_be_bytes());
+        for i in 0..keys {
+            input.extend(vec![i as u8 + 1; DILITHIUM_PUBLIC_KEY_SIZE]);
+        }
+        input.extend(vec![0xAA; DILITHIUM_SIGNATURE_SIZE * signatures as usize]);
+        for (key_hash, index, message) in items {
+            input.extend(key_hash);
+            input.extend(index.to_be_bytes());
+            input.extend((message.len() as u32).to_be_bytes());
+            input.extend(*message);
+        }
+        input
+    }
+
+    #[test]
+    fn test_parse_signature_batch() {
+        let key_hash = keccak_256(&[1u8; DILITHIUM_PUBLIC_KEY_SIZE]);
+        let input = batch_input(2, 1, &[(key_hash, 0, b"first"), ([9; 32], 3, b"")]);
+
+        let batch = parse_signature_batch(&input).unwrap();
+        assert_eq!(batch.keys.len(), 2);
+        assert_eq!(batch.signatures.len(), 1);
+        assert_eq!(batch.items.len(), 2);
+        assert_eq!(batch.items[0].key_hash, key_hash);
+        assert_eq!(batch.items[0].message, b"first");
+        assert_eq!(batch.items[1].signature_index, 3);
+        assert!(batch.items[1].message.is_empty());
+
+        // No items
+        assert!(parse_signature_batch(&batch_input(1, 1, &[])).is_none());
+        // Truncated message
+        assert!(parse_signature_batch(&input[..input.len() - 1]).is_none());
+        // Missing signature bytes
+        assert!(parse_signature_batch(&batch_input(0, 1, &[])[..100]).is_none());
+        // Too many items
+        let items = vec![([0u8; 32], 0u16, &b""[..]); MAX_BATCH_SIGNATURES + 1];
+        assert!(parse_signature_batch(&batch_input(0, 0, &items)).is_none());
+    }
+
+    /// `abi.encode` of `bytes` arguments
+    fn abi_encode_bytes(args: &[&[u8]]) -> Vec<u8> {
+        let mut head = Vec::new();
+        let mut tail = Vec::new();
+        for arg in args {
+            let offset = 32 * args.len() + tail.len();
+            head.extend(sp_core::U256::from(offset).to_big_endian());
+            tail.extend(sp_core::U256::from(arg.len()).to_big_endian());
+            tail.extend(*arg);
+            tail.resize(tail.len().next_multiple_of(32), 0);
+        }
+        head.extend(tail);
+        head
+    }
+
+    #[test]
+    fn test_abi_bytes_arg() {
+        let signature = vec![0xAA; DILITHIUM_SIGNATURE_SIZE];
+        let input = abi_encode_bytes(&[b"hello", &[], &signature]);
+
+        assert_eq!(abi_bytes_arg(&input, 0), Some(&b"hello"[..]));
+        assert_eq!(abi_bytes_arg(&input, 1), Some(&[][..]));
+        assert_eq!(abi_bytes_arg(&input, 2), Some(&signature[..]));
+        // No fourth argument
+        assert_eq!(abi_bytes_arg(&input, 3), None);
+        // Truncated signature
+        assert_eq!(abi_bytes_arg(&input[..input.len() - 100], 2), None);
+
+        // Offset past the input, and past 2^64
+        let mut bad_offset = input.clone();
+        bad_offset[31] = 0xff;
+        bad_offset[30] = 0xff;
+        assert_eq!(abi_bytes_arg(&bad_offset, 0), None);
+        bad_offset[0] = 1;
+        assert_eq!(abi_bytes_arg(&bad_offset, 0), None);
+    }
+
+    #[test]
+    fn test_abi_uint_arg() {
+        let mut input = vec![0u8; 64];
+        input[31] = 7;
+        input[48] = 1;
+
+        assert_eq!(abi_uint_arg(&input, 0), Some(7));
+        assert_eq!(abi_uint_arg(&input, 1), Some(1 << 120));
+        assert_eq!(abi_uint_arg(&input, 2), None);
+
+        // Past u128
+        input[15] = 1;
+        assert_eq!(abi_uint_arg(&input, 0), None);
+    }
+
+    #[test]
+    fn test_abi_address_arg() {
+        let mut input = vec![0u8; 32];
+        input[12..].copy_from_slice(&[0xAB; 20]);
+        assert_eq!(abi_address_arg(&input, 0), Some(H160::repeat_byte(0xAB)));
+
+        // Missing, short or with dirty upper bytes
+        assert_eq!(abi_address_arg(&input, 1), None);
+        assert_eq!(abi_address_arg(&input[..31], 0), None);
+        input[11] = 1;
+        assert_eq!(abi_address_arg(&input, 0), None);
+    }
+
+    #[test]
+    fn test_u64_id_arg() {
+        let id = 0x0102_0304_0506_0708u64;
+        let mut word = vec![0u8; 32];
+        word[24..].copy_from_slice(&id.to_be_bytes());
+
+        // abi.encode(uint64), bare or behind its selector
+        assert_eq!(u64_id_arg(&word, GET_BATCH_INFO_SIGNATURE), Some((id, IdEncoding::Abi)));
+        let call = [&keccak_256(GET_BATCH_INFO_SIGNATURE)[..4], &word[..]].concat();
+        assert_eq!(u64_id_arg(&call, GET_BATCH_INFO_SIGNATURE), Some((id, IdEncoding::Abi)));
+        // Another function's selector is not stripped
+        assert_eq!(u64_id_arg(&call, IS_REQUEST_VERIFIED_SIGNATURE), None);
+
+        // Legacy little-endian IDs, with or without trailing bytes
+        assert_eq!(
+            u64_id_arg(&id.to_le_bytes(), GET_BATCH_INFO_SIGNATURE),
+            Some((id, IdEncoding::LegacyLe))
+        );
+        assert_eq!(
+            u64_id_arg(&[&id.to_le_bytes()[..], &[0xFF; 4]].concat(), GET_BATCH_INFO_SIGNATURE),
+            Some((id, IdEncoding::LegacyLe))
+        );
+
+        // Too short, or past u64
+        assert_eq!(u64_id_arg(&[1; 7], GET_BATCH_INFO_SIGNATURE), None);
+        word[23] = 1;
+        assert_eq!(u64_id_arg(&word, GET_BATCH_INFO_SIGNATURE), None);
+    }
+
+    #[test]
+    fn test_sp1_proof_args() {
+        // abi.encode(bytes32, bytes, bytes): the first head word holds the
+        // vkey in place of an offset
+        let mut args = abi_encode_bytes(&[&[], b"public values", &[0xAA; 260]]);
+        args[..32].copy_from_slice(&[7u8; 32]);
+        let expected = Some(([7u8; 32], &b"public values"[..], &[0xAA; 260][..]));
+        assert_eq!(sp1_proof_args(&args), expected);
+
+        // As a call through ISP1Verifier
+        let call = [&keccak_256(VERIFY_PROOF_SIGNATURE)[..4], &args[..]].concat();
+        assert_eq!(sp1_proof_args(&call), expected);
+
+        // Truncated proof
+        assert_eq!(sp1_proof_args(&args[..args.len() - 32]), None);
+        assert_eq!(sp1_proof_args(&[7u8; 31]), None);
+    }
+
+    #[test]
+    fn test_encode_batch_info() {
+        let output = encode_batch_info([7u8; 32], 3, 1_000, IdEncoding::Abi);
+        assert_eq!(output.len(), 96);
+        assert_eq!(&output[..32], &[7u8; 32]);
+        // Same layout as abi.encode(bytes32, uint32, uint64)
+        assert_eq!(abi_uint_arg(&output, 1), Some(3));
+        assert_eq!(abi_uint_arg(&output, 2), Some(1_000));
+
+        let legacy = encode_batch_info([7u8; 32], 3, 1_000, IdEncoding::LegacyLe);
+        assert_eq!(legacy.len(), 64);
+        assert_eq!(&legacy[32..36], &3u32.to_be_bytes());
+        assert_eq!(&legacy[36..44], &1_000u64.to_be_bytes());
+    }
+
+    #[test]
+    fn test_encode_status_and_bytes() {
+        let output = encode_status_and_bytes(COMPUTE_FULFILLED, b"6765");
+        assert_eq!(output.len(), 128);
+        assert_eq!(output[31], COMPUTE_FULFILLED);
+        // Same layout as abi.encode(uint8, bytes)
+        assert_eq!(abi_bytes_arg(&output, 1), Some(&b"6765"[..]));
+
+        let empty = encode_status_and_bytes(COMPUTE_UNKNOWN, &[]);
+        assert_eq!(empty.len(), 96);
+        assert_eq!(abi_bytes_arg(&empty, 1), Some(&[][..]));
+    }
+
+    #[test]
+    fn test_encode_bool_array() {
+        let output = encode_bool_array([true, false, true].into_iter());
+        assert_eq!(output.len(), 64 + 3 * 32);
+        assert_eq!(sp_core::U256::from_big_endian(&output[0..32]), 32.into());
+        assert_eq!(sp_core::U256::from_big_endian(&output[32..64]), 3.into());
+        assert_eq!(output[95], 1);
+        assert_eq!(output[127], 0);
+        assert_eq!(output[159], 1);
+    }
 }
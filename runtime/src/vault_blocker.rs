@@ -1,25 +1,66 @@
 //! Quantum Vault Transfer Blocker
 //!
 //! This module provides a `TransactionExtension` that blocks standard `pallet_balances::transfer*`
-//! calls from accounts that have been converted to Quantum Vaults.
+//! calls from accounts that have been converted to Quantum Vaults, including
+//! when wrapped in another call (`sudo_as` dispatching as the vault). Wrappers
+//! are inspected up to `MAX_WRAPPED_CALL_DEPTH` levels deep; deeper nesting is
+//! rejected outright.
 //!
 //! Vault accounts can only transfer funds using `pallet_quantum_vault::vault_transfer`
-//! which requires a valid Dilithium signature.
+//! which requires a valid Dilithium signature. Pallet calls that pay out of
+//! the signer to an account anyone can control (`RemlVerifier::tip_batch`,
+//! `ZkCoprocessor::submit_request`) are blocked too; their pallets also reject
+//! vaults at dispatch.
+//!
+//! The same extension rejects calls into a paused pallet (vault operations while
+//! `QuantumVault` is paused, proof submissions while `RemlVerifier` is paused),
+//! so they never reach a block only to fail in execution.
+//!
+//! Proof submissions that do reach a block and fail are recorded in
+//! `RemlVerifier`'s recent rejections after dispatch, since the failed call's
+//! own storage writes are rolled back.
+//!
+//! It also provides the EVM-side check used by `eth_call` / `eth_estimateGas`, so
+//! value transfers from vault-mapped H160 addresses fail fast with a readable
+//! revert reason instead of a generic error.
+//!
+//! On-chain, [`VaultGuardRunner`] wraps the EVM runner and refuses value
+//! transfers from vault-mapped addresses, whether they come from a
+//! `pallet_evm` extrinsic or an Ethereum transaction. The extension rejects
+//! the `pallet_evm` calls at pool admission already.
 
-use crate::{Runtime, RuntimeCall};
+use crate::{configs::HashedAddressMapping, AccountId, Runtime, RuntimeCall};
+use alloc::vec::Vec;
 use codec::{Decode, DecodeWithMemTracking, Encode};
-use frame_support::{pallet_prelude::TransactionSource, traits::OriginTrait};
+use ethereum::AuthorizationList;
+use fp_evm::{CallInfo, CreateInfo, ExitError, ExitReason, ExitRevert, UsedGas};
+use frame_support::{
+    pallet_prelude::TransactionSource,
+    traits::{Get, OriginTrait},
+};
+use pallet_evm::{runner::RunnerError, AddressMapping, EvmConfig, Runner};
 use scale_info::TypeInfo;
+use sp_core::{H160, H256, U256};
 use sp_runtime::{
-    impl_tx_ext_default,
-    traits::{DispatchInfoOf, TransactionExtension},
-    transaction_validity::InvalidTransaction,
-    Weight,
+    traits::{
+        DispatchInfoOf, DispatchOriginOf, PostDispatchInfoOf, StaticLookup, TransactionExtension,
+    },
+    transaction_validity::{InvalidTransaction, TransactionValidityError},
+    DispatchResult, Weight,
 };
 
 /// Custom error code for vault transfer block
 const VAULT_TRANSFER_BLOCKED: u8 = 100;
 
+/// Custom error code for vault operations while vaults are paused
+const VAULTS_PAUSED: u8 = 101;
+
+/// Custom error code for proof submissions while the verifier is paused
+const VERIFIER_PAUSED: u8 = 102;
+
+/// Levels of wrapping calls inspected for transfers out of a vault
+const MAX_WRAPPED_CALL_DEPTH: u32 = 4;
+
 /// Transaction extension that blocks standard transfers from vault accounts.
 ///
 /// When an account is converted to a Quantum Vault, they can only transfer funds
@@ -50,11 +91,19 @@ impl TransactionExtension<RuntimeCall> for CheckVaultTransfer {
     const IDENTIFIER: &'static str = "CheckVaultTransfer";
     type Implicit = ();
     type Val = ();
-    type Pre = ();
+    /// Batch ID and submitter of a proof submission, to record it if rejected
+    type Pre = Option<(u64, AccountId)>;
 
-    fn weight(&self, _: &RuntimeCall) -> Weight {
-        // Minimal weight - just a storage read check
-        Weight::from_parts(1_000, 0)
+    fn weight(&self, call: &RuntimeCall) -> Weight {
+        // Minimal weight - a couple of storage read checks
+        let checks = Weight::from_parts(1_000, 0);
+        match proof_batch_id(call) {
+            // Recording a rejection
+            Some(_) => checks.saturating_add(
+                <Runtime as frame_system::Config>::DbWeight::get().reads_writes(1, 1),
+            ),
+            None => checks,
+        }
     }
 
     fn validate(
@@ -67,30 +116,400 @@ impl TransactionExtension<RuntimeCall> for CheckVaultTransfer {
         _inherited_implication: &impl Encode,
         _source: TransactionSource,
     ) -> sp_runtime::traits::ValidateResult<Self::Val, RuntimeCall> {
-        // Check if sender is a vault account attempting a balance transfer
-        if let Some(who) = origin.as_signer() {
-            // Check if this is a balance transfer call
-            let is_balance_transfer = matches!(
-                call,
-                RuntimeCall::Balances(
-                    pallet_balances::Call::transfer_allow_death { .. }
-                        | pallet_balances::Call::transfer_keep_alive { .. }
-                        | pallet_balances::Call::transfer_all { .. }
-                )
+        // Check if the call, or a call it wraps, moves funds out of a vault
+        if moves_vault_funds(origin.as_signer().cloned(), call, 0) {
+            log::warn!(
+                target: "quantum-vault",
+                "🚫 Blocked standard transfer from vault account. Use vault_transfer instead."
             );
+            return Err(InvalidTransaction::Custom(VAULT_TRANSFER_BLOCKED).into());
+        }
 
-            // If it's a balance transfer, check if the sender is a vault
-            if is_balance_transfer && pallet_quantum_vault::Pallet::<Runtime>::is_vault(who) {
-                log::warn!(
-                    target: "quantum-vault",
-                    "🚫 Blocked standard transfer from vault account. Use vault_transfer instead."
-                );
-                return Err(InvalidTransaction::Custom(VAULT_TRANSFER_BLOCKED).into());
-            }
+        if let Some(code) = paused_call_error(call) {
+            return Err(InvalidTransaction::Custom(code).into());
         }
 
         Ok((Default::default(), (), origin))
     }
 
-    impl_tx_ext_default!(RuntimeCall; prepare);
+    fn prepare(
+        self,
+        _val: Self::Val,
+        origin: &DispatchOriginOf<RuntimeCall>,
+        call: &RuntimeCall,
+        _info: &DispatchInfoOf<RuntimeCall>,
+        _len: usize,
+    ) -> Result<Self::Pre, TransactionValidityError> {
+        Ok(proof_batch_id(call).zip(origin.as_signer().cloned()))
+    }
+
+    fn post_dispatch_details(
+        pre: Self::Pre,
+        _info: &DispatchInfoOf<RuntimeCall>,
+        _post_info: &PostDispatchInfoOf<RuntimeCall>,
+        _len: usize,
+        result: &DispatchResult,
+    ) -> Result<Weight, TransactionValidityError> {
+        if let (Some((batch_id, aggregator)), Err(error)) = (pre, result) {
+            pallet_reml_verifier::Pallet::<Runtime>::note_rejection(batch_id, aggregator, error);
+        }
+        Ok(Weight::zero())
+    }
+}
+
+/// Batch ID of a proof submission call
+fn proof_batch_id(call: &RuntimeCall) -> Option<u64> {
+    match call {
+        RuntimeCall::RemlVerifier(
+            pallet_reml_verifier::Call::submit_proof { submission }
+            | pallet_reml_verifier::Call::submit_cosigned_proof { submission, .. },
+        ) => Some(submission.batch_id),
+        RuntimeCall::RemlVerifier(pallet_reml_verifier::Call::submit_single_proof {
+            submission,
+        }) => Some(submission.batch_id),
+        _ => None,
+    }
+}
+
+/// Whether `call`, dispatched as `signer` (`None` for root), moves funds out
+/// of a vault by a path other than `vault_transfer`
+///
+/// Wrapping calls are inspected with the origin their inner call runs as.
+fn moves_vault_funds(signer: Option<AccountId>, call: &RuntimeCall, depth: u32) -> bool {
+    if depth > MAX_WRAPPED_CALL_DEPTH {
+        return true;
+    }
+
+    match call {
+        RuntimeCall::Balances(
+            pallet_balances::Call::transfer_allow_death { .. }
+            | pallet_balances::Call::transfer_keep_alive { .. }
+            | pallet_balances::Call::transfer_all { .. },
+        )
+        | RuntimeCall::RemlVerifier(pallet_reml_verifier::Call::tip_batch { .. })
+        | RuntimeCall::ZkCoprocessor(pallet_zk_coprocessor::Call::submit_request { .. }) => {
+            signer.is_some_and(|who| pallet_quantum_vault::Pallet::<Runtime>::is_vault(&who))
+        }
+        // EVM calls spend from the source's mapped account, whoever signs them
+        RuntimeCall::EVM(_) => evm_value_transfer(call)
+            .is_some_and(|(source, value)| is_blocked_evm_value_transfer(&source, value)),
+        RuntimeCall::Sudo(
+            pallet_sudo::Call::sudo { call }
+            | pallet_sudo::Call::sudo_unchecked_weight { call, .. },
+        ) => moves_vault_funds(None, call, depth + 1),
+        RuntimeCall::Sudo(pallet_sudo::Call::sudo_as { who, call }) => {
+            let who = <Runtime as frame_system::Config>::Lookup::lookup(who.clone()).ok();
+            // An unknown account fails at dispatch anyway
+            who.is_some() && moves_vault_funds(who, call, depth + 1)
+        }
+        _ => false,
+    }
+}
+
+/// Source and value of a `pallet_evm` call or contract creation
+fn evm_value_transfer(call: &RuntimeCall) -> Option<(H160, U256)> {
+    match call {
+        RuntimeCall::EVM(
+            pallet_evm::Call::call { source, value, .. }
+            | pallet_evm::Call::create { source, value, .. }
+            | pallet_evm::Call::create2 { source, value, .. },
+        ) => Some((*source, *value)),
+        _ => None,
+    }
+}
+
+/// Custom error code for `call` if the pallet it targets is paused
+fn paused_call_error(call: &RuntimeCall) -> Option<u8> {
+    match call {
+        RuntimeCall::QuantumVault(
+            pallet_quantum_vault::Call::create_vault { .. }
+            | pallet_quantum_vault::Call::create_vault_with_premium_mode { .. }
+            | pallet_quantum_vault::Call::create_vault_from_descriptor { .. }
+            | pallet_quantum_vault::Call::create_vault_for { .. }
+            | pallet_quantum_vault::Call::create_vault_with_message_mode { .. }
+            | pallet_quantum_vault::Call::create_vault_with_security_level { .. }
+            | pallet_quantum_vault::Call::destroy_vault { .. }
+            | pallet_quantum_vault::Call::vault_approve { .. }
+            | pallet_quantum_vault::Call::vault_transfer_from { .. }
+            | pallet_quantum_vault::Call::set_vault_policy { .. }
+            | pallet_quantum_vault::Call::vault_transfer { .. }
+            | pallet_quantum_vault::Call::vault_transfer_with_nonce { .. },
+        ) if pallet_quantum_vault::Pallet::<Runtime>::is_paused() => Some(VAULTS_PAUSED),
+        RuntimeCall::RemlVerifier(
+            pallet_reml_verifier::Call::submit_proof { .. }
+            | pallet_reml_verifier::Call::submit_cosigned_proof { .. }
+            | pallet_reml_verifier::Call::submit_single_proof { .. },
+        ) if pallet_reml_verifier::Pallet::<Runtime>::is_paused() => Some(VERIFIER_PAUSED),
+        _ => None,
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// EVM RPC CHECKS
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Revert reason for EVM value transfers out of a vault
+pub const VAULT_EVM_TRANSFER_BLOCKED_REASON: &str =
+    "QuantumVault: value transfers from vault accounts are blocked, use vault_transfer";
+
+/// Solidity `Error(string)` selector
+const ERROR_STRING_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+
+/// Whether an EVM call from `from` carrying `value` would move funds out of a vault
+pub fn is_blocked_evm_value_transfer(from: &H160, value: U256) -> bool {
+    !value.is_zero()
+        && pallet_quantum_vault::Pallet::<Runtime>::is_vault(&HashedAddressMapping::into_account_id(
+            *from,
+        ))
+}
+
+/// ABI-encoded `Error(string)` revert data for [`VAULT_EVM_TRANSFER_BLOCKED_REASON`]
+pub fn vault_evm_revert_data() -> Vec<u8> {
+    let reason = VAULT_EVM_TRANSFER_BLOCKED_REASON.as_bytes();
+    let padded_len = reason.len().div_ceil(32) * 32;
+
+    let mut data = Vec::with_capacity(4 + 64 + padded_len);
+    data.extend_from_slice(&ERROR_STRING_SELECTOR);
+    data.extend_from_slice(&U256::from(32).to_big_endian());
+    data.extend_from_slice(&U256::from(reason.len()).to_big_endian());
+    data.extend_from_slice(reason);
+    data.resize(4 + 64 + padded_len, 0);
+    data
+}
+
+/// `eth_call` / `eth_estimateGas` result for a blocked vault value transfer
+///
+/// Frontier surfaces this as `execution reverted: <reason>`.
+pub fn blocked_call_info() -> pallet_evm::CallInfo {
+    pallet_evm::CallInfo {
+        exit_reason: ExitReason::Revert(ExitRevert::Reverted),
+        value: vault_evm_revert_data(),
+        used_gas: UsedGas { standard: U256::zero(), effective: U256::zero() },
+        weight_info: None,
+        logs: Vec::new(),
+    }
+}
+
+/// Contract-creation counterpart of [`blocked_call_info`]
+///
+/// Create results carry no return data, so the reason travels in the error.
+pub fn blocked_create_info() -> pallet_evm::CreateInfo {
+    pallet_evm::CreateInfo {
+        exit_reason: ExitReason::Error(ExitError::Other(VAULT_EVM_TRANSFER_BLOCKED_REASON.into())),
+        value: H160::zero(),
+        used_gas: UsedGas { standard: U256::zero(), effective: U256::zero() },
+        weight_info: None,
+        logs: Vec::new(),
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// EVM RUNNER
+// ═══════════════════════════════════════════════════════════════════════════
+
+type StackRunner = pallet_evm::runner::stack::Runner<Runtime>;
+
+/// EVM runner that refuses value transfers from vault-mapped addresses
+///
+/// Every EVM execution goes through the runner, so this also covers Ethereum
+/// transactions, which never see `CheckVaultTransfer`. Blocked executions
+/// fail with `BalanceLow`: the vault's balance is not spendable from the EVM.
+/// Everything else is delegated to the stack runner.
+pub struct VaultGuardRunner;
+
+impl VaultGuardRunner {
+    fn check(source: &H160, value: U256) -> Result<(), RunnerError<pallet_evm::Error<Runtime>>> {
+        if is_blocked_evm_value_transfer(source, value) {
+            return Err(RunnerError {
+                error: pallet_evm::Error::<Runtime>::BalanceLow,
+                weight: <Runtime as frame_system::Config>::DbWeight::get().reads(1),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl Runner<Runtime> for VaultGuardRunner {
+    type Error = pallet_evm::Error<Runtime>;
+
+    fn validate(
+        source: H160,
+        target: Option<H160>,
+        input: Vec<u8>,
+        value: U256,
+        gas_limit: u64,
+        max_fee_per_gas: Option<U256>,
+        max_priority_fee_per_gas: Option<U256>,
+        nonce: Option<U256>,
+        access_list: Vec<(H160, Vec<H256>)>,
+        authorization_list: AuthorizationList,
+        is_transactional: bool,
+        weight_limit: Option<Weight>,
+        proof_size_base_cost: Option<u64>,
+        evm_config: &EvmConfig,
+    ) -> Result<(), RunnerError<Self::Error>> {
+        Self::check(&source, value)?;
+        StackRunner::validate(
+            source,
+            target,
+            input,
+            value,
+            gas_limit,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            nonce,
+            access_list,
+            authorization_list,
+            is_transactional,
+            weight_limit,
+            proof_size_base_cost,
+            evm_config,
+        )
+    }
+
+    fn call(
+        source: H160,
+        target: H160,
+        input: Vec<u8>,
+        value: U256,
+        gas_limit: u64,
+        max_fee_per_gas: Option<U256>,
+        max_priority_fee_per_gas: Option<U256>,
+        nonce: Option<U256>,
+        access_list: Vec<(H160, Vec<H256>)>,
+        authorization_list: AuthorizationList,
+        is_transactional: bool,
+        validate: bool,
+        weight_limit: Option<Weight>,
+        proof_size_base_cost: Option<u64>,
+        config: &EvmConfig,
+    ) -> Result<CallInfo, RunnerError<Self::Error>> {
+        Self::check(&source, value)?;
+        StackRunner::call(
+            source,
+            target,
+            input,
+            value,
+            gas_limit,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            nonce,
+            access_list,
+            authorization_list,
+            is_transactional,
+            validate,
+            weight_limit,
+            proof_size_base_cost,
+            config,
+        )
+    }
+
+    fn create(
+        source: H160,
+        init: Vec<u8>,
+        value: U256,
+        gas_limit: u64,
+        max_fee_per_gas: Option<U256>,
+        max_priority_fee_per_gas: Option<U256>,
+        nonce: Option<U256>,
+        access_list: Vec<(H160, Vec<H256>)>,
+        authorization_list: AuthorizationList,
+        is_transactional: bool,
+        validate: bool,
+        weight_limit: Option<Weight>,
+        proof_size_base_cost: Option<u64>,
+        config: &EvmConfig,
+    ) -> Result<CreateInfo, RunnerError<Self::Error>> {
+        Self::check(&source, value)?;
+        StackRunner::create(
+            source,
+            init,
+            value,
+            gas_limit,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            nonce,
+            access_list,
+            authorization_list,
+            is_transactional,
+            validate,
+            weight_limit,
+            proof_size_base_cost,
+            config,
+        )
+    }
+
+    fn create2(
+        source: H160,
+        init: Vec<u8>,
+        salt: H256,
+        value: U256,
+        gas_limit: u64,
+        max_fee_per_gas: Option<U256>,
+        max_priority_fee_per_gas: Option<U256>,
+        nonce: Option<U256>,
+        access_list: Vec<(H160, Vec<H256>)>,
+        authorization_list: AuthorizationList,
+        is_transactional: bool,
+        validate: bool,
+        weight_limit: Option<Weight>,
+        proof_size_base_cost: Option<u64>,
+        config: &EvmConfig,
+    ) -> Result<CreateInfo, RunnerError<Self::Error>> {
+        Self::check(&source, value)?;
+        StackRunner::create2(
+            source,
+            init,
+            salt,
+            value,
+            gas_limit,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            nonce,
+            access_list,
+            authorization_list,
+            is_transactional,
+            validate,
+            weight_limit,
+            proof_size_base_cost,
+            config,
+        )
+    }
+
+    fn create_force_address(
+        source: H160,
+        init: Vec<u8>,
+        value: U256,
+        gas_limit: u64,
+        max_fee_per_gas: Option<U256>,
+        max_priority_fee_per_gas: Option<U256>,
+        nonce: Option<U256>,
+        access_list: Vec<(H160, Vec<H256>)>,
+        authorization_list: AuthorizationList,
+        is_transactional: bool,
+        validate: bool,
+        weight_limit: Option<Weight>,
+        proof_size_base_cost: Option<u64>,
+        config: &EvmConfig,
+        contract_address: H160,
+    ) -> Result<CreateInfo, RunnerError<Self::Error>> {
+        Self::check(&source, value)?;
+        StackRunner::create_force_address(
+            source,
+            init,
+            value,
+            gas_limit,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            nonce,
+            access_list,
+            authorization_list,
+            is_transactional,
+            validate,
+            weight_limit,
+            proof_size_base_cost,
+            config,
+            contract_address,
+        )
+    }
 }
@@ -0,0 +1,103 @@
+#!/usr/bin/env python3
+"""
+Quantum Vault Signing Payload - Test Vector Generator
+
+Writes primitives/vault-payload/test-vectors.json from an implementation of
+the payload format written independently of the Rust crate:
+
+    payload = "TESSERAX_VAULT" || version: u8 || kind: u8 || field*
+    field   = len: u32 (little-endian) || bytes
+
+Accounts are 32-byte AccountId32 values and balances SCALE-encoded u128
+(16 bytes, little-endian), as on the Tesserax runtime.
+"""
+
+import json
+import os
+import struct
+
+MAGIC = b"TESSERAX_VAULT"
+VERSION = 1
+KINDS = {"transfer": 0, "destroy": 1, "rotate": 2, "freeze": 3, "accept": 4, "approve": 5,
+         "announce": 6, "policy": 7}
+
+ALICE = bytes([0xd4, 0x35, 0x93, 0xc7]) + bytes(range(28))
+BOB = bytes([0x8e, 0xaf, 0x04, 0x15]) + bytes(range(100, 128))
+KEY_HASH = bytes(range(0xa0, 0xc0))
+ANNOUNCEMENT = "Runtime upgrade to spec 120 at block 1000000".encode()
+
+
+def u64(n):
+    return struct.pack("<Q", n)
+
+
+def u128(n):
+    return n.to_bytes(16, "little")
+
+
+def u32(n):
+    return struct.pack("<I", n)
+
+
+def some(encoded):
+    """SCALE `Option::Some`"""
+    return b"\x01" + encoded
+
+
+NONE = b"\x00"
+
+
+def payload(kind, fields):
+    out = MAGIC + bytes([VERSION, KINDS[kind]])
+    for field in fields:
+        out += u32(len(field)) + field
+    return out
+
+
+def vector(name, kind, fields, **inputs):
+    """`inputs` are the builder arguments; byte strings are hex-encoded"""
+    entry = {"name": name, "kind": kind}
+    for key, value in inputs.items():
+        entry[key] = value.hex() if isinstance(value, bytes) else value
+    entry["payload"] = payload(kind, fields).hex()
+    return entry
+
+
+def main():
+    tsrx = 10**18
+    vectors = [
+        vector("transfer", "transfer", [ALICE, BOB, u128(5 * tsrx), u64(0)],
+               **{"from": ALICE}, to=BOB, amount=u128(5 * tsrx), nonce=0),
+        vector("transfer_max_values", "transfer",
+               [ALICE, ALICE, u128(2**128 - 1), u64(2**64 - 1)],
+               **{"from": ALICE}, to=ALICE, amount=u128(2**128 - 1), nonce=2**64 - 1),
+        vector("destroy", "destroy", [ALICE, u64(7)], account=ALICE, nonce=7),
+        vector("rotate", "rotate", [BOB, KEY_HASH, u64(42)],
+               account=BOB, new_public_key_hash=KEY_HASH, nonce=42),
+        vector("freeze", "freeze", [BOB, u64(1)], account=BOB, nonce=1),
+        vector("accept", "accept", [BOB, ALICE, KEY_HASH, u128(tsrx), struct.pack("<I", 3)],
+               target=BOB, funder=ALICE, public_key_hash=KEY_HASH,
+               initial_deposit=u128(tsrx), account_nonce=struct.pack("<I", 3)),
+        vector("approve", "approve", [ALICE, BOB, u128(100 * tsrx), u64(5)],
+               vault=ALICE, spender=BOB, limit=u128(100 * tsrx), nonce=5),
+        vector("announce", "announce", [BOB, ANNOUNCEMENT, u64(9)],
+               vault=BOB, message=ANNOUNCEMENT, nonce=9),
+        # Whitelist of two accounts: compact length 2 is 0x08
+        vector("policy", "policy",
+               [ALICE, some(u128(50 * tsrx)), some(b"\x08" + BOB + ALICE), u64(11)],
+               vault=ALICE, daily_limit=some(u128(50 * tsrx)),
+               whitelist=some(b"\x08" + BOB + ALICE), nonce=11),
+        vector("policy_unrestricted", "policy", [BOB, NONE, NONE, u64(0)],
+               vault=BOB, daily_limit=NONE, whitelist=NONE, nonce=0),
+    ]
+
+    path = os.path.join(os.path.dirname(__file__), "..", "primitives", "vault-payload",
+                        "test-vectors.json")
+    with open(path, "w") as f:
+        json.dump({"version": VERSION, "vectors": vectors}, f, indent=2)
+        f.write("\n")
+    print(f"Wrote {len(vectors)} vectors to {os.path.normpath(path)}")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,73 @@
+// Light-client regression check for vault wallets.
+//
+// Syncs a smoldot light client against a running node and exercises the
+// runtime APIs a vault wallet depends on:
+//   (a) QuantumVaultApi_is_vault
+//   (b) QuantumVaultApi_vault_nonce
+//   (c) TaggedTransactionQueue_validate_transaction (must be exposed)
+//
+// Usage:
+//   ./target/release/tesserax-node build-spec --chain local --raw > /tmp/local.json
+//   # add the node's multiaddr to "bootNodes", start the node, then:
+//   CHAIN_SPEC=/tmp/local.json npm run check
+
+import { readFileSync } from "node:fs";
+import * as smoldot from "smoldot";
+
+// Alice (sr25519 //Alice) - SCALE-encoded AccountId32 is the raw 32 bytes
+const ALICE = "d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d";
+
+const chainSpec = readFileSync(process.env.CHAIN_SPEC ?? "chain-spec.json", "utf8");
+const client = smoldot.start({ maxLogLevel: 3 });
+const chain = await client.addChain({ chainSpec });
+
+let nextId = 1;
+async function rpc(method, params = []) {
+    const id = nextId++;
+    chain.sendJsonRpc(JSON.stringify({ jsonrpc: "2.0", id, method, params }));
+    for (;;) {
+        const response = JSON.parse(await chain.nextJsonRpcResponse());
+        if (response.id !== id) continue;
+        if (response.error) throw new Error(`${method}: ${response.error.message}`);
+        return response.result;
+    }
+}
+
+async function waitForSync() {
+    for (;;) {
+        const health = await rpc("system_health");
+        if (!health.isSyncing && health.peers > 0) return;
+        await new Promise((resolve) => setTimeout(resolve, 1000));
+    }
+}
+
+function assert(condition, message) {
+    if (!condition) throw new Error(`❌ ${message}`);
+    console.log(`✅ ${message}`);
+}
+
+try {
+    await waitForSync();
+
+    const version = await rpc("state_getRuntimeVersion");
+    assert(version.specName === "tesserax-runtime", "runtime is tesserax-runtime");
+
+    // (a) Vault status: Alice is not a vault on a fresh chain (bool false = 0x00)
+    const isVault = await rpc("state_call", ["QuantumVaultApi_is_vault", `0x${ALICE}`]);
+    assert(isVault === "0x00", "QuantumVaultApi_is_vault answers over the light client");
+
+    // (b) Vault nonce: None for a non-vault (Option::None = 0x00)
+    const nonce = await rpc("state_call", ["QuantumVaultApi_vault_nonce", `0x${ALICE}`]);
+    assert(nonce === "0x00", "QuantumVaultApi_vault_nonce answers over the light client");
+
+    // (c) Submission path: smoldot validates transactions through this API
+    // before gossiping them, so it must be listed in the runtime version.
+    const TAGGED_TX_QUEUE = "0xd2bc9897eed08f15";
+    assert(
+        version.apis.some(([apiId]) => apiId === TAGGED_TX_QUEUE),
+        "TaggedTransactionQueue is exposed",
+    );
+} finally {
+    chain.remove();
+    await client.terminate();
+}
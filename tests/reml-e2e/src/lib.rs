@@ -0,0 +1,11 @@
+//! # Re-ML End-to-End Harness
+//!
+//! Test-only crate. `tests/host_guest_pallet.rs` drives a batch through the
+//! host, guest and pallet code paths in one process:
+//!
+//! ```bash
+//! cargo test -p reml-e2e --features e2e
+//! ```
+//!
+//! Each crate has its own unit tests; this harness catches encoding
+//! mismatches between them (public values layout, Merkle root, epoch).
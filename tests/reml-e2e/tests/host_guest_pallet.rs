@@ -0,0 +1,226 @@
+//! Host → guest → pallet end-to-end test
+//!
+//! 1. Host: sign a batch with real Dilithium2 keys (`reml_lib::test_batch`)
+//! 2. Guest: run `reml_guest::process_batch` natively, checking signatures
+//!    with the reference implementation instead of the zkVM verifier
+//! 3. Mock prover: wrap the output in a proof bundle that embeds the public
+//!    values binding hash, and round-trip it through JSON like `proof.json`
+//! 4. Pallet: submit it to pallet-reml-verifier in a mock runtime
+
+#![cfg(feature = "e2e")]
+
+use frame_support::{
+    assert_noop, assert_ok, derive_impl, parameter_types,
+    traits::{ConstU32, ConstU64, Nothing},
+    BoundedVec, PalletId,
+};
+use pallet_reml_verifier::{
+    BatchRootsBlake2, Error, ProofCommitments, ProofSubmission, PublicValues, MIN_PROOF_SIZE,
+    REML_PROGRAM_ID,
+};
+use reml_lib::{
+    test_batch, RemlProofBundle, RemlProofInput, RemlProofOutput, REML_PROOF_EPOCH,
+};
+use sp_io::hashing::blake2_256;
+use sp_runtime::{testing::H256, BuildStorage};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+    pub enum Test {
+        System: frame_system,
+        Balances: pallet_balances,
+        RemlVerifier: pallet_reml_verifier,
+    }
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+    type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type AccountStore = System;
+}
+
+/// Verification key hash the mock prover reports and the runtime expects
+const VKEY_HASH: [u8; 32] = [0x5A; 32];
+
+/// Registered aggregator account
+const AGGREGATOR: u64 = 7;
+
+parameter_types! {
+    pub const ExpectedVKeyHash: [u8; 32] = VKEY_HASH;
+    pub const RewardPotId: PalletId = PalletId(*b"reml/pot");
+    pub const PrunerShare: sp_runtime::Perbill = sp_runtime::Perbill::zero();
+}
+
+impl pallet_reml_verifier::Config for Test {
+    type WeightInfo = ();
+    type AdminOrigin = frame_system::EnsureRoot<u64>;
+    type MaxAggregators = ConstU32<4>;
+    type ExpectedVKeyHash = ExpectedVKeyHash;
+    type TransitionVKeyHash = ();
+    type ProgramVKeys = ();
+    type InlineRequestMarks = ConstU32<{ pallet_reml_verifier::MAX_VERIFIED_REQUESTS }>;
+    type ProofEpoch = ConstU32<REML_PROOF_EPOCH>;
+    type OnBatchVerified = ();
+    type CoSignature = sp_runtime::testing::TestSignature;
+    type RewardCurrency = Balances;
+    type VaultCheck = Nothing;
+    type BatchReward = ConstU64<0>;
+    type RewardPotId = RewardPotId;
+    type EraLength = ConstU64<14_400>;
+    type MaxBatchesPerEra = ConstU32<{ u32::MAX }>;
+    type AggregatorBond = ConstU64<0>;
+    type UnbondingPeriod = ConstU64<0>;
+    type Slash = ();
+    type ProofRetentionPeriod = ConstU64<{ u64::MAX }>;
+    type MaxAnchorAge = ConstU64<10>;
+    type SubmissionDeposit = ConstU64<0>;
+    type PrunerShare = PrunerShare;
+}
+
+fn new_test_ext() -> sp_io::TestExternalities {
+    let storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+    let mut ext = sp_io::TestExternalities::new(storage);
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(RemlVerifier::register_aggregator(RuntimeOrigin::root(), AGGREGATOR));
+    });
+    ext
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// PIPELINE STAGES
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Guest: batch logic, with host-side signature checks
+fn run_guest(input: RemlProofInput) -> RemlProofOutput {
+    reml_guest::process_batch(&input, test_batch::verify_request)
+}
+
+/// Mock prover: proof bytes carry the binding hash the pallet looks for
+fn mock_prove(output: RemlProofOutput) -> RemlProofBundle {
+    let mut proof = vec![0x01];
+    proof.extend_from_slice(&to_public_values(&output).binding_hash());
+    proof.resize(MIN_PROOF_SIZE, 0);
+
+    let bundle = RemlProofBundle::new(proof, output, VKEY_HASH);
+
+    // Aggregators read bundles back from the prover's JSON output
+    let json = serde_json::to_string(&bundle).unwrap();
+    serde_json::from_str(&json).unwrap()
+}
+
+fn to_public_values(output: &RemlProofOutput) -> PublicValues {
+    PublicValues {
+        version: output.version,
+        chain_id: output.chain_id,
+        epoch: output.epoch,
+        batch_id: output.batch_id,
+        verified_count: output.verified_count,
+        mldsa44_count: output.mldsa44_count,
+        falcon512_count: output.falcon512_count,
+        requests_root: output.requests_root,
+        requests_root_blake2: output.requests_root_blake2,
+        verified_request_ids: BoundedVec::try_from(output.verified_request_ids.clone())
+            .expect("batch fits MAX_VERIFIED_REQUESTS"),
+        verified_request_owners: BoundedVec::try_from(output.verified_request_owners.clone())
+            .expect("batch fits MAX_VERIFIED_REQUESTS"),
+        verified_request_messages: BoundedVec::try_from(output.verified_request_messages.clone())
+            .expect("batch fits MAX_VERIFIED_REQUESTS"),
+    }
+}
+
+/// Aggregator: proof bundle → `submit_proof` payload
+fn to_submission(bundle: &RemlProofBundle) -> ProofSubmission {
+    ProofSubmission {
+        batch_id: bundle.output.batch_id,
+        proof: BoundedVec::try_from(bundle.proof.clone()).expect("proof fits MAX_PROOF_SIZE"),
+        public_values: to_public_values(&bundle.output),
+        vkey_hash: bundle.vkey_hash,
+        program_id: REML_PROGRAM_ID,
+        // Genesis, the tests run at block 1
+        anchor_block: 0,
+        anchor_hash: System::block_hash(0).into(),
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TESTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn batch_from_host_is_accepted_by_pallet() {
+    let input = RemlProofInput::new(test_batch::generate_requests(8, 2), 1);
+    let output = run_guest(input.clone());
+
+    // Corrupted signatures (IDs 0 and 1) are left out
+    assert_eq!(output.verified_request_ids, (2..8).collect::<Vec<u64>>());
+    assert_eq!(output.epoch, REML_PROOF_EPOCH);
+
+    let bundle = mock_prove(output);
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(RemlVerifier::submit_proof(
+            RuntimeOrigin::signed(AGGREGATOR),
+            to_submission(&bundle)
+        ));
+
+        let info = RemlVerifier::verified_batches(1).expect("batch verified");
+        assert_eq!(info.requests_root, bundle.output.requests_root);
+        assert_eq!(info.signature_count, 6);
+        assert_eq!(RemlVerifier::batch_by_root(bundle.output.requests_root).unwrap().0, 1);
+        assert_eq!(BatchRootsBlake2::<Test>::get(1), Some(bundle.output.requests_root_blake2));
+
+        for id in 2..8 {
+            assert!(RemlVerifier::is_request_verified(id));
+            // Owned by the key that signed it, as a vault hashes its key
+            let request = &input.requests[id as usize];
+            assert_eq!(RemlVerifier::request_owner(id), Some(blake2_256(&request.public_key)));
+            // Over the message it signed, as a vault hashes what it checks
+            assert_eq!(
+                RemlVerifier::request_message(id),
+                Some(blake2_256(&request.signed_message()))
+            );
+        }
+        assert!(!RemlVerifier::is_request_verified(0));
+        assert_eq!(RemlVerifier::request_owner(0), None);
+        assert_eq!(RemlVerifier::request_message(0), None);
+    });
+}
+
+#[test]
+fn proof_for_another_epoch_is_rejected() {
+    let input = RemlProofInput::new(test_batch::generate_requests(2, 0), 2)
+        .with_epoch(REML_PROOF_EPOCH + 1);
+    let bundle = mock_prove(run_guest(input));
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            RemlVerifier::submit_proof(RuntimeOrigin::signed(AGGREGATOR), to_submission(&bundle)),
+            Error::<Test>::StaleProofEpoch
+        );
+    });
+}
+
+#[test]
+fn host_commitment_matches_pallet() {
+    let input = RemlProofInput::new(test_batch::generate_requests(2, 0), 3);
+    let bundle = mock_prove(run_guest(input));
+    let commitment = bundle.proof_commitment();
+
+    assert_eq!(RemlVerifier::compute_proof_commitment(&to_submission(&bundle)), commitment);
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(RemlVerifier::submit_proof(
+            RuntimeOrigin::signed(AGGREGATOR),
+            to_submission(&bundle)
+        ));
+        // What the host looks up before submitting again
+        assert!(ProofCommitments::<Test>::contains_key(H256(commitment)));
+    });
+}
@@ -0,0 +1,255 @@
+//! # Sanctuary Audit
+//!
+//! Replays historical blocks over RPC and cross-checks fee and emission
+//! accounting against on-chain state, block by block:
+//!
+//! - **Fees**: the block's `FeesCollected` events must add up to the growth of
+//!   `QuantumVault::TotalFeesCollected`, and its `VaultTransfer` premiums must
+//!   match the premium `FeesCollected` events.
+//! - **Emission**: the block's `RewardMinted` and `RewardRedirected` events
+//!   must add up to the growth of `EmissionApi_total_emitted`, the scheduled
+//!   emission. The one-time `BonusMinted` is reported separately.
+//!
+//! Every mismatch is printed as a consistency alarm, and the exit code is
+//! non-zero if there was any.
+//!
+//! ```bash
+//! sanctuary-audit --url ws://127.0.0.1:9944 --from 1 --to 100000
+//! ```
+//!
+//! Events are decoded with the runtime types this binary was built with.
+//! Blocks whose events don't decode (older runtimes with different events)
+//! raise an alarm; audit them with a build of the matching runtime.
+
+use anyhow::{bail, Context, Result};
+use clap::Parser;
+use codec::Decode;
+use frame_system::EventRecord;
+use jsonrpsee::{
+    core::client::ClientT,
+    rpc_params,
+    ws_client::{WsClient, WsClientBuilder},
+};
+use sp_core::{twox_128, Bytes};
+use tesserax_runtime::{Balance, BlockNumber, Hash, Header, RuntimeEvent};
+
+#[derive(Parser)]
+#[command(
+    name = "sanctuary-audit",
+    about = "Replay blocks and cross-check fee and emission accounting"
+)]
+struct Cli {
+    /// Node RPC endpoint
+    #[arg(long, default_value = "ws://127.0.0.1:9944")]
+    url: String,
+
+    /// First block to replay
+    #[arg(long, default_value = "1")]
+    from: BlockNumber,
+
+    /// Last block to replay (default: finalized head)
+    #[arg(long)]
+    to: Option<BlockNumber>,
+}
+
+/// Amounts a block's events account for
+#[derive(Default)]
+struct Totals {
+    /// All `FeesCollected`
+    fees: Balance,
+    /// `FeesCollected` with the transfer premium reason
+    premium_fees: Balance,
+    /// `VaultTransfer::premium_fee`
+    transfer_premiums: Balance,
+    /// `RewardMinted` and `RewardRedirected`
+    emitted: Balance,
+    /// `BonusMinted`
+    bonus: Balance,
+}
+
+impl Totals {
+    fn add_event(&mut self, event: &RuntimeEvent) {
+        use pallet_emission::Event as EmissionEvent;
+        use pallet_quantum_vault::Event as VaultEvent;
+
+        match event {
+            RuntimeEvent::QuantumVault(VaultEvent::FeesCollected { amount, reason, .. }) => {
+                self.fees += amount;
+                if *reason == pallet_quantum_vault::FEE_REASON_VAULT_TRANSFER_PREMIUM {
+                    self.premium_fees += amount;
+                }
+            },
+            RuntimeEvent::QuantumVault(VaultEvent::VaultTransfer { premium_fee, .. }) => {
+                self.transfer_premiums += premium_fee;
+            },
+            RuntimeEvent::Emission(EmissionEvent::RewardMinted { reward, .. }) => {
+                self.emitted += reward;
+            },
+            RuntimeEvent::Emission(EmissionEvent::RewardRedirected { amount, .. }) => {
+                self.emitted += amount;
+            },
+            RuntimeEvent::Emission(EmissionEvent::BonusMinted { amount, .. }) => {
+                self.bonus += amount;
+            },
+            _ => {},
+        }
+    }
+
+    fn add(&mut self, other: &Totals) {
+        self.fees += other.fees;
+        self.premium_fees += other.premium_fees;
+        self.transfer_premiums += other.transfer_premiums;
+        self.emitted += other.emitted;
+        self.bonus += other.bonus;
+    }
+}
+
+/// Node RPC queries used by the audit
+struct Chain {
+    client: WsClient,
+}
+
+impl Chain {
+    async fn block_hash(&self, number: BlockNumber) -> Result<Hash> {
+        self.client
+            .request::<Option<Hash>, _>("chain_getBlockHash", rpc_params![number])
+            .await?
+            .with_context(|| format!("Block {number} not found"))
+    }
+
+    async fn finalized_number(&self) -> Result<BlockNumber> {
+        let hash: Hash = self.client.request("chain_getFinalizedHead", rpc_params![]).await?;
+        let header: Header = self.client.request("chain_getHeader", rpc_params![hash]).await?;
+        Ok(header.number)
+    }
+
+    /// Raw storage value at `key`, `None` if unset
+    async fn storage(&self, key: &[u8], at: Hash) -> Result<Option<Bytes>> {
+        Ok(self.client.request("state_getStorage", rpc_params![Bytes(key.to_vec()), at]).await?)
+    }
+
+    async fn total_fees_collected(&self, at: Hash) -> Result<Balance> {
+        let key = storage_key(b"QuantumVault", b"TotalFeesCollected");
+        match self.storage(&key, at).await? {
+            Some(data) => Ok(Balance::decode(&mut &data[..])?),
+            None => Ok(0),
+        }
+    }
+
+    async fn total_emitted(&self, at: Hash) -> Result<Balance> {
+        let data: Bytes = self
+            .client
+            .request("state_call", rpc_params!["EmissionApi_total_emitted", Bytes(vec![]), at])
+            .await?;
+        Ok(Balance::decode(&mut &data[..])?)
+    }
+
+    async fn events(&self, at: Hash) -> Result<Vec<EventRecord<RuntimeEvent, Hash>>> {
+        match self.storage(&storage_key(b"System", b"Events"), at).await? {
+            Some(data) => Ok(Decode::decode(&mut &data[..])?),
+            None => Ok(Vec::new()),
+        }
+    }
+}
+
+/// Storage key of a `StorageValue`
+fn storage_key(pallet: &[u8], item: &[u8]) -> Vec<u8> {
+    [twox_128(pallet), twox_128(item)].concat()
+}
+
+/// Growth of a running total, `None` if it shrank
+fn growth(before: Balance, after: Balance) -> Option<Balance> {
+    after.checked_sub(before)
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let chain = Chain {
+        client: WsClientBuilder::default()
+            .build(&cli.url)
+            .await
+            .with_context(|| format!("Failed to connect to {}", cli.url))?,
+    };
+
+    let to = match cli.to {
+        Some(to) => to,
+        None => chain.finalized_number().await?,
+    };
+    if cli.from == 0 || cli.from > to {
+        bail!("Invalid range {}..={to}: blocks start at 1", cli.from);
+    }
+
+    let parent = chain.block_hash(cli.from - 1).await?;
+    let mut fees_before = chain.total_fees_collected(parent).await?;
+    let mut emitted_before = chain.total_emitted(parent).await?;
+    let mut totals = Totals::default();
+    let mut alarms = 0u32;
+    let mut alarm = |number: BlockNumber, message: String| {
+        alarms += 1;
+        println!("🚨 #{number}: {message}");
+    };
+
+    for number in cli.from..=to {
+        let hash = chain.block_hash(number).await?;
+        let fees = chain.total_fees_collected(hash).await?;
+        let emitted = chain.total_emitted(hash).await?;
+
+        let mut block = Totals::default();
+        match chain.events(hash).await {
+            Ok(records) => records.iter().for_each(|record| block.add_event(&record.event)),
+            Err(e) => alarm(number, format!("events could not be decoded: {e}")),
+        }
+
+        match growth(fees_before, fees) {
+            Some(grown) if grown == block.fees => {},
+            Some(grown) => alarm(
+                number,
+                format!(
+                    "TotalFeesCollected grew by {grown}, FeesCollected events sum to {}",
+                    block.fees
+                ),
+            ),
+            None => {
+                alarm(number, format!("TotalFeesCollected shrank from {fees_before} to {fees}"))
+            },
+        }
+        if block.transfer_premiums != block.premium_fees {
+            alarm(
+                number,
+                format!(
+                    "VaultTransfer premiums sum to {}, premium FeesCollected to {}",
+                    block.transfer_premiums, block.premium_fees
+                ),
+            );
+        }
+        match growth(emitted_before, emitted) {
+            Some(scheduled) if scheduled == block.emitted => {},
+            Some(scheduled) => alarm(
+                number,
+                format!("schedule emits {scheduled}, reward events mint {}", block.emitted),
+            ),
+            None => {
+                alarm(number, format!("total_emitted shrank from {emitted_before} to {emitted}"))
+            },
+        }
+
+        totals.add(&block);
+        fees_before = fees;
+        emitted_before = emitted;
+        if number % 10_000 == 0 {
+            eprintln!("… replayed up to #{number}");
+        }
+    }
+
+    println!("Replayed blocks {}..={to}", cli.from);
+    println!("  Fees collected:     {} (transfer premiums {})", totals.fees, totals.premium_fees);
+    println!("  Emission minted:    {}", totals.emitted);
+    println!("  Bonus minted:       {}", totals.bonus);
+    println!("  Consistency alarms: {alarms}");
+
+    if alarms > 0 {
+        bail!("{alarms} consistency alarm(s)");
+    }
+    Ok(())
+}
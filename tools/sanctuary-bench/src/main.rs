@@ -0,0 +1,400 @@
+//! # Sanctuary Bench
+//!
+//! Measures what a vault transfer costs on each of its two paths, so the
+//! protocol team can tune fee discounts for proof-backed transfers with data:
+//!
+//! - **In-band**: `vault_transfer` authorized by its Dilithium signature alone.
+//! - **Re-ML**: `vault_transfer` with a `request_id` from a verified batch,
+//!   whose proof stands in for the signature. The batch's `submit_proof` (and
+//!   the `on_idle` request marks it defers) is shared by the batch's transfers,
+//!   so it is measured at several batch sizes.
+//!
+//! For every row it reports chain weight, inclusion fee, transfer premium and
+//! dispatch wall-clock per transfer. Extrinsics run natively against the
+//! runtime this binary was built with, in fresh test externalities; off-chain
+//! work (signing, proving) is not timed. Fees are computed for the encoded call,
+//! without the signature and extensions every path pays alike.
+//!
+//! ```bash
+//! sanctuary-bench e2e --batch-sizes 1,16,64,256 --transfers 32 --output report.md
+//! ```
+//!
+//! Re-ML proofs are built like a testnet aggregator's (structural proofs), so
+//! run it against a runtime without a Groth16 verifying key set at genesis.
+
+use anyhow::{anyhow, bail, Context, Result};
+use clap::{Parser, Subcommand};
+use codec::Encode;
+use frame_support::{
+    dispatch::GetDispatchInfo,
+    traits::{fungible::Mutate, Get, Hooks},
+    weights::Weight,
+};
+use pallet_reml_verifier::{
+    ProofSubmission, PublicValues, MAX_VERIFIED_REQUESTS, MIN_PROOF_SIZE, REML_PROGRAM_ID,
+    REML_VERSION, TESSERAX_CHAIN_ID,
+};
+use sp_runtime::{traits::Dispatchable, BuildStorage};
+use std::{
+    fmt::Write as _,
+    ops::AddAssign,
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+use tesserax_runtime::{
+    configs, AccountId, Balance, Balances, QuantumVault, RemlVerifier, Runtime, RuntimeCall,
+    RuntimeOrigin, System, TransactionPayment, TSRX,
+};
+
+/// Amount moved by each measured transfer
+const TRANSFER_AMOUNT: Balance = TSRX;
+
+#[derive(Parser)]
+#[command(name = "sanctuary-bench", about = "Benchmark vault transfer paths end to end")]
+struct Cli {
+    #[command(subcommand)]
+    command: Commands,
+}
+
+#[derive(Subcommand)]
+enum Commands {
+    /// Compare in-band and Re-ML-backed vault transfers
+    E2e {
+        /// Re-ML batch sizes to measure (at most 1000)
+        #[arg(long, value_delimiter = ',', default_value = "1,16,64,256")]
+        batch_sizes: Vec<u32>,
+
+        /// Transfers measured on the in-band path
+        #[arg(long, default_value = "32")]
+        transfers: u32,
+
+        /// Write the Markdown report here instead of stdout
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+}
+
+/// What dispatching one or more extrinsics cost
+#[derive(Clone, Copy, Default)]
+struct Cost {
+    weight: Weight,
+    fee: Balance,
+    elapsed: Duration,
+}
+
+impl AddAssign for Cost {
+    fn add_assign(&mut self, other: Cost) {
+        self.weight = self.weight.saturating_add(other.weight);
+        self.fee = self.fee.saturating_add(other.fee);
+        self.elapsed += other.elapsed;
+    }
+}
+
+/// One line of the report, costs per transfer
+struct Row {
+    path: &'static str,
+    batch_size: Option<u32>,
+    transfers: u32,
+    /// Everything dispatched for the row, proof included
+    total: Cost,
+    premium: Balance,
+}
+
+impl Row {
+    fn per_transfer(&self) -> Cost {
+        let n = self.transfers.max(1);
+        Cost {
+            weight: self.total.weight / n as u64,
+            fee: self.total.fee / n as Balance,
+            elapsed: self.total.elapsed / n,
+        }
+    }
+}
+
+fn main() -> Result<()> {
+    match Cli::parse().command {
+        Commands::E2e { batch_sizes, transfers, output } => {
+            if let Some(size) =
+                batch_sizes.iter().find(|&&size| size == 0 || size > MAX_VERIFIED_REQUESTS)
+            {
+                bail!("Batch size {size} is outside 1..={MAX_VERIFIED_REQUESTS}");
+            }
+
+            let mut rows = vec![in_band(transfers)?];
+            for batch_size in batch_sizes {
+                rows.push(reml_batch(batch_size)?);
+            }
+
+            let report = report(&rows);
+            match output {
+                Some(path) => std::fs::write(&path, report)
+                    .with_context(|| format!("Failed to write {}", path.display()))?,
+                None => print!("{report}"),
+            }
+            Ok(())
+        },
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// PATHS
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// `transfers` vault transfers authorized by their Dilithium signatures
+fn in_band(transfers: u32) -> Result<Row> {
+    new_ext().execute_with(|| {
+        let vault = Vault::create()?;
+        let mut total = Cost::default();
+        for _ in 0..transfers {
+            total += vault.transfer(None)?;
+        }
+        Ok(Row {
+            path: "in-band",
+            batch_size: None,
+            transfers,
+            total,
+            premium: vault.premium(false),
+        })
+    })
+}
+
+/// A Re-ML batch of `batch_size` requests, each backing one vault transfer
+fn reml_batch(batch_size: u32) -> Result<Row> {
+    if !cfg!(feature = "lenient-testnet") {
+        bail!("Re-ML rows need a runtime built with `--features lenient-testnet`");
+    }
+    new_ext().execute_with(|| {
+        let vault = Vault::create()?;
+        let aggregator = account(3);
+        // Covers the submission deposit reserved for the batch
+        Balances::mint_into(&aggregator, 10 * TSRX)
+            .map_err(|e| anyhow!("Failed to fund the aggregator: {e:?}"))?;
+        dispatch(
+            RuntimeCall::RemlVerifier(pallet_reml_verifier::Call::register_aggregator {
+                aggregator: aggregator.clone(),
+            }),
+            RuntimeOrigin::root(),
+        )?;
+
+        // Request `i` backs the `i`th transfer, so it commits that transfer's payload
+        let request_ids: Vec<u64> = (1..=batch_size as u64).collect();
+        let first_nonce = QuantumVault::vault_nonce(&vault.account).context("Not a vault")?;
+        let messages = request_ids
+            .iter()
+            .map(|request_id| vault.message_hash(first_nonce + request_id - 1))
+            .collect();
+        let mut total = dispatch(
+            RuntimeCall::RemlVerifier(pallet_reml_verifier::Call::submit_proof {
+                submission: structural_submission(
+                    1,
+                    request_ids.clone(),
+                    vault.key_hash(),
+                    messages,
+                ),
+            }),
+            RuntimeOrigin::signed(aggregator),
+        )?;
+
+        // Requests past `InlineRequestMarks` are marked with leftover block weight
+        let start = Instant::now();
+        let weight = RemlVerifier::on_idle(System::block_number(), Weight::MAX);
+        total += Cost { weight, fee: 0, elapsed: start.elapsed() };
+
+        for request_id in request_ids {
+            total += vault.transfer(Some(request_id))?;
+        }
+        Ok(Row {
+            path: "re-ml",
+            batch_size: Some(batch_size),
+            transfers: batch_size,
+            total,
+            premium: vault.premium(true),
+        })
+    })
+}
+
+/// A vault with a real Dilithium2 key, funded for the benchmark
+struct Vault {
+    account: AccountId,
+    recipient: AccountId,
+    keypair: pqc_dilithium::Keypair,
+}
+
+impl Vault {
+    fn create() -> Result<Self> {
+        let vault = Vault {
+            account: account(1),
+            recipient: account(2),
+            keypair: pqc_dilithium::Keypair::generate(),
+        };
+        Balances::mint_into(&vault.account, 1_000_000 * TSRX)
+            .map_err(|e| anyhow!("Failed to fund the vault: {e:?}"))?;
+        dispatch(
+            RuntimeCall::QuantumVault(pallet_quantum_vault::Call::create_vault {
+                public_key: vault.keypair.public.to_vec(),
+            }),
+            RuntimeOrigin::signed(vault.account.clone()),
+        )?;
+        Ok(vault)
+    }
+
+    /// Owner hash the guest commits for requests signed by this vault's key
+    fn key_hash(&self) -> [u8; 32] {
+        sp_io::hashing::blake2_256(&self.keypair.public)
+    }
+
+    /// Signing payload of the transfer with `nonce`
+    fn payload(&self, nonce: u64) -> Vec<u8> {
+        tesserax_vault_payload::transfer(
+            &self.account.encode(),
+            &self.recipient.encode(),
+            &TRANSFER_AMOUNT.encode(),
+            nonce,
+        )
+    }
+
+    /// Message hash the guest commits for a request signing the transfer
+    /// with `nonce`
+    fn message_hash(&self, nonce: u64) -> [u8; 32] {
+        sp_io::hashing::blake2_256(&self.payload(nonce))
+    }
+
+    /// Dispatch the next transfer, signed or backed by `request_id`
+    ///
+    /// A backed transfer carries no signature, its request's proof covers it.
+    fn transfer(&self, request_id: Option<u64>) -> Result<Cost> {
+        let nonce = QuantumVault::vault_nonce(&self.account).context("Not a vault")?;
+        let signature = match request_id {
+            Some(_) => Vec::new(),
+            None => self.keypair.sign(&self.payload(nonce)).to_vec(),
+        };
+        dispatch(
+            RuntimeCall::QuantumVault(pallet_quantum_vault::Call::vault_transfer {
+                signature,
+                to: self.recipient.clone(),
+                amount: TRANSFER_AMOUNT,
+                request_id,
+            }),
+            RuntimeOrigin::signed(self.account.clone()),
+        )
+    }
+
+    /// Premium of the next transfer, per the runtime's premium schedule
+    fn premium(&self, proof_backed: bool) -> Balance {
+        QuantumVault::transfer_premium(
+            &self.account,
+            &self.recipient,
+            TRANSFER_AMOUNT,
+            proof_backed,
+        )
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// RUNTIME
+// ═══════════════════════════════════════════════════════════════════════════
+
+fn new_ext() -> sp_io::TestExternalities {
+    let storage = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .expect("default genesis builds");
+    let mut ext = sp_io::TestExternalities::new(storage);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+fn account(seed: u8) -> AccountId {
+    AccountId::from([seed; 32])
+}
+
+/// Dispatch `call` from `origin` and measure it
+fn dispatch(call: RuntimeCall, origin: RuntimeOrigin) -> Result<Cost> {
+    let info = call.get_dispatch_info();
+    let len = call.encoded_size() as u32;
+
+    let start = Instant::now();
+    let result = call.dispatch(origin);
+    let elapsed = start.elapsed();
+
+    let post_info = result.map_err(|e| anyhow!("Dispatch failed: {:?}", e.error))?;
+    Ok(Cost {
+        weight: post_info.calc_actual_weight(&info),
+        fee: TransactionPayment::compute_actual_fee(len, &info, &post_info, 0),
+        elapsed,
+    })
+}
+
+/// Batch proof as a testnet aggregator submits it, without a Groth16 key
+///
+/// Every request is committed as signed by `owner` over its message in
+/// `messages`, so its vault can consume it in place of a signature.
+fn structural_submission(
+    batch_id: u64,
+    request_ids: Vec<u64>,
+    owner: [u8; 32],
+    messages: Vec<[u8; 32]>,
+) -> ProofSubmission {
+    let owners = vec![owner; request_ids.len()];
+    let public_values = PublicValues {
+        version: REML_VERSION,
+        chain_id: TESSERAX_CHAIN_ID,
+        epoch: configs::RemlProofEpoch::get(),
+        batch_id,
+        verified_count: request_ids.len() as u32,
+        mldsa44_count: request_ids.len() as u32,
+        falcon512_count: 0,
+        requests_root: tesserax_merkle::keccak_requests_root(&request_ids),
+        requests_root_blake2: tesserax_merkle::blake2_requests_root(&request_ids),
+        verified_request_ids: request_ids.try_into().expect("batch size checked"),
+        verified_request_owners: owners.try_into().expect("batch size checked"),
+        verified_request_messages: messages.try_into().expect("batch size checked"),
+    };
+    let mut proof = vec![0x01];
+    proof.extend_from_slice(&public_values.binding_hash());
+    proof.resize(MIN_PROOF_SIZE, 0);
+
+    ProofSubmission {
+        batch_id,
+        proof: proof.try_into().expect("MIN_PROOF_SIZE fits"),
+        public_values,
+        vkey_hash: configs::ExpectedVKeyHash::get(),
+        program_id: REML_PROGRAM_ID,
+        // Genesis, the bench runs at block 1
+        anchor_block: 0,
+        anchor_hash: System::block_hash(0).into(),
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// REPORT
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Markdown table of `rows`, costs per transfer
+fn report(rows: &[Row]) -> String {
+    let mut out = String::from(
+        "| Path | Batch size | Transfers | ref_time / transfer | proof_size / transfer | \
+         Fee / transfer (TSRX) | Premium (TSRX) | Dispatch / transfer |\n\
+         |------|-----------:|----------:|--------------------:|----------------------:|\
+         ----------------------:|---------------:|--------------------:|\n",
+    );
+    for row in rows {
+        let cost = row.per_transfer();
+        let _ = writeln!(
+            out,
+            "| {} | {} | {} | {} | {} | {:.6} | {:.6} | {:?} |",
+            row.path,
+            row.batch_size.map_or("-".into(), |size| size.to_string()),
+            row.transfers,
+            cost.weight.ref_time(),
+            cost.weight.proof_size(),
+            tsrx(cost.fee),
+            tsrx(row.premium),
+            cost.elapsed,
+        );
+    }
+    out
+}
+
+fn tsrx(amount: Balance) -> f64 {
+    amount as f64 / TSRX as f64
+}
@@ -0,0 +1,278 @@
+//! # Tesserax xtask
+//!
+//! Repository automation, run as `cargo xtask <command>`.
+//!
+//! - `e2e`: builds the node and runs the zombienet tests in `zombienet/`
+//!   against a two-validator `local` network.
+//! - `vkey`: builds the SP1 guest, computes its vkey hash and regenerates
+//!   `runtime/src/configs/reml_vkey.rs` (`--check` fails on drift or an
+//!   unpinned hash instead).
+
+use anyhow::{bail, Context, Result};
+use clap::{Parser, Subcommand};
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+/// Zombienet test run by default
+const DEFAULT_E2E_TEST: &str = "zombienet/0001-reml-smoke.zndsl";
+
+/// Runtime file holding the pinned guest vkey hash
+const VKEY_FILE: &str = "runtime/src/configs/reml_vkey.rs";
+
+#[derive(Parser)]
+#[command(name = "xtask", about = "Tesserax repository automation")]
+struct Cli {
+    #[command(subcommand)]
+    command: Commands,
+}
+
+#[derive(Subcommand)]
+enum Commands {
+    /// Launch a two-node local network with zombienet and run an e2e test
+    E2e {
+        /// Zombienet test (`.zndsl`), relative to the repository root
+        #[arg(long, default_value = DEFAULT_E2E_TEST)]
+        test: PathBuf,
+
+        /// Zombienet binary
+        #[arg(long, env = "ZOMBIENET", default_value = "zombienet")]
+        zombienet: PathBuf,
+
+        /// Reuse the existing `target/release/tesserax-node`
+        #[arg(long)]
+        skip_build: bool,
+    },
+
+    /// Build the Re-ML guest and pin its vkey hash in the runtime
+    Vkey {
+        /// Fail if the runtime hash differs from the guest instead of rewriting it
+        #[arg(long)]
+        check: bool,
+
+        /// Reuse the existing guest ELF in `reml/target/elf`
+        #[arg(long)]
+        skip_build: bool,
+    },
+}
+
+fn main() -> Result<()> {
+    match Cli::parse().command {
+        Commands::E2e {
+            test,
+            zombienet,
+            skip_build,
+        } => e2e(&test, &zombienet, skip_build),
+        Commands::Vkey { check, skip_build } => vkey(check, skip_build),
+    }
+}
+
+/// Repository root (parent of this crate)
+fn project_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("xtask lives in the repository root")
+        .to_path_buf()
+}
+
+/// Run a command, failing on a non-zero exit
+fn run(command: &mut Command) -> Result<()> {
+    let status = command
+        .status()
+        .with_context(|| format!("failed to start {:?}", command.get_program()))?;
+    if !status.success() {
+        bail!("{:?} exited with {status}", command.get_program());
+    }
+    Ok(())
+}
+
+fn e2e(test: &Path, zombienet: &Path, skip_build: bool) -> Result<()> {
+    let root = project_root();
+
+    if !skip_build {
+        // The smoke test submits a mock proof, which only the lenient
+        // testnet verifier accepts
+        println!("🔨 Building tesserax-node (release, lenient-testnet)");
+        run(Command::new(env!("CARGO"))
+            .args(["build", "--release", "-p", "tesserax-node"])
+            .args(["--features", "lenient-testnet"])
+            .current_dir(&root))?;
+    }
+
+    let node = root.join("target/release/tesserax-node");
+    if !node.exists() {
+        bail!("{} not found, run without --skip-build", node.display());
+    }
+
+    let test = root.join(test);
+    if !test.exists() {
+        bail!("zombienet test {} not found", test.display());
+    }
+
+    println!("🧟 Running {}", test.display());
+    run(Command::new(zombienet)
+        .args(["--provider", "native", "test"])
+        .arg(&test)
+        .current_dir(&root))
+    .context(
+        "zombienet test failed (install zombienet from \
+         https://github.com/paritytech/zombienet/releases or set ZOMBIENET)",
+    )
+}
+
+fn vkey(check: bool, skip_build: bool) -> Result<()> {
+    let root = project_root();
+
+    if !skip_build {
+        println!("🔨 Building Re-ML guest (cargo prove build)");
+        run(Command::new("cargo")
+            .args(["prove", "build"])
+            .current_dir(root.join("reml/guest")))
+        .context("guest build failed (install the SP1 toolchain with sp1up)")?;
+    }
+
+    println!("🔑 Computing guest vkey hash");
+    let output = Command::new(env!("CARGO"))
+        .args(["run", "--release", "--manifest-path", "reml/Cargo.toml"])
+        .args(["--bin", "reml-prover", "--", "vkey-hash", "--hex"])
+        .current_dir(&root)
+        .output()
+        .context("failed to start reml-prover")?;
+    if !output.status.success() {
+        bail!(
+            "reml-prover vkey-hash failed:\n{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+    let guest = parse_prover_output(&String::from_utf8_lossy(&output.stdout))?;
+
+    let path = root.join(VKEY_FILE);
+    let source =
+        std::fs::read_to_string(&path).with_context(|| format!("failed to read {VKEY_FILE}"))?;
+    let pinned = parse_vkey_file(&source)?;
+
+    println!("   guest:   0x{}", to_hex(&guest));
+    println!("   runtime: 0x{}", to_hex(&pinned));
+
+    if pinned == guest {
+        println!("✅ Runtime vkey hash matches the guest");
+        return Ok(());
+    }
+
+    if check {
+        if pinned == [0u8; 32] {
+            bail!(
+                "runtime vkey hash is unpinned (all zeros), so only lenient-testnet builds \
+                 accept proofs; run `cargo xtask vkey` and commit"
+            );
+        }
+        bail!("runtime vkey hash drifted from the guest, run `cargo xtask vkey` and commit");
+    }
+
+    std::fs::write(&path, render_vkey_file(&source, &guest)?)
+        .with_context(|| format!("failed to write {VKEY_FILE}"))?;
+    println!("✅ Updated {VKEY_FILE}");
+    Ok(())
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+/// Hash printed by `reml-prover vkey-hash --hex` (last `0x…` line; log lines
+/// share stdout)
+fn parse_prover_output(stdout: &str) -> Result<[u8; 32]> {
+    let line = stdout
+        .lines()
+        .rev()
+        .map(str::trim)
+        .find(|line| line.starts_with("0x") && line.len() == 66)
+        .context("reml-prover printed no vkey hash")?;
+
+    let mut hash = [0u8; 32];
+    for (i, byte) in hash.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&line[2 + 2 * i..4 + 2 * i], 16)
+            .with_context(|| format!("invalid vkey hash {line}"))?;
+    }
+    Ok(hash)
+}
+
+/// Span of the `REML_VKEY_HASH` array body (between `[` and `]`)
+fn vkey_array_span(source: &str) -> Result<(usize, usize)> {
+    let decl = source
+        .find("REML_VKEY_HASH")
+        .context("REML_VKEY_HASH not found")?;
+    let eq = decl
+        + source[decl..]
+            .find('=')
+            .context("REML_VKEY_HASH has no value")?;
+    let open = eq
+        + source[eq..]
+            .find('[')
+            .context("REML_VKEY_HASH is not an array")?;
+    let close = open
+        + source[open..]
+            .find(']')
+            .context("unterminated REML_VKEY_HASH")?;
+    Ok((open + 1, close))
+}
+
+fn parse_vkey_file(source: &str) -> Result<[u8; 32]> {
+    let (start, end) = vkey_array_span(source)?;
+    let bytes = source[start..end]
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(|item| {
+            u8::from_str_radix(item.trim_start_matches("0x"), 16)
+                .with_context(|| format!("invalid byte {item} in REML_VKEY_HASH"))
+        })
+        .collect::<Result<Vec<u8>>>()?;
+
+    bytes.try_into().map_err(|bytes: Vec<u8>| {
+        anyhow::anyhow!("REML_VKEY_HASH has {} bytes, expected 32", bytes.len())
+    })
+}
+
+/// `source` with the array body replaced by `hash`, 8 bytes per line
+fn render_vkey_file(source: &str, hash: &[u8; 32]) -> Result<String> {
+    let (start, end) = vkey_array_span(source)?;
+    let mut body = String::from("\n");
+    for row in hash.chunks(8) {
+        let row: Vec<String> = row.iter().map(|b| format!("0x{b:02x}")).collect();
+        body.push_str(&format!("    {},\n", row.join(", ")));
+    }
+    Ok(format!("{}{body}{}", &source[..start], &source[end..]))
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TESTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VKEY_SOURCE: &str = include_str!("../../runtime/src/configs/reml_vkey.rs");
+
+    #[test]
+    fn vkey_file_round_trips() {
+        let hash: [u8; 32] = core::array::from_fn(|i| i as u8 * 7);
+        let rendered = render_vkey_file(VKEY_SOURCE, &hash).unwrap();
+
+        assert_eq!(parse_vkey_file(&rendered).unwrap(), hash);
+        // Re-rendering the committed value leaves the file untouched
+        let pinned = parse_vkey_file(VKEY_SOURCE).unwrap();
+        assert_eq!(render_vkey_file(VKEY_SOURCE, &pinned).unwrap(), VKEY_SOURCE);
+    }
+
+    #[test]
+    fn prover_hash_is_read_past_log_lines() {
+        let stdout = format!(
+            "INFO Computing verification key hash\n0x{}\n",
+            "ab".repeat(32)
+        );
+        assert_eq!(parse_prover_output(&stdout).unwrap(), [0xab; 32]);
+    }
+}
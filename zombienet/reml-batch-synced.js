@@ -0,0 +1,25 @@
+// Checks that the batch verified through Alice is stored on Bob once
+// finalized, i.e. the block was imported and executed by the second node.
+
+const BATCH_ID = 1;
+
+async function run(nodeName, networkInfo) {
+    const { wsUri, userDefinedTypes } = networkInfo.nodesByName[nodeName];
+    const api = await zombie.connect(wsUri, userDefinedTypes);
+
+    for (;;) {
+        const finalized = await api.rpc.chain.getFinalizedHead();
+        const at = await api.at(finalized);
+        const batch = await at.query.remlVerifier.verifiedBatches(BATCH_ID);
+        if (batch.isSome) {
+            console.log(`✅ batch ${BATCH_ID} finalized on ${nodeName}`);
+            break;
+        }
+        await new Promise((resolve) => setTimeout(resolve, 2000));
+    }
+
+    await api.disconnect();
+    return 0;
+}
+
+module.exports = { run };
@@ -0,0 +1,161 @@
+// Re-ML smoke test, run by zombienet on a live node.
+//
+// (a) sudo registers Alice as an aggregator  → AggregatorRegistered
+// (b) Alice submits a mock proof for batch 1 → ProofVerified
+//
+// The mock proof embeds the public values binding hash, which is what the
+// pallet checks on a node built with `--features lenient-testnet`; the
+// requests roots are the keccak and blake2 Merkle roots shared with reml-lib.
+
+const REML_VERSION = 5;
+const REML_PROGRAM_ID = 1;
+const TESSERAX_CHAIN_ID = 13817;
+const REML_PROOF_EPOCH = 1;
+const MIN_PROOF_SIZE = 1024;
+const BATCH_ID = 1;
+const REQUEST_IDS = [1, 2, 3, 4, 5];
+
+function le(value, bytes) {
+    const out = new Uint8Array(bytes);
+    let v = BigInt(value);
+    for (let i = 0; i < bytes; i++) {
+        out[i] = Number(v & 0xffn);
+        v >>= 8n;
+    }
+    return out;
+}
+
+function requestsRoot(hash, util, ids) {
+    let level = ids.map((id) => hash(le(id, 8)));
+    while (level.length > 1) {
+        const next = [];
+        for (let i = 0; i < level.length; i += 2) {
+            next.push(
+                i + 1 < level.length
+                    ? hash(util.u8aConcat(level[i], level[i + 1]))
+                    : level[i],
+            );
+        }
+        level = next;
+    }
+    return level[0];
+}
+
+function bindingHash(util, pv) {
+    return util.blake2AsU8a(
+        util.u8aConcat(
+            le(pv.version, 1),
+            le(pv.chainId, 4),
+            le(pv.epoch, 4),
+            le(pv.batchId, 8),
+            le(pv.verifiedCount, 4),
+            le(pv.mldsa44Count, 4),
+            le(pv.falcon512Count, 4),
+            pv.requestsRoot,
+            pv.requestsRootBlake2,
+            util.blake2AsU8a(util.u8aConcat(...pv.verifiedRequestOwners)),
+            util.blake2AsU8a(util.u8aConcat(...pv.verifiedRequestMessages)),
+        ),
+    );
+}
+
+// Sign, submit and wait for inclusion; returns the extrinsic's events
+function submit(api, tx, signer) {
+    return new Promise((resolve, reject) => {
+        tx.signAndSend(signer, ({ status, events, dispatchError }) => {
+            if (dispatchError) {
+                const error = dispatchError.isModule
+                    ? api.registry.findMetaError(dispatchError.asModule)
+                    : { section: "dispatch", name: dispatchError.toString() };
+                reject(new Error(`${error.section}.${error.name}`));
+            } else if (status.isInBlock) {
+                resolve(events.map(({ event }) => event));
+            }
+        }).catch(reject);
+    });
+}
+
+function expectEvent(events, section, method) {
+    const event = events.find((e) => e.section === section && e.method === method);
+    if (!event) throw new Error(`missing ${section}.${method}`);
+    console.log(`✅ ${section}.${method} ${JSON.stringify(event.data.toHuman())}`);
+    return event;
+}
+
+async function run(nodeName, networkInfo) {
+    const { wsUri, userDefinedTypes } = networkInfo.nodesByName[nodeName];
+    const api = await zombie.connect(wsUri, userDefinedTypes);
+    const util = zombie.util;
+
+    await util.cryptoWaitReady();
+    const keyring = new zombie.Keyring({ type: "sr25519" });
+    const alice = keyring.addFromUri("//Alice");
+
+    // (a) Register the aggregator through sudo (Alice is sudo on `local`)
+    let events = await submit(
+        api,
+        api.tx.sudo.sudo(api.tx.remlVerifier.registerAggregator(alice.address)),
+        alice,
+    );
+    expectEvent(events, "sudo", "Sudid");
+    expectEvent(events, "remlVerifier", "AggregatorRegistered");
+
+    // (b) Submit a mock proof bound to the public values. Each request's
+    // owner is the blake2 hash of its signer's public key and its message the
+    // blake2 hash of what it signed; stand-in hashes do here, as no vault
+    // consumes the requests.
+    const publicValues = {
+        version: REML_VERSION,
+        chainId: TESSERAX_CHAIN_ID,
+        epoch: REML_PROOF_EPOCH,
+        batchId: BATCH_ID,
+        verifiedCount: REQUEST_IDS.length,
+        mldsa44Count: REQUEST_IDS.length,
+        falcon512Count: 0,
+        requestsRoot: requestsRoot(util.keccakAsU8a, util, REQUEST_IDS),
+        requestsRootBlake2: requestsRoot(util.blake2AsU8a, util, REQUEST_IDS),
+        verifiedRequestIds: REQUEST_IDS,
+        verifiedRequestOwners: REQUEST_IDS.map((id) => util.blake2AsU8a(le(id, 8))),
+        verifiedRequestMessages: REQUEST_IDS.map((id) => util.blake2AsU8a(le(id + 1, 8))),
+    };
+    const proof = new Uint8Array(MIN_PROOF_SIZE);
+    proof[0] = 0x01;
+    proof.set(bindingHash(util, publicValues), 1);
+
+    // Anchor to the best block, which the submission's block will follow
+    const anchor = await api.rpc.chain.getHeader();
+
+    events = await submit(
+        api,
+        api.tx.remlVerifier.submitProof({
+            batchId: BATCH_ID,
+            proof: util.u8aToHex(proof),
+            publicValues: {
+                ...publicValues,
+                requestsRoot: util.u8aToHex(publicValues.requestsRoot),
+                requestsRootBlake2: util.u8aToHex(publicValues.requestsRootBlake2),
+                verifiedRequestOwners: publicValues.verifiedRequestOwners.map((owner) =>
+                    util.u8aToHex(owner),
+                ),
+                verifiedRequestMessages: publicValues.verifiedRequestMessages.map((message) =>
+                    util.u8aToHex(message),
+                ),
+            },
+            // Pinned guest vkey (`cargo xtask vkey`), or zeros when unpinned
+            vkeyHash: api.consts.remlVerifier.expectedVKeyHash.toHex(),
+            programId: REML_PROGRAM_ID,
+            anchorBlock: anchor.number.toNumber(),
+            anchorHash: anchor.hash.toHex(),
+        }),
+        alice,
+    );
+    const verified = expectEvent(events, "remlVerifier", "ProofVerified");
+    if (verified.data.signatureCount.toNumber() !== REQUEST_IDS.length) {
+        throw new Error("ProofVerified reports the wrong signature count");
+    }
+
+    await api.disconnect();
+    return 0;
+}
+
+module.exports = { run };